@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 use std::str::FromStr;
 
 use crate::services::skill::SkillStore;
@@ -168,6 +169,56 @@ pub struct McpServer {
     pub tags: Vec<String>,
 }
 
+impl McpServer {
+    /// 服务器使用的传输方式（由 `server.type` 推断）
+    pub fn transport(&self) -> McpTransport {
+        McpTransport::from_spec(&self.server)
+    }
+}
+
+/// MCP 传输方式：本地 stdio 命令，或通过 url 连接的 SSE/HTTP 远程服务器
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum McpTransport {
+    #[default]
+    Stdio,
+    Sse,
+    Http,
+}
+
+impl McpTransport {
+    /// 缺省 type 时：有 url 无 command 视为 sse（与 Gemini 约定一致），否则视为 stdio
+    pub fn from_spec(spec: &serde_json::Value) -> Self {
+        match spec.get("type").and_then(|value| value.as_str()) {
+            Some("sse") => Self::Sse,
+            Some("http") => Self::Http,
+            Some(_) => Self::Stdio,
+            None if spec.get("command").is_none() && spec.get("url").is_some() => Self::Sse,
+            None => Self::Stdio,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Stdio => "stdio",
+            Self::Sse => "sse",
+            Self::Http => "http",
+        }
+    }
+
+    pub fn is_remote(&self) -> bool {
+        !matches!(self, Self::Stdio)
+    }
+
+    /// 循环切换到下一种传输方式
+    pub fn next(&self) -> Self {
+        match self {
+            Self::Stdio => Self::Sse,
+            Self::Sse => Self::Http,
+            Self::Http => Self::Stdio,
+        }
+    }
+}
+
 /// MCP 配置：单客户端维度（v3.6.x 及以前，保留用于向后兼容）
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct McpConfig {
@@ -252,6 +303,37 @@ impl AppType {
             AppType::Gemini => "gemini", // 新增
         }
     }
+
+    /// 项目级标记目录（如 `.claude/`），用于按工作目录自动识别应用
+    fn project_marker(&self) -> &'static str {
+        match self {
+            AppType::Claude => ".claude",
+            AppType::Codex => ".codex",
+            AppType::Gemini => ".gemini",
+        }
+    }
+
+    /// 从 `start` 逐级向上查找项目标记目录，返回最近一级识别到的应用
+    ///
+    /// 用户主目录下的同名目录是全局配置而非项目标记，查找到主目录即停止；
+    /// 同一级目录存在多个应用标记时视为无法判断，返回 None
+    pub fn detect_from_dir(start: &Path) -> Option<AppType> {
+        let home = dirs::home_dir();
+        for dir in start.ancestors() {
+            if home.as_deref() == Some(dir) {
+                break;
+            }
+            let mut found = [AppType::Claude, AppType::Codex, AppType::Gemini]
+                .into_iter()
+                .filter(|app| dir.join(app.project_marker()).is_dir());
+            match (found.next(), found.next()) {
+                (None, _) => continue,
+                (Some(app), None) => return Some(app),
+                (Some(_), Some(_)) => return None,
+            }
+        }
+        None
+    }
 }
 
 impl std::fmt::Display for AppType {
@@ -959,4 +1041,24 @@ mod tests {
                 .enabled
         );
     }
+
+    #[test]
+    #[serial]
+    fn detect_from_dir_uses_nearest_project_marker_below_home() {
+        let home = TempHome::new();
+        let root = home.dir.path();
+        fs::create_dir_all(root.join(".claude")).expect("global claude dir");
+        fs::create_dir_all(root.join("proj/.codex")).expect("project codex dir");
+        fs::create_dir_all(root.join("proj/src/nested")).expect("nested dir");
+        fs::create_dir_all(root.join("both/.claude")).expect("both claude");
+        fs::create_dir_all(root.join("both/.gemini")).expect("both gemini");
+        fs::create_dir_all(root.join("plain")).expect("plain dir");
+
+        assert_eq!(
+            AppType::detect_from_dir(&root.join("proj/src/nested")),
+            Some(AppType::Codex)
+        );
+        assert_eq!(AppType::detect_from_dir(&root.join("plain")), None);
+        assert_eq!(AppType::detect_from_dir(&root.join("both")), None);
+    }
 }
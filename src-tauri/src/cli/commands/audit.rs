@@ -0,0 +1,61 @@
+use clap::Subcommand;
+
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, info, to_json};
+use crate::error::AppError;
+use crate::services::audit::{audit_log_path, recent_entries};
+
+#[derive(Subcommand)]
+pub enum AuditCommand {
+    /// Show the most recent entries of ~/.cc-switch/audit.log
+    Tail {
+        /// Number of entries to show
+        #[arg(short = 'n', long, default_value_t = 20)]
+        lines: usize,
+        /// Print the entries as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub fn execute(cmd: AuditCommand) -> Result<(), AppError> {
+    match cmd {
+        AuditCommand::Tail { lines, json } => tail(lines, json),
+    }
+}
+
+fn tail(lines: usize, json: bool) -> Result<(), AppError> {
+    let entries = recent_entries(lines)?;
+    if json {
+        let output = to_json(&entries).map_err(|source| AppError::JsonSerialize { source })?;
+        println!("{}", output);
+        return Ok(());
+    }
+    if entries.is_empty() {
+        println!(
+            "{}",
+            info(&texts::audit_log_empty(
+                &audit_log_path().display().to_string()
+            ))
+        );
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["Time", "Operation", "App", "ID", "Outcome"]);
+    for entry in &entries {
+        let outcome = match &entry.error {
+            Some(err) if !entry.is_ok() => format!("{}: {err}", entry.outcome),
+            _ => entry.outcome.clone(),
+        };
+        table.add_row(vec![
+            entry.timestamp.clone(),
+            entry.operation.clone(),
+            entry.app.clone().unwrap_or_else(|| "-".to_string()),
+            entry.id.clone().unwrap_or_else(|| "-".to_string()),
+            outcome,
+        ]);
+    }
+    println!("{}", table);
+    Ok(())
+}
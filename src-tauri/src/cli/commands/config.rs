@@ -1,30 +1,71 @@
 use clap::Subcommand;
 use std::fs;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
 use crate::app_config::AppType;
+use crate::cli::commands::speedtest::SpeedtestArgs;
+use crate::cli::commands::{diagnostics, time_filter};
 use crate::cli::i18n::texts;
-use crate::cli::ui::{error, highlight, info, success, to_json};
+use crate::cli::tui::form::is_secret_field;
+use crate::cli::ui::{
+    create_table, error, highlight, info, render_unified_diff, success, to_json, warning,
+};
 use crate::error::AppError;
-use crate::services::ConfigService;
+use crate::services::config::BackupInfo;
+use crate::services::config_archive::ARCHIVE_EXTENSION;
+use crate::services::provider::resolve_env_placeholders;
+use crate::services::{
+    ArchiveKind, ChangeKind, ConfigArchiveService, ConfigChangeSummary, ConfigService, Diagnostic,
+    DiagnosticReport, McpService, NormalizeOutcome, PromptService, ProviderService,
+    SpeedtestOptions,
+};
 use crate::store::AppState;
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
 
 #[derive(Subcommand)]
 pub enum ConfigCommand {
     /// Show current configuration
     Show,
+    /// Show the active provider, MCP and prompt summary for an app
+    Current {
+        /// Print the summary as JSON
+        #[arg(long)]
+        json: bool,
+        /// Print the active provider and enabled MCP server env as shell exports
+        #[arg(long, conflicts_with = "json")]
+        env: bool,
+    },
     /// Show configuration file path
     Path,
-    /// Export configuration to file
+    /// Open the configuration directory in the system file manager
+    Open,
+    /// Export configuration to file (SQL dump, or a .ccs archive with skills)
     Export {
         /// Output file path
-        file: PathBuf,
+        #[arg(required_unless_present = "output")]
+        file: Option<PathBuf>,
+
+        /// Output file path (alternative to the positional argument)
+        #[arg(short, long, conflicts_with = "file")]
+        output: Option<PathBuf>,
+
+        /// Bundle the database and skills into a passphrase-encrypted archive
+        #[arg(long)]
+        encrypt: bool,
     },
-    /// Import configuration from file
+    /// Import configuration from a SQL dump or a .ccs archive
     Import {
         /// Input file path
         file: PathBuf,
     },
+    /// List available backups
+    Backups {
+        /// Only show backups created since a relative (7d, 24h) or absolute (2024-01-01) time
+        #[arg(long, value_parser = time_filter::parse_since)]
+        since: Option<DateTime<Utc>>,
+    },
     /// Create a backup of current configuration
     Backup {
         /// Optional custom name for the backup
@@ -42,9 +83,86 @@ pub enum ConfigCommand {
         file: Option<PathBuf>,
     },
     /// Validate configuration file
-    Validate,
-    /// Reset to default configuration
-    Reset,
+    #[command(alias = "selftest")]
+    Validate {
+        /// Print a machine-readable diagnostic report
+        #[arg(long)]
+        json: bool,
+        /// Also check that each initialized app's live files exist and parse (honors --app)
+        #[arg(long)]
+        live: bool,
+    },
+    /// Rewrite the legacy config.json in canonical form (sorted keys, defaults filled)
+    Normalize,
+    /// Reset to default configuration (with --app, only clear that app's providers)
+    Reset {
+        /// Skip the confirmation prompt (required when not running in a terminal)
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+    /// Get or set persisted offline mode (on|off), which disables network features
+    Offline {
+        /// Optional state to set (omit to show current)
+        #[arg(value_parser = clap::builder::BoolishValueParser::new())]
+        enabled: Option<bool>,
+    },
+    /// Show or hide sponsor presets in the Add Provider templates (on|off)
+    Sponsors {
+        /// Optional state to set (omit to show current)
+        #[arg(value_parser = clap::builder::BoolishValueParser::new())]
+        enabled: Option<bool>,
+    },
+    /// Get or set how many days ahead provider key expiry warnings start (default 7)
+    ExpiryWarning {
+        /// Days before the expiry date (0-365)
+        #[arg(value_parser = clap::value_parser!(u32).range(0..=365))]
+        days: Option<u32>,
+    },
+    /// Get or set a shell command run after every successful provider switch
+    ///
+    /// The command receives CC_SWITCH_APP, CC_SWITCH_PROVIDER_ID and CC_SWITCH_PROVIDER_NAME;
+    /// failures are logged and never undo the switch.
+    OnSwitch {
+        /// Command to run (omit to show the current one)
+        command: Option<String>,
+        /// Remove the configured command
+        #[arg(long, conflicts_with = "command")]
+        clear: bool,
+    },
+    /// Sync the app's enabled skills after every successful provider switch (on|off)
+    SkillsOnSwitch {
+        /// Optional state to set (omit to show current)
+        #[arg(value_parser = clap::builder::BoolishValueParser::new())]
+        enabled: Option<bool>,
+    },
+    /// Only log skill symlink-to-copy fallbacks at debug level (on|off)
+    ///
+    /// When off, each skill warns on its first fallback and stays quiet afterwards.
+    QuietSkillFallback {
+        /// Optional state to set (omit to show current)
+        #[arg(value_parser = clap::builder::BoolishValueParser::new())]
+        quiet: Option<bool>,
+    },
+    /// Get or set the base URL and model pre-filled in new-provider forms for the app
+    ProviderDefaults {
+        /// Default base URL
+        #[arg(long)]
+        base_url: Option<String>,
+        /// Default model
+        #[arg(long)]
+        model: Option<String>,
+        /// Restore the built-in defaults
+        #[arg(long, conflicts_with_all = ["base_url", "model"])]
+        reset: bool,
+    },
+    /// Get or set the default speedtest request timeout and User-Agent
+    Speedtest {
+        #[command(flatten)]
+        request: SpeedtestArgs,
+        /// Restore the built-in defaults
+        #[arg(long, conflicts_with_all = ["timeout", "user_agent"])]
+        reset: bool,
+    },
 
     /// Manage common configuration snippet (per app)
     #[command(subcommand)]
@@ -75,22 +193,296 @@ pub enum CommonConfigCommand {
         #[arg(long)]
         apply: bool,
     },
+    /// Re-apply the saved snippet to the current provider's live config
+    Apply {
+        /// Only show how the snippet changes the current provider's config
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Validate a snippet and preview its merge into the current provider (nothing is saved)
+    Validate {
+        /// Snippet string (JSON object, or TOML for Codex)
+        #[arg(long, conflicts_with = "file")]
+        json: Option<String>,
+
+        /// Read the snippet from file
+        #[arg(long, conflicts_with = "json")]
+        file: Option<PathBuf>,
+    },
+}
+
+impl ConfigCommand {
+    /// 是否会修改配置（用于 `--read-only` 拦截）
+    pub fn is_mutation(&self) -> bool {
+        match self {
+            Self::Show
+            | Self::Current { .. }
+            | Self::Path
+            | Self::Open
+            | Self::Export { .. }
+            | Self::Backups { .. }
+            | Self::Validate { .. } => false,
+            Self::Offline { enabled } | Self::Sponsors { enabled } => enabled.is_some(),
+            Self::ExpiryWarning { days } => days.is_some(),
+            Self::OnSwitch { command, clear } => command.is_some() || *clear,
+            Self::SkillsOnSwitch { enabled } => enabled.is_some(),
+            Self::QuietSkillFallback { quiet } => quiet.is_some(),
+            Self::ProviderDefaults {
+                base_url,
+                model,
+                reset,
+            } => base_url.is_some() || model.is_some() || *reset,
+            Self::Speedtest { request, reset } => {
+                request.timeout.is_some() || request.user_agent.is_some() || *reset
+            }
+            Self::Common(cmd) => !matches!(
+                cmd,
+                CommonConfigCommand::Show
+                    | CommonConfigCommand::Validate { .. }
+                    | CommonConfigCommand::Apply { dry_run: true }
+            ),
+            _ => true,
+        }
+    }
 }
 
 pub fn execute(cmd: ConfigCommand, app: Option<AppType>) -> Result<(), AppError> {
     match cmd {
         ConfigCommand::Show => show_config(),
+        ConfigCommand::Current { env: true, .. } => {
+            let state = get_state()?;
+            print!(
+                "{}",
+                render_current_env_exports(&state, &crate::cli::resolve_app(app))?
+            );
+            Ok(())
+        }
+        ConfigCommand::Current { json, .. } => show_current(crate::cli::resolve_app(app), json),
         ConfigCommand::Path => show_path(),
-        ConfigCommand::Export { file } => export_config(&file),
+        ConfigCommand::Open => open_config_dir(),
+        ConfigCommand::Export {
+            file,
+            output,
+            encrypt,
+        } => export_config(&file.or(output).unwrap_or_default(), encrypt),
         ConfigCommand::Import { file } => import_config(&file),
+        ConfigCommand::Backups { since } => list_backups(since),
         ConfigCommand::Backup { name } => backup_config(name.as_deref()),
         ConfigCommand::Restore { backup, file } => {
             restore_config(backup.as_deref(), file.as_deref())
         }
-        ConfigCommand::Validate => validate_config(),
-        ConfigCommand::Reset => reset_config(),
-        ConfigCommand::Common(cmd) => execute_common(cmd, app.unwrap_or(AppType::Claude)),
+        ConfigCommand::Validate { json, live } => validate_config(json, live.then_some(app)),
+        ConfigCommand::Normalize => normalize_config(),
+        ConfigCommand::Reset { yes } => match app {
+            Some(app) => reset_app_config(app, yes),
+            None => reset_config(yes),
+        },
+        ConfigCommand::Offline { enabled } => offline_mode(enabled),
+        ConfigCommand::Sponsors { enabled } => sponsor_presets(enabled),
+        ConfigCommand::ExpiryWarning { days } => expiry_warning(days),
+        ConfigCommand::OnSwitch { command, clear } => on_switch_command(command, clear),
+        ConfigCommand::SkillsOnSwitch { enabled } => sync_skills_on_switch(enabled),
+        ConfigCommand::QuietSkillFallback { quiet } => quiet_skill_fallback(quiet),
+        ConfigCommand::ProviderDefaults {
+            base_url,
+            model,
+            reset,
+        } => provider_defaults(crate::cli::resolve_app(app), base_url, model, reset),
+        ConfigCommand::Speedtest { request, reset } => speedtest_defaults(request, reset),
+        ConfigCommand::Common(cmd) => execute_common(cmd, crate::cli::resolve_app(app)),
+    }
+}
+
+fn offline_mode(enabled: Option<bool>) -> Result<(), AppError> {
+    match enabled {
+        Some(enabled) => {
+            crate::settings::set_offline_mode(enabled)?;
+            println!("{}", success(&texts::offline_mode_changed(enabled)));
+        }
+        None => {
+            let enabled = crate::settings::get_offline_mode();
+            println!("{}", highlight(texts::offline_mode_label()));
+            println!(
+                "{}",
+                if enabled {
+                    texts::enabled()
+                } else {
+                    texts::disabled()
+                }
+            );
+        }
+    }
+    Ok(())
+}
+
+fn expiry_warning(days: Option<u32>) -> Result<(), AppError> {
+    match days {
+        Some(days) => {
+            crate::settings::set_expiry_warning_days(Some(days))?;
+            println!("{}", success(&texts::expiry_warning_window_set(days)));
+        }
+        None => println!(
+            "{}",
+            texts::expiry_warning_window(crate::services::expiry_warning_days())
+        ),
+    }
+    Ok(())
+}
+
+fn on_switch_command(command: Option<String>, clear: bool) -> Result<(), AppError> {
+    if clear {
+        crate::settings::set_on_switch_command(None)?;
+        println!("{}", success(texts::on_switch_command_cleared()));
+        return Ok(());
+    }
+    match command {
+        Some(command) => {
+            let command = command.trim();
+            if command.is_empty() {
+                return Err(AppError::InvalidInput(
+                    texts::on_switch_command_empty().to_string(),
+                ));
+            }
+            crate::settings::set_on_switch_command(Some(command.to_string()))?;
+            println!("{}", success(&texts::on_switch_command_set(command)));
+        }
+        None => match crate::settings::get_on_switch_command() {
+            Some(command) => println!("{}", texts::on_switch_command_current(&command)),
+            None => println!("{}", info(texts::on_switch_command_none())),
+        },
+    }
+    Ok(())
+}
+
+fn quiet_skill_fallback(quiet: Option<bool>) -> Result<(), AppError> {
+    match quiet {
+        Some(quiet) => {
+            crate::settings::set_quiet_skill_copy_fallback(quiet)?;
+            println!("{}", success(&texts::quiet_skill_fallback_changed(quiet)));
+        }
+        None => {
+            println!("{}", highlight(texts::quiet_skill_fallback_label()));
+            println!(
+                "{}",
+                if crate::settings::get_quiet_skill_copy_fallback() {
+                    texts::enabled()
+                } else {
+                    texts::disabled()
+                }
+            );
+        }
+    }
+    Ok(())
+}
+
+fn sync_skills_on_switch(enabled: Option<bool>) -> Result<(), AppError> {
+    match enabled {
+        Some(enabled) => {
+            crate::settings::set_sync_skills_on_switch(enabled)?;
+            println!(
+                "{}",
+                success(&texts::sync_skills_on_switch_changed(enabled))
+            );
+        }
+        None => {
+            println!("{}", highlight(texts::sync_skills_on_switch_label()));
+            println!(
+                "{}",
+                if crate::settings::get_sync_skills_on_switch() {
+                    texts::enabled()
+                } else {
+                    texts::disabled()
+                }
+            );
+        }
+    }
+    Ok(())
+}
+
+fn sponsor_presets(enabled: Option<bool>) -> Result<(), AppError> {
+    match enabled {
+        Some(enabled) => {
+            crate::settings::set_hide_sponsor_presets(!enabled)?;
+            println!("{}", success(&texts::sponsor_presets_changed(enabled)));
+        }
+        None => {
+            let hidden = crate::settings::get_hide_sponsor_presets();
+            println!("{}", highlight(texts::sponsor_presets_label()));
+            println!(
+                "{}",
+                if hidden {
+                    texts::disabled()
+                } else {
+                    texts::enabled()
+                }
+            );
+        }
     }
+    Ok(())
+}
+
+fn provider_defaults(
+    app_type: AppType,
+    base_url: Option<String>,
+    model: Option<String>,
+    reset: bool,
+) -> Result<(), AppError> {
+    if reset {
+        crate::settings::set_new_provider_defaults(&app_type, None, None)?;
+        println!(
+            "{}",
+            success(&texts::provider_defaults_reset(app_type.as_str()))
+        );
+    } else if base_url.is_some() || model.is_some() {
+        let (current_base_url, current_model) =
+            crate::settings::get_new_provider_defaults(&app_type);
+        let non_blank = |value: String| Some(value.trim().to_string()).filter(|v| !v.is_empty());
+        let base_url = base_url.map_or(current_base_url, non_blank);
+        let model = model.map_or(current_model, non_blank);
+        crate::settings::set_new_provider_defaults(&app_type, base_url, model)?;
+        println!(
+            "{}",
+            success(&texts::provider_defaults_saved(app_type.as_str()))
+        );
+    }
+
+    let (base_url, model) = crate::cli::tui::form::new_provider_defaults(&app_type);
+    println!(
+        "{}",
+        highlight(&texts::provider_defaults_label(app_type.as_str()))
+    );
+    println!("{}", texts::provider_defaults_summary(&base_url, &model));
+    Ok(())
+}
+
+fn speedtest_defaults(request: SpeedtestArgs, reset: bool) -> Result<(), AppError> {
+    let SpeedtestArgs {
+        timeout,
+        user_agent,
+    } = request;
+    if reset {
+        crate::settings::set_speedtest_defaults(None, None)?;
+        println!("{}", success(texts::speedtest_defaults_reset()));
+    } else if timeout.is_some() || user_agent.is_some() {
+        let user_agent = user_agent
+            .map(|ua| ua.trim().to_string())
+            .or_else(crate::settings::get_speedtest_user_agent)
+            .filter(|ua| !ua.is_empty());
+        let timeout = timeout.or_else(crate::settings::get_speedtest_timeout_secs);
+        crate::settings::set_speedtest_defaults(user_agent, timeout)?;
+        println!("{}", success(texts::speedtest_defaults_saved()));
+    }
+
+    let options = SpeedtestOptions::default();
+    println!("{}", highlight(texts::speedtest_defaults_label()));
+    println!(
+        "{}",
+        texts::speedtest_defaults_summary(
+            options.effective_timeout_secs(),
+            &options.effective_user_agent()
+        )
+    );
+    Ok(())
 }
 
 fn get_state() -> Result<AppState, AppError> {
@@ -105,13 +497,208 @@ fn show_config() -> Result<(), AppError> {
     println!("{}", "=".repeat(50));
     println!();
 
-    // Display in pretty JSON format
-    let json = to_json(&*config).map_err(|e| AppError::Message(e.to_string()))?;
+    // Display in pretty JSON format (pooled API keys masked)
+    let mut value =
+        serde_json::to_value(&*config).map_err(|source| AppError::JsonSerialize { source })?;
+    crate::cli::tui::form::redact_key_pools(&mut value);
+    let json = to_json(&value).map_err(|e| AppError::Message(e.to_string()))?;
     println!("{}", json);
 
     Ok(())
 }
 
+/// 当前应用的配置摘要（交互式视图与 `config current` 共用）
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentConfigSummary {
+    pub app: String,
+    pub provider: Option<CurrentProviderSummary>,
+    pub mcp_total: usize,
+    pub mcp_enabled: usize,
+    pub prompt_total: usize,
+    pub active_prompt: Option<ActivePromptSummary>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentProviderSummary {
+    pub id: String,
+    pub name: String,
+    pub api_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivePromptSummary {
+    pub id: String,
+    pub name: String,
+}
+
+pub fn collect_current_config_summary(
+    state: &AppState,
+    app_type: &AppType,
+) -> Result<CurrentConfigSummary, AppError> {
+    let current_provider = ProviderService::current(state, app_type.clone())?;
+    let providers = ProviderService::list(state, app_type.clone())?;
+    let provider = providers
+        .get(&current_provider)
+        .map(|provider| CurrentProviderSummary {
+            id: current_provider.clone(),
+            name: provider.name.clone(),
+            api_url: crate::cli::commands::provider::extract_api_url(
+                &provider.settings_config,
+                app_type,
+            ),
+        });
+
+    let mcp_servers = McpService::get_all_servers(state)?;
+    let mcp_enabled = mcp_servers
+        .values()
+        .filter(|s| s.apps.is_enabled_for(app_type))
+        .count();
+
+    let prompts = PromptService::get_prompts(state, app_type.clone())?;
+    let active_prompt =
+        prompts
+            .iter()
+            .find(|(_, p)| p.enabled)
+            .map(|(id, p)| ActivePromptSummary {
+                id: id.clone(),
+                name: p.name.clone(),
+            });
+
+    Ok(CurrentConfigSummary {
+        app: app_type.as_str().to_string(),
+        provider,
+        mcp_total: mcp_servers.len(),
+        mcp_enabled,
+        prompt_total: prompts.len(),
+        active_prompt,
+    })
+}
+
+/// 当前应用的供应商凭据与已启用 MCP 服务器的 env，渲染为可 `source` 的 shell export；
+/// `${env:NAME}` 占位符先解析为实际值，疑似密钥的变量在行尾以 `# secret` 标注
+pub fn render_current_env_exports(
+    state: &AppState,
+    app_type: &AppType,
+) -> Result<String, AppError> {
+    let mut out = String::new();
+    let current = ProviderService::current(state, app_type.clone())?;
+    let providers = ProviderService::list(state, app_type.clone())?;
+    match providers.get(&current) {
+        Some(provider) => {
+            out.push_str(&format!(
+                "# cc-switch {}: provider '{}' ({})\n",
+                app_type.as_str(),
+                current,
+                provider.name
+            ));
+            let env_key = if matches!(app_type, AppType::Codex) {
+                "auth"
+            } else {
+                "env"
+            };
+            let settings = resolve_env_placeholders(&provider.settings_config)?;
+            push_env_exports(&mut out, settings.get(env_key));
+        }
+        None => out.push_str(&format!(
+            "# cc-switch {}: no current provider\n",
+            app_type.as_str()
+        )),
+    }
+
+    let mut servers: Vec<_> = McpService::get_all_servers(state)?
+        .into_values()
+        .filter(|server| server.apps.is_enabled_for(app_type))
+        .collect();
+    servers.sort_by(|a, b| a.id.cmp(&b.id));
+    for server in servers {
+        let Some(env) = server
+            .server
+            .get("env")
+            .filter(|env| env.as_object().is_some_and(|env| !env.is_empty()))
+        else {
+            continue;
+        };
+        out.push_str(&format!("\n# MCP server '{}'\n", server.id));
+        push_env_exports(&mut out, Some(env));
+    }
+    Ok(out)
+}
+
+fn push_env_exports(out: &mut String, env: Option<&serde_json::Value>) {
+    let Some(env) = env.and_then(|env| env.as_object()) else {
+        return;
+    };
+    let mut entries: Vec<_> = env.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    for (key, value) in entries {
+        let valid_name = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            continue;
+        }
+        let value = match value {
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Number(_) | serde_json::Value::Bool(_) => value.to_string(),
+            _ => continue,
+        };
+        let secret = is_secret_field(key);
+        out.push_str(&format!(
+            "export {key}='{}'{}\n",
+            value.replace('\'', "'\\''"),
+            if secret { "  # secret" } else { "" }
+        ));
+    }
+}
+
+pub fn print_current_config_summary(summary: &CurrentConfigSummary) {
+    println!("\n{}", highlight(texts::current_configuration()));
+    println!("{}", texts::tui_rule_heavy(60));
+
+    if let Some(provider) = &summary.provider {
+        println!("\n{}", highlight(texts::provider_label()));
+        println!(
+            "  {}:     {}",
+            texts::name_label_with_colon(),
+            provider.name
+        );
+        let api_url = provider.api_url.as_deref().unwrap_or(texts::tui_na());
+        println!("  {}:  {}", texts::api_url_label_colon(), api_url);
+    }
+
+    println!("\n{}", highlight(texts::mcp_servers_label()));
+    println!("  {}:     {}", texts::total(), summary.mcp_total);
+    println!("  {}:     {}", texts::enabled(), summary.mcp_enabled);
+
+    println!("\n{}", highlight(texts::prompts_label()));
+    println!("  {}:     {}", texts::total(), summary.prompt_total);
+    match &summary.active_prompt {
+        Some(prompt) => println!("  {}:     {}", texts::active(), prompt.name),
+        None => println!("  {}:     {}", texts::active(), texts::none()),
+    }
+
+    println!("\n{}", texts::tui_rule(60));
+}
+
+fn show_current(app_type: AppType, json: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let summary = collect_current_config_summary(&state, &app_type)?;
+
+    if json {
+        let output = to_json(&summary).map_err(|source| AppError::JsonSerialize { source })?;
+        println!("{}", output);
+    } else {
+        print_current_config_summary(&summary);
+    }
+
+    Ok(())
+}
+
 fn execute_common(cmd: CommonConfigCommand, app_type: AppType) -> Result<(), AppError> {
     match cmd {
         CommonConfigCommand::Show => show_common(app_type),
@@ -119,7 +706,79 @@ fn execute_common(cmd: CommonConfigCommand, app_type: AppType) -> Result<(), App
             set_common(app_type, json.as_deref(), file.as_deref(), apply)
         }
         CommonConfigCommand::Clear { apply } => clear_common(app_type, apply),
+        CommonConfigCommand::Apply { dry_run } => apply_common(app_type, dry_run),
+        CommonConfigCommand::Validate { json, file } => {
+            validate_common(app_type, json.as_deref(), file.as_deref())
+        }
+    }
+}
+
+/// 校验通用配置片段（Codex 为 TOML，其余为 JSON 对象），返回规范化后的文本或本地化错误信息
+pub(crate) fn normalize_common_snippet(app_type: &AppType, raw: &str) -> Result<String, String> {
+    let raw = raw.trim();
+    if matches!(app_type, AppType::Codex) {
+        let doc: toml_edit::DocumentMut = raw.parse().map_err(|e: toml_edit::TomlError| {
+            texts::common_config_snippet_invalid_toml(&e.to_string())
+        })?;
+        return Ok(doc.to_string().trim().to_string());
+    }
+
+    let value: serde_json::Value = serde_json::from_str(raw)
+        .map_err(|e| texts::common_config_snippet_invalid_json(&e.to_string()))?;
+    if !value.is_object() {
+        return Err(texts::common_config_snippet_not_object().to_string());
     }
+    serde_json::to_string_pretty(&value)
+        .map_err(|e| texts::failed_to_serialize_json(&e.to_string()))
+}
+
+fn validate_common(
+    app_type: AppType,
+    json_text: Option<&str>,
+    file: Option<&Path>,
+) -> Result<(), AppError> {
+    let raw = if let Some(text) = json_text {
+        text.to_string()
+    } else if let Some(path) = file {
+        fs::read_to_string(path).map_err(|e| AppError::io(path, e))?
+    } else {
+        return Err(AppError::InvalidInput(
+            texts::config_common_snippet_require_json_or_file().to_string(),
+        ));
+    };
+
+    let snippet = normalize_common_snippet(&app_type, &raw).map_err(AppError::InvalidInput)?;
+    println!(
+        "{}",
+        success(&texts::config_common_snippet_valid(app_type.as_str()))
+    );
+
+    let state = get_state()?;
+    let Some(preview) = ProviderService::preview_common_snippet(&state, app_type, &snippet)? else {
+        println!(
+            "{}",
+            info(texts::config_common_snippet_no_current_provider())
+        );
+        return Ok(());
+    };
+
+    println!();
+    println!(
+        "{}",
+        highlight(&texts::config_common_snippet_preview_title(
+            &preview.provider_id
+        ))
+    );
+    println!("{}", "=".repeat(50));
+    println!("{}", preview.merged);
+    if !preview.applies {
+        println!();
+        println!(
+            "{}",
+            warning(texts::config_common_snippet_disabled_for_provider())
+        );
+    }
+    Ok(())
 }
 
 fn show_common(app_type: AppType) -> Result<(), AppError> {
@@ -223,6 +882,47 @@ fn clear_common(app_type: AppType, apply: bool) -> Result<(), AppError> {
     Ok(())
 }
 
+fn apply_common(app_type: AppType, dry_run: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    if !dry_run {
+        return apply_common_to_current(&state, app_type);
+    }
+
+    let Some(preview) = ProviderService::preview_saved_common_snippet(&state, app_type)? else {
+        println!(
+            "{}",
+            info(texts::config_common_snippet_no_current_provider())
+        );
+        return Ok(());
+    };
+
+    println!(
+        "{}",
+        highlight(&texts::config_common_snippet_diff_title(
+            &preview.provider_id
+        ))
+    );
+    // 供应商关闭了通用配置时，--apply 不会合并片段，预览也应显示无变化
+    if !preview.applies {
+        println!("{}", info(texts::config_common_snippet_no_effect()));
+        println!(
+            "{}",
+            warning(texts::config_common_snippet_disabled_for_provider())
+        );
+        return Ok(());
+    }
+    match render_unified_diff(
+        "without snippet",
+        "with snippet",
+        &preview.base,
+        &preview.merged,
+    ) {
+        Some(diff) => println!("{}", diff),
+        None => println!("{}", info(texts::config_common_snippet_no_effect())),
+    }
+    Ok(())
+}
+
 fn apply_common_to_current(state: &AppState, app_type: AppType) -> Result<(), AppError> {
     use crate::services::ProviderService;
 
@@ -232,11 +932,21 @@ fn apply_common_to_current(state: &AppState, app_type: AppType) -> Result<(), Ap
         return Ok(());
     }
 
-    ProviderService::switch(state, app_type, &current_id)?;
+    ProviderService::resync_live(state, app_type, &current_id)?;
     println!("{}", success("✓ Applied to live config."));
     Ok(())
 }
 
+fn open_config_dir() -> Result<(), AppError> {
+    let config_dir = crate::config::get_app_config_dir();
+    crate::cli::opener::open_in_file_manager(&config_dir)?;
+    println!(
+        "{}",
+        success(&texts::opened_dir(&config_dir.display().to_string()))
+    );
+    Ok(())
+}
+
 fn show_path() -> Result<(), AppError> {
     let config_dir = crate::config::get_app_config_dir();
     let db_path = config_dir.join("cc-switch.db");
@@ -274,7 +984,7 @@ fn show_path() -> Result<(), AppError> {
     Ok(())
 }
 
-fn export_config(file: &PathBuf) -> Result<(), AppError> {
+fn export_config(file: &PathBuf, encrypt: bool) -> Result<(), AppError> {
     println!(
         "{}",
         info(&format!("Exporting configuration to {}...", file.display()))
@@ -302,7 +1012,20 @@ fn export_config(file: &PathBuf) -> Result<(), AppError> {
     }
 
     // Export configuration
-    ConfigService::export_config_to_path(file)?;
+    let as_archive = encrypt
+        || file
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(ARCHIVE_EXTENSION));
+    if as_archive {
+        let passphrase = if encrypt {
+            Some(prompt_passphrase(true)?)
+        } else {
+            None
+        };
+        ConfigArchiveService::export_to_path(file, passphrase.as_deref())?;
+    } else {
+        ConfigService::export_config_to_path(file)?;
+    }
 
     println!(
         "{}",
@@ -329,10 +1052,16 @@ fn import_config(file: &PathBuf) -> Result<(), AppError> {
         )));
     }
 
+    let archive_kind = ConfigArchiveService::detect(file)?;
+
     // Confirm import
     println!();
     println!("{}", highlight("Warning:"));
-    println!("This will replace your current database with the imported SQL backup.");
+    if archive_kind.is_some() {
+        println!("{}", texts::archive_import_warning());
+    } else {
+        println!("This will replace your current database with the imported SQL backup.");
+    }
     println!("A backup will be created automatically.");
     println!();
 
@@ -348,15 +1077,29 @@ fn import_config(file: &PathBuf) -> Result<(), AppError> {
 
     // Perform import
     let state = get_state()?;
-    let backup_id = ConfigService::import_config_from_path(file, &state)?;
+    let outcome = match archive_kind {
+        Some(kind) => {
+            let passphrase = if kind == ArchiveKind::Encrypted {
+                Some(prompt_passphrase(false)?)
+            } else {
+                None
+            };
+            ConfigArchiveService::import_from_path(file, passphrase.as_deref(), &state)?
+        }
+        None => ConfigService::import_config_from_path(file, &state)?,
+    };
 
     println!(
         "{}",
         success(&format!("✓ Configuration imported from {}", file.display()))
     );
-    if !backup_id.is_empty() {
-        println!("{}", info(&format!("  Backup created: {}", backup_id)));
+    if !outcome.backup_id.is_empty() {
+        println!(
+            "{}",
+            info(&format!("  Backup created: {}", outcome.backup_id))
+        );
     }
+    print_config_changes(&outcome.changes);
     println!();
     println!(
         "{}",
@@ -366,6 +1109,33 @@ fn import_config(file: &PathBuf) -> Result<(), AppError> {
     Ok(())
 }
 
+fn prompt_passphrase(confirm: bool) -> Result<String, AppError> {
+    let mut prompt = inquire::Password::new(texts::archive_passphrase_prompt())
+        .with_display_mode(inquire::PasswordDisplayMode::Masked);
+    if confirm {
+        prompt = prompt.with_custom_confirmation_message(texts::archive_passphrase_confirm());
+    } else {
+        prompt = prompt.without_confirmation();
+    }
+    prompt
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))
+}
+
+fn normalize_config() -> Result<(), AppError> {
+    match ConfigService::normalize_config_file()? {
+        NormalizeOutcome::Unchanged => println!("{}", info(texts::config_normalize_unchanged())),
+        NormalizeOutcome::Rewritten { backup } => {
+            println!("{}", success(texts::config_normalize_done()));
+            println!(
+                "{}",
+                texts::config_normalize_backup(&backup.display().to_string())
+            );
+        }
+    }
+    Ok(())
+}
+
 fn backup_config(custom_name: Option<&str>) -> Result<(), AppError> {
     let config_path = crate::config::get_app_config_path();
 
@@ -393,6 +1163,54 @@ fn backup_config(custom_name: Option<&str>) -> Result<(), AppError> {
     Ok(())
 }
 
+fn list_backups(since: Option<DateTime<Utc>>) -> Result<(), AppError> {
+    let config_path = crate::config::get_app_config_path();
+    let backups = ConfigService::list_backups(&config_path)?;
+    let backups = filter_backups_since(backups, since);
+
+    if backups.is_empty() {
+        println!("{}", info(texts::no_backups_found()));
+        if since.is_none() {
+            println!("{}", info(texts::create_backup_first_hint()));
+        }
+        return Ok(());
+    }
+
+    println!("{}", highlight(texts::available_backups()));
+    println!("{}", texts::found_backups(backups.len()));
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Name", "Created"]);
+    for backup in &backups {
+        let created = backup
+            .created_at()
+            .map(|dt| {
+                dt.with_timezone(&Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
+        table.add_row(vec![
+            backup.id.clone(),
+            backup.display_name.clone(),
+            created,
+        ]);
+    }
+    println!("{table}");
+    Ok(())
+}
+
+/// 按 `--since` 过滤备份；无法解析时间戳的备份在过滤时被排除
+fn filter_backups_since(backups: Vec<BackupInfo>, since: Option<DateTime<Utc>>) -> Vec<BackupInfo> {
+    match since {
+        Some(since) => backups
+            .into_iter()
+            .filter(|backup| backup.created_at().is_some_and(|ts| ts >= since))
+            .collect(),
+        None => backups,
+    }
+}
+
 fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(), AppError> {
     let config_path = crate::config::get_app_config_path();
 
@@ -412,18 +1230,19 @@ fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(
         }
 
         let state = get_state()?;
-        let pre_restore_backup = ConfigService::restore_from_backup_id(id, &state)?;
+        let outcome = ConfigService::restore_from_backup_id(id, &state)?;
 
         println!(
             "{}",
             success(&format!("✓ Configuration restored from backup '{}'", id))
         );
-        if !pre_restore_backup.is_empty() {
+        if !outcome.backup_id.is_empty() {
             println!(
                 "{}",
-                info(&format!("  Pre-restore backup: {}", pre_restore_backup))
+                info(&format!("  Pre-restore backup: {}", outcome.backup_id))
             );
         }
+        print_config_changes(&outcome.changes);
         println!();
         println!(
             "{}",
@@ -467,18 +1286,19 @@ fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(
         }
 
         let state = get_state()?;
-        let pre_restore_backup = ConfigService::import_config_from_path(file, &state)?;
+        let outcome = ConfigService::import_config_from_path(file, &state)?;
 
         println!(
             "{}",
             success(&format!("✓ Configuration restored from {}", file.display()))
         );
-        if !pre_restore_backup.is_empty() {
+        if !outcome.backup_id.is_empty() {
             println!(
                 "{}",
-                info(&format!("  Pre-restore backup: {}", pre_restore_backup))
+                info(&format!("  Pre-restore backup: {}", outcome.backup_id))
             );
         }
+        print_config_changes(&outcome.changes);
         println!();
         println!(
             "{}",
@@ -536,7 +1356,7 @@ fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(
     }
 
     let state = get_state()?;
-    let pre_restore_backup = ConfigService::restore_from_backup_id(&selected_backup.id, &state)?;
+    let outcome = ConfigService::restore_from_backup_id(&selected_backup.id, &state)?;
 
     println!(
         "{}",
@@ -545,12 +1365,13 @@ fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(
             selected_backup.display_name
         ))
     );
-    if !pre_restore_backup.is_empty() {
+    if !outcome.backup_id.is_empty() {
         println!(
             "{}",
-            info(&format!("  Pre-restore backup: {}", pre_restore_backup))
+            info(&format!("  Pre-restore backup: {}", outcome.backup_id))
         );
     }
+    print_config_changes(&outcome.changes);
     println!();
     println!(
         "{}",
@@ -560,47 +1381,146 @@ fn restore_config(backup_id: Option<&str>, file_path: Option<&Path>) -> Result<(
     Ok(())
 }
 
-fn validate_config() -> Result<(), AppError> {
+/// 打印导入 / 恢复前后的配置变更表
+pub(crate) fn print_config_changes(changes: &ConfigChangeSummary) {
+    println!();
+    println!("{}", highlight(texts::config_changes_title()));
+    if changes.is_empty() {
+        println!("{}", info(texts::config_changes_none()));
+        return;
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["Type", "App", "ID", "Change"]);
+    for change in &changes.changes {
+        table.add_row(vec![
+            change.section.as_str().to_string(),
+            change
+                .app
+                .as_ref()
+                .map(|app| app.as_str().to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            change.id.clone(),
+            texts::config_change_kind(change.kind).to_string(),
+        ]);
+    }
+    println!("{}", table);
+    println!(
+        "{}",
+        info(&texts::config_changes_counts(
+            changes.count(ChangeKind::Added),
+            changes.count(ChangeKind::Removed),
+            changes.count(ChangeKind::Modified),
+        ))
+    );
+}
+
+/// `live` 为 Some 时追加 live 文件检查（内层为 None 表示检查全部应用）
+fn validate_config(json: bool, live: Option<Option<AppType>>) -> Result<(), AppError> {
     let config_dir = crate::config::get_app_config_dir();
     let db_path = config_dir.join("cc-switch.db");
+    let mut checks = Vec::new();
 
-    println!("{}", info("Validating database..."));
-    println!();
-
+    // 数据库尚未创建（首次运行前）不算错误，只提示
     if !db_path.exists() {
-        println!("{}", error("✗ Database file does not exist"));
-        println!("Path: {}", db_path.display());
-        return Ok(());
+        checks.push(
+            Diagnostic::warn("config.database_file")
+                .with_label(texts::config_validate_db_missing())
+                .with_details([db_path.display().to_string()])
+                .with_remediation("cc-switch config import <file>"),
+        );
+    } else {
+        checks.push(
+            Diagnostic::ok("config.database_file")
+                .with_label(texts::config_validate_db_exists())
+                .with_details([db_path.display().to_string()]),
+        );
+        checks.push(match database_summary() {
+            Ok(summary) => Diagnostic::ok("config.database_schema")
+                .with_label(texts::config_validate_db_readable())
+                .with_details(summary),
+            Err(err) => Diagnostic::error("config.database_schema")
+                .with_label(texts::config_validate_db_unreadable())
+                .with_details([err.to_string()])
+                .with_remediation("cc-switch config restore --backup <id>"),
+        });
+    }
+
+    if let Some(app) = live {
+        let state = get_state()?;
+        let apps = match app {
+            Some(app) => vec![app],
+            None => vec![AppType::Claude, AppType::Codex, AppType::Gemini],
+        };
+        for app in apps {
+            checks.push(ProviderService::check_live_files(&state, app)?);
+        }
     }
 
-    println!("{} Database file exists", success("✓"));
-    println!("Path: {}", db_path.display());
+    let report = DiagnosticReport::new("config validate", checks);
+    diagnostics::print_report(&report, json)
+}
 
+fn database_summary() -> Result<Vec<String>, AppError> {
     let db = crate::Database::init()?;
-    println!("{} Database schema is readable", success("✓"));
-
-    // Show some stats
-    let claude_count = db.get_all_providers("claude")?.len();
-    let codex_count = db.get_all_providers("codex")?.len();
-    let gemini_count = db.get_all_providers("gemini")?.len();
-    let mcp_count = db.get_all_mcp_servers()?.len();
-    let skills_count = db.get_all_installed_skills()?.len();
+    Ok(vec![
+        format!(
+            "Claude providers: {}",
+            db.get_all_providers("claude")?.len()
+        ),
+        format!("Codex providers: {}", db.get_all_providers("codex")?.len()),
+        format!(
+            "Gemini providers: {}",
+            db.get_all_providers("gemini")?.len()
+        ),
+        format!("MCP servers: {}", db.get_all_mcp_servers()?.len()),
+        format!("Skills installed: {}", db.get_all_installed_skills()?.len()),
+    ])
+}
 
-    println!();
-    println!("{}", highlight("Database Summary:"));
-    println!("Claude providers:  {}", claude_count);
-    println!("Codex providers:   {}", codex_count);
-    println!("Gemini providers:  {}", gemini_count);
-    println!("MCP servers:       {}", mcp_count);
-    println!("Skills installed:  {}", skills_count);
+/// 未传 `--yes` 时交互确认；非终端环境下直接报错
+fn confirm_reset(prompt: &str, yes: bool) -> Result<bool, AppError> {
+    if yes {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        return Err(AppError::InvalidInput(
+            texts::config_reset_requires_yes().to_string(),
+        ));
+    }
+    inquire::Confirm::new(prompt)
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))
+}
 
-    println!();
-    println!("{}", success("✓ Database validation passed"));
+fn reset_app_config(app_type: AppType, yes: bool) -> Result<(), AppError> {
+    println!(
+        "{}",
+        warning(&texts::config_reset_app_warning(app_type.as_str()))
+    );
+    if !confirm_reset(&texts::config_reset_app_prompt(app_type.as_str()), yes)? {
+        println!("{}", info(texts::cancelled()));
+        return Ok(());
+    }
 
+    let state = get_state()?;
+    let outcome = ConfigService::reset_app(&state, &app_type)?;
+    println!(
+        "{}",
+        success(&texts::config_reset_app_done(app_type.as_str()))
+    );
+    if !outcome.backup_id.is_empty() {
+        println!(
+            "{}",
+            info(&format!("  Backup created: {}", outcome.backup_id))
+        );
+    }
+    print_config_changes(&outcome.changes);
     Ok(())
 }
 
-fn reset_config() -> Result<(), AppError> {
+fn reset_config(yes: bool) -> Result<(), AppError> {
     println!("{}", highlight("Reset Configuration"));
     println!("{}", "=".repeat(50));
     println!();
@@ -612,28 +1532,18 @@ fn reset_config() -> Result<(), AppError> {
     println!("  cc-switch config backup");
     println!();
 
-    let confirm = inquire::Confirm::new("Are you sure you want to reset to default configuration?")
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+    let confirm = confirm_reset(
+        "Are you sure you want to reset to default configuration?",
+        yes,
+    )?;
 
     if !confirm {
         println!("{}", info("Cancelled."));
         return Ok(());
     }
 
-    // Create a backup before reset (SQL)
-    let config_path = crate::config::get_app_config_path();
-    let backup_id = ConfigService::create_backup(&config_path, None)?;
-
-    // Delete the database file
-    let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
-    if db_path.exists() {
-        fs::remove_file(&db_path).map_err(|e| AppError::io(&db_path, e))?;
-    }
-
-    // Recreate empty DB
-    let _ = crate::Database::init()?;
+    // Back up, delete and recreate an empty database
+    let backup_id = ConfigService::reset_all()?;
 
     println!("{}", success("✓ Configuration reset to defaults"));
     if !backup_id.is_empty() {
@@ -0,0 +1,51 @@
+use crate::cli::i18n::texts;
+use crate::cli::ui::{error, highlight, info, success, to_json, warning};
+use crate::error::AppError;
+use crate::services::{DiagnosticReport, DiagnosticStatus};
+
+/// 输出诊断报告（`--json` 时输出结构化 JSON），存在错误级检查时返回错误以便脚本判断退出码
+pub(crate) fn print_report(report: &DiagnosticReport, json: bool) -> Result<(), AppError> {
+    if json {
+        let output = to_json(report)
+            .map_err(|e| AppError::Message(format!("Failed to serialize report: {e}")))?;
+        println!("{output}");
+    } else {
+        println!("{}", highlight(&report.command));
+        for check in &report.checks {
+            let label = check
+                .label
+                .clone()
+                .or_else(|| texts::diagnostic_check_label(&check.check))
+                .unwrap_or_else(|| check.check.clone());
+            let line = match check.status {
+                DiagnosticStatus::Ok => success(&format!("✓ {label}")),
+                DiagnosticStatus::Warn => warning(&format!("⚠ {label}")),
+                DiagnosticStatus::Error => error(&format!("✗ {label}")),
+            };
+            println!("{line}");
+            for detail in &check.details {
+                println!("    {detail}");
+            }
+            if let Some(remediation) = &check.remediation {
+                println!("    {}", info(&format!("→ {remediation}")));
+            }
+        }
+        println!();
+        println!(
+            "{}",
+            texts::diagnostics_summary(
+                report.count(DiagnosticStatus::Ok),
+                report.count(DiagnosticStatus::Warn),
+                report.count(DiagnosticStatus::Error),
+            )
+        );
+    }
+
+    match report.count(DiagnosticStatus::Error) {
+        0 => Ok(()),
+        errors => Err(AppError::Message(texts::diagnostics_failed(
+            &report.command,
+            errors,
+        ))),
+    }
+}
@@ -1,39 +1,70 @@
 use crate::app_config::AppType;
+use crate::cli::commands::diagnostics;
+use crate::cli::i18n::texts;
 use crate::cli::ui::{create_table, error, highlight, info, success};
 use crate::error::AppError;
-use crate::services::env_checker;
+use crate::services::env_checker::{self, EnvConflict};
+use crate::services::{check_app_compat, Diagnostic, DiagnosticReport};
 use clap::Subcommand;
 
 #[derive(Subcommand)]
 pub enum EnvCommand {
     /// Check for environment variable conflicts
-    Check,
+    Check {
+        /// Print a machine-readable diagnostic report
+        #[arg(long)]
+        json: bool,
+    },
     /// List all relevant environment variables
     List,
+    /// Detect installed app CLI versions and warn about known config format incompatibilities
+    ///
+    /// Checks every app unless --app is given.
+    CheckCompat {
+        /// Print a machine-readable diagnostic report
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 pub fn execute(cmd: EnvCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+    // check-compat 未指定 --app 时检查全部应用，不做自动识别
+    let app_type = || crate::cli::resolve_app(app.clone());
 
     match cmd {
-        EnvCommand::Check => check_conflicts(app_type),
-        EnvCommand::List => list_env_vars(app_type),
+        EnvCommand::Check { json } => check_conflicts(app_type(), json),
+        EnvCommand::List => list_env_vars(app_type()),
+        EnvCommand::CheckCompat { json } => check_compat(app.clone(), json),
     }
 }
 
-fn check_conflicts(app_type: AppType) -> Result<(), AppError> {
+fn check_compat(app: Option<AppType>, json: bool) -> Result<(), AppError> {
+    let apps = match app {
+        Some(app) => vec![app],
+        None => vec![AppType::Claude, AppType::Codex, AppType::Gemini],
+    };
+    let checks = apps.iter().map(check_app_compat).collect();
+    let report = DiagnosticReport::new("env check-compat", checks);
+    diagnostics::print_report(&report, json)
+}
+
+fn check_conflicts(app_type: AppType, json: bool) -> Result<(), AppError> {
     let app_str = app_type.as_str();
 
+    // 检测冲突
+    let conflicts = env_checker::check_env_conflicts(app_str)
+        .map_err(|e| AppError::Message(format!("Failed to check environment variables: {}", e)))?;
+
+    if json {
+        return print_conflicts_report(app_str, &conflicts);
+    }
+
     println!(
         "\n{}",
         highlight(&format!("Checking Environment Variables for {}", app_str))
     );
     println!("{}", "═".repeat(60));
 
-    // 检测冲突
-    let conflicts = env_checker::check_env_conflicts(app_str)
-        .map_err(|e| AppError::Message(format!("Failed to check environment variables: {}", e)))?;
-
     if conflicts.is_empty() {
         println!(
             "\n{}",
@@ -92,6 +123,25 @@ fn check_conflicts(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
+fn print_conflicts_report(app_str: &str, conflicts: &[EnvConflict]) -> Result<(), AppError> {
+    // 报告中不包含变量值，避免泄露密钥
+    let check = if conflicts.is_empty() {
+        Diagnostic::ok("env.conflicts")
+    } else {
+        Diagnostic::warn("env.conflicts")
+            .with_details(conflicts.iter().map(|conflict| {
+                format!(
+                    "{} ({}: {})",
+                    conflict.var_name, conflict.source_type, conflict.source_path
+                )
+            }))
+            .with_remediation(texts::env_conflicts_remediation())
+    };
+
+    let report = DiagnosticReport::new(format!("env check ({app_str})"), vec![check]);
+    diagnostics::print_report(&report, true)
+}
+
 fn list_env_vars(app_type: AppType) -> Result<(), AppError> {
     let app_str = app_type.as_str();
 
@@ -0,0 +1,128 @@
+use std::io::IsTerminal;
+
+use clap::Args;
+use inquire::{Confirm, Select};
+
+use crate::app_config::AppType;
+use crate::cli::i18n::{self, texts, Language};
+use crate::cli::ui::{highlight, info, success, warning};
+use crate::error::AppError;
+use crate::services::{ProviderService, SkillService};
+use crate::store::AppState;
+
+#[derive(Args, Debug, Clone)]
+pub struct InitCommand {
+    /// Interface language (en or zh)
+    #[arg(long, value_parser = ["en", "zh"])]
+    pub lang: Option<String>,
+
+    /// Import existing live configs as default providers without asking
+    #[arg(long)]
+    pub import_live: bool,
+
+    /// Do not offer to create a first provider
+    #[arg(long)]
+    pub skip_provider: bool,
+
+    /// Accept defaults without prompting (non-interactive)
+    #[arg(short = 'y', long)]
+    pub yes: bool,
+}
+
+pub fn execute(cmd: InitCommand, app: Option<AppType>) -> Result<(), AppError> {
+    let interactive = !cmd.yes && std::io::stdin().is_terminal();
+    let app_type = crate::cli::resolve_app(app);
+
+    println!("{}", highlight(texts::init_title()));
+    println!("{}", "=".repeat(50));
+
+    // 1. 语言
+    let language = match cmd.lang.as_deref() {
+        Some(code) => Some(Language::from_code(code)),
+        None if interactive => Some(prompt_language()?),
+        None => None,
+    };
+    if let Some(language) = language {
+        i18n::set_language(language)?;
+        println!(
+            "{}",
+            success(&texts::init_language_set(language.display_name()))
+        );
+    }
+
+    // 2. 导入已有 live 配置
+    let state = AppState::try_new()?;
+    let import = cmd.import_live
+        || cmd.yes
+        || (interactive && confirm(texts::init_import_live_prompt(), true)?);
+    if import {
+        import_live_configs(&state)?;
+    }
+
+    // 3. 首个供应商
+    if !cmd.skip_provider && provider_count(&state, &app_type)? == 0 {
+        if interactive && confirm(&texts::init_add_provider_prompt(app_type.as_str()), true)? {
+            crate::cli::commands::provider::add_provider(app_type.clone())?;
+        } else {
+            println!("{}", info(&texts::init_no_provider_hint(app_type.as_str())));
+        }
+    }
+
+    // 4. 默认技能仓库
+    let seeded = SkillService::seed_default_repos()?;
+    println!("{}", success(&texts::init_skill_repos_seeded(seeded)));
+
+    println!();
+    println!("{}", success(texts::init_done()));
+    println!("{}", info(texts::init_next_steps()));
+    Ok(())
+}
+
+fn prompt_language() -> Result<Language, AppError> {
+    Select::new(
+        texts::select_language(),
+        vec![Language::English, Language::Chinese],
+    )
+    .with_starting_cursor(if i18n::is_chinese() { 1 } else { 0 })
+    .prompt()
+    .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))
+}
+
+fn confirm(message: &str, default: bool) -> Result<bool, AppError> {
+    Confirm::new(message)
+        .with_default(default)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))
+}
+
+fn provider_count(state: &AppState, app_type: &AppType) -> Result<usize, AppError> {
+    let config = state.config.read().map_err(AppError::from)?;
+    Ok(config
+        .get_manager(app_type)
+        .map(|manager| manager.get_all_providers().len())
+        .unwrap_or(0))
+}
+
+/// 为尚无供应商的应用导入现有 live 配置；缺少 live 文件的应用跳过
+fn import_live_configs(state: &AppState) -> Result<(), AppError> {
+    for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        if provider_count(state, &app_type)? > 0 {
+            println!(
+                "{}",
+                info(&texts::init_import_skipped_existing(app_type.as_str()))
+            );
+            continue;
+        }
+        match ProviderService::import_default_config(state, app_type.clone()) {
+            Ok(()) => println!("{}", success(&texts::init_import_done(app_type.as_str()))),
+            Err(err) => println!(
+                "{}",
+                warning(&texts::init_import_failed(
+                    app_type.as_str(),
+                    &err.to_string()
+                ))
+            ),
+        }
+    }
+    Ok(())
+}
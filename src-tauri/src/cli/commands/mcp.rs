@@ -1,8 +1,11 @@
 use clap::Subcommand;
 
 use crate::app_config::{AppType, McpApps, McpServer};
+use crate::cli::i18n::texts;
+use crate::cli::interactive::utils::prompt_multiselect_with_defaults;
 use crate::cli::ui::{create_table, error, highlight, info, success};
 use crate::error::AppError;
+use crate::mcp::ExternalMcpSource;
 use crate::services::McpService;
 use crate::store::AppState;
 
@@ -22,6 +25,17 @@ pub enum McpCommand {
         /// Server ID to delete
         id: String,
     },
+    /// Duplicate an MCP server under a new id (disabled for all apps by default)
+    Duplicate {
+        /// Server ID to duplicate
+        id: String,
+        /// Name for the copy (its id is derived from this name)
+        #[arg(long)]
+        name: Option<String>,
+        /// Keep the original server's enabled apps
+        #[arg(long)]
+        keep_apps: bool,
+    },
     /// Enable an MCP server for specific app(s)
     Enable {
         /// Server ID to enable
@@ -37,25 +51,47 @@ pub enum McpCommand {
         /// Command to validate
         command: String,
     },
+    /// Check every server: stdio commands are on PATH, sse/http URLs are valid (use --app to limit to one app)
+    Check,
     /// Sync MCP configuration to live files
     Sync,
     /// Import MCP servers from live configuration
-    Import,
+    Import {
+        /// Import from another MCP client instead of the app's live config (servers stay disabled)
+        #[arg(long, value_enum)]
+        source: Option<ExternalMcpSource>,
+    },
+}
+
+impl McpCommand {
+    /// 是否会修改配置（用于 `--read-only` 拦截）
+    pub fn is_mutation(&self) -> bool {
+        !matches!(self, Self::List | Self::Validate { .. } | Self::Check)
+    }
 }
 
 pub fn execute(cmd: McpCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+    let app_type = crate::cli::resolve_app(app.clone());
 
     match cmd {
         McpCommand::List => list_servers(app_type),
         McpCommand::Add => add_server(app_type),
         McpCommand::Edit { id } => edit_server(app_type, &id),
         McpCommand::Delete { id } => delete_server(&id),
+        McpCommand::Duplicate {
+            id,
+            name,
+            keep_apps,
+        } => duplicate_server(&id, name.as_deref(), keep_apps),
         McpCommand::Enable { id } => enable_server(app_type, &id),
         McpCommand::Disable { id } => disable_server(app_type, &id),
         McpCommand::Validate { command } => validate_command(&command),
+        McpCommand::Check => check_commands(app.as_ref()),
         McpCommand::Sync => sync_servers(),
-        McpCommand::Import => import_servers(app_type),
+        McpCommand::Import { source: None } => import_servers(app_type),
+        McpCommand::Import {
+            source: Some(source),
+        } => import_external_servers(source),
     }
 }
 
@@ -182,6 +218,27 @@ fn delete_server(id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn duplicate_server(id: &str, name: Option<&str>, keep_apps: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let server = McpService::duplicate_server(&state, id, name, keep_apps)?;
+
+    println!(
+        "{}",
+        success(&format!(
+            "✓ Duplicated MCP server '{}' as '{}' ({})",
+            id, server.id, server.name
+        ))
+    );
+    if !keep_apps {
+        println!(
+            "{}",
+            info("Note: The copy is disabled for all apps; use 'cc-switch mcp enable' to activate it.")
+        );
+    }
+
+    Ok(())
+}
+
 fn enable_server(app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
     let app_str = app_type.as_str().to_string();
@@ -260,18 +317,14 @@ fn import_servers(app_type: AppType) -> Result<(), AppError> {
         ))
     );
 
-    let count = match app_type {
-        AppType::Claude => McpService::import_from_claude(&state)?,
-        AppType::Codex => McpService::import_from_codex(&state)?,
-        AppType::Gemini => McpService::import_from_gemini(&state)?,
-    };
+    let summary = McpService::import_from_app(&state, &app_type)?;
 
-    if count > 0 {
+    if summary.total() > 0 {
         println!(
             "{}",
             success(&format!(
-                "✓ Imported {} MCP server(s) from {}",
-                count, app_str
+                "✓ Imported {} new MCP server(s) from {}, merged {} duplicate(s)",
+                summary.added, app_str, summary.merged
             ))
         );
         println!(
@@ -288,11 +341,69 @@ fn import_servers(app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
+fn import_external_servers(source: ExternalMcpSource) -> Result<(), AppError> {
+    let state = get_state()?;
+    let source_str = source.as_str();
+    let path = source.config_path();
+
+    println!(
+        "{}",
+        info(&format!(
+            "Importing MCP servers from {} ({})...",
+            source_str,
+            path.display()
+        ))
+    );
+
+    if !path.exists() {
+        println!(
+            "{}",
+            info(&format!(
+                "No {} MCP config found at {}.",
+                source_str,
+                path.display()
+            ))
+        );
+        return Ok(());
+    }
+
+    let summary = McpService::import_from_external(&state, source)?;
+
+    if summary.added > 0 {
+        println!(
+            "{}",
+            success(&format!(
+                "✓ Imported {} new MCP server(s) from {}",
+                summary.added, source_str
+            ))
+        );
+        println!(
+            "{}",
+            info("Note: Imported servers are not enabled for any app yet; use 'cc-switch mcp enable <id> --app <app>'.")
+        );
+    } else {
+        println!(
+            "{}",
+            info(&format!(
+                "No new MCP servers found in {} config.",
+                source_str
+            ))
+        );
+    }
+
+    Ok(())
+}
+
 fn add_server(_app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
 
     let mut apps = McpApps::default();
     apps.set_enabled_for(&_app_type, true);
+    // 先选择目标应用并写入模板，编辑器中对 `apps` 的修改优先
+    let Some(apps) = prompt_mcp_apps(&apps)? else {
+        println!("{}", info(texts::cancelled()));
+        return Ok(());
+    };
 
     let template = McpServer {
         id: String::new(),
@@ -342,7 +453,16 @@ fn edit_server(_app_type: AppType, id: &str) -> Result<(), AppError> {
         )));
     };
 
-    let initial = serde_json::to_string_pretty(&existing)
+    // 先选择目标应用并写入待编辑的 JSON，编辑器中对 `apps` 的修改优先
+    let Some(apps) = prompt_mcp_apps(&existing.apps)? else {
+        println!("{}", info(texts::cancelled()));
+        return Ok(());
+    };
+    let seeded = McpServer {
+        apps,
+        ..existing.clone()
+    };
+    let initial = serde_json::to_string_pretty(&seeded)
         .map_err(|e| AppError::Message(format!("failed to serialize server: {e}")))?;
 
     println!("{}", info(&format!("Editing MCP server '{}'...", id)));
@@ -350,11 +470,6 @@ fn edit_server(_app_type: AppType, id: &str) -> Result<(), AppError> {
     let edited =
         edit::edit(&initial).map_err(|e| AppError::Message(format!("editor failed: {e}")))?;
 
-    if edited.trim_end() == initial.trim_end() {
-        println!("{}", info("No changes detected."));
-        return Ok(());
-    }
-
     let mut server: McpServer = serde_json::from_str(&edited)
         .map_err(|e| AppError::Message(format!("invalid JSON: {e}")))?;
     server.id = id.to_string();
@@ -364,12 +479,85 @@ fn edit_server(_app_type: AppType, id: &str) -> Result<(), AppError> {
         ));
     }
 
+    if edited.trim_end() == initial.trim_end() && server.apps == existing.apps {
+        println!("{}", info("No changes detected."));
+        return Ok(());
+    }
+
     McpService::upsert_server(&state, server)?;
 
     println!("{}", success("✓ MCP server updated"));
     Ok(())
 }
 
+/// 一次多选 Claude/Codex/Gemini（默认勾选当前已启用的应用），其余字段保持不变；取消时返回 None
+fn prompt_mcp_apps(current: &McpApps) -> Result<Option<McpApps>, AppError> {
+    let options = vec![AppType::Claude, AppType::Codex, AppType::Gemini];
+    let defaults: Vec<usize> = options
+        .iter()
+        .enumerate()
+        .filter(|(_, app)| current.is_enabled_for(app))
+        .map(|(idx, _)| idx)
+        .collect();
+    let Some(selected) = prompt_multiselect_with_defaults(
+        texts::mcp_select_target_apps(),
+        options.clone(),
+        &defaults,
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let mut apps = current.clone();
+    for app in &options {
+        apps.set_enabled_for(app, selected.contains(app));
+    }
+    Ok(Some(apps))
+}
+
+fn check_commands(app: Option<&AppType>) -> Result<(), AppError> {
+    let state = get_state()?;
+    let checks = McpService::check_commands(&state, app)?;
+
+    if checks.is_empty() {
+        println!("{}", info(texts::mcp_check_no_servers()));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Apps", "Transport", "Command / URL", "OK"]);
+    for check in &checks {
+        let apps = check
+            .apps
+            .iter()
+            .map(|app| app.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        table.add_row(vec![
+            check.id.clone(),
+            if apps.is_empty() {
+                "-".to_string()
+            } else {
+                apps
+            },
+            check.transport.as_str().to_string(),
+            check.target.clone(),
+            if check.ok { "✓" } else { "✗" }.to_string(),
+        ]);
+    }
+    println!("{}", table);
+
+    let missing = checks.iter().filter(|check| !check.ok).count();
+    if missing > 0 {
+        return Err(AppError::Message(texts::mcp_check_missing(
+            missing,
+            checks.len(),
+        )));
+    }
+    println!("{}", success(&texts::mcp_check_all_found(checks.len())));
+    Ok(())
+}
+
 fn validate_command(command: &str) -> Result<(), AppError> {
     println!("{}", info(&format!("Validating command '{}'...", command)));
 
@@ -1,8 +1,15 @@
+pub mod audit;
 pub mod config;
+pub mod diagnostics;
 pub mod env;
+pub mod init;
 pub mod mcp;
+pub mod profile;
 pub mod prompts;
 pub mod provider;
 pub mod provider_input;
 pub mod skills;
+pub mod speedtest;
+pub mod stats;
+pub mod time_filter;
 pub mod update;
@@ -0,0 +1,97 @@
+use clap::Subcommand;
+
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, highlight, info, success};
+use crate::error::AppError;
+use crate::services::ProfileService;
+
+#[derive(Subcommand)]
+pub enum ProfileCommand {
+    /// List all profiles
+    List,
+    /// Show the profile in effect for this run
+    Current,
+    /// Create a new profile
+    Create {
+        /// Profile name (letters, digits, '-' or '_')
+        name: String,
+    },
+    /// Persist the active profile (use 'default' for ~/.cc-switch)
+    Use {
+        /// Profile name
+        name: String,
+    },
+    /// Delete a profile and all of its data
+    Delete {
+        /// Profile name
+        name: String,
+    },
+}
+
+impl ProfileCommand {
+    /// 是否会修改配置（用于 `--read-only` 拦截）
+    pub fn is_mutation(&self) -> bool {
+        !matches!(self, Self::List | Self::Current)
+    }
+}
+
+pub fn execute(cmd: ProfileCommand) -> Result<(), AppError> {
+    match cmd {
+        ProfileCommand::List => list_profiles(),
+        ProfileCommand::Current => show_current(),
+        ProfileCommand::Create { name } => create_profile(&name),
+        ProfileCommand::Use { name } => use_profile(&name),
+        ProfileCommand::Delete { name } => delete_profile(&name),
+    }
+}
+
+fn list_profiles() -> Result<(), AppError> {
+    let current = ProfileService::current();
+    let mut table = create_table();
+    table.set_header(vec!["", "Profile", "Directory"]);
+    for name in ProfileService::list()? {
+        let marker = if name == current { "✓" } else { " " };
+        let dir = ProfileService::profile_dir(&name)?;
+        table.add_row(vec![marker.to_string(), name, dir.display().to_string()]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+fn show_current() -> Result<(), AppError> {
+    let current = ProfileService::current();
+    let dir = ProfileService::profile_dir(&current)?;
+    println!("{}", highlight(&current));
+    println!("{}", info(&dir.display().to_string()));
+    Ok(())
+}
+
+fn create_profile(name: &str) -> Result<(), AppError> {
+    let dir = ProfileService::create(name)?;
+    println!(
+        "{}",
+        success(&texts::profile_created(name, &dir.display().to_string()))
+    );
+    Ok(())
+}
+
+fn use_profile(name: &str) -> Result<(), AppError> {
+    ProfileService::set_active(name)?;
+    println!("{}", success(&texts::profile_activated(name)));
+    Ok(())
+}
+
+fn delete_profile(name: &str) -> Result<(), AppError> {
+    let confirm = inquire::Confirm::new(&texts::profile_delete_confirm(name))
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+    if !confirm {
+        println!("{}", info(texts::cancelled()));
+        return Ok(());
+    }
+
+    ProfileService::delete(name)?;
+    println!("{}", success(&texts::profile_deleted(name)));
+    Ok(())
+}
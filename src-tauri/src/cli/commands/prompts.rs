@@ -1,7 +1,8 @@
 use clap::Subcommand;
+use std::collections::HashMap;
 
 use crate::app_config::AppType;
-use crate::cli::ui::{create_table, highlight, info, success};
+use crate::cli::ui::{create_table, highlight, info, render_unified_diff, success, warning};
 use crate::error::AppError;
 use crate::prompt::Prompt;
 use crate::services::PromptService;
@@ -14,12 +15,22 @@ pub enum PromptsCommand {
     /// Show current active prompt
     Current,
     /// Activate a prompt preset
+    #[command(alias = "enable")]
     Activate {
         /// Prompt preset ID
         id: String,
+        /// Deactivate every other prompt of the app (default)
+        #[arg(long, conflicts_with = "append")]
+        exclusive: bool,
+        /// Keep other active prompts; the live file joins them ordered by last update
+        #[arg(long)]
+        append: bool,
+    },
+    /// Deactivate an active prompt (all active prompts when no ID is given)
+    Deactivate {
+        /// Prompt preset ID
+        id: Option<String>,
     },
-    /// Deactivate the current active prompt
-    Deactivate,
     /// Create a new prompt preset
     Create,
     /// Edit a prompt preset
@@ -37,20 +48,33 @@ pub enum PromptsCommand {
         /// Prompt preset ID
         id: String,
     },
+    /// Compare the active prompt with the live prompt file
+    Diff,
+}
+
+impl PromptsCommand {
+    /// 是否会修改配置（用于 `--read-only` 拦截）
+    pub fn is_mutation(&self) -> bool {
+        !matches!(
+            self,
+            Self::List | Self::Current | Self::Show { .. } | Self::Diff
+        )
+    }
 }
 
 pub fn execute(cmd: PromptsCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+    let app_type = crate::cli::resolve_app(app);
 
     match cmd {
         PromptsCommand::List => list_prompts(app_type),
         PromptsCommand::Current => show_current(app_type),
-        PromptsCommand::Activate { id } => activate_prompt(app_type, &id),
-        PromptsCommand::Deactivate => deactivate_prompt(app_type),
+        PromptsCommand::Activate { id, append, .. } => activate_prompt(app_type, &id, !append),
+        PromptsCommand::Deactivate { id } => deactivate_prompt(app_type, id.as_deref()),
         PromptsCommand::Create => create_prompt(app_type),
         PromptsCommand::Edit { id } => edit_prompt(app_type, &id),
         PromptsCommand::Delete { id } => delete_prompt(app_type, &id),
         PromptsCommand::Show { id } => show_prompt(app_type, &id),
+        PromptsCommand::Diff => diff_prompt(app_type),
     }
 }
 
@@ -123,58 +147,124 @@ fn show_current(app_type: AppType) -> Result<(), AppError> {
     let state = get_state()?;
     let prompts = PromptService::get_prompts(&state, app_type.clone())?;
 
-    // 找到当前激活的 prompt
-    let active = prompts
-        .iter()
-        .find(|(_, p)| p.enabled)
-        .map(|(id, p)| (id.clone(), p.clone()));
-
-    match active {
-        Some((id, prompt)) => {
-            let updated = prompt
-                .updated_at
-                .and_then(|ts| {
-                    use chrono::{DateTime, Utc};
-                    DateTime::<Utc>::from_timestamp(ts, 0)
-                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-                })
-                .unwrap_or_else(|| "Unknown".to_string());
-
-            println!("{}", highlight("Current Active Prompt"));
-            println!("{}", "=".repeat(50));
-            println!("ID:          {}", id);
-            println!("Name:        {}", prompt.name);
-            if let Some(desc) = &prompt.description {
-                println!("Description: {}", desc);
-            }
-            println!("Updated:     {}", updated);
-            println!("App:         {}", app_type.as_str());
+    // 已启用的 prompt（按写入 live 文件的顺序）
+    let active = PromptService::active_prompts(&prompts);
+    if active.is_empty() {
+        println!("{}", info("No active prompt preset."));
+        println!("Use 'cc-switch prompts activate <id>' to activate a prompt.");
+        return Ok(());
+    }
+
+    for (idx, (id, prompt)) in active.into_iter().enumerate() {
+        if idx > 0 {
             println!();
-            println!("{}", highlight("Content Preview:"));
-            println!("{}", "-".repeat(50));
-
-            // 显示内容预览（前 10 行）
-            let lines: Vec<&str> = prompt.content.lines().collect();
-            let preview_lines = lines.iter().take(10);
-            for line in preview_lines {
-                println!("{}", line);
-            }
-
-            if lines.len() > 10 {
-                println!("...");
-                println!("{}", info(&format!("({} more lines)", lines.len() - 10)));
-            }
         }
-        None => {
-            println!("{}", info("No active prompt preset."));
-            println!("Use 'cc-switch prompts activate <id>' to activate a prompt.");
+        let updated = prompt
+            .updated_at
+            .and_then(|ts| {
+                use chrono::{DateTime, Utc};
+                DateTime::<Utc>::from_timestamp(ts, 0)
+                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            })
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        println!("{}", highlight("Current Active Prompt"));
+        println!("{}", "=".repeat(50));
+        println!("ID:          {}", id);
+        println!("Name:        {}", prompt.name);
+        if let Some(desc) = &prompt.description {
+            println!("Description: {}", desc);
+        }
+        println!("Updated:     {}", updated);
+        println!("App:         {}", app_type.as_str());
+        println!();
+        println!("{}", highlight("Content Preview:"));
+        println!("{}", "-".repeat(50));
+
+        // 显示内容预览（前 10 行）
+        let lines: Vec<&str> = prompt.content.lines().collect();
+        let preview_lines = lines.iter().take(10);
+        for line in preview_lines {
+            println!("{}", line);
+        }
+
+        if lines.len() > 10 {
+            println!("...");
+            println!("{}", info(&format!("({} more lines)", lines.len() - 10)));
+        }
+    }
+
+    Ok(())
+}
+
+fn diff_prompt(app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let prompts = PromptService::get_prompts(&state, app_type.clone())?;
+    let active = active_prompt_ids(&prompts);
+
+    let live_path = crate::prompt_files::prompt_file_path(&app_type)?;
+    let live = PromptService::get_current_file_content(app_type.clone())?;
+    if live.is_none() {
+        println!(
+            "{}",
+            warning(&format!(
+                "Live prompt file does not exist: {}",
+                live_path.display()
+            ))
+        );
+    }
+
+    // 未激活任何提示词时，live 文件应为空（停用会清空文件）
+    let stored = PromptService::live_content(&prompts);
+    let stored_label = if active.is_empty() {
+        "stored: (no active prompt)".to_string()
+    } else {
+        format!("stored: {}", active.join(" + "))
+    };
+    let live_label = format!("live: {}", live_path.display());
+
+    match render_unified_diff(
+        &stored_label,
+        &live_label,
+        &stored,
+        live.as_deref().unwrap_or(""),
+    ) {
+        None => match active.as_slice() {
+            [] => println!(
+                "{}",
+                info("No active prompt preset and the live prompt file is empty.")
+            ),
+            [id] => println!(
+                "{}",
+                success(&format!(
+                    "✓ Live prompt file matches the active prompt '{id}'"
+                ))
+            ),
+            ids => println!(
+                "{}",
+                success(&format!(
+                    "✓ Live prompt file matches the active prompts '{}'",
+                    ids.join("', '")
+                ))
+            ),
+        },
+        Some(diff) => {
+            println!("{diff}");
+            println!();
+            println!(
+                "{}",
+                info(&format!(
+                    "Application: {} (use 'cc-switch prompts activate <id>' to rewrite the live file)",
+                    app_type.as_str()
+                ))
+            );
         }
     }
 
     Ok(())
 }
 
-fn activate_prompt(app_type: AppType, id: &str) -> Result<(), AppError> {
+fn activate_prompt(app_type: AppType, id: &str, exclusive: bool) -> Result<(), AppError> {
     let state = get_state()?;
     let app_str = app_type.as_str().to_string();
 
@@ -188,12 +278,23 @@ fn activate_prompt(app_type: AppType, id: &str) -> Result<(), AppError> {
     }
 
     // 执行激活
-    PromptService::enable_prompt(&state, app_type, id)?;
+    PromptService::enable_prompt_with(&state, app_type.clone(), id, exclusive)?;
 
     println!(
         "{}",
         success(&format!("✓ Activated prompt preset '{}'", id))
     );
+    if !exclusive {
+        let prompts = PromptService::get_prompts(&state, app_type)?;
+        let active = active_prompt_ids(&prompts);
+        println!(
+            "{}",
+            info(&format!(
+                "  Active prompts (in order): {}",
+                active.join(", ")
+            ))
+        );
+    }
     println!("{}", info(&format!("  Application: {}", app_str)));
     println!();
     println!(
@@ -334,41 +435,58 @@ fn create_prompt(_app_type: AppType) -> Result<(), AppError> {
     Ok(())
 }
 
-fn deactivate_prompt(app_type: AppType) -> Result<(), AppError> {
+fn deactivate_prompt(app_type: AppType, id: Option<&str>) -> Result<(), AppError> {
     let state = get_state()?;
     let prompts = PromptService::get_prompts(&state, app_type.clone())?;
 
-    // Find currently enabled prompt
-    let active = prompts
-        .iter()
-        .find(|(_, p)| p.enabled)
-        .map(|(id, _)| id.clone());
+    // 指定 ID 时只停用该项，否则停用全部已启用项
+    let targets = match id {
+        Some(id) => vec![id.to_string()],
+        None => active_prompt_ids(&prompts),
+    };
 
-    match active {
-        Some(id) => {
-            // Deactivate the current prompt
-            PromptService::disable_prompt(&state, app_type.clone(), &id)?;
+    if targets.is_empty() {
+        println!("{}", info("No active prompt to deactivate."));
+        println!("Use 'cc-switch prompts activate <id>' to activate a prompt preset.");
+        return Ok(());
+    }
 
-            println!(
-                "{}",
-                success(&format!("✓ Deactivated prompt preset '{}'", id))
-            );
-            println!("{}", info(&format!("  Application: {}", app_type.as_str())));
-            println!();
-            println!(
-                "{}",
-                info("Note: The live configuration file has been cleared.")
-            );
-        }
-        None => {
-            println!("{}", info("No active prompt to deactivate."));
-            println!("Use 'cc-switch prompts activate <id>' to activate a prompt preset.");
-        }
+    for id in &targets {
+        PromptService::disable_prompt(&state, app_type.clone(), id)?;
+        println!(
+            "{}",
+            success(&format!("✓ Deactivated prompt preset '{}'", id))
+        );
+    }
+    println!("{}", info(&format!("  Application: {}", app_type.as_str())));
+    println!();
+
+    let remaining = active_prompt_ids(&PromptService::get_prompts(&state, app_type)?);
+    if remaining.is_empty() {
+        println!(
+            "{}",
+            info("Note: The live configuration file has been cleared.")
+        );
+    } else {
+        println!(
+            "{}",
+            info(&format!(
+                "Note: The live file now contains: {}",
+                remaining.join(", ")
+            ))
+        );
     }
 
     Ok(())
 }
 
+fn active_prompt_ids(prompts: &HashMap<String, Prompt>) -> Vec<String> {
+    PromptService::active_prompts(prompts)
+        .into_iter()
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
 fn edit_prompt(_app_type: AppType, id: &str) -> Result<(), AppError> {
     let state = get_state()?;
     let prompts = PromptService::get_prompts(&state, _app_type.clone())?;
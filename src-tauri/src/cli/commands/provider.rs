@@ -1,18 +1,30 @@
 use clap::Subcommand;
+use std::io::IsTerminal;
 
 use crate::app_config::AppType;
+use crate::cli::commands::diagnostics;
 use crate::cli::commands::provider_input::{
-    current_timestamp, display_provider_summary, generate_provider_id, prompt_basic_fields,
-    prompt_optional_fields, prompt_settings_config, prompt_settings_config_for_add, OptionalFields,
+    current_timestamp, display_provider_summary, prompt_basic_fields, prompt_optional_fields,
+    prompt_settings_config, prompt_settings_config_for_add, required, OptionalFields,
     ProviderAddMode,
 };
+use crate::cli::commands::speedtest::{self, SpeedtestArgs};
 use crate::cli::i18n::texts;
-use crate::cli::ui::{create_table, error, highlight, info, success, warning};
+use crate::cli::interactive::utils::{prompt_confirm, prompt_select, prompt_text_input};
+use crate::cli::tui::form::{GeminiAuthType, ProviderAddFormState};
+use crate::cli::ui::{
+    create_table, error, highlight, info, skills_sync_note, success, to_json, warning,
+};
 use crate::error::AppError;
-use crate::provider::{Provider, ProviderMeta};
-use crate::services::{ProviderService, SpeedtestService};
+use crate::provider::{generate_provider_id, Provider, ProviderMeta};
+use crate::services::{
+    expiry_status, expiry_warning_days, parse_expiry_date, parse_meta_assignment,
+    provider_expiry_date, provider_headers, set_provider_headers, Diagnostic, DiagnosticReport,
+    HealthStatus, LiveField, LiveFileMethod, LiveVerification, ProviderHealth, ProviderService,
+    SpeedtestOptions, SpeedtestService,
+};
 use crate::store::AppState;
-use inquire::{Confirm, Select, Text};
+use inquire::Confirm;
 
 fn supports_official_provider(app_type: &AppType) -> bool {
     matches!(app_type, AppType::Codex)
@@ -23,11 +35,27 @@ pub enum ProviderCommand {
     /// List all providers
     List,
     /// Show current provider
-    Current,
+    Current {
+        /// Print only the raw API key of the current provider (for scripts)
+        #[arg(long)]
+        print_key: bool,
+        /// Allow --print-key to write the secret to an interactive terminal
+        #[arg(long, requires = "print_key")]
+        unsafe_print_secret: bool,
+        /// Print a single stable tab-separated line: app, id, name (for shell prompts)
+        #[arg(long, conflicts_with = "print_key")]
+        porcelain: bool,
+    },
     /// Switch to a provider
     Switch {
-        /// Provider ID to switch to
+        /// Provider ID to switch to (falls back to a unique partial match on id or name)
         id: String,
+        /// Re-read the live files afterwards and confirm base URL / API key match
+        #[arg(long)]
+        verify: bool,
+        /// Don't back up live files that were modified since cc-switch last wrote them
+        #[arg(long)]
+        no_backup: bool,
     },
     /// Add a new provider (interactive)
     Add,
@@ -40,6 +68,14 @@ pub enum ProviderCommand {
     Delete {
         /// Provider ID to delete
         id: String,
+        /// Allow deleting the current provider (clears the current selection)
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print a provider as shareable JSON (internal fields removed, secrets blanked)
+    Share {
+        /// Provider ID to share
+        id: String,
     },
     /// Duplicate a provider
     Duplicate {
@@ -50,24 +86,241 @@ pub enum ProviderCommand {
     Speedtest {
         /// Provider ID to test
         id: String,
+        #[command(flatten)]
+        request: SpeedtestArgs,
+    },
+    /// Speedtest the failover queue and switch to the fastest healthy provider
+    Failover {
+        /// Add a provider to the failover queue instead of running failover
+        #[arg(long, value_name = "ID", conflicts_with = "remove")]
+        add: Option<String>,
+        /// Remove a provider from the failover queue instead of running failover
+        #[arg(long, value_name = "ID")]
+        remove: Option<String>,
+        /// Only show the ranking; do not switch
+        #[arg(long)]
+        dry_run: bool,
+        #[command(flatten)]
+        request: SpeedtestArgs,
+    },
+    /// Speedtest all providers and archive (or delete) the unreachable ones, never the current one
+    Prune {
+        /// Select providers whose endpoint fails or times out
+        #[arg(long, required = true)]
+        unreachable: bool,
+        /// Delete instead of archiving to ~/.cc-switch/backups/providers
+        #[arg(long)]
+        delete: bool,
+        /// Skip the confirmation prompt (required when not running in a terminal)
+        #[arg(short = 'y', long)]
+        yes: bool,
+        #[command(flatten)]
+        request: SpeedtestArgs,
+    },
+    /// Check every provider: endpoint reachability, API key validity and latency
+    Health {
+        #[command(flatten)]
+        request: SpeedtestArgs,
+    },
+    /// Replace the API key of every provider pointing at a base URL
+    RotateKey {
+        /// Base URL to match (trailing slash and case are ignored)
+        #[arg(long)]
+        base_url: String,
+        /// New API key to write
+        #[arg(long)]
+        new_key: String,
+    },
+    /// Validate every provider's config and report problems per ID
+    Validate {
+        /// Print a machine-readable diagnostic report
+        #[arg(long)]
+        json: bool,
+    },
+    /// Search providers of all apps by ID, name or API URL
+    Search {
+        /// Text to look for (case-insensitive)
+        query: String,
+    },
+    /// Copy a provider's endpoint and key into another app
+    CloneToApp {
+        /// Provider ID to clone
+        id: String,
+        /// Source application
+        #[arg(long, value_enum)]
+        from: AppType,
+        /// Target application
+        #[arg(long, value_enum)]
+        to: AppType,
+    },
+    /// Get or set how live config files are written (copy|symlink)
+    LiveMethod {
+        /// Optional method to set (omit to show current)
+        #[arg(value_enum)]
+        method: Option<LiveFileMethod>,
+    },
+    /// Set structured metadata fields on a provider (key=value, empty value removes)
+    SetMeta {
+        /// Provider ID
+        id: String,
+        /// Field assignments, e.g. owner=team-a expires_at=2026-12-31 note=
+        #[arg(required = true)]
+        assignments: Vec<String>,
+    },
+    /// Record when a provider's API key expires (YYYY-MM-DD) to get warnings ahead of time
+    SetExpiry {
+        /// Provider ID
+        id: String,
+        /// Expiry date (YYYY-MM-DD)
+        #[arg(required_unless_present = "clear")]
+        date: Option<String>,
+        /// Remove the recorded expiry date
+        #[arg(long, conflicts_with = "date")]
+        clear: bool,
+    },
+    /// Manage a provider's API key pool (one key is written per switch, round-robin)
+    #[command(subcommand)]
+    Keys(ProviderKeysCommand),
+}
+
+#[derive(Subcommand)]
+pub enum ProviderKeysCommand {
+    /// List the keys in the pool and which one the next switch uses
+    List {
+        /// Provider ID
+        id: String,
     },
+    /// Add keys to the pool (the provider's current key is kept as the first entry)
+    Add {
+        /// Provider ID
+        id: String,
+        /// API keys to add
+        #[arg(required = true)]
+        keys: Vec<String>,
+    },
+    /// Remove a key from the pool by its number in `keys list`
+    Remove {
+        /// Provider ID
+        id: String,
+        /// Key number (starting at 1)
+        number: usize,
+    },
+}
+
+impl ProviderCommand {
+    /// 是否会修改配置（用于 `--read-only` 拦截）
+    pub fn is_mutation(&self) -> bool {
+        match self {
+            Self::List
+            | Self::Current { .. }
+            | Self::Speedtest { .. }
+            | Self::Health { .. }
+            | Self::Share { .. }
+            | Self::Validate { .. }
+            | Self::Search { .. }
+            | Self::Keys(ProviderKeysCommand::List { .. }) => false,
+            Self::Failover {
+                add: None,
+                remove: None,
+                dry_run,
+                ..
+            } => !dry_run,
+            Self::LiveMethod { method } => method.is_some(),
+            _ => true,
+        }
+    }
 }
 
 pub fn execute(cmd: ProviderCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.unwrap_or(AppType::Claude);
+    let app_type = crate::cli::resolve_app(app);
 
     match cmd {
         ProviderCommand::List => list_providers(app_type),
-        ProviderCommand::Current => show_current(app_type),
-        ProviderCommand::Switch { id } => switch_provider(app_type, &id),
+        ProviderCommand::Current {
+            print_key: true,
+            unsafe_print_secret,
+            ..
+        } => print_current_key(app_type, unsafe_print_secret),
+        ProviderCommand::Current { porcelain, .. } => show_current(app_type, porcelain),
+        ProviderCommand::Switch {
+            id,
+            verify,
+            no_backup,
+        } => switch_provider(app_type, &id, verify, !no_backup),
         ProviderCommand::Add => add_provider(app_type),
         ProviderCommand::Edit { id } => edit_provider(app_type, &id),
-        ProviderCommand::Delete { id } => delete_provider(app_type, &id),
+        ProviderCommand::Delete { id, force } => delete_provider(app_type, &id, force),
+        ProviderCommand::Share { id } => share_provider(app_type, &id),
         ProviderCommand::Duplicate { id } => duplicate_provider(app_type, &id),
-        ProviderCommand::Speedtest { id } => speedtest_provider(app_type, &id),
+        ProviderCommand::Speedtest { id, request } => {
+            speedtest_provider(app_type, &id, request.options())
+        }
+        ProviderCommand::Failover { add: Some(id), .. } => set_failover_queued(app_type, &id, true),
+        ProviderCommand::Failover {
+            remove: Some(id), ..
+        } => set_failover_queued(app_type, &id, false),
+        ProviderCommand::Failover {
+            dry_run, request, ..
+        } => run_failover(app_type, dry_run, request.options()),
+        ProviderCommand::Prune {
+            delete,
+            yes,
+            request,
+            ..
+        } => prune_unreachable(app_type, delete, yes, request.options()),
+        ProviderCommand::Health { request } => provider_health(app_type, request.options()),
+        ProviderCommand::RotateKey { base_url, new_key } => {
+            rotate_provider_key(app_type, &base_url, &new_key)
+        }
+        ProviderCommand::Validate { json } => validate_providers(app_type, json),
+        ProviderCommand::Search { query } => search_providers(&query),
+        ProviderCommand::CloneToApp { id, from, to } => clone_provider_to_app(&id, from, to),
+        ProviderCommand::LiveMethod { method } => live_method(method),
+        ProviderCommand::SetMeta { id, assignments } => {
+            set_provider_meta(app_type, &id, &assignments)
+        }
+        ProviderCommand::SetExpiry { id, date, .. } => {
+            set_provider_expiry(app_type, &id, date.as_deref())
+        }
+        ProviderCommand::Keys(cmd) => execute_keys(cmd, app_type),
     }
 }
 
+fn execute_keys(cmd: ProviderKeysCommand, app_type: AppType) -> Result<(), AppError> {
+    let state = get_state()?;
+    let (id, pool) = match cmd {
+        ProviderKeysCommand::List { id } => {
+            let pool = ProviderService::api_key_pool(&state, app_type, &id)?;
+            (id, pool)
+        }
+        ProviderKeysCommand::Add { id, keys } => {
+            let pool = ProviderService::add_api_keys(&state, app_type, &id, keys)?;
+            println!(
+                "{}",
+                success(&texts::provider_keys_added(&id, pool.keys.len()))
+            );
+            (id, pool)
+        }
+        ProviderKeysCommand::Remove { id, number } => {
+            let pool = ProviderService::remove_api_key(&state, app_type, &id, number)?;
+            println!("{}", success(&texts::provider_keys_removed(&id, number)));
+            (id, pool)
+        }
+    };
+
+    if pool.keys.is_empty() {
+        println!("{}", info(&texts::provider_keys_empty(&id)));
+        return Ok(());
+    }
+    println!("{}", highlight(&texts::provider_keys_title(&id)));
+    for (index, key) in pool.keys.iter().enumerate() {
+        let marker = if index == pool.next_index { "→" } else { " " };
+        println!("{marker} {}. {}", index + 1, mask_api_key(key));
+    }
+    println!("{}", info(texts::provider_keys_next_hint()));
+    Ok(())
+}
+
 fn get_state() -> Result<AppState, AppError> {
     AppState::try_new()
 }
@@ -117,18 +370,58 @@ fn list_providers(app_type: AppType) -> Result<(), AppError> {
     println!("\n{} Application: {}", info("ℹ"), app_str);
     println!("{} Current: {}", info("→"), highlight(&current_id));
 
+    let warnings = ProviderService::expiry_warnings(&state, app_type)?;
+    if !warnings.is_empty() {
+        println!();
+        for entry in &warnings {
+            println!("{}", warning(&texts::provider_expiry_warning(entry)));
+        }
+    }
+
     Ok(())
 }
 
-fn show_current(app_type: AppType) -> Result<(), AppError> {
+/// `provider current --porcelain` 的稳定输出格式：`<app>\t<id>\t<name>`，
+/// 字段内的制表符与换行替换为空格，保证始终为单行三列
+fn porcelain_line(app_type: &AppType, id: &str, name: &str) -> String {
+    let field = |value: &str| value.replace(['\t', '\r', '\n'], " ");
+    format!("{}\t{}\t{}", app_type.as_str(), field(id), field(name))
+}
+
+fn print_current_key(app_type: AppType, unsafe_print_secret: bool) -> Result<(), AppError> {
+    use std::io::IsTerminal;
+
+    // 明文密钥默认只允许输出到管道/文件，避免直接显示在终端上
+    if std::io::stdout().is_terminal() && !unsafe_print_secret {
+        return Err(AppError::Message(
+            texts::provider_print_key_refused_tty().to_string(),
+        ));
+    }
+
+    let state = get_state()?;
+    let key = ProviderService::current_api_key(&state, app_type)?;
+    println!("{key}");
+    Ok(())
+}
+
+fn show_current(app_type: AppType, porcelain: bool) -> Result<(), AppError> {
     let state = get_state()?;
     let current_id = ProviderService::current(&state, app_type.clone())?;
+    // 未设置当前供应商时 porcelain 不输出任何内容，便于 PS1 直接引用
+    if porcelain && current_id.is_empty() {
+        return Ok(());
+    }
     let providers = ProviderService::list(&state, app_type.clone())?;
 
     let provider = providers
         .get(&current_id)
         .ok_or_else(|| AppError::Message(format!("Current provider '{}' not found", current_id)))?;
 
+    if porcelain {
+        println!("{}", porcelain_line(&app_type, &current_id, &provider.name));
+        return Ok(());
+    }
+
     println!("{}", highlight("Current Provider"));
     println!("{}", "═".repeat(60));
 
@@ -145,6 +438,22 @@ fn show_current(app_type: AppType) -> Result<(), AppError> {
         texts::app_label_with_colon(),
         app_type.as_str()
     );
+    if let Some(expires_at) = provider_expiry_date(provider) {
+        let today = chrono::Local::now().date_naive();
+        let line = format!(
+            "  {} {}",
+            texts::expires_at_label(),
+            expires_at.format("%Y-%m-%d")
+        );
+        match expiry_status(provider, today, expiry_warning_days()) {
+            Some(status) => println!(
+                "{} ({})",
+                warning(&line),
+                texts::provider_expiry_status(status)
+            ),
+            None => println!("{line}"),
+        }
+    }
 
     // 仅 Claude 应用显示详细配置
     if matches!(app_type, AppType::Claude) {
@@ -188,24 +497,55 @@ fn show_current(app_type: AppType) -> Result<(), AppError> {
         println!("  API URL:  {}", api_url);
     }
 
+    if let Some(meta) = provider
+        .meta
+        .as_ref()
+        .filter(|meta| !meta.api_keys.is_empty())
+    {
+        println!("\n{}", highlight(&texts::provider_keys_title(&current_id)));
+        let next = meta.api_key_index.unwrap_or(0) % meta.api_keys.len();
+        for (index, key) in meta.api_keys.iter().enumerate() {
+            let marker = if index == next { "→" } else { " " };
+            println!("  {marker} {}. {}", index + 1, mask_api_key(key));
+        }
+    }
+
+    if let Some(fields) = provider
+        .meta
+        .as_ref()
+        .map(|meta| &meta.fields)
+        .filter(|fields| !fields.is_empty())
+    {
+        println!("\n{}", highlight(texts::provider_meta_section_header()));
+        print_meta_fields(fields);
+    }
+
     println!("\n{}", "─".repeat(60));
 
     Ok(())
 }
 
-fn switch_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
+fn switch_provider(
+    app_type: AppType,
+    id: &str,
+    verify: bool,
+    backup_live: bool,
+) -> Result<(), AppError> {
     let state = get_state()?;
     let app_str = app_type.as_str().to_string();
     let skip_live_sync = !crate::sync_policy::should_sync_live(&app_type);
 
-    // 检查 provider 是否存在
+    // 精确 ID 优先，否则按 ID / 名称模糊匹配唯一的供应商
     let providers = ProviderService::list(&state, app_type.clone())?;
-    if !providers.contains_key(id) {
-        return Err(AppError::Message(format!("Provider '{}' not found", id)));
+    let resolved = resolve_provider_id(&providers, id)?;
+    if resolved != id {
+        println!("{}", info(&texts::provider_fuzzy_matched(id, &resolved)));
     }
+    let id = resolved.as_str();
 
     // 执行切换
-    ProviderService::switch(&state, app_type, id)?;
+    let outcome =
+        ProviderService::switch_with_live_backup(&state, app_type.clone(), id, backup_live)?;
 
     println!("{}", success(&format!("✓ Switched to provider '{}'", id)));
     println!("{}", info(&format!("  Application: {}", app_str)));
@@ -214,6 +554,12 @@ fn switch_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
             "{}",
             warning(&texts::live_sync_skipped_uninitialized_warning(&app_str))
         );
+    } else if verify {
+        report_live_verification(&state, app_type.clone(), id);
+    }
+    if let Some(result) = &outcome.skills_sync {
+        let (note, failed) = skills_sync_note(result);
+        println!("{}", if failed { warning(&note) } else { info(&note) });
     }
     println!(
         "\n{}",
@@ -223,26 +569,58 @@ fn switch_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
-fn delete_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
+/// 切换已完成，核对失败只提示不报错
+fn report_live_verification(state: &AppState, app_type: AppType, id: &str) {
+    match ProviderService::verify_live(state, app_type, id) {
+        Ok(LiveVerification::Verified) => println!("{}", success(texts::live_verify_ok())),
+        Ok(LiveVerification::Skipped) => {}
+        Ok(LiveVerification::Mismatch(mismatches)) => {
+            println!("{}", warning(texts::live_verify_mismatch()));
+            for mismatch in mismatches {
+                let shown = |value: &str| match mismatch.field {
+                    LiveField::ApiKey => mask_api_key(value),
+                    LiveField::BaseUrl => value.to_string(),
+                };
+                println!(
+                    "  {}: {} {} / {} {}",
+                    mismatch.field.label(),
+                    texts::live_verify_expected(),
+                    shown(&mismatch.expected),
+                    texts::live_verify_actual(),
+                    mismatch
+                        .actual
+                        .as_deref()
+                        .map(shown)
+                        .unwrap_or_else(|| "-".to_string())
+                );
+            }
+        }
+        Err(err) => println!("{}", warning(&texts::live_verify_failed(&err.to_string()))),
+    }
+}
+
+fn delete_provider(app_type: AppType, id: &str, force: bool) -> Result<(), AppError> {
     let state = get_state()?;
 
     // 检查是否是当前 provider
     let current_id = ProviderService::current(&state, app_type.clone())?;
-    if id == current_id {
+    let is_current = id == current_id;
+    if is_current && !force {
         return Err(AppError::Message(
-            "Cannot delete the current active provider. Please switch to another provider first."
-                .to_string(),
+            texts::cannot_delete_current_provider_hint(id),
         ));
     }
 
     // 确认删除
-    let confirm = inquire::Confirm::new(&format!(
-        "Are you sure you want to delete provider '{}'?",
-        id
-    ))
-    .with_default(false)
-    .prompt()
-    .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+    let prompt = if is_current {
+        texts::confirm_force_delete_current_provider(id)
+    } else {
+        texts::confirm_delete(id)
+    };
+    let confirm = inquire::Confirm::new(&prompt)
+        .with_default(false)
+        .prompt()
+        .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
 
     if !confirm {
         println!("{}", info("Cancelled."));
@@ -250,14 +628,273 @@ fn delete_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     }
 
     // 执行删除
-    ProviderService::delete(&state, app_type, id)?;
+    if is_current {
+        ProviderService::force_delete(&state, app_type, id)?;
+    } else {
+        ProviderService::delete(&state, app_type, id)?;
+    }
 
     println!("{}", success(&format!("✓ Deleted provider '{}'", id)));
 
     Ok(())
 }
 
-fn add_provider(app_type: AppType) -> Result<(), AppError> {
+fn rotate_provider_key(app_type: AppType, base_url: &str, new_key: &str) -> Result<(), AppError> {
+    let state = get_state()?;
+    let updated = ProviderService::rotate_key(&state, app_type.clone(), base_url, new_key)?;
+
+    if updated.is_empty() {
+        println!(
+            "{}",
+            warning(&texts::provider_rotate_key_no_match(
+                app_type.as_str(),
+                base_url
+            ))
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        success(&texts::provider_rotate_key_done(
+            updated.len(),
+            &mask_api_key(new_key.trim())
+        ))
+    );
+    for id in &updated {
+        println!("  - {id}");
+    }
+    Ok(())
+}
+
+fn validate_providers(app_type: AppType, json: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    let mut results = ProviderService::validate_all(&state, app_type.clone())?;
+
+    if json {
+        results.sort_by(|a, b| a.id.cmp(&b.id));
+        let checks = results
+            .into_iter()
+            .map(|result| {
+                let check = format!("provider.{}", result.id);
+                if result.is_ok() {
+                    Diagnostic::ok(check)
+                } else {
+                    Diagnostic::error(check)
+                        .with_details(result.problems)
+                        .with_remediation(format!("cc-switch provider edit {}", result.id))
+                }
+            })
+            .collect();
+        let report =
+            DiagnosticReport::new(format!("provider validate ({})", app_type.as_str()), checks);
+        return diagnostics::print_report(&report, true);
+    }
+
+    if results.is_empty() {
+        println!("{}", info("No providers found."));
+        return Ok(());
+    }
+
+    let failed: Vec<_> = results.iter().filter(|result| !result.is_ok()).collect();
+    if failed.is_empty() {
+        println!(
+            "{}",
+            success(&texts::provider_validate_all_ok(
+                app_type.as_str(),
+                results.len()
+            ))
+        );
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec![
+        "ID",
+        "Name",
+        texts::provider_validate_problems_header(),
+    ]);
+    for result in &failed {
+        table.add_row(vec![
+            result.id.clone(),
+            result.name.clone(),
+            result.problems.join("\n"),
+        ]);
+    }
+    println!("{}", table);
+
+    Err(AppError::Message(texts::provider_validate_failed(
+        app_type.as_str(),
+        failed.len(),
+        results.len(),
+    )))
+}
+
+fn search_providers(query: &str) -> Result<(), AppError> {
+    let state = get_state()?;
+    let mut table = create_table();
+    table.set_header(vec!["", "App", "ID", "Name", "API URL"]);
+
+    let mut matches = 0;
+    for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        let providers = ProviderService::list(&state, app_type.clone())?;
+        let current_id = ProviderService::current(&state, app_type.clone())?;
+        for (id, provider) in providers {
+            let api_url = extract_api_url(&provider.settings_config, &app_type);
+            let fields = [
+                id.as_str(),
+                provider.name.as_str(),
+                api_url.as_deref().unwrap_or(""),
+            ];
+            if !matches_search_query(query, &fields) {
+                continue;
+            }
+            matches += 1;
+            let current_marker = if id == current_id { "✓" } else { " " };
+            table.add_row(vec![
+                current_marker.to_string(),
+                app_type.as_str().to_string(),
+                id.clone(),
+                provider.name.clone(),
+                api_url.unwrap_or_else(|| "N/A".to_string()),
+            ]);
+        }
+    }
+
+    if matches == 0 {
+        println!("{}", info(&texts::provider_search_no_match(query)));
+        return Ok(());
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+fn live_method(method: Option<LiveFileMethod>) -> Result<(), AppError> {
+    match method {
+        Some(method) => {
+            let state = get_state()?;
+            ProviderService::set_live_file_method(&state, method)?;
+            println!("{}", success(&texts::provider_live_method_set(method)));
+        }
+        None => {
+            println!("{}", highlight(texts::provider_live_method_title()));
+            println!(
+                "{}",
+                texts::provider_live_method_name(crate::settings::get_live_file_method())
+            );
+        }
+    }
+    Ok(())
+}
+
+fn set_provider_meta(app_type: AppType, id: &str, assignments: &[String]) -> Result<(), AppError> {
+    let updates = assignments
+        .iter()
+        .map(|assignment| parse_meta_assignment(assignment))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let state = get_state()?;
+    let fields = ProviderService::set_meta_fields(&state, app_type, id, updates)?;
+    println!("{}", success(&texts::provider_meta_updated(id)));
+    print_meta_fields(&fields);
+    Ok(())
+}
+
+/// 在保留其余元数据的前提下写入到期日
+fn with_expiry(meta: Option<ProviderMeta>, expires_at: Option<String>) -> Option<ProviderMeta> {
+    match (meta, expires_at) {
+        (Some(mut meta), expires_at) => {
+            meta.expires_at = expires_at;
+            Some(meta)
+        }
+        (None, Some(expires_at)) => Some(ProviderMeta {
+            expires_at: Some(expires_at),
+            ..Default::default()
+        }),
+        (None, None) => None,
+    }
+}
+
+fn set_provider_expiry(app_type: AppType, id: &str, date: Option<&str>) -> Result<(), AppError> {
+    let date = date.map(parse_expiry_date).transpose()?;
+    let state = get_state()?;
+    ProviderService::set_expiry(&state, app_type, id, date)?;
+    match date {
+        Some(date) => println!(
+            "{}",
+            success(&texts::provider_expiry_set(id, &date.to_string()))
+        ),
+        None => println!("{}", success(&texts::provider_expiry_cleared(id))),
+    }
+    Ok(())
+}
+
+fn print_meta_fields(fields: &std::collections::BTreeMap<String, String>) {
+    if fields.is_empty() {
+        println!("  {}", texts::provider_meta_empty());
+        return;
+    }
+    for (key, value) in fields {
+        println!("  {key}: {value}");
+    }
+}
+
+/// 解析 `provider switch` 的 ID：精确匹配优先；否则对 ID 和名称做大小写不敏感的子串匹配，
+/// 无结果时再按字符顺序做子序列匹配。唯一候选时返回其 ID，多个候选时列出并报错
+fn resolve_provider_id(
+    providers: &indexmap::IndexMap<String, Provider>,
+    query: &str,
+) -> Result<String, AppError> {
+    if providers.contains_key(query) {
+        return Ok(query.to_string());
+    }
+
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Err(AppError::Message(format!("Provider '{}' not found", query)));
+    }
+    let matching = |matches: &dyn Fn(&str) -> bool| -> Vec<(&String, &Provider)> {
+        providers
+            .iter()
+            .filter(|(id, provider)| {
+                matches(&id.to_lowercase()) || matches(&provider.name.to_lowercase())
+            })
+            .collect()
+    };
+
+    let mut candidates = matching(&|field| field.contains(&needle));
+    if candidates.is_empty() {
+        candidates = matching(&|field| is_subsequence(&needle, field));
+    }
+
+    match candidates.as_slice() {
+        [] => Err(AppError::Message(format!("Provider '{}' not found", query))),
+        [(id, _)] => Ok((*id).clone()),
+        many => Err(AppError::Message(texts::provider_fuzzy_ambiguous(
+            query,
+            &many
+                .iter()
+                .map(|(id, provider)| format!("{id} ({})", provider.name))
+                .collect::<Vec<_>>(),
+        ))),
+    }
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|c| chars.any(|h| h == c))
+}
+
+/// 大小写不敏感的子串匹配；空查询匹配全部
+fn matches_search_query(query: &str, fields: &[&str]) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || fields
+            .iter()
+            .any(|field| field.to_lowercase().contains(&query))
+}
+
+pub(crate) fn add_provider(app_type: AppType) -> Result<(), AppError> {
     // Disable bracketed paste mode to work around inquire dropping paste events
     crate::cli::terminal::disable_bracketed_paste_mode_best_effort();
 
@@ -269,17 +906,15 @@ fn add_provider(app_type: AppType) -> Result<(), AppError> {
             texts::add_official_provider(),
             texts::add_third_party_provider(),
         ];
-        match Select::new(texts::select_provider_add_mode(), choices.clone()).prompt() {
-            Ok(selected) if selected == texts::add_official_provider() => ProviderAddMode::Official,
-            Ok(_selected) => ProviderAddMode::ThirdParty,
-            Err(inquire::error::InquireError::OperationCanceled)
-            | Err(inquire::error::InquireError::OperationInterrupted) => {
+        match prompt_select(texts::select_provider_add_mode(), choices)? {
+            Some(selected) if selected == texts::add_official_provider() => {
+                ProviderAddMode::Official
+            }
+            Some(_selected) => ProviderAddMode::ThirdParty,
+            None => {
                 println!("{}", info(texts::cancelled()));
                 return Ok(());
             }
-            Err(e) => {
-                return Err(AppError::Message(texts::input_failed_error(&e.to_string())));
-            }
         }
     } else {
         ProviderAddMode::ThirdParty
@@ -301,11 +936,12 @@ fn add_provider(app_type: AppType) -> Result<(), AppError> {
     );
     let (name, website_url) = match (app_type.clone(), add_mode) {
         (AppType::Codex, ProviderAddMode::Official) => {
-            let name = Text::new(texts::provider_name_label())
-                .with_placeholder("OpenAI")
-                .with_help_message(texts::provider_name_help())
-                .prompt()
-                .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?;
+            let name = required(prompt_text_input(
+                texts::provider_name_label(),
+                None,
+                Some("OpenAI"),
+                texts::provider_name_help(),
+            )?)?;
             let name = name.trim().to_string();
             if name.is_empty() {
                 return Err(AppError::InvalidInput(
@@ -320,18 +956,18 @@ fn add_provider(app_type: AppType) -> Result<(), AppError> {
     println!("{}", info(&texts::generated_id_message(&id)));
 
     // 3. 收集配置
-    let settings_config = prompt_settings_config_for_add(&app_type, add_mode)?;
+    let mut settings_config = prompt_settings_config_for_add(&app_type, add_mode)?;
 
     // 4. 询问是否配置可选字段
-    let optional = if Confirm::new(texts::configure_optional_fields_prompt())
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
-    {
+    let optional = if required(prompt_confirm(
+        texts::configure_optional_fields_prompt(),
+        false,
+    )?)? {
         prompt_optional_fields(None)?
     } else {
         OptionalFields::default()
     };
+    set_provider_headers(&mut settings_config, optional.headers);
 
     // 5. 构建 Provider 对象
     let provider = Provider {
@@ -345,9 +981,10 @@ fn add_provider(app_type: AppType) -> Result<(), AppError> {
         notes: optional.notes,
         icon: None,
         icon_color: None,
-        meta: if is_codex_official {
+        meta: if is_codex_official || optional.expires_at.is_some() {
             Some(ProviderMeta {
-                codex_official: Some(true),
+                codex_official: is_codex_official.then_some(true),
+                expires_at: optional.expires_at,
                 ..Default::default()
             })
         } else {
@@ -358,10 +995,10 @@ fn add_provider(app_type: AppType) -> Result<(), AppError> {
 
     // 6. 显示摘要并确认
     display_provider_summary(&provider, &app_type);
-    if !Confirm::new(&texts::confirm_create_entity(texts::entity_provider()))
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+    if prompt_confirm(
+        &texts::confirm_create_entity(texts::entity_provider()),
+        false,
+    )? != Some(true)
     {
         println!("{}", info(texts::cancelled()));
         return Ok(());
@@ -383,12 +1020,126 @@ fn add_provider(app_type: AppType) -> Result<(), AppError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn matches_search_query_is_case_insensitive_across_fields() {
+        let fields = ["relay-a", "Relay A", "https://Relay.Example/v1"];
+        assert!(matches_search_query("relay.example", &fields));
+        assert!(matches_search_query("  RELAY A ", &fields));
+        assert!(!matches_search_query("other.example", &fields));
+    }
+
+    #[test]
+    fn resolve_provider_id_prefers_exact_then_unique_fuzzy_match() {
+        let mut providers = indexmap::IndexMap::new();
+        for (id, name) in [
+            ("packy", "PackyCode Relay"),
+            ("packy-backup", "Packy Backup"),
+            ("openrouter", "OpenRouter"),
+        ] {
+            providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    name.to_string(),
+                    serde_json::json!({}),
+                    None,
+                ),
+            );
+        }
+
+        assert_eq!(resolve_provider_id(&providers, "packy").unwrap(), "packy");
+        assert_eq!(
+            resolve_provider_id(&providers, "ROUTER").unwrap(),
+            "openrouter"
+        );
+        assert_eq!(resolve_provider_id(&providers, "relay").unwrap(), "packy");
+        assert_eq!(
+            resolve_provider_id(&providers, "opnrtr").unwrap(),
+            "openrouter"
+        );
+
+        let err = resolve_provider_id(&providers, "pack")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("packy (PackyCode Relay)") && err.contains("packy-backup"));
+        assert!(resolve_provider_id(&providers, "missing").is_err());
+    }
+
+    #[test]
+    fn porcelain_line_is_single_tab_separated_line() {
+        assert_eq!(
+            porcelain_line(&AppType::Claude, "prod", "Claude Prod"),
+            "claude\tprod\tClaude Prod"
+        );
+        assert_eq!(
+            porcelain_line(&AppType::Codex, "a\tb", "multi\nline"),
+            "codex\ta b\tmulti line"
+        );
+    }
+
     #[test]
     fn supports_official_provider_is_codex_only() {
         assert!(supports_official_provider(&AppType::Codex));
         assert!(!supports_official_provider(&AppType::Claude));
         assert!(!supports_official_provider(&AppType::Gemini));
     }
+
+    #[test]
+    fn build_cloned_provider_maps_claude_endpoint_into_codex_config_and_auth() {
+        let source = Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            serde_json::json!({
+                "env": {
+                    "ANTHROPIC_BASE_URL": "https://relay.example.com/v1",
+                    "ANTHROPIC_AUTH_TOKEN": "sk-relay"
+                }
+            }),
+            Some("https://relay.example.com".to_string()),
+        );
+
+        let (provider, warnings) = build_cloned_provider(
+            &source,
+            &AppType::Claude,
+            &AppType::Codex,
+            &["relay".to_string()],
+        )
+        .expect("clone should build");
+
+        assert!(warnings.is_empty());
+        assert_eq!(provider.id, "relay-1");
+        assert_eq!(provider.name, "Relay");
+        assert_eq!(
+            provider.settings_config["auth"]["OPENAI_API_KEY"],
+            serde_json::json!("sk-relay")
+        );
+        let config = provider.settings_config["config"]
+            .as_str()
+            .expect("config should be TOML string");
+        assert!(config.contains("base_url = \"https://relay.example.com/v1\""));
+        assert!(config.contains("model_provider = \"relay_1\""));
+    }
+
+    #[test]
+    fn build_cloned_provider_warns_for_oauth_only_source() {
+        let source = Provider::with_id(
+            "google".to_string(),
+            "Google OAuth".to_string(),
+            serde_json::json!({ "env": {}, "config": {} }),
+            None,
+        );
+
+        let (provider, warnings) =
+            build_cloned_provider(&source, &AppType::Gemini, &AppType::Claude, &[])
+                .expect("clone should build");
+
+        assert_eq!(warnings.len(), 1);
+        let env = provider.settings_config["env"]
+            .as_object()
+            .expect("env object");
+        assert!(!env.contains_key("ANTHROPIC_AUTH_TOKEN"));
+        assert!(!env.contains_key("ANTHROPIC_BASE_URL"));
+    }
 }
 
 fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
@@ -407,10 +1158,7 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let original = manager
         .providers
         .get(id)
-        .ok_or_else(|| {
-            let msg = texts::entity_not_found(texts::entity_provider(), id);
-            AppError::localized("provider.not_found", msg.clone(), msg)
-        })?
+        .ok_or_else(|| ProviderService::provider_not_found(id))?
         .clone();
     let is_current = manager.current == id;
     drop(config);
@@ -427,26 +1175,25 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let (name, website_url) = prompt_basic_fields(Some(&original))?;
 
     // 4. 询问是否修改配置
-    let settings_config = if Confirm::new(texts::modify_provider_config_prompt())
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
-    {
+    let mut settings_config = if required(prompt_confirm(
+        texts::modify_provider_config_prompt(),
+        false,
+    )?)? {
         prompt_settings_config(&app_type, Some(&original.settings_config))?
     } else {
         original.settings_config.clone()
     };
 
     // 5. 询问是否修改可选字段
-    let optional = if Confirm::new(texts::modify_optional_fields_prompt())
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
-    {
+    let optional = if required(prompt_confirm(
+        texts::modify_optional_fields_prompt(),
+        false,
+    )?)? {
         prompt_optional_fields(Some(&original))?
     } else {
         OptionalFields::from_provider(&original)
     };
+    set_provider_headers(&mut settings_config, optional.headers);
 
     // 6. 构建更新后的 Provider（保留 meta 和 created_at）
     let updated = Provider {
@@ -460,17 +1207,20 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
         notes: optional.notes,
         icon: None,
         icon_color: None,
-        meta: original.meta,                           // 保留元数据
-        in_failover_queue: original.in_failover_queue, // 保留故障转移状态
+        meta: with_expiry(original.meta.clone(), optional.expires_at), // 保留元数据，仅更新到期日
+        in_failover_queue: original.in_failover_queue,                 // 保留故障转移状态
     };
 
-    // 7. 显示修改摘要并确认
+    // 7. 显示修改摘要与字段级变更并确认
     println!("\n{}", highlight(texts::updated_config_header()));
     display_provider_summary(&updated, &app_type);
-    if !Confirm::new(&texts::confirm_update_entity(texts::entity_provider()))
-        .with_default(false)
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+    if !print_provider_field_changes(&original, &updated) {
+        return Ok(());
+    }
+    if prompt_confirm(
+        &texts::confirm_update_entity(texts::entity_provider()),
+        false,
+    )? != Some(true)
     {
         println!("{}", info(texts::cancelled()));
         return Ok(());
@@ -491,13 +1241,166 @@ fn edit_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+/// 打印编辑前后的字段级变更；没有变更时提示并返回 false
+pub(crate) fn print_provider_field_changes(before: &Provider, after: &Provider) -> bool {
+    let changes = crate::cli::tui::form::provider_field_changes(before, after);
+    if changes.is_empty() {
+        println!("\n{}", info(texts::no_changes_detected()));
+        return false;
+    }
+    println!("\n{}", highlight(texts::provider_changes_header()));
+    for line in crate::cli::tui::form::format_provider_field_changes(&changes) {
+        println!("  {line}");
+    }
+    true
+}
+
+/// 只把 JSON 输出到 stdout，便于直接重定向到文件
+fn share_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
+    let state = get_state()?;
+    let providers = ProviderService::list(&state, app_type)?;
+    let provider = providers
+        .get(id)
+        .ok_or_else(|| ProviderService::provider_not_found(id))?;
+
+    let snippet = crate::cli::tui::form::provider_share_value(provider);
+    let output = to_json(&snippet)
+        .map_err(|e| AppError::Message(texts::failed_to_serialize_json(&e.to_string())))?;
+    println!("{output}");
+    Ok(())
+}
+
 fn duplicate_provider(_app_type: AppType, id: &str) -> Result<(), AppError> {
     println!("{}", info(&format!("Duplicating provider '{}'...", id)));
     println!("{}", error("Provider duplication is not yet implemented."));
     Ok(())
 }
 
-fn speedtest_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
+fn clone_provider_to_app(id: &str, from: AppType, to: AppType) -> Result<(), AppError> {
+    if from == to {
+        return Err(AppError::InvalidInput(
+            texts::clone_to_app_same_app_error().to_string(),
+        ));
+    }
+
+    let state = get_state()?;
+    let providers = ProviderService::list(&state, from.clone())?;
+    let source = providers
+        .get(id)
+        .ok_or_else(|| ProviderService::provider_not_found(id))?;
+    let existing_ids: Vec<String> = ProviderService::list(&state, to.clone())?
+        .keys()
+        .cloned()
+        .collect();
+
+    let (provider, warnings) = build_cloned_provider(source, &from, &to, &existing_ids)?;
+    for message in &warnings {
+        println!("{}", warning(message));
+    }
+
+    let new_id = provider.id.clone();
+    ProviderService::add(&state, to.clone(), provider)?;
+
+    println!(
+        "{}",
+        success(&texts::provider_cloned_to_app(id, &new_id, to.as_str()))
+    );
+
+    Ok(())
+}
+
+/// 基于源供应商的 Base URL / API Key，通过表单构建目标应用的等价供应商
+fn build_cloned_provider(
+    source: &Provider,
+    from: &AppType,
+    to: &AppType,
+    existing_ids: &[String],
+) -> Result<(Provider, Vec<String>), AppError> {
+    let source_form = ProviderAddFormState::from_provider(from.clone(), source);
+    let mut warnings = Vec::new();
+
+    let oauth_only = match from {
+        AppType::Claude => false,
+        AppType::Codex => source_form.is_codex_official_provider(),
+        AppType::Gemini => source_form.gemini_auth_type == GeminiAuthType::OAuth,
+    };
+    let (base_url, api_key) = if oauth_only {
+        warnings.push(texts::clone_to_app_oauth_only_warning(from.as_str()));
+        (String::new(), String::new())
+    } else {
+        let (base_url, api_key) = match from {
+            AppType::Claude => {
+                let api_key = if source_form.claude_api_key.is_blank() {
+                    source
+                        .settings_config
+                        .get("env")
+                        .and_then(|env| env.get("ANTHROPIC_API_KEY"))
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                } else {
+                    source_form.claude_api_key.value.as_str()
+                };
+                (source_form.claude_base_url.value.as_str(), api_key)
+            }
+            AppType::Codex => (
+                source_form.codex_base_url.value.as_str(),
+                source_form.codex_api_key.value.as_str(),
+            ),
+            AppType::Gemini => (
+                source_form.gemini_base_url.value.as_str(),
+                source_form.gemini_api_key.value.as_str(),
+            ),
+        };
+        if api_key.trim().is_empty() {
+            warnings.push(texts::clone_to_app_missing_api_key_warning());
+        }
+        (base_url.trim().to_string(), api_key.trim().to_string())
+    };
+
+    let mut form = ProviderAddFormState::new(to.clone());
+    form.id.set(generate_provider_id(&source.id, existing_ids));
+    form.id_is_manual = true;
+    form.name.set(source.name.clone());
+    if let Some(url) = source.website_url.as_deref() {
+        form.website_url.set(url);
+    }
+    if let Some(notes) = source.notes.as_deref() {
+        form.notes.set(notes);
+    }
+
+    match to {
+        AppType::Claude => {
+            form.claude_base_url.set(base_url.as_str());
+            form.claude_api_key.set(api_key.as_str());
+        }
+        AppType::Codex => {
+            if !base_url.is_empty() {
+                form.codex_base_url.set(base_url.as_str());
+            }
+            form.codex_api_key.set(api_key.as_str());
+        }
+        AppType::Gemini => {
+            form.gemini_auth_type = GeminiAuthType::ApiKey;
+            if !base_url.is_empty() {
+                form.gemini_base_url.set(base_url.as_str());
+            }
+            form.gemini_api_key.set(api_key.as_str());
+        }
+    }
+
+    let mut provider: Provider = serde_json::from_value(form.to_provider_json_value())
+        .map_err(|source| AppError::JsonSerialize { source })?;
+    provider.created_at = Some(current_timestamp());
+    provider.sort_index = source.sort_index;
+
+    Ok((provider, warnings))
+}
+
+fn speedtest_provider(
+    app_type: AppType,
+    id: &str,
+    options: SpeedtestOptions,
+) -> Result<(), AppError> {
     let state = get_state()?;
 
     // Get provider by ID
@@ -521,43 +1424,280 @@ fn speedtest_provider(app_type: AppType, id: &str) -> Result<(), AppError> {
     let runtime = tokio::runtime::Runtime::new()
         .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
 
-    let results = runtime
-        .block_on(async { SpeedtestService::test_endpoints(vec![api_url.clone()], None).await })?;
+    let results = runtime.block_on(async {
+        let headers = provider_headers(&provider.settings_config);
+        SpeedtestService::test_endpoints_with_headers(vec![(api_url.clone(), headers)], options)
+            .await
+    })?;
 
-    // Display results
-    if let Some(result) = results.first() {
-        let mut table = create_table();
-        table.set_header(vec!["Endpoint", "Latency", "Status"]);
+    speedtest::print_results(vec![(id.to_string(), results.into_iter().next())]);
+    Ok(())
+}
+
+fn set_failover_queued(app_type: AppType, id: &str, queued: bool) -> Result<(), AppError> {
+    let state = get_state()?;
+    ProviderService::set_failover_queued(&state, app_type.clone(), id, queued)?;
+    let message = if queued {
+        texts::failover_queue_added(id, app_type.as_str())
+    } else {
+        texts::failover_queue_removed(id, app_type.as_str())
+    };
+    println!("{}", success(&message));
+    Ok(())
+}
+
+fn run_failover(
+    app_type: AppType,
+    dry_run: bool,
+    options: SpeedtestOptions,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let candidates = ProviderService::failover_candidates(&state, app_type.clone())?;
+    if candidates.is_empty() {
+        println!("{}", info(&texts::failover_queue_empty(app_type.as_str())));
+        return Ok(());
+    }
 
-        let latency_str = if let Some(latency) = result.latency {
-            format!("{} ms", latency)
-        } else if result.error.is_some() {
-            "Failed".to_string()
+    println!(
+        "{}",
+        info(&texts::failover_testing(
+            candidates.len(),
+            app_type.as_str()
+        ))
+    );
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let ranked = runtime.block_on(ProviderService::speedtest_failover_candidates(
+        candidates, options,
+    ))?;
+
+    let mut table = create_table();
+    table.set_header(vec!["", "ID", "Name", "Latency", "Status"]);
+    for (idx, candidate) in ranked.iter().enumerate() {
+        let marker = if idx == 0 && candidate.is_healthy() {
+            "→"
         } else {
-            "Timeout".to_string()
+            " "
         };
-
-        let status_str = result
+        let latency = match (candidate.latency, &candidate.url, &candidate.error) {
+            (_, None, Some(err)) => err.clone(),
+            (_, None, None) => texts::failover_no_url().to_string(),
+            (Some(ms), _, None) => format!("{} ms", ms),
+            (_, _, Some(err)) => err.clone(),
+            (None, _, None) => "Timeout".to_string(),
+        };
+        let status = candidate
             .status
             .map(|s| s.to_string())
             .unwrap_or_else(|| "N/A".to_string());
+        table.add_row(vec![
+            marker.to_string(),
+            candidate.provider_id.clone(),
+            candidate.provider_name.clone(),
+            latency,
+            status,
+        ]);
+    }
+    println!("{}", table);
+
+    let Some(best) = ranked.first().filter(|c| c.is_healthy()) else {
+        return Err(AppError::Message(texts::failover_no_healthy(
+            app_type.as_str(),
+        )));
+    };
 
-        table.add_row(vec![result.url.clone(), latency_str, status_str]);
+    if ProviderService::current(&state, app_type.clone())? == best.provider_id {
+        println!(
+            "{}",
+            success(&texts::failover_already_fastest(&best.provider_id))
+        );
+        return Ok(());
+    }
+    if dry_run {
+        println!("{}", info(&texts::failover_dry_run(&best.provider_id)));
+        return Ok(());
+    }
 
-        println!("{}", table);
+    drop(state);
+    switch_provider(app_type, &best.provider_id, false, true)
+}
 
-        // Show error details if any
-        if let Some(err) = &result.error {
-            println!("\n{}", error(&format!("Error: {}", err)));
-        } else if result.latency.is_some() {
-            println!("\n{}", success("✓ Speedtest completed successfully"));
+fn prune_unreachable(
+    app_type: AppType,
+    delete: bool,
+    yes: bool,
+    options: SpeedtestOptions,
+) -> Result<(), AppError> {
+    let state = get_state()?;
+    let total = ProviderService::list(&state, app_type.clone())?.len();
+    let candidates = ProviderService::prune_candidates(&state, app_type.clone())?;
+    let tested = candidates.iter().filter(|c| c.url.is_some()).count();
+    println!(
+        "{}",
+        info(&texts::provider_prune_testing(tested, app_type.as_str()))
+    );
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let tested = runtime.block_on(ProviderService::speedtest_failover_candidates(
+        candidates, options,
+    ))?;
+    let unreachable: Vec<_> = tested.into_iter().filter(|c| c.is_unreachable()).collect();
+
+    if unreachable.is_empty() {
+        println!("{}", success(texts::provider_prune_none()));
+        println!("{}", info(&texts::provider_prune_summary(total, 0)));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Name", "Endpoint", "Error"]);
+    for candidate in &unreachable {
+        table.add_row(vec![
+            candidate.provider_id.clone(),
+            candidate.provider_name.clone(),
+            candidate.url.clone().unwrap_or_default(),
+            candidate
+                .error
+                .clone()
+                .unwrap_or_else(|| "Timeout".to_string()),
+        ]);
+    }
+    println!("{}", table);
+
+    if !yes {
+        if !std::io::stdin().is_terminal() {
+            return Err(AppError::InvalidInput(
+                texts::provider_prune_requires_yes().to_string(),
+            ));
+        }
+        let confirmed = Confirm::new(&texts::provider_prune_confirm(unreachable.len(), delete))
+            .with_default(false)
+            .prompt()
+            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?;
+        if !confirmed {
+            println!("{}", info(texts::cancelled()));
+            return Ok(());
         }
     }
 
+    let ids: Vec<String> = unreachable.into_iter().map(|c| c.provider_id).collect();
+    if let Some(path) = ProviderService::prune_providers(&state, app_type.clone(), &ids, !delete)? {
+        println!(
+            "{}",
+            info(&texts::provider_prune_archived(&path.display().to_string()))
+        );
+    }
+    println!(
+        "{}",
+        success(&texts::provider_prune_summary(total - ids.len(), ids.len()))
+    );
     Ok(())
 }
 
-fn extract_api_url(settings_config: &serde_json::Value, app_type: &AppType) -> Option<String> {
+fn provider_health(app_type: AppType, options: SpeedtestOptions) -> Result<(), AppError> {
+    let state = get_state()?;
+    let targets = ProviderService::health_targets(&state, app_type.clone())?;
+    if targets.is_empty() {
+        println!("{}", info(texts::no_providers()));
+        return Ok(());
+    }
+    println!(
+        "{}",
+        info(&texts::provider_health_testing(
+            targets.len(),
+            app_type.as_str()
+        ))
+    );
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let results = runtime.block_on(ProviderService::check_provider_health(
+        app_type, targets, options,
+    ))?;
+
+    let mut table = create_table();
+    table.set_header(vec!["ID", "Name", "Health", "Latency", "Auth", "Detail"]);
+    for health in &results {
+        table.add_row(vec![
+            comfy_table::Cell::new(&health.provider_id),
+            comfy_table::Cell::new(&health.provider_name),
+            health_status_cell(health.status),
+            comfy_table::Cell::new(
+                health
+                    .latency
+                    .map(|ms| format!("{} ms", ms))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            comfy_table::Cell::new(
+                health
+                    .auth_status
+                    .map(|status| status.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            comfy_table::Cell::new(health_detail(health)),
+        ]);
+    }
+    println!("{}", table);
+
+    let count = |status| results.iter().filter(|h| h.status == status).count();
+    println!(
+        "\n{}",
+        info(&texts::provider_health_summary(
+            count(HealthStatus::Healthy),
+            count(HealthStatus::AuthFailed),
+            count(HealthStatus::AuthUnknown),
+            count(HealthStatus::Unreachable),
+            count(HealthStatus::NotApplicable),
+        ))
+    );
+    Ok(())
+}
+
+fn health_status_cell(status: HealthStatus) -> comfy_table::Cell {
+    use comfy_table::{Cell, Color};
+
+    let (label, color) = match status {
+        HealthStatus::Healthy => (texts::provider_health_ok(), Color::Green),
+        HealthStatus::AuthFailed => (texts::provider_health_auth_failed(), Color::Yellow),
+        HealthStatus::AuthUnknown => (texts::provider_health_auth_unknown(), Color::Yellow),
+        HealthStatus::Unreachable => (texts::provider_health_unreachable(), Color::Red),
+        HealthStatus::NotApplicable => (texts::provider_health_not_applicable(), Color::DarkGrey),
+    };
+    if colored::control::SHOULD_COLORIZE.should_colorize() {
+        Cell::new(label).fg(color)
+    } else {
+        Cell::new(label)
+    }
+}
+
+fn health_detail(health: &ProviderHealth) -> String {
+    if health.status == HealthStatus::NotApplicable {
+        return texts::provider_health_official().to_string();
+    }
+    if let (None, Some(err)) = (&health.url, &health.error) {
+        return err.clone();
+    }
+    if health.url.is_none() {
+        return texts::failover_no_url().to_string();
+    }
+    if health.is_missing_key() {
+        return texts::provider_health_no_key().to_string();
+    }
+    health
+        .error
+        .clone()
+        .or_else(|| {
+            health
+                .auth_error
+                .as_ref()
+                .map(|err| texts::provider_health_auth_probe_failed(err))
+        })
+        .unwrap_or_default()
+}
+
+pub(crate) fn extract_api_url(
+    settings_config: &serde_json::Value,
+    app_type: &AppType,
+) -> Option<String> {
     match app_type {
         AppType::Claude => settings_config
             .get("env")?
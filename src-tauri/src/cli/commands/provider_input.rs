@@ -3,10 +3,12 @@
 
 use crate::app_config::AppType;
 use crate::cli::i18n::texts;
+use crate::cli::interactive::utils::{
+    prompt_confirm_with_help, prompt_select_with_cursor, prompt_text_input,
+};
 use crate::error::AppError;
 use crate::provider::Provider;
 use colored::Colorize;
-use inquire::{Confirm, Select, Text};
 use serde_json::{json, Value};
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -18,6 +20,47 @@ pub enum ProviderAddMode {
     ThirdParty,
 }
 
+/// 根据 Base URL 的主机名/路径推测其面向的应用（无法判断时返回 None）
+pub fn guess_app_from_base_url(raw: &str) -> Option<AppType> {
+    let url = url::Url::parse(raw.trim()).ok()?;
+    let host = url.host_str()?.to_ascii_lowercase();
+    let path = url.path().trim_end_matches('/').to_ascii_lowercase();
+
+    if host.contains("anthropic.com") || path.contains("/anthropic") || path.contains("/claude") {
+        return Some(AppType::Claude);
+    }
+    if host.ends_with("googleapis.com") || path.contains("/gemini") || path.contains("/v1beta") {
+        return Some(AppType::Gemini);
+    }
+    if host.contains("openai.com")
+        || path.ends_with("/v1")
+        || path.contains("/chat/completions")
+        || path.ends_with("/responses")
+    {
+        return Some(AppType::Codex);
+    }
+    None
+}
+
+/// Base URL 看起来属于其他应用时返回提示文案（仅提示，不阻止保存）
+pub fn base_url_mismatch_warning(app_type: &AppType, base_url: &str) -> Option<String> {
+    let guessed = guess_app_from_base_url(base_url)?;
+    if &guessed == app_type {
+        return None;
+    }
+    Some(texts::base_url_app_mismatch_warning(
+        base_url.trim(),
+        app_type.as_str(),
+        guessed.as_str(),
+    ))
+}
+
+fn warn_on_base_url_mismatch(app_type: &AppType, base_url: &str) {
+    if let Some(message) = base_url_mismatch_warning(app_type, base_url) {
+        println!("{}", crate::cli::ui::warning(&message));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +86,37 @@ mod tests {
             "official Codex provider should use responses wire API"
         );
     }
+
+    #[test]
+    fn base_url_mismatch_warns_for_openai_style_url_on_claude() {
+        assert!(base_url_mismatch_warning(&AppType::Claude, "https://api.openai.com/v1").is_some());
+        assert!(base_url_mismatch_warning(&AppType::Claude, "https://relay.example/v1/").is_some());
+        assert!(
+            base_url_mismatch_warning(&AppType::Codex, "https://api.deepseek.com/anthropic")
+                .is_some()
+        );
+        assert!(base_url_mismatch_warning(
+            &AppType::Codex,
+            "https://generativelanguage.googleapis.com"
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn base_url_mismatch_is_silent_for_matching_or_unknown_urls() {
+        assert!(
+            base_url_mismatch_warning(&AppType::Claude, "https://api.deepseek.com/anthropic")
+                .is_none()
+        );
+        assert!(base_url_mismatch_warning(&AppType::Claude, "https://www.packyapi.com").is_none());
+        assert!(base_url_mismatch_warning(&AppType::Codex, "https://api.openai.com/v1").is_none());
+        assert!(base_url_mismatch_warning(
+            &AppType::Gemini,
+            "https://generativelanguage.googleapis.com"
+        )
+        .is_none());
+        assert!(base_url_mismatch_warning(&AppType::Claude, "not a url").is_none());
+    }
 }
 
 pub fn prompt_settings_config_for_add(
@@ -103,6 +177,10 @@ fn build_codex_official_settings_config(model: &str, _wire_api: &str) -> Value {
 #[derive(Default)]
 pub struct OptionalFields {
     pub notes: Option<String>,
+    /// API Key 到期日（YYYY-MM-DD）
+    pub expires_at: Option<String>,
+    /// 测速等探测请求附带的自定义请求头
+    pub headers: serde_json::Map<String, Value>,
     pub icon: Option<String>,
     pub icon_color: Option<String>,
     pub sort_index: Option<usize>,
@@ -113,6 +191,16 @@ impl OptionalFields {
     pub fn from_provider(provider: &Provider) -> Self {
         Self {
             notes: provider.notes.clone(),
+            expires_at: provider
+                .meta
+                .as_ref()
+                .and_then(|meta| meta.expires_at.clone()),
+            headers: provider
+                .settings_config
+                .get("headers")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default(),
             icon: provider.icon.clone(),
             icon_color: provider.icon_color.clone(),
             sort_index: provider.sort_index,
@@ -120,40 +208,9 @@ impl OptionalFields {
     }
 }
 
-/// 生成唯一的 Provider ID
-/// 基于名称转换为 kebab-case，如有冲突则追加数字后缀
-pub fn generate_provider_id(name: &str, existing_ids: &[String]) -> String {
-    // 转换为 kebab-case
-    let base_id = name
-        .to_lowercase()
-        .chars()
-        .map(|c| {
-            if c.is_alphanumeric() || c == '-' || c == '_' {
-                c
-            } else if c.is_whitespace() {
-                '-'
-            } else {
-                '-'
-            }
-        })
-        .collect::<String>()
-        .trim_matches('-')
-        .to_string();
-
-    // 检查唯一性
-    if !existing_ids.contains(&base_id) {
-        return base_id;
-    }
-
-    // 追加数字后缀
-    let mut counter = 1;
-    loop {
-        let candidate = format!("{}-{}", base_id, counter);
-        if !existing_ids.contains(&candidate) {
-            return candidate;
-        }
-        counter += 1;
-    }
+/// 交互输入被取消（Esc / EOF）时中止当前流程
+pub(crate) fn required<T>(value: Option<T>) -> Result<T, AppError> {
+    value.ok_or_else(|| AppError::Message(texts::input_failed_error(texts::cancelled())))
 }
 
 /// 收集基本字段：name, website_url
@@ -163,18 +220,20 @@ pub fn prompt_basic_fields(
     // 供应商名称：根据上下文选择方法
     let name = if let Some(provider) = current {
         // 编辑模式：预填充当前值
-        Text::new(texts::provider_name_label())
-            .with_initial_value(&provider.name)
-            .with_help_message(texts::provider_name_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::provider_name_label(),
+            Some(&provider.name),
+            None,
+            texts::provider_name_help(),
+        )?)?
     } else {
         // 新增模式：显示示例占位符
-        Text::new(texts::provider_name_label())
-            .with_placeholder("OpenAI")
-            .with_help_message(texts::provider_name_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::provider_name_label(),
+            None,
+            Some(texts::provider_name_placeholder()),
+            texts::provider_name_help(),
+        )?)?
     };
 
     let name = name.trim().to_string();
@@ -187,17 +246,19 @@ pub fn prompt_basic_fields(
     // 官网 URL：同样处理
     let website_url = if let Some(provider) = current {
         let initial = provider.website_url.as_deref().unwrap_or("");
-        Text::new(texts::website_url_label())
-            .with_initial_value(initial)
-            .with_help_message(texts::website_url_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::website_url_label(),
+            Some(initial),
+            None,
+            texts::website_url_help(),
+        )?)?
     } else {
-        Text::new(texts::website_url_label())
-            .with_placeholder("https://openai.com")
-            .with_help_message(texts::website_url_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::website_url_label(),
+            None,
+            Some(texts::website_url_placeholder()),
+            texts::website_url_help(),
+        )?)?
     };
 
     let website_url = if website_url.trim().is_empty() {
@@ -288,18 +349,20 @@ fn prompt_model_field(
 
     let input = if let Some(existing) = existing_value {
         // 编辑模式 - 有现有值：预填充
-        Text::new(&format!("{}：", field_name))
-            .with_initial_value(existing)
-            .with_help_message(texts::model_default_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}：", field_name),
+            Some(existing),
+            None,
+            texts::model_default_help(),
+        )?)?
     } else {
         // 新增模式或编辑模式无现有值：占位符
-        Text::new(&format!("{}：", field_name))
-            .with_placeholder(placeholder)
-            .with_help_message(texts::model_default_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}：", field_name),
+            None,
+            Some(placeholder),
+            texts::model_default_help(),
+        )?)?
     };
 
     let trimmed = input.trim();
@@ -329,18 +392,20 @@ fn prompt_claude_config(current: Option<&Value>) -> Result<Value, AppError> {
         .filter(|s| !s.is_empty())
     {
         // 编辑模式：显示完整 API Key 供编辑
-        Text::new(texts::api_key_label())
-            .with_initial_value(current_key)
-            .with_help_message(texts::api_key_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::api_key_label(),
+            Some(current_key),
+            None,
+            texts::api_key_help(),
+        )?)?
     } else {
         // 新增模式：占位符示例
-        Text::new(texts::api_key_label())
-            .with_placeholder("sk-ant-...")
-            .with_help_message(texts::api_key_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::api_key_label(),
+            None,
+            Some(texts::claude_api_key_placeholder()),
+            texts::api_key_help(),
+        )?)?
     };
 
     let base_url = if let Some(current_url) = current
@@ -349,25 +414,29 @@ fn prompt_claude_config(current: Option<&Value>) -> Result<Value, AppError> {
         .and_then(|u| u.as_str())
         .filter(|s| !s.is_empty())
     {
-        Text::new(texts::base_url_label())
-            .with_initial_value(current_url)
-            .with_help_message(texts::api_key_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::base_url_label(),
+            Some(current_url),
+            None,
+            texts::api_key_help(),
+        )?)?
     } else {
-        Text::new(texts::base_url_label())
-            .with_placeholder(texts::base_url_placeholder())
-            .with_help_message(texts::api_key_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::base_url_label(),
+            None,
+            Some(texts::base_url_placeholder()),
+            texts::api_key_help(),
+        )?)?
     };
 
     // 询问是否配置模型
-    let config_models = Confirm::new(texts::configure_model_names_prompt())
-        .with_default(false)
-        .with_help_message(texts::api_key_help())
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?;
+    let config_models = required(prompt_confirm_with_help(
+        texts::configure_model_names_prompt(),
+        false,
+        texts::api_key_help(),
+    )?)?;
+
+    warn_on_base_url_mismatch(&AppType::Claude, &base_url);
 
     let mut env = serde_json::Map::new();
     env.insert("ANTHROPIC_AUTH_TOKEN".to_string(), json!(api_key.trim()));
@@ -467,32 +536,36 @@ fn prompt_codex_config(current: Option<&Value>) -> Result<Value, AppError> {
 
     // 1. API Key（恢复：用于旧版本 Codex 兼容性）
     let api_key = if let Some(current_key) = current_api_key {
-        Text::new(texts::openai_api_key_label())
-            .with_initial_value(current_key)
-            .with_help_message(texts::api_key_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::openai_api_key_label(),
+            Some(current_key),
+            None,
+            texts::api_key_help(),
+        )?)?
     } else {
-        Text::new(texts::openai_api_key_label())
-            .with_placeholder("sk-...")
-            .with_help_message(texts::api_key_help())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::openai_api_key_label(),
+            None,
+            Some(texts::codex_api_key_placeholder()),
+            texts::api_key_help(),
+        )?)?
     };
 
     // 2. Base URL
     let base_url = if let Some(current) = current_base_url.as_deref() {
-        Text::new(&format!("{}:", texts::tui_label_base_url()))
-            .with_initial_value(current)
-            .with_help_message("API endpoint (e.g., https://api.openai.com/v1)")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::tui_label_base_url()),
+            Some(current),
+            None,
+            "API endpoint (e.g., https://api.openai.com/v1)",
+        )?)?
     } else {
-        Text::new(&format!("{}:", texts::tui_label_base_url()))
-            .with_placeholder("https://api.openai.com/v1")
-            .with_help_message("API endpoint")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::tui_label_base_url()),
+            None,
+            Some(texts::codex_base_url_placeholder()),
+            "API endpoint",
+        )?)?
     };
     let base_url = base_url.trim().to_string();
     if base_url.is_empty() {
@@ -500,20 +573,23 @@ fn prompt_codex_config(current: Option<&Value>) -> Result<Value, AppError> {
             texts::base_url_empty_error().to_string(),
         ));
     }
+    warn_on_base_url_mismatch(&AppType::Codex, &base_url);
 
     // 3. Model
     let model = if let Some(current) = current_model.as_deref() {
-        Text::new(&format!("{}:", texts::model_label()))
-            .with_initial_value(current)
-            .with_help_message("Model name (e.g., gpt-5.2-codex, o3)")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::model_label()),
+            Some(current),
+            None,
+            "Model name (e.g., gpt-5.2-codex, o3)",
+        )?)?
     } else {
-        Text::new(&format!("{}:", texts::model_label()))
-            .with_placeholder("gpt-5.2-codex")
-            .with_help_message("Model name")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::model_label()),
+            None,
+            Some(texts::codex_model_placeholder()),
+            "Model name",
+        )?)?
     };
 
     Ok(build_codex_settings_config(
@@ -562,31 +638,35 @@ fn prompt_codex_official_config(current: Option<&Value>) -> Result<Value, AppErr
     }
 
     let base_url = if let Some(current) = current_base_url.as_deref() {
-        Text::new(&format!("{}:", texts::tui_label_base_url()))
-            .with_initial_value(current)
-            .with_help_message("API endpoint (e.g., https://api.openai.com/v1)")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::tui_label_base_url()),
+            Some(current),
+            None,
+            "API endpoint (e.g., https://api.openai.com/v1)",
+        )?)?
     } else {
-        Text::new(&format!("{}:", texts::tui_label_base_url()))
-            .with_placeholder(CODEX_OFFICIAL_BASE_URL)
-            .with_help_message("API endpoint")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::tui_label_base_url()),
+            None,
+            Some(CODEX_OFFICIAL_BASE_URL),
+            "API endpoint",
+        )?)?
     };
 
     let model = if let Some(current) = current_model.as_deref() {
-        Text::new(&format!("{}:", texts::model_label()))
-            .with_initial_value(current)
-            .with_help_message("Model name (e.g., gpt-5.2-codex, o3)")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::model_label()),
+            Some(current),
+            None,
+            "Model name (e.g., gpt-5.2-codex, o3)",
+        )?)?
     } else {
-        Text::new(&format!("{}:", texts::model_label()))
-            .with_placeholder("gpt-5.2-codex")
-            .with_help_message("Model name")
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            &format!("{}:", texts::model_label()),
+            None,
+            Some(texts::codex_model_placeholder()),
+            "Model name",
+        )?)?
     };
 
     Ok(build_codex_settings_config(
@@ -610,11 +690,12 @@ fn prompt_gemini_config(current: Option<&Value>) -> Result<Value, AppError> {
 
     let auth_options = vec![texts::google_oauth_official(), texts::generic_api_key()];
 
-    let auth_type = Select::new(texts::auth_type_label(), auth_options.clone())
-        .with_starting_cursor(default_index)
-        .with_help_message(texts::select_auth_method_help())
-        .prompt()
-        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?;
+    let auth_type = required(prompt_select_with_cursor(
+        texts::auth_type_label(),
+        auth_options.clone(),
+        default_index,
+        texts::select_auth_method_help(),
+    )?)?;
 
     // Match using the translated strings
     let google_oauth = texts::google_oauth_official();
@@ -633,17 +714,19 @@ fn prompt_gemini_config(current: Option<&Value>) -> Result<Value, AppError> {
             .and_then(|k| k.as_str())
             .filter(|s| !s.is_empty())
         {
-            Text::new(texts::gemini_api_key_label())
-                .with_initial_value(current_key)
-                .with_help_message(texts::generic_api_key_help())
-                .prompt()
-                .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+            required(prompt_text_input(
+                texts::gemini_api_key_label(),
+                Some(current_key),
+                None,
+                texts::generic_api_key_help(),
+            )?)?
         } else {
-            Text::new(texts::gemini_api_key_label())
-                .with_placeholder("AIza... or pk-...")
-                .with_help_message(texts::generic_api_key_help())
-                .prompt()
-                .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+            required(prompt_text_input(
+                texts::gemini_api_key_label(),
+                None,
+                Some(texts::gemini_api_key_placeholder()),
+                texts::generic_api_key_help(),
+            )?)?
         };
 
         let base_url = if let Some(current_url) = current
@@ -652,19 +735,23 @@ fn prompt_gemini_config(current: Option<&Value>) -> Result<Value, AppError> {
             .and_then(|u| u.as_str())
             .filter(|s| !s.is_empty())
         {
-            Text::new(texts::gemini_base_url_label())
-                .with_initial_value(current_url)
-                .with_help_message(texts::gemini_base_url_help())
-                .prompt()
-                .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+            required(prompt_text_input(
+                texts::gemini_base_url_label(),
+                Some(current_url),
+                None,
+                texts::gemini_base_url_help(),
+            )?)?
         } else {
-            Text::new(texts::gemini_base_url_label())
-                .with_placeholder(texts::gemini_base_url_placeholder())
-                .with_help_message(texts::gemini_base_url_help())
-                .prompt()
-                .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+            required(prompt_text_input(
+                texts::gemini_base_url_label(),
+                None,
+                Some(texts::gemini_base_url_placeholder()),
+                texts::gemini_base_url_help(),
+            )?)?
         };
 
+        warn_on_base_url_mismatch(&AppType::Gemini, &base_url);
+
         Ok(json!({
             "env": {
                 "GEMINI_API_KEY": api_key.trim(),
@@ -681,17 +768,19 @@ pub fn prompt_optional_fields(current: Option<&Provider>) -> Result<OptionalFiel
 
     let notes = if let Some(provider) = current {
         let initial = provider.notes.as_deref().unwrap_or("");
-        Text::new(texts::notes_label())
-            .with_initial_value(initial)
-            .with_help_message(texts::notes_help_edit())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::notes_label(),
+            Some(initial),
+            None,
+            texts::notes_help_edit(),
+        )?)?
     } else {
-        Text::new(texts::notes_label())
-            .with_placeholder(texts::notes_example_placeholder())
-            .with_help_message(texts::notes_help_new())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::notes_label(),
+            None,
+            Some(texts::notes_example_placeholder()),
+            texts::notes_help_new(),
+        )?)?
     };
     let notes = if notes.trim().is_empty() {
         None
@@ -699,22 +788,55 @@ pub fn prompt_optional_fields(current: Option<&Provider>) -> Result<OptionalFiel
         Some(notes.trim().to_string())
     };
 
+    let initial_expiry = current
+        .and_then(|provider| provider.meta.as_ref())
+        .and_then(|meta| meta.expires_at.as_deref())
+        .unwrap_or("");
+    let expires_at = required(prompt_text_input(
+        texts::expires_at_label(),
+        Some(initial_expiry),
+        Some(texts::expires_at_placeholder()),
+        texts::expires_at_help(),
+    )?)?;
+    let expires_at = if expires_at.trim().is_empty() {
+        None
+    } else {
+        Some(
+            crate::services::parse_expiry_date(&expires_at)?
+                .format("%Y-%m-%d")
+                .to_string(),
+        )
+    };
+
+    let initial_headers = current
+        .map(|provider| crate::services::format_header_lines(&provider.settings_config))
+        .unwrap_or_default();
+    let headers = required(prompt_text_input(
+        texts::headers_label(),
+        Some(&initial_headers),
+        Some(texts::headers_placeholder()),
+        texts::headers_help(),
+    )?)?;
+    let headers = crate::services::parse_header_lines(&headers)?;
+
     let sort_index_str = if let Some(provider) = current {
         let initial = provider
             .sort_index
             .map(|i| i.to_string())
             .unwrap_or_default();
-        Text::new(texts::sort_index_label())
-            .with_initial_value(&initial)
-            .with_help_message(texts::sort_index_help_edit())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::sort_index_label(),
+            Some(&initial),
+            None,
+            texts::sort_index_help_edit(),
+        )?)?
     } else {
-        Text::new(texts::sort_index_label())
-            .with_placeholder(texts::sort_index_placeholder())
-            .with_help_message(texts::sort_index_help_new())
-            .prompt()
-            .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?
+        required(prompt_text_input(
+            texts::sort_index_label(),
+            None,
+            Some(texts::sort_index_placeholder()),
+            texts::sort_index_help_new(),
+        )?)?
     };
     let sort_index =
         if sort_index_str.trim().is_empty() {
@@ -727,6 +849,8 @@ pub fn prompt_optional_fields(current: Option<&Provider>) -> Result<OptionalFiel
 
     Ok(OptionalFields {
         notes,
+        expires_at,
+        headers,
         icon: None,
         icon_color: None,
         sort_index,
@@ -809,11 +933,26 @@ pub fn display_provider_summary(provider: &Provider, app_type: &AppType) {
     }
 
     // 可选字段
-    if provider.notes.is_some() || provider.sort_index.is_some() {
+    let expires_at = provider
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.expires_at.as_deref());
+    let headers = crate::services::format_header_lines(&provider.settings_config);
+    if provider.notes.is_some()
+        || provider.sort_index.is_some()
+        || expires_at.is_some()
+        || !headers.is_empty()
+    {
         println!("\n{}", texts::optional_fields_label().bright_cyan());
         if let Some(notes) = &provider.notes {
             println!("  {}: {}", texts::notes_label_colon(), notes);
         }
+        if let Some(expires_at) = expires_at {
+            println!("  {} {}", texts::expires_at_label(), expires_at);
+        }
+        if !headers.is_empty() {
+            println!("  {} {}", texts::headers_label(), headers);
+        }
         if let Some(idx) = provider.sort_index {
             println!("  {}: {}", texts::sort_index_label_colon(), idx);
         }
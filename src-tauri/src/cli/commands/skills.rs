@@ -1,11 +1,15 @@
 use clap::Subcommand;
 use std::future::Future;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 
 use crate::app_config::AppType;
-use crate::cli::ui::{create_table, highlight, info, success};
+use crate::cli::commands::diagnostics;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, error, highlight, info, success};
 use crate::error::AppError;
-use crate::services::skill::{SkillRepo, SyncMethod};
-use crate::services::SkillService;
+use crate::services::skill::{Skill, SkillRepo, SkillSyncReport, SyncMethod};
+use crate::services::{DiagnosticReport, SkillService};
 
 #[derive(Subcommand)]
 pub enum SkillsCommand {
@@ -16,11 +20,29 @@ pub enum SkillsCommand {
     Discover {
         /// Optional query filter (matches name/directory)
         query: Option<String>,
+        /// Also match the query against SKILL.md body text (slower: reads every SKILL.md)
+        #[arg(long, requires = "query")]
+        deep: bool,
+        /// Show at most N skills
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+        /// Sort order
+        #[arg(long, value_enum, default_value_t = DiscoverSort::Name)]
+        sort: DiscoverSort,
+        /// Only show skills that are already installed
+        #[arg(long, conflicts_with = "available_only")]
+        installed_only: bool,
+        /// Only show skills that are not installed yet
+        #[arg(long)]
+        available_only: bool,
     },
     /// Install a skill (SSOT -> app skills dir)
     Install {
         /// Skill directory name or full key (owner/name:directory)
         spec: String,
+        /// Resolve the skill and show where it would be installed without changing anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Uninstall a skill (remove from SSOT and app dirs)
     Uninstall {
@@ -31,26 +53,75 @@ pub enum SkillsCommand {
     Enable {
         /// Skill directory or id
         spec: String,
+        /// Enable for every app at once
+        #[arg(long)]
+        all: bool,
     },
     /// Disable a skill for the selected app
     Disable {
         /// Skill directory or id
         spec: String,
+        /// Disable for every app at once
+        #[arg(long)]
+        all: bool,
     },
     /// Sync enabled skills to app skills dirs
     Sync,
     /// Scan unmanaged skills in app skills dirs
-    ScanUnmanaged,
+    ScanUnmanaged {
+        /// Also list app-dir symlinks that point into the SSOT skills dir
+        #[arg(long)]
+        follow_symlinks: bool,
+    },
+    /// Check SSOT integrity, app-dir sync state and unmanaged skills
+    Doctor {
+        /// Print a machine-readable diagnostic report
+        #[arg(long)]
+        json: bool,
+        /// Repair SSOT skill folders missing from the index: re-register them or delete them
+        #[arg(long, value_enum, value_name = "ACTION")]
+        orphans: Option<OrphanAction>,
+    },
     /// Import unmanaged skills from app skills dirs into SSOT
     ImportFromApps {
         /// One or more skill directories to import
         directories: Vec<String>,
+        /// Skip the confirmation prompt (required when not running in a terminal)
+        #[arg(short = 'y', long)]
+        yes: bool,
+        /// Undo the last import: remove the dirs it added from SSOT and the index
+        #[arg(long, conflicts_with_all = ["directories", "yes"])]
+        undo: bool,
     },
     /// Show skill information
     Info {
         /// Skill directory or id
         spec: String,
     },
+    /// Print a skill's README URL (lists installed skills when omitted)
+    Readme {
+        /// Skill directory, id or discovery key (owner/name:directory)
+        spec: Option<String>,
+        /// Open the README in the default browser
+        #[arg(long, requires = "spec")]
+        open: bool,
+    },
+    /// Export all managed skills (SSOT dir + index) to a tar.gz bundle
+    Export {
+        /// Output file
+        #[arg(short, long, default_value = "skills.tar.gz")]
+        output: PathBuf,
+    },
+    /// Import a bundle created by `skills export`, then sync enabled skills to apps
+    Import {
+        /// Bundle file (.tar.gz)
+        file: PathBuf,
+        /// How to handle skills that already exist locally (prompts when omitted)
+        #[arg(long, value_enum)]
+        on_conflict: Option<SkillConflictStrategy>,
+    },
+    /// Open the skills SSOT directory in the system file manager
+    Open,
     /// Get or set the skills sync method (auto|symlink|copy)
     SyncMethod {
         /// Optional method to set (omit to show current)
@@ -62,37 +133,136 @@ pub enum SkillsCommand {
     Repos(SkillReposCommand),
 }
 
+/// `skills doctor --orphans` 对孤立 SSOT 目录的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OrphanAction {
+    /// Rebuild index records from SKILL.md
+    Register,
+    /// Remove the folders from the SSOT directory
+    Delete,
+}
+
+/// `skills discover` 的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DiscoverSort {
+    /// By skill name
+    Name,
+    /// By repository (owner/name), then skill name
+    Repo,
+}
+
+/// `skills import` 遇到同名技能时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SkillConflictStrategy {
+    /// Keep the local skill
+    Skip,
+    /// Replace the local skill with the bundled one
+    Overwrite,
+}
+
 #[derive(Subcommand)]
 pub enum SkillReposCommand {
     /// List all repositories
     List,
     /// Add a repository
     Add {
-        /// Repository (GitHub URL or owner/name[@branch])
+        /// Repository (GitHub URL or owner/name[@branch[,fallback...]])
         url: String,
+        /// Extra branches to try when the branch download fails (repeatable)
+        #[arg(long = "fallback-branch", value_name = "BRANCH")]
+        fallback_branches: Vec<String>,
     },
     /// Remove a repository
     Remove {
         /// Repository (GitHub URL or owner/name)
         url: String,
     },
+    /// Show or set the global fallback branches tried before main/master
+    FallbackBranches {
+        /// Branches to try in order (omit to show the current list)
+        branches: Vec<String>,
+        /// Clear the global fallback branches
+        #[arg(long, conflicts_with = "branches")]
+        clear: bool,
+    },
+}
+
+impl SkillsCommand {
+    /// 是否会修改配置（用于 `--read-only` 拦截）
+    pub fn is_mutation(&self) -> bool {
+        match self {
+            Self::List
+            | Self::Discover { .. }
+            | Self::ScanUnmanaged { .. }
+            | Self::Doctor { orphans: None, .. }
+            | Self::Info { .. }
+            | Self::Readme { .. }
+            | Self::Export { .. }
+            | Self::Open => false,
+            Self::Install { dry_run, .. } => !dry_run,
+            Self::SyncMethod { method } => method.is_some(),
+            Self::Repos(cmd) => !matches!(cmd, SkillReposCommand::List),
+            _ => true,
+        }
+    }
 }
 
 pub fn execute(cmd: SkillsCommand, app: Option<AppType>) -> Result<(), AppError> {
-    let app_type = app.clone().unwrap_or(AppType::Claude);
+    let app_type = crate::cli::resolve_app(app.clone());
 
     match cmd {
         SkillsCommand::List => list_installed(),
-        SkillsCommand::Discover { query } => discover_skills(query.as_deref()),
-        SkillsCommand::Install { spec } => install_skill(&app_type, &spec),
+        SkillsCommand::Discover {
+            query,
+            deep,
+            limit,
+            sort,
+            installed_only,
+            available_only,
+        } => discover_skills(
+            query.as_deref(),
+            DiscoverOptions {
+                limit,
+                sort,
+                deep,
+                installed: if installed_only {
+                    Some(true)
+                } else if available_only {
+                    Some(false)
+                } else {
+                    None
+                },
+            },
+        ),
+        SkillsCommand::Install { spec, dry_run } => {
+            if dry_run {
+                plan_install_skill(&app_type, &spec)
+            } else {
+                install_skill(&app_type, &spec)
+            }
+        }
         SkillsCommand::Uninstall { spec } => uninstall_skill(&spec),
-        SkillsCommand::Enable { spec } => toggle_skill(&app_type, &spec, true),
-        SkillsCommand::Disable { spec } => toggle_skill(&app_type, &spec, false),
+        SkillsCommand::Enable { spec, all: false } => toggle_skill(&app_type, &spec, true),
+        SkillsCommand::Disable { spec, all: false } => toggle_skill(&app_type, &spec, false),
+        SkillsCommand::Enable { spec, all: true } => toggle_skill_all_apps(&spec, true),
+        SkillsCommand::Disable { spec, all: true } => toggle_skill_all_apps(&spec, false),
         SkillsCommand::Sync => sync_skills(app.as_ref()),
-        SkillsCommand::ScanUnmanaged => scan_unmanaged(),
-        SkillsCommand::ImportFromApps { directories } => import_from_apps(directories),
+        SkillsCommand::ScanUnmanaged { follow_symlinks } => scan_unmanaged(follow_symlinks),
+        SkillsCommand::Doctor { json, orphans } => skills_doctor(json, orphans),
+        SkillsCommand::ImportFromApps { undo: true, .. } => undo_app_import(),
+        SkillsCommand::ImportFromApps {
+            directories, yes, ..
+        } => import_from_apps(directories, yes),
         SkillsCommand::Info { spec } => show_skill_info(&spec),
+        SkillsCommand::Readme { spec: None, .. } => list_readme_urls(),
+        SkillsCommand::Readme {
+            spec: Some(spec),
+            open,
+        } => show_readme_url(&spec, open),
+        SkillsCommand::Export { output } => export_bundle(&output),
+        SkillsCommand::Import { file, on_conflict } => import_bundle(&file, on_conflict),
         SkillsCommand::SyncMethod { method } => sync_method(method),
+        SkillsCommand::Open => open_ssot_dir(),
         SkillsCommand::Repos(repos_cmd) => execute_repos(repos_cmd),
     }
 }
@@ -129,32 +299,89 @@ fn list_installed() -> Result<(), AppError> {
     Ok(())
 }
 
-fn discover_skills(query: Option<&str>) -> Result<(), AppError> {
-    let service = SkillService::new()?;
-    let mut skills = run_async(service.list_skills())?;
+/// `skills discover` 结果的后处理选项
+#[derive(Debug, Clone, Copy)]
+struct DiscoverOptions {
+    limit: Option<usize>,
+    sort: DiscoverSort,
+    /// 查询同时匹配 SKILL.md 正文
+    deep: bool,
+    /// Some(true) 只保留已安装，Some(false) 只保留未安装
+    installed: Option<bool>,
+}
 
+fn skill_repo_label(skill: &Skill) -> String {
+    match (&skill.repo_owner, &skill.repo_name) {
+        (Some(owner), Some(name)) => format!("{owner}/{name}"),
+        _ => String::new(),
+    }
+}
+
+/// 按查询、安装状态过滤后排序并截断；返回保留的结果与截断前的数量
+fn select_discovered(
+    mut skills: Vec<Skill>,
+    query: Option<&str>,
+    options: DiscoverOptions,
+) -> (Vec<Skill>, usize) {
     if let Some(query) = query.map(str::trim).filter(|q| !q.is_empty()) {
         let q = query.to_lowercase();
         skills.retain(|s| {
-            s.name.to_lowercase().contains(&q) || s.directory.to_lowercase().contains(&q)
+            s.name.to_lowercase().contains(&q)
+                || s.directory.to_lowercase().contains(&q)
+                || (options.deep
+                    && s.body
+                        .as_deref()
+                        .is_some_and(|body| body.to_lowercase().contains(&q)))
         });
     }
+    if let Some(installed) = options.installed {
+        skills.retain(|s| s.installed == installed);
+    }
+
+    match options.sort {
+        DiscoverSort::Name => skills.sort_by_key(|s| s.name.to_lowercase()),
+        DiscoverSort::Repo => {
+            skills.sort_by_key(|s| (skill_repo_label(s).to_lowercase(), s.name.to_lowercase()))
+        }
+    }
+
+    let total = skills.len();
+    if let Some(limit) = options.limit {
+        skills.truncate(limit);
+    }
+    (skills, total)
+}
+
+fn discover_skills(query: Option<&str>, options: DiscoverOptions) -> Result<(), AppError> {
+    let service = SkillService::new()?;
+    let skills = if options.deep {
+        run_async(service.list_skills_with_body())?
+    } else {
+        run_async(service.list_skills())?
+    };
+    let (skills, total) = select_discovered(skills, query, options);
 
     if skills.is_empty() {
         println!("{}", info("No skills found."));
         return Ok(());
     }
 
+    let shown = skills.len();
     let mut table = create_table();
-    table.set_header(vec!["", "Directory", "Name"]);
+    table.set_header(vec!["", "Directory", "Name", "Repo"]);
     for skill in skills {
+        let repo = skill_repo_label(&skill);
         table.add_row(vec![
             if skill.installed { "✓" } else { " " }.to_string(),
             skill.directory,
             skill.name,
+            repo,
         ]);
     }
     println!("{}", table);
+    if shown < total {
+        println!("{}", info(&texts::skills_discover_truncated(shown, total)));
+    }
     Ok(())
 }
 
@@ -172,6 +399,35 @@ fn install_skill(app_type: &AppType, spec: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn plan_install_skill(app_type: &AppType, spec: &str) -> Result<(), AppError> {
+    let service = SkillService::new()?;
+    let plan = run_async(service.plan_install(spec, app_type))?;
+
+    println!("{}", highlight("Dry run: no files will be changed"));
+    println!("Skill:     {} ({})", plan.skill.name, plan.skill.key);
+    println!(
+        "Repo:      {}/{}@{}",
+        plan.skill.repo_owner, plan.skill.repo_name, plan.skill.repo_branch
+    );
+    println!("Install:   {}", plan.ssot_path.display());
+    println!(
+        "App path:  {} ({})",
+        plan.app_path.display(),
+        app_type.as_str()
+    );
+    if plan.already_installed {
+        println!(
+            "{}",
+            info(&format!(
+                "'{}' is already installed; install would only enable it for {}",
+                plan.install_name,
+                app_type.as_str()
+            ))
+        );
+    }
+    Ok(())
+}
+
 fn uninstall_skill(spec: &str) -> Result<(), AppError> {
     SkillService::uninstall(spec)?;
     println!("{}", success(&format!("✓ Uninstalled skill '{spec}'")));
@@ -192,14 +448,60 @@ fn toggle_skill(app_type: &AppType, spec: &str, enabled: bool) -> Result<(), App
     Ok(())
 }
 
+fn toggle_skill_all_apps(spec: &str, enabled: bool) -> Result<(), AppError> {
+    let report = SkillService::toggle_all_apps(spec, enabled)?;
+    print_sync_table(&report);
+    let summary = texts::skill_toggled_all_apps(spec, enabled, report.failed_count());
+    if report.failed_count() > 0 {
+        println!("{}", error(&summary));
+    } else {
+        println!("{}", success(&format!("✓ {summary}")));
+    }
+    report.ensure_no_failures()
+}
+
 fn sync_skills(app: Option<&AppType>) -> Result<(), AppError> {
-    SkillService::sync_all_enabled(app)?;
-    println!("{}", success("✓ Skills synced successfully"));
-    Ok(())
+    let report = SkillService::sync_all_enabled(app)?;
+    print_sync_report(&report);
+    report.ensure_no_failures()
+}
+
+/// 以表格形式输出 Skills 同步结果（CLI 与交互模式共用）
+pub fn print_sync_report(report: &SkillSyncReport) {
+    if report.entries.is_empty() {
+        println!("{}", info("No installed skills found."));
+        return;
+    }
+
+    print_sync_table(report);
+
+    let summary = texts::skills_sync_summary(
+        report.synced_count(),
+        report.skipped_count(),
+        report.failed_count(),
+    );
+    if report.failed_count() > 0 {
+        println!("{}", error(&summary));
+    } else {
+        println!("{}", success(&format!("✓ {summary}")));
+    }
+}
+
+fn print_sync_table(report: &SkillSyncReport) {
+    let mut table = create_table();
+    table.set_header(vec!["App", "Directory", "Result"]);
+    for entry in &report.entries {
+        table.add_row(vec![
+            entry.app.as_str().to_string(),
+            entry.directory.clone(),
+            texts::skills_sync_status_label(&entry.status),
+        ]);
+    }
+    println!("{}", table);
 }
 
-fn scan_unmanaged() -> Result<(), AppError> {
-    let skills = SkillService::scan_unmanaged()?;
+fn scan_unmanaged(follow_symlinks: bool) -> Result<(), AppError> {
+    let skills = SkillService::scan_unmanaged_with(follow_symlinks)?;
     if skills.is_empty() {
         println!("{}", info("No unmanaged skills found."));
         return Ok(());
@@ -214,18 +516,76 @@ fn scan_unmanaged() -> Result<(), AppError> {
     Ok(())
 }
 
-fn import_from_apps(directories: Vec<String>) -> Result<(), AppError> {
+fn skills_doctor(json: bool, orphans: Option<OrphanAction>) -> Result<(), AppError> {
+    match orphans {
+        Some(OrphanAction::Register) => {
+            let registered = SkillService::register_orphaned_ssot_skills()?;
+            if !json {
+                println!(
+                    "{}",
+                    success(&texts::skills_orphans_registered(registered.len()))
+                );
+            }
+        }
+        Some(OrphanAction::Delete) => {
+            let deleted = SkillService::delete_orphaned_ssot_skills()?;
+            if !json {
+                println!("{}", success(&texts::skills_orphans_deleted(deleted.len())));
+            }
+        }
+        None => {}
+    }
+
+    let report = DiagnosticReport::new("skills doctor", SkillService::doctor()?);
+    diagnostics::print_report(&report, json)
+}
+
+fn import_from_apps(directories: Vec<String>, yes: bool) -> Result<(), AppError> {
     if directories.is_empty() {
         return Err(AppError::InvalidInput(
             "Please provide at least one directory".to_string(),
         ));
     }
 
+    let preview = SkillService::preview_import_from_apps(&directories)?;
+    if !yes {
+        for skill in &preview {
+            println!("  {}  [{}]", skill.directory, skill.found_in.join(", "));
+        }
+        if !std::io::stdin().is_terminal() {
+            return Err(AppError::InvalidInput(
+                texts::skills_import_from_apps_requires_yes().to_string(),
+            ));
+        }
+        let confirmed = inquire::Confirm::new(&texts::skills_import_from_apps_confirm(&preview))
+            .with_default(false)
+            .prompt()
+            .map_err(|e| AppError::Message(format!("Prompt failed: {}", e)))?;
+        if !confirmed {
+            println!("{}", info(texts::cancelled()));
+            return Ok(());
+        }
+    }
+
     let imported = SkillService::import_from_apps(directories)?;
     println!(
         "{}",
         success(&format!("✓ Imported {} skill(s) into SSOT", imported.len()))
     );
+    println!("{}", info(texts::skills_import_undo_hint()));
+    Ok(())
+}
+
+fn undo_app_import() -> Result<(), AppError> {
+    let removed = SkillService::undo_last_app_import()?;
+    if removed.is_empty() {
+        println!("{}", info(texts::skills_import_undo_nothing()));
+        return Ok(());
+    }
+    for directory in &removed {
+        println!("  - {directory}");
+    }
+    println!("{}", success(&texts::skills_import_undone(removed.len())));
     Ok(())
 }
 
@@ -256,11 +616,121 @@ fn show_skill_info(spec: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn list_readme_urls() -> Result<(), AppError> {
+    let skills = SkillService::list_installed()?;
+    if skills.is_empty() {
+        println!("{}", info("No installed skills found."));
+        return Ok(());
+    }
+
+    let mut table = create_table();
+    table.set_header(vec!["Directory", "README"]);
+    for skill in skills {
+        table.add_row(vec![
+            skill.directory,
+            skill.readme_url.unwrap_or_else(|| "-".to_string()),
+        ]);
+    }
+    println!("{}", table);
+    Ok(())
+}
+
+/// 已安装技能优先使用记录的 README 链接，否则回退到仓库发现结果
+fn resolve_readme_url(spec: &str) -> Result<String, AppError> {
+    let spec = spec.trim();
+    if let Some(url) = SkillService::installed_readme_url(spec)? {
+        return Ok(url);
+    }
+
+    // 从仓库安装的 Skill，其 ID 即发现结果的 key
+    let service = SkillService::new()?;
+    let skills = run_async(service.list_skills())?;
+    skills
+        .into_iter()
+        .find(|s| s.key.eq_ignore_ascii_case(spec) || s.directory.eq_ignore_ascii_case(spec))
+        .and_then(|s| s.readme_url)
+        .ok_or_else(|| AppError::Message(format!("No README URL found for skill: {spec}")))
+}
+
+fn show_readme_url(spec: &str, open: bool) -> Result<(), AppError> {
+    let url = resolve_readme_url(spec)?;
+    println!("{url}");
+    if open {
+        crate::cli::opener::open_url(&url)?;
+    }
+    Ok(())
+}
+
+fn export_bundle(output: &Path) -> Result<(), AppError> {
+    let count = SkillService::export_bundle(output)?;
+    println!(
+        "{}",
+        success(&texts::skills_bundle_exported(
+            count,
+            &output.display().to_string()
+        ))
+    );
+    Ok(())
+}
+
+fn import_bundle(file: &Path, on_conflict: Option<SkillConflictStrategy>) -> Result<(), AppError> {
+    let interactive = std::io::stdin().is_terminal();
+    let mut prompt_error = None;
+    let mut decide = |directory: &str| match on_conflict {
+        Some(strategy) => strategy == SkillConflictStrategy::Overwrite,
+        None if interactive && prompt_error.is_none() => {
+            match inquire::Confirm::new(&texts::skills_bundle_overwrite_prompt(directory))
+                .with_default(false)
+                .prompt()
+            {
+                Ok(overwrite) => overwrite,
+                Err(e) => {
+                    prompt_error = Some(e);
+                    false
+                }
+            }
+        }
+        None => false,
+    };
+    let report = SkillService::import_bundle(file, &mut decide)?;
+    if let Some(e) = prompt_error {
+        log::warn!("Prompt failed, remaining conflicts were skipped: {e}");
+    }
+
+    println!(
+        "{}",
+        success(&texts::skills_bundle_imported(
+            report.imported.len(),
+            report.repos_added
+        ))
+    );
+    if !report.skipped.is_empty() {
+        println!(
+            "{}",
+            info(&texts::skills_bundle_skipped(&report.skipped.join(", ")))
+        );
+    }
+    if !report.missing.is_empty() {
+        println!(
+            "{}",
+            error(&texts::skills_bundle_missing(&report.missing.join(", ")))
+        );
+    }
+    print_sync_report(&report.sync);
+    report.sync.ensure_no_failures()
+}
+
 fn execute_repos(cmd: SkillReposCommand) -> Result<(), AppError> {
     match cmd {
         SkillReposCommand::List => list_repos(),
-        SkillReposCommand::Add { url } => add_repo(&url),
+        SkillReposCommand::Add {
+            url,
+            fallback_branches,
+        } => add_repo(&url, fallback_branches),
         SkillReposCommand::Remove { url } => remove_repo(&url),
+        SkillReposCommand::FallbackBranches { branches, clear } => {
+            global_fallback_branches(branches, clear)
+        }
     }
 }
 
@@ -273,20 +743,27 @@ fn list_repos() -> Result<(), AppError> {
     }
 
     let mut table = create_table();
-    table.set_header(vec!["Enabled", "Repo", "Branch"]);
+    table.set_header(vec!["Enabled", "Repo", "Branch", "Fallback"]);
     for repo in repos {
         table.add_row(vec![
             if repo.enabled { "✓" } else { " " }.to_string(),
             format!("{}/{}", repo.owner, repo.name),
             repo.branch,
+            repo.fallback_branches.join(", "),
         ]);
     }
     println!("{}", table);
     Ok(())
 }
 
-fn add_repo(_url: &str) -> Result<(), AppError> {
-    let repo = parse_repo_spec(_url)?;
+fn add_repo(_url: &str, fallback_branches: Vec<String>) -> Result<(), AppError> {
+    let mut repo = parse_repo_spec(_url)?;
+    for branch in fallback_branches {
+        let branch = branch.trim();
+        if !branch.is_empty() && !repo.fallback_branches.iter().any(|b| b == branch) {
+            repo.fallback_branches.push(branch.to_string());
+        }
+    }
     SkillService::upsert_repo(repo)?;
     println!("{}", success("✓ Repository added."));
     Ok(())
@@ -299,6 +776,51 @@ fn remove_repo(_url: &str) -> Result<(), AppError> {
     Ok(())
 }
 
+fn global_fallback_branches(branches: Vec<String>, clear: bool) -> Result<(), AppError> {
+    if clear {
+        crate::settings::set_skill_fallback_branches(Vec::new())?;
+        println!("{}", success("✓ Global fallback branches cleared."));
+        return Ok(());
+    }
+    if branches.is_empty() {
+        let current = crate::settings::get_skill_fallback_branches();
+        println!("{}", highlight("Global Fallback Branches"));
+        if current.is_empty() {
+            println!("(none; only main/master are tried)");
+        } else {
+            println!("{}", current.join(", "));
+        }
+        return Ok(());
+    }
+
+    let mut unique: Vec<String> = Vec::new();
+    for branch in branches.iter().flat_map(|b| b.split(',')).map(str::trim) {
+        if !branch.is_empty() && !unique.iter().any(|b| b == branch) {
+            unique.push(branch.to_string());
+        }
+    }
+    let branches = unique;
+    crate::settings::set_skill_fallback_branches(branches.clone())?;
+    println!(
+        "{}",
+        success(&format!(
+            "✓ Global fallback branches set to {}",
+            branches.join(", ")
+        ))
+    );
+    Ok(())
+}
+
+fn open_ssot_dir() -> Result<(), AppError> {
+    let ssot_dir = SkillService::get_ssot_dir()?;
+    crate::cli::opener::open_in_file_manager(&ssot_dir)?;
+    println!(
+        "{}",
+        success(&texts::opened_dir(&ssot_dir.display().to_string()))
+    );
+    Ok(())
+}
+
 fn sync_method(method: Option<SyncMethod>) -> Result<(), AppError> {
     match method {
         Some(method) => {
@@ -325,7 +847,7 @@ fn parse_repo_spec(raw: &str) -> Result<SkillRepo, AppError> {
         ));
     }
 
-    // Allow: https://github.com/owner/name or owner/name[@branch]
+    // Allow: https://github.com/owner/name or owner/name[@branch[,fallback...]]
     let without_prefix = raw
         .strip_prefix("https://github.com/")
         .or_else(|| raw.strip_prefix("http://github.com/"))
@@ -345,10 +867,113 @@ fn parse_repo_spec(raw: &str) -> Result<SkillRepo, AppError> {
         ));
     };
 
+    let (branch, fallback_branches) = SkillRepo::parse_branch_list(branch);
     Ok(SkillRepo {
         owner: owner.to_string(),
         name: name.to_string(),
-        branch: branch.unwrap_or("main").to_string(),
+        branch,
         enabled: true,
+        fallback_branches,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill(name: &str, repo: &str, installed: bool) -> Skill {
+        Skill {
+            key: format!("{repo}:{name}"),
+            name: name.to_string(),
+            description: String::new(),
+            directory: name.to_lowercase(),
+            readme_url: None,
+            installed,
+            repo_owner: Some(repo.split('/').next().unwrap_or_default().to_string()),
+            repo_name: Some(repo.split('/').nth(1).unwrap_or_default().to_string()),
+            repo_branch: None,
+            body: None,
+        }
+    }
+
+    fn names(skills: &[Skill]) -> Vec<&str> {
+        skills.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn select_discovered_filters_sorts_and_limits() {
+        let catalog = vec![
+            skill("Zeta", "acme/a", false),
+            skill("alpha", "zed/z", true),
+            skill("Beta", "acme/a", true),
+        ];
+        let options = DiscoverOptions {
+            limit: None,
+            sort: DiscoverSort::Name,
+            deep: false,
+            installed: None,
+        };
+
+        let (all, total) = select_discovered(catalog.clone(), None, options);
+        assert_eq!(names(&all), vec!["alpha", "Beta", "Zeta"]);
+        assert_eq!(total, 3);
+
+        let (by_repo, _) = select_discovered(
+            catalog.clone(),
+            None,
+            DiscoverOptions {
+                sort: DiscoverSort::Repo,
+                ..options
+            },
+        );
+        assert_eq!(names(&by_repo), vec!["Beta", "Zeta", "alpha"]);
+
+        let (installed, _) = select_discovered(
+            catalog.clone(),
+            None,
+            DiscoverOptions {
+                installed: Some(true),
+                limit: Some(1),
+                ..options
+            },
+        );
+        assert_eq!(names(&installed), vec!["alpha"]);
+
+        let (available, total) = select_discovered(
+            catalog,
+            Some("eta"),
+            DiscoverOptions {
+                installed: Some(false),
+                ..options
+            },
+        );
+        assert_eq!(names(&available), vec!["Zeta"]);
+        assert_eq!(total, 1);
+    }
+
+    #[test]
+    fn select_discovered_deep_matches_skill_body() {
+        let mut pdf = skill("Doc Tools", "acme/a", false);
+        pdf.body = Some("Extract tables from PDF files.".to_string());
+        let catalog = vec![pdf, skill("Other", "acme/a", false)];
+        let options = DiscoverOptions {
+            limit: None,
+            sort: DiscoverSort::Name,
+            deep: false,
+            installed: None,
+        };
+
+        let (shallow, _) = select_discovered(catalog.clone(), Some("pdf"), options);
+        assert!(shallow.is_empty());
+
+        let (deep, _) = select_discovered(
+            catalog,
+            Some("pdf"),
+            DiscoverOptions {
+                deep: true,
+                ..options
+            },
+        );
+        assert_eq!(names(&deep), vec!["Doc Tools"]);
+    }
+}
@@ -0,0 +1,211 @@
+use clap::{ArgGroup, Args};
+use std::path::PathBuf;
+
+use crate::app_config::AppType;
+use crate::cli::commands::provider::extract_api_url;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, info, success, warning};
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::{
+    provider_headers, EndpointLatency, ProviderService, SpeedtestOptions, SpeedtestService,
+};
+use crate::store::AppState;
+
+#[derive(Args, Debug, Clone)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .multiple(true)
+        .args(["provider", "url", "all", "file"])
+))]
+pub struct SpeedtestCommand {
+    /// Provider ID to test (uses --app to pick the app)
+    #[arg(long, value_name = "ID", conflicts_with = "all")]
+    pub provider: Option<String>,
+    /// Endpoint URL to test (repeatable)
+    #[arg(long, value_name = "URL")]
+    pub url: Vec<String>,
+    /// Test every provider of the app
+    #[arg(long)]
+    pub all: bool,
+    /// Read endpoint URLs from a file (one per line, `#` starts a comment)
+    #[arg(long, value_name = "PATH")]
+    pub file: Option<PathBuf>,
+    #[command(flatten)]
+    pub request: SpeedtestArgs,
+}
+
+/// 测速请求的超时与 User-Agent，所有测速相关命令共用
+#[derive(Args, Debug, Clone, Default)]
+pub struct SpeedtestArgs {
+    /// Request timeout in seconds, 2-30 (defaults to the `config speedtest` setting or 8)
+    #[arg(long, value_parser = clap::value_parser!(u64).range(2..=30))]
+    pub timeout: Option<u64>,
+    /// User-Agent header for the request (defaults to the `config speedtest` setting)
+    #[arg(long)]
+    pub user_agent: Option<String>,
+}
+
+impl SpeedtestArgs {
+    pub fn options(self) -> SpeedtestOptions {
+        SpeedtestOptions::default()
+            .with_timeout(self.timeout)
+            .with_user_agent(self.user_agent)
+    }
+}
+
+/// 一个待测目标：显示标签、端点 URL（供应商无 URL 时为 None）与附加请求头
+struct Target {
+    label: String,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+}
+
+impl Target {
+    fn provider(id: &str, provider: &Provider, app_type: &AppType) -> Self {
+        Self {
+            label: id.to_string(),
+            url: extract_api_url(&provider.settings_config, app_type),
+            headers: provider_headers(&provider.settings_config),
+        }
+    }
+}
+
+pub fn execute(cmd: SpeedtestCommand, app: Option<AppType>) -> Result<(), AppError> {
+    let app_type = crate::cli::resolve_app(app);
+    let options = cmd.request.clone().options();
+
+    let targets = collect_targets(&cmd, &app_type)?;
+    if targets.is_empty() {
+        println!("{}", info(texts::speedtest_no_targets()));
+        return Ok(());
+    }
+
+    let endpoints: Vec<(String, Vec<(String, String)>)> = targets
+        .iter()
+        .filter_map(|t| Some((t.url.clone()?, t.headers.clone())))
+        .collect();
+    println!(
+        "{}",
+        info(&texts::speedtest_testing_endpoints(endpoints.len()))
+    );
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
+    let mut results = runtime
+        .block_on(SpeedtestService::test_endpoints_with_headers(
+            endpoints, options,
+        ))?
+        .into_iter();
+
+    // test_endpoints 按输入顺序返回结果
+    let rows = targets
+        .into_iter()
+        .map(|target| {
+            let result = target.url.as_ref().and_then(|_| results.next());
+            (target.label, result)
+        })
+        .collect();
+    print_results(rows);
+    Ok(())
+}
+
+/// 输出测速结果表（目标、端点、延迟、状态）与汇总行；结果为 None 表示目标没有 URL
+pub(crate) fn print_results(rows: Vec<(String, Option<EndpointLatency>)>) {
+    let mut table = create_table();
+    table.set_header(vec!["Target", "Endpoint", "Latency", "Status"]);
+    let mut failed = 0;
+    for (label, result) in rows {
+        let (endpoint, latency, status) = match &result {
+            Some(result) => (
+                result.url.clone(),
+                latency_cell(result),
+                result
+                    .status
+                    .map(|s| s.to_string())
+                    .unwrap_or_else(|| "N/A".to_string()),
+            ),
+            None => (
+                "-".to_string(),
+                texts::failover_no_url().to_string(),
+                "N/A".to_string(),
+            ),
+        };
+        if result.as_ref().is_none_or(|r| r.latency.is_none()) {
+            failed += 1;
+        }
+        table.add_row(vec![label, endpoint, latency, status]);
+    }
+    println!("{}", table);
+
+    if failed == 0 {
+        println!("\n{}", success(texts::speedtest_completed()));
+    } else {
+        println!("\n{}", warning(&texts::speedtest_failed_count(failed)));
+    }
+}
+
+fn collect_targets(cmd: &SpeedtestCommand, app_type: &AppType) -> Result<Vec<Target>, AppError> {
+    let mut targets = Vec::new();
+
+    if cmd.provider.is_some() || cmd.all {
+        let state = AppState::try_new()?;
+        let providers = ProviderService::list(&state, app_type.clone())?;
+        if let Some(id) = &cmd.provider {
+            let provider = providers
+                .get(id)
+                .ok_or_else(|| AppError::Message(format!("Provider '{}' not found", id)))?;
+            targets.push(Target::provider(id, provider, app_type));
+        } else {
+            targets.extend(
+                providers
+                    .iter()
+                    .map(|(id, provider)| Target::provider(id, provider, app_type)),
+            );
+        }
+    }
+
+    let mut urls = cmd.url.clone();
+    if let Some(path) = &cmd.file {
+        let text = std::fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+        urls.extend(parse_url_list(&text));
+    }
+    targets.extend(urls.into_iter().map(|url| Target {
+        label: url.clone(),
+        url: Some(url),
+        headers: Vec::new(),
+    }));
+
+    Ok(targets)
+}
+
+/// 解析 `--file` 内容：每行一个 URL，忽略空行与 `#` 注释
+fn parse_url_list(text: &str) -> Vec<String> {
+    text.lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+pub(crate) fn latency_cell(result: &EndpointLatency) -> String {
+    match (result.latency, &result.error) {
+        (Some(ms), _) => format!("{} ms", ms),
+        (None, Some(err)) => err.clone(),
+        (None, None) => "Timeout".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_url_list_skips_blank_lines_and_comments() {
+        let text = "# endpoints\nhttps://a.example.com\n\n  https://b.example.com  # backup\n";
+        assert_eq!(
+            parse_url_list(text),
+            vec!["https://a.example.com", "https://b.example.com"]
+        );
+    }
+}
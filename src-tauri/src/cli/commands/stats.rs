@@ -0,0 +1,117 @@
+use clap::Args;
+use serde::Serialize;
+
+use crate::app_config::AppType;
+use crate::cli::commands::config::collect_current_config_summary;
+use crate::cli::i18n::texts;
+use crate::cli::ui::{create_table, highlight, to_json};
+use crate::error::AppError;
+use crate::services::{ConfigService, McpService, ProviderService, SkillService};
+use crate::store::AppState;
+
+#[derive(Args, Debug, Clone)]
+pub struct StatsCommand {
+    /// Print the counts as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// 所有应用的数量汇总（供应商、MCP、提示词、技能、仓库、备份）
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsSummary {
+    pub apps: Vec<AppStats>,
+    pub mcp_total: usize,
+    pub skills_installed: usize,
+    pub skill_repos: usize,
+    pub backups: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppStats {
+    pub app: String,
+    pub providers: usize,
+    pub current_provider: Option<String>,
+    pub mcp_enabled: usize,
+    pub prompts: usize,
+    pub active_prompt: Option<String>,
+    pub skills_enabled: usize,
+}
+
+pub fn collect_stats(state: &AppState) -> Result<StatsSummary, AppError> {
+    let skills = SkillService::list_installed()?;
+    let mut apps = Vec::new();
+    for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        let summary = collect_current_config_summary(state, &app_type)?;
+        apps.push(AppStats {
+            app: summary.app,
+            providers: ProviderService::list(state, app_type.clone())?.len(),
+            current_provider: summary.provider.map(|p| p.id),
+            mcp_enabled: summary.mcp_enabled,
+            prompts: summary.prompt_total,
+            active_prompt: summary.active_prompt.map(|p| p.id),
+            skills_enabled: skills
+                .iter()
+                .filter(|s| s.apps.is_enabled_for(&app_type))
+                .count(),
+        });
+    }
+
+    let config_path = crate::config::get_app_config_dir().join("cc-switch.db");
+    Ok(StatsSummary {
+        apps,
+        mcp_total: McpService::get_all_servers(state)?.len(),
+        skills_installed: skills.len(),
+        skill_repos: SkillService::list_repos()?.len(),
+        backups: ConfigService::list_backups(&config_path)?.len(),
+    })
+}
+
+pub fn execute(cmd: StatsCommand) -> Result<(), AppError> {
+    let state = AppState::try_new()?;
+    let stats = collect_stats(&state)?;
+
+    if cmd.json {
+        let output = to_json(&stats).map_err(|source| AppError::JsonSerialize { source })?;
+        println!("{}", output);
+        return Ok(());
+    }
+
+    println!("{}", highlight(texts::stats_title()));
+    let mut table = create_table();
+    table.set_header(vec![
+        texts::stats_header_app(),
+        texts::stats_header_providers(),
+        texts::stats_header_current(),
+        texts::stats_header_mcp_enabled(),
+        texts::stats_header_prompts(),
+        texts::stats_header_active_prompt(),
+        texts::stats_header_skills_enabled(),
+    ]);
+    for app in &stats.apps {
+        table.add_row(vec![
+            app.app.clone(),
+            app.providers.to_string(),
+            app.current_provider
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+            app.mcp_enabled.to_string(),
+            app.prompts.to_string(),
+            app.active_prompt.clone().unwrap_or_else(|| "-".to_string()),
+            app.skills_enabled.to_string(),
+        ]);
+    }
+    println!("{}", table);
+
+    println!();
+    for (label, count) in [
+        (texts::stats_mcp_servers(), stats.mcp_total),
+        (texts::stats_skills_installed(), stats.skills_installed),
+        (texts::stats_skill_repos(), stats.skill_repos),
+        (texts::stats_backups(), stats.backups),
+    ] {
+        println!("  {}: {}", label, count);
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// 命令行 `--since` 过滤参数的共享解析逻辑
+// 支持相对时长（`30m`、`24h`、`7d`、`2w`）与绝对时间（`2024-01-01`、RFC3339）
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone, Utc};
+
+/// 解析 `--since` 参数，返回对应的起始时间（UTC）。
+///
+/// 供 clap `value_parser` 直接使用，错误以字符串形式返回。
+pub fn parse_since(raw: &str) -> Result<DateTime<Utc>, String> {
+    parse_since_at(raw, Utc::now())
+}
+
+fn parse_since_at(raw: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let value = raw.trim();
+    if value.is_empty() {
+        return Err("time filter cannot be empty".to_string());
+    }
+
+    if let Some(duration) = parse_relative_duration(value) {
+        return Ok(now - duration);
+    }
+
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(value) {
+        return Ok(parsed.with_timezone(&Utc));
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is always a valid time");
+        // 绝对日期按本地时区的零点解释
+        if let Some(local) = Local.from_local_datetime(&midnight).earliest() {
+            return Ok(local.with_timezone(&Utc));
+        }
+        return Ok(Utc.from_utc_datetime(&midnight));
+    }
+
+    Err(format!(
+        "invalid time filter '{value}' (expected e.g. 24h, 7d, 2w or 2024-01-01)"
+    ))
+}
+
+/// 解析相对时长：`<数字><单位>`，单位为 m/h/d/w。
+fn parse_relative_duration(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    if amount < 0 {
+        return None;
+    }
+    match unit.to_ascii_lowercase() {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2024-06-15T12:00:00Z")
+            .expect("valid timestamp")
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn parse_since_accepts_relative_durations() {
+        let now = fixed_now();
+        assert_eq!(
+            parse_since_at("24h", now).unwrap(),
+            now - Duration::hours(24)
+        );
+        assert_eq!(parse_since_at("7d", now).unwrap(), now - Duration::days(7));
+        assert_eq!(parse_since_at("2W", now).unwrap(), now - Duration::weeks(2));
+        assert_eq!(
+            parse_since_at(" 30m ", now).unwrap(),
+            now - Duration::minutes(30)
+        );
+    }
+
+    #[test]
+    fn parse_since_accepts_absolute_dates() {
+        let now = fixed_now();
+        assert_eq!(
+            parse_since_at("2024-01-01T08:00:00+08:00", now).unwrap(),
+            DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc)
+        );
+
+        let expected_local = Local
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2024, 1, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_since_at("2024-01-01", now).unwrap(), expected_local);
+    }
+
+    #[test]
+    fn parse_since_rejects_invalid_input() {
+        let now = fixed_now();
+        for raw in ["", "7", "d", "-3d", "7y", "2024-13-01", "yesterday"] {
+            assert!(
+                parse_since_at(raw, now).is_err(),
+                "{raw} should be rejected"
+            );
+        }
+    }
+}
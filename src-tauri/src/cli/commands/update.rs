@@ -52,6 +52,7 @@ struct DownloadedAsset {
 }
 
 pub fn execute(cmd: UpdateCommand) -> Result<(), AppError> {
+    crate::settings::ensure_online()?;
     let runtime = create_runtime()?;
     runtime.block_on(execute_async(cmd))
 }
@@ -668,6 +669,7 @@ pub(crate) struct UpdateCheckInfo {
 }
 
 pub(crate) async fn check_for_update() -> Result<UpdateCheckInfo, AppError> {
+    crate::settings::ensure_online()?;
     let current_version = env!("CARGO_PKG_VERSION");
     let client = create_http_client()?;
     let target_tag = resolve_target_tag(&client, None).await?;
@@ -688,6 +690,7 @@ pub(crate) async fn download_and_apply(
     target_tag: &str,
     on_progress: impl Fn(u64, Option<u64>),
 ) -> Result<(), AppError> {
+    crate::settings::ensure_online()?;
     let client = create_http_client()?;
     let expected_asset_name = release_asset_name()?;
     let release = fetch_release_by_tag(&client, target_tag).await?;
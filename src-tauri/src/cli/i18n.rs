@@ -257,6 +257,46 @@ pub mod texts {
         }
     }
 
+    pub fn plain_select_prompt() -> &'static str {
+        if is_chinese() {
+            "输入编号（直接回车返回）："
+        } else {
+            "Enter a number (empty to go back): "
+        }
+    }
+
+    pub fn plain_multiselect_prompt() -> &'static str {
+        if is_chinese() {
+            "输入编号，逗号或空格分隔（直接回车表示不选，q 返回）："
+        } else {
+            "Enter numbers separated by commas or spaces (empty for none, q to go back): "
+        }
+    }
+
+    pub fn plain_multiselect_defaults_prompt() -> &'static str {
+        if is_chinese() {
+            "输入编号，逗号或空格分隔（直接回车保留 * 标记的选项，q 返回）："
+        } else {
+            "Enter numbers separated by commas or spaces (empty keeps the * items, q to go back): "
+        }
+    }
+
+    pub fn plain_invalid_choice(max: usize) -> String {
+        if is_chinese() {
+            format!("无效输入，请输入 1-{max} 之间的编号。")
+        } else {
+            format!("Invalid input; enter a number between 1 and {max}.")
+        }
+    }
+
+    pub fn plain_invalid_yes_no() -> &'static str {
+        if is_chinese() {
+            "请输入 y 或 n。"
+        } else {
+            "Please answer y or n."
+        }
+    }
+
     pub fn main_menu_search_prompt() -> &'static str {
         if is_chinese() {
             "输入搜索关键字（空或 Esc 清除过滤）："
@@ -558,9 +598,169 @@ pub mod texts {
 
     pub fn tui_help_text() -> &'static str {
         if is_chinese() {
-            "[ ]  切换应用\n←→  切换菜单/内容焦点\n↑↓  移动\n/   过滤\nEsc  返回\n?   显示/关闭帮助\n\n页面快捷键（在页面内容区顶部显示）：\n- Providers: Enter 详情，s 切换，a 添加，e 编辑，d 删除，t 测速\n- Provider Detail: s 切换，e 编辑，t 测速\n- MCP: x 启用/禁用(当前应用)，m 选择应用，a 添加，e 编辑，i 导入，v 校验命令，d 删除\n- Prompts: Enter 查看，a 激活，x 取消激活(当前)，e 编辑，d 删除\n- Skills: Enter 详情，x 启用/禁用(当前应用)，a 安装，d 卸载，f 发现，u 未管理，r 仓库，s 同步，m 同步方式\n- Config: Enter 打开/执行，e 编辑片段\n- Settings: Enter 应用"
+            "[ ]  切换应用\n←→  切换菜单/内容焦点\n↑↓  移动\n1-9  跳到第 N 行（多位数后按 Enter）\n/   过滤\nEsc  返回\nL   消息记录\n?   显示/关闭帮助\n\n页面快捷键（在页面内容区顶部显示）：\n- Providers: Enter 详情，s 切换，a 添加，e 编辑，d 删除，t 测速\n- Provider Detail: s 切换，e 编辑，t 测速，c 分享\n- MCP: x 启用/禁用(当前应用)，m 选择应用，a 添加，e 编辑，i 导入，v 校验命令，d 删除\n- Prompts: Enter 查看，a 激活，x 取消激活(当前)，e 编辑，d 删除\n- Skills: Enter 详情，x 启用/禁用(当前应用)，a 安装，d 卸载，f 发现，u 未管理，r 仓库，s 同步，m 同步方式\n- Config: Enter 打开/执行，e 编辑片段\n- Settings: Enter 应用"
+        } else {
+            "[ ]  switch app\n←→  focus menu/content\n↑↓  move\n1-9  jump to row N (Enter after multiple digits)\n/   filter\nEsc  back\nL   message log\n?   toggle help\n\nPage keys (shown at the top of each page):\n- Providers: Enter details, s switch, a add, e edit, d delete, t speedtest\n- Provider Detail: s switch, e edit, t speedtest, c share\n- MCP: x toggle current, m select apps, a add, e edit, i import, v validate, d delete\n- Prompts: Enter view, a activate, x deactivate active, e edit, d delete\n- Skills: Enter details, x toggle current, a install, d uninstall, f discover, u unmanaged, r repos, s sync, m sync method\n- Config: Enter open/run, e edit snippet\n- Settings: Enter apply"
+        }
+    }
+
+    pub fn tui_settings_toast_duration() -> &'static str {
+        if is_chinese() {
+            "提示显示时长"
+        } else {
+            "Toast duration"
+        }
+    }
+
+    pub fn tui_settings_toast_duration_prompt() -> &'static str {
+        if is_chinese() {
+            "基础显示秒数（1-60，留空恢复默认；警告/错误会停留更久）"
+        } else {
+            "Base seconds to show toasts (1-60, empty for default; warnings/errors stay longer)"
+        }
+    }
+
+    pub fn tui_settings_toast_duration_value(secs: Option<u64>) -> String {
+        match secs {
+            Some(secs) if is_chinese() => format!("{secs} 秒"),
+            Some(secs) => format!("{secs}s"),
+            None if is_chinese() => "默认".to_string(),
+            None => "Default".to_string(),
+        }
+    }
+
+    pub fn tui_settings_toast_position() -> &'static str {
+        if is_chinese() {
+            "提示显示位置"
+        } else {
+            "Toast position"
+        }
+    }
+
+    pub fn tui_toast_position_label(top: bool) -> &'static str {
+        match (top, is_chinese()) {
+            (true, true) => "顶部",
+            (true, false) => "Top",
+            (false, true) => "底部",
+            (false, false) => "Bottom",
+        }
+    }
+
+    pub fn tui_toast_toast_duration_set(secs: Option<u64>) -> String {
+        let value = tui_settings_toast_duration_value(secs);
+        if is_chinese() {
+            format!("✓ 提示显示时长: {value}")
+        } else {
+            format!("✓ Toast duration: {value}")
+        }
+    }
+
+    pub fn tui_toast_toast_position_set(position: &str) -> String {
+        if is_chinese() {
+            format!("✓ 提示显示位置: {position}")
+        } else {
+            format!("✓ Toast position: {position}")
+        }
+    }
+
+    pub fn tui_settings_tick_rate() -> &'static str {
+        if is_chinese() {
+            "刷新间隔"
+        } else {
+            "Tick rate"
+        }
+    }
+
+    pub fn tui_settings_tick_rate_prompt(min: u64, max: u64) -> String {
+        if is_chinese() {
+            format!(
+                "事件循环间隔毫秒（{min}-{max}，留空恢复默认；SSH 慢速连接可调大，环境变量 CC_SWITCH_TUI_TICK_MS 优先）"
+            )
+        } else {
+            format!(
+                "Event loop interval in ms ({min}-{max}, empty for default; raise it on slow SSH links; CC_SWITCH_TUI_TICK_MS overrides)"
+            )
+        }
+    }
+
+    pub fn tui_settings_tick_rate_value(ms: Option<u64>) -> String {
+        match ms {
+            Some(ms) => format!("{ms} ms"),
+            None if is_chinese() => "默认".to_string(),
+            None => "Default".to_string(),
+        }
+    }
+
+    pub fn tui_toast_tick_rate_set(ms: Option<u64>) -> String {
+        let value = tui_settings_tick_rate_value(ms);
+        if is_chinese() {
+            format!("✓ 刷新间隔: {value}")
+        } else {
+            format!("✓ Tick rate: {value}")
+        }
+    }
+
+    pub fn tui_toast_invalid_tick_rate(min: u64, max: u64) -> String {
+        if is_chinese() {
+            format!("请输入 {min}-{max} 之间的毫秒数")
+        } else {
+            format!("Enter a number of milliseconds between {min} and {max}")
+        }
+    }
+
+    pub fn tui_toast_invalid_toast_duration(max: u64) -> String {
+        if is_chinese() {
+            format!("请输入 1-{max} 之间的秒数")
+        } else {
+            format!("Enter a number of seconds between 1 and {max}")
+        }
+    }
+
+    pub fn tui_toast_log_title() -> &'static str {
+        if is_chinese() {
+            "消息记录"
+        } else {
+            "Message Log"
+        }
+    }
+
+    pub fn tui_toast_log_empty() -> &'static str {
+        if is_chinese() {
+            "暂无消息"
+        } else {
+            "No messages yet"
+        }
+    }
+
+    pub fn tui_toast_kind_info() -> &'static str {
+        if is_chinese() {
+            "信息"
+        } else {
+            "Info"
+        }
+    }
+
+    pub fn tui_toast_kind_success() -> &'static str {
+        if is_chinese() {
+            "成功"
+        } else {
+            "Success"
+        }
+    }
+
+    pub fn tui_toast_kind_warning() -> &'static str {
+        if is_chinese() {
+            "警告"
+        } else {
+            "Warning"
+        }
+    }
+
+    pub fn tui_toast_kind_error() -> &'static str {
+        if is_chinese() {
+            "错误"
         } else {
-            "[ ]  switch app\n←→  focus menu/content\n↑↓  move\n/   filter\nEsc  back\n?   toggle help\n\nPage keys (shown at the top of each page):\n- Providers: Enter details, s switch, a add, e edit, d delete, t speedtest\n- Provider Detail: s switch, e edit, t speedtest\n- MCP: x toggle current, m select apps, a add, e edit, i import, v validate, d delete\n- Prompts: Enter view, a activate, x deactivate active, e edit, d delete\n- Skills: Enter details, x toggle current, a install, d uninstall, f discover, u unmanaged, r repos, s sync, m sync method\n- Config: Enter open/run, e edit snippet\n- Settings: Enter apply"
+            "Error"
         }
     }
 
@@ -624,6 +824,26 @@ pub mod texts {
         "JSON"
     }
 
+    pub fn tui_editor_toml_field_title() -> &'static str {
+        "TOML"
+    }
+
+    pub fn tui_editor_validation_ok() -> &'static str {
+        if is_chinese() {
+            "格式正确"
+        } else {
+            "valid"
+        }
+    }
+
+    pub fn tui_editor_validation_error(line: usize, column: usize, message: &str) -> String {
+        if is_chinese() {
+            format!("第 {line} 行第 {column} 列: {message}")
+        } else {
+            format!("line {line}, col {column}: {message}")
+        }
+    }
+
     pub fn tui_editor_hint_view() -> &'static str {
         if is_chinese() {
             "Enter 编辑  ↑↓ 滚动  Ctrl+S 保存  Esc 返回"
@@ -836,6 +1056,22 @@ pub mod texts {
         "API URL"
     }
 
+    pub fn tui_header_latency() -> &'static str {
+        if is_chinese() {
+            "延迟"
+        } else {
+            "Latency"
+        }
+    }
+
+    pub fn tui_header_status() -> &'static str {
+        if is_chinese() {
+            "状态"
+        } else {
+            "Status"
+        }
+    }
+
     pub fn tui_header_directory() -> &'static str {
         if is_chinese() {
             "目录"
@@ -984,27 +1220,31 @@ pub mod texts {
         }
     }
 
-    pub fn tui_label_app_claude() -> &'static str {
+    pub fn tui_label_transport() -> &'static str {
         if is_chinese() {
-            "应用: Claude"
+            "传输方式"
         } else {
-            "App: Claude"
+            "Transport"
         }
     }
 
-    pub fn tui_label_app_codex() -> &'static str {
+    pub fn tui_label_url() -> &'static str {
+        "URL"
+    }
+
+    pub fn tui_label_apps() -> &'static str {
         if is_chinese() {
-            "应用: Codex"
+            "应用"
         } else {
-            "App: Codex"
+            "Apps"
         }
     }
 
-    pub fn tui_label_app_gemini() -> &'static str {
+    pub fn tui_mcp_apps_hint() -> &'static str {
         if is_chinese() {
-            "应用: Gemini"
+            "←/→ 选择  空格 切换  a 全选/全不选"
         } else {
-            "App: Gemini"
+            "←/→ select  Space toggle  a all/none"
         }
     }
 
@@ -1198,9 +1438,9 @@ pub mod texts {
 
     pub fn tui_provider_detail_keys() -> &'static str {
         if is_chinese() {
-            "按键：s=切换  e=编辑  t=测速"
+            "按键：s=切换  e=编辑  t=测速  c=分享"
         } else {
-            "Keys: s=switch  e=edit  t=speedtest"
+            "Keys: s=switch  e=edit  t=speedtest  c=share"
         }
     }
 
@@ -1228,6 +1468,72 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_share() -> &'static str {
+        if is_chinese() {
+            "分享"
+        } else {
+            "share"
+        }
+    }
+
+    pub fn tui_provider_share_title(name: &str) -> String {
+        if is_chinese() {
+            format!("分享供应商（已清空密钥）: {name}")
+        } else {
+            format!("Share Provider (secrets blanked): {name}")
+        }
+    }
+
+    pub fn tui_key_failover_queue() -> &'static str {
+        if is_chinese() {
+            "备用队列"
+        } else {
+            "failover queue"
+        }
+    }
+
+    pub fn tui_key_failover() -> &'static str {
+        if is_chinese() {
+            "故障转移"
+        } else {
+            "failover"
+        }
+    }
+
+    pub fn tui_expiry_badge() -> &'static str {
+        if is_chinese() {
+            " ⚠ 密钥到期"
+        } else {
+            " ⚠ key expiry"
+        }
+    }
+
+    pub fn tui_failover_badge() -> &'static str {
+        if is_chinese() {
+            "（备用）"
+        } else {
+            " (failover)"
+        }
+    }
+
+    pub fn tui_key_speedtest_all() -> &'static str {
+        if is_chinese() {
+            "全部测速"
+        } else {
+            "test all"
+        }
+    }
+
+    /// 排序键提示：显示按下后切换到的排序方式
+    pub fn tui_key_speedtest_sort(sorted_by_latency: bool) -> &'static str {
+        match (is_chinese(), sorted_by_latency) {
+            (true, true) => "按状态排序",
+            (true, false) => "按延迟排序",
+            (false, true) => "sort by status",
+            (false, false) => "sort by latency",
+        }
+    }
+
     pub fn tui_key_details() -> &'static str {
         if is_chinese() {
             "详情"
@@ -1348,6 +1654,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_reveal() -> &'static str {
+        if is_chinese() {
+            "显示/隐藏"
+        } else {
+            "show/hide"
+        }
+    }
+
     pub fn tui_key_toggle() -> &'static str {
         if is_chinese() {
             "启用/禁用"
@@ -1380,6 +1694,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_activate_append() -> &'static str {
+        if is_chinese() {
+            "追加启用"
+        } else {
+            "activate (append)"
+        }
+    }
+
     pub fn tui_key_deactivate() -> &'static str {
         if is_chinese() {
             "取消激活"
@@ -1396,6 +1718,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_open_dir() -> &'static str {
+        if is_chinese() {
+            "打开目录"
+        } else {
+            "open dir"
+        }
+    }
+
     pub fn tui_key_apply() -> &'static str {
         if is_chinese() {
             "应用"
@@ -1404,6 +1734,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_key_preview() -> &'static str {
+        if is_chinese() {
+            "预览"
+        } else {
+            "preview"
+        }
+    }
+
     pub fn tui_key_edit_snippet() -> &'static str {
         if is_chinese() {
             "编辑片段"
@@ -1706,6 +2044,20 @@ pub mod texts {
         }
     }
 
+    pub fn tui_skills_discover_progress(done: usize, total: usize, pending: &str) -> String {
+        if is_chinese() {
+            if pending.is_empty() {
+                format!("已获取 {done}/{total} 个仓库，正在整理结果…")
+            } else {
+                format!("已获取 {done}/{total} 个仓库\n正在获取: {pending}")
+            }
+        } else if pending.is_empty() {
+            format!("Fetched {done}/{total} repos, collecting results…")
+        } else {
+            format!("Fetched {done}/{total} repos\nFetching: {pending}")
+        }
+    }
+
     pub fn tui_skills_discover_prompt() -> &'static str {
         if is_chinese() {
             "搜索关键字（留空显示全部）："
@@ -2101,6 +2453,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_config_item_reset_app() -> &'static str {
+        if is_chinese() {
+            "仅重置当前应用"
+        } else {
+            "Reset Current App"
+        }
+    }
+
     pub fn tui_config_item_reset() -> &'static str {
         if is_chinese() {
             "重置配置"
@@ -2117,6 +2477,23 @@ pub mod texts {
         }
     }
 
+    pub fn tui_config_item_edit_in_editor() -> &'static str {
+        if is_chinese() {
+            "在编辑器中编辑配置"
+        } else {
+            "Edit Config in $EDITOR"
+        }
+    }
+
+    pub fn tui_toast_config_edited(backup_id: &str) -> String {
+        match (is_chinese(), backup_id.is_empty()) {
+            (true, true) => "✓ 已应用编辑后的配置".to_string(),
+            (true, false) => format!("✓ 已应用编辑后的配置（编辑前备份: {backup_id}）"),
+            (false, true) => "✓ Applied the edited config".to_string(),
+            (false, false) => format!("✓ Applied the edited config (backup: {backup_id})"),
+        }
+    }
+
     pub fn tui_config_item_show_path() -> &'static str {
         if is_chinese() {
             "显示配置路径"
@@ -2165,6 +2542,22 @@ pub mod texts {
         }
     }
 
+    pub fn tui_failover_label(count: usize) -> String {
+        if is_chinese() {
+            format!("故障转移队列（{count} 个供应商）")
+        } else {
+            format!("failover queue ({count} providers)")
+        }
+    }
+
+    pub fn tui_speedtest_all_label(count: usize) -> String {
+        if is_chinese() {
+            format!("{count} 个端点")
+        } else {
+            format!("{count} endpoints")
+        }
+    }
+
     pub fn tui_speedtest_title_with_url(url: &str) -> String {
         if is_chinese() {
             format!("测速: {}", url)
@@ -2437,6 +2830,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_common_snippet_diff_title(app: &str, provider_id: &str) -> String {
+        if is_chinese() {
+            format!("通用片段预演 ({}) · {}", app, provider_id)
+        } else {
+            format!("Common Snippet Dry Run ({}) · {}", app, provider_id)
+        }
+    }
+
     pub fn tui_config_reset_title() -> &'static str {
         if is_chinese() {
             "重置配置"
@@ -2485,6 +2886,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_mcp_invalid_url() -> &'static str {
+        if is_chinese() {
+            "URL 无效（需为 http:// 或 https:// 地址）。"
+        } else {
+            "Invalid URL (must be an http:// or https:// address)."
+        }
+    }
+
     pub fn tui_confirm_restore_backup_title() -> &'static str {
         if is_chinese() {
             "恢复备份"
@@ -2501,31 +2910,44 @@ pub mod texts {
         }
     }
 
-    pub fn tui_speedtest_line_url(url: &str) -> String {
-        format!("URL: {}", url)
+    pub fn tui_toast_failover_queue_empty() -> &'static str {
+        if is_chinese() {
+            "故障转移队列为空：先用 f 把备用供应商加入队列。"
+        } else {
+            "Failover queue is empty: press f to add backup providers first."
+        }
+    }
+
+    pub fn tui_toast_failover_queued(name: &str, queued: bool) -> String {
+        match (is_chinese(), queued) {
+            (true, true) => format!("已将 {name} 加入故障转移队列。"),
+            (true, false) => format!("已将 {name} 移出故障转移队列。"),
+            (false, true) => format!("Added {name} to the failover queue."),
+            (false, false) => format!("Removed {name} from the failover queue."),
+        }
     }
 
-    pub fn tui_speedtest_line_latency(latency: &str) -> String {
+    pub fn tui_toast_failover_switched(name: &str) -> String {
         if is_chinese() {
-            format!("延迟:   {latency}")
+            format!("✓ 已切换到最快的可用供应商：{name}。")
         } else {
-            format!("Latency: {latency}")
+            format!("✓ Switched to the fastest healthy provider: {name}.")
         }
     }
 
-    pub fn tui_speedtest_line_status(status: &str) -> String {
+    pub fn tui_toast_failover_already_fastest(name: &str) -> String {
         if is_chinese() {
-            format!("状态:   {status}")
+            format!("当前供应商 {name} 已是最快的可用供应商。")
         } else {
-            format!("Status:  {status}")
+            format!("Current provider {name} is already the fastest healthy one.")
         }
     }
 
-    pub fn tui_speedtest_line_error(err: &str) -> String {
+    pub fn tui_toast_failover_no_healthy() -> &'static str {
         if is_chinese() {
-            format!("错误:   {err}")
+            "故障转移队列中没有可用的供应商，未切换。"
         } else {
-            format!("Error:   {err}")
+            "No healthy provider in the failover queue; nothing was switched."
         }
     }
 
@@ -2545,67 +2967,131 @@ pub mod texts {
         }
     }
 
-    pub fn tui_toast_speedtest_unavailable(err: &str) -> String {
+    pub fn tui_startup_summary_title() -> &'static str {
         if is_chinese() {
-            format!("测速不可用: {err}")
+            "启动概览"
         } else {
-            format!("Speedtest unavailable: {err}")
+            "Startup summary"
         }
     }
 
-    pub fn tui_toast_speedtest_disabled() -> &'static str {
+    pub fn tui_key_any() -> &'static str {
         if is_chinese() {
-            "本次会话测速不可用。"
+            "任意键"
         } else {
-            "Speedtest is disabled for this session."
+            "Any key"
         }
     }
 
-    pub fn tui_toast_local_env_check_unavailable(err: &str) -> String {
+    pub fn tui_key_continue() -> &'static str {
         if is_chinese() {
-            format!("本地环境检查不可用: {err}")
+            "继续"
         } else {
-            format!("Local environment check unavailable: {err}")
+            "continue"
         }
     }
 
-    pub fn tui_toast_local_env_check_disabled() -> &'static str {
+    pub fn tui_startup_speedtest() -> &'static str {
         if is_chinese() {
-            "本次会话本地环境检查不可用。"
+            "测速后台任务"
         } else {
-            "Local environment check is disabled for this session."
+            "Speedtest worker"
         }
     }
 
-    pub fn tui_toast_local_env_check_request_failed(err: &str) -> String {
+    pub fn tui_startup_skills() -> &'static str {
         if is_chinese() {
-            format!("本地环境检查刷新请求失败: {err}")
+            "Skills 后台任务"
         } else {
-            format!("Failed to enqueue local environment check: {err}")
+            "Skills worker"
         }
     }
 
-    pub fn tui_toast_speedtest_request_failed(err: &str) -> String {
+    pub fn tui_startup_local_env() -> &'static str {
         if is_chinese() {
-            format!("测速请求失败: {err}")
+            "本地环境检查"
         } else {
-            format!("Failed to enqueue speedtest: {err}")
+            "Local environment check"
+        }
+    }
+
+    pub fn tui_startup_webdav() -> &'static str {
+        if is_chinese() {
+            "WebDAV 后台任务"
+        } else {
+            "WebDAV worker"
+        }
+    }
+
+    pub fn tui_startup_update() -> &'static str {
+        if is_chinese() {
+            "更新检查"
+        } else {
+            "Update checker"
+        }
+    }
+
+    pub fn tui_startup_network() -> &'static str {
+        if is_chinese() {
+            "网络"
+        } else {
+            "Network"
+        }
+    }
+
+    pub fn tui_startup_offline_mode() -> &'static str {
+        if is_chinese() {
+            "离线模式已启用，测速、Skills 发现与更新检查将被跳过"
+        } else {
+            "offline mode is on; speedtest, skills discovery and update checks are skipped"
+        }
+    }
+
+    pub fn tui_startup_app_config(app: &str) -> String {
+        if is_chinese() {
+            format!("{app} 配置目录")
+        } else {
+            format!("{app} config directory")
+        }
+    }
+
+    pub fn tui_startup_app_not_initialized() -> &'static str {
+        if is_chinese() {
+            "未初始化，切换供应商时不会写入 live 配置"
+        } else {
+            "not initialized; switching providers won't write live config"
+        }
+    }
+
+    pub fn tui_toast_speedtest_disabled() -> &'static str {
+        if is_chinese() {
+            "本次会话测速不可用。"
+        } else {
+            "Speedtest is disabled for this session."
+        }
+    }
+
+    pub fn tui_toast_local_env_check_disabled() -> &'static str {
+        if is_chinese() {
+            "本次会话本地环境检查不可用。"
+        } else {
+            "Local environment check is disabled for this session."
         }
     }
 
-    pub fn tui_toast_skills_worker_unavailable(err: &str) -> String {
+    pub fn tui_toast_local_env_check_request_failed(err: &str) -> String {
         if is_chinese() {
-            format!("Skills 后台任务不可用: {err}")
+            format!("本地环境检查刷新请求失败: {err}")
         } else {
-            format!("Skills worker unavailable: {err}")
+            format!("Failed to enqueue local environment check: {err}")
         }
     }
 
-    pub fn tui_toast_webdav_worker_unavailable(err: &str) -> String {
+    pub fn tui_toast_speedtest_request_failed(err: &str) -> String {
         if is_chinese() {
-            format!("WebDAV 后台任务不可用: {err}")
+            format!("测速请求失败: {err}")
         } else {
-            format!("WebDAV worker unavailable: {err}")
+            format!("Failed to enqueue speedtest: {err}")
         }
     }
 
@@ -2641,6 +3127,14 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_skills_discover_cancelled() -> &'static str {
+        if is_chinese() {
+            "已取消发现"
+        } else {
+            "Discover cancelled"
+        }
+    }
+
     pub fn tui_toast_skill_installed(directory: &str) -> String {
         if is_chinese() {
             format!("已安装: {directory}")
@@ -2684,6 +3178,23 @@ pub mod texts {
         }
     }
 
+    pub fn skill_toggled_all_apps(directory: &str, enabled: bool, failed: usize) -> String {
+        match (is_chinese(), enabled) {
+            (true, true) => format!("已为所有应用启用 {directory}（失败 {failed} 项）"),
+            (true, false) => format!("已为所有应用禁用 {directory}（失败 {failed} 项）"),
+            (false, true) => format!("Enabled {directory} for all apps ({failed} failed)"),
+            (false, false) => format!("Disabled {directory} for all apps ({failed} failed)"),
+        }
+    }
+
+    pub fn tui_key_toggle_all_apps() -> &'static str {
+        if is_chinese() {
+            "全部应用"
+        } else {
+            "all apps"
+        }
+    }
+
     pub fn tui_toast_skill_uninstalled(directory: &str) -> String {
         if is_chinese() {
             format!("已卸载: {directory}")
@@ -2692,11 +3203,74 @@ pub mod texts {
         }
     }
 
-    pub fn tui_toast_skills_synced() -> &'static str {
+    pub fn skills_sync_status_label(status: &crate::services::skill::SkillSyncStatus) -> String {
+        use crate::services::skill::SkillSyncStatus;
+        let zh = is_chinese();
+        match status {
+            SkillSyncStatus::Symlinked => if zh { "符号链接" } else { "symlink" }.to_string(),
+            SkillSyncStatus::Copied => if zh { "复制" } else { "copy" }.to_string(),
+            SkillSyncStatus::Skipped => if zh {
+                "跳过（未启用）"
+            } else {
+                "skipped (disabled)"
+            }
+            .to_string(),
+            SkillSyncStatus::Removed => if zh { "已移除" } else { "removed" }.to_string(),
+            SkillSyncStatus::Failed(err) => {
+                if zh {
+                    format!("失败: {err}")
+                } else {
+                    format!("failed: {err}")
+                }
+            }
+        }
+    }
+
+    pub fn skills_orphans_registered(count: usize) -> String {
+        if is_chinese() {
+            format!("✓ 已重新登记 {count} 个孤立的 Skill（未启用任何应用）")
+        } else {
+            format!("✓ Re-registered {count} orphaned skill(s) (not enabled for any app)")
+        }
+    }
+
+    pub fn skills_orphans_deleted(count: usize) -> String {
+        if is_chinese() {
+            format!("✓ 已删除 {count} 个孤立的 Skill 目录")
+        } else {
+            format!("✓ Deleted {count} orphaned skill folder(s)")
+        }
+    }
+
+    pub fn skills_discover_truncated(shown: usize, total: usize) -> String {
+        if is_chinese() {
+            format!("显示 {shown} / {total} 个（使用 --limit 调整）")
+        } else {
+            format!("Showing {shown} of {total} (adjust with --limit)")
+        }
+    }
+
+    pub fn skills_sync_summary(synced: usize, skipped: usize, failed: usize) -> String {
+        if is_chinese() {
+            format!("已同步 {synced} 项，跳过 {skipped} 项，失败 {failed} 项")
+        } else {
+            format!("{synced} synced, {skipped} skipped, {failed} failed")
+        }
+    }
+
+    pub fn tui_toast_skills_sync_partial_failure(failed: usize) -> String {
+        if is_chinese() {
+            format!("{failed} 个 Skill 同步失败，其余已继续同步。")
+        } else {
+            format!("{failed} skill sync(s) failed; the rest were still synced.")
+        }
+    }
+
+    pub fn tui_skills_sync_report_title() -> &'static str {
         if is_chinese() {
-            "Skills 同步完成。"
+            "Skills 同步结果"
         } else {
-            "Skills synced."
+            "Skills Sync Report"
         }
     }
 
@@ -2764,6 +3338,64 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_sync_skills_on_switch_toggled(enabled: bool) -> String {
+        match (is_chinese(), enabled) {
+            (true, true) => "已启用切换后同步 Skills。".to_string(),
+            (true, false) => "已关闭切换后同步 Skills。".to_string(),
+            (false, true) => "Skills will be synced after each provider switch.".to_string(),
+            (false, false) => "Sync skills on switch disabled.".to_string(),
+        }
+    }
+
+    pub fn tui_toast_quiet_skill_fallback_toggled(quiet: bool) -> String {
+        match (is_chinese(), quiet) {
+            (true, true) => "Skill 复制回退日志已静默。".to_string(),
+            (true, false) => "Skill 首次复制回退时将记录警告。".to_string(),
+            (false, true) => "Skill copy fallback logging silenced.".to_string(),
+            (false, false) => "Skills will warn on their first copy fallback.".to_string(),
+        }
+    }
+
+    pub fn tui_toast_offline_mode_toggled(enabled: bool) -> String {
+        if is_chinese() {
+            if enabled {
+                "已启用离线模式，网络功能已禁用。".to_string()
+            } else {
+                "已关闭离线模式。".to_string()
+            }
+        } else {
+            if enabled {
+                "Offline mode enabled; network features are disabled.".to_string()
+            } else {
+                "Offline mode disabled.".to_string()
+            }
+        }
+    }
+
+    pub fn tui_toast_clipboard_unavailable(err: &str) -> String {
+        if is_chinese() {
+            format!("无法读取剪贴板：{err}")
+        } else {
+            format!("Failed to read clipboard: {err}")
+        }
+    }
+
+    pub fn tui_toast_offline_mode_blocked() -> &'static str {
+        if is_chinese() {
+            "离线模式已启用，该操作需要网络。"
+        } else {
+            "Offline mode is enabled; this action requires network access."
+        }
+    }
+
+    pub fn tui_toast_opened_dir(path: &str) -> String {
+        if is_chinese() {
+            format!("已在文件管理器中打开: {path}")
+        } else {
+            format!("Opened in file manager: {path}")
+        }
+    }
+
     pub fn tui_toast_skip_claude_onboarding_toggled(enabled: bool) -> String {
         if is_chinese() {
             if enabled {
@@ -2892,11 +3524,27 @@ pub mod texts {
         }
     }
 
-    pub fn tui_toast_mcp_imported(count: usize) -> String {
+    pub fn tui_toast_mcp_imported(added: usize, merged: usize) -> String {
+        if is_chinese() {
+            format!("已导入 {added} 个新 MCP 服务器，合并 {merged} 个重复项。")
+        } else {
+            format!("Imported {added} new MCP server(s), merged {merged} duplicate(s).")
+        }
+    }
+
+    pub fn tui_confirm_live_sync_skip_title() -> &'static str {
         if is_chinese() {
-            format!("已导入 {count} 个 MCP 服务器。")
+            "应用未初始化"
         } else {
-            format!("Imported {count} MCP server(s).")
+            "App Not Initialized"
+        }
+    }
+
+    pub fn tui_confirm_live_sync_skip_message(app: &str) -> String {
+        if is_chinese() {
+            format!("未检测到 {app} 客户端本地配置，此更改只会保存到 cc-switch，不会写入 live 文件；运行一次 {app} 完成初始化后才会生效。仍要继续吗？")
+        } else {
+            format!("{app} has no local config yet, so this change is saved in cc-switch only and won't reach the live files until {app} has been run once. Continue anyway?")
         }
     }
 
@@ -2928,6 +3576,21 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_mcp_apps_partial_failure(succeeded: &[&str], failed: &[String]) -> String {
+        let none = if is_chinese() { "无" } else { "none" };
+        let succeeded = if succeeded.is_empty() {
+            none.to_string()
+        } else {
+            succeeded.join(", ")
+        };
+        let failed = failed.join(", ");
+        if is_chinese() {
+            format!("MCP 应用部分更新失败。成功：{succeeded}；失败：{failed}")
+        } else {
+            format!("MCP apps partially updated. Succeeded: {succeeded}; failed: {failed}")
+        }
+    }
+
     pub fn tui_toast_command_available_in_path(command: &str) -> String {
         if is_chinese() {
             format!("✓ 命令 '{command}' 在 PATH 中可用")
@@ -2944,6 +3607,50 @@ pub mod texts {
         }
     }
 
+    pub fn mcp_check_no_servers() -> &'static str {
+        if is_chinese() {
+            "没有需要检查的 MCP 服务器"
+        } else {
+            "No MCP servers to check"
+        }
+    }
+
+    pub fn mcp_check_missing(missing: usize, total: usize) -> String {
+        if is_chinese() {
+            format!(
+                "{total} 个 MCP 服务器中有 {missing} 个检查未通过（命令不在 PATH 中或 url 无效）"
+            )
+        } else {
+            format!(
+                "{missing} of {total} MCP server(s) failed the check (command not in PATH or invalid URL)"
+            )
+        }
+    }
+
+    pub fn mcp_check_all_found(total: usize) -> String {
+        if is_chinese() {
+            format!("✓ 全部 {total} 个 MCP 服务器检查通过")
+        } else {
+            format!("✓ All {total} MCP server(s) passed the check")
+        }
+    }
+
+    pub fn tui_mcp_check_title() -> &'static str {
+        if is_chinese() {
+            "MCP 命令检查"
+        } else {
+            "MCP Command Check"
+        }
+    }
+
+    pub fn tui_key_check_all() -> &'static str {
+        if is_chinese() {
+            "全部检查"
+        } else {
+            "check all"
+        }
+    }
+
     pub fn tui_toast_prompt_activated() -> &'static str {
         if is_chinese() {
             "提示词已启用。"
@@ -3032,6 +3739,59 @@ pub mod texts {
         }
     }
 
+    pub fn provider_fuzzy_matched(query: &str, id: &str) -> String {
+        if is_chinese() {
+            format!("'{query}' 匹配到供应商 '{id}'")
+        } else {
+            format!("'{query}' matched provider '{id}'")
+        }
+    }
+
+    pub fn provider_fuzzy_ambiguous(query: &str, candidates: &[String]) -> String {
+        let list = candidates.join(", ");
+        if is_chinese() {
+            format!("'{query}' 匹配到多个供应商，请使用更精确的 ID：{list}")
+        } else {
+            format!("'{query}' matches several providers, use a more specific id: {list}")
+        }
+    }
+
+    pub fn config_changes_title() -> &'static str {
+        if is_chinese() {
+            "配置变更"
+        } else {
+            "Configuration Changes"
+        }
+    }
+
+    pub fn config_changes_none() -> &'static str {
+        if is_chinese() {
+            "供应商、MCP 服务器和提示词均无变化"
+        } else {
+            "No providers, MCP servers or prompts changed"
+        }
+    }
+
+    pub fn config_changes_counts(added: usize, removed: usize, modified: usize) -> String {
+        if is_chinese() {
+            format!("新增 {added}，删除 {removed}，修改 {modified}")
+        } else {
+            format!("{added} added, {removed} removed, {modified} modified")
+        }
+    }
+
+    pub fn config_change_kind(kind: crate::services::ChangeKind) -> &'static str {
+        use crate::services::ChangeKind;
+        match (kind, is_chinese()) {
+            (ChangeKind::Added, true) => "新增",
+            (ChangeKind::Added, false) => "added",
+            (ChangeKind::Removed, true) => "删除",
+            (ChangeKind::Removed, false) => "removed",
+            (ChangeKind::Modified, true) => "修改",
+            (ChangeKind::Modified, false) => "modified",
+        }
+    }
+
     pub fn tui_toast_webdav_settings_saved() -> &'static str {
         if is_chinese() {
             "WebDAV 同步设置已保存。"
@@ -3168,6 +3928,22 @@ pub mod texts {
         }
     }
 
+    pub fn tui_config_validation_live_header() -> &'static str {
+        if is_chinese() {
+            "Live 文件:"
+        } else {
+            "Live files:"
+        }
+    }
+
+    pub fn tui_toast_live_files_need_attention() -> &'static str {
+        if is_chinese() {
+            "配置有效，但部分 live 文件缺失或无法解析。"
+        } else {
+            "Config is valid, but some live files are missing or unreadable."
+        }
+    }
+
     pub fn tui_toast_validation_passed() -> &'static str {
         if is_chinese() {
             "校验通过。"
@@ -3192,6 +3968,15 @@ pub mod texts {
         }
     }
 
+    pub fn tui_toast_config_reset_app(app: &str, backup_id: &str) -> String {
+        match (is_chinese(), backup_id.is_empty()) {
+            (true, true) => format!("{app} 配置已重置"),
+            (true, false) => format!("{app} 配置已重置（备份: {backup_id}）"),
+            (false, true) => format!("{app} config reset"),
+            (false, false) => format!("{app} config reset (backup: {backup_id})"),
+        }
+    }
+
     pub fn menu_home() -> &'static str {
         let (en, zh) = menu_home_variants();
         if is_chinese() {
@@ -3429,63 +4214,156 @@ pub mod texts {
         }
     }
 
-    pub fn skills_manage_repos() -> &'static str {
+    /// 导入前的确认摘要：目录数量与各应用来源
+    pub fn skills_import_from_apps_confirm(
+        preview: &[crate::app_config::UnmanagedSkill],
+    ) -> String {
+        let by_app = ["claude", "codex", "gemini"]
+            .into_iter()
+            .filter_map(|app| {
+                let count = preview
+                    .iter()
+                    .filter(|skill| skill.found_in.iter().any(|found| found == app))
+                    .count();
+                (count > 0).then(|| format!("{app} ({count})"))
+            })
+            .collect::<Vec<_>>();
         if is_chinese() {
-            "📦 管理技能仓库"
+            format!(
+                "将从 {} 导入 {} 个技能目录到 SSOT 并纳入管理，确认？",
+                by_app.join("、"),
+                preview.len()
+            )
         } else {
-            "📦 Manage Skill Repos"
+            format!(
+                "Import {} skill dir(s) from {} into SSOT and manage them?",
+                preview.len(),
+                by_app.join(", ")
+            )
         }
     }
 
-    pub fn skills_enter_query() -> &'static str {
+    pub fn skills_import_from_apps_requires_yes() -> &'static str {
         if is_chinese() {
-            "输入搜索关键词（可选）："
+            "非交互环境下需要 --yes 才能导入"
         } else {
-            "Enter search query (optional):"
+            "Refusing to import without confirmation; pass --yes when not running in a terminal"
         }
     }
 
-    pub fn skills_enter_install_spec() -> &'static str {
+    pub fn skills_import_undo_hint() -> &'static str {
         if is_chinese() {
-            "输入 Skill 名称或 key（owner/name:directory）："
+            "如需撤销本次导入：cc-switch skills import-from-apps --undo"
         } else {
-            "Enter skill directory or key (owner/name:directory):"
+            "To undo this import: cc-switch skills import-from-apps --undo"
         }
     }
 
-    pub fn skills_select_skill() -> &'static str {
+    pub fn skills_import_undo_nothing() -> &'static str {
         if is_chinese() {
-            "选择一个 Skill："
+            "没有可撤销的应用目录导入。"
         } else {
-            "Select a skill:"
+            "No app-dir import to undo."
         }
     }
 
-    pub fn skills_confirm_install(name: &str, app: &str) -> String {
+    pub fn skills_import_undone(count: usize) -> String {
         if is_chinese() {
-            format!("确认安装 '{name}' 并启用到 {app}？")
+            format!("已撤销导入，从 SSOT 与索引中移除 {count} 个技能目录。")
         } else {
-            format!("Install '{name}' and enable for {app}?")
+            format!("Undid import: removed {count} skill dir(s) from SSOT and the index.")
         }
     }
 
-    pub fn skills_confirm_uninstall(name: &str) -> String {
+    pub fn tui_skills_import_title() -> &'static str {
         if is_chinese() {
-            format!("确认卸载 '{name}'？")
+            "导入 Skill"
         } else {
-            format!("Uninstall '{name}'?")
+            "Import Skills"
         }
     }
 
-    pub fn skills_confirm_toggle(name: &str, app: &str, enabled: bool) -> String {
+    pub fn tui_skills_undo_import_title() -> &'static str {
         if is_chinese() {
-            if enabled {
-                format!("确认启用 '{name}' 到 {app}？")
-            } else {
-                format!("确认在 {app} 禁用 '{name}'？")
-            }
-        } else if enabled {
-            format!("Enable '{name}' for {app}?")
+            "撤销导入"
+        } else {
+            "Undo Import"
+        }
+    }
+
+    pub fn tui_confirm_undo_import_message() -> &'static str {
+        if is_chinese() {
+            "从 SSOT 与索引中移除上次从应用目录导入时新增的技能目录？"
+        } else {
+            "Remove the skill dirs added by the last app-dir import from SSOT and the index?"
+        }
+    }
+
+    pub fn tui_key_undo_import() -> &'static str {
+        if is_chinese() {
+            "撤销导入"
+        } else {
+            "undo import"
+        }
+    }
+
+    pub fn skills_manage_repos() -> &'static str {
+        if is_chinese() {
+            "📦 管理技能仓库"
+        } else {
+            "📦 Manage Skill Repos"
+        }
+    }
+
+    pub fn skills_enter_query() -> &'static str {
+        if is_chinese() {
+            "输入搜索关键词（可选）："
+        } else {
+            "Enter search query (optional):"
+        }
+    }
+
+    pub fn skills_enter_install_spec() -> &'static str {
+        if is_chinese() {
+            "输入 Skill 名称或 key（owner/name:directory）："
+        } else {
+            "Enter skill directory or key (owner/name:directory):"
+        }
+    }
+
+    pub fn skills_select_skill() -> &'static str {
+        if is_chinese() {
+            "选择一个 Skill："
+        } else {
+            "Select a skill:"
+        }
+    }
+
+    pub fn skills_confirm_install(name: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("确认安装 '{name}' 并启用到 {app}？")
+        } else {
+            format!("Install '{name}' and enable for {app}?")
+        }
+    }
+
+    pub fn skills_confirm_uninstall(name: &str) -> String {
+        if is_chinese() {
+            format!("确认卸载 '{name}'？")
+        } else {
+            format!("Uninstall '{name}'?")
+        }
+    }
+
+    pub fn skills_confirm_toggle(name: &str, app: &str, enabled: bool) -> String {
+        if is_chinese() {
+            if enabled {
+                format!("确认启用 '{name}' 到 {app}？")
+            } else {
+                format!("确认在 {app} 禁用 '{name}'？")
+            }
+        } else if enabled {
+            format!("Enable '{name}' for {app}?")
         } else {
             format!("Disable '{name}' for {app}?")
         }
@@ -3711,6 +4589,39 @@ pub mod texts {
         }
     }
 
+    pub fn clone_to_app_same_app_error() -> &'static str {
+        if is_chinese() {
+            "源应用与目标应用相同，请使用不同的 --from / --to。"
+        } else {
+            "Source and target apps are the same; pick different --from / --to."
+        }
+    }
+
+    pub fn clone_to_app_oauth_only_warning(app: &str) -> String {
+        if is_chinese() {
+            format!("⚠ 源供应商使用 {app} 的 OAuth/官方登录，没有可复制的 Base URL 或 API Key；请在目标供应商中手动填写。")
+        } else {
+            format!("⚠ Source provider uses {app} OAuth/official login; no base URL or API key can be carried over. Fill them in on the new provider.")
+        }
+    }
+
+    pub fn clone_to_app_missing_api_key_warning() -> String {
+        if is_chinese() {
+            "⚠ 源供应商未配置 API Key，目标供应商将不含 API Key。".to_string()
+        } else {
+            "⚠ Source provider has no API key; the cloned provider will be created without one."
+                .to_string()
+        }
+    }
+
+    pub fn provider_cloned_to_app(source_id: &str, new_id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将供应商 '{source_id}' 复制到 {app}，新 ID: '{new_id}'")
+        } else {
+            format!("✓ Cloned provider '{source_id}' to {app} as '{new_id}'")
+        }
+    }
+
     pub fn no_deletable_providers() -> &'static str {
         if is_chinese() {
             "没有可删除的供应商（无法删除当前供应商）。"
@@ -3865,7 +4776,11 @@ pub mod texts {
     }
 
     pub fn provider_name_placeholder() -> &'static str {
-        "OpenAI"
+        if is_chinese() {
+            "如 OpenAI"
+        } else {
+            "e.g., OpenAI"
+        }
     }
 
     pub fn provider_name_empty_error() -> &'static str {
@@ -3901,7 +4816,11 @@ pub mod texts {
     }
 
     pub fn website_url_placeholder() -> &'static str {
-        "https://openai.com"
+        if is_chinese() {
+            "如 https://openai.com"
+        } else {
+            "e.g., https://openai.com"
+        }
     }
 
     // Provider Commands
@@ -3909,6 +4828,84 @@ pub mod texts {
         "Use 'cc-switch provider add' to create a new provider."
     }
 
+    pub fn provider_search_no_match(query: &str) -> String {
+        if is_chinese() {
+            format!("没有供应商的 ID、名称或 API 地址包含 '{query}'")
+        } else {
+            format!("No provider ID, name or API URL matches '{query}'")
+        }
+    }
+
+    pub fn provider_print_key_refused_tty() -> &'static str {
+        if is_chinese() {
+            "拒绝将 API Key 明文输出到终端；请通过管道使用，或显式传入 --unsafe-print-secret"
+        } else {
+            "Refusing to print the API key to a terminal; pipe the output or pass --unsafe-print-secret"
+        }
+    }
+
+    pub fn provider_meta_section_header() -> &'static str {
+        if is_chinese() {
+            "元数据字段"
+        } else {
+            "Metadata Fields"
+        }
+    }
+
+    pub fn provider_meta_updated(id: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已更新供应商 '{id}' 的元数据字段")
+        } else {
+            format!("✓ Updated metadata fields of provider '{id}'")
+        }
+    }
+
+    pub fn provider_meta_empty() -> &'static str {
+        if is_chinese() {
+            "（无元数据字段）"
+        } else {
+            "(no metadata fields)"
+        }
+    }
+
+    pub fn provider_live_method_title() -> &'static str {
+        if is_chinese() {
+            "Live 配置写入方式"
+        } else {
+            "Live Config Method"
+        }
+    }
+
+    pub fn provider_live_method_name(method: crate::services::LiveFileMethod) -> &'static str {
+        match (is_chinese(), method) {
+            (true, crate::services::LiveFileMethod::Copy) => "copy（普通文件）",
+            (true, crate::services::LiveFileMethod::Symlink) => "symlink（链接到托管副本）",
+            (false, crate::services::LiveFileMethod::Copy) => "copy (plain files)",
+            (false, crate::services::LiveFileMethod::Symlink) => {
+                "symlink (linked to managed copies)"
+            }
+        }
+    }
+
+    pub fn provider_live_method_set(method: crate::services::LiveFileMethod) -> String {
+        match (is_chinese(), method) {
+            (true, crate::services::LiveFileMethod::Symlink) => {
+                "✓ Live 配置已改为符号链接到 ~/.cc-switch/live/ 下的托管副本（原文件已备份）"
+                    .to_string()
+            }
+            (true, crate::services::LiveFileMethod::Copy) => {
+                "✓ Live 配置已改为普通文件".to_string()
+            }
+            (false, crate::services::LiveFileMethod::Symlink) => {
+                "✓ Live config files now symlink to managed copies under ~/.cc-switch/live/ (originals backed up)"
+                    .to_string()
+            }
+            (false, crate::services::LiveFileMethod::Copy) => {
+                "✓ Live config files are now plain copies".to_string()
+            }
+        }
+    }
+
     pub fn app_config_not_found(app: &str) -> String {
         if is_chinese() {
             format!("应用 {} 配置不存在", app)
@@ -3985,211 +4982,453 @@ pub mod texts {
         "Cannot delete the current active provider. Please switch to another provider first."
     }
 
-    // Provider Input - Basic Fields
-    pub fn provider_name_prompt() -> &'static str {
+    pub fn provider_rotate_key_no_match(app: &str, base_url: &str) -> String {
         if is_chinese() {
-            "供应商名称："
+            format!("未找到 Base URL 为 {base_url} 的 {app} 供应商，未做任何修改")
         } else {
-            "Provider Name:"
+            format!("No {app} provider uses base URL {base_url}; nothing was changed")
         }
     }
 
-    // Provider Input - Claude Configuration
-    pub fn config_claude_header() -> &'static str {
+    pub fn provider_rotate_key_done(count: usize, masked_key: &str) -> String {
         if is_chinese() {
-            "配置 Claude 供应商："
+            format!("✓ 已将 {count} 个供应商的 API Key 更新为 {masked_key}")
         } else {
-            "Configure Claude Provider:"
+            format!("✓ Updated the API key of {count} provider(s) to {masked_key}")
         }
     }
 
-    pub fn api_key_label() -> &'static str {
+    pub fn tui_label_key_pool() -> &'static str {
         if is_chinese() {
-            "API Key："
+            "Key 池"
         } else {
-            "API Key:"
+            "Key pool"
         }
     }
 
-    pub fn api_key_help() -> &'static str {
+    pub fn tui_key_pool_summary(count: usize, next: usize) -> String {
         if is_chinese() {
-            "留空使用默认值"
+            format!("{count} 个 Key，下次切换使用第 {next} 个（provider keys 管理）")
         } else {
-            "Leave empty to use default"
+            format!("{count} key(s), next switch uses #{next} (manage with provider keys)")
         }
     }
 
-    pub fn base_url_label() -> &'static str {
+    pub fn provider_keys_title(id: &str) -> String {
         if is_chinese() {
-            "Base URL："
+            format!("供应商 {id} 的 API Key 池")
         } else {
-            "Base URL:"
+            format!("API key pool of provider {id}")
         }
     }
 
-    pub fn base_url_empty_error() -> &'static str {
+    pub fn provider_keys_empty(id: &str) -> String {
         if is_chinese() {
-            "API 请求地址不能为空"
+            format!("供应商 {id} 未配置 Key 池，切换时使用其单个 API Key")
         } else {
-            "API URL cannot be empty"
+            format!("Provider {id} has no key pool; switching uses its single API key")
         }
     }
 
-    pub fn base_url_placeholder() -> &'static str {
+    pub fn provider_keys_next_hint() -> &'static str {
         if is_chinese() {
-            "如 https://api.anthropic.com"
+            "→ 标记下次切换时写入的 Key"
         } else {
-            "e.g., https://api.anthropic.com"
+            "→ marks the key written on the next switch"
         }
     }
 
-    pub fn configure_model_names_prompt() -> &'static str {
+    pub fn provider_keys_added(id: &str, total: usize) -> String {
         if is_chinese() {
-            "配置模型名称？"
+            format!("✓ 已更新供应商 {id} 的 Key 池（共 {total} 个）")
         } else {
-            "Configure model names?"
+            format!("✓ Updated the key pool of provider {id} ({total} key(s))")
         }
     }
 
-    pub fn model_default_label() -> &'static str {
+    pub fn provider_keys_removed(id: &str, number: usize) -> String {
         if is_chinese() {
-            "默认模型："
+            format!("✓ 已从供应商 {id} 的 Key 池移除第 {number} 个 Key")
         } else {
-            "Default Model:"
+            format!("✓ Removed key #{number} from the key pool of provider {id}")
         }
     }
 
-    pub fn model_default_help() -> &'static str {
+    pub fn provider_validate_all_ok(app: &str, count: usize) -> String {
         if is_chinese() {
-            "留空使用 Claude Code 默认模型"
+            format!("✓ {app} 的 {count} 个供应商均通过校验")
         } else {
-            "Leave empty to use Claude Code default"
+            format!("✓ All {count} {app} provider(s) passed validation")
         }
     }
 
-    pub fn model_haiku_label() -> &'static str {
+    pub fn provider_validate_failed(app: &str, failed: usize, total: usize) -> String {
         if is_chinese() {
-            "Haiku 模型："
+            format!("{app} 有 {failed}/{total} 个供应商未通过校验")
         } else {
-            "Haiku Model:"
+            format!("{failed} of {total} {app} provider(s) failed validation")
         }
     }
 
-    pub fn model_haiku_placeholder() -> &'static str {
+    pub fn provider_validate_problems_header() -> &'static str {
         if is_chinese() {
-            "如 claude-3-5-haiku-20241022"
+            "问题"
         } else {
-            "e.g., claude-3-5-haiku-20241022"
+            "Problems"
         }
     }
 
-    pub fn model_sonnet_label() -> &'static str {
+    pub fn diagnostics_summary(ok: usize, warn: usize, error: usize) -> String {
         if is_chinese() {
-            "Sonnet 模型："
+            format!("通过 {ok} 项，警告 {warn} 项，错误 {error} 项")
         } else {
-            "Sonnet Model:"
+            format!("{ok} passed, {warn} warning(s), {error} error(s)")
         }
     }
 
-    pub fn model_sonnet_placeholder() -> &'static str {
+    pub fn config_validate_db_exists() -> &'static str {
         if is_chinese() {
-            "如 claude-3-5-sonnet-20241022"
+            "数据库文件存在"
         } else {
-            "e.g., claude-3-5-sonnet-20241022"
+            "Database file exists"
         }
     }
 
-    pub fn model_opus_label() -> &'static str {
+    pub fn config_validate_db_missing() -> &'static str {
         if is_chinese() {
-            "Opus 模型："
+            "数据库文件不存在"
         } else {
-            "Opus Model:"
+            "Database file does not exist"
         }
     }
 
-    pub fn model_opus_placeholder() -> &'static str {
+    pub fn config_validate_db_readable() -> &'static str {
         if is_chinese() {
-            "如 claude-3-opus-20240229"
+            "数据库结构可读取"
         } else {
-            "e.g., claude-3-opus-20240229"
+            "Database schema is readable"
         }
     }
 
-    // Provider Input - Codex Configuration
-    pub fn config_codex_header() -> &'static str {
+    pub fn config_validate_db_unreadable() -> &'static str {
         if is_chinese() {
-            "配置 Codex 供应商："
+            "数据库结构无法读取"
         } else {
-            "Configure Codex Provider:"
+            "Database schema is not readable"
         }
     }
 
-    pub fn openai_api_key_label() -> &'static str {
+    pub fn config_validate_live_files(app: &str) -> String {
         if is_chinese() {
-            "OpenAI API Key："
+            format!("{app} live 配置文件")
         } else {
-            "OpenAI API Key:"
+            format!("{app} live config files")
         }
     }
 
-    pub fn anthropic_api_key_label() -> &'static str {
+    /// 诊断检查标识对应的本地化名称；未知标识返回 None（直接显示标识）
+    pub fn diagnostic_check_label(check: &str) -> Option<String> {
+        let zh = is_chinese();
+        let label = match check.split_once('.') {
+            Some(("skills", "ssot_migration")) => if zh {
+                "SSOT 迁移状态"
+            } else {
+                "SSOT migration"
+            }
+            .to_string(),
+            Some(("skills", "ssot_dirs")) => if zh {
+                "SSOT 目录完整性"
+            } else {
+                "SSOT directories"
+            }
+            .to_string(),
+            Some(("skills", "ssot_orphans")) => if zh {
+                "孤立的 SSOT 目录"
+            } else {
+                "Orphaned SSOT directories"
+            }
+            .to_string(),
+            Some(("skills", "app_sync")) => if zh {
+                "应用目录同步"
+            } else {
+                "App directory sync"
+            }
+            .to_string(),
+            Some(("skills", "unmanaged")) => if zh {
+                "未纳管的 Skill"
+            } else {
+                "Unmanaged skills"
+            }
+            .to_string(),
+            Some(("env", "conflicts")) => if zh {
+                "环境变量冲突"
+            } else {
+                "Environment variable conflicts"
+            }
+            .to_string(),
+            Some(("compat", app)) => {
+                if zh {
+                    format!("{app} 版本兼容性")
+                } else {
+                    format!("{app} version compatibility")
+                }
+            }
+            Some(("live", app)) => config_validate_live_files(app),
+            Some(("provider", id)) => {
+                if zh {
+                    format!("供应商 {id}")
+                } else {
+                    format!("Provider {id}")
+                }
+            }
+            _ => return None,
+        };
+        Some(label)
+    }
+
+    pub fn env_conflicts_remediation() -> &'static str {
         if is_chinese() {
-            "Anthropic API Key："
+            "请从 shell 配置文件或系统设置中移除这些环境变量"
         } else {
-            "Anthropic API Key:"
+            "Remove these variables from your shell config files or system settings"
         }
     }
 
-    pub fn config_toml_label() -> &'static str {
+    pub fn diagnostics_failed(command: &str, error: usize) -> String {
         if is_chinese() {
-            "配置内容 (TOML)："
+            format!("{command}：{error} 项检查未通过")
         } else {
-            "Config Content (TOML):"
+            format!("{command}: {error} check(s) failed")
         }
     }
 
-    pub fn config_toml_help() -> &'static str {
+    pub fn cannot_delete_current_provider_hint(id: &str) -> String {
         if is_chinese() {
-            "按 Esc 后 Enter 提交"
+            format!(
+                "'{id}' 是当前正在使用的供应商，无法删除。请先切换到其他供应商，或使用 --force 删除并清空当前选择。"
+            )
         } else {
-            "Press Esc then Enter to submit"
+            format!(
+                "'{id}' is the current active provider. Switch to another provider first, or pass --force to delete it and clear the current selection."
+            )
         }
     }
 
-    pub fn config_toml_placeholder() -> &'static str {
+    pub fn confirm_force_delete_current_provider(id: &str) -> String {
         if is_chinese() {
-            "留空使用默认配置"
+            format!("'{id}' 是当前供应商，删除后将清空当前选择。确定删除吗？")
         } else {
-            "Leave empty to use default config"
+            format!(
+                "'{id}' is the current provider; deleting it clears the current selection. Delete anyway?"
+            )
         }
     }
 
-    // Codex 0.64+ Configuration
-    pub fn codex_auth_mode_info() -> &'static str {
+    // Provider Input - Basic Fields
+    pub fn provider_name_prompt() -> &'static str {
         if is_chinese() {
-            "⚠ 请选择 Codex 的鉴权方式（决定 API Key 从哪里读取）"
+            "供应商名称："
         } else {
-            "⚠ Choose how Codex authenticates (where the API key is read from)"
+            "Provider Name:"
         }
     }
 
-    pub fn codex_auth_mode_label() -> &'static str {
+    // Provider Input - Claude Configuration
+    pub fn config_claude_header() -> &'static str {
         if is_chinese() {
-            "认证方式："
+            "配置 Claude 供应商："
         } else {
-            "Auth Mode:"
+            "Configure Claude Provider:"
         }
     }
 
-    pub fn codex_auth_mode_help() -> &'static str {
+    pub fn api_key_label() -> &'static str {
         if is_chinese() {
-            "OpenAI 认证：使用 auth.json/凭据存储；环境变量：使用 env_key 指定的变量（未设置会报错）"
+            "API Key："
         } else {
-            "OpenAI auth uses auth.json/credential store; env var mode uses env_key (missing env var will error)"
+            "API Key:"
         }
     }
 
-    pub fn codex_auth_mode_openai() -> &'static str {
+    pub fn api_key_help() -> &'static str {
+        if is_chinese() {
+            "留空使用默认值"
+        } else {
+            "Leave empty to use default"
+        }
+    }
+
+    pub fn base_url_label() -> &'static str {
+        if is_chinese() {
+            "Base URL："
+        } else {
+            "Base URL:"
+        }
+    }
+
+    pub fn base_url_empty_error() -> &'static str {
+        if is_chinese() {
+            "API 请求地址不能为空"
+        } else {
+            "API URL cannot be empty"
+        }
+    }
+
+    pub fn base_url_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 https://api.anthropic.com"
+        } else {
+            "e.g., https://api.anthropic.com"
+        }
+    }
+
+    pub fn configure_model_names_prompt() -> &'static str {
+        if is_chinese() {
+            "配置模型名称？"
+        } else {
+            "Configure model names?"
+        }
+    }
+
+    pub fn model_default_label() -> &'static str {
+        if is_chinese() {
+            "默认模型："
+        } else {
+            "Default Model:"
+        }
+    }
+
+    pub fn model_default_help() -> &'static str {
+        if is_chinese() {
+            "留空使用 Claude Code 默认模型"
+        } else {
+            "Leave empty to use Claude Code default"
+        }
+    }
+
+    pub fn model_haiku_label() -> &'static str {
+        if is_chinese() {
+            "Haiku 模型："
+        } else {
+            "Haiku Model:"
+        }
+    }
+
+    pub fn model_haiku_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 claude-3-5-haiku-20241022"
+        } else {
+            "e.g., claude-3-5-haiku-20241022"
+        }
+    }
+
+    pub fn model_sonnet_label() -> &'static str {
+        if is_chinese() {
+            "Sonnet 模型："
+        } else {
+            "Sonnet Model:"
+        }
+    }
+
+    pub fn model_sonnet_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 claude-3-5-sonnet-20241022"
+        } else {
+            "e.g., claude-3-5-sonnet-20241022"
+        }
+    }
+
+    pub fn model_opus_label() -> &'static str {
+        if is_chinese() {
+            "Opus 模型："
+        } else {
+            "Opus Model:"
+        }
+    }
+
+    pub fn model_opus_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 claude-3-opus-20240229"
+        } else {
+            "e.g., claude-3-opus-20240229"
+        }
+    }
+
+    // Provider Input - Codex Configuration
+    pub fn config_codex_header() -> &'static str {
+        if is_chinese() {
+            "配置 Codex 供应商："
+        } else {
+            "Configure Codex Provider:"
+        }
+    }
+
+    pub fn openai_api_key_label() -> &'static str {
+        if is_chinese() {
+            "OpenAI API Key："
+        } else {
+            "OpenAI API Key:"
+        }
+    }
+
+    pub fn anthropic_api_key_label() -> &'static str {
+        if is_chinese() {
+            "Anthropic API Key："
+        } else {
+            "Anthropic API Key:"
+        }
+    }
+
+    pub fn config_toml_label() -> &'static str {
+        if is_chinese() {
+            "配置内容 (TOML)："
+        } else {
+            "Config Content (TOML):"
+        }
+    }
+
+    pub fn config_toml_help() -> &'static str {
+        if is_chinese() {
+            "按 Esc 后 Enter 提交"
+        } else {
+            "Press Esc then Enter to submit"
+        }
+    }
+
+    pub fn config_toml_placeholder() -> &'static str {
+        if is_chinese() {
+            "留空使用默认配置"
+        } else {
+            "Leave empty to use default config"
+        }
+    }
+
+    // Codex 0.64+ Configuration
+    pub fn codex_auth_mode_info() -> &'static str {
+        if is_chinese() {
+            "⚠ 请选择 Codex 的鉴权方式（决定 API Key 从哪里读取）"
+        } else {
+            "⚠ Choose how Codex authenticates (where the API key is read from)"
+        }
+    }
+
+    pub fn codex_auth_mode_label() -> &'static str {
+        if is_chinese() {
+            "认证方式："
+        } else {
+            "Auth Mode:"
+        }
+    }
+
+    pub fn codex_auth_mode_help() -> &'static str {
+        if is_chinese() {
+            "OpenAI 认证：使用 auth.json/凭据存储；环境变量：使用 env_key 指定的变量（未设置会报错）"
+        } else {
+            "OpenAI auth uses auth.json/credential store; env var mode uses env_key (missing env var will error)"
+        }
+    }
+
+    pub fn codex_auth_mode_openai() -> &'static str {
         if is_chinese() {
             "OpenAI 认证（推荐，无需环境变量）"
         } else {
@@ -4400,6 +5639,70 @@ pub mod texts {
         }
     }
 
+    pub fn claude_api_key_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 sk-ant-..."
+        } else {
+            "e.g., sk-ant-..."
+        }
+    }
+
+    pub fn codex_api_key_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 sk-..."
+        } else {
+            "e.g., sk-..."
+        }
+    }
+
+    pub fn codex_base_url_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 https://api.openai.com/v1"
+        } else {
+            "e.g., https://api.openai.com/v1"
+        }
+    }
+
+    pub fn codex_model_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 gpt-5.2-codex"
+        } else {
+            "e.g., gpt-5.2-codex"
+        }
+    }
+
+    pub fn gemini_api_key_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 AIza... 或 pk-..."
+        } else {
+            "e.g., AIza... or pk-..."
+        }
+    }
+
+    pub fn gemini_model_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 gemini-3-pro-preview"
+        } else {
+            "e.g., gemini-3-pro-preview"
+        }
+    }
+
+    pub fn expires_at_placeholder() -> &'static str {
+        if is_chinese() {
+            "YYYY-MM-DD，如 2026-12-31"
+        } else {
+            "YYYY-MM-DD, e.g., 2026-12-31"
+        }
+    }
+
+    pub fn headers_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 x-api-version: 2024-01; x-relay-region: us"
+        } else {
+            "e.g., x-api-version: 2024-01; x-relay-region: us"
+        }
+    }
+
     pub fn gemini_base_url_placeholder() -> &'static str {
         if is_chinese() {
             "如 https://generativelanguage.googleapis.com"
@@ -4505,75 +5808,244 @@ pub mod texts {
         }
     }
 
-    pub fn notes_placeholder() -> &'static str {
+    pub fn expires_at_label() -> &'static str {
         if is_chinese() {
-            "可选的备注信息"
+            "密钥到期日："
         } else {
-            "Optional notes"
+            "Key expires:"
         }
     }
 
-    pub fn sort_index_label() -> &'static str {
+    pub fn expires_at_help() -> &'static str {
         if is_chinese() {
-            "排序索引："
+            "格式 YYYY-MM-DD，留空表示不跟踪到期"
         } else {
-            "Sort Index:"
+            "Format YYYY-MM-DD; leave empty to skip expiry tracking"
         }
     }
 
-    pub fn sort_index_help() -> &'static str {
+    pub fn headers_label() -> &'static str {
         if is_chinese() {
-            "数字越小越靠前，留空使用创建时间排序"
+            "请求头："
         } else {
-            "Lower numbers appear first, leave empty to sort by creation time"
+            "Request headers:"
         }
     }
 
-    pub fn sort_index_placeholder() -> &'static str {
+    pub fn headers_help() -> &'static str {
         if is_chinese() {
-            "如 1, 2, 3..."
+            "格式 Key: Value，多个用 ; 分隔；仅用于测速等探测请求，不写入应用配置"
         } else {
-            "e.g., 1, 2, 3..."
+            "Format Key: Value, separate several with ;. Only sent with speedtest probes, never written to app configs"
         }
     }
 
-    pub fn invalid_sort_index() -> &'static str {
-        if is_chinese() {
-            "排序索引必须是有效的数字"
-        } else {
-            "Sort index must be a valid number"
+    pub fn provider_expiry_status(status: crate::services::ExpiryStatus) -> String {
+        use crate::services::ExpiryStatus;
+        match (is_chinese(), status) {
+            (true, ExpiryStatus::Expired { days_ago }) => format!("已过期 {days_ago} 天"),
+            (true, ExpiryStatus::ExpiringSoon { days_left: 0 }) => "今天到期".to_string(),
+            (true, ExpiryStatus::ExpiringSoon { days_left }) => format!("{days_left} 天后到期"),
+            (false, ExpiryStatus::Expired { days_ago }) => {
+                format!("expired {days_ago} day(s) ago")
+            }
+            (false, ExpiryStatus::ExpiringSoon { days_left: 0 }) => "expires today".to_string(),
+            (false, ExpiryStatus::ExpiringSoon { days_left }) => {
+                format!("expires in {days_left} day(s)")
+            }
         }
     }
 
-    pub fn optional_fields_config() -> &'static str {
+    pub fn provider_expiry_warning(warning: &crate::services::ExpiryWarning) -> String {
+        let status = provider_expiry_status(warning.status);
         if is_chinese() {
-            "可选字段配置："
+            format!(
+                "⚠ 供应商 '{}' 的密钥{}（{}）",
+                warning.id, status, warning.expires_at
+            )
         } else {
-            "Optional Fields Configuration:"
+            format!(
+                "⚠ Key of provider '{}' {} ({})",
+                warning.id, status, warning.expires_at
+            )
         }
     }
 
-    pub fn notes_example_placeholder() -> &'static str {
-        if is_chinese() {
-            "自定义供应商，用于测试"
-        } else {
-            "Custom provider for testing"
+    pub fn tui_toast_provider_expiry_warnings(
+        warnings: &[crate::services::ExpiryWarning],
+    ) -> String {
+        match warnings {
+            [single] => provider_expiry_warning(single),
+            _ if is_chinese() => format!(
+                "⚠ {} 个供应商的密钥已过期或即将到期，最早：'{}'",
+                warnings.len(),
+                warnings.first().map(|w| w.id.as_str()).unwrap_or_default()
+            ),
+            _ => format!(
+                "⚠ {} provider keys have expired or expire soon; earliest: '{}'",
+                warnings.len(),
+                warnings.first().map(|w| w.id.as_str()).unwrap_or_default()
+            ),
         }
     }
 
-    pub fn notes_help_edit() -> &'static str {
+    pub fn tui_provider_invalid_expiry() -> &'static str {
         if is_chinese() {
-            "关于此供应商的额外说明，直接回车保持原值"
+            "格式无效，应为 YYYY-MM-DD"
         } else {
-            "Additional notes about this provider, press Enter to keep current value"
+            "invalid, expected YYYY-MM-DD"
         }
     }
 
-    pub fn notes_help_new() -> &'static str {
+    pub fn tui_toast_provider_invalid_headers() -> &'static str {
         if is_chinese() {
-            "关于此供应商的额外说明，直接回车跳过"
+            "请求头格式无效，应为 Key: Value（多个用 ; 分隔）"
         } else {
-            "Additional notes about this provider, press Enter to skip"
+            "Invalid request headers; expected Key: Value (separate several with ;)"
+        }
+    }
+
+    pub fn provider_expiry_set(id: &str, date: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将供应商 '{id}' 的密钥到期日设为 {date}")
+        } else {
+            format!("✓ Key expiry of provider '{id}' set to {date}")
+        }
+    }
+
+    pub fn provider_expiry_cleared(id: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已清除供应商 '{id}' 的密钥到期日")
+        } else {
+            format!("✓ Cleared key expiry of provider '{id}'")
+        }
+    }
+
+    pub fn expiry_warning_window(days: u32) -> String {
+        if is_chinese() {
+            format!("密钥到期提醒窗口：{days} 天")
+        } else {
+            format!("Key expiry warning window: {days} day(s)")
+        }
+    }
+
+    pub fn expiry_warning_window_set(days: u32) -> String {
+        if is_chinese() {
+            format!("✓ 将在密钥到期前 {days} 天开始提醒")
+        } else {
+            format!("✓ Key expiry warnings now start {days} day(s) ahead")
+        }
+    }
+
+    pub fn on_switch_command_current(command: &str) -> String {
+        if is_chinese() {
+            format!("切换后执行的命令：{command}")
+        } else {
+            format!("On-switch command: {command}")
+        }
+    }
+
+    pub fn on_switch_command_none() -> &'static str {
+        if is_chinese() {
+            "未配置切换后执行的命令"
+        } else {
+            "No on-switch command configured"
+        }
+    }
+
+    pub fn on_switch_command_set(command: &str) -> String {
+        if is_chinese() {
+            format!("✓ 每次切换供应商成功后将执行：{command}")
+        } else {
+            format!("✓ Will run after every successful provider switch: {command}")
+        }
+    }
+
+    pub fn on_switch_command_cleared() -> &'static str {
+        if is_chinese() {
+            "✓ 已移除切换后执行的命令"
+        } else {
+            "✓ On-switch command removed"
+        }
+    }
+
+    pub fn on_switch_command_empty() -> &'static str {
+        if is_chinese() {
+            "命令不能为空（使用 --clear 移除）"
+        } else {
+            "Command cannot be empty (use --clear to remove it)"
+        }
+    }
+
+    pub fn notes_placeholder() -> &'static str {
+        if is_chinese() {
+            "可选的备注信息"
+        } else {
+            "Optional notes"
+        }
+    }
+
+    pub fn sort_index_label() -> &'static str {
+        if is_chinese() {
+            "排序索引："
+        } else {
+            "Sort Index:"
+        }
+    }
+
+    pub fn sort_index_help() -> &'static str {
+        if is_chinese() {
+            "数字越小越靠前，留空使用创建时间排序"
+        } else {
+            "Lower numbers appear first, leave empty to sort by creation time"
+        }
+    }
+
+    pub fn sort_index_placeholder() -> &'static str {
+        if is_chinese() {
+            "如 1, 2, 3..."
+        } else {
+            "e.g., 1, 2, 3..."
+        }
+    }
+
+    pub fn invalid_sort_index() -> &'static str {
+        if is_chinese() {
+            "排序索引必须是有效的数字"
+        } else {
+            "Sort index must be a valid number"
+        }
+    }
+
+    pub fn optional_fields_config() -> &'static str {
+        if is_chinese() {
+            "可选字段配置："
+        } else {
+            "Optional Fields Configuration:"
+        }
+    }
+
+    pub fn notes_example_placeholder() -> &'static str {
+        if is_chinese() {
+            "自定义供应商，用于测试"
+        } else {
+            "Custom provider for testing"
+        }
+    }
+
+    pub fn notes_help_edit() -> &'static str {
+        if is_chinese() {
+            "关于此供应商的额外说明，直接回车保持原值"
+        } else {
+            "Additional notes about this provider, press Enter to keep current value"
+        }
+    }
+
+    pub fn notes_help_new() -> &'static str {
+        if is_chinese() {
+            "关于此供应商的额外说明，直接回车跳过"
+        } else {
+            "Additional notes about this provider, press Enter to skip"
         }
     }
 
@@ -4835,475 +6307,1308 @@ pub mod texts {
         if is_chinese() {
             "➕ 编辑供应商"
         } else {
-            "➕ Edit Provider"
+            "➕ Edit Provider"
+        }
+    }
+
+    pub fn no_editable_providers() -> &'static str {
+        if is_chinese() {
+            "没有可编辑的供应商"
+        } else {
+            "No providers available for editing"
+        }
+    }
+
+    pub fn select_provider_to_edit() -> &'static str {
+        if is_chinese() {
+            "选择要编辑的供应商："
+        } else {
+            "Select provider to edit:"
+        }
+    }
+
+    pub fn choose_edit_mode() -> &'static str {
+        if is_chinese() {
+            "选择编辑模式："
+        } else {
+            "Choose edit mode:"
+        }
+    }
+
+    pub fn select_config_file_to_edit() -> &'static str {
+        if is_chinese() {
+            "选择要编辑的配置文件："
+        } else {
+            "Select config file to edit:"
+        }
+    }
+
+    pub fn provider_missing_auth_field() -> &'static str {
+        if is_chinese() {
+            "settings_config 中缺少 'auth' 字段"
+        } else {
+            "Missing 'auth' field in settings_config"
+        }
+    }
+
+    pub fn provider_missing_or_invalid_config_field() -> &'static str {
+        if is_chinese() {
+            "settings_config 中缺少或无效的 'config' 字段"
+        } else {
+            "Missing or invalid 'config' field in settings_config"
+        }
+    }
+
+    pub fn edit_mode_interactive() -> &'static str {
+        if is_chinese() {
+            "📝 交互式编辑 (分步提示)"
+        } else {
+            "📝 Interactive editing (step-by-step prompts)"
+        }
+    }
+
+    pub fn edit_mode_json_editor() -> &'static str {
+        if is_chinese() {
+            "✏️  JSON 编辑 (使用外部编辑器)"
+        } else {
+            "✏️  JSON editing (use external editor)"
+        }
+    }
+
+    pub fn cancel() -> &'static str {
+        if is_chinese() {
+            "❌ 取消"
+        } else {
+            "❌ Cancel"
+        }
+    }
+
+    pub fn opening_external_editor() -> &'static str {
+        if is_chinese() {
+            "正在打开外部编辑器..."
+        } else {
+            "Opening external editor..."
+        }
+    }
+
+    pub fn invalid_json_syntax() -> &'static str {
+        if is_chinese() {
+            "无效的 JSON 语法"
+        } else {
+            "Invalid JSON syntax"
+        }
+    }
+
+    pub fn invalid_provider_structure() -> &'static str {
+        if is_chinese() {
+            "无效的供应商结构"
+        } else {
+            "Invalid provider structure"
+        }
+    }
+
+    pub fn provider_id_cannot_be_changed() -> &'static str {
+        if is_chinese() {
+            "供应商 ID 不能被修改"
+        } else {
+            "Provider ID cannot be changed"
+        }
+    }
+
+    pub fn retry_editing() -> &'static str {
+        if is_chinese() {
+            "是否重新编辑？"
+        } else {
+            "Retry editing?"
+        }
+    }
+
+    pub fn no_changes_detected() -> &'static str {
+        if is_chinese() {
+            "未检测到任何更改"
+        } else {
+            "No changes detected"
+        }
+    }
+
+    pub fn provider_summary() -> &'static str {
+        if is_chinese() {
+            "供应商信息摘要"
+        } else {
+            "Provider Summary"
+        }
+    }
+
+    pub fn confirm_save_changes() -> &'static str {
+        if is_chinese() {
+            "确认保存更改？"
+        } else {
+            "Save changes?"
+        }
+    }
+
+    pub fn editor_failed() -> &'static str {
+        if is_chinese() {
+            "编辑器失败"
+        } else {
+            "Editor failed"
+        }
+    }
+
+    pub fn invalid_selection_format() -> &'static str {
+        if is_chinese() {
+            "无效的选择格式"
+        } else {
+            "Invalid selection format"
+        }
+    }
+
+    // Provider Display Labels (for show_current and view_provider_detail)
+    pub fn basic_info_section_header() -> &'static str {
+        if is_chinese() {
+            "基本信息 / Basic Info"
+        } else {
+            "Basic Info"
+        }
+    }
+
+    pub fn name_label_with_colon() -> &'static str {
+        if is_chinese() {
+            "名称"
+        } else {
+            "Name"
+        }
+    }
+
+    pub fn app_label_with_colon() -> &'static str {
+        if is_chinese() {
+            "应用"
+        } else {
+            "App"
+        }
+    }
+
+    pub fn api_config_section_header() -> &'static str {
+        if is_chinese() {
+            "API 配置 / API Configuration"
+        } else {
+            "API Configuration"
+        }
+    }
+
+    pub fn model_config_section_header() -> &'static str {
+        if is_chinese() {
+            "模型配置 / Model Configuration"
+        } else {
+            "Model Configuration"
+        }
+    }
+
+    pub fn main_model_label_with_colon() -> &'static str {
+        if is_chinese() {
+            "主模型"
+        } else {
+            "Main Model"
+        }
+    }
+
+    pub fn provider_changes_header() -> &'static str {
+        if is_chinese() {
+            "字段变更："
+        } else {
+            "Field Changes:"
+        }
+    }
+
+    pub fn provider_diff_secret() -> &'static str {
+        if is_chinese() {
+            "（密钥已修改，内容隐藏）"
+        } else {
+            "(secret changed, hidden)"
+        }
+    }
+
+    pub fn tui_provider_edit_confirm_title() -> &'static str {
+        if is_chinese() {
+            "保存以下修改？"
+        } else {
+            "Save these changes?"
+        }
+    }
+
+    pub fn updated_config_header() -> &'static str {
+        if is_chinese() {
+            "修改后配置："
+        } else {
+            "Updated Configuration:"
+        }
+    }
+
+    // Provider Add/Edit Messages
+    pub fn generated_id_message(id: &str) -> String {
+        if is_chinese() {
+            format!("生成的 ID: {}", id)
+        } else {
+            format!("Generated ID: {}", id)
+        }
+    }
+
+    pub fn edit_fields_instruction() -> &'static str {
+        if is_chinese() {
+            "逐个编辑字段（直接回车保留当前值）：\n"
+        } else {
+            "Edit fields one by one (press Enter to keep current value):\n"
+        }
+    }
+
+    // ============================================
+    // MCP SERVER MANAGEMENT (MCP 服务器管理)
+    // ============================================
+
+    pub fn mcp_management() -> &'static str {
+        if is_chinese() {
+            "🛠️  MCP 服务器管理"
+        } else {
+            "🛠️  MCP Server Management"
+        }
+    }
+
+    pub fn no_mcp_servers() -> &'static str {
+        if is_chinese() {
+            "未找到 MCP 服务器。"
+        } else {
+            "No MCP servers found."
+        }
+    }
+
+    pub fn sync_all_servers() -> &'static str {
+        if is_chinese() {
+            "🔄 同步所有服务器"
+        } else {
+            "🔄 Sync All Servers"
+        }
+    }
+
+    pub fn synced_successfully() -> &'static str {
+        if is_chinese() {
+            "✓ 所有 MCP 服务器同步成功"
+        } else {
+            "✓ All MCP servers synced successfully"
+        }
+    }
+
+    // ============================================
+    // PROMPT MANAGEMENT (提示词管理)
+    // ============================================
+
+    pub fn prompts_management() -> &'static str {
+        if is_chinese() {
+            "💬 提示词管理"
+        } else {
+            "💬 Prompt Management"
+        }
+    }
+
+    pub fn no_prompts() -> &'static str {
+        if is_chinese() {
+            "未找到提示词预设。"
+        } else {
+            "No prompt presets found."
+        }
+    }
+
+    pub fn switch_active_prompt() -> &'static str {
+        if is_chinese() {
+            "🔄 切换活动提示词"
+        } else {
+            "🔄 Switch Active Prompt"
+        }
+    }
+
+    pub fn no_prompts_available() -> &'static str {
+        if is_chinese() {
+            "没有可用的提示词。"
+        } else {
+            "No prompts available."
+        }
+    }
+
+    pub fn select_prompt_to_activate() -> &'static str {
+        if is_chinese() {
+            "选择要激活的提示词："
+        } else {
+            "Select prompt to activate:"
+        }
+    }
+
+    pub fn activated_prompt(id: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已激活提示词 '{}'", id)
+        } else {
+            format!("✓ Activated prompt '{}'", id)
+        }
+    }
+
+    pub fn deactivated_prompt(id: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已取消激活提示词 '{}'", id)
+        } else {
+            format!("✓ Deactivated prompt '{}'", id)
+        }
+    }
+
+    pub fn prompt_cleared_note() -> &'static str {
+        if is_chinese() {
+            "实时文件已清空"
+        } else {
+            "Live prompt file has been cleared"
+        }
+    }
+
+    pub fn prompt_synced_note() -> &'static str {
+        if is_chinese() {
+            "注意：提示词已同步到实时配置文件。"
+        } else {
+            "Note: The prompt has been synced to the live configuration file."
+        }
+    }
+
+    // Configuration View
+    pub fn current_configuration() -> &'static str {
+        if is_chinese() {
+            "👁️  当前配置"
+        } else {
+            "👁️  Current Configuration"
+        }
+    }
+
+    pub fn provider_label() -> &'static str {
+        if is_chinese() {
+            "供应商："
+        } else {
+            "Provider:"
+        }
+    }
+
+    pub fn mcp_servers_label() -> &'static str {
+        if is_chinese() {
+            "MCP 服务器："
+        } else {
+            "MCP Servers:"
+        }
+    }
+
+    pub fn prompts_label() -> &'static str {
+        if is_chinese() {
+            "提示词："
+        } else {
+            "Prompts:"
+        }
+    }
+
+    pub fn total() -> &'static str {
+        if is_chinese() {
+            "总计"
+        } else {
+            "Total"
+        }
+    }
+
+    pub fn enabled() -> &'static str {
+        if is_chinese() {
+            "启用"
+        } else {
+            "Enabled"
+        }
+    }
+
+    pub fn disabled() -> &'static str {
+        if is_chinese() {
+            "禁用"
+        } else {
+            "Disabled"
+        }
+    }
+
+    pub fn active() -> &'static str {
+        if is_chinese() {
+            "活动"
+        } else {
+            "Active"
+        }
+    }
+
+    pub fn none() -> &'static str {
+        if is_chinese() {
+            "无"
+        } else {
+            "None"
+        }
+    }
+
+    // Settings
+    pub fn settings_title() -> &'static str {
+        if is_chinese() {
+            "⚙️  设置"
+        } else {
+            "⚙️  Settings"
+        }
+    }
+
+    pub fn change_language() -> &'static str {
+        if is_chinese() {
+            "🌐 切换语言"
+        } else {
+            "🌐 Change Language"
+        }
+    }
+
+    pub fn current_language_label() -> &'static str {
+        if is_chinese() {
+            "当前语言"
+        } else {
+            "Current Language"
+        }
+    }
+
+    pub fn select_language() -> &'static str {
+        if is_chinese() {
+            "选择语言："
+        } else {
+            "Select language:"
+        }
+    }
+
+    pub fn language_changed() -> &'static str {
+        if is_chinese() {
+            "✓ 语言已更改"
+        } else {
+            "✓ Language changed"
+        }
+    }
+
+    pub fn skip_claude_onboarding() -> &'static str {
+        if is_chinese() {
+            "🚫 跳过 Claude Code 初次安装确认"
+        } else {
+            "🚫 Skip Claude Code onboarding confirmation"
+        }
+    }
+
+    pub fn skip_claude_onboarding_label() -> &'static str {
+        if is_chinese() {
+            "跳过 Claude Code 初次安装确认"
+        } else {
+            "Skip Claude Code onboarding confirmation"
+        }
+    }
+
+    pub fn provider_defaults_label(app: &str) -> String {
+        if is_chinese() {
+            format!("新建供应商默认值（{app}）")
+        } else {
+            format!("New provider defaults ({app})")
+        }
+    }
+
+    pub fn provider_defaults_summary(base_url: &str, model: &str) -> String {
+        let or_none = |value: &str| {
+            if value.is_empty() {
+                none().to_string()
+            } else {
+                value.to_string()
+            }
+        };
+        if is_chinese() {
+            format!("请求地址: {}\n模型: {}", or_none(base_url), or_none(model))
+        } else {
+            format!("Base URL: {}\nModel: {}", or_none(base_url), or_none(model))
+        }
+    }
+
+    pub fn provider_defaults_saved(app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已保存 {app} 新建供应商默认值")
+        } else {
+            format!("✓ Saved new provider defaults for {app}")
+        }
+    }
+
+    pub fn provider_defaults_reset(app: &str) -> String {
+        if is_chinese() {
+            format!("✓ {app} 新建供应商默认值已恢复内置值")
+        } else {
+            format!("✓ New provider defaults for {app} reset to built-in values")
+        }
+    }
+
+    pub fn speedtest_defaults_label() -> &'static str {
+        if is_chinese() {
+            "测速设置"
+        } else {
+            "Speedtest settings"
+        }
+    }
+
+    pub fn speedtest_defaults_summary(timeout_secs: u64, user_agent: &str) -> String {
+        if is_chinese() {
+            format!("超时: {timeout_secs} 秒\nUser-Agent: {user_agent}")
+        } else {
+            format!("Timeout: {timeout_secs}s\nUser-Agent: {user_agent}")
+        }
+    }
+
+    pub fn speedtest_defaults_saved() -> &'static str {
+        if is_chinese() {
+            "✓ 测速设置已保存"
+        } else {
+            "✓ Speedtest settings saved"
+        }
+    }
+
+    pub fn speedtest_defaults_reset() -> &'static str {
+        if is_chinese() {
+            "✓ 测速设置已恢复默认值"
+        } else {
+            "✓ Speedtest settings reset to defaults"
+        }
+    }
+
+    pub fn offline_mode_label() -> &'static str {
+        if is_chinese() {
+            "离线模式"
+        } else {
+            "Offline mode"
+        }
+    }
+
+    pub fn sync_skills_on_switch_label() -> &'static str {
+        if is_chinese() {
+            "切换后同步 Skills"
+        } else {
+            "Sync skills on switch"
+        }
+    }
+
+    pub fn sync_skills_on_switch_changed(enable: bool) -> String {
+        match (is_chinese(), enable) {
+            (true, true) => "✓ 切换供应商后将自动同步该应用的 Skills".to_string(),
+            (true, false) => "✓ 已关闭切换后同步 Skills".to_string(),
+            (false, true) => {
+                "✓ Skills will be synced for the app after each provider switch".to_string()
+            }
+            (false, false) => "✓ Sync skills on switch disabled".to_string(),
+        }
+    }
+
+    pub fn config_reset_requires_yes() -> &'static str {
+        if is_chinese() {
+            "非交互环境下重置配置需要传入 --yes"
+        } else {
+            "Pass --yes to reset the configuration non-interactively"
+        }
+    }
+
+    pub fn config_reset_app_warning(app: &str) -> String {
+        if is_chinese() {
+            format!("将删除 {app} 的全部供应商并清空当前选择，其他应用不受影响（会先创建备份）。")
+        } else {
+            format!("This removes every {app} provider and clears its current selection; other apps are untouched (a backup is created first).")
+        }
+    }
+
+    pub fn config_reset_app_prompt(app: &str) -> String {
+        if is_chinese() {
+            format!("确定要重置 {app} 的配置吗？")
+        } else {
+            format!("Reset the {app} configuration?")
+        }
+    }
+
+    pub fn config_reset_app_done(app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已重置 {app} 的配置")
+        } else {
+            format!("✓ {app} configuration reset")
+        }
+    }
+
+    pub fn quiet_skill_fallback_label() -> &'static str {
+        if is_chinese() {
+            "静默 Skill 复制回退日志"
+        } else {
+            "Quiet skill copy fallback"
+        }
+    }
+
+    pub fn quiet_skill_fallback_changed(quiet: bool) -> String {
+        match (is_chinese(), quiet) {
+            (true, true) => "✓ Skill 符号链接回退为复制时仅记录 debug 日志".to_string(),
+            (true, false) => "✓ 每个 Skill 首次回退为复制时将记录警告".to_string(),
+            (false, true) => {
+                "✓ Skill symlink-to-copy fallbacks will only be logged at debug level".to_string()
+            }
+            (false, false) => {
+                "✓ Each skill will warn on its first symlink-to-copy fallback".to_string()
+            }
+        }
+    }
+
+    pub fn skills_synced_after_switch(summary: &str) -> String {
+        if is_chinese() {
+            format!("Skills：{summary}")
+        } else {
+            format!("Skills: {summary}")
+        }
+    }
+
+    pub fn skills_sync_after_switch_failed(err: &str) -> String {
+        if is_chinese() {
+            format!("切换后同步 Skills 失败：{err}")
+        } else {
+            format!("Skill sync after switch failed: {err}")
+        }
+    }
+
+    pub fn sponsor_presets_label() -> &'static str {
+        if is_chinese() {
+            "赞助商预设"
+        } else {
+            "Sponsor presets"
+        }
+    }
+
+    pub fn sponsor_presets_changed(enable: bool) -> String {
+        match (is_chinese(), enable) {
+            (true, true) => "✓ 添加供应商模板中将显示赞助商预设".to_string(),
+            (true, false) => "✓ 添加供应商模板中已隐藏赞助商预设".to_string(),
+            (false, true) => {
+                "✓ Sponsor presets will be shown in Add Provider templates".to_string()
+            }
+            (false, false) => "✓ Sponsor presets hidden from Add Provider templates".to_string(),
+        }
+    }
+
+    pub fn offline_mode_changed(enable: bool) -> String {
+        if is_chinese() {
+            if enable {
+                "✓ 已启用离线模式（Skills 发现/安装、更新检查与测速将被跳过）".to_string()
+            } else {
+                "✓ 已关闭离线模式".to_string()
+            }
+        } else {
+            if enable {
+                "✓ Offline mode enabled (skill discovery/install, update checks and speedtests are skipped)".to_string()
+            } else {
+                "✓ Offline mode disabled".to_string()
+            }
+        }
+    }
+
+    pub fn base_url_app_mismatch_warning(url: &str, app: &str, guessed: &str) -> String {
+        if is_chinese() {
+            format!("⚠ Base URL {url} 看起来是 {guessed} 风格的端点，但当前应用为 {app}，请确认是否选错了应用")
+        } else {
+            format!("⚠ Base URL {url} looks like a {guessed}-style endpoint, but the selected app is {app}; double-check the app")
+        }
+    }
+
+    pub fn profile_created(name: &str, dir: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已创建配置档 '{name}': {dir}")
+        } else {
+            format!("✓ Created profile '{name}': {dir}")
+        }
+    }
+
+    pub fn profile_activated(name: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 '{name}' 设为激活配置档")
+        } else {
+            format!("✓ Active profile set to '{name}'")
+        }
+    }
+
+    pub fn profile_delete_confirm(name: &str) -> String {
+        if is_chinese() {
+            format!("确定删除配置档 '{name}' 及其全部数据吗？")
+        } else {
+            format!("Delete profile '{name}' and all of its data?")
+        }
+    }
+
+    pub fn profile_deleted(name: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已删除配置档 '{name}'")
+        } else {
+            format!("✓ Deleted profile '{name}'")
+        }
+    }
+
+    pub fn live_verify_ok() -> &'static str {
+        if is_chinese() {
+            "✓ 已核对 live 文件：Base URL / API Key 与供应商一致"
+        } else {
+            "✓ Live files verified: base URL / API key match the provider"
+        }
+    }
+
+    pub fn live_verify_mismatch() -> &'static str {
+        if is_chinese() {
+            "⚠ live 文件与供应商不一致："
+        } else {
+            "⚠ Live files do not match the provider:"
+        }
+    }
+
+    pub fn live_verify_expected() -> &'static str {
+        if is_chinese() {
+            "期望"
+        } else {
+            "expected"
+        }
+    }
+
+    pub fn live_verify_actual() -> &'static str {
+        if is_chinese() {
+            "实际"
+        } else {
+            "actual"
+        }
+    }
+
+    pub fn live_verify_failed(err: &str) -> String {
+        if is_chinese() {
+            format!("⚠ 无法核对 live 文件: {err}")
+        } else {
+            format!("⚠ Could not verify live files: {err}")
+        }
+    }
+
+    pub fn tui_toast_live_verified() -> &'static str {
+        if is_chinese() {
+            "✓ 已切换，live 文件核对通过。"
+        } else {
+            "✓ Switched; live files verified."
+        }
+    }
+
+    pub fn tui_toast_live_verify_mismatch(fields: &str) -> String {
+        if is_chinese() {
+            format!("⚠ 已切换，但 live 文件不一致: {fields}。")
+        } else {
+            format!("⚠ Switched, but live files do not match: {fields}.")
+        }
+    }
+
+    pub fn failover_queue_added(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 '{id}' 加入 {app} 的故障转移队列")
+        } else {
+            format!("✓ Added '{id}' to the {app} failover queue")
+        }
+    }
+
+    pub fn failover_queue_removed(id: &str, app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 已将 '{id}' 移出 {app} 的故障转移队列")
+        } else {
+            format!("✓ Removed '{id}' from the {app} failover queue")
+        }
+    }
+
+    pub fn failover_queue_empty(app: &str) -> String {
+        if is_chinese() {
+            format!("{app} 的故障转移队列为空。使用 `cc-switch provider failover --add <id>` 添加备用供应商。")
+        } else {
+            format!("The {app} failover queue is empty. Add backups with `cc-switch provider failover --add <id>`.")
+        }
+    }
+
+    pub fn failover_testing(count: usize, app: &str) -> String {
+        if is_chinese() {
+            format!("正在测速 {app} 故障转移队列中的 {count} 个供应商...")
+        } else {
+            format!("Testing {count} provider(s) in the {app} failover queue...")
         }
     }
 
-    pub fn no_editable_providers() -> &'static str {
+    pub fn provider_prune_testing(count: usize, app: &str) -> String {
         if is_chinese() {
-            "没有可编辑的供应商"
+            format!("正在测速 {app} 的 {count} 个供应商（当前供应商除外）...")
         } else {
-            "No providers available for editing"
+            format!("Testing {count} {app} provider(s), skipping the current one...")
         }
     }
 
-    pub fn select_provider_to_edit() -> &'static str {
+    pub fn provider_prune_none() -> &'static str {
         if is_chinese() {
-            "选择要编辑的供应商："
+            "所有供应商端点均可达，无需清理"
         } else {
-            "Select provider to edit:"
+            "Every provider endpoint is reachable; nothing to prune"
         }
     }
 
-    pub fn choose_edit_mode() -> &'static str {
+    pub fn provider_prune_confirm(count: usize, delete: bool) -> String {
+        match (is_chinese(), delete) {
+            (true, true) => format!("删除以上 {count} 个不可达的供应商？"),
+            (true, false) => format!("归档并移除以上 {count} 个不可达的供应商？"),
+            (false, true) => format!("Delete the {count} unreachable provider(s) above?"),
+            (false, false) => {
+                format!("Archive and remove the {count} unreachable provider(s) above?")
+            }
+        }
+    }
+
+    pub fn provider_prune_requires_yes() -> &'static str {
         if is_chinese() {
-            "选择编辑模式："
+            "非交互环境下需要 --yes 才能清理供应商"
         } else {
-            "Choose edit mode:"
+            "Refusing to prune without confirmation; pass --yes when not running in a terminal"
         }
     }
 
-    pub fn select_config_file_to_edit() -> &'static str {
+    pub fn provider_prune_archived(path: &str) -> String {
         if is_chinese() {
-            "选择要编辑的配置文件："
+            format!("已归档到 {path}")
         } else {
-            "Select config file to edit:"
+            format!("Archived to {path}")
         }
     }
 
-    pub fn provider_missing_auth_field() -> &'static str {
+    pub fn provider_prune_summary(kept: usize, removed: usize) -> String {
         if is_chinese() {
-            "settings_config 中缺少 'auth' 字段"
+            format!("保留 {kept} 个，移除 {removed} 个供应商")
         } else {
-            "Missing 'auth' field in settings_config"
+            format!("Kept {kept}, removed {removed} provider(s)")
         }
     }
 
-    pub fn provider_missing_or_invalid_config_field() -> &'static str {
+    pub fn provider_health_testing(count: usize, app: &str) -> String {
         if is_chinese() {
-            "settings_config 中缺少或无效的 'config' 字段"
+            format!("正在检查 {app} 的 {count} 个供应商（测速 + API Key 校验）...")
         } else {
-            "Missing or invalid 'config' field in settings_config"
+            format!("Checking {count} {app} provider(s) (speedtest + API key)...")
         }
     }
 
-    pub fn edit_mode_interactive() -> &'static str {
+    pub fn provider_health_ok() -> &'static str {
         if is_chinese() {
-            "📝 交互式编辑 (分步提示)"
+            "可用"
         } else {
-            "📝 Interactive editing (step-by-step prompts)"
+            "OK"
         }
     }
 
-    pub fn edit_mode_json_editor() -> &'static str {
+    pub fn provider_health_auth_failed() -> &'static str {
         if is_chinese() {
-            "✏️  JSON 编辑 (使用外部编辑器)"
+            "鉴权失败"
         } else {
-            "✏️  JSON editing (use external editor)"
+            "Auth failed"
         }
     }
 
-    pub fn cancel() -> &'static str {
+    pub fn provider_health_auth_unknown() -> &'static str {
         if is_chinese() {
-            "❌ 取消"
+            "鉴权未知"
         } else {
-            "❌ Cancel"
+            "Auth unknown"
         }
     }
 
-    pub fn opening_external_editor() -> &'static str {
+    pub fn provider_health_auth_probe_failed(err: &str) -> String {
         if is_chinese() {
-            "正在打开外部编辑器..."
+            format!("API Key 校验请求失败：{err}")
         } else {
-            "Opening external editor..."
+            format!("API key check failed: {err}")
         }
     }
 
-    pub fn invalid_json_syntax() -> &'static str {
+    pub fn provider_health_not_applicable() -> &'static str {
         if is_chinese() {
-            "无效的 JSON 语法"
+            "不适用"
         } else {
-            "Invalid JSON syntax"
+            "N/A"
         }
     }
 
-    pub fn invalid_provider_structure() -> &'static str {
+    pub fn provider_health_official() -> &'static str {
         if is_chinese() {
-            "无效的供应商结构"
+            "官方供应商，未配置 URL / API Key，跳过检查"
         } else {
-            "Invalid provider structure"
+            "Official provider without URL / API key; skipped"
         }
     }
 
-    pub fn provider_id_cannot_be_changed() -> &'static str {
+    pub fn provider_health_unreachable() -> &'static str {
         if is_chinese() {
-            "供应商 ID 不能被修改"
+            "不可达"
         } else {
-            "Provider ID cannot be changed"
+            "Unreachable"
         }
     }
 
-    pub fn retry_editing() -> &'static str {
+    pub fn provider_health_no_key() -> &'static str {
         if is_chinese() {
-            "是否重新编辑？"
+            "未配置 API Key"
         } else {
-            "Retry editing?"
+            "No API key configured"
         }
     }
 
-    pub fn no_changes_detected() -> &'static str {
+    pub fn provider_health_summary(
+        healthy: usize,
+        auth_failed: usize,
+        auth_unknown: usize,
+        unreachable: usize,
+        not_applicable: usize,
+    ) -> String {
         if is_chinese() {
-            "未检测到任何更改"
+            format!(
+                "可用 {healthy} 个，鉴权失败 {auth_failed} 个，鉴权未知 {auth_unknown} 个，不可达 {unreachable} 个，不适用 {not_applicable} 个"
+            )
         } else {
-            "No changes detected"
+            format!(
+                "{healthy} OK, {auth_failed} auth failed, {auth_unknown} auth unknown, {unreachable} unreachable, {not_applicable} n/a"
+            )
         }
     }
 
-    pub fn provider_summary() -> &'static str {
+    pub fn stats_title() -> &'static str {
         if is_chinese() {
-            "供应商信息摘要"
+            "配置概览"
         } else {
-            "Provider Summary"
+            "Configuration Stats"
         }
     }
 
-    pub fn confirm_save_changes() -> &'static str {
+    pub fn stats_header_app() -> &'static str {
         if is_chinese() {
-            "确认保存更改？"
+            "应用"
         } else {
-            "Save changes?"
+            "App"
         }
     }
 
-    pub fn editor_failed() -> &'static str {
+    pub fn stats_header_providers() -> &'static str {
         if is_chinese() {
-            "编辑器失败"
+            "供应商"
         } else {
-            "Editor failed"
+            "Providers"
         }
     }
 
-    pub fn invalid_selection_format() -> &'static str {
+    pub fn stats_header_current() -> &'static str {
         if is_chinese() {
-            "无效的选择格式"
+            "当前"
         } else {
-            "Invalid selection format"
+            "Current"
         }
     }
 
-    // Provider Display Labels (for show_current and view_provider_detail)
-    pub fn basic_info_section_header() -> &'static str {
+    pub fn stats_header_mcp_enabled() -> &'static str {
         if is_chinese() {
-            "基本信息 / Basic Info"
+            "已启用 MCP"
         } else {
-            "Basic Info"
+            "MCP enabled"
         }
     }
 
-    pub fn name_label_with_colon() -> &'static str {
+    pub fn stats_header_prompts() -> &'static str {
         if is_chinese() {
-            "名称"
+            "提示词"
         } else {
-            "Name"
+            "Prompts"
         }
     }
 
-    pub fn app_label_with_colon() -> &'static str {
+    pub fn stats_header_active_prompt() -> &'static str {
         if is_chinese() {
-            "应用"
+            "当前提示词"
         } else {
-            "App"
+            "Active prompt"
         }
     }
 
-    pub fn api_config_section_header() -> &'static str {
+    pub fn stats_header_skills_enabled() -> &'static str {
         if is_chinese() {
-            "API 配置 / API Configuration"
+            "已启用技能"
         } else {
-            "API Configuration"
+            "Skills enabled"
         }
     }
 
-    pub fn model_config_section_header() -> &'static str {
+    pub fn stats_mcp_servers() -> &'static str {
         if is_chinese() {
-            "模型配置 / Model Configuration"
+            "MCP 服务器总数"
         } else {
-            "Model Configuration"
+            "MCP servers"
         }
     }
 
-    pub fn main_model_label_with_colon() -> &'static str {
+    pub fn stats_skills_installed() -> &'static str {
         if is_chinese() {
-            "主模型"
+            "已安装技能"
         } else {
-            "Main Model"
+            "Installed skills"
         }
     }
 
-    pub fn updated_config_header() -> &'static str {
+    pub fn stats_skill_repos() -> &'static str {
         if is_chinese() {
-            "修改后配置："
+            "技能仓库"
         } else {
-            "Updated Configuration:"
+            "Skill repos"
         }
     }
 
-    // Provider Add/Edit Messages
-    pub fn generated_id_message(id: &str) -> String {
+    pub fn stats_backups() -> &'static str {
         if is_chinese() {
-            format!("生成的 ID: {}", id)
+            "备份"
         } else {
-            format!("Generated ID: {}", id)
+            "Backups"
         }
     }
 
-    pub fn edit_fields_instruction() -> &'static str {
+    pub fn audit_log_empty(path: &str) -> String {
         if is_chinese() {
-            "逐个编辑字段（直接回车保留当前值）：\n"
+            format!("审计日志为空（{path}）")
         } else {
-            "Edit fields one by one (press Enter to keep current value):\n"
+            format!("No audit log entries yet ({path})")
         }
     }
 
-    // ============================================
-    // MCP SERVER MANAGEMENT (MCP 服务器管理)
-    // ============================================
-
-    pub fn mcp_management() -> &'static str {
+    pub fn speedtest_no_targets() -> &'static str {
         if is_chinese() {
-            "🛠️  MCP 服务器管理"
+            "没有可测速的目标"
         } else {
-            "🛠️  MCP Server Management"
+            "Nothing to test"
         }
     }
 
-    pub fn no_mcp_servers() -> &'static str {
+    pub fn speedtest_testing_endpoints(count: usize) -> String {
         if is_chinese() {
-            "未找到 MCP 服务器。"
+            format!("正在测速 {count} 个端点...")
         } else {
-            "No MCP servers found."
+            format!("Testing {count} endpoint(s)...")
         }
     }
 
-    pub fn sync_all_servers() -> &'static str {
+    pub fn speedtest_completed() -> &'static str {
         if is_chinese() {
-            "🔄 同步所有服务器"
+            "✓ 测速完成"
         } else {
-            "🔄 Sync All Servers"
+            "✓ Speedtest completed successfully"
         }
     }
 
-    pub fn synced_successfully() -> &'static str {
+    pub fn speedtest_failed_count(count: usize) -> String {
         if is_chinese() {
-            "✓ 所有 MCP 服务器同步成功"
+            format!("{count} 个目标测速失败或未配置 URL")
         } else {
-            "✓ All MCP servers synced successfully"
+            format!("{count} target(s) failed or have no URL")
         }
     }
 
-    // ============================================
-    // PROMPT MANAGEMENT (提示词管理)
-    // ============================================
-
-    pub fn prompts_management() -> &'static str {
+    pub fn failover_no_url() -> &'static str {
         if is_chinese() {
-            "💬 提示词管理"
+            "未配置 Base URL"
         } else {
-            "💬 Prompt Management"
+            "No base URL"
         }
     }
 
-    pub fn no_prompts() -> &'static str {
+    pub fn failover_no_healthy(app: &str) -> String {
         if is_chinese() {
-            "未找到提示词预设。"
+            format!("{app} 的故障转移队列中没有可用的供应商，未切换。")
         } else {
-            "No prompt presets found."
+            format!("No healthy provider in the {app} failover queue; nothing was switched.")
         }
     }
 
-    pub fn switch_active_prompt() -> &'static str {
+    pub fn failover_already_fastest(id: &str) -> String {
         if is_chinese() {
-            "🔄 切换活动提示词"
+            format!("✓ 当前供应商 '{id}' 已是最快的可用供应商")
         } else {
-            "🔄 Switch Active Prompt"
+            format!("✓ Current provider '{id}' is already the fastest healthy one")
         }
     }
 
-    pub fn no_prompts_available() -> &'static str {
+    pub fn failover_dry_run(id: &str) -> String {
         if is_chinese() {
-            "没有可用的提示词。"
+            format!("将切换到 '{id}'（--dry-run，未切换）")
         } else {
-            "No prompts available."
+            format!("Would switch to '{id}' (--dry-run; nothing changed)")
         }
     }
 
-    pub fn select_prompt_to_activate() -> &'static str {
+    pub fn skills_bundle_exported(count: usize, path: &str) -> String {
         if is_chinese() {
-            "选择要激活的提示词："
+            format!("✓ 已导出 {count} 个技能到 {path}")
         } else {
-            "Select prompt to activate:"
+            format!("✓ Exported {count} skill(s) to {path}")
         }
     }
 
-    pub fn activated_prompt(id: &str) -> String {
+    pub fn skills_bundle_overwrite_prompt(directory: &str) -> String {
         if is_chinese() {
-            format!("✓ 已激活提示词 '{}'", id)
+            format!("本机已存在技能 '{directory}'，是否用导入的版本覆盖？")
         } else {
-            format!("✓ Activated prompt '{}'", id)
+            format!(
+                "Skill '{directory}' already exists locally. Overwrite it with the imported one?"
+            )
         }
     }
 
-    pub fn deactivated_prompt(id: &str) -> String {
+    pub fn skills_bundle_imported(skills: usize, repos: usize) -> String {
         if is_chinese() {
-            format!("✓ 已取消激活提示词 '{}'", id)
+            format!("✓ 已导入 {skills} 个技能，新增 {repos} 个仓库")
         } else {
-            format!("✓ Deactivated prompt '{}'", id)
+            format!("✓ Imported {skills} skill(s) and {repos} new repo(s)")
         }
     }
 
-    pub fn prompt_cleared_note() -> &'static str {
+    pub fn skills_bundle_skipped(list: &str) -> String {
         if is_chinese() {
-            "实时文件已清空"
+            format!("已保留本机版本: {list}")
         } else {
-            "Live prompt file has been cleared"
+            format!("Kept local version: {list}")
         }
     }
 
-    pub fn prompt_synced_note() -> &'static str {
+    pub fn skills_bundle_missing(list: &str) -> String {
         if is_chinese() {
-            "注意：提示词已同步到实时配置文件。"
+            format!("归档中缺少以下技能目录，已跳过: {list}")
         } else {
-            "Note: The prompt has been synced to the live configuration file."
+            format!("Skipped skills missing from the bundle: {list}")
         }
     }
 
-    // Configuration View
-    pub fn current_configuration() -> &'static str {
+    pub fn init_title() -> &'static str {
         if is_chinese() {
-            "👁️  当前配置"
+            "CC-Switch 首次运行引导"
         } else {
-            "👁️  Current Configuration"
+            "CC-Switch First-Run Setup"
         }
     }
 
-    pub fn provider_label() -> &'static str {
+    pub fn init_language_set(language: &str) -> String {
         if is_chinese() {
-            "供应商："
+            format!("✓ 界面语言: {language}")
         } else {
-            "Provider:"
+            format!("✓ Interface language: {language}")
         }
     }
 
-    pub fn mcp_servers_label() -> &'static str {
+    pub fn init_import_live_prompt() -> &'static str {
         if is_chinese() {
-            "MCP 服务器："
+            "是否将现有的 Claude/Codex/Gemini 配置导入为默认供应商？"
         } else {
-            "MCP Servers:"
+            "Import existing Claude/Codex/Gemini configs as default providers?"
         }
     }
 
-    pub fn prompts_label() -> &'static str {
+    pub fn init_import_done(app: &str) -> String {
         if is_chinese() {
-            "提示词："
+            format!("✓ 已导入 {app} 的现有配置")
         } else {
-            "Prompts:"
+            format!("✓ Imported existing {app} config")
         }
     }
 
-    pub fn total() -> &'static str {
+    pub fn init_import_skipped_existing(app: &str) -> String {
         if is_chinese() {
-            "总计"
+            format!("{app} 已有供应商，跳过导入")
         } else {
-            "Total"
+            format!("{app} already has providers, skipping import")
         }
     }
 
-    pub fn enabled() -> &'static str {
+    pub fn init_import_failed(app: &str, err: &str) -> String {
         if is_chinese() {
-            "启用"
+            format!("未导入 {app}: {err}")
         } else {
-            "Enabled"
+            format!("Skipped {app}: {err}")
         }
     }
 
-    pub fn disabled() -> &'static str {
+    pub fn init_add_provider_prompt(app: &str) -> String {
         if is_chinese() {
-            "禁用"
+            format!("{app} 还没有供应商，现在添加一个吗？")
         } else {
-            "Disabled"
+            format!("{app} has no providers yet. Add one now?")
         }
     }
 
-    pub fn active() -> &'static str {
+    pub fn init_no_provider_hint(app: &str) -> String {
         if is_chinese() {
-            "活动"
+            format!("{app} 还没有供应商，可稍后运行 'cc-switch --app {app} provider add' 添加")
         } else {
-            "Active"
+            format!("{app} has no providers yet; run 'cc-switch --app {app} provider add' later")
         }
     }
 
-    pub fn none() -> &'static str {
+    pub fn init_skill_repos_seeded(count: usize) -> String {
         if is_chinese() {
-            "无"
+            format!("✓ 默认技能仓库已就绪（新增 {count} 个）")
         } else {
-            "None"
+            format!("✓ Default skill repos ready ({count} added)")
         }
     }
 
-    // Settings
-    pub fn settings_title() -> &'static str {
+    pub fn init_done() -> &'static str {
         if is_chinese() {
-            "⚙️  设置"
+            "✓ 初始化完成"
         } else {
-            "⚙️  Settings"
+            "✓ Setup complete"
         }
     }
 
-    pub fn change_language() -> &'static str {
+    pub fn init_next_steps() -> &'static str {
         if is_chinese() {
-            "🌐 切换语言"
+            "下一步: 运行 'cc-switch' 进入交互模式，或 'cc-switch provider list' 查看供应商"
         } else {
-            "🌐 Change Language"
+            "Next: run 'cc-switch' for interactive mode, or 'cc-switch provider list' to see providers"
         }
     }
 
-    pub fn current_language_label() -> &'static str {
+    pub fn archive_passphrase_prompt() -> &'static str {
         if is_chinese() {
-            "当前语言"
+            "归档口令："
         } else {
-            "Current Language"
+            "Archive passphrase:"
         }
     }
 
-    pub fn select_language() -> &'static str {
+    pub fn archive_passphrase_confirm() -> &'static str {
         if is_chinese() {
-            "选择语言："
+            "确认口令："
         } else {
-            "Select language:"
+            "Confirm passphrase:"
         }
     }
 
-    pub fn language_changed() -> &'static str {
+    pub fn archive_import_warning() -> &'static str {
         if is_chinese() {
-            "✓ 语言已更改"
+            "这将用归档中的内容替换当前数据库与 Skills 目录。"
         } else {
-            "✓ Language changed"
+            "This will replace your current database and skills directory with the archive contents."
         }
     }
 
-    pub fn skip_claude_onboarding() -> &'static str {
+    pub fn open_dir_failed(program: &str, path: &str, err: &str) -> String {
         if is_chinese() {
-            "🚫 跳过 Claude Code 初次安装确认"
+            format!("无法使用 {program} 打开目录 {path}: {err}")
         } else {
-            "🚫 Skip Claude Code onboarding confirmation"
+            format!("Failed to open {path} with {program}: {err}")
         }
     }
 
-    pub fn skip_claude_onboarding_label() -> &'static str {
+    pub fn opened_dir(path: &str) -> String {
         if is_chinese() {
-            "跳过 Claude Code 初次安装确认"
+            format!("✓ 已在文件管理器中打开: {path}")
         } else {
-            "Skip Claude Code onboarding confirmation"
+            format!("✓ Opened in file manager: {path}")
         }
     }
 
@@ -5495,6 +7800,60 @@ pub mod texts {
         }
     }
 
+    pub fn config_common_snippet_valid(app: &str) -> String {
+        if is_chinese() {
+            format!("✓ 应用 '{}' 的通用配置片段格式有效", app)
+        } else {
+            format!("✓ Common config snippet is valid for app '{}'", app)
+        }
+    }
+
+    pub fn config_common_snippet_no_current_provider() -> &'static str {
+        if is_chinese() {
+            "当前没有供应商，无法预览合并结果。"
+        } else {
+            "No current provider; nothing to preview."
+        }
+    }
+
+    pub fn config_common_snippet_preview_title(provider_id: &str) -> String {
+        if is_chinese() {
+            format!("合并到当前供应商 '{}' 后的配置（未保存）", provider_id)
+        } else {
+            format!("Merged into current provider '{}' (not saved)", provider_id)
+        }
+    }
+
+    pub fn config_common_snippet_diff_title(provider_id: &str) -> String {
+        if is_chinese() {
+            format!(
+                "通用片段对当前供应商 '{}' 的影响（预演，未应用）",
+                provider_id
+            )
+        } else {
+            format!(
+                "Changes the common snippet makes to current provider '{}' (dry run, not applied)",
+                provider_id
+            )
+        }
+    }
+
+    pub fn config_common_snippet_no_effect() -> &'static str {
+        if is_chinese() {
+            "通用片段不会改变当前供应商的配置。"
+        } else {
+            "The common snippet does not change the current provider's config."
+        }
+    }
+
+    pub fn config_common_snippet_disabled_for_provider() -> &'static str {
+        if is_chinese() {
+            "注意：当前供应商已关闭通用配置，切换时不会合并此片段。"
+        } else {
+            "Note: the current provider has common config disabled; switching will not merge this snippet."
+        }
+    }
+
     pub fn config_reset() -> &'static str {
         if is_chinese() {
             "🔄 重置配置"
@@ -5680,6 +8039,30 @@ pub mod texts {
         }
     }
 
+    pub fn config_normalize_done() -> &'static str {
+        if is_chinese() {
+            "✓ config.json 已按规范格式重写"
+        } else {
+            "✓ config.json rewritten in canonical form"
+        }
+    }
+
+    pub fn config_normalize_unchanged() -> &'static str {
+        if is_chinese() {
+            "config.json 已是规范格式，无需改动"
+        } else {
+            "config.json is already in canonical form; nothing to do"
+        }
+    }
+
+    pub fn config_normalize_backup(path: &str) -> String {
+        if is_chinese() {
+            format!("原文件已备份到: {path}")
+        } else {
+            format!("Original backed up to: {path}")
+        }
+    }
+
     pub fn backup_created(id: &str) -> String {
         if is_chinese() {
             format!("✓ 已创建备份，ID: {}", id)
@@ -5785,6 +8168,14 @@ pub mod texts {
         }
     }
 
+    pub fn mcp_select_target_apps() -> &'static str {
+        if is_chinese() {
+            "选择该 MCP 服务器要启用的应用（空格勾选，回车确认）："
+        } else {
+            "Select the apps this MCP server targets (Space to toggle, Enter to confirm):"
+        }
+    }
+
     pub fn select_apps_to_enable() -> &'static str {
         if is_chinese() {
             "选择要启用的应用："
@@ -6123,4 +8514,21 @@ mod tests {
         assert!(label.contains("(opt.)"));
         assert!(!label.contains("(optional)"));
     }
+
+    #[test]
+    fn diagnostic_check_ids_map_to_readable_labels() {
+        assert_eq!(
+            texts::diagnostic_check_label("skills.ssot_orphans").as_deref(),
+            Some("Orphaned SSOT directories")
+        );
+        assert_eq!(
+            texts::diagnostic_check_label("compat.codex").as_deref(),
+            Some("codex version compatibility")
+        );
+        assert_eq!(
+            texts::diagnostic_check_label("live.claude").as_deref(),
+            Some("claude live config files")
+        );
+        assert_eq!(texts::diagnostic_check_label("unknown.check"), None);
+    }
 }
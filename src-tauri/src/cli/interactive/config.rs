@@ -1,7 +1,7 @@
-use inquire::{Confirm, Text};
 use std::path::Path;
 
 use crate::app_config::AppType;
+use crate::cli::commands::config::print_config_changes;
 use crate::cli::i18n::texts;
 use crate::cli::ui::{error, highlight, info, success};
 use crate::config::get_app_config_path;
@@ -10,8 +10,8 @@ use crate::services::ConfigService;
 use crate::services::ProviderService;
 
 use super::utils::{
-    clear_screen, get_state, handle_inquire, pause, prompt_confirm, prompt_select, prompt_text,
-    prompt_text_with_default,
+    clear_screen, get_state, pause, prompt_confirm, prompt_confirm_with_help, prompt_select,
+    prompt_text, prompt_text_with_default, prompt_text_with_help,
 };
 
 pub fn manage_config_menu(app_type: &AppType) -> Result<(), AppError> {
@@ -226,7 +226,7 @@ fn edit_common_config_snippet_interactive(app_type: &AppType) -> Result<(), AppE
                 info(texts::common_config_snippet_no_current_provider())
             );
         } else {
-            ProviderService::switch(&state, app_type.clone(), &current_id)?;
+            ProviderService::resync_live(&state, app_type.clone(), &current_id)?;
             println!("{}", success(texts::common_config_snippet_applied()));
         }
     } else {
@@ -285,7 +285,10 @@ fn show_full_config_interactive() -> Result<(), AppError> {
     clear_screen();
     let state = get_state()?;
     let config = state.config.read()?;
-    let json = serde_json::to_string_pretty(&*config)
+    let mut value = serde_json::to_value(&*config)
+        .map_err(|e| AppError::Message(format!("Failed to serialize config: {}", e)))?;
+    crate::cli::tui::form::redact_key_pools(&mut value);
+    let json = serde_json::to_string_pretty(&value)
         .map_err(|e| AppError::Message(format!("Failed to serialize config: {}", e)))?;
 
     println!(
@@ -342,10 +345,14 @@ fn import_config_interactive(path: &str) -> Result<(), AppError> {
     }
 
     let state = get_state()?;
-    let backup_id = ConfigService::import_config_from_path(file_path, &state)?;
+    let outcome = ConfigService::import_config_from_path(file_path, &state)?;
 
     println!("\n{}", success(&texts::imported_from(path)));
-    println!("{}", info(&format!("Backup created: {}", backup_id)));
+    println!(
+        "{}",
+        info(&format!("Backup created: {}", outcome.backup_id))
+    );
+    print_config_changes(&outcome.changes);
     pause();
     Ok(())
 }
@@ -359,22 +366,18 @@ fn backup_config_interactive() -> Result<(), AppError> {
     println!("{}", texts::tui_rule(60));
 
     // 询问是否使用自定义名称
-    let Some(use_custom_name) = handle_inquire(
-        Confirm::new("是否使用自定义备份名称？")
-            .with_default(false)
-            .with_help_message("自定义名称可以帮助您识别备份用途，如 'before-update'")
-            .prompt(),
+    let Some(use_custom_name) = prompt_confirm_with_help(
+        "是否使用自定义备份名称？",
+        false,
+        "自定义名称可以帮助您识别备份用途，如 'before-update'",
     )?
     else {
         return Ok(());
     };
 
     let custom_name = if use_custom_name {
-        let Some(input) = handle_inquire(
-            Text::new("请输入备份名称：")
-                .with_help_message("仅支持字母、数字、短横线和下划线")
-                .prompt(),
-        )?
+        let Some(input) =
+            prompt_text_with_help("请输入备份名称：", "仅支持字母、数字、短横线和下划线")?
         else {
             return Ok(());
         };
@@ -458,18 +461,19 @@ fn restore_config_interactive() -> Result<(), AppError> {
     }
 
     let state = get_state()?;
-    let pre_restore_backup = ConfigService::restore_from_backup_id(&selected_backup.id, &state)?;
+    let outcome = ConfigService::restore_from_backup_id(&selected_backup.id, &state)?;
 
     println!(
         "\n{}",
         success(&format!("✓ 已从备份恢复: {}", selected_backup.display_name))
     );
-    if !pre_restore_backup.is_empty() {
+    if !outcome.backup_id.is_empty() {
         println!(
             "{}",
-            info(&format!("  恢复前配置已备份: {}", pre_restore_backup))
+            info(&format!("  恢复前配置已备份: {}", outcome.backup_id))
         );
     }
+    print_config_changes(&outcome.changes);
     println!("\n{}", info("注意：重启 CLI 客户端以应用更改"));
 
     pause();
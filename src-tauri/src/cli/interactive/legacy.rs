@@ -1,10 +1,11 @@
 use std::io::IsTerminal;
 
 use crate::app_config::AppType;
+use crate::cli::commands::config::{collect_current_config_summary, print_current_config_summary};
 use crate::cli::i18n::texts;
+use crate::cli::plain::is_plain_mode;
 use crate::cli::ui::{error, highlight, info, set_tui_theme_app, success};
 use crate::error::AppError;
-use crate::services::{McpService, PromptService, ProviderService};
 
 use super::utils::{
     app_switch_direction_from_key, clear_screen, cycle_app_type, pause, prompt_select,
@@ -13,20 +14,34 @@ use super::utils::{
 use super::{config, mcp, prompts, provider, settings, skills};
 
 pub fn run(app: Option<AppType>) -> Result<(), AppError> {
-    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+    let plain = is_plain_mode();
+    if !plain && (!std::io::stdin().is_terminal() || !std::io::stdout().is_terminal()) {
         return Err(AppError::Message(
             texts::interactive_requires_tty().to_string(),
         ));
     }
 
     // Disable bracketed paste mode to work around inquire dropping paste events
-    crate::cli::terminal::disable_bracketed_paste_mode_best_effort();
+    if !plain {
+        crate::cli::terminal::disable_bracketed_paste_mode_best_effort();
+    }
 
-    let mut app_type = app.unwrap_or(AppType::Claude);
+    let mut app_type = crate::cli::resolve_app(app);
     set_tui_theme_app(Some(app_type.clone()));
 
     loop {
-        match show_main_menu(&mut app_type)? {
+        let choice = show_main_menu(&mut app_type)?;
+
+        // 旧版菜单的管理子菜单混合了查看与修改，只读模式下整体拒绝进入
+        if choice.is_management() {
+            if let Err(e) = crate::settings::ensure_writable() {
+                println!("\n{}", error(&format!("{}: {}", texts::error_prefix(), e)));
+                pause();
+                continue;
+            }
+        }
+
+        match choice {
             MainMenuChoice::ManageProviders => {
                 if let Err(e) = provider::manage_providers_menu(&app_type) {
                     println!("\n{}", error(&format!("{}: {}", texts::error_prefix(), e)));
@@ -99,6 +114,12 @@ enum MainMenuChoice {
     Exit,
 }
 
+impl MainMenuChoice {
+    fn is_management(&self) -> bool {
+        !matches!(self, Self::ViewCurrentConfig | Self::SwitchApp | Self::Exit)
+    }
+}
+
 impl std::fmt::Display for MainMenuChoice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -142,7 +163,7 @@ fn show_main_menu(app_type: &mut AppType) -> Result<MainMenuChoice, AppError> {
         MainMenuChoice::Exit,
     ];
 
-    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+    if is_plain_mode() || !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
         return Ok(
             prompt_select(&texts::main_menu_prompt(app_type.as_str()), choices)?
                 .unwrap_or(MainMenuChoice::Exit),
@@ -293,48 +314,9 @@ fn select_app() -> Result<AppType, AppError> {
 fn view_current_config(app_type: &AppType) -> Result<(), AppError> {
     use super::utils::get_state;
 
-    println!("\n{}", highlight(texts::current_configuration()));
-    println!("{}", texts::tui_rule_heavy(60));
-
     let state = get_state()?;
-
-    // Provider info
-    let current_provider = ProviderService::current(&state, app_type.clone())?;
-    let providers = ProviderService::list(&state, app_type.clone())?;
-    if let Some(provider) = providers.get(&current_provider) {
-        println!("\n{}", highlight(texts::provider_label()));
-        println!(
-            "  {}:     {}",
-            texts::name_label_with_colon(),
-            provider.name
-        );
-        let api_url = provider::extract_api_url(&provider.settings_config, &app_type)
-            .unwrap_or_else(|| texts::tui_na().to_string());
-        println!("  {}:  {}", texts::api_url_label_colon(), api_url);
-    }
-
-    // MCP servers count
-    let mcp_servers = McpService::get_all_servers(&state)?;
-    let enabled_count = mcp_servers
-        .values()
-        .filter(|s| s.apps.is_enabled_for(app_type))
-        .count();
-    println!("\n{}", highlight(texts::mcp_servers_label()));
-    println!("  {}:     {}", texts::total(), mcp_servers.len());
-    println!("  {}:     {}", texts::enabled(), enabled_count);
-
-    // Prompts
-    let prompts = PromptService::get_prompts(&state, app_type.clone())?;
-    let active_prompt = prompts.iter().find(|(_, p)| p.enabled);
-    println!("\n{}", highlight(texts::prompts_label()));
-    println!("  {}:     {}", texts::total(), prompts.len());
-    if let Some((_, p)) = active_prompt {
-        println!("  {}:     {}", texts::active(), p.name);
-    } else {
-        println!("  {}:     {}", texts::active(), texts::none());
-    }
-
-    println!("\n{}", texts::tui_rule(60));
+    let summary = collect_current_config_summary(&state, app_type)?;
+    print_current_config_summary(&summary);
     pause();
 
     Ok(())
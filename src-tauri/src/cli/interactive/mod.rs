@@ -4,7 +4,7 @@ mod prompts;
 mod provider;
 mod settings;
 mod skills;
-mod utils;
+pub(crate) mod utils;
 
 pub mod legacy;
 
@@ -18,7 +18,10 @@ pub fn run(app: Option<AppType>) -> Result<(), AppError> {
         return legacy::run(app);
     }
 
-    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+    if crate::cli::plain::is_plain_mode()
+        || !std::io::stdin().is_terminal()
+        || !std::io::stdout().is_terminal()
+    {
         return legacy::run(app);
     }
 
@@ -2,9 +2,9 @@ use indexmap::IndexMap;
 
 use crate::app_config::AppType;
 use crate::cli::i18n::texts;
-use crate::cli::ui::{create_table, error, highlight, info, success, warning};
+use crate::cli::ui::{create_table, error, highlight, info, skills_sync_note, success, warning};
 use crate::error::AppError;
-use crate::services::{ProviderService, SpeedtestService};
+use crate::services::{ProviderService, SpeedtestOptions, SpeedtestService};
 use crate::store::AppState;
 
 use super::utils::{clear_screen, get_state, pause, prompt_confirm, prompt_select};
@@ -203,8 +203,9 @@ fn speedtest_provider_interactive(
     let runtime = tokio::runtime::Runtime::new()
         .map_err(|e| AppError::Message(format!("Failed to create async runtime: {}", e)))?;
 
-    let results = runtime
-        .block_on(async { SpeedtestService::test_endpoints(vec![api_url.clone()], None).await })?;
+    let results = runtime.block_on(async {
+        SpeedtestService::test_endpoints(vec![api_url.clone()], SpeedtestOptions::default()).await
+    })?;
 
     // Display results
     if let Some(result) = results.first() {
@@ -232,7 +233,7 @@ fn speedtest_provider_interactive(
         if let Some(err) = &result.error {
             println!("\n{}", error(&format!("Error: {}", err)));
         } else if result.latency.is_some() {
-            println!("\n{}", success("✓ Speedtest completed successfully"));
+            println!("\n{}", success(texts::speedtest_completed()));
         }
     }
 
@@ -306,7 +307,7 @@ fn switch_provider_interactive(
         .ok_or_else(|| AppError::Message(texts::invalid_selection_format().to_string()))?;
 
     let skip_live_sync = !crate::sync_policy::should_sync_live(app_type);
-    ProviderService::switch(state, app_type.clone(), id)?;
+    let outcome = ProviderService::switch(state, app_type.clone(), id)?;
 
     println!("\n{}", success(&texts::switched_to_provider(id)));
     if skip_live_sync {
@@ -317,6 +318,10 @@ fn switch_provider_interactive(
             ))
         );
     }
+    if let Some(result) = &outcome.skills_sync {
+        let (note, failed) = skills_sync_note(result);
+        println!("{}", if failed { warning(&note) } else { info(&note) });
+    }
     println!("{}", info(texts::restart_note()));
     pause();
 
@@ -637,6 +642,12 @@ fn edit_provider_with_json_editor(
         println!("\n{}", highlight(texts::provider_summary()));
         println!("{}", texts::tui_rule(60));
         display_provider_summary(&updated_provider, app_type);
+        if !crate::cli::commands::provider::print_provider_field_changes(
+            original,
+            &updated_provider,
+        ) {
+            return Ok(());
+        }
 
         // 6. Confirm save
         let Some(confirm) = prompt_confirm(texts::confirm_save_changes(), false)? else {
@@ -659,7 +670,7 @@ fn edit_provider_with_json_editor(
 
         // 8. Immediately sync to live config files
         println!("\n{}", info("Syncing to live config files..."));
-        ProviderService::switch(&state, app_type.clone(), id)?;
+        ProviderService::resync_live(&state, app_type.clone(), id)?;
 
         println!("{}", success("✓ Changes synced to live config files"));
         println!("{}", info(texts::restart_note()));
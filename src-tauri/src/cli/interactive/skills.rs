@@ -307,7 +307,7 @@ fn sync_now() -> Result<(), AppError> {
     println!("{}", "─".repeat(60));
 
     match SkillServiceType::sync_all_enabled(None) {
-        Ok(()) => println!("{}", success("✓ Synced.")),
+        Ok(report) => crate::cli::commands::skills::print_sync_report(&report),
         Err(e) => println!("{}", error(&e.to_string())),
     }
     pause();
@@ -490,7 +490,7 @@ fn import_from_apps_flow() -> Result<(), AppError> {
         return Ok(());
     }
 
-    let options: Vec<String> = unmanaged.into_iter().map(|s| s.directory).collect();
+    let options: Vec<String> = unmanaged.iter().map(|s| s.directory.clone()).collect();
     let Some(selected) = prompt_multiselect(texts::skills_select_unmanaged_to_import(), options)?
     else {
         return Ok(());
@@ -502,12 +502,23 @@ fn import_from_apps_flow() -> Result<(), AppError> {
         return Ok(());
     }
 
+    let preview: Vec<_> = unmanaged
+        .into_iter()
+        .filter(|s| selected.contains(&s.directory))
+        .collect();
+    if prompt_confirm(&texts::skills_import_from_apps_confirm(&preview), false)? != Some(true) {
+        println!("{}", info(texts::cancelled()));
+        pause();
+        return Ok(());
+    }
+
     match SkillServiceType::import_from_apps(selected) {
         Ok(imported) => {
             println!(
                 "{}",
                 success(&format!("✓ Imported {} skill(s).", imported.len()))
             );
+            println!("{}", info(texts::skills_import_undo_hint()));
         }
         Err(e) => println!("{}", error(&e.to_string())),
     }
@@ -648,10 +659,12 @@ fn parse_repo_spec(raw: &str) -> Result<SkillRepo, AppError> {
         ));
     };
 
+    let (branch, fallback_branches) = SkillRepo::parse_branch_list(branch);
     Ok(SkillRepo {
         owner: owner.to_string(),
         name: name.to_string(),
-        branch: branch.unwrap_or("main").to_string(),
+        branch,
         enabled: true,
+        fallback_branches,
     })
 }
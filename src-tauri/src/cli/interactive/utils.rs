@@ -5,6 +5,7 @@ use inquire::{Confirm, MultiSelect, Select, Text};
 
 use crate::app_config::AppType;
 use crate::cli::i18n::texts;
+use crate::cli::plain;
 use crate::error::AppError;
 use crate::store::AppState;
 
@@ -38,7 +39,7 @@ pub fn get_state() -> Result<AppState, AppError> {
 }
 
 pub fn clear_screen() {
-    if !io::stdout().is_terminal() {
+    if !io::stdout().is_terminal() || plain::is_plain_mode() {
         return;
     }
 
@@ -59,6 +60,9 @@ pub fn prompt_select<T>(message: &str, options: Vec<T>) -> Result<Option<T>, App
 where
     T: Clone + std::fmt::Display,
 {
+    if plain::is_plain_mode() {
+        return plain::select(message, options);
+    }
     handle_inquire(
         Select::new(message, options)
             .with_help_message(texts::select_filter_help())
@@ -66,10 +70,34 @@ where
     )
 }
 
+/// 带初始光标与帮助信息的单选；`--plain` 下退化为编号菜单
+pub fn prompt_select_with_cursor<T>(
+    message: &str,
+    options: Vec<T>,
+    cursor: usize,
+    help: &str,
+) -> Result<Option<T>, AppError>
+where
+    T: Clone + std::fmt::Display,
+{
+    if plain::is_plain_mode() {
+        return plain::select(message, options);
+    }
+    handle_inquire(
+        Select::new(message, options)
+            .with_starting_cursor(cursor)
+            .with_help_message(help)
+            .prompt(),
+    )
+}
+
 pub fn prompt_multiselect<T>(message: &str, options: Vec<T>) -> Result<Option<Vec<T>>, AppError>
 where
     T: Clone + std::fmt::Display,
 {
+    if plain::is_plain_mode() {
+        return plain::multiselect(message, options);
+    }
     handle_inquire(
         MultiSelect::new(message, options)
             .with_help_message(texts::select_filter_help())
@@ -77,24 +105,61 @@ where
     )
 }
 
+/// 带默认勾选的多选
+pub fn prompt_multiselect_with_defaults<T>(
+    message: &str,
+    options: Vec<T>,
+    defaults: &[usize],
+) -> Result<Option<Vec<T>>, AppError>
+where
+    T: Clone + std::fmt::Display,
+{
+    if plain::is_plain_mode() {
+        return plain::multiselect_with_defaults(message, options, defaults);
+    }
+    handle_inquire(
+        MultiSelect::new(message, options)
+            .with_default(defaults)
+            .with_help_message(texts::select_filter_help())
+            .prompt(),
+    )
+}
+
 pub fn prompt_confirm(message: &str, default: bool) -> Result<Option<bool>, AppError> {
+    prompt_confirm_with_help(message, default, texts::esc_to_go_back_help())
+}
+
+pub fn prompt_confirm_with_help(
+    message: &str,
+    default: bool,
+    help: &str,
+) -> Result<Option<bool>, AppError> {
+    if plain::is_plain_mode() {
+        return plain::confirm(message, default);
+    }
     handle_inquire(
         Confirm::new(message)
             .with_default(default)
-            .with_help_message(texts::esc_to_go_back_help())
+            .with_help_message(help)
             .prompt(),
     )
 }
 
 pub fn prompt_text(message: &str) -> Result<Option<String>, AppError> {
-    handle_inquire(
-        Text::new(message)
-            .with_help_message(texts::esc_to_go_back_help())
-            .prompt(),
-    )
+    prompt_text_with_help(message, texts::esc_to_go_back_help())
+}
+
+pub fn prompt_text_with_help(message: &str, help: &str) -> Result<Option<String>, AppError> {
+    if plain::is_plain_mode() {
+        return plain::text(message, None);
+    }
+    handle_inquire(Text::new(message).with_help_message(help).prompt())
 }
 
 pub fn prompt_text_with_default(message: &str, default: &str) -> Result<Option<String>, AppError> {
+    if plain::is_plain_mode() {
+        return plain::text(message, Some(default));
+    }
     handle_inquire(
         Text::new(message)
             .with_default(default)
@@ -103,6 +168,26 @@ pub fn prompt_text_with_default(message: &str, default: &str) -> Result<Option<S
     )
 }
 
+/// 可预填初始值或占位符的文本输入；`--plain` 下初始值作为默认值
+pub fn prompt_text_input(
+    message: &str,
+    initial: Option<&str>,
+    placeholder: Option<&str>,
+    help: &str,
+) -> Result<Option<String>, AppError> {
+    if plain::is_plain_mode() {
+        return plain::text(message, initial);
+    }
+    let mut prompt = Text::new(message).with_help_message(help);
+    if let Some(initial) = initial {
+        prompt = prompt.with_initial_value(initial);
+    }
+    if let Some(placeholder) = placeholder {
+        prompt = prompt.with_placeholder(placeholder);
+    }
+    handle_inquire(prompt.prompt())
+}
+
 pub fn pause() {
     print!("{} ", texts::press_enter());
     let _ = io::stdout().flush();
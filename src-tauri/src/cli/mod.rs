@@ -1,9 +1,12 @@
 use clap::{Parser, Subcommand};
 use clap_complete::Shell;
+use std::path::PathBuf;
 
 pub mod commands;
 pub mod i18n;
 pub mod interactive;
+pub mod opener;
+pub mod plain;
 pub mod terminal;
 pub mod tui;
 pub mod ui;
@@ -18,7 +21,7 @@ use crate::app_config::AppType;
     long_about = "Unified management for Claude Code, Codex & Gemini CLI provider configurations, MCP servers, Skills extensions, and system prompts.\n\nRun without arguments to enter interactive mode."
 )]
 pub struct Cli {
-    /// Specify the application type
+    /// Specify the application type (auto-detected from .claude/.codex/.gemini project dirs when omitted)
     #[arg(short, long, global = true, value_enum)]
     pub app: Option<AppType>,
 
@@ -26,12 +29,64 @@ pub struct Cli {
     #[arg(short, long, global = true)]
     pub verbose: bool,
 
+    /// Disable network features (skill discovery/install, update checks, speedtests)
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Hide sponsor presets from the Add Provider templates for this run
+    #[arg(long, global = true)]
+    pub no_sponsors: bool,
+
+    /// Refuse every command that would modify configuration (for demos and audits)
+    #[arg(
+        long,
+        global = true,
+        env = "CC_SWITCH_READ_ONLY",
+        value_parser = clap::builder::FalseyValueParser::new()
+    )]
+    pub read_only: bool,
+
+    /// Print list tables without borders (for narrow terminals and piping)
+    #[arg(
+        long,
+        global = true,
+        env = "CC_SWITCH_COMPACT",
+        value_parser = clap::builder::FalseyValueParser::new()
+    )]
+    pub compact: bool,
+
+    /// Start the TUI on the main screen instead of restoring the last page and app
+    #[arg(long, global = true)]
+    pub fresh: bool,
+
+    /// Use numbered text menus instead of interactive widgets (auto-enabled without a TTY or with TERM=dumb)
+    #[arg(long, global = true)]
+    pub plain: bool,
+
+    /// Use a named profile (~/.cc-switch/profiles/<name>) for this run
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Also write logs to a rotating file (`--log-file=PATH`; bare `--log-file` uses
+    /// ~/.cc-switch/logs/cc-switch.log)
+    #[arg(
+        long,
+        global = true,
+        value_name = "PATH",
+        num_args = 0..=1,
+        require_equals = true
+    )]
+    pub log_file: Option<Option<PathBuf>>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Guided first-run setup (language, live config import, first provider)
+    Init(commands::init::InitCommand),
+
     /// Manage providers (list, add, edit, delete, switch)
     #[command(subcommand)]
     Provider(commands::provider::ProviderCommand),
@@ -56,6 +111,20 @@ pub enum Commands {
     #[command(subcommand)]
     Env(commands::env::EnvCommand),
 
+    /// Manage named profiles (separate config directories)
+    #[command(subcommand)]
+    Profile(commands::profile::ProfileCommand),
+
+    /// Inspect the audit log of configuration changes
+    #[command(subcommand)]
+    Audit(commands::audit::AuditCommand),
+
+    /// Measure endpoint latency for providers, URLs or a URL list file
+    Speedtest(commands::speedtest::SpeedtestCommand),
+
+    /// Show counts of providers, MCP servers, prompts, skills, repos and backups
+    Stats(commands::stats::StatsCommand),
+
     /// Update cc-switch binary to latest release
     Update(commands::update::UpdateCommand),
 
@@ -71,6 +140,42 @@ pub enum Commands {
     },
 }
 
+impl Commands {
+    /// 是否会修改配置；交互模式自行在各操作处拦截，这里视为只读
+    pub fn is_mutation(&self) -> bool {
+        match self {
+            Self::Init(_) | Self::Update(_) => true,
+            Self::Provider(cmd) => cmd.is_mutation(),
+            Self::Mcp(cmd) => cmd.is_mutation(),
+            Self::Prompts(cmd) => cmd.is_mutation(),
+            Self::Skills(cmd) => cmd.is_mutation(),
+            Self::Config(cmd) => cmd.is_mutation(),
+            Self::Profile(cmd) => cmd.is_mutation(),
+            Self::Env(_)
+            | Self::Audit(_)
+            | Self::Speedtest(_)
+            | Self::Stats(_)
+            | Self::Interactive
+            | Self::Completions { .. } => false,
+        }
+    }
+}
+
+/// 根据当前工作目录中的项目标记目录识别应用
+pub fn detect_app_from_cwd() -> Option<AppType> {
+    let cwd = std::env::current_dir().ok()?;
+    let detected = AppType::detect_from_dir(&cwd);
+    if let Some(app) = &detected {
+        log::debug!("根据工作目录 {} 自动识别应用: {app}", cwd.display());
+    }
+    detected
+}
+
+/// 解析目标应用：显式 `--app` 优先，其次按工作目录自动识别，最后回退到 Claude
+pub fn resolve_app(app: Option<AppType>) -> AppType {
+    app.or_else(detect_app_from_cwd).unwrap_or(AppType::Claude)
+}
+
 /// Generate shell completions
 pub fn generate_completions(shell: Shell) {
     use clap::CommandFactory;
@@ -0,0 +1,63 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::cli::i18n::texts;
+use crate::error::AppError;
+
+/// 当前平台用于打开目录的系统命令
+fn file_manager_program() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    }
+}
+
+fn spawn_opener(target: &OsStr) -> Result<(), AppError> {
+    let program = file_manager_program();
+    Command::new(program)
+        .arg(target)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| {
+            AppError::Message(texts::open_dir_failed(
+                program,
+                &target.to_string_lossy(),
+                &e.to_string(),
+            ))
+        })
+}
+
+/// 在系统文件管理器中打开目录（目录不存在时先创建）
+pub fn open_in_file_manager(dir: &Path) -> Result<(), AppError> {
+    std::fs::create_dir_all(dir).map_err(|e| AppError::io(dir, e))?;
+    spawn_opener(dir.as_os_str())
+}
+
+/// 在默认浏览器中打开链接
+pub fn open_url(url: &str) -> Result<(), AppError> {
+    spawn_opener(OsStr::new(url))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_manager_program_matches_platform() {
+        let program = file_manager_program();
+        if cfg!(target_os = "windows") {
+            assert_eq!(program, "explorer");
+        } else if cfg!(target_os = "macos") {
+            assert_eq!(program, "open");
+        } else {
+            assert_eq!(program, "xdg-open");
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! 纯文本交互：在哑终端 / CI shell 等 inquire 组件无法正常渲染的环境下，
+//! 用编号菜单和逐行输入代替 Select/Confirm/Text。
+
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::cli::i18n::texts;
+use crate::error::AppError;
+
+static PLAIN_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+/// 命令行 `--plain` 覆盖（仅对当前进程生效）
+pub fn set_plain_mode(enabled: bool) {
+    PLAIN_OVERRIDE.store(enabled, Ordering::Relaxed);
+}
+
+/// 是否使用纯文本菜单：显式 `--plain`、stdin 非 TTY 或 `TERM=dumb`
+pub fn is_plain_mode() -> bool {
+    PLAIN_OVERRIDE.load(Ordering::Relaxed)
+        || !io::stdin().is_terminal()
+        || std::env::var("TERM").is_ok_and(|term| term == "dumb")
+}
+
+/// 读取一行输入；EOF 返回 None
+fn read_line(prompt: &str) -> Result<Option<String>, AppError> {
+    print!("{prompt}");
+    let _ = io::stdout().flush();
+    let mut line = String::new();
+    let read = io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .map_err(|e| AppError::Message(texts::input_failed_error(&e.to_string())))?;
+    if read == 0 {
+        println!();
+        return Ok(None);
+    }
+    Ok(Some(line.trim().to_string()))
+}
+
+/// 编号单选；空输入或 EOF 视为返回上一步
+pub fn select<T>(message: &str, options: Vec<T>) -> Result<Option<T>, AppError>
+where
+    T: Clone + std::fmt::Display,
+{
+    if options.is_empty() {
+        return Ok(None);
+    }
+    println!("{message}");
+    for (idx, option) in options.iter().enumerate() {
+        println!("  {:>2}) {}", idx + 1, option);
+    }
+    loop {
+        let Some(input) = read_line(texts::plain_select_prompt())? else {
+            return Ok(None);
+        };
+        if input.is_empty() {
+            return Ok(None);
+        }
+        match parse_choice(&input, options.len()) {
+            Some(idx) => return Ok(Some(options[idx].clone())),
+            None => println!("{}", texts::plain_invalid_choice(options.len())),
+        }
+    }
+}
+
+/// 编号多选；空输入表示不选，`q` 或 EOF 视为返回上一步
+pub fn multiselect<T>(message: &str, options: Vec<T>) -> Result<Option<Vec<T>>, AppError>
+where
+    T: Clone + std::fmt::Display,
+{
+    multiselect_with_defaults(message, options, &[])
+}
+
+/// 带默认勾选（以 `*` 标记）的编号多选；有默认勾选时空输入保留默认
+pub fn multiselect_with_defaults<T>(
+    message: &str,
+    options: Vec<T>,
+    defaults: &[usize],
+) -> Result<Option<Vec<T>>, AppError>
+where
+    T: Clone + std::fmt::Display,
+{
+    println!("{message}");
+    for (idx, option) in options.iter().enumerate() {
+        let marker = if defaults.contains(&idx) { "*" } else { " " };
+        println!(" {marker}{:>2}) {}", idx + 1, option);
+    }
+    let prompt = if defaults.is_empty() {
+        texts::plain_multiselect_prompt()
+    } else {
+        texts::plain_multiselect_defaults_prompt()
+    };
+    loop {
+        let Some(input) = read_line(prompt)? else {
+            return Ok(None);
+        };
+        if input.eq_ignore_ascii_case("q") {
+            return Ok(None);
+        }
+        if input.trim().is_empty() && !defaults.is_empty() {
+            return Ok(Some(
+                defaults
+                    .iter()
+                    .filter_map(|&idx| options.get(idx).cloned())
+                    .collect(),
+            ));
+        }
+        match parse_choices(&input, options.len()) {
+            Some(indices) => {
+                return Ok(Some(
+                    indices
+                        .into_iter()
+                        .map(|idx| options[idx].clone())
+                        .collect(),
+                ))
+            }
+            None => println!("{}", texts::plain_invalid_choice(options.len())),
+        }
+    }
+}
+
+/// y/n 确认；空输入取默认值，EOF 视为返回上一步
+pub fn confirm(message: &str, default: bool) -> Result<Option<bool>, AppError> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    loop {
+        let Some(input) = read_line(&format!("{message} {hint} "))? else {
+            return Ok(None);
+        };
+        match parse_yes_no(&input, default) {
+            Some(value) => return Ok(Some(value)),
+            None => println!("{}", texts::plain_invalid_yes_no()),
+        }
+    }
+}
+
+/// 文本输入；带默认值时空输入取默认值，EOF 视为返回上一步
+pub fn text(message: &str, default: Option<&str>) -> Result<Option<String>, AppError> {
+    let prompt = match default.filter(|d| !d.is_empty()) {
+        Some(default) => format!("{message} [{default}] "),
+        None => format!("{message} "),
+    };
+    Ok(read_line(&prompt)?.map(|input| match default {
+        Some(default) if input.is_empty() => default.to_string(),
+        _ => input,
+    }))
+}
+
+/// 解析 1-based 编号
+fn parse_choice(input: &str, len: usize) -> Option<usize> {
+    input
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=len).contains(n))
+        .map(|n| n - 1)
+}
+
+/// 解析逗号/空格分隔的编号列表（去重、保持输入顺序）
+fn parse_choices(input: &str, len: usize) -> Option<Vec<usize>> {
+    let mut indices = Vec::new();
+    for part in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|p| !p.is_empty())
+    {
+        let idx = parse_choice(part, len)?;
+        if !indices.contains(&idx) {
+            indices.push(idx);
+        }
+    }
+    Some(indices)
+}
+
+fn parse_yes_no(input: &str, default: bool) -> Option<bool> {
+    match input.trim().to_lowercase().as_str() {
+        "" => Some(default),
+        "y" | "yes" | "是" => Some(true),
+        "n" | "no" | "否" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_choice_is_one_based_and_bounded() {
+        assert_eq!(parse_choice("1", 3), Some(0));
+        assert_eq!(parse_choice(" 3 ", 3), Some(2));
+        assert_eq!(parse_choice("0", 3), None);
+        assert_eq!(parse_choice("4", 3), None);
+        assert_eq!(parse_choice("x", 3), None);
+    }
+
+    #[test]
+    fn parse_choices_accepts_commas_and_spaces() {
+        assert_eq!(parse_choices("1, 3 2,1", 3), Some(vec![0, 2, 1]));
+        assert_eq!(parse_choices("", 3), Some(vec![]));
+        assert_eq!(parse_choices("1,9", 3), None);
+    }
+
+    #[test]
+    fn parse_yes_no_uses_default_for_empty_input() {
+        assert_eq!(parse_yes_no("", true), Some(true));
+        assert_eq!(parse_yes_no("", false), Some(false));
+        assert_eq!(parse_yes_no("Y", false), Some(true));
+        assert_eq!(parse_yes_no("no", true), Some(false));
+        assert_eq!(parse_yes_no("maybe", true), None);
+    }
+}
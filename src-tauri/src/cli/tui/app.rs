@@ -1,6 +1,7 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::Size;
-use std::collections::HashSet;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet, VecDeque};
 use unicode_width::UnicodeWidthChar;
 
 use crate::app_config::AppType;
@@ -8,8 +9,9 @@ use crate::cli::i18n::current_language;
 use crate::cli::i18n::texts;
 use crate::cli::i18n::Language;
 use crate::services::skill::SyncMethod;
+use crate::settings::ToastPosition;
 
-use super::data::UiData;
+use super::data::{ProviderRow, UiData};
 use super::form::{
     CodexWireApi, FormFocus, FormMode, FormState, GeminiAuthType, McpAddField, McpAddFormState,
     ProviderAddField, ProviderAddFormState,
@@ -17,6 +19,24 @@ use super::form::{
 use super::route::{NavItem, Route};
 
 const PROVIDER_NOTES_MAX_CHARS: usize = 120;
+const TOAST_HISTORY_LIMIT: usize = 50;
+/// 事件循环的默认 tick 间隔（毫秒）
+pub const DEFAULT_TICK_RATE_MS: u64 = 200;
+pub const MIN_TICK_RATE_MS: u64 = 50;
+pub const MAX_TICK_RATE_MS: u64 = 2000;
+const TICK_RATE_ENV: &str = "CC_SWITCH_TUI_TICK_MS";
+const DEFAULT_TOAST_MS: u64 = 2400;
+const MAX_TOAST_DURATION_SECS: u64 = 60;
+
+/// 生效的 tick 间隔：环境变量优先于设置，超出范围时钳制
+pub fn tick_rate_ms() -> u64 {
+    std::env::var(TICK_RATE_ENV)
+        .ok()
+        .and_then(|raw| raw.trim().parse::<u64>().ok())
+        .or_else(crate::settings::get_tui_tick_rate_ms)
+        .map(|ms| ms.clamp(MIN_TICK_RATE_MS, MAX_TICK_RATE_MS))
+        .unwrap_or(DEFAULT_TICK_RATE_MS)
+}
 
 #[derive(Debug, Clone)]
 pub struct FilterState {
@@ -67,25 +87,67 @@ impl Toast {
         Self {
             message: message.into(),
             kind,
-            remaining_ticks: 12,
+            remaining_ticks: toast_ticks(
+                kind,
+                crate::settings::get_toast_duration_secs(),
+                tick_rate_ms(),
+            ),
+        }
+    }
+}
+
+/// toast 停留的 tick 数：基础时长可配置，警告与错误分别停留 1.5 倍和 2 倍
+fn toast_ticks(kind: ToastKind, duration_secs: Option<u64>, tick_ms: u64) -> u16 {
+    let ms = duration_secs
+        .map(|secs| secs.clamp(1, MAX_TOAST_DURATION_SECS) * 1000)
+        .unwrap_or(DEFAULT_TOAST_MS);
+    let base = (ms / tick_ms.max(1)).max(1) as u16;
+    match kind {
+        ToastKind::Info | ToastKind::Success => base,
+        ToastKind::Warning => base + base / 2,
+        ToastKind::Error => base * 2,
+    }
+}
+
+impl ToastKind {
+    fn label(self) -> &'static str {
+        match self {
+            ToastKind::Info => texts::tui_toast_kind_info(),
+            ToastKind::Success => texts::tui_toast_kind_success(),
+            ToastKind::Warning => texts::tui_toast_kind_warning(),
+            ToastKind::Error => texts::tui_toast_kind_error(),
         }
     }
 }
 
+/// 消息日志中的一条 toast 记录
+#[derive(Debug, Clone)]
+pub struct ToastRecord {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub message: String,
+    pub kind: ToastKind,
+}
+
 #[derive(Debug, Clone)]
 pub enum ConfirmAction {
     Quit,
+    ProviderSwitch { id: String },
     ProviderDelete { id: String },
+    McpToggle { id: String, enabled: bool },
     McpDelete { id: String },
     PromptDelete { id: String },
     SkillsUninstall { directory: String },
     SkillsRepoRemove { owner: String, name: String },
+    SkillsImportFromApps { directories: Vec<String> },
+    SkillsUndoAppImport,
     ConfigImport { path: String },
     ConfigRestoreBackup { id: String },
+    ConfigResetApp,
     ConfigReset,
     SettingsSetSkipClaudeOnboarding { enabled: bool },
     EditorDiscard,
     EditorSaveBeforeClose,
+    ProviderEditSave { id: String, content: String },
 }
 
 #[derive(Debug, Clone)]
@@ -106,6 +168,8 @@ pub enum TextSubmit {
     SkillsRepoAdd,
     WebDavJianguoyunUsername,
     WebDavJianguoyunPassword,
+    SettingsToastDuration,
+    SettingsTickRate,
 }
 
 #[derive(Debug, Clone)]
@@ -117,6 +181,29 @@ pub struct TextInputState {
     pub secret: bool,
 }
 
+/// 启动概览中的一项：后台任务或环境检查，`warning` 为 None 表示正常
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StartupCheck {
+    pub label: String,
+    pub warning: Option<String>,
+}
+
+impl StartupCheck {
+    pub fn ok(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            warning: None,
+        }
+    }
+
+    pub fn warn(label: impl Into<String>, warning: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            warning: Some(warning.into()),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TextViewState {
     pub title: String,
@@ -129,6 +216,68 @@ pub enum LoadingKind {
     Generic,
     WebDav,
     UpdateCheck,
+    SkillsDiscover,
+}
+
+/// 测速目标：端点 URL 及其所属供应商
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpeedtestTarget {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl SpeedtestTarget {
+    fn from_row(row: &ProviderRow) -> Option<Self> {
+        row.api_url.as_ref().map(|url| Self {
+            provider_id: row.id.clone(),
+            provider_name: row.provider.name.clone(),
+            url: url.clone(),
+            headers: crate::services::provider_headers(&row.provider.settings_config),
+        })
+    }
+}
+
+/// 测速结果表中的一行
+#[derive(Debug, Clone)]
+pub struct SpeedtestResultRow {
+    pub target: SpeedtestTarget,
+    pub latency: Option<u128>,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeedtestSort {
+    Latency,
+    Status,
+}
+
+impl SpeedtestSort {
+    fn toggled(self) -> Self {
+        match self {
+            Self::Latency => Self::Status,
+            Self::Status => Self::Latency,
+        }
+    }
+}
+
+/// 按延迟（失败排在最后）或状态（成功 → 其他状态码 → 失败）排序，相同时按延迟
+pub fn sort_speedtest_rows(rows: &mut [SpeedtestResultRow], sort: SpeedtestSort) {
+    let latency = |row: &SpeedtestResultRow| row.latency.unwrap_or(u128::MAX);
+    match sort {
+        SpeedtestSort::Latency => rows.sort_by_key(|row| (row.latency.is_none(), latency(row))),
+        SpeedtestSort::Status => rows.sort_by_key(|row| {
+            let rank = match (row.status, &row.error) {
+                (Some(status), None) if (200..400).contains(&status) => 0,
+                (Some(_), None) => 1,
+                (Some(_), Some(_)) => 2,
+                (None, _) => 3,
+            };
+            (rank, latency(row))
+        }),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -148,6 +297,11 @@ pub enum Overlay {
         app_type: AppType,
         view: TextViewState,
     },
+    /// 通用片段应用前的预演：当前供应商配置合并片段前后的逐行 diff
+    CommonSnippetDiff {
+        app_type: AppType,
+        view: TextViewState,
+    },
     ClaudeModelPicker {
         selected: usize,
         editing: bool,
@@ -167,12 +321,13 @@ pub enum Overlay {
         message: String,
     },
     SpeedtestRunning {
-        url: String,
+        label: String,
     },
     SpeedtestResult {
-        url: String,
-        lines: Vec<String>,
-        scroll: usize,
+        label: String,
+        rows: Vec<SpeedtestResultRow>,
+        selected: usize,
+        sort: SpeedtestSort,
     },
     UpdateAvailable {
         current: String,
@@ -187,6 +342,9 @@ pub enum Overlay {
         success: bool,
         message: String,
     },
+    StartupSummary {
+        checks: Vec<StartupCheck>,
+    },
 }
 
 impl Overlay {
@@ -199,6 +357,59 @@ impl Overlay {
 pub enum EditorKind {
     Plain,
     Json,
+    Toml,
+}
+
+/// 编辑内容停止变化后延迟多少个 tick 再做实时校验
+const EDITOR_VALIDATION_DEBOUNCE_TICKS: u8 = 2;
+
+/// 编辑器内容的实时校验结果（JSON / TOML）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditorValidation {
+    Valid,
+    Invalid {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl EditorValidation {
+    fn check(kind: EditorKind, text: &str) -> Option<Self> {
+        match kind {
+            EditorKind::Plain => None,
+            EditorKind::Json => Some(match serde_json::from_str::<serde_json::Value>(text) {
+                Ok(_) => Self::Valid,
+                Err(err) => {
+                    let message = err.to_string();
+                    // serde_json 的错误信息末尾带有 "at line X column Y"，位置单独展示
+                    let message = message
+                        .split(" at line ")
+                        .next()
+                        .unwrap_or(&message)
+                        .to_string();
+                    Self::Invalid {
+                        line: err.line(),
+                        column: err.column(),
+                        message,
+                    }
+                }
+            }),
+            EditorKind::Toml => Some(match toml::from_str::<toml::Table>(text) {
+                Ok(_) => Self::Valid,
+                Err(err) => {
+                    let offset = err.span().map(|span| span.start).unwrap_or(0);
+                    let before = &text[..offset.min(text.len())];
+                    let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+                    Self::Invalid {
+                        line: before.matches('\n').count() + 1,
+                        column: before[line_start..].chars().count() + 1,
+                        message: err.message().trim().to_string(),
+                    }
+                }
+            }),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -231,6 +442,8 @@ pub struct EditorState {
     pub cursor_row: usize,
     pub cursor_col: usize,
     pub initial_text: String,
+    pub validation: Option<EditorValidation>,
+    validation_pending_ticks: u8,
 }
 
 impl EditorState {
@@ -249,6 +462,7 @@ impl EditorState {
             lines.push(String::new());
         }
 
+        let validation = EditorValidation::check(kind, &initial_text);
         Self {
             title: title.into(),
             kind,
@@ -259,6 +473,25 @@ impl EditorState {
             cursor_row: 0,
             cursor_col: 0,
             initial_text,
+            validation,
+            validation_pending_ticks: 0,
+        }
+    }
+
+    /// 内容变化后重新开始校验倒计时（防抖）
+    fn mark_edited(&mut self) {
+        if self.kind != EditorKind::Plain {
+            self.validation_pending_ticks = EDITOR_VALIDATION_DEBOUNCE_TICKS;
+        }
+    }
+
+    fn tick_validation(&mut self) {
+        if self.validation_pending_ticks == 0 {
+            return;
+        }
+        self.validation_pending_ticks -= 1;
+        if self.validation_pending_ticks == 0 {
+            self.validation = EditorValidation::check(self.kind, &self.text());
         }
     }
 
@@ -497,6 +730,10 @@ pub enum Action {
         directory: String,
         enabled: bool,
     },
+    SkillsToggleAllApps {
+        directory: String,
+        enabled: bool,
+    },
     SkillsInstall {
         spec: String,
     },
@@ -512,6 +749,7 @@ pub enum Action {
     SkillsDiscover {
         query: String,
     },
+    CancelSkillsDiscover,
     SkillsRepoAdd {
         spec: String,
     },
@@ -528,6 +766,7 @@ pub enum Action {
     SkillsImportFromApps {
         directories: Vec<String>,
     },
+    SkillsUndoAppImport,
 
     ProviderSwitch {
         id: String,
@@ -536,7 +775,12 @@ pub enum Action {
         id: String,
     },
     ProviderSpeedtest {
-        url: String,
+        label: String,
+        targets: Vec<SpeedtestTarget>,
+    },
+    ProviderFailoverQueue {
+        id: String,
+        queued: bool,
     },
 
     McpToggle {
@@ -554,9 +798,12 @@ pub enum Action {
     McpValidate {
         command: String,
     },
+    McpCheckAll,
 
     PromptActivate {
         id: String,
+        /// false 时保留其他已启用的提示词（追加启用）
+        exclusive: bool,
     },
     PromptDeactivate {
         id: String,
@@ -578,13 +825,18 @@ pub enum Action {
         id: String,
     },
     ConfigShowFull,
+    ConfigEditInEditor,
     ConfigValidate,
+    ConfigOpenDir,
     ConfigCommonSnippetClear {
         app_type: AppType,
     },
     ConfigCommonSnippetApply {
         app_type: AppType,
     },
+    ConfigCommonSnippetPreview {
+        app_type: AppType,
+    },
     ConfigWebDavCheckConnection,
     ConfigWebDavUpload,
     ConfigWebDavDownload,
@@ -593,6 +845,7 @@ pub enum Action {
         username: String,
         password: String,
     },
+    ConfigResetApp,
     ConfigReset,
 
     EditorSubmit {
@@ -604,6 +857,24 @@ pub enum Action {
     SetSkipClaudeOnboarding {
         enabled: bool,
     },
+    SetToastDuration {
+        secs: Option<u64>,
+    },
+    SetToastPosition {
+        position: ToastPosition,
+    },
+    SetTickRate {
+        ms: Option<u64>,
+    },
+    SetOfflineMode {
+        enabled: bool,
+    },
+    SetSyncSkillsOnSwitch {
+        enabled: bool,
+    },
+    SetQuietSkillFallback {
+        quiet: bool,
+    },
     SetLanguage(Language),
 
     CheckUpdate,
@@ -612,10 +883,49 @@ pub enum Action {
     CancelUpdateCheck,
 }
 
+impl Action {
+    /// 是否会修改配置（`--read-only` 下拒绝执行）
+    pub fn is_mutation(&self) -> bool {
+        match self {
+            Action::None
+            | Action::ReloadData
+            | Action::SwitchRoute(_)
+            | Action::Quit
+            | Action::SetAppType(_)
+            | Action::LocalEnvRefresh
+            | Action::SkillsDiscover { .. }
+            | Action::CancelSkillsDiscover
+            | Action::SkillsScanUnmanaged
+            | Action::ProviderSpeedtest { .. }
+            | Action::McpValidate { .. }
+            | Action::McpCheckAll
+            | Action::ConfigExport { .. }
+            | Action::ConfigShowFull
+            | Action::ConfigValidate
+            | Action::ConfigOpenDir
+            | Action::ConfigWebDavCheckConnection
+            | Action::EditorDiscard
+            | Action::CheckUpdate
+            | Action::CancelUpdate
+            | Action::CancelUpdateCheck => false,
+            // 仅回填表单内容，真正保存时才会再次提交
+            Action::EditorSubmit {
+                submit:
+                    EditorSubmit::ProviderFormApplyJson
+                    | EditorSubmit::ProviderFormApplyCodexAuth
+                    | EditorSubmit::ProviderFormApplyCodexConfigToml,
+                ..
+            } => false,
+            _ => true,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ConfigItem {
     Path,
     ShowFull,
+    EditInEditor,
     Export,
     Import,
     Backup,
@@ -623,13 +933,15 @@ pub enum ConfigItem {
     Validate,
     CommonSnippet,
     WebDavSync,
+    ResetApp,
     Reset,
 }
 
 impl ConfigItem {
-    pub const ALL: [ConfigItem; 10] = [
+    pub const ALL: [ConfigItem; 12] = [
         ConfigItem::Path,
         ConfigItem::ShowFull,
+        ConfigItem::EditInEditor,
         ConfigItem::Export,
         ConfigItem::Import,
         ConfigItem::Backup,
@@ -637,6 +949,7 @@ impl ConfigItem {
         ConfigItem::Validate,
         ConfigItem::CommonSnippet,
         ConfigItem::WebDavSync,
+        ConfigItem::ResetApp,
         ConfigItem::Reset,
     ];
 }
@@ -645,13 +958,25 @@ impl ConfigItem {
 pub enum SettingsItem {
     Language,
     SkipClaudeOnboarding,
+    OfflineMode,
+    SyncSkillsOnSwitch,
+    QuietSkillFallback,
+    ToastDuration,
+    ToastPosition,
+    TickRate,
     CheckForUpdates,
 }
 
 impl SettingsItem {
-    pub const ALL: [SettingsItem; 3] = [
+    pub const ALL: [SettingsItem; 9] = [
         SettingsItem::Language,
         SettingsItem::SkipClaudeOnboarding,
+        SettingsItem::OfflineMode,
+        SettingsItem::SyncSkillsOnSwitch,
+        SettingsItem::QuietSkillFallback,
+        SettingsItem::ToastDuration,
+        SettingsItem::ToastPosition,
+        SettingsItem::TickRate,
         SettingsItem::CheckForUpdates,
     ];
 }
@@ -677,6 +1002,41 @@ impl WebDavConfigItem {
     ];
 }
 
+/// 计算列表视口的起始偏移：尽量保持上次偏移，仅在选中项移出视口时滚动
+pub fn scroll_window(len: usize, selected: usize, offset: usize, height: usize) -> usize {
+    if len == 0 || height == 0 {
+        return 0;
+    }
+    let selected = selected.min(len - 1);
+    let offset = offset.min(len.saturating_sub(height));
+    if selected < offset {
+        selected
+    } else if selected >= offset + height {
+        selected + 1 - height
+    } else {
+        offset
+    }
+}
+
+/// 各列表路由的滚动偏移（渲染时按视口高度更新）
+#[derive(Debug, Clone, Default)]
+pub struct ListScroll {
+    offsets: RefCell<HashMap<Route, usize>>,
+}
+
+impl ListScroll {
+    pub fn offset(&self, route: &Route) -> usize {
+        self.offsets.borrow().get(route).copied().unwrap_or(0)
+    }
+
+    /// 根据列表长度、选中项与视口高度计算并记录新的偏移
+    pub fn window(&self, route: &Route, len: usize, selected: usize, height: usize) -> usize {
+        let offset = scroll_window(len, selected, self.offset(route), height);
+        self.offsets.borrow_mut().insert(route.clone(), offset);
+        offset
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct App {
     pub app_type: AppType,
@@ -690,6 +1050,7 @@ pub struct App {
     pub form: Option<FormState>,
     pub overlay: Overlay,
     pub toast: Option<Toast>,
+    pub toast_history: VecDeque<ToastRecord>,
     pub should_quit: bool,
     pub last_size: Size,
     pub tick: u64,
@@ -713,6 +1074,11 @@ pub struct App {
     pub webdav_quick_setup_username: Option<String>,
     pub language_idx: usize,
     pub settings_idx: usize,
+    pub list_scroll: ListScroll,
+    /// 数字键快速定位的输入缓冲（如输入 12 后按 Enter 跳到第 12 行）
+    pub count_buffer: String,
+    /// 正在进行的故障转移测速标签；测速完成后切换到最快的健康供应商
+    pub failover_label: Option<String>,
 }
 
 impl App {
@@ -729,6 +1095,7 @@ impl App {
             form: None,
             overlay: Overlay::None,
             toast: None,
+            toast_history: VecDeque::new(),
             should_quit: false,
             last_size: Size::new(0, 0),
             tick: 0,
@@ -750,6 +1117,9 @@ impl App {
             webdav_quick_setup_username: None,
             language_idx: 0,
             settings_idx: 0,
+            list_scroll: ListScroll::default(),
+            count_buffer: String::new(),
+            failover_label: None,
         }
     }
 
@@ -797,6 +1167,15 @@ impl App {
         Action::SwitchRoute(route)
     }
 
+    /// 启动时恢复上次所在页面（不产生 Action，数据由调用方加载）
+    pub fn restore_route(&mut self, route: Route) {
+        if let Some(parent) = route.parent() {
+            let _ = self.set_route_no_history(parent.clone());
+            self.route_stack.push(parent);
+        }
+        let _ = self.set_route_no_history(route);
+    }
+
     fn push_route_and_switch(&mut self, route: Route) -> Action {
         if route == self.route {
             return Action::None;
@@ -815,6 +1194,9 @@ impl App {
 
     pub fn on_tick(&mut self) {
         self.tick = self.tick.wrapping_add(1);
+        if let Some(editor) = &mut self.editor {
+            editor.tick_validation();
+        }
         if let Some(toast) = &mut self.toast {
             if toast.remaining_ticks > 0 {
                 toast.remaining_ticks -= 1;
@@ -825,8 +1207,90 @@ impl App {
         }
     }
 
+    /// 启动检查有警告时才弹出汇总，全部通过时不打断首次按键
+    pub fn show_startup_summary(&mut self, checks: Vec<StartupCheck>) {
+        if self.overlay.is_active() || checks.iter().all(|check| check.warning.is_none()) {
+            return;
+        }
+        self.overlay = Overlay::StartupSummary { checks };
+    }
+
     pub fn push_toast(&mut self, message: impl Into<String>, kind: ToastKind) {
-        self.toast = Some(Toast::new(message, kind));
+        let toast = Toast::new(message, kind);
+        if self.toast_history.len() >= TOAST_HISTORY_LIMIT {
+            self.toast_history.pop_front();
+        }
+        self.toast_history.push_back(ToastRecord {
+            at: chrono::Local::now(),
+            message: toast.message.clone(),
+            kind,
+        });
+        self.toast = Some(toast);
+    }
+
+    /// 打开消息日志（最新的记录在最上方）
+    pub fn open_toast_log(&mut self) {
+        let lines = if self.toast_history.is_empty() {
+            vec![texts::tui_toast_log_empty().to_string()]
+        } else {
+            self.toast_history
+                .iter()
+                .rev()
+                .map(|record| {
+                    format!(
+                        "{}  {:<7}  {}",
+                        record.at.format("%H:%M:%S"),
+                        record.kind.label(),
+                        record.message
+                    )
+                })
+                .collect()
+        };
+        self.overlay = Overlay::TextView(TextViewState {
+            title: texts::tui_toast_log_title().to_string(),
+            lines,
+            scroll: 0,
+        });
+    }
+
+    /// 应用未初始化时切换供应商 / 开关 MCP 不会写入 live 文件，先弹窗确认
+    fn confirm_if_live_sync_skipped(&mut self, data: &UiData, action: Action) -> Action {
+        if !data.live_sync_skipped {
+            return action;
+        }
+        let confirm = match &action {
+            Action::ProviderSwitch { id } => ConfirmAction::ProviderSwitch { id: id.clone() },
+            Action::McpToggle { id, enabled } => ConfirmAction::McpToggle {
+                id: id.clone(),
+                enabled: *enabled,
+            },
+            _ => return action,
+        };
+        self.overlay = Overlay::Confirm(ConfirmOverlay {
+            title: texts::tui_confirm_live_sync_skip_title().to_string(),
+            message: texts::tui_confirm_live_sync_skip_message(self.app_type.as_str()),
+            action: confirm,
+        });
+        Action::None
+    }
+
+    fn start_speedtest(&mut self, label: String, targets: Vec<SpeedtestTarget>) -> Action {
+        if self.block_if_offline() {
+            return Action::None;
+        }
+        self.overlay = Overlay::SpeedtestRunning {
+            label: label.clone(),
+        };
+        Action::ProviderSpeedtest { label, targets }
+    }
+
+    /// 离线模式下拦截需要网络的操作并提示，返回 true 表示已拦截
+    fn block_if_offline(&mut self) -> bool {
+        if !crate::settings::is_offline_mode() {
+            return false;
+        }
+        self.push_toast(texts::tui_toast_offline_mode_blocked(), ToastKind::Warning);
+        true
     }
 
     pub fn open_help(&mut self) {
@@ -845,6 +1309,22 @@ impl App {
             return Action::Quit;
         }
 
+        if key.modifiers.contains(KeyModifiers::CONTROL)
+            && matches!(key.code, KeyCode::Char('v'))
+            && self.accepts_text_input()
+        {
+            match read_clipboard_text() {
+                Ok(text) => return self.on_paste(&text, data),
+                Err(err) => {
+                    self.push_toast(
+                        texts::tui_toast_clipboard_unavailable(&err),
+                        ToastKind::Warning,
+                    );
+                    return Action::None;
+                }
+            }
+        }
+
         if self.overlay.is_active() {
             return self.on_overlay_key(key, data);
         }
@@ -861,12 +1341,20 @@ impl App {
             return self.on_filter_key(key);
         }
 
+        if let Some(action) = self.on_count_key(&key, data) {
+            return action;
+        }
+
         // Global actions.
         match key.code {
             KeyCode::Char('?') => {
                 self.open_help();
                 return Action::None;
             }
+            KeyCode::Char('L') => {
+                self.open_toast_log();
+                return Action::None;
+            }
             KeyCode::Char('/') => {
                 self.filter.active = true;
                 return Action::None;
@@ -898,6 +1386,124 @@ impl App {
         }
     }
 
+    /// 将粘贴内容（括号粘贴事件或 Ctrl-V 剪贴板）插入当前聚焦的输入框。
+    ///
+    /// 单行输入会去掉换行；多行编辑器中的换行按回车处理。
+    pub fn on_paste(&mut self, text: &str, data: &UiData) -> Action {
+        if !self.accepts_text_input() {
+            return Action::None;
+        }
+
+        let multiline = self.editor.is_some() && !self.overlay.is_active();
+        for c in text.chars() {
+            let code = match c {
+                '\r' => continue,
+                '\n' if multiline => KeyCode::Enter,
+                '\n' => continue,
+                '\t' if multiline => KeyCode::Tab,
+                c if c.is_control() => continue,
+                c => KeyCode::Char(c),
+            };
+            let _ = self.on_key(KeyEvent::new(code, KeyModifiers::NONE), data);
+        }
+        Action::None
+    }
+
+    /// 当前是否有可接收文本输入的焦点（输入框、编辑器、过滤器）。
+    fn accepts_text_input(&self) -> bool {
+        match &self.overlay {
+            Overlay::TextInput(_) => return true,
+            Overlay::ClaudeModelPicker { editing, .. } => return *editing,
+            overlay if overlay.is_active() => return false,
+            _ => {}
+        }
+        if self.editor.is_some() {
+            return true;
+        }
+        match &self.form {
+            Some(FormState::ProviderAdd(provider)) => provider.editing,
+            Some(FormState::McpAdd(mcp)) => mcp.editing,
+            None => self.filter.active,
+        }
+    }
+
+    /// 数字键快速定位：每输入一位即跳到对应行（从 1 开始，超出则停在末行），
+    /// 多位数后按 Enter 确认；其他按键清空缓冲并照常处理
+    fn on_count_key(&mut self, key: &KeyEvent, data: &UiData) -> Option<Action> {
+        const MAX_COUNT_DIGITS: usize = 4;
+
+        let digit = match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => c,
+            KeyCode::Enter if !self.count_buffer.is_empty() => {
+                self.count_buffer.clear();
+                return Some(Action::None);
+            }
+            _ => {
+                self.count_buffer.clear();
+                return None;
+            }
+        };
+
+        let len = self.selection_len(data)?;
+        if digit == '0' && self.count_buffer.is_empty() {
+            return Some(Action::None);
+        }
+        if self.count_buffer.len() >= MAX_COUNT_DIGITS {
+            self.count_buffer.clear();
+        }
+        self.count_buffer.push(digit);
+
+        let row = self.count_buffer.parse::<usize>().unwrap_or(1).max(1);
+        if let Some(selected) = self.selection_mut() {
+            *selected = (row - 1).min(len.saturating_sub(1));
+        }
+        Some(Action::None)
+    }
+
+    /// 当前焦点所在列表的长度；没有可定位的列表时返回 None
+    fn selection_len(&self, data: &UiData) -> Option<usize> {
+        if matches!(self.focus, Focus::Nav) {
+            return Some(NavItem::ALL.len());
+        }
+        let len = match &self.route {
+            Route::Providers => visible_providers(&self.filter, data).len(),
+            Route::Mcp => visible_mcp(&self.filter, data).len(),
+            Route::Prompts => visible_prompts(&self.filter, data).len(),
+            Route::Skills => visible_skills_installed(&self.filter, data).len(),
+            Route::SkillsDiscover => {
+                visible_skills_discover(&self.filter, &self.skills_discover_results).len()
+            }
+            Route::SkillsRepos => visible_skills_repos(&self.filter, data).len(),
+            Route::SkillsUnmanaged => {
+                visible_skills_unmanaged(&self.filter, &self.skills_unmanaged_results).len()
+            }
+            Route::Config => visible_config_items(&self.filter).len(),
+            Route::ConfigWebDav => visible_webdav_config_items(&self.filter).len(),
+            Route::Settings => SettingsItem::ALL.len(),
+            Route::Main | Route::ProviderDetail { .. } | Route::SkillDetail { .. } => return None,
+        };
+        Some(len)
+    }
+
+    fn selection_mut(&mut self) -> Option<&mut usize> {
+        if matches!(self.focus, Focus::Nav) {
+            return Some(&mut self.nav_idx);
+        }
+        match &self.route {
+            Route::Providers => Some(&mut self.provider_idx),
+            Route::Mcp => Some(&mut self.mcp_idx),
+            Route::Prompts => Some(&mut self.prompt_idx),
+            Route::Skills => Some(&mut self.skills_idx),
+            Route::SkillsDiscover => Some(&mut self.skills_discover_idx),
+            Route::SkillsRepos => Some(&mut self.skills_repo_idx),
+            Route::SkillsUnmanaged => Some(&mut self.skills_unmanaged_idx),
+            Route::Config => Some(&mut self.config_idx),
+            Route::ConfigWebDav => Some(&mut self.config_webdav_idx),
+            Route::Settings => Some(&mut self.settings_idx),
+            Route::Main | Route::ProviderDetail { .. } | Route::SkillDetail { .. } => None,
+        }
+    }
+
     fn on_back_key(&mut self) -> Action {
         match self.route {
             Route::Main => {
@@ -1013,6 +1619,15 @@ impl App {
                     enabled,
                 }
             }
+            KeyCode::Char('X') => {
+                let Some(skill) = visible.get(self.skills_idx) else {
+                    return Action::None;
+                };
+                Action::SkillsToggleAllApps {
+                    directory: skill.directory.clone(),
+                    enabled: !enabled_for_all_apps(&skill.apps),
+                }
+            }
             KeyCode::Char('i') => self.push_route_and_switch(Route::SkillsUnmanaged),
             _ => Action::None,
         }
@@ -1033,6 +1648,9 @@ impl App {
                 Action::None
             }
             KeyCode::Char('f') => {
+                if self.block_if_offline() {
+                    return Action::None;
+                }
                 self.overlay = Overlay::TextInput(TextInputState {
                     title: texts::tui_skills_discover_title().to_string(),
                     prompt: texts::tui_skills_discover_prompt().to_string(),
@@ -1043,6 +1661,9 @@ impl App {
                 Action::None
             }
             KeyCode::Enter => {
+                if self.block_if_offline() {
+                    return Action::None;
+                }
                 let visible = visible_skills_discover(&self.filter, &self.skills_discover_results);
                 let Some(skill) = visible.get(self.skills_discover_idx) else {
                     return Action::None;
@@ -1148,7 +1769,26 @@ impl App {
                     .cloned()
                     .collect::<Vec<_>>();
                 directories.sort();
-                Action::SkillsImportFromApps { directories }
+                let preview = self
+                    .skills_unmanaged_results
+                    .iter()
+                    .filter(|skill| directories.contains(&skill.directory))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                self.overlay = Overlay::Confirm(ConfirmOverlay {
+                    title: texts::tui_skills_import_title().to_string(),
+                    message: texts::skills_import_from_apps_confirm(&preview),
+                    action: ConfirmAction::SkillsImportFromApps { directories },
+                });
+                Action::None
+            }
+            KeyCode::Char('u') => {
+                self.overlay = Overlay::Confirm(ConfirmOverlay {
+                    title: texts::tui_skills_undo_import_title().to_string(),
+                    message: texts::tui_confirm_undo_import_message().to_string(),
+                    action: ConfirmAction::SkillsUndoAppImport,
+                });
+                Action::None
             }
             KeyCode::Char('r') => Action::SkillsScanUnmanaged,
             _ => Action::None,
@@ -1170,6 +1810,10 @@ impl App {
                 directory: skill.directory.clone(),
                 enabled: !skill.apps.is_enabled_for(&self.app_type),
             },
+            KeyCode::Char('X') => Action::SkillsToggleAllApps {
+                directory: skill.directory.clone(),
+                enabled: !enabled_for_all_apps(&skill.apps),
+            },
             KeyCode::Char('d') => {
                 self.overlay = Overlay::Confirm(ConfirmOverlay {
                     title: texts::tui_skills_uninstall_title().to_string(),
@@ -1229,7 +1873,8 @@ impl App {
                     self.push_toast(texts::tui_toast_provider_already_in_use(), ToastKind::Info);
                     return Action::None;
                 }
-                Action::ProviderSwitch { id: row.id.clone() }
+                let action = Action::ProviderSwitch { id: row.id.clone() };
+                self.confirm_if_live_sync_skipped(data, action)
             }
             KeyCode::Char('d') => {
                 let Some(row) = visible.get(self.provider_idx) else {
@@ -1256,12 +1901,50 @@ impl App {
                 let Some(row) = visible.get(self.provider_idx) else {
                     return Action::None;
                 };
-                let Some(url) = row.api_url.clone() else {
+                let Some(target) = SpeedtestTarget::from_row(row) else {
                     self.push_toast(texts::tui_toast_provider_no_api_url(), ToastKind::Warning);
                     return Action::None;
                 };
-                self.overlay = Overlay::SpeedtestRunning { url: url.clone() };
-                Action::ProviderSpeedtest { url }
+                self.start_speedtest(target.url.clone(), vec![target])
+            }
+            KeyCode::Char('T') => {
+                let targets: Vec<SpeedtestTarget> = visible
+                    .iter()
+                    .filter_map(|row| SpeedtestTarget::from_row(row))
+                    .collect();
+                if targets.is_empty() {
+                    self.push_toast(texts::tui_toast_provider_no_api_url(), ToastKind::Warning);
+                    return Action::None;
+                }
+                self.start_speedtest(texts::tui_speedtest_all_label(targets.len()), targets)
+            }
+            KeyCode::Char('f') => {
+                let Some(row) = visible.get(self.provider_idx) else {
+                    return Action::None;
+                };
+                Action::ProviderFailoverQueue {
+                    id: row.id.clone(),
+                    queued: !row.provider.in_failover_queue,
+                }
+            }
+            KeyCode::Char('F') => {
+                let targets: Vec<SpeedtestTarget> = data
+                    .providers
+                    .rows
+                    .iter()
+                    .filter(|row| row.provider.in_failover_queue)
+                    .filter_map(SpeedtestTarget::from_row)
+                    .collect();
+                if targets.is_empty() {
+                    self.push_toast(texts::tui_toast_failover_queue_empty(), ToastKind::Warning);
+                    return Action::None;
+                }
+                let label = texts::tui_failover_label(targets.len());
+                let action = self.start_speedtest(label.clone(), targets);
+                if !matches!(action, Action::None) {
+                    self.failover_label = Some(label);
+                }
+                action
             }
             _ => Action::None,
         }
@@ -1283,15 +1966,25 @@ impl App {
                     self.push_toast(texts::tui_toast_provider_already_in_use(), ToastKind::Info);
                     return Action::None;
                 }
-                Action::ProviderSwitch { id: row.id.clone() }
+                let action = Action::ProviderSwitch { id: row.id.clone() };
+                self.confirm_if_live_sync_skipped(data, action)
             }
             KeyCode::Char('t') => {
-                let Some(url) = row.api_url.clone() else {
+                let Some(target) = SpeedtestTarget::from_row(row) else {
                     self.push_toast(texts::tui_toast_provider_no_api_url(), ToastKind::Warning);
                     return Action::None;
                 };
-                self.overlay = Overlay::SpeedtestRunning { url: url.clone() };
-                Action::ProviderSpeedtest { url }
+                self.start_speedtest(target.url.clone(), vec![target])
+            }
+            KeyCode::Char('c') => {
+                let snippet = super::form::provider_share_value(&row.provider);
+                let text = serde_json::to_string_pretty(&snippet).unwrap_or_default();
+                self.overlay = Overlay::TextView(TextViewState {
+                    title: texts::tui_provider_share_title(&row.provider.name),
+                    lines: text.lines().map(|s| s.to_string()).collect(),
+                    scroll: 0,
+                });
+                Action::None
             }
             _ => Action::None,
         }
@@ -1326,10 +2019,11 @@ impl App {
                     return Action::None;
                 };
                 let enabled = row.server.apps.is_enabled_for(&self.app_type);
-                Action::McpToggle {
+                let action = Action::McpToggle {
                     id: row.id.clone(),
                     enabled: !enabled,
-                }
+                };
+                self.confirm_if_live_sync_skipped(data, action)
             }
             KeyCode::Char('m') => {
                 let Some(row) = visible.get(self.mcp_idx) else {
@@ -1344,6 +2038,7 @@ impl App {
                 Action::None
             }
             KeyCode::Char('i') => Action::McpImport,
+            KeyCode::Char('c') => Action::McpCheckAll,
             KeyCode::Char('v') => {
                 self.overlay = Overlay::TextInput(TextInputState {
                     title: texts::tui_input_validate_command_title().to_string(),
@@ -1397,10 +2092,27 @@ impl App {
                 let Some(row) = visible.get(self.prompt_idx) else {
                     return Action::None;
                 };
-                Action::PromptActivate { id: row.id.clone() }
+                Action::PromptActivate {
+                    id: row.id.clone(),
+                    exclusive: true,
+                }
+            }
+            KeyCode::Char('A') => {
+                let Some(row) = visible.get(self.prompt_idx) else {
+                    return Action::None;
+                };
+                Action::PromptActivate {
+                    id: row.id.clone(),
+                    exclusive: false,
+                }
             }
             KeyCode::Char('x') => {
-                let active = data.prompts.rows.iter().find(|p| p.prompt.enabled);
+                // 优先停用选中的已启用项，否则停用第一个已启用项
+                let active = visible
+                    .get(self.prompt_idx)
+                    .copied()
+                    .filter(|row| row.prompt.enabled)
+                    .or_else(|| data.prompts.rows.iter().find(|p| p.prompt.enabled));
                 let Some(active) = active else {
                     self.push_toast(
                         texts::tui_toast_prompt_no_active_to_deactivate(),
@@ -1470,6 +2182,7 @@ impl App {
                 }
                 Action::None
             }
+            KeyCode::Char('o') => Action::ConfigOpenDir,
             KeyCode::Enter => {
                 let Some(item) = items.get(self.config_idx) else {
                     return Action::None;
@@ -1491,6 +2204,7 @@ impl App {
                         Action::None
                     }
                     ConfigItem::ShowFull => Action::ConfigShowFull,
+                    ConfigItem::EditInEditor => Action::ConfigEditInEditor,
                     ConfigItem::Export => {
                         self.overlay = Overlay::TextInput(TextInputState {
                             title: texts::tui_config_export_title().to_string(),
@@ -1537,6 +2251,14 @@ impl App {
                         Action::None
                     }
                     ConfigItem::WebDavSync => self.push_route_and_switch(Route::ConfigWebDav),
+                    ConfigItem::ResetApp => {
+                        self.overlay = Overlay::Confirm(ConfirmOverlay {
+                            title: texts::tui_config_reset_title().to_string(),
+                            message: texts::config_reset_app_warning(self.app_type.as_str()),
+                            action: ConfirmAction::ConfigResetApp,
+                        });
+                        Action::None
+                    }
                     ConfigItem::Reset => {
                         self.overlay = Overlay::Confirm(ConfirmOverlay {
                             title: texts::tui_config_reset_title().to_string(),
@@ -1664,18 +2386,65 @@ impl App {
                     });
                     Action::None
                 }
-                Some(SettingsItem::CheckForUpdates) => Action::CheckUpdate,
-                None => Action::None,
-            },
-            _ => Action::None,
-        }
-    }
-
-    fn on_overlay_key(&mut self, key: KeyEvent, data: &UiData) -> Action {
-        if matches!(key.code, KeyCode::Char('e')) {
-            match &self.overlay {
-                Overlay::CommonSnippetPicker { selected } => {
-                    let app_type = snippet_picker_app_type(*selected);
+                Some(SettingsItem::OfflineMode) => Action::SetOfflineMode {
+                    enabled: !crate::settings::get_offline_mode(),
+                },
+                Some(SettingsItem::SyncSkillsOnSwitch) => Action::SetSyncSkillsOnSwitch {
+                    enabled: !crate::settings::get_sync_skills_on_switch(),
+                },
+                Some(SettingsItem::QuietSkillFallback) => Action::SetQuietSkillFallback {
+                    quiet: !crate::settings::get_quiet_skill_copy_fallback(),
+                },
+                Some(SettingsItem::ToastDuration) => {
+                    self.overlay = Overlay::TextInput(TextInputState {
+                        title: texts::tui_settings_toast_duration().to_string(),
+                        prompt: texts::tui_settings_toast_duration_prompt().to_string(),
+                        buffer: crate::settings::get_toast_duration_secs()
+                            .map(|secs| secs.to_string())
+                            .unwrap_or_default(),
+                        submit: TextSubmit::SettingsToastDuration,
+                        secret: false,
+                    });
+                    Action::None
+                }
+                Some(SettingsItem::ToastPosition) => Action::SetToastPosition {
+                    position: match crate::settings::get_toast_position() {
+                        ToastPosition::Top => ToastPosition::Bottom,
+                        ToastPosition::Bottom => ToastPosition::Top,
+                    },
+                },
+                Some(SettingsItem::TickRate) => {
+                    self.overlay = Overlay::TextInput(TextInputState {
+                        title: texts::tui_settings_tick_rate().to_string(),
+                        prompt: texts::tui_settings_tick_rate_prompt(
+                            MIN_TICK_RATE_MS,
+                            MAX_TICK_RATE_MS,
+                        ),
+                        buffer: crate::settings::get_tui_tick_rate_ms()
+                            .map(|ms| ms.to_string())
+                            .unwrap_or_default(),
+                        submit: TextSubmit::SettingsTickRate,
+                        secret: false,
+                    });
+                    Action::None
+                }
+                Some(SettingsItem::CheckForUpdates) => {
+                    if self.block_if_offline() {
+                        return Action::None;
+                    }
+                    Action::CheckUpdate
+                }
+                None => Action::None,
+            },
+            _ => Action::None,
+        }
+    }
+
+    fn on_overlay_key(&mut self, key: KeyEvent, data: &UiData) -> Action {
+        if matches!(key.code, KeyCode::Char('e')) {
+            match &self.overlay {
+                Overlay::CommonSnippetPicker { selected } => {
+                    let app_type = snippet_picker_app_type(*selected);
                     self.open_common_snippet_editor(app_type, data, None);
                     return Action::None;
                 }
@@ -1691,6 +2460,10 @@ impl App {
 
         match &mut self.overlay {
             Overlay::None => Action::None,
+            Overlay::StartupSummary { .. } => {
+                self.overlay = Overlay::None;
+                Action::None
+            }
             Overlay::Help => match key.code {
                 KeyCode::Esc | KeyCode::Char('?') => {
                     self.overlay = Overlay::None;
@@ -1702,9 +2475,16 @@ impl App {
                 KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter => {
                     let action = match &confirm.action {
                         ConfirmAction::Quit => Action::Quit,
+                        ConfirmAction::ProviderSwitch { id } => {
+                            Action::ProviderSwitch { id: id.clone() }
+                        }
                         ConfirmAction::ProviderDelete { id } => {
                             Action::ProviderDelete { id: id.clone() }
                         }
+                        ConfirmAction::McpToggle { id, enabled } => Action::McpToggle {
+                            id: id.clone(),
+                            enabled: *enabled,
+                        },
                         ConfirmAction::McpDelete { id } => Action::McpDelete { id: id.clone() },
                         ConfirmAction::PromptDelete { id } => {
                             Action::PromptDelete { id: id.clone() }
@@ -1718,17 +2498,28 @@ impl App {
                                 name: name.clone(),
                             }
                         }
+                        ConfirmAction::SkillsImportFromApps { directories } => {
+                            Action::SkillsImportFromApps {
+                                directories: directories.clone(),
+                            }
+                        }
+                        ConfirmAction::SkillsUndoAppImport => Action::SkillsUndoAppImport,
                         ConfirmAction::ConfigImport { path } => {
                             Action::ConfigImport { path: path.clone() }
                         }
                         ConfirmAction::ConfigRestoreBackup { id } => {
                             Action::ConfigRestoreBackup { id: id.clone() }
                         }
+                        ConfirmAction::ConfigResetApp => Action::ConfigResetApp,
                         ConfirmAction::ConfigReset => Action::ConfigReset,
                         ConfirmAction::SettingsSetSkipClaudeOnboarding { enabled } => {
                             Action::SetSkipClaudeOnboarding { enabled: *enabled }
                         }
                         ConfirmAction::EditorDiscard => Action::EditorDiscard,
+                        ConfirmAction::ProviderEditSave { id, content } => Action::EditorSubmit {
+                            submit: EditorSubmit::ProviderEdit { id: id.clone() },
+                            content: content.clone(),
+                        },
                         ConfirmAction::EditorSaveBeforeClose => {
                             if let Some(editor) = self.editor.as_ref() {
                                 Action::EditorSubmit {
@@ -1804,6 +2595,45 @@ impl App {
                             let name = if raw.is_empty() { None } else { Some(raw) };
                             Action::ConfigBackup { name }
                         }
+                        TextSubmit::SettingsToastDuration => {
+                            if raw.is_empty() {
+                                return Action::SetToastDuration { secs: None };
+                            }
+                            match raw.parse::<u64>() {
+                                Ok(secs) if (1..=MAX_TOAST_DURATION_SECS).contains(&secs) => {
+                                    Action::SetToastDuration { secs: Some(secs) }
+                                }
+                                _ => {
+                                    self.push_toast(
+                                        texts::tui_toast_invalid_toast_duration(
+                                            MAX_TOAST_DURATION_SECS,
+                                        ),
+                                        ToastKind::Warning,
+                                    );
+                                    Action::None
+                                }
+                            }
+                        }
+                        TextSubmit::SettingsTickRate => {
+                            if raw.is_empty() {
+                                return Action::SetTickRate { ms: None };
+                            }
+                            match raw.parse::<u64>() {
+                                Ok(ms) if (MIN_TICK_RATE_MS..=MAX_TICK_RATE_MS).contains(&ms) => {
+                                    Action::SetTickRate { ms: Some(ms) }
+                                }
+                                _ => {
+                                    self.push_toast(
+                                        texts::tui_toast_invalid_tick_rate(
+                                            MIN_TICK_RATE_MS,
+                                            MAX_TICK_RATE_MS,
+                                        ),
+                                        ToastKind::Warning,
+                                    );
+                                    Action::None
+                                }
+                            }
+                        }
                         TextSubmit::McpValidateCommand => {
                             if raw.is_empty() {
                                 self.push_toast(
@@ -1822,10 +2652,16 @@ impl App {
                                 );
                                 return Action::None;
                             }
+                            if self.block_if_offline() {
+                                return Action::None;
+                            }
                             Action::SkillsInstall { spec: raw }
                         }
                         TextSubmit::SkillsDiscoverQuery => {
                             self.skills_discover_query = raw.clone();
+                            if self.block_if_offline() {
+                                return Action::None;
+                            }
                             Action::SkillsDiscover { query: raw }
                         }
                         TextSubmit::SkillsRepoAdd => {
@@ -1984,6 +2820,9 @@ impl App {
                 KeyCode::Char('a') => Action::ConfigCommonSnippetApply {
                     app_type: app_type.clone(),
                 },
+                KeyCode::Char('p') => Action::ConfigCommonSnippetPreview {
+                    app_type: app_type.clone(),
+                },
                 KeyCode::Char('c') => Action::ConfigCommonSnippetClear {
                     app_type: app_type.clone(),
                 },
@@ -2003,6 +2842,29 @@ impl App {
                 }
                 _ => Action::None,
             },
+            Overlay::CommonSnippetDiff { app_type, view } => match key.code {
+                KeyCode::Char('a') => {
+                    let app_type = app_type.clone();
+                    self.open_common_snippet_view(app_type.clone(), data);
+                    Action::ConfigCommonSnippetApply { app_type }
+                }
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    let app_type = app_type.clone();
+                    self.open_common_snippet_view(app_type, data);
+                    Action::None
+                }
+                KeyCode::Up => {
+                    view.scroll = view.scroll.saturating_sub(1);
+                    Action::None
+                }
+                KeyCode::Down => {
+                    if !view.lines.is_empty() {
+                        view.scroll = (view.scroll + 1).min(view.lines.len() - 1);
+                    }
+                    Action::None
+                }
+                _ => Action::None,
+            },
             Overlay::SkillsSyncMethodPicker { selected } => match key.code {
                 KeyCode::Esc => {
                     self.overlay = Overlay::None;
@@ -2125,10 +2987,10 @@ impl App {
                 KeyCode::Esc => {
                     let kind = *kind;
                     self.overlay = Overlay::None;
-                    if kind == LoadingKind::UpdateCheck {
-                        Action::CancelUpdateCheck
-                    } else {
-                        Action::None
+                    match kind {
+                        LoadingKind::UpdateCheck => Action::CancelUpdateCheck,
+                        LoadingKind::SkillsDiscover => Action::CancelSkillsDiscover,
+                        _ => Action::None,
                     }
                 }
                 _ => Action::None,
@@ -2181,21 +3043,49 @@ impl App {
                 }
                 _ => Action::None,
             },
-            Overlay::SpeedtestResult { scroll, lines, .. } => match key.code {
+            Overlay::SpeedtestResult {
+                rows,
+                selected,
+                sort,
+                ..
+            } => match key.code {
                 KeyCode::Esc | KeyCode::Char('q') => {
                     self.overlay = Overlay::None;
                     Action::None
                 }
                 KeyCode::Up => {
-                    *scroll = scroll.saturating_sub(1);
+                    *selected = selected.saturating_sub(1);
                     Action::None
                 }
                 KeyCode::Down => {
-                    if !lines.is_empty() {
-                        *scroll = (*scroll + 1).min(lines.len() - 1);
+                    if !rows.is_empty() {
+                        *selected = (*selected + 1).min(rows.len() - 1);
                     }
                     Action::None
                 }
+                KeyCode::Char('s') => {
+                    *sort = sort.toggled();
+                    sort_speedtest_rows(rows, *sort);
+                    *selected = 0;
+                    Action::None
+                }
+                KeyCode::Enter => {
+                    let Some(id) = rows
+                        .get(*selected)
+                        .map(|row| row.target.provider_id.clone())
+                    else {
+                        return Action::None;
+                    };
+                    if id == data.providers.current_id {
+                        self.push_toast(
+                            texts::tui_toast_provider_already_in_use(),
+                            ToastKind::Info,
+                        );
+                        return Action::None;
+                    }
+                    self.overlay = Overlay::None;
+                    self.confirm_if_live_sync_skipped(data, Action::ProviderSwitch { id })
+                }
                 _ => Action::None,
             },
             Overlay::UpdateAvailable { selected, .. } => match key.code {
@@ -2301,7 +3191,7 @@ impl App {
         });
 
         let kind = if matches!(app_type, AppType::Codex) {
-            EditorKind::Plain
+            EditorKind::Toml
         } else {
             EditorKind::Json
         };
@@ -2358,6 +3248,18 @@ impl App {
             return Action::None;
         };
 
+        // Ctrl+R 只在供应商表单中切换密钥显示，其他表单照常处理按键
+        if key.modifiers.contains(KeyModifiers::CONTROL) && matches!(key.code, KeyCode::Char('r')) {
+            if let FormState::ProviderAdd(provider) = form {
+                if matches!(provider.focus, FormFocus::Fields) {
+                    if let Some(field) = provider.fields().get(provider.field_idx).copied() {
+                        provider.toggle_secret_reveal(field);
+                    }
+                }
+                return Action::None;
+            }
+        }
+
         if matches!(key.code, KeyCode::Tab) {
             match form {
                 FormState::ProviderAdd(provider) => {
@@ -2519,6 +3421,22 @@ impl App {
                     match key.code {
                         KeyCode::Esc | KeyCode::Enter => {
                             provider.editing = false;
+                            let mismatch = match selected {
+                                ProviderAddField::ClaudeBaseUrl
+                                | ProviderAddField::CodexBaseUrl
+                                | ProviderAddField::GeminiBaseUrl => {
+                                    provider.input(selected).and_then(|input| {
+                                        crate::cli::commands::provider_input::base_url_mismatch_warning(
+                                            &provider.app_type,
+                                            &input.value,
+                                        )
+                                    })
+                                }
+                                _ => None,
+                            };
+                            if let Some(message) = mismatch {
+                                self.push_toast(message, ToastKind::Warning);
+                            }
                             return Action::None;
                         }
                         KeyCode::Left => {
@@ -2563,12 +3481,10 @@ impl App {
                                     .iter()
                                     .map(|row| row.id.clone())
                                     .collect::<Vec<_>>();
-                                provider.id.set(
-                                    crate::cli::commands::provider_input::generate_provider_id(
-                                        provider.name.value.trim(),
-                                        &existing_ids,
-                                    ),
-                                );
+                                provider.id.set(crate::provider::generate_provider_id(
+                                    provider.name.value.trim(),
+                                    &existing_ids,
+                                ));
                             }
                             return Action::None;
                         }
@@ -2590,12 +3506,10 @@ impl App {
                                     .iter()
                                     .map(|row| row.id.clone())
                                     .collect::<Vec<_>>();
-                                provider.id.set(
-                                    crate::cli::commands::provider_input::generate_provider_id(
-                                        provider.name.value.trim(),
-                                        &existing_ids,
-                                    ),
-                                );
+                                provider.id.set(crate::provider::generate_provider_id(
+                                    provider.name.value.trim(),
+                                    &existing_ids,
+                                ));
                             }
                             return Action::None;
                         }
@@ -2631,12 +3545,10 @@ impl App {
                                     .iter()
                                     .map(|row| row.id.clone())
                                     .collect::<Vec<_>>();
-                                provider.id.set(
-                                    crate::cli::commands::provider_input::generate_provider_id(
-                                        provider.name.value.trim(),
-                                        &existing_ids,
-                                    ),
-                                );
+                                provider.id.set(crate::provider::generate_provider_id(
+                                    provider.name.value.trim(),
+                                    &existing_ids,
+                                ));
                             }
                             return Action::None;
                         }
@@ -2772,7 +3684,7 @@ impl App {
                                     .to_string();
                                 self.open_editor(
                                     texts::tui_codex_config_toml_title(),
-                                    EditorKind::Plain,
+                                    EditorKind::Toml,
                                     config_text,
                                     EditorSubmit::ProviderFormApplyCodexConfigToml,
                                 );
@@ -2939,7 +3851,7 @@ impl App {
                             return Action::None;
                         }
                         KeyCode::Char(c) => {
-                            if c.is_control() {
+                            if c.is_control() || key.modifiers.contains(KeyModifiers::CONTROL) {
                                 return Action::None;
                             }
                             let _ = mcp.input_mut(selected).map(|input| input.insert_char(c));
@@ -2957,17 +3869,21 @@ impl App {
                             mcp.field_idx = (mcp.field_idx + 1).min(fields.len() - 1);
                             return Action::None;
                         }
+                        KeyCode::Left | KeyCode::Right if selected == McpAddField::Apps => {
+                            mcp.move_app_cursor(key.code == KeyCode::Right);
+                            return Action::None;
+                        }
+                        KeyCode::Char('a') if selected == McpAddField::Apps => {
+                            mcp.toggle_all_apps();
+                            return Action::None;
+                        }
                         KeyCode::Char(' ') | KeyCode::Enter => match selected {
-                            McpAddField::AppClaude => {
-                                mcp.apps.claude = !mcp.apps.claude;
+                            McpAddField::Transport => {
+                                mcp.transport = mcp.transport.next();
                                 return Action::None;
                             }
-                            McpAddField::AppCodex => {
-                                mcp.apps.codex = !mcp.apps.codex;
-                                return Action::None;
-                            }
-                            McpAddField::AppGemini => {
-                                mcp.apps.gemini = !mcp.apps.gemini;
+                            McpAddField::Apps => {
+                                mcp.toggle_app_at_cursor();
                                 return Action::None;
                             }
                             _ => {
@@ -3023,6 +3939,25 @@ impl App {
                         );
                         return Action::None;
                     }
+                    // 到期日无效时停在该字段，错误在表单内联显示
+                    if provider.expires_at_invalid() {
+                        provider.focus = FormFocus::Fields;
+                        if let Some(idx) = provider
+                            .fields()
+                            .iter()
+                            .position(|field| *field == ProviderAddField::ExpiresAt)
+                        {
+                            provider.field_idx = idx;
+                        }
+                        return Action::None;
+                    }
+                    if crate::services::parse_header_lines(&provider.headers.value).is_err() {
+                        self.push_toast(
+                            texts::tui_toast_provider_invalid_headers(),
+                            ToastKind::Warning,
+                        );
+                        return Action::None;
+                    }
 
                     let provider_json = if matches!(provider.app_type, AppType::Codex) {
                         provider.to_provider_json_value()
@@ -3040,20 +3975,54 @@ impl App {
                     let content = serde_json::to_string_pretty(&provider_json)
                         .unwrap_or_else(|_| "{}".to_string());
 
-                    return Action::EditorSubmit {
-                        submit: match &provider.mode {
-                            FormMode::Add => EditorSubmit::ProviderAdd,
-                            FormMode::Edit { id } => EditorSubmit::ProviderEdit { id: id.clone() },
-                        },
-                        content,
+                    let FormMode::Edit { id } = &provider.mode else {
+                        return Action::EditorSubmit {
+                            submit: EditorSubmit::ProviderAdd,
+                            content,
+                        };
+                    };
+                    let original = data
+                        .providers
+                        .rows
+                        .iter()
+                        .find(|row| &row.id == id)
+                        .map(|row| &row.provider);
+                    let edited =
+                        serde_json::from_value::<crate::provider::Provider>(provider_json).ok();
+                    let changes = match (original, edited) {
+                        (Some(original), Some(edited)) => {
+                            super::form::provider_field_changes(original, &edited)
+                        }
+                        _ => Vec::new(),
                     };
+                    // 没有字段变化时无需确认，直接保存关闭表单
+                    if changes.is_empty() {
+                        return Action::EditorSubmit {
+                            submit: EditorSubmit::ProviderEdit { id: id.clone() },
+                            content,
+                        };
+                    }
+                    self.overlay = Overlay::Confirm(ConfirmOverlay {
+                        title: texts::tui_provider_edit_confirm_title().to_string(),
+                        message: super::form::format_provider_field_changes(&changes).join("\n"),
+                        action: ConfirmAction::ProviderEditSave {
+                            id: id.clone(),
+                            content,
+                        },
+                    });
+                    return Action::None;
                 }
                 FormState::McpAdd(mcp) => {
                     if !mcp.has_required_fields() {
                         self.push_toast(texts::tui_toast_mcp_missing_fields(), ToastKind::Warning);
                         return Action::None;
                     }
-                    if mcp.command.is_blank() {
+                    if mcp.transport.is_remote() {
+                        if !crate::services::is_valid_mcp_url(&mcp.url.value) {
+                            self.push_toast(texts::tui_toast_mcp_invalid_url(), ToastKind::Warning);
+                            return Action::None;
+                        }
+                    } else if mcp.command.is_blank() {
                         self.push_toast(texts::tui_toast_command_empty(), ToastKind::Warning);
                         return Action::None;
                     }
@@ -3181,27 +4150,32 @@ impl App {
             }
             KeyCode::Backspace => {
                 editor.backspace();
+                editor.mark_edited();
                 editor.ensure_cursor_visible(viewport);
                 Action::None
             }
             KeyCode::Delete => {
                 editor.delete();
+                editor.mark_edited();
                 editor.ensure_cursor_visible(viewport);
                 Action::None
             }
             KeyCode::Enter => {
                 editor.newline();
+                editor.mark_edited();
                 editor.ensure_cursor_visible(viewport);
                 Action::None
             }
             KeyCode::Tab => {
                 editor.insert_str("  ");
+                editor.mark_edited();
                 editor.ensure_cursor_visible(viewport);
                 Action::None
             }
             KeyCode::Char(c) => {
                 if !c.is_control() {
                     editor.insert_char(c);
+                    editor.mark_edited();
                     editor.ensure_cursor_visible(viewport);
                 }
                 Action::None
@@ -3210,6 +4184,30 @@ impl App {
         }
     }
 
+    /// 终端尺寸变化：记录新尺寸，并把依赖视口大小的滚动位置夹回有效范围
+    pub fn on_resize(&mut self, size: Size) {
+        self.last_size = size;
+
+        let viewport = self.editor_viewport_size();
+        if let Some(editor) = self.editor.as_mut() {
+            editor.ensure_cursor_visible(viewport);
+        }
+
+        let height = self.text_view_height();
+        if let Overlay::TextView(view)
+        | Overlay::CommonSnippetView { view, .. }
+        | Overlay::CommonSnippetDiff { view, .. } = &mut self.overlay
+        {
+            view.scroll = view.scroll.min(view.lines.len().saturating_sub(height));
+        }
+    }
+
+    /// 与 `ui.rs` 中文本查看浮层（内容区 90% 高度，减去边框与按键栏）的布局一致
+    fn text_view_height(&self) -> usize {
+        let content_height = self.last_size.height.saturating_sub(3).saturating_sub(1);
+        (usize::from(content_height) * 90 / 100).saturating_sub(3)
+    }
+
     fn editor_viewport_size(&self) -> Size {
         // Matches `render()` + `render_content()` + `render_editor()` layout math in `ui.rs`.
         let mut width = self.last_size.width.saturating_sub(30);
@@ -3465,6 +4463,7 @@ fn config_item_label(item: &ConfigItem) -> &'static str {
     match item {
         ConfigItem::Path => crate::cli::i18n::texts::tui_config_item_show_path(),
         ConfigItem::ShowFull => crate::cli::i18n::texts::tui_config_item_show_full(),
+        ConfigItem::EditInEditor => crate::cli::i18n::texts::tui_config_item_edit_in_editor(),
         ConfigItem::Export => crate::cli::i18n::texts::tui_config_item_export(),
         ConfigItem::Import => crate::cli::i18n::texts::tui_config_item_import(),
         ConfigItem::Backup => crate::cli::i18n::texts::tui_config_item_backup(),
@@ -3472,6 +4471,7 @@ fn config_item_label(item: &ConfigItem) -> &'static str {
         ConfigItem::Validate => crate::cli::i18n::texts::tui_config_item_validate(),
         ConfigItem::CommonSnippet => crate::cli::i18n::texts::tui_config_item_common_snippet(),
         ConfigItem::WebDavSync => crate::cli::i18n::texts::tui_config_item_webdav_sync(),
+        ConfigItem::ResetApp => crate::cli::i18n::texts::tui_config_item_reset_app(),
         ConfigItem::Reset => crate::cli::i18n::texts::tui_config_item_reset(),
     }
 }
@@ -3502,6 +4502,12 @@ fn webdav_config_item_label(item: &WebDavConfigItem) -> &'static str {
     }
 }
 
+fn read_clipboard_text() -> Result<String, String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|e| e.to_string())
+}
+
 fn cycle_app_type(current: &AppType, dir: i8) -> AppType {
     match (current, dir) {
         (AppType::Claude, 1) => AppType::Codex,
@@ -3538,6 +4544,13 @@ fn snippet_picker_app_type(index: usize) -> AppType {
     app_type_for_picker_index(index)
 }
 
+/// `X` 在全部启用时停用所有应用，否则为所有应用启用
+fn enabled_for_all_apps(apps: &crate::app_config::SkillApps) -> bool {
+    [AppType::Claude, AppType::Codex, AppType::Gemini]
+        .iter()
+        .all(|app| apps.is_enabled_for(app))
+}
+
 fn sync_method_picker_index(method: SyncMethod) -> usize {
     match method {
         SyncMethod::Auto => 0,
@@ -3580,6 +4593,30 @@ mod tests {
         UiData::default()
     }
 
+    #[test]
+    fn resize_updates_size_and_clamps_text_view_scroll() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.overlay = Overlay::TextView(TextViewState {
+            title: "t".to_string(),
+            lines: (0..100).map(|i| i.to_string()).collect(),
+            scroll: 95,
+        });
+
+        app.on_resize(Size::new(120, 44));
+        assert_eq!(app.last_size, Size::new(120, 44));
+        let Overlay::TextView(view) = &app.overlay else {
+            panic!("text view should stay open");
+        };
+        // 内容区 40 行 → 浮层 36 行 → 可见 33 行
+        assert_eq!(view.scroll, 100 - 33);
+
+        app.on_resize(Size::new(120, 10));
+        let Overlay::TextView(view) = &app.overlay else {
+            panic!("text view should stay open");
+        };
+        assert_eq!(view.scroll, 100 - 33, "shrinking never scrolls back down");
+    }
+
     #[test]
     fn nav_menu_includes_skills_entry() {
         assert!(
@@ -3633,6 +4670,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn skills_shift_x_toggles_every_app_based_on_current_flags() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Skills;
+        app.focus = Focus::Content;
+        let mut data = data();
+        let skill = crate::app_config::InstalledSkill {
+            id: "local:demo".to_string(),
+            name: "Demo".to_string(),
+            description: None,
+            directory: "demo".to_string(),
+            repo_owner: None,
+            repo_name: None,
+            repo_branch: None,
+            readme_url: None,
+            apps: crate::app_config::SkillApps::only(&AppType::Claude),
+            installed_at: 0,
+        };
+        data.skills.installed.push(skill);
+
+        let action = app.on_key(key(KeyCode::Char('X')), &data);
+        assert!(matches!(
+            action,
+            Action::SkillsToggleAllApps { ref directory, enabled: true } if directory == "demo"
+        ));
+
+        for app_type in [AppType::Codex, AppType::Gemini] {
+            data.skills.installed[0]
+                .apps
+                .set_enabled_for(&app_type, true);
+        }
+        let action = app.on_key(key(KeyCode::Char('X')), &data);
+        assert!(matches!(
+            action,
+            Action::SkillsToggleAllApps { enabled: false, .. }
+        ));
+    }
+
     #[test]
     fn config_e_key_opens_common_snippet_picker_when_selected() {
         let mut app = App::new(Some(AppType::Claude));
@@ -3648,6 +4723,142 @@ mod tests {
         assert!(matches!(app.overlay, Overlay::CommonSnippetPicker { .. }));
     }
 
+    #[test]
+    fn scroll_window_keeps_selection_visible_with_stable_offset() {
+        assert_eq!(scroll_window(0, 5, 3, 10), 0);
+        assert_eq!(scroll_window(100, 5, 0, 10), 0);
+        assert_eq!(scroll_window(100, 15, 0, 10), 6);
+        assert_eq!(scroll_window(100, 12, 6, 10), 6, "offset should not jump");
+        assert_eq!(scroll_window(100, 3, 6, 10), 3);
+        assert_eq!(scroll_window(20, 19, 50, 10), 10, "offset clamps to tail");
+
+        let scroll = ListScroll::default();
+        assert_eq!(scroll.window(&Route::Providers, 100, 40, 10), 31);
+        assert_eq!(scroll.offset(&Route::Providers), 31);
+        assert_eq!(
+            scroll.offset(&Route::Mcp),
+            0,
+            "offsets are tracked per route"
+        );
+    }
+
+    #[test]
+    fn toast_history_is_bounded_and_shown_newest_first() {
+        let mut app = App::new(Some(AppType::Claude));
+        for i in 0..(TOAST_HISTORY_LIMIT + 5) {
+            app.push_toast(format!("message {i}"), ToastKind::Info);
+        }
+        app.push_toast("last failure", ToastKind::Error);
+        assert_eq!(app.toast_history.len(), TOAST_HISTORY_LIMIT);
+        assert_eq!(app.toast_history.front().unwrap().message, "message 6");
+
+        app.on_key(key(KeyCode::Char('L')), &data());
+        let Overlay::TextView(view) = &app.overlay else {
+            panic!("expected message log overlay, got {:?}", app.overlay);
+        };
+        assert_eq!(view.lines.len(), TOAST_HISTORY_LIMIT);
+        assert!(view.lines[0].contains("last failure"));
+        assert!(view.lines[0].contains(ToastKind::Error.label()));
+    }
+
+    #[test]
+    fn toast_ticks_scale_with_kind_and_configured_duration() {
+        let tick = DEFAULT_TICK_RATE_MS;
+        assert_eq!(toast_ticks(ToastKind::Success, None, tick), 12);
+        assert!(
+            toast_ticks(ToastKind::Error, None, tick) > toast_ticks(ToastKind::Warning, None, tick)
+        );
+        assert!(
+            toast_ticks(ToastKind::Warning, None, tick) > toast_ticks(ToastKind::Info, None, tick)
+        );
+
+        assert_eq!(toast_ticks(ToastKind::Info, Some(4), tick), 20);
+        assert_eq!(toast_ticks(ToastKind::Error, Some(4), tick), 40);
+        assert_eq!(
+            toast_ticks(ToastKind::Info, Some(999), tick),
+            toast_ticks(ToastKind::Info, Some(MAX_TOAST_DURATION_SECS), tick)
+        );
+    }
+
+    #[test]
+    fn toast_ticks_keep_wall_clock_duration_across_tick_rates() {
+        assert_eq!(toast_ticks(ToastKind::Info, Some(4), 1000), 4);
+        assert_eq!(toast_ticks(ToastKind::Info, Some(4), 50), 80);
+        assert_eq!(toast_ticks(ToastKind::Info, None, MAX_TICK_RATE_MS), 1);
+    }
+
+    #[test]
+    fn settings_tick_rate_input_validates_range() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Settings;
+        app.focus = Focus::Content;
+        app.settings_idx = SettingsItem::ALL
+            .iter()
+            .position(|item| matches!(item, SettingsItem::TickRate))
+            .expect("tick rate item");
+
+        app.on_key(key(KeyCode::Enter), &data());
+        let Overlay::TextInput(input) = &mut app.overlay else {
+            panic!("expected tick rate input, got {:?}", app.overlay);
+        };
+        input.buffer = "10".to_string();
+        let action = app.on_key(key(KeyCode::Enter), &data());
+        assert!(matches!(action, Action::None));
+        assert!(matches!(
+            app.toast.as_ref().map(|t| t.kind),
+            Some(ToastKind::Warning)
+        ));
+
+        app.on_key(key(KeyCode::Enter), &data());
+        let Overlay::TextInput(input) = &mut app.overlay else {
+            panic!("expected tick rate input, got {:?}", app.overlay);
+        };
+        input.buffer = "500".to_string();
+        let action = app.on_key(key(KeyCode::Enter), &data());
+        assert!(matches!(action, Action::SetTickRate { ms: Some(500) }));
+    }
+
+    #[test]
+    fn settings_toast_duration_input_validates_range() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Settings;
+        app.focus = Focus::Content;
+        app.settings_idx = SettingsItem::ALL
+            .iter()
+            .position(|item| matches!(item, SettingsItem::ToastDuration))
+            .expect("toast duration item");
+
+        app.on_key(key(KeyCode::Enter), &data());
+        let Overlay::TextInput(input) = &mut app.overlay else {
+            panic!("expected toast duration input, got {:?}", app.overlay);
+        };
+        input.buffer = "0".to_string();
+        let action = app.on_key(key(KeyCode::Enter), &data());
+        assert!(matches!(action, Action::None));
+        assert!(matches!(
+            app.toast.as_ref().map(|t| t.kind),
+            Some(ToastKind::Warning)
+        ));
+
+        app.on_key(key(KeyCode::Enter), &data());
+        let Overlay::TextInput(input) = &mut app.overlay else {
+            panic!("expected toast duration input, got {:?}", app.overlay);
+        };
+        input.buffer = "5".to_string();
+        let action = app.on_key(key(KeyCode::Enter), &data());
+        assert!(matches!(action, Action::SetToastDuration { secs: Some(5) }));
+    }
+
+    #[test]
+    fn config_o_key_opens_config_dir() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Config;
+        app.focus = Focus::Content;
+
+        let action = app.on_key(key(KeyCode::Char('o')), &data());
+        assert!(matches!(action, Action::ConfigOpenDir));
+    }
+
     #[test]
     fn app_cycles_left_right() {
         let mut app = App::new(Some(AppType::Claude));
@@ -3797,21 +5008,117 @@ mod tests {
     }
 
     #[test]
-    fn providers_s_key_triggers_switch_action() {
+    fn providers_shift_t_tests_all_endpoints_and_enter_switches_to_selected_row() {
         let mut app = App::new(Some(AppType::Claude));
         app.route = Route::Providers;
         app.focus = Focus::Content;
 
         let mut data = UiData::default();
-        data.providers.rows.push(super::super::data::ProviderRow {
-            id: "p1".to_string(),
-            provider: crate::provider::Provider::with_id(
-                "p1".to_string(),
-                "Provider One".to_string(),
-                json!({"env":{"ANTHROPIC_BASE_URL":"https://example.com"}}),
-                None,
-            ),
-            api_url: Some("https://example.com".to_string()),
+        data.providers.current_id = "slow".to_string();
+        for (id, url) in [
+            ("slow", Some("https://slow.example.com")),
+            ("none", None),
+            ("fast", Some("https://fast.example.com")),
+        ] {
+            data.providers.rows.push(super::super::data::ProviderRow {
+                id: id.to_string(),
+                provider: crate::provider::Provider::with_id(
+                    id.to_string(),
+                    id.to_uppercase(),
+                    json!({}),
+                    None,
+                ),
+                api_url: url.map(str::to_string),
+                is_current: id == "slow",
+            });
+        }
+
+        let action = app.on_key(key(KeyCode::Char('T')), &data);
+        let Action::ProviderSpeedtest { label, targets } = action else {
+            panic!("expected speedtest action");
+        };
+        let ids: Vec<_> = targets.iter().map(|t| t.provider_id.as_str()).collect();
+        assert_eq!(ids, vec!["slow", "fast"]);
+
+        let result =
+            |target: &SpeedtestTarget, latency, status, error: Option<&str>| SpeedtestResultRow {
+                target: target.clone(),
+                latency,
+                status,
+                error: error.map(str::to_string),
+            };
+        let mut rows = vec![
+            result(&targets[0], Some(900), Some(200), None),
+            result(&targets[1], Some(120), Some(503), None),
+        ];
+        sort_speedtest_rows(&mut rows, SpeedtestSort::Latency);
+        assert_eq!(rows[0].target.provider_id, "fast");
+        sort_speedtest_rows(&mut rows, SpeedtestSort::Status);
+        assert_eq!(rows[0].target.provider_id, "slow");
+
+        app.overlay = Overlay::SpeedtestResult {
+            label,
+            rows,
+            selected: 0,
+            sort: SpeedtestSort::Status,
+        };
+        // 's' 切换回按延迟排序，最快的端点排到第一行
+        app.on_key(key(KeyCode::Char('s')), &data);
+        let action = app.on_key(key(KeyCode::Enter), &data);
+        assert!(matches!(action, Action::ProviderSwitch { id } if id == "fast"));
+        assert!(matches!(app.overlay, Overlay::None));
+    }
+
+    #[test]
+    fn providers_f_keys_toggle_queue_and_test_only_queued_providers() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        for (id, queued) in [("main", false), ("backup", true)] {
+            let mut provider =
+                crate::provider::Provider::with_id(id.to_string(), id.to_string(), json!({}), None);
+            provider.in_failover_queue = queued;
+            data.providers.rows.push(super::super::data::ProviderRow {
+                id: id.to_string(),
+                provider,
+                api_url: Some(format!("https://{id}.example.com")),
+                is_current: id == "main",
+            });
+        }
+
+        let action = app.on_key(key(KeyCode::Char('f')), &data);
+        assert!(matches!(
+            action,
+            Action::ProviderFailoverQueue { id, queued: true } if id == "main"
+        ));
+
+        let action = app.on_key(key(KeyCode::Char('F')), &data);
+        let Action::ProviderSpeedtest { label, targets } = action else {
+            panic!("expected failover speedtest action");
+        };
+        let ids: Vec<_> = targets.iter().map(|t| t.provider_id.as_str()).collect();
+        assert_eq!(ids, vec!["backup"]);
+        assert_eq!(app.failover_label.as_deref(), Some(label.as_str()));
+    }
+
+    #[test]
+    fn providers_s_key_triggers_switch_action() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        data.providers.rows.push(super::super::data::ProviderRow {
+            id: "p1".to_string(),
+            provider: crate::provider::Provider::with_id(
+                "p1".to_string(),
+                "Provider One".to_string(),
+                json!({"env":{"ANTHROPIC_BASE_URL":"https://example.com"}}),
+                None,
+            ),
+            api_url: Some("https://example.com".to_string()),
             is_current: false,
         });
 
@@ -3819,6 +5126,47 @@ mod tests {
         assert!(matches!(action, Action::ProviderSwitch { id } if id == "p1"));
     }
 
+    #[test]
+    fn providers_switch_asks_for_confirmation_when_live_sync_is_skipped() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let mut data = UiData {
+            live_sync_skipped: true,
+            ..UiData::default()
+        };
+        data.providers.rows.push(super::super::data::ProviderRow {
+            id: "p1".to_string(),
+            provider: crate::provider::Provider::with_id(
+                "p1".to_string(),
+                "Provider One".to_string(),
+                json!({}),
+                None,
+            ),
+            api_url: None,
+            is_current: false,
+        });
+
+        let action = app.on_key(key(KeyCode::Char('s')), &data);
+        assert!(matches!(action, Action::None));
+        assert!(matches!(
+            &app.overlay,
+            Overlay::Confirm(ConfirmOverlay {
+                action: ConfirmAction::ProviderSwitch { id },
+                ..
+            }) if id == "p1"
+        ));
+
+        let action = app.on_key(key(KeyCode::Esc), &data);
+        assert!(matches!(action, Action::None));
+        assert!(matches!(app.overlay, Overlay::None));
+
+        app.on_key(key(KeyCode::Char('s')), &data);
+        let action = app.on_key(key(KeyCode::Char('y')), &data);
+        assert!(matches!(action, Action::ProviderSwitch { id } if id == "p1"));
+    }
+
     #[test]
     fn provider_detail_s_key_triggers_switch_action_and_enter_is_noop() {
         let mut app = App::new(Some(AppType::Claude));
@@ -4012,7 +5360,42 @@ mod tests {
         });
 
         let action = app.on_key(key(KeyCode::Char('a')), &data);
-        assert!(matches!(action, Action::PromptActivate { id } if id == "pr1"));
+        assert!(matches!(
+            action,
+            Action::PromptActivate { id, exclusive: true } if id == "pr1"
+        ));
+
+        let action = app.on_key(key(KeyCode::Char('A')), &data);
+        assert!(matches!(
+            action,
+            Action::PromptActivate { id, exclusive: false } if id == "pr1"
+        ));
+    }
+
+    #[test]
+    fn restore_route_selects_nav_and_keeps_back_navigation() {
+        let mut app = App::new(Some(AppType::Codex));
+        let route = Route::from_session_key(Route::SkillsRepos.session_key()).expect("known key");
+        app.restore_route(route);
+
+        assert_eq!(app.route, Route::SkillsRepos);
+        assert_eq!(app.nav_item(), NavItem::Skills);
+        assert_eq!(app.focus, Focus::Content);
+
+        let data = UiData::default();
+        assert!(matches!(
+            app.on_key(key(KeyCode::Esc), &data),
+            Action::SwitchRoute(Route::Skills)
+        ));
+
+        assert_eq!(
+            Route::SkillDetail {
+                directory: "gone".to_string()
+            }
+            .session_key(),
+            "skills"
+        );
+        assert_eq!(Route::from_session_key("nope"), None);
     }
 
     #[test]
@@ -4077,6 +5460,12 @@ mod tests {
                 app_type: AppType::Claude
             }
         ));
+        assert!(matches!(
+            app.on_key(key(KeyCode::Char('p')), &data),
+            Action::ConfigCommonSnippetPreview {
+                app_type: AppType::Claude
+            }
+        ));
         assert!(matches!(
             app.on_key(key(KeyCode::Char('c')), &data),
             Action::ConfigCommonSnippetClear {
@@ -4092,6 +5481,40 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn config_common_snippet_diff_applies_and_returns_to_snippet_view() {
+        let mut app = App::new(Some(AppType::Claude));
+        let data = UiData::default();
+        app.overlay = Overlay::CommonSnippetDiff {
+            app_type: AppType::Codex,
+            view: TextViewState {
+                title: "diff".to_string(),
+                lines: vec!["  a".to_string(), "+ b".to_string()],
+                scroll: 0,
+            },
+        };
+
+        app.on_key(key(KeyCode::Down), &data);
+        assert!(matches!(
+            &app.overlay,
+            Overlay::CommonSnippetDiff { view, .. } if view.scroll == 1
+        ));
+
+        assert!(matches!(
+            app.on_key(key(KeyCode::Char('a')), &data),
+            Action::ConfigCommonSnippetApply {
+                app_type: AppType::Codex
+            }
+        ));
+        assert!(matches!(
+            app.overlay,
+            Overlay::CommonSnippetView {
+                app_type: AppType::Codex,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn config_common_snippet_picker_shows_snippet_for_non_current_app() {
         let mut app = App::new(Some(AppType::Claude));
@@ -4244,7 +5667,7 @@ mod tests {
         assert!(matches!(
             app.editor.as_ref().map(|e| (&e.kind, &e.submit)),
             Some((
-                EditorKind::Plain,
+                EditorKind::Toml,
                 EditorSubmit::ConfigCommonSnippet {
                     app_type: AppType::Codex
                 }
@@ -4322,7 +5745,7 @@ mod tests {
         assert!(matches!(
             app.editor.as_ref().map(|e| (&e.kind, &e.submit)),
             Some((
-                EditorKind::Plain,
+                EditorKind::Toml,
                 EditorSubmit::ProviderFormApplyCodexConfigToml
             ))
         ));
@@ -4566,6 +5989,135 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn digit_keys_jump_to_rows_and_enter_confirms_multi_digit_counts() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Settings;
+        app.focus = Focus::Content;
+
+        app.on_key(key(KeyCode::Char('3')), &data());
+        assert_eq!(app.settings_idx, 2);
+        assert_eq!(app.count_buffer, "3");
+
+        // 先清空缓冲，再输入多位数；超出列表长度时停在末行
+        app.on_key(key(KeyCode::Down), &data());
+        assert!(app.count_buffer.is_empty());
+        app.on_key(key(KeyCode::Char('0')), &data());
+        assert!(app.count_buffer.is_empty(), "leading zero is ignored");
+        app.on_key(key(KeyCode::Char('1')), &data());
+        assert_eq!(app.settings_idx, 0);
+        app.on_key(key(KeyCode::Char('2')), &data());
+        assert_eq!(app.settings_idx, SettingsItem::ALL.len() - 1);
+
+        let action = app.on_key(key(KeyCode::Enter), &data());
+        assert!(
+            matches!(action, Action::None),
+            "Enter only confirms the count"
+        );
+        assert!(app.count_buffer.is_empty());
+        assert_eq!(app.settings_idx, SettingsItem::ALL.len() - 1);
+
+        app.focus = Focus::Nav;
+        app.on_key(key(KeyCode::Char('2')), &data());
+        assert_eq!(app.nav_idx, 1);
+    }
+
+    #[test]
+    fn paste_into_text_input_overlay_strips_newlines() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.overlay = Overlay::TextInput(TextInputState {
+            title: "t".to_string(),
+            prompt: "p".to_string(),
+            buffer: "sk-".to_string(),
+            submit: TextSubmit::SkillsRepoAdd,
+            secret: true,
+        });
+
+        let action = app.on_paste("abc\r\n123\n", &data());
+        assert!(matches!(action, Action::None));
+        match &app.overlay {
+            Overlay::TextInput(input) => assert_eq!(input.buffer, "sk-abc123"),
+            other => panic!("expected text input overlay, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn json_editor_validation_updates_after_debounce_ticks() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.open_editor(
+            "Provider",
+            EditorKind::Json,
+            "{\n  \"a\": 1\n}",
+            EditorSubmit::ProviderAdd,
+        );
+        assert_eq!(
+            app.editor.as_ref().and_then(|e| e.validation.clone()),
+            Some(EditorValidation::Valid)
+        );
+
+        app.on_key(key(KeyCode::Down), &data());
+        app.on_key(key(KeyCode::End), &data());
+        app.on_key(key(KeyCode::Char(',')), &data());
+        app.on_tick();
+        assert_eq!(
+            app.editor.as_ref().and_then(|e| e.validation.clone()),
+            Some(EditorValidation::Valid),
+            "validation should wait for the debounce window"
+        );
+
+        app.on_tick();
+        match app.editor.as_ref().and_then(|e| e.validation.clone()) {
+            Some(EditorValidation::Invalid { line, .. }) => assert_eq!(line, 3),
+            other => panic!("expected invalid JSON, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn toml_editor_validation_reports_error_position() {
+        let mut app = App::new(Some(AppType::Codex));
+        app.open_editor(
+            "config.toml",
+            EditorKind::Toml,
+            "model = \"gpt\"\nbroken = \n",
+            EditorSubmit::ProviderFormApplyCodexConfigToml,
+        );
+        match app.editor.as_ref().and_then(|e| e.validation.clone()) {
+            Some(EditorValidation::Invalid { line, .. }) => assert_eq!(line, 2),
+            other => panic!("expected invalid TOML, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn paste_into_editor_keeps_newlines() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.open_editor(
+            "Prompt",
+            EditorKind::Plain,
+            "",
+            EditorSubmit::PromptEdit {
+                id: "pr1".to_string(),
+            },
+        );
+
+        app.on_paste("line1\r\nline2", &data());
+        assert_eq!(
+            app.editor.as_ref().map(|e| e.text()),
+            Some("line1\nline2".to_string())
+        );
+    }
+
+    #[test]
+    fn paste_without_focused_input_is_ignored() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let action = app.on_paste("q", &data());
+        assert!(matches!(action, Action::None));
+        assert!(!app.should_quit);
+        assert!(matches!(app.overlay, Overlay::None));
+    }
+
     #[test]
     fn prompts_e_opens_editor_and_ctrl_s_submits() {
         let mut app = App::new(Some(AppType::Claude));
@@ -4813,6 +6365,51 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn provider_edit_save_confirms_field_diff_before_submitting() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let mut data = UiData::default();
+        data.providers.rows.push(super::super::data::ProviderRow {
+            id: "p1".to_string(),
+            provider: crate::provider::Provider::with_id(
+                "p1".to_string(),
+                "Provider One".to_string(),
+                json!({"env":{"ANTHROPIC_BASE_URL":"https://example.com"}}),
+                None,
+            ),
+            api_url: Some("https://example.com".to_string()),
+            is_current: false,
+        });
+
+        app.on_key(key(KeyCode::Char('e')), &data);
+        let Some(FormState::ProviderAdd(form)) = app.form.as_mut() else {
+            panic!("expected provider edit form");
+        };
+        form.name.set("Renamed");
+
+        let action = app.on_key(ctrl(KeyCode::Char('s')), &data);
+        assert!(matches!(action, Action::None));
+        let Overlay::Confirm(confirm) = &app.overlay else {
+            panic!("expected diff confirmation");
+        };
+        assert!(confirm
+            .message
+            .lines()
+            .any(|line| line == "~ name: Provider One → Renamed"));
+
+        let submit = app.on_key(key(KeyCode::Enter), &data);
+        assert!(matches!(
+            submit,
+            Action::EditorSubmit {
+                submit: EditorSubmit::ProviderEdit { .. },
+                content
+            } if content.contains("Renamed")
+        ));
+    }
+
     #[test]
     fn provider_edit_form_tab_cycles_between_fields_and_json() {
         let mut app = App::new(Some(AppType::Claude));
@@ -5185,6 +6782,115 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn provider_form_ctrl_r_toggles_api_key_reveal_while_editing() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let data = UiData::default();
+        app.on_key(key(KeyCode::Char('a')), &data);
+        app.on_key(key(KeyCode::Enter), &data);
+
+        if let Some(super::super::form::FormState::ProviderAdd(form)) = app.form.as_mut() {
+            form.focus = super::super::form::FormFocus::Fields;
+            form.editing = true;
+            form.claude_api_key.set("sk-secret");
+            form.field_idx = form
+                .fields()
+                .iter()
+                .position(|field| *field == ProviderAddField::ClaudeApiKey)
+                .expect("ClaudeApiKey field should exist");
+        } else {
+            panic!("expected ProviderAdd form");
+        }
+
+        let revealed = |app: &App| match app.form.as_ref() {
+            Some(super::super::form::FormState::ProviderAdd(form)) => (
+                form.is_secret_revealed(ProviderAddField::ClaudeApiKey),
+                form.claude_api_key.value.clone(),
+            ),
+            other => panic!("expected ProviderAdd form, got: {other:?}"),
+        };
+
+        assert_eq!(revealed(&app), (false, "sk-secret".to_string()));
+        app.on_key(ctrl(KeyCode::Char('r')), &data);
+        assert_eq!(
+            revealed(&app),
+            (true, "sk-secret".to_string()),
+            "Ctrl+R should reveal without inserting text"
+        );
+        app.on_key(ctrl(KeyCode::Char('r')), &data);
+        assert_eq!(revealed(&app), (false, "sk-secret".to_string()));
+    }
+
+    #[test]
+    fn mcp_form_passes_ctrl_r_through_without_inserting_text() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Mcp;
+        app.focus = Focus::Content;
+
+        let data = UiData::default();
+        app.on_key(key(KeyCode::Char('a')), &data);
+        if let Some(super::super::form::FormState::McpAdd(form)) = app.form.as_mut() {
+            form.focus = super::super::form::FormFocus::Fields;
+            form.editing = true;
+            form.field_idx = form
+                .fields()
+                .iter()
+                .position(|field| *field == McpAddField::Id)
+                .expect("Id field should exist");
+            form.id.set("srv");
+        } else {
+            panic!("expected McpAdd form");
+        }
+
+        app.on_key(ctrl(KeyCode::Char('r')), &data);
+        app.on_key(key(KeyCode::Char('r')), &data);
+        match app.form.as_ref() {
+            Some(super::super::form::FormState::McpAdd(form)) => {
+                assert_eq!(form.id.value, "srvr");
+            }
+            other => panic!("expected McpAdd form, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn provider_form_warns_when_claude_base_url_looks_like_openai() {
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+
+        let data = UiData::default();
+        app.on_key(key(KeyCode::Char('a')), &data);
+        app.on_key(key(KeyCode::Enter), &data);
+
+        if let Some(super::super::form::FormState::ProviderAdd(form)) = app.form.as_mut() {
+            form.focus = super::super::form::FormFocus::Fields;
+            form.editing = true;
+            form.claude_base_url.set("https://api.openai.com/v1");
+            form.field_idx = form
+                .fields()
+                .iter()
+                .position(|field| *field == ProviderAddField::ClaudeBaseUrl)
+                .expect("ClaudeBaseUrl field should exist");
+        } else {
+            panic!("expected ProviderAdd form");
+        }
+
+        app.toast = None;
+        app.on_key(key(KeyCode::Enter), &data);
+        let toast = app.toast.as_ref().expect("mismatch should raise a toast");
+        assert_eq!(toast.kind, ToastKind::Warning);
+        assert!(
+            matches!(
+                app.form,
+                Some(super::super::form::FormState::ProviderAdd(_))
+            ),
+            "warning must not close the form"
+        );
+    }
+
     #[test]
     fn claude_model_overlay_editing_updates_form_value() {
         let mut app = App::new(Some(AppType::Claude));
@@ -5314,6 +7020,52 @@ mod tests {
         assert!(matches!(app.overlay, Overlay::None));
     }
 
+    #[test]
+    fn skills_discover_loading_overlay_esc_emits_cancel_action() {
+        let mut app = App::new(None);
+        app.overlay = Overlay::Loading {
+            kind: LoadingKind::SkillsDiscover,
+            title: texts::tui_skills_discover_title().to_string(),
+            message: "Working...".to_string(),
+        };
+
+        let action = app.on_key(key(KeyCode::Esc), &data());
+        assert!(matches!(action, Action::CancelSkillsDiscover));
+        assert!(matches!(app.overlay, Overlay::None));
+    }
+
+    #[test]
+    fn startup_summary_opens_only_when_a_check_warns() {
+        let mut app = App::new(None);
+        app.show_startup_summary(vec![
+            StartupCheck::ok("Speedtest worker"),
+            StartupCheck::ok("Network"),
+        ]);
+        assert!(matches!(app.overlay, Overlay::None));
+
+        app.show_startup_summary(vec![
+            StartupCheck::ok("Speedtest worker"),
+            StartupCheck::warn("Network", "offline"),
+        ]);
+        assert!(matches!(app.overlay, Overlay::StartupSummary { .. }));
+    }
+
+    #[test]
+    fn startup_summary_overlay_is_dismissed_by_any_key() {
+        let mut app = App::new(None);
+        app.overlay = Overlay::StartupSummary {
+            checks: vec![
+                StartupCheck::ok("Speedtest worker"),
+                StartupCheck::warn("Network", "offline"),
+            ],
+        };
+
+        let action = app.on_key(key(KeyCode::Char('x')), &data());
+        assert!(matches!(action, Action::None));
+        assert!(matches!(app.overlay, Overlay::None));
+        assert!(!app.should_quit);
+    }
+
     #[test]
     fn update_result_overlay_success_esc_hides_without_exiting() {
         let mut app = App::new(None);
@@ -71,6 +71,8 @@ pub struct UiData {
     pub prompts: PromptsSnapshot,
     pub config: ConfigSnapshot,
     pub skills: SkillsSnapshot,
+    /// 当前应用未初始化，切换/开关不会写入 live 文件
+    pub live_sync_skipped: bool,
 }
 
 pub(crate) fn load_state() -> Result<AppState, AppError> {
@@ -93,6 +95,7 @@ impl UiData {
             prompts,
             config,
             skills,
+            live_sync_skipped: !crate::sync_policy::should_sync_live(app_type),
         })
     }
 }
@@ -1,4 +1,6 @@
-use crate::app_config::{AppType, McpApps, McpServer};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::app_config::{AppType, McpApps, McpServer, McpTransport};
 use crate::provider::Provider;
 use serde_json::{json, Value};
 
@@ -148,6 +150,7 @@ pub enum ProviderAddField {
     Name,
     WebsiteUrl,
     Notes,
+    ExpiresAt,
     ClaudeBaseUrl,
     ClaudeApiKey,
     ClaudeModelConfig,
@@ -161,11 +164,24 @@ pub enum ProviderAddField {
     GeminiApiKey,
     GeminiBaseUrl,
     GeminiModel,
+    Headers,
     CommonConfigDivider,
     CommonSnippet,
     IncludeCommonConfig,
 }
 
+impl ProviderAddField {
+    /// 是否为敏感字段（渲染时默认以 `•` 遮盖）
+    pub fn is_secret(self) -> bool {
+        matches!(
+            self,
+            ProviderAddField::ClaudeApiKey
+                | ProviderAddField::CodexApiKey
+                | ProviderAddField::GeminiApiKey
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ProviderTemplateId {
     Custom,
@@ -211,6 +227,103 @@ const SPONSOR_PROVIDER_PRESETS: [SponsorProviderPreset; 1] = [SponsorProviderPre
     gemini_base_url: "https://www.packyapi.com",
 }];
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RelayEndpoint {
+    base_url: &'static str,
+    model: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RelayProviderPreset {
+    provider_name: &'static str,
+    website_url: &'static str,
+    claude: Option<RelayEndpoint>,
+    codex: Option<RelayEndpoint>,
+    gemini: Option<RelayEndpoint>,
+}
+
+// Community relay presets (no partner/promo metadata).
+// Shown after built-in and sponsor templates, only for apps the relay supports.
+// The API key is always left blank for the user to fill in.
+const RELAY_PROVIDER_PRESETS: [RelayProviderPreset; 5] = [
+    RelayProviderPreset {
+        provider_name: "DeepSeek",
+        website_url: "https://platform.deepseek.com",
+        claude: Some(RelayEndpoint {
+            base_url: "https://api.deepseek.com/anthropic",
+            model: "deepseek-chat",
+        }),
+        codex: Some(RelayEndpoint {
+            base_url: "https://api.deepseek.com/v1",
+            model: "deepseek-chat",
+        }),
+        gemini: None,
+    },
+    RelayProviderPreset {
+        provider_name: "Moonshot (Kimi)",
+        website_url: "https://platform.moonshot.cn",
+        claude: Some(RelayEndpoint {
+            base_url: "https://api.moonshot.cn/anthropic",
+            model: "kimi-k2-turbo-preview",
+        }),
+        codex: Some(RelayEndpoint {
+            base_url: "https://api.moonshot.cn/v1",
+            model: "kimi-k2-turbo-preview",
+        }),
+        gemini: None,
+    },
+    RelayProviderPreset {
+        provider_name: "Zhipu GLM",
+        website_url: "https://open.bigmodel.cn",
+        claude: Some(RelayEndpoint {
+            base_url: "https://open.bigmodel.cn/api/anthropic",
+            model: "glm-4.6",
+        }),
+        codex: None,
+        gemini: None,
+    },
+    RelayProviderPreset {
+        provider_name: "MiniMax",
+        website_url: "https://platform.minimaxi.com",
+        claude: Some(RelayEndpoint {
+            base_url: "https://api.minimaxi.com/anthropic",
+            model: "MiniMax-M2",
+        }),
+        codex: None,
+        gemini: None,
+    },
+    RelayProviderPreset {
+        provider_name: "OpenRouter",
+        website_url: "https://openrouter.ai",
+        claude: Some(RelayEndpoint {
+            base_url: "https://openrouter.ai/api",
+            model: "anthropic/claude-sonnet-4.5",
+        }),
+        codex: Some(RelayEndpoint {
+            base_url: "https://openrouter.ai/api/v1",
+            model: "openai/gpt-5-codex",
+        }),
+        gemini: None,
+    },
+];
+
+impl RelayProviderPreset {
+    fn endpoint_for(&self, app_type: &AppType) -> Option<RelayEndpoint> {
+        match app_type {
+            AppType::Claude => self.claude,
+            AppType::Codex => self.codex,
+            AppType::Gemini => self.gemini,
+        }
+    }
+}
+
+fn relay_presets_for(app_type: &AppType) -> Vec<&'static RelayProviderPreset> {
+    RELAY_PROVIDER_PRESETS
+        .iter()
+        .filter(|preset| preset.endpoint_for(app_type).is_some())
+        .collect()
+}
+
 const PROVIDER_TEMPLATE_DEFS_CLAUDE: [ProviderTemplateDef; 3] = [
     ProviderTemplateDef {
         id: ProviderTemplateId::Custom,
@@ -264,11 +377,11 @@ fn provider_builtin_template_defs(app_type: &AppType) -> &'static [ProviderTempl
 pub enum McpAddField {
     Id,
     Name,
+    Transport,
     Command,
     Args,
-    AppClaude,
-    AppCodex,
-    AppGemini,
+    Url,
+    Apps,
 }
 
 #[derive(Debug, Clone)]
@@ -285,12 +398,18 @@ pub struct ProviderAddFormState {
     pub name: TextInput,
     pub website_url: TextInput,
     pub notes: TextInput,
+    /// API Key 到期日（YYYY-MM-DD，可留空）
+    pub expires_at: TextInput,
+    /// 测速等探测请求附带的请求头（`Key: Value; ...`）
+    pub headers: TextInput,
     pub include_common_config: bool,
     pub json_scroll: usize,
     pub codex_preview_section: CodexPreviewSection,
     pub codex_auth_scroll: usize,
     pub codex_config_scroll: usize,
     claude_model_config_touched: bool,
+    revealed_secret_fields: Vec<ProviderAddField>,
+    show_sponsor_presets: bool,
 
     // Claude
     pub claude_api_key: TextInput,
@@ -316,19 +435,45 @@ pub struct ProviderAddFormState {
     pub gemini_model: TextInput,
 }
 
+/// 新建供应商表单内置的（请求地址, 模型）默认值
+pub fn builtin_provider_defaults(app_type: &AppType) -> (&'static str, &'static str) {
+    match app_type {
+        AppType::Claude => ("", ""),
+        AppType::Codex => ("https://api.openai.com/v1", "gpt-5.2-codex"),
+        AppType::Gemini => ("https://generativelanguage.googleapis.com", ""),
+    }
+}
+
+/// 设置中的 `default_*` 优先，未设置时回退到内置默认值
+pub fn new_provider_defaults(app_type: &AppType) -> (String, String) {
+    let (builtin_base_url, builtin_model) = builtin_provider_defaults(app_type);
+    let (base_url, model) = crate::settings::get_new_provider_defaults(app_type);
+    (
+        base_url.unwrap_or_else(|| builtin_base_url.to_string()),
+        model.unwrap_or_else(|| builtin_model.to_string()),
+    )
+}
+
 impl ProviderAddFormState {
     pub fn new(app_type: AppType) -> Self {
-        let codex_defaults = match app_type {
-            AppType::Codex => (
-                "https://api.openai.com/v1",
-                "gpt-5.2-codex",
-                CodexWireApi::Responses,
-                true,
-            ),
-            _ => ("", "", CodexWireApi::Responses, true),
-        };
-
-        let gemini_base_url_default = "https://generativelanguage.googleapis.com";
+        let defaults = new_provider_defaults(&app_type);
+        Self::with_defaults(app_type, defaults)
+    }
+
+    /// 按给定的（请求地址, 模型）默认值初始化当前应用的字段
+    fn with_defaults(app_type: AppType, defaults: (String, String)) -> Self {
+        let (base_url_default, model_default) = defaults;
+        let mut claude_defaults = (String::new(), String::new());
+        let mut codex_defaults = (String::new(), String::new());
+        let mut gemini_defaults = (
+            builtin_provider_defaults(&AppType::Gemini).0.to_string(),
+            String::new(),
+        );
+        match app_type {
+            AppType::Claude => claude_defaults = (base_url_default, model_default),
+            AppType::Codex => codex_defaults = (base_url_default, model_default),
+            AppType::Gemini => gemini_defaults = (base_url_default, model_default),
+        }
 
         Self {
             app_type,
@@ -343,16 +488,20 @@ impl ProviderAddFormState {
             name: TextInput::new(""),
             website_url: TextInput::new(""),
             notes: TextInput::new(""),
+            expires_at: TextInput::new(""),
+            headers: TextInput::new(""),
             include_common_config: true,
             json_scroll: 0,
             codex_preview_section: CodexPreviewSection::Auth,
             codex_auth_scroll: 0,
             codex_config_scroll: 0,
             claude_model_config_touched: false,
+            revealed_secret_fields: Vec::new(),
+            show_sponsor_presets: !crate::settings::is_sponsor_presets_hidden(),
 
             claude_api_key: TextInput::new(""),
-            claude_base_url: TextInput::new(""),
-            claude_model: TextInput::new(""),
+            claude_base_url: TextInput::new(claude_defaults.0),
+            claude_model: TextInput::new(claude_defaults.1),
             claude_reasoning_model: TextInput::new(""),
             claude_haiku_model: TextInput::new(""),
             claude_sonnet_model: TextInput::new(""),
@@ -360,20 +509,25 @@ impl ProviderAddFormState {
 
             codex_base_url: TextInput::new(codex_defaults.0),
             codex_model: TextInput::new(codex_defaults.1),
-            codex_wire_api: codex_defaults.2,
-            codex_requires_openai_auth: codex_defaults.3,
+            codex_wire_api: CodexWireApi::Responses,
+            codex_requires_openai_auth: true,
             codex_env_key: TextInput::new("OPENAI_API_KEY"),
             codex_api_key: TextInput::new(""),
 
             gemini_auth_type: GeminiAuthType::ApiKey,
             gemini_api_key: TextInput::new(""),
-            gemini_base_url: TextInput::new(gemini_base_url_default),
-            gemini_model: TextInput::new(""),
+            gemini_base_url: TextInput::new(gemini_defaults.0),
+            gemini_model: TextInput::new(gemini_defaults.1),
         }
     }
 
+    /// 编辑已有供应商：设置中的 `default_*` 只用于新建，这里仅回退到内置默认值
     pub fn from_provider(app_type: AppType, provider: &Provider) -> Self {
-        let mut form = Self::new(app_type.clone());
+        let (base_url_default, model_default) = builtin_provider_defaults(&app_type);
+        let mut form = Self::with_defaults(
+            app_type.clone(),
+            (base_url_default.to_string(), model_default.to_string()),
+        );
         form.mode = FormMode::Edit {
             id: provider.id.clone(),
         };
@@ -389,6 +543,16 @@ impl ProviderAddFormState {
         if let Some(notes) = provider.notes.as_deref() {
             form.notes.set(notes);
         }
+        if let Some(expires_at) = provider
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.expires_at.as_deref())
+        {
+            form.expires_at.set(expires_at);
+        }
+        form.headers.set(crate::services::format_header_lines(
+            &provider.settings_config,
+        ));
         form.include_common_config = provider
             .meta
             .as_ref()
@@ -523,8 +687,25 @@ impl ProviderAddFormState {
         !self.id.is_blank() && !self.name.is_blank()
     }
 
+    /// 到期日非空且无法解析（表单内联提示，保存时停在该字段）
+    pub fn expires_at_invalid(&self) -> bool {
+        !self.expires_at.is_blank()
+            && crate::services::parse_expiry_date(&self.expires_at.value).is_err()
+    }
+
+    /// 当前可用的赞助商预设；设置隐藏时为空
+    fn sponsor_presets(&self) -> &'static [SponsorProviderPreset] {
+        if self.show_sponsor_presets {
+            &SPONSOR_PROVIDER_PRESETS
+        } else {
+            &[]
+        }
+    }
+
     pub fn template_count(&self) -> usize {
-        provider_builtin_template_defs(&self.app_type).len() + SPONSOR_PROVIDER_PRESETS.len()
+        provider_builtin_template_defs(&self.app_type).len()
+            + self.sponsor_presets().len()
+            + relay_presets_for(&self.app_type).len()
     }
 
     pub fn template_labels(&self) -> Vec<&'static str> {
@@ -533,10 +714,15 @@ impl ProviderAddFormState {
             .map(|def| def.label)
             .collect::<Vec<_>>();
         labels.extend(
-            SPONSOR_PROVIDER_PRESETS
+            self.sponsor_presets()
                 .iter()
                 .map(|preset| preset.chip_label),
         );
+        labels.extend(
+            relay_presets_for(&self.app_type)
+                .into_iter()
+                .map(|preset| preset.provider_name),
+        );
         labels
     }
 
@@ -545,6 +731,7 @@ impl ProviderAddFormState {
             ProviderAddField::Name,
             ProviderAddField::WebsiteUrl,
             ProviderAddField::Notes,
+            ProviderAddField::ExpiresAt,
         ];
 
         match self.app_type {
@@ -570,6 +757,7 @@ impl ProviderAddFormState {
             }
         }
 
+        fields.push(ProviderAddField::Headers);
         fields.push(ProviderAddField::CommonConfigDivider);
         fields.push(ProviderAddField::CommonSnippet);
         fields.push(ProviderAddField::IncludeCommonConfig);
@@ -582,6 +770,7 @@ impl ProviderAddFormState {
             ProviderAddField::Name => Some(&self.name),
             ProviderAddField::WebsiteUrl => Some(&self.website_url),
             ProviderAddField::Notes => Some(&self.notes),
+            ProviderAddField::ExpiresAt => Some(&self.expires_at),
             ProviderAddField::ClaudeBaseUrl => Some(&self.claude_base_url),
             ProviderAddField::ClaudeApiKey => Some(&self.claude_api_key),
             ProviderAddField::CodexBaseUrl => Some(&self.codex_base_url),
@@ -591,6 +780,7 @@ impl ProviderAddFormState {
             ProviderAddField::GeminiApiKey => Some(&self.gemini_api_key),
             ProviderAddField::GeminiBaseUrl => Some(&self.gemini_base_url),
             ProviderAddField::GeminiModel => Some(&self.gemini_model),
+            ProviderAddField::Headers => Some(&self.headers),
             ProviderAddField::CodexWireApi
             | ProviderAddField::CodexRequiresOpenaiAuth
             | ProviderAddField::ClaudeModelConfig
@@ -601,12 +791,43 @@ impl ProviderAddFormState {
         }
     }
 
+    pub fn is_secret_revealed(&self, field: ProviderAddField) -> bool {
+        self.revealed_secret_fields.contains(&field)
+    }
+
+    /// 切换敏感字段的明文显示；非敏感字段不做处理
+    pub fn toggle_secret_reveal(&mut self, field: ProviderAddField) {
+        if !field.is_secret() {
+            return;
+        }
+        if let Some(pos) = self
+            .revealed_secret_fields
+            .iter()
+            .position(|revealed| *revealed == field)
+        {
+            self.revealed_secret_fields.remove(pos);
+        } else {
+            self.revealed_secret_fields.push(field);
+        }
+    }
+
+    /// 用于渲染的字段文本：敏感字段未显示明文时以 `•` 替换每个字符
+    pub fn display_value(&self, field: ProviderAddField) -> Option<String> {
+        let input = self.input(field)?;
+        if field.is_secret() && !self.is_secret_revealed(field) {
+            Some("•".repeat(input.value.chars().count()))
+        } else {
+            Some(input.value.clone())
+        }
+    }
+
     pub fn input_mut(&mut self, field: ProviderAddField) -> Option<&mut TextInput> {
         match field {
             ProviderAddField::Id => Some(&mut self.id),
             ProviderAddField::Name => Some(&mut self.name),
             ProviderAddField::WebsiteUrl => Some(&mut self.website_url),
             ProviderAddField::Notes => Some(&mut self.notes),
+            ProviderAddField::ExpiresAt => Some(&mut self.expires_at),
             ProviderAddField::ClaudeBaseUrl => Some(&mut self.claude_base_url),
             ProviderAddField::ClaudeApiKey => Some(&mut self.claude_api_key),
             ProviderAddField::CodexBaseUrl => Some(&mut self.codex_base_url),
@@ -616,6 +837,7 @@ impl ProviderAddFormState {
             ProviderAddField::GeminiApiKey => Some(&mut self.gemini_api_key),
             ProviderAddField::GeminiBaseUrl => Some(&mut self.gemini_base_url),
             ProviderAddField::GeminiModel => Some(&mut self.gemini_model),
+            ProviderAddField::Headers => Some(&mut self.headers),
             ProviderAddField::CodexWireApi
             | ProviderAddField::CodexRequiresOpenaiAuth
             | ProviderAddField::ClaudeModelConfig
@@ -667,14 +889,19 @@ impl ProviderAddFormState {
 
     pub fn apply_template(&mut self, idx: usize, existing_ids: &[String]) {
         let builtin_defs = provider_builtin_template_defs(&self.app_type);
-        let total_templates = builtin_defs.len() + SPONSOR_PROVIDER_PRESETS.len();
-        let idx = idx.min(total_templates.saturating_sub(1));
+        let sponsor_end = builtin_defs.len() + self.sponsor_presets().len();
+        let idx = idx.min(self.template_count().saturating_sub(1));
         self.template_idx = idx;
         self.id_is_manual = false;
 
-        if idx >= builtin_defs.len() {
+        if idx >= sponsor_end {
+            let relay_idx = idx - sponsor_end;
+            if let Some(preset) = relay_presets_for(&self.app_type).get(relay_idx) {
+                self.apply_relay_preset(preset);
+            }
+        } else if idx >= builtin_defs.len() {
             let sponsor_idx = idx.saturating_sub(builtin_defs.len());
-            if let Some(preset) = SPONSOR_PROVIDER_PRESETS.get(sponsor_idx) {
+            if let Some(preset) = self.sponsor_presets().get(sponsor_idx) {
                 self.apply_sponsor_preset(preset);
             }
         } else {
@@ -692,6 +919,8 @@ impl ProviderAddFormState {
                     self.name = defaults.name;
                     self.website_url = defaults.website_url;
                     self.notes = defaults.notes;
+                    self.expires_at = defaults.expires_at;
+                    self.headers = defaults.headers;
                     self.json_scroll = defaults.json_scroll;
                     self.codex_preview_section = defaults.codex_preview_section;
                     self.codex_auth_scroll = defaults.codex_auth_scroll;
@@ -720,6 +949,8 @@ impl ProviderAddFormState {
 
             self.extra = json!({});
             self.notes.set("");
+            self.expires_at.set("");
+            self.headers.set("");
             match template_id {
                 ProviderTemplateId::Custom => {}
                 ProviderTemplateId::ClaudeOfficial => {
@@ -765,10 +996,7 @@ impl ProviderAddFormState {
         }
 
         if !self.id_is_manual && !self.name.is_blank() {
-            let id = crate::cli::commands::provider_input::generate_provider_id(
-                self.name.value.trim(),
-                existing_ids,
-            );
+            let id = crate::provider::generate_provider_id(self.name.value.trim(), existing_ids);
             self.id.set(id);
         }
     }
@@ -783,6 +1011,8 @@ impl ProviderAddFormState {
         self.name.set(preset.provider_name);
         self.website_url.set(preset.website_url);
         self.notes.set("");
+        self.expires_at.set("");
+        self.headers.set("");
 
         match self.app_type {
             AppType::Claude => {
@@ -800,6 +1030,44 @@ impl ProviderAddFormState {
         }
     }
 
+    fn apply_relay_preset(&mut self, preset: &RelayProviderPreset) {
+        let Some(endpoint) = preset.endpoint_for(&self.app_type) else {
+            return;
+        };
+        self.extra = json!({});
+        self.name.set(preset.provider_name);
+        self.website_url.set(preset.website_url);
+        self.notes.set("");
+        self.expires_at.set("");
+        self.headers.set("");
+
+        match self.app_type {
+            AppType::Claude => {
+                self.claude_api_key.set("");
+                self.claude_base_url.set(endpoint.base_url);
+                self.claude_model.set(endpoint.model);
+                self.claude_reasoning_model.set("");
+                self.claude_haiku_model.set(endpoint.model);
+                self.claude_sonnet_model.set(endpoint.model);
+                self.claude_opus_model.set(endpoint.model);
+                self.claude_model_config_touched = true;
+            }
+            AppType::Codex => {
+                self.codex_api_key.set("");
+                self.codex_base_url.set(endpoint.base_url);
+                self.codex_model.set(endpoint.model);
+                self.codex_wire_api = CodexWireApi::Chat;
+                self.codex_requires_openai_auth = true;
+            }
+            AppType::Gemini => {
+                self.gemini_auth_type = GeminiAuthType::ApiKey;
+                self.gemini_api_key.set("");
+                self.gemini_base_url.set(endpoint.base_url);
+                self.gemini_model.set(endpoint.model);
+            }
+        }
+    }
+
     pub fn is_codex_official_provider(&self) -> bool {
         if !matches!(self.app_type, AppType::Codex) {
             return false;
@@ -860,6 +1128,18 @@ impl ProviderAddFormState {
                 "applyCommonConfig".to_string(),
                 json!(self.include_common_config),
             );
+            // 可解析的日期统一写成 YYYY-MM-DD
+            match crate::services::parse_expiry_date(&self.expires_at.value) {
+                Ok(date) => {
+                    meta_obj.insert(
+                        "expiresAt".to_string(),
+                        json!(date.format("%Y-%m-%d").to_string()),
+                    );
+                }
+                Err(_) => {
+                    upsert_optional_trimmed(meta_obj, "expiresAt", self.expires_at.value.as_str())
+                }
+            }
         }
 
         let settings_value = provider_obj
@@ -868,6 +1148,10 @@ impl ProviderAddFormState {
         if !settings_value.is_object() {
             *settings_value = json!({});
         }
+        // 格式无效时保留原值，保存前由调用方校验
+        if let Ok(headers) = crate::services::parse_header_lines(&self.headers.value) {
+            crate::services::set_provider_headers(settings_value, headers);
+        }
         let settings_obj = settings_value
             .as_object_mut()
             .expect("settingsConfig must be a JSON object");
@@ -1214,12 +1498,25 @@ pub struct McpAddFormState {
     pub extra: Value,
     pub id: TextInput,
     pub name: TextInput,
+    pub transport: McpTransport,
     pub command: TextInput,
     pub args: TextInput,
+    pub url: TextInput,
     pub apps: McpApps,
+    /// 应用复选框组中当前光标所在的应用下标
+    pub app_cursor: usize,
     pub json_scroll: usize,
 }
 
+/// MCP 表单应用复选框组的顺序
+pub const MCP_FORM_APPS: [AppType; 3] = [AppType::Claude, AppType::Codex, AppType::Gemini];
+
+impl Default for McpAddFormState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl McpAddFormState {
     pub fn new() -> Self {
         Self {
@@ -1231,9 +1528,12 @@ impl McpAddFormState {
             extra: json!({}),
             id: TextInput::new(""),
             name: TextInput::new(""),
+            transport: McpTransport::Stdio,
             command: TextInput::new(""),
             args: TextInput::new(""),
+            url: TextInput::new(""),
             apps: McpApps::default(),
+            app_cursor: 0,
             json_scroll: 0,
         }
     }
@@ -1248,7 +1548,11 @@ impl McpAddFormState {
         form.id.set(server.id.clone());
         form.name.set(server.name.clone());
         form.apps = server.apps.clone();
+        form.transport = server.transport();
 
+        if let Some(url) = server.server.get("url").and_then(|v| v.as_str()) {
+            form.url.set(url);
+        }
         if let Some(command) = server.server.get("command").and_then(|v| v.as_str()) {
             form.command.set(command);
         }
@@ -1284,15 +1588,14 @@ impl McpAddFormState {
     }
 
     pub fn fields(&self) -> Vec<McpAddField> {
-        vec![
-            McpAddField::Id,
-            McpAddField::Name,
-            McpAddField::Command,
-            McpAddField::Args,
-            McpAddField::AppClaude,
-            McpAddField::AppCodex,
-            McpAddField::AppGemini,
-        ]
+        let mut fields = vec![McpAddField::Id, McpAddField::Name, McpAddField::Transport];
+        if self.transport.is_remote() {
+            fields.push(McpAddField::Url);
+        } else {
+            fields.extend([McpAddField::Command, McpAddField::Args]);
+        }
+        fields.push(McpAddField::Apps);
+        fields
     }
 
     pub fn input(&self, field: McpAddField) -> Option<&TextInput> {
@@ -1301,7 +1604,8 @@ impl McpAddFormState {
             McpAddField::Name => Some(&self.name),
             McpAddField::Command => Some(&self.command),
             McpAddField::Args => Some(&self.args),
-            McpAddField::AppClaude | McpAddField::AppCodex | McpAddField::AppGemini => None,
+            McpAddField::Url => Some(&self.url),
+            McpAddField::Transport | McpAddField::Apps => None,
         }
     }
 
@@ -1311,7 +1615,36 @@ impl McpAddFormState {
             McpAddField::Name => Some(&mut self.name),
             McpAddField::Command => Some(&mut self.command),
             McpAddField::Args => Some(&mut self.args),
-            McpAddField::AppClaude | McpAddField::AppCodex | McpAddField::AppGemini => None,
+            McpAddField::Url => Some(&mut self.url),
+            McpAddField::Transport | McpAddField::Apps => None,
+        }
+    }
+
+    pub fn move_app_cursor(&mut self, forward: bool) {
+        self.app_cursor = if forward {
+            (self.app_cursor + 1).min(MCP_FORM_APPS.len() - 1)
+        } else {
+            self.app_cursor.saturating_sub(1)
+        };
+    }
+
+    pub fn cursor_app(&self) -> &'static AppType {
+        &MCP_FORM_APPS[self.app_cursor.min(MCP_FORM_APPS.len() - 1)]
+    }
+
+    pub fn toggle_app_at_cursor(&mut self) {
+        let app = self.cursor_app();
+        self.apps
+            .set_enabled_for(app, !self.apps.is_enabled_for(app));
+    }
+
+    /// 全部启用；若已全部启用则全部取消
+    pub fn toggle_all_apps(&mut self) {
+        let enable = !MCP_FORM_APPS
+            .iter()
+            .all(|app| self.apps.is_enabled_for(app));
+        for app in &MCP_FORM_APPS {
+            self.apps.set_enabled_for(app, enable);
         }
     }
 
@@ -1325,8 +1658,10 @@ impl McpAddFormState {
                 let defaults = Self::new();
                 self.extra = defaults.extra;
                 self.name = defaults.name;
+                self.transport = defaults.transport;
                 self.command = defaults.command;
                 self.args = defaults.args;
+                self.url = defaults.url;
                 self.json_scroll = defaults.json_scroll;
             }
             return;
@@ -1335,6 +1670,7 @@ impl McpAddFormState {
         match template {
             1 => {
                 self.name.set("Filesystem");
+                self.transport = McpTransport::Stdio;
                 self.command.set("npx");
                 self.args
                     .set("-y @modelcontextprotocol/server-filesystem /");
@@ -1366,8 +1702,20 @@ impl McpAddFormState {
         let server_obj = server_value
             .as_object_mut()
             .expect("server must be a JSON object");
-        server_obj.insert("command".to_string(), json!(self.command.value.trim()));
-        server_obj.insert("args".to_string(), Value::Array(args));
+        server_obj.insert("type".to_string(), json!(self.transport.as_str()));
+        if self.transport.is_remote() {
+            server_obj.remove("command");
+            server_obj.remove("args");
+            server_obj.remove("env");
+            server_obj.remove("cwd");
+            server_obj.insert("url".to_string(), json!(self.url.value.trim()));
+        } else {
+            server_obj.remove("url");
+            server_obj.remove("headers");
+            server_obj.remove("http_headers");
+            server_obj.insert("command".to_string(), json!(self.command.value.trim()));
+            server_obj.insert("args".to_string(), Value::Array(args));
+        }
 
         obj.insert(
             "apps".to_string(),
@@ -1859,6 +2207,220 @@ pub fn strip_provider_internal_fields(value: &Value) -> Value {
     }
 }
 
+/// 键名像密钥（key/token/secret/password/authorization）；`env_key` 只是环境变量名，不算
+pub(crate) fn is_secret_field(key: &str) -> bool {
+    // 只匹配密钥形态的后缀（`*_API_KEY`、`*_TOKEN`、`apiKey` 等），
+    // 避免把 `MAX_OUTPUT_TOKENS`、`env_key` 这类普通字段当作密钥
+    let key: String = key
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    key == "key"
+        || [
+            "apikey",
+            "apikeys",
+            "token",
+            "secret",
+            "secretkey",
+            "password",
+            "authorization",
+        ]
+        .iter()
+        .any(|suffix| key.ends_with(suffix))
+}
+
+/// 清空密钥类字段的值（保留 `${env:NAME}` 占位符），Codex 的 `config` TOML 也一并处理
+pub fn blank_provider_secrets(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut out = serde_json::Map::new();
+            for (k, v) in map {
+                let blanked = match v {
+                    Value::String(s) if is_secret_field(k) && !s.starts_with("${env:") => {
+                        Value::String(String::new())
+                    }
+                    Value::String(s) if k == "config" => {
+                        Value::String(blank_toml_secrets(s).unwrap_or_else(|| s.clone()))
+                    }
+                    Value::Array(items) if is_secret_field(k) => Value::Array(
+                        items
+                            .iter()
+                            .map(|item| match item {
+                                Value::String(s) if !s.starts_with("${env:") => {
+                                    Value::String(String::new())
+                                }
+                                other => other.clone(),
+                            })
+                            .collect(),
+                    ),
+                    other => blank_provider_secrets(other),
+                };
+                out.insert(k.clone(), blanked);
+            }
+            Value::Object(out)
+        }
+        Value::Array(items) => Value::Array(items.iter().map(blank_provider_secrets).collect()),
+        other => other.clone(),
+    }
+}
+
+fn blank_toml_secrets(text: &str) -> Option<String> {
+    fn walk(table: &mut dyn toml_edit::TableLike) {
+        for (key, item) in table.iter_mut() {
+            if let Some(inner) = item.as_table_like_mut() {
+                walk(inner);
+            } else if is_secret_field(key.get())
+                && item
+                    .as_str()
+                    .is_some_and(|s| !s.is_empty() && !s.starts_with("${env:"))
+            {
+                *item = toml_edit::value("");
+            }
+        }
+    }
+
+    if text.trim().is_empty() {
+        return None;
+    }
+    let mut doc = text.parse::<toml_edit::DocumentMut>().ok()?;
+    walk(doc.as_table_mut());
+    Some(doc.to_string())
+}
+
+/// 可分享给他人的供应商 JSON：去掉内部字段并清空密钥
+pub fn provider_share_value(provider: &Provider) -> Value {
+    let value = serde_json::to_value(provider).unwrap_or(Value::Null);
+    blank_provider_secrets(&strip_provider_internal_fields(&value))
+}
+
+/// 展示完整配置时将 `meta.apiKeys` Key 池脱敏（只保留前 8 个字符）
+pub fn redact_key_pools(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                match v {
+                    Value::Array(keys) if k == "apiKeys" => {
+                        for key in keys.iter_mut() {
+                            if let Value::String(s) = key {
+                                let prefix: String = s.chars().take(8).collect();
+                                if prefix.len() < s.len() {
+                                    *s = format!("{prefix}...");
+                                }
+                            }
+                        }
+                    }
+                    other => redact_key_pools(other),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_key_pools),
+        _ => {}
+    }
+}
+
+/// 编辑前后单个字段的变化；路径形如 `settingsConfig.env.ANTHROPIC_BASE_URL`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderFieldChange {
+    pub path: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+    /// 密钥类字段只提示已修改，不显示内容
+    pub secret: bool,
+}
+
+/// 按字段比较编辑前后的供应商；Codex 的 `config` TOML 按键展开比较
+pub fn provider_field_changes(before: &Provider, after: &Provider) -> Vec<ProviderFieldChange> {
+    let leaves = |provider: &Provider| {
+        let mut out = BTreeMap::new();
+        flatten_leaves(
+            "",
+            &serde_json::to_value(provider).unwrap_or(Value::Null),
+            &mut out,
+        );
+        // 未设置时默认合并通用配置，与显式 true 等价
+        out.entry("meta.applyCommonConfig".to_string())
+            .or_insert_with(|| "true".to_string());
+        out
+    };
+    let (old, new) = (leaves(before), leaves(after));
+    let paths: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    paths
+        .into_iter()
+        .filter(|path| old.get(*path) != new.get(*path))
+        .map(|path| ProviderFieldChange {
+            path: path.clone(),
+            old: old.get(path).cloned(),
+            new: new.get(path).cloned(),
+            // 数组元素路径形如 `meta.apiKeys[0]`，按所在字段名判断
+            secret: path
+                .rsplit('.')
+                .next()
+                .and_then(|segment| segment.split('[').next())
+                .is_some_and(is_secret_field),
+        })
+        .collect()
+}
+
+fn flatten_leaves(prefix: &str, value: &Value, out: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                let toml_table = match child {
+                    Value::String(text) if key == "config" => toml::from_str::<toml::Table>(text)
+                        .ok()
+                        .and_then(|table| serde_json::to_value(table).ok()),
+                    _ => None,
+                };
+                flatten_leaves(&path, toml_table.as_ref().unwrap_or(child), out);
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                flatten_leaves(&format!("{prefix}[{index}]"), item, out);
+            }
+        }
+        Value::Null => {}
+        Value::String(text) => {
+            out.insert(prefix.to_string(), text.clone());
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+/// 每个变化一行：`~ 路径: 旧 → 新`，新增 / 删除分别以 `+` / `-` 开头
+pub fn format_provider_field_changes(changes: &[ProviderFieldChange]) -> Vec<String> {
+    changes
+        .iter()
+        .map(|change| {
+            let path = &change.path;
+            let marker = match (&change.old, &change.new) {
+                (None, Some(_)) => '+',
+                (Some(_), None) => '-',
+                _ => '~',
+            };
+            if change.secret {
+                return format!(
+                    "{marker} {path}: {}",
+                    crate::cli::i18n::texts::provider_diff_secret()
+                );
+            }
+            match (&change.old, &change.new) {
+                (Some(old), Some(new)) => format!("{marker} {path}: {old} → {new}"),
+                (Some(value), None) | (None, Some(value)) => format!("{marker} {path}: {value}"),
+                (None, None) => format!("{marker} {path}"),
+            }
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub enum FormState {
     ProviderAdd(ProviderAddFormState),
@@ -1871,6 +2433,197 @@ const MCP_TEMPLATES: [&str; 2] = ["Custom", "Filesystem (npx)"];
 mod tests {
     use super::*;
 
+    #[test]
+    fn new_provider_defaults_are_not_applied_when_editing() {
+        let add = ProviderAddFormState::with_defaults(
+            AppType::Claude,
+            (
+                "https://relay.example.org".to_string(),
+                "org-claude".to_string(),
+            ),
+        );
+        assert_eq!(add.claude_base_url.value, "https://relay.example.org");
+        assert_eq!(add.claude_model.value, "org-claude");
+
+        let official = Provider::with_id(
+            "official".to_string(),
+            "Claude Official".to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-official" } }),
+            None,
+        );
+        let edit = ProviderAddFormState::from_provider(AppType::Claude, &official);
+        assert!(edit.claude_base_url.is_blank());
+        assert!(edit.claude_model.is_blank());
+
+        let saved = edit.to_provider_json_value();
+        assert_eq!(
+            saved["settingsConfig"], official.settings_config,
+            "editing must not inject the org base URL or model"
+        );
+    }
+
+    #[test]
+    fn provider_field_changes_expand_codex_toml_and_hide_secrets() {
+        let before = Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-old" },
+                "config": "model = \"gpt-5\"\nbase_url = \"https://old.example/v1\"\n"
+            }),
+            None,
+        );
+        let mut after = before.clone();
+        after.settings_config = json!({
+            "auth": { "OPENAI_API_KEY": "sk-new" },
+            "config": "model = \"gpt-5\"\nbase_url = \"https://new.example/v1\"\nwire_api = \"responses\"\n"
+        });
+
+        let lines = format_provider_field_changes(&provider_field_changes(&before, &after));
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            format!(
+                "~ settingsConfig.auth.OPENAI_API_KEY: {}",
+                crate::cli::i18n::texts::provider_diff_secret()
+            )
+        );
+        assert_eq!(
+            lines[1],
+            "~ settingsConfig.config.base_url: https://old.example/v1 → https://new.example/v1"
+        );
+        assert_eq!(lines[2], "+ settingsConfig.config.wire_api: responses");
+        assert!(provider_field_changes(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn provider_field_changes_mask_key_pool_elements() {
+        let before = Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-one" } }),
+            None,
+        );
+        let mut after = before.clone();
+        after.meta = Some(crate::provider::ProviderMeta {
+            api_keys: vec!["sk-one".to_string(), "sk-two".to_string()],
+            ..Default::default()
+        });
+
+        let changes = provider_field_changes(&before, &after);
+        let pooled: Vec<&ProviderFieldChange> = changes
+            .iter()
+            .filter(|change| change.path.starts_with("meta.apiKeys"))
+            .collect();
+        assert_eq!(
+            pooled.iter().map(|c| c.path.as_str()).collect::<Vec<_>>(),
+            ["meta.apiKeys[0]", "meta.apiKeys[1]"]
+        );
+        assert!(pooled.iter().all(|change| change.secret));
+        let lines = format_provider_field_changes(&changes);
+        assert!(
+            lines.iter().all(|line| !line.contains("sk-two")),
+            "{lines:?}"
+        );
+    }
+
+    #[test]
+    fn provider_share_value_strips_internal_fields_and_blanks_secrets() {
+        let mut provider = Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-live" },
+                "env": { "SHARED_TOKEN": "${env:SHARED_TOKEN}" },
+                "config": "model = \"gpt-5\"\n\n[model_providers.relay]\nbase_url = \"https://relay.example/v1\"\nenv_key = \"RELAY_KEY\"\nexperimental_bearer_token = \"tok-123\"\n"
+            }),
+            None,
+        );
+        provider.created_at = Some(1);
+        provider.meta = Some(Default::default());
+
+        let shared = provider_share_value(&provider);
+        assert!(shared.get("createdAt").is_none());
+        assert!(shared.get("meta").is_none());
+        assert_eq!(shared["id"], "relay");
+        let settings = &shared["settingsConfig"];
+        assert_eq!(settings["auth"]["OPENAI_API_KEY"], "");
+        assert_eq!(
+            settings["env"]["SHARED_TOKEN"], "${env:SHARED_TOKEN}",
+            "env placeholders are not secrets"
+        );
+        let config = settings["config"].as_str().expect("config text");
+        assert!(config.contains("base_url = \"https://relay.example/v1\""));
+        assert!(config.contains("env_key = \"RELAY_KEY\""));
+        assert!(!config.contains("tok-123"));
+    }
+
+    #[test]
+    fn is_secret_field_matches_secret_suffixes_only() {
+        for key in [
+            "ANTHROPIC_AUTH_TOKEN",
+            "ANTHROPIC_API_KEY",
+            "OPENAI_API_KEY",
+            "apiKey",
+            "x-api-key",
+            "experimental_bearer_token",
+            "client_secret",
+            "Authorization",
+            "GITHUB_TOKEN",
+            "apiKeys",
+            // Codex auth.json 的 ChatGPT 登录凭据
+            "id_token",
+            "idToken",
+            "access_token",
+            "refresh_token",
+        ] {
+            assert!(is_secret_field(key), "{key} should be secret");
+        }
+        for key in [
+            "CLAUDE_CODE_MAX_OUTPUT_TOKENS",
+            "MAX_THINKING_TOKENS",
+            "MAX_OUTPUT_TOKENS",
+            "env_key",
+            "ANTHROPIC_BASE_URL",
+        ] {
+            assert!(!is_secret_field(key), "{key} should stay visible");
+        }
+    }
+
+    #[test]
+    fn provider_share_value_keeps_token_limits_visible() {
+        let provider = Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            json!({ "env": {
+                "ANTHROPIC_AUTH_TOKEN": "sk-live",
+                "MAX_OUTPUT_TOKENS": "8192"
+            } }),
+            None,
+        );
+        let shared = provider_share_value(&provider);
+        assert_eq!(shared["settingsConfig"]["env"]["ANTHROPIC_AUTH_TOKEN"], "");
+        assert_eq!(shared["settingsConfig"]["env"]["MAX_OUTPUT_TOKENS"], "8192");
+    }
+
+    #[test]
+    fn redact_key_pools_masks_only_pooled_keys() {
+        let mut value = json!({
+            "claude": { "providers": { "p1": {
+                "settingsConfig": { "env": { "ANTHROPIC_AUTH_TOKEN": "sk-primary-123456" } },
+                "meta": { "apiKeys": ["sk-pool-aaaaaaaa", "short"], "apiKeyIndex": 1 }
+            } } }
+        });
+        redact_key_pools(&mut value);
+        let provider = &value["claude"]["providers"]["p1"];
+        assert_eq!(provider["meta"]["apiKeys"], json!(["sk-pool-...", "short"]));
+        assert_eq!(provider["meta"]["apiKeyIndex"], 1);
+        assert_eq!(
+            provider["settingsConfig"]["env"]["ANTHROPIC_AUTH_TOKEN"],
+            "sk-primary-123456"
+        );
+    }
+
     fn packycode_template_index(app_type: AppType) -> usize {
         let builtin_len = provider_builtin_template_defs(&app_type).len();
         let sponsor_idx = SPONSOR_PROVIDER_PRESETS
@@ -1931,6 +2684,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn provider_add_form_hidden_sponsor_presets_are_omitted_and_indexes_shift() {
+        let mut form = ProviderAddFormState::new(AppType::Claude);
+        form.show_sponsor_presets = false;
+
+        let labels = form.template_labels();
+        assert!(!labels.contains(&"* PackyCode"));
+        assert_eq!(labels.len(), form.template_count());
+
+        let idx = labels
+            .iter()
+            .position(|label| *label == "RightCode")
+            .expect("RightCode template should remain");
+        form.apply_template(idx, &[]);
+        assert_eq!(form.to_provider_json_value()["name"], "RightCode");
+    }
+
+    #[test]
+    fn provider_add_form_normalizes_expiry_and_flags_invalid_dates() {
+        let mut form = ProviderAddFormState::new(AppType::Claude);
+        form.expires_at.set(" 2026-1-5 ");
+        assert!(!form.expires_at_invalid());
+        assert_eq!(
+            form.to_provider_json_value()["meta"]["expiresAt"],
+            "2026-01-05"
+        );
+
+        form.expires_at.set("next month");
+        assert!(form.expires_at_invalid());
+        form.expires_at.set("");
+        assert!(!form.expires_at_invalid());
+        assert!(form.to_provider_json_value()["meta"]
+            .get("expiresAt")
+            .is_none());
+    }
+
     fn rightcode_template_index(app_type: AppType) -> usize {
         ProviderAddFormState::new(app_type)
             .template_labels()
@@ -1995,6 +2784,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn provider_add_form_headers_round_trip_through_settings_config() {
+        let mut form = ProviderAddFormState::new(AppType::Claude);
+        form.id.set("relay");
+        form.name.set("Relay");
+        form.headers.set("x-relay-region: us; x-client: cli");
+
+        let value = form.to_provider_json_value();
+        assert_eq!(
+            value["settingsConfig"]["headers"],
+            json!({ "x-relay-region": "us", "x-client": "cli" })
+        );
+
+        let provider: Provider = serde_json::from_value(value).expect("parse provider");
+        let mut edit = ProviderAddFormState::from_provider(AppType::Claude, &provider);
+        assert_eq!(edit.headers.value, "x-client: cli; x-relay-region: us");
+
+        edit.headers.set("");
+        let value = edit.to_provider_json_value();
+        assert!(value["settingsConfig"].get("headers").is_none());
+    }
+
     #[test]
     fn provider_add_form_fields_include_notes() {
         for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
@@ -2077,6 +2888,74 @@ mod tests {
         assert_eq!(provider["meta"]["partnerPromotionKey"], "packycode");
     }
 
+    #[test]
+    fn provider_add_form_relay_templates_follow_sponsors_and_skip_unsupported_apps() {
+        let claude_labels = ProviderAddFormState::new(AppType::Claude).template_labels();
+        let sponsor_pos = claude_labels
+            .iter()
+            .position(|label| *label == "* PackyCode")
+            .expect("PackyCode label");
+        let deepseek_pos = claude_labels
+            .iter()
+            .position(|label| *label == "DeepSeek")
+            .expect("DeepSeek relay label for Claude");
+        assert!(deepseek_pos > sponsor_pos);
+
+        let codex_labels = ProviderAddFormState::new(AppType::Codex).template_labels();
+        assert!(codex_labels.contains(&"DeepSeek"));
+        assert!(!codex_labels.contains(&"Zhipu GLM"));
+    }
+
+    #[test]
+    fn provider_add_form_relay_template_claude_sets_base_url_and_models_without_key() {
+        let mut form = ProviderAddFormState::new(AppType::Claude);
+        form.claude_api_key.set("sk-previous");
+        let existing_ids = Vec::<String>::new();
+
+        let idx = form
+            .template_labels()
+            .iter()
+            .position(|label| *label == "DeepSeek")
+            .expect("DeepSeek relay template");
+        form.apply_template(idx, &existing_ids);
+
+        let provider = form.to_provider_json_value();
+        assert_eq!(provider["id"], "deepseek");
+        assert_eq!(provider["name"], "DeepSeek");
+        let env = &provider["settingsConfig"]["env"];
+        assert_eq!(
+            env["ANTHROPIC_BASE_URL"],
+            "https://api.deepseek.com/anthropic"
+        );
+        assert_eq!(env["ANTHROPIC_MODEL"], "deepseek-chat");
+        assert_eq!(env["ANTHROPIC_DEFAULT_SONNET_MODEL"], "deepseek-chat");
+        assert!(env.get("ANTHROPIC_AUTH_TOKEN").is_none());
+        assert!(provider["meta"].get("isPartner").is_none());
+        assert!(provider["meta"].get("partnerPromotionKey").is_none());
+    }
+
+    #[test]
+    fn provider_add_form_relay_template_codex_uses_chat_wire_api() {
+        let mut form = ProviderAddFormState::new(AppType::Codex);
+        let existing_ids = Vec::<String>::new();
+
+        let idx = form
+            .template_labels()
+            .iter()
+            .position(|label| *label == "Moonshot (Kimi)")
+            .expect("Moonshot relay template");
+        form.apply_template(idx, &existing_ids);
+
+        let provider = form.to_provider_json_value();
+        let cfg = provider["settingsConfig"]["config"]
+            .as_str()
+            .expect("settingsConfig.config should be string");
+        assert!(cfg.contains("base_url = \"https://api.moonshot.cn/v1\""));
+        assert!(cfg.contains("model = \"kimi-k2-turbo-preview\""));
+        assert!(cfg.contains("wire_api = \"chat\""));
+        assert!(provider["settingsConfig"].get("auth").is_none());
+    }
+
     #[test]
     fn provider_add_form_packycode_template_gemini_sets_partner_meta_and_base_url() {
         let mut form = ProviderAddFormState::new(AppType::Gemini);
@@ -2476,6 +3355,57 @@ requires_openai_auth = true
         assert_eq!(server["apps"]["gemini"], true);
     }
 
+    #[test]
+    fn mcp_add_form_app_checkbox_group_toggles_single_and_all() {
+        let mut form = McpAddFormState::new();
+        assert_eq!(form.fields().last(), Some(&McpAddField::Apps));
+
+        form.move_app_cursor(true);
+        form.toggle_app_at_cursor();
+        assert!(!form.apps.claude && form.apps.codex && !form.apps.gemini);
+
+        form.toggle_all_apps();
+        assert!(form.apps.claude && form.apps.codex && form.apps.gemini);
+        form.toggle_all_apps();
+        assert!(!form.apps.claude && !form.apps.codex && !form.apps.gemini);
+
+        form.move_app_cursor(true);
+        form.move_app_cursor(true);
+        assert_eq!(form.app_cursor, MCP_FORM_APPS.len() - 1);
+    }
+
+    #[test]
+    fn mcp_add_form_remote_transport_swaps_command_fields_for_url() {
+        let mut form = McpAddFormState::new();
+        form.id.set("remote");
+        form.name.set("Remote");
+        form.command.set("npx");
+        form.transport = McpTransport::Http;
+        form.url.set(" https://mcp.example.com/mcp ");
+
+        assert!(form.fields().contains(&McpAddField::Url));
+        assert!(!form.fields().contains(&McpAddField::Command));
+
+        let server = form.to_mcp_server_json_value();
+        assert_eq!(server["server"]["type"], "http");
+        assert_eq!(server["server"]["url"], "https://mcp.example.com/mcp");
+        assert!(server["server"].get("command").is_none());
+        assert!(server["server"].get("args").is_none());
+
+        let parsed: McpServer = serde_json::from_value(server).expect("server");
+        let reopened = McpAddFormState::from_server(&parsed);
+        assert_eq!(reopened.transport, McpTransport::Http);
+        assert_eq!(reopened.url.value, "https://mcp.example.com/mcp");
+
+        let mut back = reopened.clone();
+        back.transport = McpTransport::Stdio;
+        back.command.set("uvx");
+        let server = back.to_mcp_server_json_value();
+        assert_eq!(server["server"]["type"], "stdio");
+        assert_eq!(server["server"]["command"], "uvx");
+        assert!(server["server"].get("url").is_none());
+    }
+
     #[test]
     fn provider_add_form_switching_back_to_custom_clears_template_values() {
         let mut form = ProviderAddFormState::new(AppType::Claude);
@@ -1,33 +1,41 @@
 mod app;
 mod data;
-mod form;
+pub(crate) mod form;
 mod route;
 mod terminal;
 mod theme;
 mod ui;
 
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
 use crossterm::event::{self, KeyEventKind};
+use ratatui::prelude::Size;
 use serde_json::json;
 use serde_json::Value;
 
 use crate::app_config::AppType;
 use crate::cli::i18n::{set_language, texts};
+use crate::cli::ui::{line_diff, skills_sync_note, DiffLine};
 use crate::error::AppError;
 use crate::provider::Provider;
 use crate::services::{
-    skill::SkillRepo, ConfigService, EndpointLatency, McpService, PromptService, ProviderService,
-    SkillService, SyncDecision, WebDavSyncService,
+    rank_failover_candidates, skill::SkillRepo, ChangeKind, ConfigArchiveService,
+    ConfigChangeSummary, ConfigService, DiagnosticStatus, EndpointLatency, FailoverCandidate,
+    LiveVerification, McpService, PromptService, ProviderService, SkillService, SyncDecision,
+    WebDavSyncService,
 };
 use crate::settings::{
-    get_webdav_sync_settings, set_webdav_sync_settings, webdav_jianguoyun_preset,
+    get_webdav_sync_settings, set_webdav_sync_settings, webdav_jianguoyun_preset, TuiSession,
     WebDavSyncSettings,
 };
 
-use app::{Action, App, EditorSubmit, LoadingKind, Overlay, TextViewState, ToastKind};
+use app::{
+    sort_speedtest_rows, Action, App, EditorSubmit, LoadingKind, Overlay, SpeedtestResultRow,
+    SpeedtestSort, SpeedtestTarget, StartupCheck, TextViewState, ToastKind,
+};
 use data::{load_state, UiData};
 use form::FormState;
 use terminal::{PanicRestoreHookGuard, TuiTerminal};
@@ -36,10 +44,15 @@ fn command_lookup_name(raw: &str) -> Option<&str> {
     raw.split_whitespace().next()
 }
 
+struct SpeedtestReq {
+    label: String,
+    targets: Vec<SpeedtestTarget>,
+}
+
 enum SpeedtestMsg {
     Finished {
-        url: String,
-        result: Result<Vec<EndpointLatency>, String>,
+        label: String,
+        result: Result<Vec<SpeedtestResultRow>, String>,
     },
 }
 
@@ -54,12 +67,17 @@ enum LocalEnvMsg {
 }
 
 enum SkillsReq {
-    Discover { query: String },
+    Discover { request_id: u64, query: String },
     Install { spec: String, app: AppType },
 }
 
 enum SkillsMsg {
+    DiscoverProgress {
+        request_id: u64,
+        progress: crate::services::DiscoverProgress,
+    },
     DiscoverFinished {
+        request_id: u64,
         query: String,
         result: Result<Vec<crate::services::skill::Skill>, String>,
     },
@@ -113,7 +131,7 @@ enum WebDavMsg {
 }
 
 struct SpeedtestSystem {
-    req_tx: mpsc::Sender<String>,
+    req_tx: mpsc::Sender<SpeedtestReq>,
     result_rx: mpsc::Receiver<SpeedtestMsg>,
     _handle: std::thread::JoinHandle<()>,
 }
@@ -190,81 +208,105 @@ impl RequestTracker {
     }
 }
 
+static FRESH_START: AtomicBool = AtomicBool::new(false);
+
+/// 命令行 `--fresh`：本次启动不恢复上次的页面与应用
+pub fn set_fresh_start(enabled: bool) {
+    FRESH_START.store(enabled, Ordering::Relaxed);
+}
+
+fn startup_check<T>(label: &str, result: &Result<T, AppError>) -> StartupCheck {
+    match result {
+        Ok(_) => StartupCheck::ok(label),
+        Err(err) => StartupCheck::warn(label, err.to_string()),
+    }
+}
+
+/// 启动时的环境检查：离线模式与各应用配置目录是否已初始化
+fn startup_environment_checks() -> Vec<StartupCheck> {
+    let mut checks = vec![if crate::settings::is_offline_mode() {
+        StartupCheck::warn(
+            texts::tui_startup_network(),
+            texts::tui_startup_offline_mode(),
+        )
+    } else {
+        StartupCheck::ok(texts::tui_startup_network())
+    }];
+    for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        let label = texts::tui_startup_app_config(app_type.as_str());
+        checks.push(if crate::sync_policy::should_sync_live(&app_type) {
+            StartupCheck::ok(label)
+        } else {
+            StartupCheck::warn(label, texts::tui_startup_app_not_initialized())
+        });
+    }
+    checks
+}
+
 pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
     let _panic_hook = PanicRestoreHookGuard::install();
     let mut terminal = TuiTerminal::new()?;
-    let mut app = App::new(app_override);
+    let session = if FRESH_START.load(Ordering::Relaxed) {
+        None
+    } else {
+        crate::settings::get_tui_last_session()
+    };
+    let mut app = App::new(
+        app_override
+            .or_else(crate::cli::detect_app_from_cwd)
+            .or_else(|| session.as_ref().map(|session| session.app.clone())),
+    );
+    let last_route = session.and_then(|session| route::Route::from_session_key(&session.route));
+    if let Some(route) = last_route {
+        app.restore_route(route);
+    }
     let mut data = UiData::load(&app.app_type)?;
+    push_expiry_warning_toast(&mut app, &data);
 
-    let tick_rate = Duration::from_millis(200);
+    let mut tick_rate = Duration::from_millis(app::tick_rate_ms());
     let mut last_tick = Instant::now();
     let mut webdav_loading = RequestTracker::default();
     let mut update_check = RequestTracker::default();
+    let mut skills_discover = RequestTracker::default();
 
-    let speedtest = match start_speedtest_system() {
-        Ok(system) => Some(system),
-        Err(err) => {
-            app.push_toast(
-                texts::tui_toast_speedtest_unavailable(&err.to_string()),
-                ToastKind::Warning,
-            );
-            None
-        }
-    };
+    // 启动检查汇总到一个概览浮层，而不是逐条弹出 toast
+    let mut startup_checks = Vec::new();
 
-    let skills = match start_skills_system() {
-        Ok(system) => Some(system),
-        Err(err) => {
-            app.push_toast(
-                texts::tui_toast_skills_worker_unavailable(&err.to_string()),
-                ToastKind::Warning,
-            );
-            None
-        }
-    };
+    let speedtest = start_speedtest_system();
+    startup_checks.push(startup_check(texts::tui_startup_speedtest(), &speedtest));
+    let speedtest = speedtest.ok();
+
+    let skills = start_skills_system();
+    startup_checks.push(startup_check(texts::tui_startup_skills(), &skills));
+    let skills = skills.ok();
 
-    let local_env = match start_local_env_system() {
-        Ok(system) => {
-            if let Err(err) = system.req_tx.send(LocalEnvReq::Refresh) {
+    let local_env = start_local_env_system();
+    let local_env_check = match &local_env {
+        Ok(system) => match system.req_tx.send(LocalEnvReq::Refresh) {
+            Ok(()) => StartupCheck::ok(texts::tui_startup_local_env()),
+            Err(err) => {
                 app.local_env_loading = false;
-                app.push_toast(
-                    texts::tui_toast_local_env_check_request_failed(&err.to_string()),
-                    ToastKind::Warning,
-                );
+                StartupCheck::warn(texts::tui_startup_local_env(), err.to_string())
             }
-            Some(system)
-        }
+        },
         Err(err) => {
             app.local_env_loading = false;
-            app.push_toast(
-                texts::tui_toast_local_env_check_unavailable(&err.to_string()),
-                ToastKind::Warning,
-            );
-            None
+            StartupCheck::warn(texts::tui_startup_local_env(), err.to_string())
         }
     };
+    startup_checks.push(local_env_check);
+    let local_env = local_env.ok();
 
-    let webdav = match start_webdav_system() {
-        Ok(system) => Some(system),
-        Err(err) => {
-            app.push_toast(
-                texts::tui_toast_webdav_worker_unavailable(&err.to_string()),
-                ToastKind::Warning,
-            );
-            None
-        }
-    };
+    let webdav = start_webdav_system();
+    startup_checks.push(startup_check(texts::tui_startup_webdav(), &webdav));
+    let webdav = webdav.ok();
 
-    let update_system = match start_update_system() {
-        Ok(system) => Some(system),
-        Err(err) => {
-            app.push_toast(
-                texts::tui_toast_update_check_failed(&err.to_string()),
-                ToastKind::Warning,
-            );
-            None
-        }
-    };
+    let update_system = start_update_system();
+    startup_checks.push(startup_check(texts::tui_startup_update(), &update_system));
+    let update_system = update_system.ok();
+
+    startup_checks.extend(startup_environment_checks());
+    app.show_startup_summary(startup_checks);
 
     loop {
         app.last_size = terminal.size()?;
@@ -273,7 +315,7 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
         // Handle async speedtest results (non-blocking).
         if let Some(speedtest) = speedtest.as_ref() {
             while let Ok(msg) = speedtest.result_rx.try_recv() {
-                handle_speedtest_msg(&mut app, msg);
+                handle_speedtest_msg(&mut app, &mut data, msg);
             }
         }
 
@@ -287,7 +329,8 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
         // Handle async Skills results (non-blocking).
         if let Some(skills) = skills.as_ref() {
             while let Ok(msg) = skills.result_rx.try_recv() {
-                if let Err(err) = handle_skills_msg(&mut app, &mut data, msg) {
+                if let Err(err) = handle_skills_msg(&mut app, &mut data, &mut skills_discover, msg)
+                {
                     app.push_toast(err.to_string(), ToastKind::Error);
                 }
             }
@@ -311,39 +354,51 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
 
         let timeout = tick_rate.saturating_sub(last_tick.elapsed());
         if event::poll(timeout).map_err(|e| AppError::Message(e.to_string()))? {
-            match event::read().map_err(|e| AppError::Message(e.to_string()))? {
+            let action = match event::read().map_err(|e| AppError::Message(e.to_string()))? {
                 event::Event::Key(key) if key.kind == KeyEventKind::Press => {
-                    let action = app.on_key(key, &data);
-                    if let Err(err) = handle_action(
-                        &mut terminal,
-                        &mut app,
-                        &mut data,
-                        speedtest.as_ref().map(|s| &s.req_tx),
-                        skills.as_ref().map(|s| &s.req_tx),
-                        local_env.as_ref().map(|s| &s.req_tx),
-                        webdav.as_ref().map(|s| &s.req_tx),
-                        &mut webdav_loading,
-                        update_system.as_ref().map(|s| &s.req_tx),
-                        &mut update_check,
-                        action,
+                    Some(app.on_key(key, &data))
+                }
+                event::Event::Paste(text) => Some(app.on_paste(&text, &data)),
+                event::Event::Resize(width, height) => {
+                    // 立即按新尺寸重绘，避免部分终端在下一次按键前显示错乱的旧布局
+                    let size = Size::new(width, height);
+                    terminal.resize(size)?;
+                    app.on_resize(size);
+                    terminal.draw(|f| ui::render(f, &app, &data))?;
+                    None
+                }
+                _ => None,
+            };
+            if let Some(action) = action {
+                if let Err(err) = handle_action(
+                    &mut terminal,
+                    &mut app,
+                    &mut data,
+                    speedtest.as_ref().map(|s| &s.req_tx),
+                    skills.as_ref().map(|s| &s.req_tx),
+                    &mut skills_discover,
+                    local_env.as_ref().map(|s| &s.req_tx),
+                    webdav.as_ref().map(|s| &s.req_tx),
+                    &mut webdav_loading,
+                    update_system.as_ref().map(|s| &s.req_tx),
+                    &mut update_check,
+                    action,
+                ) {
+                    if matches!(
+                        &err,
+                        AppError::Localized { key, .. } if *key == "tui_terminal_error"
                     ) {
-                        if matches!(
-                            &err,
-                            AppError::Localized { key, .. } if *key == "tui_terminal_error"
-                        ) {
-                            return Err(err);
-                        }
-                        app.push_toast(err.to_string(), ToastKind::Error);
+                        return Err(err);
                     }
+                    app.push_toast(err.to_string(), ToastKind::Error);
                 }
-                event::Event::Resize(_, _) => {}
-                _ => {}
             }
         }
 
         if last_tick.elapsed() >= tick_rate {
             app.on_tick();
             last_tick = Instant::now();
+            tick_rate = Duration::from_millis(app::tick_rate_ms());
         }
 
         if app.should_quit {
@@ -351,39 +406,96 @@ pub fn run(app_override: Option<AppType>) -> Result<(), AppError> {
         }
     }
 
+    save_tui_session(&app);
     Ok(())
 }
 
-fn handle_speedtest_msg(app: &mut App, msg: SpeedtestMsg) {
+/// 退出时记录当前页面与应用；只读模式下不写入
+fn save_tui_session(app: &App) {
+    if crate::settings::is_read_only() {
+        return;
+    }
+    let session = TuiSession {
+        route: app.route.session_key().to_string(),
+        app: app.app_type.clone(),
+    };
+    if crate::settings::get_tui_last_session().as_ref() == Some(&session) {
+        return;
+    }
+    if let Err(err) = crate::settings::set_tui_last_session(Some(session)) {
+        log::warn!("保存 TUI 页面状态失败: {err}");
+    }
+}
+
+/// 导入 / 恢复后以文本视图列出供应商、MCP 服务器和提示词的变更
+fn show_config_changes(app: &mut App, changes: &ConfigChangeSummary) {
+    let mut lines = vec![texts::config_changes_counts(
+        changes.count(ChangeKind::Added),
+        changes.count(ChangeKind::Removed),
+        changes.count(ChangeKind::Modified),
+    )];
+    if changes.is_empty() {
+        lines.push(texts::config_changes_none().to_string());
+    }
+    lines.push(String::new());
+    lines.extend(changes.changes.iter().map(|change| {
+        let marker = match change.kind {
+            ChangeKind::Added => "+",
+            ChangeKind::Removed => "-",
+            ChangeKind::Modified => "~",
+        };
+        format!(
+            "{marker} {:<8} {:<7} {}  ({})",
+            change.section.as_str(),
+            change.app.as_ref().map(AppType::as_str).unwrap_or("-"),
+            change.id,
+            texts::config_change_kind(change.kind)
+        )
+    }));
+    app.overlay = Overlay::TextView(TextViewState {
+        title: texts::config_changes_title().to_string(),
+        lines,
+        scroll: 0,
+    });
+}
+
+/// 启动时提醒已过期或即将到期的供应商密钥（仅比较本地日期）
+fn push_expiry_warning_toast(app: &mut App, data: &UiData) {
+    let warnings = crate::services::collect_expiry_warnings(
+        data.providers.rows.iter().map(|row| &row.provider),
+        chrono::Local::now().date_naive(),
+        crate::services::expiry_warning_days(),
+    );
+    if !warnings.is_empty() {
+        app.push_toast(
+            texts::tui_toast_provider_expiry_warnings(&warnings),
+            ToastKind::Warning,
+        );
+    }
+}
+
+fn handle_speedtest_msg(app: &mut App, data: &mut UiData, msg: SpeedtestMsg) {
     match msg {
-        SpeedtestMsg::Finished { url, result } => match result {
-            Ok(rows) => {
-                let mut lines = vec![texts::tui_speedtest_line_url(&url), String::new()];
-                for row in rows {
-                    let latency = row
-                        .latency
-                        .map(texts::tui_latency_ms)
-                        .unwrap_or_else(|| texts::tui_na().to_string());
-                    let status = row
-                        .status
-                        .map(|v| v.to_string())
-                        .unwrap_or_else(|| texts::tui_na().to_string());
-                    let err = row.error.unwrap_or_default();
-
-                    lines.push(texts::tui_speedtest_line_latency(&latency));
-                    lines.push(texts::tui_speedtest_line_status(&status));
-                    if !err.trim().is_empty() {
-                        lines.push(texts::tui_speedtest_line_error(&err));
+        SpeedtestMsg::Finished { label, result } => match result {
+            Ok(mut rows) => {
+                sort_speedtest_rows(&mut rows, SpeedtestSort::Latency);
+                if app.failover_label.as_ref() == Some(&label) {
+                    app.failover_label = None;
+                    if let Err(err) = switch_to_fastest_failover(app, data, &rows) {
+                        app.push_toast(err.to_string(), ToastKind::Error);
                     }
                 }
 
                 // Only force-open the result modal if the user hasn't closed it.
                 match &app.overlay {
-                    Overlay::SpeedtestRunning { url: running_url } if running_url == &url => {
+                    Overlay::SpeedtestRunning {
+                        label: running_label,
+                    } if running_label == &label => {
                         app.overlay = Overlay::SpeedtestResult {
-                            url,
-                            lines,
-                            scroll: 0,
+                            label,
+                            rows,
+                            selected: 0,
+                            sort: SpeedtestSort::Latency,
                         };
                     }
                     _ => {
@@ -392,8 +504,11 @@ fn handle_speedtest_msg(app: &mut App, msg: SpeedtestMsg) {
                 }
             }
             Err(err) => {
+                if app.failover_label.as_ref() == Some(&label) {
+                    app.failover_label = None;
+                }
                 app.push_toast(texts::tui_toast_speedtest_failed(&err), ToastKind::Error);
-                if matches!(&app.overlay, Overlay::SpeedtestRunning { url: running_url } if running_url == &url)
+                if matches!(&app.overlay, Overlay::SpeedtestRunning { label: running_label } if running_label == &label)
                 {
                     app.overlay = Overlay::None;
                 }
@@ -402,6 +517,55 @@ fn handle_speedtest_msg(app: &mut App, msg: SpeedtestMsg) {
     }
 }
 
+/// 故障转移测速完成后切换到最快的健康供应商
+fn switch_to_fastest_failover(
+    app: &mut App,
+    data: &mut UiData,
+    rows: &[SpeedtestResultRow],
+) -> Result<(), AppError> {
+    let mut candidates: Vec<FailoverCandidate> = rows
+        .iter()
+        .map(|row| FailoverCandidate {
+            provider_id: row.target.provider_id.clone(),
+            provider_name: row.target.provider_name.clone(),
+            url: Some(row.target.url.clone()),
+            headers: row.target.headers.clone(),
+            latency: row.latency,
+            status: row.status,
+            error: row.error.clone(),
+        })
+        .collect();
+    rank_failover_candidates(&mut candidates);
+
+    let Some(best) = candidates.first().filter(|c| c.is_healthy()) else {
+        app.push_toast(texts::tui_toast_failover_no_healthy(), ToastKind::Warning);
+        return Ok(());
+    };
+    if data.providers.current_id == best.provider_id {
+        app.push_toast(
+            texts::tui_toast_failover_already_fastest(&best.provider_name),
+            ToastKind::Info,
+        );
+        return Ok(());
+    }
+
+    let state = load_state()?;
+    let outcome = ProviderService::switch(&state, app.app_type.clone(), &best.provider_id)?;
+    let mut message = texts::tui_toast_failover_switched(&best.provider_name);
+    let mut kind = ToastKind::Success;
+    if let Some(result) = &outcome.skills_sync {
+        let (note, failed) = skills_sync_note(result);
+        message.push(' ');
+        message.push_str(&note);
+        if failed {
+            kind = ToastKind::Warning;
+        }
+    }
+    app.push_toast(message, kind);
+    *data = UiData::load(&app.app_type)?;
+    Ok(())
+}
+
 fn handle_local_env_msg(app: &mut App, msg: LocalEnvMsg) {
     match msg {
         LocalEnvMsg::Finished { result } => {
@@ -411,27 +575,64 @@ fn handle_local_env_msg(app: &mut App, msg: LocalEnvMsg) {
     }
 }
 
-fn handle_skills_msg(app: &mut App, data: &mut UiData, msg: SkillsMsg) -> Result<(), AppError> {
+fn handle_skills_msg(
+    app: &mut App,
+    data: &mut UiData,
+    skills_discover: &mut RequestTracker,
+    msg: SkillsMsg,
+) -> Result<(), AppError> {
     match msg {
-        SkillsMsg::DiscoverFinished { query, result } => match result {
-            Ok(skills) => {
-                app.overlay = Overlay::None;
-                app.skills_discover_results = skills;
-                app.skills_discover_idx = 0;
-                app.skills_discover_query = query.clone();
-                app.push_toast(
-                    texts::tui_toast_skills_discover_finished(app.skills_discover_results.len()),
-                    ToastKind::Success,
-                );
+        SkillsMsg::DiscoverProgress {
+            request_id,
+            progress,
+        } => {
+            if skills_discover.active != Some(request_id) {
+                return Ok(());
             }
-            Err(err) => {
-                app.overlay = Overlay::None;
-                app.push_toast(
-                    texts::tui_toast_skills_discover_failed(&err),
-                    ToastKind::Error,
+            if let Overlay::Loading {
+                kind: LoadingKind::SkillsDiscover,
+                message,
+                ..
+            } = &mut app.overlay
+            {
+                *message = texts::tui_skills_discover_progress(
+                    progress.done,
+                    progress.total,
+                    &progress.pending.join(", "),
                 );
             }
-        },
+        }
+        SkillsMsg::DiscoverFinished {
+            request_id,
+            query,
+            result,
+        } => {
+            // 已取消或被新请求取代的发现结果直接丢弃
+            if !skills_discover.finish_if_active(request_id) {
+                return Ok(());
+            }
+            match result {
+                Ok(skills) => {
+                    app.overlay = Overlay::None;
+                    app.skills_discover_results = skills;
+                    app.skills_discover_idx = 0;
+                    app.skills_discover_query = query.clone();
+                    app.push_toast(
+                        texts::tui_toast_skills_discover_finished(
+                            app.skills_discover_results.len(),
+                        ),
+                        ToastKind::Success,
+                    );
+                }
+                Err(err) => {
+                    app.overlay = Overlay::None;
+                    app.push_toast(
+                        texts::tui_toast_skills_discover_failed(&err),
+                        ToastKind::Error,
+                    );
+                }
+            }
+        }
         SkillsMsg::InstallFinished { spec, result } => match result {
             Ok(installed) => {
                 app.overlay = Overlay::None;
@@ -593,11 +794,12 @@ fn handle_webdav_msg(
 }
 
 fn handle_action(
-    _terminal: &mut TuiTerminal,
+    terminal: &mut TuiTerminal,
     app: &mut App,
     data: &mut UiData,
-    speedtest_req_tx: Option<&mpsc::Sender<String>>,
+    speedtest_req_tx: Option<&mpsc::Sender<SpeedtestReq>>,
     skills_req_tx: Option<&mpsc::Sender<SkillsReq>>,
+    skills_discover: &mut RequestTracker,
     local_env_req_tx: Option<&mpsc::Sender<LocalEnvReq>>,
     webdav_req_tx: Option<&mpsc::Sender<WebDavReq>>,
     webdav_loading: &mut RequestTracker,
@@ -605,6 +807,10 @@ fn handle_action(
     update_check: &mut RequestTracker,
     action: Action,
 ) -> Result<(), AppError> {
+    if action.is_mutation() {
+        crate::settings::ensure_writable()?;
+    }
+
     match action {
         Action::None => Ok(()),
         Action::ReloadData => {
@@ -659,6 +865,20 @@ fn handle_action(
             );
             Ok(())
         }
+        Action::SkillsToggleAllApps { directory, enabled } => {
+            let report = SkillService::toggle_all_apps(&directory, enabled)?;
+            *data = UiData::load(&app.app_type)?;
+            let failed = report.failed_count();
+            app.push_toast(
+                texts::skill_toggled_all_apps(&directory, enabled, failed),
+                if failed > 0 {
+                    ToastKind::Warning
+                } else {
+                    ToastKind::Success
+                },
+            );
+            Ok(())
+        }
         Action::SkillsInstall { spec } => {
             let Some(tx) = skills_req_tx else {
                 return Err(AppError::Message(
@@ -695,9 +915,33 @@ fn handle_action(
         }
         Action::SkillsSync { app: scope } => {
             let scope_ref = scope.as_ref();
-            SkillService::sync_all_enabled(scope_ref)?;
+            let report = SkillService::sync_all_enabled(scope_ref)?;
             *data = UiData::load(&app.app_type)?;
-            app.push_toast(texts::tui_toast_skills_synced(), ToastKind::Success);
+            let summary = texts::skills_sync_summary(
+                report.synced_count(),
+                report.skipped_count(),
+                report.failed_count(),
+            );
+            let mut lines = vec![summary, String::new()];
+            lines.extend(report.entries.iter().map(|entry| {
+                format!(
+                    "{:<8} {:<32} {}",
+                    entry.app.as_str(),
+                    entry.directory,
+                    texts::skills_sync_status_label(&entry.status)
+                )
+            }));
+            app.overlay = Overlay::TextView(TextViewState {
+                title: texts::tui_skills_sync_report_title().to_string(),
+                lines,
+                scroll: 0,
+            });
+            if report.failed_count() > 0 {
+                app.push_toast(
+                    texts::tui_toast_skills_sync_partial_failure(report.failed_count()),
+                    ToastKind::Warning,
+                );
+            }
             Ok(())
         }
         Action::SkillsSetSyncMethod { method } => {
@@ -715,13 +959,25 @@ fn handle_action(
                     texts::tui_error_skills_worker_unavailable().to_string(),
                 ));
             };
+            let request_id = skills_discover.start();
             app.overlay = Overlay::Loading {
-                kind: LoadingKind::Generic,
+                kind: LoadingKind::SkillsDiscover,
                 title: texts::tui_skills_discover_title().to_string(),
                 message: texts::tui_loading().to_string(),
             };
-            tx.send(SkillsReq::Discover { query })
-                .map_err(|e| AppError::Message(e.to_string()))?;
+            if let Err(err) = tx.send(SkillsReq::Discover { request_id, query }) {
+                skills_discover.cancel();
+                app.overlay = Overlay::None;
+                return Err(AppError::Message(err.to_string()));
+            }
+            Ok(())
+        }
+        Action::CancelSkillsDiscover => {
+            skills_discover.cancel();
+            app.push_toast(
+                texts::tui_toast_skills_discover_cancelled(),
+                ToastKind::Info,
+            );
             Ok(())
         }
         Action::SkillsRepoAdd { spec } => {
@@ -778,6 +1034,22 @@ fn handle_action(
             );
             Ok(())
         }
+        Action::SkillsUndoAppImport => {
+            let removed = SkillService::undo_last_app_import()?;
+            if removed.is_empty() {
+                app.push_toast(texts::skills_import_undo_nothing(), ToastKind::Info);
+                return Ok(());
+            }
+            *data = UiData::load(&app.app_type)?;
+            app.skills_unmanaged_results = SkillService::scan_unmanaged()?;
+            app.skills_unmanaged_selected.clear();
+            app.skills_unmanaged_idx = 0;
+            app.push_toast(
+                texts::skills_import_undone(removed.len()),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
         Action::EditorDiscard => {
             app.editor = None;
             Ok(())
@@ -1101,48 +1373,15 @@ fn handle_action(
                 let edited = content.trim().to_string();
                 let (next_snippet, toast) = if edited.is_empty() {
                     (None, texts::common_config_snippet_cleared())
-                } else if matches!(app_type, AppType::Codex) {
-                    let doc: toml_edit::DocumentMut = match edited.parse() {
-                        Ok(v) => v,
-                        Err(e) => {
-                            app.push_toast(
-                                texts::common_config_snippet_invalid_toml(&e.to_string()),
-                                ToastKind::Error,
-                            );
-                            return Ok(());
-                        }
-                    };
-                    let canonical = doc.to_string().trim().to_string();
-                    (Some(canonical), texts::common_config_snippet_saved())
                 } else {
-                    let value: Value = match serde_json::from_str(&edited) {
-                        Ok(v) => v,
-                        Err(e) => {
-                            app.push_toast(
-                                texts::common_config_snippet_invalid_json(&e.to_string()),
-                                ToastKind::Error,
-                            );
+                    match crate::cli::commands::config::normalize_common_snippet(&app_type, &edited)
+                    {
+                        Ok(snippet) => (Some(snippet), texts::common_config_snippet_saved()),
+                        Err(err) => {
+                            app.push_toast(err, ToastKind::Error);
                             return Ok(());
                         }
-                    };
-
-                    if !value.is_object() {
-                        app.push_toast(texts::common_config_snippet_not_object(), ToastKind::Error);
-                        return Ok(());
                     }
-
-                    let pretty = match serde_json::to_string_pretty(&value) {
-                        Ok(v) => v,
-                        Err(e) => {
-                            app.push_toast(
-                                texts::failed_to_serialize_json(&e.to_string()),
-                                ToastKind::Error,
-                            );
-                            return Ok(());
-                        }
-                    };
-
-                    (Some(pretty), texts::common_config_snippet_saved())
                 };
 
                 let state = load_state()?;
@@ -1207,16 +1446,49 @@ fn handle_action(
 
         Action::ProviderSwitch { id } => {
             let state = load_state()?;
-            ProviderService::switch(&state, app.app_type.clone(), &id)?;
-            if !crate::sync_policy::should_sync_live(&app.app_type) {
-                let mut message =
-                    texts::tui_toast_live_sync_skipped_uninitialized(app.app_type.as_str());
+            let outcome = ProviderService::switch(&state, app.app_type.clone(), &id)?;
+            let (mut message, mut kind) =
+                match ProviderService::verify_live(&state, app.app_type.clone(), &id) {
+                    Ok(LiveVerification::Skipped) => {
+                        let mut message =
+                            texts::tui_toast_live_sync_skipped_uninitialized(app.app_type.as_str());
+                        message.push(' ');
+                        message.push_str(texts::restart_note());
+                        (message, ToastKind::Warning)
+                    }
+                    Ok(LiveVerification::Verified) => (
+                        format!(
+                            "{} {}",
+                            texts::tui_toast_live_verified(),
+                            texts::restart_note()
+                        ),
+                        ToastKind::Success,
+                    ),
+                    Ok(LiveVerification::Mismatch(mismatches)) => {
+                        let fields = mismatches
+                            .iter()
+                            .map(|m| m.field.label())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        (
+                            texts::tui_toast_live_verify_mismatch(&fields),
+                            ToastKind::Warning,
+                        )
+                    }
+                    Err(err) => (
+                        texts::live_verify_failed(&err.to_string()),
+                        ToastKind::Warning,
+                    ),
+                };
+            if let Some(result) = &outcome.skills_sync {
+                let (note, failed) = skills_sync_note(result);
                 message.push(' ');
-                message.push_str(texts::restart_note());
-                app.push_toast(message, ToastKind::Warning);
-            } else {
-                app.push_toast(texts::restart_note(), ToastKind::Success);
+                message.push_str(&note);
+                if failed {
+                    kind = ToastKind::Warning;
+                }
             }
+            app.push_toast(message, kind);
             *data = UiData::load(&app.app_type)?;
             Ok(())
         }
@@ -1228,9 +1500,9 @@ fn handle_action(
             Ok(())
         }
         // Provider editing is handled via the in-app editor (EditorSubmit).
-        Action::ProviderSpeedtest { url } => {
+        Action::ProviderSpeedtest { label, targets } => {
             let Some(tx) = speedtest_req_tx else {
-                if matches!(&app.overlay, Overlay::SpeedtestRunning { url: running_url } if running_url == &url)
+                if matches!(&app.overlay, Overlay::SpeedtestRunning { label: running_label } if running_label == &label)
                 {
                     app.overlay = Overlay::None;
                 }
@@ -1238,8 +1510,12 @@ fn handle_action(
                 return Ok(());
             };
 
-            if let Err(err) = tx.send(url.clone()) {
-                if matches!(&app.overlay, Overlay::SpeedtestRunning { url: running_url } if running_url == &url)
+            let req = SpeedtestReq {
+                label: label.clone(),
+                targets,
+            };
+            if let Err(err) = tx.send(req) {
+                if matches!(&app.overlay, Overlay::SpeedtestRunning { label: running_label } if running_label == &label)
                 {
                     app.overlay = Overlay::None;
                 }
@@ -1251,6 +1527,24 @@ fn handle_action(
             Ok(())
         }
 
+        Action::ProviderFailoverQueue { id, queued } => {
+            let state = load_state()?;
+            ProviderService::set_failover_queued(&state, app.app_type.clone(), &id, queued)?;
+            let name = data
+                .providers
+                .rows
+                .iter()
+                .find(|row| row.id == id)
+                .map(|row| row.provider.name.clone())
+                .unwrap_or(id);
+            app.push_toast(
+                texts::tui_toast_failover_queued(&name, queued),
+                ToastKind::Success,
+            );
+            *data = UiData::load(&app.app_type)?;
+            Ok(())
+        }
+
         Action::McpToggle { id, enabled } => {
             let state = load_state()?;
             McpService::toggle_app(&state, &id, app.app_type.clone(), enabled)?;
@@ -1268,35 +1562,36 @@ fn handle_action(
             Ok(())
         }
         Action::McpSetApps { id, apps } => {
-            let Some(before) = data
-                .mcp
-                .rows
-                .iter()
-                .find(|row| row.id == id)
-                .map(|row| row.server.apps.clone())
-            else {
+            if !data.mcp.rows.iter().any(|row| row.id == id) {
                 app.push_toast(texts::tui_toast_mcp_server_not_found(), ToastKind::Warning);
                 return Ok(());
-            };
-
-            let state = load_state()?;
-            let mut skipped: Vec<&str> = Vec::new();
-            let mut changed = false;
-
-            for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
-                let next_enabled = apps.is_enabled_for(&app_type);
-                if before.is_enabled_for(&app_type) == next_enabled {
-                    continue;
-                }
-
-                changed = true;
-                McpService::toggle_app(&state, &id, app_type.clone(), next_enabled)?;
-                if !crate::sync_policy::should_sync_live(&app_type) {
-                    skipped.push(app_type.as_str());
-                }
             }
 
-            if !changed {
+            let state = load_state()?;
+            let update = McpService::set_apps(&state, &id, &apps)?;
+            let skipped: Vec<&str> = update
+                .succeeded
+                .iter()
+                .filter(|app_type| !crate::sync_policy::should_sync_live(app_type))
+                .map(|app_type| app_type.as_str())
+                .collect();
+
+            if !update.failed.is_empty() {
+                let succeeded: Vec<&str> = update
+                    .succeeded
+                    .iter()
+                    .map(|app_type| app_type.as_str())
+                    .collect();
+                let failed: Vec<String> = update
+                    .failed
+                    .iter()
+                    .map(|(app_type, err)| format!("{} ({err})", app_type.as_str()))
+                    .collect();
+                app.push_toast(
+                    texts::tui_toast_mcp_apps_partial_failure(&succeeded, &failed),
+                    ToastKind::Error,
+                );
+            } else if update.succeeded.is_empty() {
                 // Shouldn't happen because the picker avoids emitting an action when unchanged.
                 app.push_toast(texts::tui_toast_mcp_updated(), ToastKind::Success);
             } else if skipped.is_empty() {
@@ -1324,12 +1619,11 @@ fn handle_action(
         }
         Action::McpImport => {
             let state = load_state()?;
-            let count = match app.app_type {
-                AppType::Claude => McpService::import_from_claude(&state)?,
-                AppType::Codex => McpService::import_from_codex(&state)?,
-                AppType::Gemini => McpService::import_from_gemini(&state)?,
-            };
-            app.push_toast(texts::tui_toast_mcp_imported(count), ToastKind::Success);
+            let summary = McpService::import_from_app(&state, &app.app_type)?;
+            app.push_toast(
+                texts::tui_toast_mcp_imported(summary.added, summary.merged),
+                ToastKind::Success,
+            );
             *data = UiData::load(&app.app_type)?;
             Ok(())
         }
@@ -1352,10 +1646,53 @@ fn handle_action(
             }
             Ok(())
         }
+        Action::McpCheckAll => {
+            let state = load_state()?;
+            let checks = McpService::check_commands(&state, None)?;
+            if checks.is_empty() {
+                app.push_toast(texts::mcp_check_no_servers(), ToastKind::Info);
+                return Ok(());
+            }
 
-        Action::PromptActivate { id } => {
+            let missing = checks.iter().filter(|check| !check.ok).count();
+            let lines = checks
+                .iter()
+                .map(|check| {
+                    let apps = check
+                        .apps
+                        .iter()
+                        .map(|app| app.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!(
+                        "{} {}  {}  {}  [{}]",
+                        if check.ok { "✓" } else { "✗" },
+                        check.id,
+                        check.transport.as_str(),
+                        check.target,
+                        if apps.is_empty() { "-" } else { apps.as_str() }
+                    )
+                })
+                .collect();
+            app.overlay = Overlay::TextView(TextViewState {
+                title: texts::tui_mcp_check_title().to_string(),
+                lines,
+                scroll: 0,
+            });
+            if missing > 0 {
+                app.push_toast(
+                    texts::mcp_check_missing(missing, checks.len()),
+                    ToastKind::Warning,
+                );
+            } else {
+                app.push_toast(texts::mcp_check_all_found(checks.len()), ToastKind::Success);
+            }
+            Ok(())
+        }
+
+        Action::PromptActivate { id, exclusive } => {
             let state = load_state()?;
-            PromptService::enable_prompt(&state, app.app_type.clone(), &id)?;
+            PromptService::enable_prompt_with(&state, app.app_type.clone(), &id, exclusive)?;
             app.push_toast(texts::tui_toast_prompt_activated(), ToastKind::Success);
             *data = UiData::load(&app.app_type)?;
             Ok(())
@@ -1380,7 +1717,14 @@ fn handle_action(
             if let Some(parent) = target.parent() {
                 std::fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
             }
-            ConfigService::export_config_to_path(&target)?;
+            let is_archive = target.extension().is_some_and(|ext| {
+                ext.eq_ignore_ascii_case(crate::services::config_archive::ARCHIVE_EXTENSION)
+            });
+            if is_archive {
+                ConfigArchiveService::export_to_path(&target, None)?;
+            } else {
+                ConfigService::export_config_to_path(&target)?;
+            }
             app.push_toast(
                 texts::tui_toast_exported_to(&target.display().to_string()),
                 ToastKind::Success,
@@ -1390,7 +1734,10 @@ fn handle_action(
         Action::ConfigShowFull => {
             let state = load_state()?;
             let config = state.config.read().map_err(AppError::from)?;
-            let content = serde_json::to_string_pretty(&*config)
+            let mut value = serde_json::to_value(&*config)
+                .map_err(|e| AppError::Message(texts::failed_to_serialize_json(&e.to_string())))?;
+            form::redact_key_pools(&mut value);
+            let content = serde_json::to_string_pretty(&value)
                 .map_err(|e| AppError::Message(texts::failed_to_serialize_json(&e.to_string())))?;
             let title = texts::config_show_full()
                 .trim_start_matches("👁️")
@@ -1403,6 +1750,25 @@ fn handle_action(
             });
             Ok(())
         }
+        Action::ConfigEditInEditor => {
+            let state = load_state()?;
+            let initial = ConfigService::editable_config_json(&state)?;
+            let edited = terminal.with_terminal_restored(|| {
+                edit::edit(&initial).map_err(|e| AppError::Message(format!("editor failed: {e}")))
+            })?;
+            if edited.trim() == initial.trim() {
+                app.push_toast(texts::no_changes_detected(), ToastKind::Info);
+                return Ok(());
+            }
+            let outcome = ConfigService::apply_edited_config(&state, &edited)?;
+            app.push_toast(
+                texts::tui_toast_config_edited(&outcome.backup_id),
+                ToastKind::Success,
+            );
+            show_config_changes(app, &outcome.changes);
+            *data = UiData::load(&app.app_type)?;
+            Ok(())
+        }
         Action::ConfigImport { path } => {
             let source = PathBuf::from(path);
             if !source.exists() {
@@ -1411,15 +1777,21 @@ fn handle_action(
                 )));
             }
             let state = load_state()?;
-            let backup_id = ConfigService::import_config_from_path(&source, &state)?;
-            if backup_id.is_empty() {
+            // 加密归档需要口令，TUI 中传入 None 会返回明确的错误提示
+            let outcome = if ConfigArchiveService::detect(&source)?.is_some() {
+                ConfigArchiveService::import_from_path(&source, None, &state)?
+            } else {
+                ConfigService::import_config_from_path(&source, &state)?
+            };
+            if outcome.backup_id.is_empty() {
                 app.push_toast(texts::tui_toast_imported_config(), ToastKind::Success);
             } else {
                 app.push_toast(
-                    texts::tui_toast_imported_with_backup(&backup_id),
+                    texts::tui_toast_imported_with_backup(&outcome.backup_id),
                     ToastKind::Success,
                 );
             }
+            show_config_changes(app, &outcome.changes);
             *data = UiData::load(&app.app_type)?;
             Ok(())
         }
@@ -1436,18 +1808,28 @@ fn handle_action(
         }
         Action::ConfigRestoreBackup { id } => {
             let state = load_state()?;
-            let pre_backup = ConfigService::restore_from_backup_id(&id, &state)?;
-            if pre_backup.is_empty() {
+            let outcome = ConfigService::restore_from_backup_id(&id, &state)?;
+            if outcome.backup_id.is_empty() {
                 app.push_toast(texts::tui_toast_restored_from_backup(), ToastKind::Success);
             } else {
                 app.push_toast(
-                    texts::tui_toast_restored_with_pre_backup(&pre_backup),
+                    texts::tui_toast_restored_with_pre_backup(&outcome.backup_id),
                     ToastKind::Success,
                 );
             }
+            show_config_changes(app, &outcome.changes);
             *data = UiData::load(&app.app_type)?;
             Ok(())
         }
+        Action::ConfigOpenDir => {
+            let config_dir = crate::config::get_app_config_dir();
+            crate::cli::opener::open_in_file_manager(&config_dir)?;
+            app.push_toast(
+                texts::tui_toast_opened_dir(&config_dir.display().to_string()),
+                ToastKind::Info,
+            );
+            Ok(())
+        }
         Action::ConfigValidate => {
             let config_dir = crate::config::get_app_config_dir();
             let db_path = config_dir.join("cc-switch.db");
@@ -1465,20 +1847,45 @@ fn handle_action(
             let gemini_count = db.get_all_providers("gemini")?.len();
             let mcp_count = db.get_all_mcp_servers()?.len();
 
-            let lines = vec![
+            let mut lines = vec![
                 texts::tui_config_validation_ok().to_string(),
                 String::new(),
                 texts::tui_config_validation_provider_count(AppType::Claude.as_str(), claude_count),
                 texts::tui_config_validation_provider_count(AppType::Codex.as_str(), codex_count),
                 texts::tui_config_validation_provider_count(AppType::Gemini.as_str(), gemini_count),
                 texts::tui_config_validation_mcp_servers(mcp_count),
+                String::new(),
+                texts::tui_config_validation_live_header().to_string(),
             ];
+            let state = load_state()?;
+            let mut live_ok = true;
+            for app_type in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+                let check = ProviderService::check_live_files(&state, app_type)?;
+                let mark = match check.status {
+                    DiagnosticStatus::Ok => "✓",
+                    DiagnosticStatus::Warn => "⚠",
+                    DiagnosticStatus::Error => "✗",
+                };
+                live_ok &= check.status == DiagnosticStatus::Ok;
+                lines.push(format!("{mark} {}", check.check));
+                lines.extend(check.details.iter().map(|detail| format!("    {detail}")));
+                if let Some(remediation) = &check.remediation {
+                    lines.push(format!("    → {remediation}"));
+                }
+            }
             app.overlay = Overlay::TextView(TextViewState {
                 title: texts::tui_config_validation_title().to_string(),
                 lines,
                 scroll: 0,
             });
-            app.push_toast(texts::tui_toast_validation_passed(), ToastKind::Success);
+            if live_ok {
+                app.push_toast(texts::tui_toast_validation_passed(), ToastKind::Success);
+            } else {
+                app.push_toast(
+                    texts::tui_toast_live_files_need_attention(),
+                    ToastKind::Warning,
+                );
+            }
             Ok(())
         }
         Action::ConfigCommonSnippetClear { app_type } => {
@@ -1504,11 +1911,48 @@ fn handle_action(
                 );
                 return Ok(());
             }
-            ProviderService::switch(&state, app_type.clone(), &current_id)?;
+            ProviderService::resync_live(&state, app_type.clone(), &current_id)?;
             app.push_toast(texts::common_config_snippet_applied(), ToastKind::Success);
             *data = UiData::load(&app.app_type)?;
             Ok(())
         }
+        Action::ConfigCommonSnippetPreview { app_type } => {
+            let state = load_state()?;
+            let Some(preview) =
+                ProviderService::preview_saved_common_snippet(&state, app_type.clone())?
+            else {
+                app.push_toast(
+                    texts::config_common_snippet_no_current_provider(),
+                    ToastKind::Info,
+                );
+                return Ok(());
+            };
+            // 供应商关闭了通用配置时，应用片段不会改变 live 文件
+            if !preview.applies {
+                app.push_toast(
+                    texts::config_common_snippet_disabled_for_provider(),
+                    ToastKind::Warning,
+                );
+                return Ok(());
+            }
+            let lines = common_snippet_diff_lines(&preview.base, &preview.merged);
+            if lines.is_empty() {
+                app.push_toast(texts::config_common_snippet_no_effect(), ToastKind::Info);
+                return Ok(());
+            }
+            app.overlay = Overlay::CommonSnippetDiff {
+                view: TextViewState {
+                    title: texts::tui_common_snippet_diff_title(
+                        app_type.as_str(),
+                        &preview.provider_id,
+                    ),
+                    lines,
+                    scroll: 0,
+                },
+                app_type,
+            };
+            Ok(())
+        }
         Action::ConfigWebDavCheckConnection => {
             let Some(tx) = webdav_req_tx else {
                 app.push_toast(
@@ -1626,15 +2070,18 @@ fn handle_action(
             }
             Ok(())
         }
+        Action::ConfigResetApp => {
+            let state = load_state()?;
+            let outcome = ConfigService::reset_app(&state, &app.app_type)?;
+            app.push_toast(
+                texts::tui_toast_config_reset_app(app.app_type.as_str(), &outcome.backup_id),
+                ToastKind::Success,
+            );
+            *data = UiData::load(&app.app_type)?;
+            Ok(())
+        }
         Action::ConfigReset => {
-            let config_dir = crate::config::get_app_config_dir();
-            let db_path = config_dir.join("cc-switch.db");
-            let backup_id = ConfigService::create_backup(&db_path, None)?;
-
-            if db_path.exists() {
-                std::fs::remove_file(&db_path).map_err(|e| AppError::io(&db_path, e))?;
-            }
-            let _ = crate::Database::init()?;
+            let backup_id = ConfigService::reset_all()?;
             if backup_id.is_empty() {
                 app.push_toast(
                     texts::tui_toast_config_reset_to_defaults(),
@@ -1650,6 +2097,53 @@ fn handle_action(
             Ok(())
         }
 
+        Action::SetOfflineMode { enabled } => {
+            crate::settings::set_offline_mode(enabled)?;
+            app.push_toast(
+                texts::tui_toast_offline_mode_toggled(enabled),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
+        Action::SetSyncSkillsOnSwitch { enabled } => {
+            crate::settings::set_sync_skills_on_switch(enabled)?;
+            app.push_toast(
+                texts::tui_toast_sync_skills_on_switch_toggled(enabled),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
+        Action::SetQuietSkillFallback { quiet } => {
+            crate::settings::set_quiet_skill_copy_fallback(quiet)?;
+            app.push_toast(
+                texts::tui_toast_quiet_skill_fallback_toggled(quiet),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
+        Action::SetToastDuration { secs } => {
+            crate::settings::set_toast_duration_secs(secs)?;
+            app.push_toast(
+                texts::tui_toast_toast_duration_set(secs),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
+        Action::SetTickRate { ms } => {
+            crate::settings::set_tui_tick_rate_ms(ms)?;
+            app.push_toast(texts::tui_toast_tick_rate_set(ms), ToastKind::Success);
+            Ok(())
+        }
+        Action::SetToastPosition { position } => {
+            crate::settings::set_toast_position(position)?;
+            app.push_toast(
+                texts::tui_toast_toast_position_set(texts::tui_toast_position_label(
+                    position == crate::settings::ToastPosition::Top,
+                )),
+                ToastKind::Success,
+            );
+            Ok(())
+        }
         Action::SetSkipClaudeOnboarding { enabled } => {
             crate::settings::set_skip_claude_onboarding(enabled)?;
             app.push_toast(
@@ -1754,6 +2248,22 @@ fn update_webdav_last_error(last_error: Option<String>) {
     });
 }
 
+/// 逐行 diff，行首为 `+ ` / `- ` / 两个空格；无差异时为空
+fn common_snippet_diff_lines(base: &str, merged: &str) -> Vec<String> {
+    let lines = line_diff(base, merged);
+    if lines.iter().all(|line| matches!(line, DiffLine::Same(_))) {
+        return Vec::new();
+    }
+    lines
+        .into_iter()
+        .map(|line| match line {
+            DiffLine::Same(text) => format!("  {text}"),
+            DiffLine::Removed(text) => format!("- {text}"),
+            DiffLine::Added(text) => format!("+ {text}"),
+        })
+        .collect()
+}
+
 fn refresh_common_snippet_overlay(app: &mut App, data: &UiData) {
     let Overlay::CommonSnippetView { app_type, view } = &mut app.overlay else {
         return;
@@ -1993,7 +2503,7 @@ fn webdav_worker_loop(rx: mpsc::Receiver<WebDavReq>, tx: mpsc::Sender<WebDavMsg>
 
 fn start_speedtest_system() -> Result<SpeedtestSystem, AppError> {
     let (result_tx, result_rx) = mpsc::channel::<SpeedtestMsg>();
-    let (req_tx, req_rx) = mpsc::channel::<String>();
+    let (req_tx, req_rx) = mpsc::channel::<SpeedtestReq>();
 
     let handle = std::thread::Builder::new()
         .name("cc-switch-speedtest".to_string())
@@ -2010,7 +2520,7 @@ fn start_speedtest_system() -> Result<SpeedtestSystem, AppError> {
     })
 }
 
-fn speedtest_worker_loop(rx: mpsc::Receiver<String>, tx: mpsc::Sender<SpeedtestMsg>) {
+fn speedtest_worker_loop(rx: mpsc::Receiver<SpeedtestReq>, tx: mpsc::Sender<SpeedtestMsg>) {
     let rt = match tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
@@ -2018,9 +2528,9 @@ fn speedtest_worker_loop(rx: mpsc::Receiver<String>, tx: mpsc::Sender<SpeedtestM
         Ok(rt) => rt,
         Err(e) => {
             let err = e.to_string();
-            while let Ok(url) = rx.recv() {
+            while let Ok(req) = rx.recv() {
                 let _ = tx.send(SpeedtestMsg::Finished {
-                    url,
+                    label: req.label,
                     result: Err(err.clone()),
                 });
             }
@@ -2028,21 +2538,51 @@ fn speedtest_worker_loop(rx: mpsc::Receiver<String>, tx: mpsc::Sender<SpeedtestM
         }
     };
 
-    while let Ok(mut url) = rx.recv() {
+    while let Ok(mut req) = rx.recv() {
         for next in rx.try_iter() {
-            url = next;
+            req = next;
         }
 
+        let targets = req
+            .targets
+            .iter()
+            .map(|t| (t.url.clone(), t.headers.clone()))
+            .collect();
         let result = rt
             .block_on(async {
-                crate::services::SpeedtestService::test_endpoints(vec![url.clone()], None).await
+                crate::services::SpeedtestService::test_endpoints_with_headers(
+                    targets,
+                    crate::services::SpeedtestOptions::default(),
+                )
+                .await
             })
+            .map(|latencies| speedtest_result_rows(req.targets, latencies))
             .map_err(|e| e.to_string());
 
-        let _ = tx.send(SpeedtestMsg::Finished { url, result });
+        let _ = tx.send(SpeedtestMsg::Finished {
+            label: req.label,
+            result,
+        });
     }
 }
 
+/// 测速结果与请求目标按顺序一一对应，合并出带供应商信息的结果行
+fn speedtest_result_rows(
+    targets: Vec<SpeedtestTarget>,
+    latencies: Vec<EndpointLatency>,
+) -> Vec<SpeedtestResultRow> {
+    targets
+        .into_iter()
+        .zip(latencies)
+        .map(|(target, latency)| SpeedtestResultRow {
+            target,
+            latency: latency.latency,
+            status: latency.status,
+            error: latency.error,
+        })
+        .collect()
+}
+
 fn start_local_env_system() -> Result<LocalEnvSystem, AppError> {
     let (result_tx, result_rx) = mpsc::channel::<LocalEnvMsg>();
     let (req_tx, req_rx) = mpsc::channel::<LocalEnvReq>();
@@ -2106,8 +2646,9 @@ fn skills_worker_loop(rx: mpsc::Receiver<SkillsReq>, tx: mpsc::Sender<SkillsMsg>
             let err = e.to_string();
             while let Ok(req) = rx.recv() {
                 match req {
-                    SkillsReq::Discover { query } => {
+                    SkillsReq::Discover { request_id, query } => {
                         let _ = tx.send(SkillsMsg::DiscoverFinished {
+                            request_id,
                             query,
                             result: Err(err.clone()),
                         });
@@ -2130,8 +2671,9 @@ fn skills_worker_loop(rx: mpsc::Receiver<SkillsReq>, tx: mpsc::Sender<SkillsMsg>
             let err = e.to_string();
             while let Ok(req) = rx.recv() {
                 match req {
-                    SkillsReq::Discover { query } => {
+                    SkillsReq::Discover { request_id, query } => {
                         let _ = tx.send(SkillsMsg::DiscoverFinished {
+                            request_id,
                             query,
                             result: Err(err.clone()),
                         });
@@ -2150,10 +2692,16 @@ fn skills_worker_loop(rx: mpsc::Receiver<SkillsReq>, tx: mpsc::Sender<SkillsMsg>
 
     while let Ok(req) = rx.recv() {
         match req {
-            SkillsReq::Discover { query } => {
+            SkillsReq::Discover { request_id, query } => {
                 let query_trimmed = query.trim().to_lowercase();
+                let on_progress = |progress| {
+                    let _ = tx.send(SkillsMsg::DiscoverProgress {
+                        request_id,
+                        progress,
+                    });
+                };
                 let result = rt
-                    .block_on(async { service.list_skills().await })
+                    .block_on(async { service.list_skills_with_progress(&on_progress).await })
                     .map_err(|e| e.to_string())
                     .map(|mut skills| {
                         if !query_trimmed.is_empty() {
@@ -2167,7 +2715,11 @@ fn skills_worker_loop(rx: mpsc::Receiver<SkillsReq>, tx: mpsc::Sender<SkillsMsg>
                         skills
                     });
 
-                let _ = tx.send(SkillsMsg::DiscoverFinished { query, result });
+                let _ = tx.send(SkillsMsg::DiscoverFinished {
+                    request_id,
+                    query,
+                    result,
+                });
             }
             SkillsReq::Install { spec, app } => {
                 let spec_clone = spec.clone();
@@ -2189,7 +2741,7 @@ fn parse_repo_spec(raw: &str) -> Result<SkillRepo, AppError> {
         ));
     }
 
-    // Allow: https://github.com/owner/name or owner/name[@branch]
+    // Allow: https://github.com/owner/name or owner/name[@branch[,fallback...]]
     let without_prefix = raw
         .strip_prefix("https://github.com/")
         .or_else(|| raw.strip_prefix("http://github.com/"))
@@ -2209,11 +2761,13 @@ fn parse_repo_spec(raw: &str) -> Result<SkillRepo, AppError> {
         ));
     };
 
+    let (branch, fallback_branches) = SkillRepo::parse_branch_list(branch);
     Ok(SkillRepo {
         owner: owner.to_string(),
         name: name.to_string(),
-        branch: branch.unwrap_or("main").to_string(),
+        branch,
         enabled: true,
+        fallback_branches,
     })
 }
 
@@ -2237,6 +2791,21 @@ mod tests {
         assert_eq!(super::command_lookup_name("   "), None);
     }
 
+    #[test]
+    fn common_snippet_diff_lines_prefix_changes_and_skip_identical_configs() {
+        assert_eq!(
+            super::common_snippet_diff_lines("{\n  \"a\": 1\n}", "{\n  \"a\": 1,\n  \"b\": 2\n}"),
+            vec![
+                "  {",
+                "-   \"a\": 1",
+                "+   \"a\": 1,",
+                "+   \"b\": 2",
+                "  }"
+            ]
+        );
+        assert!(super::common_snippet_diff_lines("same", "same").is_empty());
+    }
+
     #[test]
     fn quick_setup_helper_saves_preset_and_runs_connection_check() {
         let mut captured = None;
@@ -2482,4 +3051,56 @@ mod tests {
         assert_eq!(update_check.active, None);
         assert!(matches!(app.overlay, Overlay::UpdateAvailable { .. }));
     }
+
+    #[test]
+    fn skills_discover_progress_updates_overlay_and_cancelled_result_is_dropped() {
+        let mut app = App::new(None);
+        let mut data = super::UiData::default();
+        let mut skills_discover = super::RequestTracker::default();
+        let request_id = skills_discover.start();
+        app.overlay = Overlay::Loading {
+            kind: LoadingKind::SkillsDiscover,
+            title: texts::tui_skills_discover_title().to_string(),
+            message: texts::tui_loading().to_string(),
+        };
+
+        super::handle_skills_msg(
+            &mut app,
+            &mut data,
+            &mut skills_discover,
+            super::SkillsMsg::DiscoverProgress {
+                request_id,
+                progress: crate::services::DiscoverProgress {
+                    done: 1,
+                    total: 3,
+                    pending: vec!["acme/slow".to_string(), "acme/hang".to_string()],
+                },
+            },
+        )
+        .expect("progress");
+        match &app.overlay {
+            Overlay::Loading { message, .. } => {
+                assert!(message.contains("1/3"));
+                assert!(message.contains("acme/slow, acme/hang"));
+            }
+            other => panic!("expected loading overlay, got {other:?}"),
+        }
+
+        // Esc closes the overlay and cancels the request.
+        app.overlay = Overlay::None;
+        skills_discover.cancel();
+        super::handle_skills_msg(
+            &mut app,
+            &mut data,
+            &mut skills_discover,
+            super::SkillsMsg::DiscoverFinished {
+                request_id,
+                query: String::new(),
+                result: Ok(Vec::new()),
+            },
+        )
+        .expect("finished");
+        assert!(matches!(app.overlay, Overlay::None));
+        assert!(app.toast.is_none(), "cancelled result should be dropped");
+    }
 }
@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Route {
     Main,
     Providers,
@@ -27,6 +27,54 @@ pub enum NavItem {
     Exit,
 }
 
+impl Route {
+    /// 持久化用的页面标识；详情页和依赖临时数据的页面记为其所属列表页
+    pub fn session_key(&self) -> &'static str {
+        match self {
+            Route::Main => "main",
+            Route::Providers | Route::ProviderDetail { .. } => "providers",
+            Route::Mcp => "mcp",
+            Route::Prompts => "prompts",
+            Route::Config => "config",
+            Route::ConfigWebDav => "config-webdav",
+            Route::Skills
+            | Route::SkillsDiscover
+            | Route::SkillsUnmanaged
+            | Route::SkillDetail { .. } => "skills",
+            Route::SkillsRepos => "skills-repos",
+            Route::Settings => "settings",
+        }
+    }
+
+    pub fn from_session_key(key: &str) -> Option<Route> {
+        Some(match key {
+            "main" => Route::Main,
+            "providers" => Route::Providers,
+            "mcp" => Route::Mcp,
+            "prompts" => Route::Prompts,
+            "config" => Route::Config,
+            "config-webdav" => Route::ConfigWebDav,
+            "skills" => Route::Skills,
+            "skills-repos" => Route::SkillsRepos,
+            "settings" => Route::Settings,
+            _ => return None,
+        })
+    }
+
+    /// 子页面的上级页面（恢复时压入返回栈，使 Esc 行为与手动进入一致）
+    pub fn parent(&self) -> Option<Route> {
+        match self {
+            Route::ProviderDetail { .. } => Some(Route::Providers),
+            Route::ConfigWebDav => Some(Route::Config),
+            Route::SkillsDiscover
+            | Route::SkillsRepos
+            | Route::SkillsUnmanaged
+            | Route::SkillDetail { .. } => Some(Route::Skills),
+            _ => None,
+        }
+    }
+}
+
 impl NavItem {
     pub const ALL: [NavItem; 8] = [
         NavItem::Main,
@@ -3,11 +3,11 @@ use std::sync::Arc;
 
 use crossterm::{
     cursor,
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::prelude::Size;
+use ratatui::prelude::{Rect, Size};
 use ratatui::{backend::CrosstermBackend, Terminal};
 
 use crate::error::AppError;
@@ -69,7 +69,8 @@ fn restore_stdout_best_effort(stdout: &mut Stdout) -> Result<(), AppError> {
         stdout,
         cursor::Show,
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     ) {
         record_err(&mut first_err, e);
     }
@@ -95,6 +96,7 @@ impl TuiTerminal {
             stdout,
             EnterAlternateScreen,
             EnableMouseCapture,
+            EnableBracketedPaste,
             cursor::Hide
         ) {
             let _ = restore_stdout_best_effort(&mut stdout);
@@ -148,6 +150,19 @@ impl TuiTerminal {
         })
     }
 
+    /// 按新尺寸重建缓冲区并清屏，保证下一帧完整重绘而不是沿用旧布局的差量
+    pub fn resize(&mut self, size: Size) -> Result<(), AppError> {
+        self.terminal
+            .resize(Rect::new(0, 0, size.width, size.height))
+            .map_err(|e| {
+                AppError::localized(
+                    "tui_terminal_error",
+                    format!("终端错误: {e}"),
+                    format!("Terminal error: {e}"),
+                )
+            })
+    }
+
     pub fn with_terminal_restored<T>(
         &mut self,
         f: impl FnOnce() -> Result<T, AppError>,
@@ -198,7 +213,8 @@ impl TuiTerminal {
             self.terminal.backend_mut(),
             cursor::Show,
             LeaveAlternateScreen,
-            DisableMouseCapture
+            DisableMouseCapture,
+            DisableBracketedPaste
         ) {
             record_err(&mut first_err, e);
         }
@@ -227,6 +243,7 @@ impl TuiTerminal {
             self.terminal.backend_mut(),
             EnterAlternateScreen,
             EnableMouseCapture,
+            EnableBracketedPaste,
             cursor::Hide
         ) {
             record_err(&mut first_err, e);
@@ -5,7 +5,7 @@ use ratatui::{
     text::{Line, Span},
     widgets::{
         Block, BorderType, Borders, Cell, Clear, Gauge, List, ListItem, ListState, Paragraph, Row,
-        Table, TableState, Wrap,
+        Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
     },
     Frame,
 };
@@ -13,10 +13,13 @@ use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::app_config::AppType;
 use crate::cli::i18n::texts;
+use crate::settings::ToastPosition;
 use serde_json::Value;
 
 use super::{
-    app::{App, ConfigItem, ConfirmAction, Focus, LoadingKind, Overlay, ToastKind, WebDavConfigItem},
+    app::{
+        App, ConfigItem, ConfirmAction, Focus, LoadingKind, Overlay, ToastKind, WebDavConfigItem,
+    },
     data::{McpRow, ProviderRow, UiData},
     form::{
         CodexPreviewSection, FormFocus, FormState, GeminiAuthType, McpAddField, ProviderAddField,
@@ -240,6 +243,77 @@ fn inset_left(area: Rect, left: u16) -> Rect {
     }
 }
 
+/// 表格视口窗口：只构建选中项附近可见的行
+struct ListWindow {
+    offset: usize,
+    height: usize,
+    len: usize,
+}
+
+impl ListWindow {
+    /// 表格区域扣除表头后的行数即视口高度
+    fn for_table(app: &App, route: Route, len: usize, selected: usize, area: Rect) -> Self {
+        let height = area.height.saturating_sub(1) as usize;
+        let offset = app.list_scroll.window(&route, len, selected, height);
+        Self {
+            offset,
+            height,
+            len,
+        }
+    }
+
+    fn range(&self) -> std::ops::Range<usize> {
+        self.offset..(self.offset + self.height).min(self.len)
+    }
+
+    fn relative(&self, selected: usize) -> usize {
+        selected
+            .min(self.len.saturating_sub(1))
+            .saturating_sub(self.offset)
+    }
+
+    fn scrollable(&self) -> bool {
+        self.len > self.height
+    }
+
+    /// 需要滚动条时为其预留最右侧一列
+    fn table_area(&self, area: Rect) -> Rect {
+        if self.scrollable() && area.width > 1 {
+            Rect {
+                width: area.width - 1,
+                ..area
+            }
+        } else {
+            area
+        }
+    }
+}
+
+/// 列表超出视口时在右侧绘制滚动指示条
+fn render_scroll_indicator(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    window: &ListWindow,
+    theme: &super::theme::Theme,
+) {
+    if !window.scrollable() || area.height <= 1 {
+        return;
+    }
+    let track = Rect {
+        y: area.y + 1,
+        height: area.height - 1,
+        ..area
+    };
+    let mut state = ScrollbarState::new(window.len.saturating_sub(window.height))
+        .position(window.offset)
+        .viewport_content_length(window.height);
+    let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None)
+        .style(Style::default().fg(theme.dim));
+    frame.render_stateful_widget(scrollbar, track, &mut state);
+}
+
 pub fn render(frame: &mut Frame<'_>, app: &App, data: &UiData) {
     let theme = theme_for(&app.app_type);
 
@@ -270,6 +344,7 @@ pub fn render(frame: &mut Frame<'_>, app: &App, data: &UiData) {
     render_nav(frame, app, body[0], &theme);
     render_content(frame, app, data, body[1], &theme);
     render_footer(frame, app, root[2], &theme);
+    render_top_toast(frame, app, root[1], &theme);
 
     render_overlay(frame, app, data, &theme);
 }
@@ -565,6 +640,7 @@ fn render_skills_installed(
             &[
                 ("Enter", texts::tui_key_details()),
                 ("x", texts::tui_key_toggle()),
+                ("X", texts::tui_key_toggle_all_apps()),
                 ("i", texts::tui_skills_action_import_existing()),
             ],
         );
@@ -655,7 +731,16 @@ fn render_skills_installed(
     ])
     .style(header_style);
 
-    let rows = visible.iter().map(|skill| {
+    let table_area = inset_left(chunks[2], CONTENT_INSET_LEFT);
+    let window = ListWindow::for_table(
+        app,
+        Route::Skills,
+        visible.len(),
+        app.skills_idx,
+        table_area,
+    );
+
+    let rows = visible[window.range()].iter().map(|skill| {
         Row::new(vec![
             Cell::from(skill.directory.clone()),
             Cell::from(skill.name.clone()),
@@ -681,8 +766,9 @@ fn render_skills_installed(
     .highlight_symbol(highlight_symbol(theme));
 
     let mut state = TableState::default();
-    state.select(Some(app.skills_idx));
-    frame.render_stateful_widget(table, inset_left(chunks[2], CONTENT_INSET_LEFT), &mut state);
+    state.select(Some(window.relative(app.skills_idx)));
+    frame.render_stateful_widget(table, window.table_area(table_area), &mut state);
+    render_scroll_indicator(frame, table_area, &window, theme);
 }
 
 fn render_skills_discover(
@@ -715,7 +801,7 @@ fn render_skills_discover(
         .constraints([Constraint::Length(2), Constraint::Min(0)])
         .split(inner);
 
-    if app.focus == Focus::Content {
+    if app.focus == Focus::Content && !crate::settings::is_offline_mode() {
         render_key_bar_center(
             frame,
             chunks[0],
@@ -761,7 +847,16 @@ fn render_skills_discover(
     ])
     .style(header_style);
 
-    let rows = visible.iter().map(|skill| {
+    let table_area = inset_left(chunks[1], CONTENT_INSET_LEFT);
+    let window = ListWindow::for_table(
+        app,
+        Route::SkillsDiscover,
+        visible.len(),
+        app.skills_discover_idx,
+        table_area,
+    );
+
+    let rows = visible[window.range()].iter().map(|skill| {
         let repo = match (&skill.repo_owner, &skill.repo_name) {
             (Some(owner), Some(name)) => format!("{owner}/{name}"),
             _ => "-".to_string(),
@@ -789,8 +884,9 @@ fn render_skills_discover(
     .highlight_symbol(highlight_symbol(theme));
 
     let mut state = TableState::default();
-    state.select(Some(app.skills_discover_idx));
-    frame.render_stateful_widget(table, inset_left(chunks[1], CONTENT_INSET_LEFT), &mut state);
+    state.select(Some(window.relative(app.skills_discover_idx)));
+    frame.render_stateful_widget(table, window.table_area(table_area), &mut state);
+    render_scroll_indicator(frame, table_area, &window, theme);
 }
 
 fn render_skills_repos(
@@ -929,6 +1025,7 @@ fn render_skills_unmanaged(
             &[
                 ("Space", texts::tui_key_select()),
                 ("i", texts::tui_key_import()),
+                ("u", texts::tui_key_undo_import()),
                 ("r", texts::tui_key_refresh()),
             ],
         );
@@ -1064,6 +1161,7 @@ fn render_skill_detail(
             theme,
             &[
                 ("x", texts::tui_key_toggle()),
+                ("X", texts::tui_key_toggle_all_apps()),
                 ("d", texts::tui_key_uninstall()),
                 ("s", texts::tui_key_sync()),
             ],
@@ -1167,17 +1265,42 @@ fn render_editor(
 
     let field_title = match editor.kind {
         super::app::EditorKind::Json => texts::tui_editor_json_field_title(),
+        super::app::EditorKind::Toml => texts::tui_editor_toml_field_title(),
         super::app::EditorKind::Plain => texts::tui_editor_text_field_title(),
     };
     let field_border_style = Style::default()
         .fg(theme.accent)
         .add_modifier(Modifier::BOLD);
 
+    let mut title_spans = vec![Span::raw(format!("-{}", field_title))];
+    match &editor.validation {
+        Some(super::app::EditorValidation::Valid) => {
+            title_spans.push(Span::styled(
+                format!(" ✓ {} ", texts::tui_editor_validation_ok()),
+                Style::default().fg(theme.ok),
+            ));
+        }
+        Some(super::app::EditorValidation::Invalid {
+            line,
+            column,
+            message,
+        }) => {
+            title_spans.push(Span::styled(
+                format!(
+                    " ✗ {} ",
+                    texts::tui_editor_validation_error(*line, *column, message)
+                ),
+                Style::default().fg(theme.err),
+            ));
+        }
+        None => {}
+    }
+
     let field = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
         .border_style(field_border_style)
-        .title(format!("-{}", field_title));
+        .title(Line::from(title_spans));
 
     frame.render_widget(field.clone(), chunks[1]);
     let field_inner = field.inner(chunks[1]);
@@ -1454,16 +1577,20 @@ fn render_provider_add_form(
         ])
         .split(inner);
 
-    render_key_bar(
-        frame,
-        chunks[0],
-        theme,
-        &add_form_key_items(
-            provider.focus,
-            provider.editing,
-            matches!(provider.app_type, AppType::Codex),
-        ),
+    let mut key_items = add_form_key_items(
+        provider.focus,
+        provider.editing,
+        matches!(provider.app_type, AppType::Codex),
     );
+    let secret_selected = matches!(provider.focus, FormFocus::Fields)
+        && provider
+            .fields()
+            .get(provider.field_idx)
+            .is_some_and(|field| field.is_secret());
+    if secret_selected {
+        key_items.push(("Ctrl+R", texts::tui_key_reveal()));
+    }
+    render_key_bar(frame, chunks[0], theme, &key_items);
 
     if matches!(provider.mode, super::form::FormMode::Add) {
         let labels = provider.template_labels();
@@ -1545,7 +1672,20 @@ fn render_provider_add_form(
                 ])
                 .style(Style::default().fg(theme.dim))
             } else {
-                Row::new(vec![Cell::from(pad1(label)), Cell::from(value.clone())])
+                let expiry_invalid =
+                    matches!(field, ProviderAddField::ExpiresAt) && provider.expires_at_invalid();
+                let value = match provider_field_placeholder(provider, *field) {
+                    Some(hint) => Cell::from(Span::styled(hint, Style::default().fg(theme.dim))),
+                    None if expiry_invalid => Cell::from(Line::from(vec![
+                        Span::raw(value.clone()),
+                        Span::styled(
+                            format!("  ✗ {}", texts::tui_provider_invalid_expiry()),
+                            Style::default().fg(theme.err),
+                        ),
+                    ])),
+                    None => Cell::from(value.clone()),
+                };
+                Row::new(vec![Cell::from(pad1(label)), value])
             }
         });
 
@@ -1598,11 +1738,15 @@ fn render_provider_add_form(
         .get(provider.field_idx.min(fields.len().saturating_sub(1)))
         .copied();
     if let Some(field) = selected {
-        if let Some(input) = provider.input(field) {
+        if let (Some(input), Some(shown)) = (provider.input(field), provider.display_value(field)) {
             let (visible, cursor_x) =
-                visible_text_window(&input.value, input.cursor, editor_inner.width as usize);
+                visible_text_window(&shown, input.cursor, editor_inner.width as usize);
+            let line = match provider_field_placeholder(provider, field) {
+                Some(hint) => Line::styled(hint, Style::default().fg(theme.dim)),
+                None => Line::raw(visible),
+            };
             frame.render_widget(
-                Paragraph::new(Line::raw(visible)).wrap(Wrap { trim: false }),
+                Paragraph::new(line).wrap(Wrap { trim: false }),
                 editor_inner,
             );
 
@@ -1712,6 +1856,7 @@ fn provider_field_label_and_value(
             strip_trailing_colon(texts::website_url_label()).to_string()
         }
         ProviderAddField::Notes => strip_trailing_colon(texts::notes_label()).to_string(),
+        ProviderAddField::ExpiresAt => strip_trailing_colon(texts::expires_at_label()).to_string(),
         ProviderAddField::ClaudeBaseUrl => texts::tui_label_base_url().to_string(),
         ProviderAddField::ClaudeApiKey => texts::tui_label_api_key().to_string(),
         ProviderAddField::ClaudeModelConfig => texts::tui_label_claude_model_config().to_string(),
@@ -1733,6 +1878,7 @@ fn provider_field_label_and_value(
         ProviderAddField::GeminiApiKey => texts::tui_label_api_key().to_string(),
         ProviderAddField::GeminiBaseUrl => texts::tui_label_base_url().to_string(),
         ProviderAddField::GeminiModel => texts::model_label().to_string(),
+        ProviderAddField::Headers => strip_trailing_colon(texts::headers_label()).to_string(),
         ProviderAddField::CommonConfigDivider => "- - - - - - - - -".to_string(),
         ProviderAddField::CommonSnippet => texts::tui_config_item_common_snippet().to_string(),
         ProviderAddField::IncludeCommonConfig => texts::tui_form_attach_common_config().to_string(),
@@ -1763,10 +1909,13 @@ fn provider_field_label_and_value(
         },
         ProviderAddField::CommonConfigDivider => "- - - - - - - - - -".to_string(),
         ProviderAddField::CommonSnippet => texts::tui_key_open().to_string(),
-        _ => provider
-            .input(field)
-            .map(|v| v.value.trim().to_string())
-            .unwrap_or_default(),
+        _ => match provider.input(field) {
+            Some(input) if field.is_secret() && !provider.is_secret_revealed(field) => {
+                "•".repeat(input.value.trim().chars().count())
+            }
+            Some(input) => input.value.trim().to_string(),
+            None => String::new(),
+        },
     };
 
     (
@@ -1779,6 +1928,32 @@ fn provider_field_label_and_value(
     )
 }
 
+/// 字段为空时显示的格式提示（与 CLI 交互式输入的占位符一致）
+fn provider_field_placeholder(
+    provider: &super::form::ProviderAddFormState,
+    field: ProviderAddField,
+) -> Option<&'static str> {
+    if !provider.input(field)?.value.trim().is_empty() {
+        return None;
+    }
+    match field {
+        ProviderAddField::Name => Some(texts::provider_name_placeholder()),
+        ProviderAddField::WebsiteUrl => Some(texts::website_url_placeholder()),
+        ProviderAddField::Notes => Some(texts::notes_example_placeholder()),
+        ProviderAddField::ExpiresAt => Some(texts::expires_at_placeholder()),
+        ProviderAddField::ClaudeBaseUrl => Some(texts::base_url_placeholder()),
+        ProviderAddField::ClaudeApiKey => Some(texts::claude_api_key_placeholder()),
+        ProviderAddField::CodexBaseUrl => Some(texts::codex_base_url_placeholder()),
+        ProviderAddField::CodexModel => Some(texts::codex_model_placeholder()),
+        ProviderAddField::CodexApiKey => Some(texts::codex_api_key_placeholder()),
+        ProviderAddField::GeminiApiKey => Some(texts::gemini_api_key_placeholder()),
+        ProviderAddField::GeminiBaseUrl => Some(texts::gemini_base_url_placeholder()),
+        ProviderAddField::GeminiModel => Some(texts::gemini_model_placeholder()),
+        ProviderAddField::Headers => Some(texts::headers_placeholder()),
+        _ => None,
+    }
+}
+
 fn provider_field_editor_line(
     provider: &super::form::ProviderAddFormState,
     selected: Option<ProviderAddField>,
@@ -1788,17 +1963,7 @@ fn provider_field_editor_line(
         return (Line::raw(""), 0);
     };
 
-    if let Some(input) = provider.input(field) {
-        let shown = if matches!(
-            field,
-            ProviderAddField::ClaudeApiKey
-                | ProviderAddField::CodexApiKey
-                | ProviderAddField::GeminiApiKey
-        ) {
-            input.value.clone()
-        } else {
-            input.value.clone()
-        };
+    if let (Some(input), Some(shown)) = (provider.input(field), provider.display_value(field)) {
         (Line::raw(shown), input.cursor)
     } else {
         let text = match field {
@@ -1998,35 +2163,27 @@ fn mcp_field_label_and_value(
     let label = match field {
         McpAddField::Id => texts::tui_label_id().to_string(),
         McpAddField::Name => texts::header_name().to_string(),
+        McpAddField::Transport => texts::tui_label_transport().to_string(),
         McpAddField::Command => texts::tui_label_command().to_string(),
         McpAddField::Args => texts::tui_label_args().to_string(),
-        McpAddField::AppClaude => texts::tui_label_app_claude().to_string(),
-        McpAddField::AppCodex => texts::tui_label_app_codex().to_string(),
-        McpAddField::AppGemini => texts::tui_label_app_gemini().to_string(),
+        McpAddField::Url => texts::tui_label_url().to_string(),
+        McpAddField::Apps => texts::tui_label_apps().to_string(),
     };
 
     let value = match field {
-        McpAddField::AppClaude => {
-            if mcp.apps.claude {
-                format!("[{}]", texts::tui_marker_active())
-            } else {
-                "[ ]".to_string()
-            }
-        }
-        McpAddField::AppCodex => {
-            if mcp.apps.codex {
-                format!("[{}]", texts::tui_marker_active())
-            } else {
-                "[ ]".to_string()
-            }
-        }
-        McpAddField::AppGemini => {
-            if mcp.apps.gemini {
-                format!("[{}]", texts::tui_marker_active())
-            } else {
-                "[ ]".to_string()
-            }
-        }
+        McpAddField::Transport => mcp.transport.as_str().to_string(),
+        McpAddField::Apps => super::form::MCP_FORM_APPS
+            .iter()
+            .map(|app| {
+                let mark = if mcp.apps.is_enabled_for(app) {
+                    texts::tui_marker_active()
+                } else {
+                    " "
+                };
+                format!("[{mark}] {}", app.as_str())
+            })
+            .collect::<Vec<_>>()
+            .join("  "),
         _ => mcp
             .input(field)
             .map(|v| v.value.trim().to_string())
@@ -2053,9 +2210,16 @@ fn mcp_field_editor_line(
     };
 
     let text = match field {
-        McpAddField::AppClaude => format!("claude = {}", mcp.apps.claude),
-        McpAddField::AppCodex => format!("codex = {}", mcp.apps.codex),
-        McpAddField::AppGemini => format!("gemini = {}", mcp.apps.gemini),
+        McpAddField::Transport => format!("type = {}", mcp.transport.as_str()),
+        McpAddField::Apps => {
+            let app = mcp.cursor_app();
+            format!(
+                "> {} = {}    {}",
+                app.as_str(),
+                mcp.apps.is_enabled_for(app),
+                texts::tui_mcp_apps_hint()
+            )
+        }
         _ => String::new(),
     };
 
@@ -2576,19 +2740,20 @@ fn render_providers(
         .split(inner);
 
     if app.focus == Focus::Content {
-        render_key_bar_center(
-            frame,
-            chunks[0],
-            theme,
-            &[
-                ("Enter", texts::tui_key_details()),
-                ("s", texts::tui_key_switch()),
-                ("a", texts::tui_key_add()),
-                ("e", texts::tui_key_edit()),
-                ("d", texts::tui_key_delete()),
-                ("t", texts::tui_key_speedtest()),
-            ],
-        );
+        let mut keys = vec![
+            ("Enter", texts::tui_key_details()),
+            ("s", texts::tui_key_switch()),
+            ("a", texts::tui_key_add()),
+            ("e", texts::tui_key_edit()),
+            ("d", texts::tui_key_delete()),
+            ("f", texts::tui_key_failover_queue()),
+        ];
+        if !crate::settings::is_offline_mode() {
+            keys.push(("t", texts::tui_key_speedtest()));
+            keys.push(("T", texts::tui_key_speedtest_all()));
+            keys.push(("F", texts::tui_key_failover()));
+        }
+        render_key_bar_center(frame, chunks[0], theme, &keys);
     }
 
     let visible = provider_rows_filtered(app, data);
@@ -2600,18 +2765,33 @@ fn render_providers(
     ])
     .style(header_style);
 
-    let rows = visible.iter().map(|row| {
+    let table_area = inset_left(chunks[1], CONTENT_INSET_LEFT);
+    let window = ListWindow::for_table(
+        app,
+        Route::Providers,
+        visible.len(),
+        app.provider_idx,
+        table_area,
+    );
+
+    let today = chrono::Local::now().date_naive();
+    let expiry_window = crate::services::expiry_warning_days();
+    let rows = visible[window.range()].iter().map(|row| {
         let marker = if row.is_current {
             texts::tui_marker_active()
         } else {
             texts::tui_marker_inactive()
         };
         let api = row.api_url.as_deref().unwrap_or(texts::tui_na());
-        Row::new(vec![
-            Cell::from(marker),
-            Cell::from(row.provider.name.clone()),
-            Cell::from(api),
-        ])
+        let mut name = if row.provider.in_failover_queue {
+            format!("{}{}", row.provider.name, texts::tui_failover_badge())
+        } else {
+            row.provider.name.clone()
+        };
+        if crate::services::expiry_status(&row.provider, today, expiry_window).is_some() {
+            name.push_str(texts::tui_expiry_badge());
+        }
+        Row::new(vec![Cell::from(marker), Cell::from(name), Cell::from(api)])
     });
 
     let table = Table::new(
@@ -2629,9 +2809,10 @@ fn render_providers(
     .highlight_symbol(highlight_symbol(theme));
 
     let mut state = TableState::default();
-    state.select(Some(app.provider_idx));
+    state.select(Some(window.relative(app.provider_idx)));
 
-    frame.render_stateful_widget(table, inset_left(chunks[1], CONTENT_INSET_LEFT), &mut state);
+    frame.render_stateful_widget(table, window.table_area(table_area), &mut state);
+    render_scroll_indicator(frame, table_area, &window, theme);
 }
 
 fn render_provider_detail(
@@ -2670,16 +2851,12 @@ fn render_provider_detail(
         .split(inner);
 
     if app.focus == Focus::Content {
-        render_key_bar_center(
-            frame,
-            chunks[0],
-            theme,
-            &[
-                ("s", texts::tui_key_switch()),
-                ("e", texts::tui_key_edit()),
-                ("t", texts::tui_key_speedtest()),
-            ],
-        );
+        let mut keys = vec![("s", texts::tui_key_switch()), ("e", texts::tui_key_edit())];
+        if !crate::settings::is_offline_mode() {
+            keys.push(("t", texts::tui_key_speedtest()));
+        }
+        keys.push(("c", texts::tui_key_share()));
+        render_key_bar_center(frame, chunks[0], theme, &keys);
     }
 
     let mut lines = vec![
@@ -2707,6 +2884,46 @@ fn render_provider_detail(
         ]));
     }
 
+    if let Some(expires_at) = crate::services::provider_expiry_date(&row.provider) {
+        let today = chrono::Local::now().date_naive();
+        let mut spans = vec![
+            Span::styled(
+                strip_trailing_colon(texts::expires_at_label()),
+                Style::default().fg(theme.accent),
+            ),
+            Span::raw(": "),
+            Span::raw(expires_at.format("%Y-%m-%d").to_string()),
+        ];
+        if let Some(status) = crate::services::expiry_status(
+            &row.provider,
+            today,
+            crate::services::expiry_warning_days(),
+        ) {
+            spans.push(Span::styled(
+                format!(" ({})", texts::provider_expiry_status(status)),
+                Style::default().fg(theme.warn),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if let Some(meta) = row
+        .provider
+        .meta
+        .as_ref()
+        .filter(|meta| !meta.api_keys.is_empty())
+    {
+        let next = meta.api_key_index.unwrap_or(0) % meta.api_keys.len() + 1;
+        lines.push(Line::from(vec![
+            Span::styled(
+                texts::tui_label_key_pool(),
+                Style::default().fg(theme.accent),
+            ),
+            Span::raw(": "),
+            Span::raw(texts::tui_key_pool_summary(meta.api_keys.len(), next)),
+        ]));
+    }
+
     if matches!(app.app_type, crate::app_config::AppType::Claude) {
         if let Some(env) = row
             .provider
@@ -2745,6 +2962,21 @@ fn render_provider_detail(
         }
     }
 
+    if let Some(meta) = row.provider.meta.as_ref().filter(|m| !m.fields.is_empty()) {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(Span::styled(
+            texts::provider_meta_section_header(),
+            Style::default().fg(theme.dim).add_modifier(Modifier::BOLD),
+        )));
+        for (key, value) in &meta.fields {
+            lines.push(Line::from(vec![
+                Span::styled(key.clone(), Style::default().fg(theme.accent)),
+                Span::raw(": "),
+                Span::raw(value.clone()),
+            ]));
+        }
+    }
+
     frame.render_widget(
         Paragraph::new(lines)
             .block(Block::default().borders(Borders::NONE))
@@ -2784,27 +3016,6 @@ fn render_mcp(
     ])
     .style(Style::default().fg(theme.dim).add_modifier(Modifier::BOLD));
 
-    let rows = visible.iter().map(|row| {
-        Row::new(vec![
-            Cell::from(row.server.name.clone()),
-            Cell::from(if row.server.apps.claude {
-                texts::tui_marker_active()
-            } else {
-                texts::tui_marker_inactive()
-            }),
-            Cell::from(if row.server.apps.codex {
-                texts::tui_marker_active()
-            } else {
-                texts::tui_marker_inactive()
-            }),
-            Cell::from(if row.server.apps.gemini {
-                texts::tui_marker_active()
-            } else {
-                texts::tui_marker_inactive()
-            }),
-        ])
-    });
-
     let outer = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
@@ -2830,11 +3041,36 @@ fn render_mcp(
                 ("e", texts::tui_key_edit()),
                 ("i", texts::tui_key_import()),
                 ("v", texts::tui_key_validate()),
+                ("c", texts::tui_key_check_all()),
                 ("d", texts::tui_key_delete()),
             ],
         );
     }
 
+    let table_area = inset_left(chunks[1], CONTENT_INSET_LEFT);
+    let window = ListWindow::for_table(app, Route::Mcp, visible.len(), app.mcp_idx, table_area);
+
+    let rows = visible[window.range()].iter().map(|row| {
+        Row::new(vec![
+            Cell::from(row.server.name.clone()),
+            Cell::from(if row.server.apps.claude {
+                texts::tui_marker_active()
+            } else {
+                texts::tui_marker_inactive()
+            }),
+            Cell::from(if row.server.apps.codex {
+                texts::tui_marker_active()
+            } else {
+                texts::tui_marker_inactive()
+            }),
+            Cell::from(if row.server.apps.gemini {
+                texts::tui_marker_active()
+            } else {
+                texts::tui_marker_inactive()
+            }),
+        ])
+    });
+
     let table = Table::new(
         rows,
         [
@@ -2850,9 +3086,10 @@ fn render_mcp(
     .highlight_symbol(highlight_symbol(theme));
 
     let mut state = TableState::default();
-    state.select(Some(app.mcp_idx));
+    state.select(Some(window.relative(app.mcp_idx)));
 
-    frame.render_stateful_widget(table, inset_left(chunks[1], CONTENT_INSET_LEFT), &mut state);
+    frame.render_stateful_widget(table, window.table_area(table_area), &mut state);
+    render_scroll_indicator(frame, table_area, &window, theme);
 }
 
 fn render_prompts(
@@ -2915,6 +3152,7 @@ fn render_prompts(
             &[
                 ("Enter", texts::tui_key_view()),
                 ("a", texts::tui_key_activate()),
+                ("A", texts::tui_key_activate_append()),
                 ("x", texts::tui_key_deactivate_active()),
                 ("e", texts::tui_key_edit()),
                 ("d", texts::tui_key_delete()),
@@ -2955,6 +3193,7 @@ fn config_item_label(item: &ConfigItem) -> &'static str {
     match item {
         ConfigItem::Path => texts::tui_config_item_show_path(),
         ConfigItem::ShowFull => texts::tui_config_item_show_full(),
+        ConfigItem::EditInEditor => texts::tui_config_item_edit_in_editor(),
         ConfigItem::Export => texts::tui_config_item_export(),
         ConfigItem::Import => texts::tui_config_item_import(),
         ConfigItem::Backup => texts::tui_config_item_backup(),
@@ -2962,6 +3201,7 @@ fn config_item_label(item: &ConfigItem) -> &'static str {
         ConfigItem::Validate => texts::tui_config_item_validate(),
         ConfigItem::CommonSnippet => texts::tui_config_item_common_snippet(),
         ConfigItem::WebDavSync => texts::tui_config_item_webdav_sync(),
+        ConfigItem::ResetApp => texts::tui_config_item_reset_app(),
         ConfigItem::Reset => texts::tui_config_item_reset(),
     }
 }
@@ -3020,6 +3260,7 @@ fn render_config(
         if matches!(items.get(app.config_idx), Some(ConfigItem::CommonSnippet)) {
             keys.push(("e", texts::tui_key_edit_snippet()));
         }
+        keys.push(("o", texts::tui_key_open_dir()));
         render_key_bar_center(frame, chunks[0], theme, &keys);
     }
 
@@ -3082,6 +3323,12 @@ fn render_config_webdav(
 fn render_settings(frame: &mut Frame<'_>, app: &App, area: Rect, theme: &super::theme::Theme) {
     let language = crate::cli::i18n::current_language();
     let skip_claude_onboarding = crate::settings::get_skip_claude_onboarding();
+    let offline_mode = crate::settings::get_offline_mode();
+    let sync_skills_on_switch = crate::settings::get_sync_skills_on_switch();
+    let quiet_skill_fallback = crate::settings::get_quiet_skill_copy_fallback();
+    let toast_duration = crate::settings::get_toast_duration_secs();
+    let toast_position = crate::settings::get_toast_position();
+    let tick_rate = crate::settings::get_tui_tick_rate_ms();
 
     let rows_data = super::app::SettingsItem::ALL
         .iter()
@@ -3098,6 +3345,42 @@ fn render_settings(frame: &mut Frame<'_>, app: &App, area: Rect, theme: &super::
                     texts::disabled().to_string()
                 },
             ),
+            super::app::SettingsItem::OfflineMode => (
+                texts::offline_mode_label().to_string(),
+                if offline_mode {
+                    texts::enabled().to_string()
+                } else {
+                    texts::disabled().to_string()
+                },
+            ),
+            super::app::SettingsItem::SyncSkillsOnSwitch => (
+                texts::sync_skills_on_switch_label().to_string(),
+                if sync_skills_on_switch {
+                    texts::enabled().to_string()
+                } else {
+                    texts::disabled().to_string()
+                },
+            ),
+            super::app::SettingsItem::QuietSkillFallback => (
+                texts::quiet_skill_fallback_label().to_string(),
+                if quiet_skill_fallback {
+                    texts::enabled().to_string()
+                } else {
+                    texts::disabled().to_string()
+                },
+            ),
+            super::app::SettingsItem::ToastDuration => (
+                texts::tui_settings_toast_duration().to_string(),
+                texts::tui_settings_toast_duration_value(toast_duration),
+            ),
+            super::app::SettingsItem::ToastPosition => (
+                texts::tui_settings_toast_position().to_string(),
+                texts::tui_toast_position_label(toast_position == ToastPosition::Top).to_string(),
+            ),
+            super::app::SettingsItem::TickRate => (
+                texts::tui_settings_tick_rate().to_string(),
+                texts::tui_settings_tick_rate_value(tick_rate),
+            ),
             super::app::SettingsItem::CheckForUpdates => (
                 texts::tui_settings_check_for_updates().to_string(),
                 format!("v{}", env!("CARGO_PKG_VERSION")),
@@ -3119,9 +3402,18 @@ fn render_settings(frame: &mut Frame<'_>, app: &App, area: Rect, theme: &super::
     ])
     .style(Style::default().fg(theme.dim).add_modifier(Modifier::BOLD));
 
+    let network_disabled = crate::settings::is_offline_mode();
     let rows = rows_data
         .iter()
-        .map(|(label, value)| Row::new(vec![Cell::from(label.clone()), Cell::from(value.clone())]));
+        .zip(super::app::SettingsItem::ALL.iter())
+        .map(|((label, value), item)| {
+            let row = Row::new(vec![Cell::from(label.clone()), Cell::from(value.clone())]);
+            if network_disabled && matches!(item, super::app::SettingsItem::CheckForUpdates) {
+                row.style(Style::default().fg(theme.dim))
+            } else {
+                row
+            }
+        });
 
     let outer = Block::default()
         .borders(Borders::ALL)
@@ -3203,25 +3495,109 @@ fn render_footer(frame: &mut Frame<'_>, app: &App, area: Rect, theme: &super::th
     };
 
     if let Some(toast) = &app.toast {
-        let (prefix, color) = match toast.kind {
-            ToastKind::Info => (texts::tui_toast_prefix_info(), theme.accent),
-            ToastKind::Success => (texts::tui_toast_prefix_success(), theme.ok),
-            ToastKind::Warning => (texts::tui_toast_prefix_warning(), theme.warn),
-            ToastKind::Error => (texts::tui_toast_prefix_error(), theme.err),
-        };
-        spans.push(Span::raw("  "));
-        spans.push(Span::styled(prefix, Style::default().fg(color)));
-        spans.push(Span::raw(toast.message.clone()));
+        if crate::settings::get_toast_position() == ToastPosition::Bottom {
+            let (prefix, color) = toast_prefix_and_color(toast.kind, theme);
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(prefix, Style::default().fg(color)));
+            spans.push(Span::raw(toast.message.clone()));
+        }
     }
 
     frame.render_widget(Paragraph::new(Line::from(spans)), area);
 }
 
+fn toast_prefix_and_color(kind: ToastKind, theme: &super::theme::Theme) -> (&'static str, Color) {
+    match kind {
+        ToastKind::Info => (texts::tui_toast_prefix_info(), theme.accent),
+        ToastKind::Success => (texts::tui_toast_prefix_success(), theme.ok),
+        ToastKind::Warning => (texts::tui_toast_prefix_warning(), theme.warn),
+        ToastKind::Error => (texts::tui_toast_prefix_error(), theme.err),
+    }
+}
+
+/// 顶部位置的 toast：在主体区域顶部居中显示一个带边框的提示框
+fn render_top_toast(frame: &mut Frame<'_>, app: &App, area: Rect, theme: &super::theme::Theme) {
+    let Some(toast) = &app.toast else {
+        return;
+    };
+    if crate::settings::get_toast_position() != ToastPosition::Top || area.height < 3 {
+        return;
+    }
+
+    let (prefix, color) = toast_prefix_and_color(toast.kind, theme);
+    let content_width =
+        UnicodeWidthStr::width(prefix) + UnicodeWidthStr::width(toast.message.as_str());
+    let width = (content_width as u16 + 4).min(area.width);
+    let rect = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y,
+        width,
+        height: 3,
+    };
+
+    frame.render_widget(Clear, rect);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Plain)
+        .border_style(Style::default().fg(color));
+    let line = Line::from(vec![
+        Span::styled(prefix, Style::default().fg(color)),
+        Span::raw(toast.message.clone()),
+    ]);
+    frame.render_widget(Paragraph::new(line).block(block), rect);
+}
+
 fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super::theme::Theme) {
     let content_area = content_pane_rect(frame.area(), theme);
 
     match &app.overlay {
         Overlay::None => {}
+        Overlay::StartupSummary { checks } => {
+            let height = checks.len() as u16 + 4;
+            let area = centered_rect_fixed(70, height, content_area);
+            frame.render_widget(Clear, area);
+
+            let border_color = if checks.iter().any(|check| check.warning.is_some()) {
+                theme.warn
+            } else {
+                theme.ok
+            };
+            let outer = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(border_color))
+                .title(texts::tui_startup_summary_title());
+            frame.render_widget(outer.clone(), area);
+            let inner = outer.inner(area);
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(inner);
+
+            render_key_bar_center(
+                frame,
+                chunks[0],
+                theme,
+                &[(texts::tui_key_any(), texts::tui_key_continue())],
+            );
+
+            let lines = checks
+                .iter()
+                .map(|check| match &check.warning {
+                    None => Line::from(vec![
+                        Span::styled(" ✓ ", Style::default().fg(theme.ok)),
+                        Span::raw(check.label.clone()),
+                    ]),
+                    Some(warning) => Line::from(vec![
+                        Span::styled(" ! ", Style::default().fg(theme.warn)),
+                        Span::raw(format!("{}: ", check.label)),
+                        Span::styled(warning.clone(), Style::default().fg(theme.warn)),
+                    ]),
+                })
+                .collect::<Vec<_>>();
+            frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[1]);
+        }
         Overlay::Help => {
             let area = centered_rect(70, 70, content_area);
             frame.render_widget(Clear, area);
@@ -3248,7 +3624,13 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
             frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[1]);
         }
         Overlay::Confirm(confirm) => {
-            let area = centered_rect_fixed(60, 7, content_area);
+            let is_provider_diff = matches!(confirm.action, ConfirmAction::ProviderEditSave { .. });
+            let area = if is_provider_diff {
+                let height = confirm.message.lines().count() as u16 + 4;
+                centered_rect_fixed(90, height.max(7), content_area)
+            } else {
+                centered_rect_fixed(60, 7, content_area)
+            };
             frame.render_widget(Clear, area);
             let outer = Block::default()
                 .borders(Borders::ALL)
@@ -3283,6 +3665,29 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
                     .alignment(Alignment::Center),
                     chunks[1],
                 );
+            } else if is_provider_diff {
+                render_key_bar_center(
+                    frame,
+                    chunks[0],
+                    theme,
+                    &[
+                        ("Enter", texts::tui_key_save()),
+                        ("Esc", texts::tui_key_cancel()),
+                    ],
+                );
+                let lines = confirm
+                    .message
+                    .lines()
+                    .map(|line| {
+                        let color = match line.chars().next() {
+                            Some('+') => theme.ok,
+                            Some('-') => theme.err,
+                            _ => theme.warn,
+                        };
+                        Line::from(Span::styled(line.to_string(), Style::default().fg(color)))
+                    })
+                    .collect::<Vec<_>>();
+                frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), chunks[1]);
             } else {
                 render_key_bar_center(
                     frame,
@@ -3524,6 +3929,7 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
                 theme,
                 &[
                     ("a", texts::tui_key_apply()),
+                    ("p", texts::tui_key_preview()),
                     ("c", texts::tui_key_clear()),
                     ("e", texts::tui_key_edit()),
                     ("↑↓", texts::tui_key_scroll()),
@@ -3541,6 +3947,53 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
 
             frame.render_widget(Paragraph::new(shown).wrap(Wrap { trim: false }), chunks[1]);
         }
+        Overlay::CommonSnippetDiff { view, .. } => {
+            let area = centered_rect(90, 90, content_area);
+            frame.render_widget(Clear, area);
+
+            let outer = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .border_style(Style::default().fg(theme.dim))
+                .title(view.title.clone());
+            frame.render_widget(outer.clone(), area);
+            let inner = outer.inner(area);
+
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(inner);
+
+            render_key_bar_center(
+                frame,
+                chunks[0],
+                theme,
+                &[
+                    ("a", texts::tui_key_apply()),
+                    ("↑↓", texts::tui_key_scroll()),
+                    ("Esc", texts::tui_key_close()),
+                ],
+            );
+
+            let height = chunks[1].height as usize;
+            let start = view.scroll.min(view.lines.len());
+            let end = (start + height).min(view.lines.len());
+            let shown = view.lines[start..end]
+                .iter()
+                .map(|s| {
+                    let style = if s.starts_with('+') {
+                        Style::default().fg(theme.ok)
+                    } else if s.starts_with('-') {
+                        Style::default().fg(theme.err)
+                    } else {
+                        Style::default()
+                    };
+                    Line::styled(s.clone(), style)
+                })
+                .collect::<Vec<_>>();
+
+            frame.render_widget(Paragraph::new(shown).wrap(Wrap { trim: false }), chunks[1]);
+        }
         Overlay::ClaudeModelPicker { selected, editing } => {
             let area = centered_rect(78, 62, content_area);
             frame.render_widget(Clear, area);
@@ -3820,7 +4273,7 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
                 .split(inner);
 
             let esc_label = match kind {
-                LoadingKind::UpdateCheck => texts::tui_key_cancel(),
+                LoadingKind::UpdateCheck | LoadingKind::SkillsDiscover => texts::tui_key_cancel(),
                 _ => texts::tui_key_close(),
             };
             render_key_bar_center(frame, chunks[0], theme, &[("Esc", esc_label)]);
@@ -3835,7 +4288,7 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
                 chunks[1],
             );
         }
-        Overlay::SpeedtestRunning { url } => {
+        Overlay::SpeedtestRunning { label } => {
             let area = centered_rect_fixed(70, 7, content_area);
             frame.render_widget(Clear, area);
             let outer = Block::default()
@@ -3853,16 +4306,21 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
 
             render_key_bar_center(frame, chunks[0], theme, &[("Esc", texts::tui_key_close())]);
             frame.render_widget(
-                Paragraph::new(Line::raw(texts::tui_speedtest_running(url)))
+                Paragraph::new(Line::raw(texts::tui_speedtest_running(label)))
                     .wrap(Wrap { trim: false }),
                 chunks[1],
             );
         }
-        Overlay::SpeedtestResult { url, lines, scroll } => {
+        Overlay::SpeedtestResult {
+            label,
+            rows,
+            selected,
+            sort,
+        } => {
             let area = centered_rect(90, 90, content_area);
             frame.render_widget(Clear, area);
 
-            let title = texts::tui_speedtest_title_with_url(url);
+            let title = texts::tui_speedtest_title_with_url(label);
             let outer = Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Plain)
@@ -3881,20 +4339,65 @@ fn render_overlay(frame: &mut Frame<'_>, app: &App, data: &UiData, theme: &super
                 chunks[0],
                 theme,
                 &[
-                    ("↑↓", texts::tui_key_scroll()),
+                    ("↑↓", texts::tui_key_select()),
+                    ("Enter", texts::tui_key_switch()),
+                    (
+                        "s",
+                        texts::tui_key_speedtest_sort(*sort == super::app::SpeedtestSort::Latency),
+                    ),
                     ("Esc", texts::tui_key_close()),
                 ],
             );
 
-            let height = chunks[1].height as usize;
-            let start = (*scroll).min(lines.len());
-            let end = (start + height).min(lines.len());
-            let shown = lines[start..end]
-                .iter()
-                .map(|s| Line::raw(s.clone()))
-                .collect::<Vec<_>>();
+            let header_style = Style::default().fg(theme.dim).add_modifier(Modifier::BOLD);
+            let header = Row::new(vec![
+                Cell::from(texts::header_name()),
+                Cell::from(texts::tui_header_api_url()),
+                Cell::from(texts::tui_header_latency()),
+                Cell::from(texts::tui_header_status()),
+            ])
+            .style(header_style);
+
+            let table_rows = rows.iter().map(|row| {
+                let latency = row
+                    .latency
+                    .map(texts::tui_latency_ms)
+                    .unwrap_or_else(|| texts::tui_na().to_string());
+                let (status, style) = match (&row.error, row.status) {
+                    (Some(err), _) if !err.trim().is_empty() => {
+                        (err.clone(), Style::default().fg(theme.err))
+                    }
+                    (_, Some(code)) if (200..400).contains(&code) => {
+                        (code.to_string(), Style::default().fg(theme.ok))
+                    }
+                    (_, Some(code)) => (code.to_string(), Style::default().fg(theme.warn)),
+                    (_, None) => (texts::tui_na().to_string(), Style::default()),
+                };
+                Row::new(vec![
+                    Cell::from(row.target.provider_name.clone()),
+                    Cell::from(row.target.url.clone()),
+                    Cell::from(latency),
+                    Cell::from(status).style(style),
+                ])
+            });
 
-            frame.render_widget(Paragraph::new(shown).wrap(Wrap { trim: false }), chunks[1]);
+            let table = Table::new(
+                table_rows,
+                [
+                    Constraint::Percentage(25),
+                    Constraint::Percentage(45),
+                    Constraint::Length(10),
+                    Constraint::Min(8),
+                ],
+            )
+            .header(header)
+            .block(Block::default().borders(Borders::NONE))
+            .row_highlight_style(selection_style(theme))
+            .highlight_symbol(highlight_symbol(theme));
+
+            let mut state = TableState::default();
+            state.select(Some((*selected).min(rows.len().saturating_sub(1))));
+            frame.render_stateful_widget(table, chunks[1], &mut state);
         }
         Overlay::UpdateAvailable {
             current,
@@ -4206,15 +4709,46 @@ mod tests {
     }
 
     #[test]
-    fn provider_form_shows_full_api_key_in_table_value() {
+    fn provider_form_masks_api_key_in_table_value_until_revealed() {
         let mut form = crate::cli::tui::form::ProviderAddFormState::new(AppType::Claude);
         form.claude_api_key.set("sk-test-1234567890");
+        let field = crate::cli::tui::form::ProviderAddField::ClaudeApiKey;
 
-        let (_label, value) = super::provider_field_label_and_value(
-            &form,
-            crate::cli::tui::form::ProviderAddField::ClaudeApiKey,
-        );
+        let (_label, value) = super::provider_field_label_and_value(&form, field);
+        assert_eq!(value, "•".repeat("sk-test-1234567890".len()));
+
+        form.toggle_secret_reveal(field);
+        let (_label, value) = super::provider_field_label_and_value(&form, field);
         assert_eq!(value, "sk-test-1234567890");
+        assert_eq!(form.claude_api_key.value, "sk-test-1234567890");
+    }
+
+    #[test]
+    fn provider_form_placeholder_follows_app_and_hides_once_filled() {
+        use crate::cli::tui::form::{ProviderAddField, ProviderAddFormState};
+
+        let mut claude = ProviderAddFormState::new(AppType::Claude);
+        claude.claude_api_key.set("");
+        assert_eq!(
+            super::provider_field_placeholder(&claude, ProviderAddField::ClaudeApiKey),
+            Some(texts::claude_api_key_placeholder())
+        );
+        claude.claude_api_key.set("sk-ant-real");
+        assert_eq!(
+            super::provider_field_placeholder(&claude, ProviderAddField::ClaudeApiKey),
+            None
+        );
+
+        let mut codex = ProviderAddFormState::new(AppType::Codex);
+        codex.codex_base_url.set("");
+        assert_eq!(
+            super::provider_field_placeholder(&codex, ProviderAddField::CodexBaseUrl),
+            Some(texts::codex_base_url_placeholder())
+        );
+        assert_eq!(
+            super::provider_field_placeholder(&codex, ProviderAddField::CodexWireApi),
+            None
+        );
     }
 
     static ENV_LOCK: Mutex<()> = Mutex::new(());
@@ -4297,6 +4831,7 @@ mod tests {
             prompts: PromptsSnapshot::default(),
             config: ConfigSnapshot::default(),
             skills: SkillsSnapshot::default(),
+            live_sync_skipped: false,
         }
     }
 
@@ -4386,6 +4921,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn providers_list_renders_only_rows_around_selection() {
+        let _lock = lock_env();
+        let _no_color = EnvGuard::remove("NO_COLOR");
+
+        let mut app = App::new(Some(AppType::Claude));
+        app.route = Route::Providers;
+        app.focus = Focus::Content;
+        app.provider_idx = 150;
+
+        let mut data = minimal_data(&app.app_type);
+        data.providers.rows = (0..200)
+            .map(|i| ProviderRow {
+                id: format!("p{i}"),
+                provider: Provider::with_id(
+                    format!("p{i}"),
+                    format!("Provider {i:03}"),
+                    json!({}),
+                    None,
+                ),
+                api_url: None,
+                is_current: false,
+            })
+            .collect();
+
+        let all = all_text(&render(&app, &data));
+        assert!(
+            all.contains("Provider 150"),
+            "selected row should be visible"
+        );
+        assert!(
+            !all.contains("Provider 000"),
+            "rows above viewport are skipped"
+        );
+        assert!(
+            !all.contains("Provider 199"),
+            "rows below viewport are skipped"
+        );
+        let offset = app.list_scroll.offset(&Route::Providers);
+        assert!(
+            offset > 0 && offset <= 150,
+            "offset {offset} tracks selection"
+        );
+
+        // Moving within the viewport keeps the offset stable.
+        app.provider_idx = 149;
+        render(&app, &data);
+        assert_eq!(app.list_scroll.offset(&Route::Providers), offset);
+    }
+
     #[test]
     fn header_is_wrapped_in_a_rect_block() {
         let _lock = lock_env();
@@ -4741,6 +5326,7 @@ mod tests {
             name: "skills".to_string(),
             branch: "main".to_string(),
             enabled: true,
+            fallback_branches: Vec::new(),
         }];
 
         let buf = render(&app, &data);
@@ -0,0 +1,163 @@
+use super::colors::{error, info, success};
+
+/// 统一 diff 中每个变更块前后保留的上下文行数
+const DIFF_CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// 基于最长公共子序列的逐行 diff
+pub fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (n, m) = (old_lines.len(), new_lines.len());
+
+    // lcs[i][j] = old_lines[i..] 与 new_lines[j..] 的最长公共子序列长度
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_lines[i] == new_lines[j] {
+            result.push(DiffLine::Same(old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push(DiffLine::Removed(old_lines[i]));
+            i += 1;
+        } else {
+            result.push(DiffLine::Added(new_lines[j]));
+            j += 1;
+        }
+    }
+    result.extend(old_lines[i..].iter().map(|line| DiffLine::Removed(line)));
+    result.extend(new_lines[j..].iter().map(|line| DiffLine::Added(line)));
+    result
+}
+
+/// 渲染带颜色的统一 diff；内容一致时返回 None
+pub fn render_unified_diff(
+    old_label: &str,
+    new_label: &str,
+    old: &str,
+    new: &str,
+) -> Option<String> {
+    let lines = line_diff(old, new);
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Same(_)))
+        .map(|(idx, _)| idx)
+        .collect();
+    if changed.is_empty() {
+        return None;
+    }
+
+    // 每一行之前已经过的旧/新行数，用于计算 hunk 头
+    let mut positions = Vec::with_capacity(lines.len());
+    let (mut old_pos, mut new_pos) = (0usize, 0usize);
+    for line in &lines {
+        positions.push((old_pos, new_pos));
+        match line {
+            DiffLine::Same(_) => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            DiffLine::Removed(_) => old_pos += 1,
+            DiffLine::Added(_) => new_pos += 1,
+        }
+    }
+
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for idx in changed {
+        let start = idx.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (idx + DIFF_CONTEXT_LINES + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut out = Vec::new();
+    out.push(error(&format!("--- {old_label}")));
+    out.push(success(&format!("+++ {new_label}")));
+    for (start, end) in hunks {
+        let hunk = &lines[start..end];
+        let old_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Added(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, DiffLine::Removed(_)))
+            .count();
+        let (old_start, new_start) = positions[start];
+        let hunk_start = |pos: usize, count: usize| if count == 0 { pos } else { pos + 1 };
+        out.push(info(&format!(
+            "@@ -{},{} +{},{} @@",
+            hunk_start(old_start, old_count),
+            old_count,
+            hunk_start(new_start, new_count),
+            new_count
+        )));
+        for line in hunk {
+            out.push(match line {
+                DiffLine::Same(text) => format!(" {text}"),
+                DiffLine::Removed(text) => error(&format!("-{text}")),
+                DiffLine::Added(text) => success(&format!("+{text}")),
+            });
+        }
+    }
+    Some(out.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_diff_marks_removed_and_added_lines() {
+        let diff = line_diff("a\nb\nc\n", "a\nx\nc");
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Same("a"),
+                DiffLine::Removed("b"),
+                DiffLine::Added("x"),
+                DiffLine::Same("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn render_unified_diff_returns_none_for_identical_content() {
+        assert!(render_unified_diff("old", "new", "same\n", "same").is_none());
+    }
+
+    #[test]
+    fn render_unified_diff_splits_distant_changes_into_hunks() {
+        let old: String = (1..=20).map(|n| format!("line {n}\n")).collect();
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 18\n", "");
+        let rendered = render_unified_diff("stored", "live", &old, &new).expect("diff");
+
+        assert!(rendered.contains("@@ -1,5 +1,5 @@"), "{rendered}");
+        assert!(rendered.contains("@@ -15,6 +15,5 @@"), "{rendered}");
+        assert!(rendered.contains("+line two"), "{rendered}");
+        assert!(rendered.contains("-line 18"), "{rendered}");
+    }
+}
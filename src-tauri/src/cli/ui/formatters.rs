@@ -1,5 +1,9 @@
 use serde::Serialize;
 
+use crate::cli::i18n::texts;
+use crate::error::AppError;
+use crate::services::SkillSyncReport;
+
 pub fn to_json<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
     serde_json::to_string_pretty(value)
 }
@@ -11,3 +15,21 @@ pub fn format_bool(value: bool) -> &'static str {
         "✗"
     }
 }
+
+/// 切换后 Skills 同步结果的说明文字，以及是否存在失败
+pub fn skills_sync_note(result: &Result<SkillSyncReport, AppError>) -> (String, bool) {
+    match result {
+        Ok(report) => (
+            texts::skills_synced_after_switch(&texts::skills_sync_summary(
+                report.synced_count(),
+                report.skipped_count(),
+                report.failed_count(),
+            )),
+            report.failed_count() > 0,
+        ),
+        Err(err) => (
+            texts::skills_sync_after_switch_failed(&err.to_string()),
+            true,
+        ),
+    }
+}
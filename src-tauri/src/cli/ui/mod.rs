@@ -1,7 +1,9 @@
 pub mod colors;
+pub mod diff;
 pub mod formatters;
 pub mod table;
 
 pub use colors::*;
+pub use diff::*;
 pub use formatters::*;
 pub use table::*;
@@ -1,7 +1,55 @@
-use comfy_table::{presets::UTF8_FULL, Table};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use comfy_table::{
+    presets::{NOTHING, UTF8_FULL},
+    Table,
+};
+
+static COMPACT_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+/// 命令行 `--compact` / `CC_SWITCH_COMPACT`：列表输出改用无边框表格（仅对当前进程生效）
+pub fn set_compact_tables(enabled: bool) {
+    COMPACT_OVERRIDE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_compact_tables() -> bool {
+    COMPACT_OVERRIDE.load(Ordering::Relaxed)
+}
+
+fn table_preset(compact: bool) -> &'static str {
+    if compact {
+        NOTHING
+    } else {
+        UTF8_FULL
+    }
+}
 
 pub fn create_table() -> Table {
     let mut table = Table::new();
-    table.load_preset(UTF8_FULL);
+    table.load_preset(table_preset(is_compact_tables()));
     table
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_preset_drops_borders() {
+        let render = |compact: bool| {
+            let mut table = Table::new();
+            table.load_preset(table_preset(compact));
+            table.set_header(vec!["ID", "Name"]);
+            table.add_row(vec!["p1", "Provider"]);
+            table.to_string()
+        };
+
+        let full = render(false);
+        assert!(full.contains('│') && full.contains('┌'));
+
+        let compact = render(true);
+        assert!(!compact.contains('│') && !compact.contains('┌'));
+        assert!(compact.contains("ID") && compact.contains("Provider"));
+        assert!(compact.len() < full.len());
+    }
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::RwLock;
 
 use crate::error::AppError;
 
@@ -46,6 +47,23 @@ pub fn get_claude_mcp_path() -> PathBuf {
     get_default_claude_mcp_path()
 }
 
+/// Cursor 的 MCP 配置文件路径 (~/.cursor/mcp.json)
+pub fn get_cursor_mcp_path() -> PathBuf {
+    dirs::home_dir()
+        .expect("无法获取用户主目录")
+        .join(".cursor")
+        .join("mcp.json")
+}
+
+/// Windsurf 的 MCP 配置文件路径 (~/.codeium/windsurf/mcp_config.json)
+pub fn get_windsurf_mcp_path() -> PathBuf {
+    dirs::home_dir()
+        .expect("无法获取用户主目录")
+        .join(".codeium")
+        .join("windsurf")
+        .join("mcp_config.json")
+}
+
 /// 获取 Claude Code 主配置文件路径
 pub fn get_claude_settings_path() -> PathBuf {
     let dir = get_claude_config_dir();
@@ -62,18 +80,36 @@ pub fn get_claude_settings_path() -> PathBuf {
     settings
 }
 
-/// 获取应用配置目录路径 (~/.cc-switch)
-pub fn get_app_config_dir() -> PathBuf {
-    // CLI mode: no app store override, always use default
-    // if let Some(custom) = crate::app_store::get_app_config_dir_override() {
-    //     return custom;
-    // }
+/// 配置目录覆盖（由 `--profile` / 激活的配置档设置，仅对当前进程生效）
+static APP_CONFIG_DIR_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
 
+/// 设置或清除配置目录覆盖
+pub fn set_app_config_dir_override(dir: Option<PathBuf>) {
+    if let Ok(mut guard) = APP_CONFIG_DIR_OVERRIDE.write() {
+        *guard = dir;
+    }
+}
+
+/// 获取默认应用配置目录 (~/.cc-switch)，不受配置档覆盖影响
+pub fn get_default_app_config_dir() -> PathBuf {
     dirs::home_dir()
         .expect("无法获取用户主目录")
         .join(".cc-switch")
 }
 
+/// 获取应用配置目录路径 (~/.cc-switch 或当前配置档目录)
+pub fn get_app_config_dir() -> PathBuf {
+    if let Some(custom) = APP_CONFIG_DIR_OVERRIDE
+        .read()
+        .ok()
+        .and_then(|guard| guard.clone())
+    {
+        return custom;
+    }
+
+    get_default_app_config_dir()
+}
+
 /// 获取应用配置文件路径
 pub fn get_app_config_path() -> PathBuf {
     get_app_config_dir().join("config.json")
@@ -2,6 +2,7 @@
 //!
 //! 提供 SQL 导出/导入和二进制快照备份功能。
 
+use super::import_check::check_duplicate_ids;
 use super::{lock_conn, Database, DB_BACKUP_RETAIN};
 use crate::config::get_app_config_dir;
 use crate::error::AppError;
@@ -30,16 +31,8 @@ impl Database {
 
     /// 从 SQL 文件导入，返回生成的备份 ID（若无备份则为空字符串）
     pub fn import_sql(&self, source_path: &Path) -> Result<String, AppError> {
-        if !source_path.exists() {
-            return Err(AppError::InvalidInput(format!(
-                "SQL 文件不存在: {}",
-                source_path.display()
-            )));
-        }
-
-        let sql_raw = fs::read_to_string(source_path).map_err(|e| AppError::io(source_path, e))?;
-        let sql_content = sql_raw.trim_start_matches('\u{feff}');
-        Self::validate_cc_switch_sql_export(sql_content)?;
+        let sql_content = Self::validate_import_file(source_path)?;
+        let sql_content = sql_content.as_str();
 
         // 导入前备份现有数据库
         let backup_path = self.backup_database_file()?;
@@ -79,6 +72,22 @@ impl Database {
         Ok(backup_id)
     }
 
+    /// 导入前预检：文件格式与重复 ID，不做任何写入；返回去除 BOM 后的 SQL
+    pub fn validate_import_file(source_path: &Path) -> Result<String, AppError> {
+        if !source_path.exists() {
+            return Err(AppError::InvalidInput(format!(
+                "SQL 文件不存在: {}",
+                source_path.display()
+            )));
+        }
+
+        let sql_raw = fs::read_to_string(source_path).map_err(|e| AppError::io(source_path, e))?;
+        let sql_content = sql_raw.trim_start_matches('\u{feff}');
+        Self::validate_cc_switch_sql_export(sql_content)?;
+        check_duplicate_ids(sql_content)?;
+        Ok(sql_content.to_string())
+    }
+
     /// 创建内存快照以避免长时间持有数据库锁
     pub(crate) fn snapshot_to_memory(&self) -> Result<Connection, AppError> {
         let conn = lock_conn!(self.conn);
@@ -159,7 +159,7 @@ impl Database {
         let conn = lock_conn!(self.conn);
         let mut stmt = conn
             .prepare(
-                "SELECT owner, name, branch, enabled FROM skill_repos ORDER BY owner ASC, name ASC",
+                "SELECT owner, name, branch, enabled, fallback_branches FROM skill_repos ORDER BY owner ASC, name ASC",
             )
             .map_err(|e| AppError::Database(e.to_string()))?;
 
@@ -170,6 +170,8 @@ impl Database {
                     name: row.get(1)?,
                     branch: row.get(2)?,
                     enabled: row.get(3)?,
+                    fallback_branches: serde_json::from_str(&row.get::<_, String>(4)?)
+                        .unwrap_or_default(),
                 })
             })
             .map_err(|e| AppError::Database(e.to_string()))?;
@@ -185,8 +187,15 @@ impl Database {
     pub fn save_skill_repo(&self, repo: &SkillRepo) -> Result<(), AppError> {
         let conn = lock_conn!(self.conn);
         conn.execute(
-            "INSERT OR REPLACE INTO skill_repos (owner, name, branch, enabled) VALUES (?1, ?2, ?3, ?4)",
-            params![repo.owner, repo.name, repo.branch, repo.enabled],
+            "INSERT OR REPLACE INTO skill_repos (owner, name, branch, enabled, fallback_branches) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                repo.owner,
+                repo.name,
+                repo.branch,
+                repo.enabled,
+                serde_json::to_string(&repo.fallback_branches)
+                    .map_err(|e| AppError::Database(e.to_string()))?
+            ],
         )
         .map_err(|e| AppError::Database(e.to_string()))?;
         Ok(())
@@ -0,0 +1,287 @@
+//! SQL 导入预检
+//!
+//! 主键冲突在导入时要么直接报错，要么（`INSERT OR REPLACE`）后写覆盖前写、静默丢数据。
+//! 这里在执行任何写入前扫描 INSERT 语句，找出同一导入内重复的供应商 / MCP / 提示词 ID。
+
+use std::collections::HashSet;
+
+use crate::error::AppError;
+
+/// 需要预检的表及其主键列
+const KEYED_TABLES: &[(&str, &[&str])] = &[
+    ("providers", &["app_type", "id"]),
+    ("mcp_servers", &["id"]),
+    ("prompts", &["app_type", "id"]),
+];
+
+/// 返回导入内容中重复的主键，形如 `providers:claude/p1`
+pub(crate) fn find_duplicate_ids(sql: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+
+    for statement in split_statements(sql) {
+        let Some((table, columns, rows)) = parse_insert(&statement) else {
+            continue;
+        };
+        let Some((_, key_columns)) = KEYED_TABLES.iter().find(|(name, _)| *name == table) else {
+            continue;
+        };
+        let Some(positions) = key_columns
+            .iter()
+            .map(|key| columns.iter().position(|c| c == key))
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        for row in rows {
+            let Some(parts) = positions
+                .iter()
+                .map(|&idx| row.get(idx).cloned())
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let entry = format!("{table}:{}", parts.join("/"));
+            if !seen.insert(entry.clone()) && !duplicates.contains(&entry) {
+                duplicates.push(entry);
+            }
+        }
+    }
+
+    duplicates
+}
+
+/// 发现重复主键时返回本地化错误
+pub(crate) fn check_duplicate_ids(sql: &str) -> Result<(), AppError> {
+    let duplicates = find_duplicate_ids(sql);
+    if duplicates.is_empty() {
+        return Ok(());
+    }
+    let list = duplicates.join(", ");
+    Err(AppError::localized(
+        "backup.sql.duplicate_ids",
+        format!("导入内容包含重复的 ID，已取消导入（未做任何修改）: {list}"),
+        format!("Import contains duplicate ids; nothing was changed: {list}"),
+    ))
+}
+
+/// 按分号切分语句，跳过字符串字面量与 `--` 注释
+fn split_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = sql.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            current.push(c);
+            if c == '\'' {
+                if chars.peek() == Some(&'\'') {
+                    current.push(chars.next().unwrap_or('\''));
+                } else {
+                    in_string = false;
+                }
+            }
+            continue;
+        }
+        match c {
+            '\'' => {
+                in_string = true;
+                current.push(c);
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for skipped in chars.by_ref() {
+                    if skipped == '\n' {
+                        break;
+                    }
+                }
+                current.push('\n');
+            }
+            ';' => {
+                statements.push(std::mem::take(&mut current));
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current);
+    }
+    statements
+}
+
+/// 解析 `INSERT [OR ...] INTO table (cols) VALUES (...), (...)`；无列名的语句返回 None
+fn parse_insert(statement: &str) -> Option<(String, Vec<String>, Vec<Vec<String>>)> {
+    let mut tokens = Tokenizer::new(statement.trim());
+    if !tokens.keyword("INSERT") && !tokens.keyword("REPLACE") {
+        return None;
+    }
+    if tokens.keyword("OR") {
+        tokens.identifier()?;
+    }
+    if !tokens.keyword("INTO") {
+        return None;
+    }
+    let table = tokens.identifier()?;
+
+    let mut columns = Vec::new();
+    tokens.expect('(')?;
+    loop {
+        columns.push(tokens.identifier()?);
+        if tokens.expect_any(&[',', ')'])? == ')' {
+            break;
+        }
+    }
+
+    if !tokens.keyword("VALUES") {
+        return None;
+    }
+    let mut rows = Vec::new();
+    loop {
+        tokens.expect('(')?;
+        let mut row = Vec::new();
+        loop {
+            row.push(tokens.value()?);
+            if tokens.expect_any(&[',', ')'])? == ')' {
+                break;
+            }
+        }
+        rows.push(row);
+        if tokens.expect(',').is_none() {
+            break;
+        }
+    }
+
+    Some((table, columns, rows))
+}
+
+struct Tokenizer<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { rest: input }
+    }
+
+    fn skip_ws(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn keyword(&mut self, word: &str) -> bool {
+        self.skip_ws();
+        let matches = self
+            .rest
+            .get(..word.len())
+            .is_some_and(|head| head.eq_ignore_ascii_case(word))
+            && !self.rest[word.len()..]
+                .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+        if matches {
+            self.rest = &self.rest[word.len()..];
+        }
+        matches
+    }
+
+    fn expect(&mut self, ch: char) -> Option<()> {
+        self.skip_ws();
+        self.rest = self.rest.strip_prefix(ch)?;
+        Some(())
+    }
+
+    fn expect_any(&mut self, chars: &[char]) -> Option<char> {
+        self.skip_ws();
+        let c = self.rest.chars().next().filter(|c| chars.contains(c))?;
+        self.rest = &self.rest[c.len_utf8()..];
+        Some(c)
+    }
+
+    /// 标识符：`"name"`、`` `name` ``、`[name]` 或裸名
+    fn identifier(&mut self) -> Option<String> {
+        self.skip_ws();
+        let close = match self.rest.chars().next()? {
+            '"' => '"',
+            '`' => '`',
+            '[' => ']',
+            _ => {
+                let end = self
+                    .rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                    .unwrap_or(self.rest.len());
+                if end == 0 {
+                    return None;
+                }
+                let ident = self.rest[..end].to_string();
+                self.rest = &self.rest[end..];
+                return Some(ident);
+            }
+        };
+        let end = self.rest[1..].find(close)? + 1;
+        let ident = self.rest[1..end].to_string();
+        self.rest = &self.rest[end + 1..];
+        Some(ident)
+    }
+
+    /// 值：字符串字面量返回去引号后的内容，其他（数字、NULL、X'..'）按原文返回
+    fn value(&mut self) -> Option<String> {
+        self.skip_ws();
+        if let Some(body) = self.rest.strip_prefix('\'') {
+            let mut value = String::new();
+            let mut chars = body.char_indices();
+            while let Some((idx, c)) = chars.next() {
+                if c != '\'' {
+                    value.push(c);
+                    continue;
+                }
+                if body[idx + 1..].starts_with('\'') {
+                    value.push('\'');
+                    chars.next();
+                    continue;
+                }
+                self.rest = &body[idx + 1..];
+                return Some(value);
+            }
+            return None;
+        }
+
+        let end = self
+            .rest
+            .char_indices()
+            .find(|(_, c)| matches!(c, ',' | ')'))
+            .map(|(idx, _)| idx)?;
+        let value = self.rest[..end].trim().to_string();
+        self.rest = &self.rest[end..];
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_duplicate_provider_mcp_and_prompt_ids() {
+        let sql = "-- CC Switch SQLite 导出; header\n\
+            INSERT INTO \"providers\" (\"id\", \"app_type\", \"name\") VALUES ('p1', 'claude', 'A; ''x''');\n\
+            INSERT INTO \"providers\" (\"id\", \"app_type\", \"name\") VALUES ('p1', 'codex', 'B');\n\
+            INSERT OR REPLACE INTO providers (app_type, id, name) VALUES ('claude', 'p1', 'C');\n\
+            INSERT INTO mcp_servers (id, name) VALUES ('fetch', 'a'), ('fetch', 'b');\n\
+            INSERT INTO prompts (id, app_type, name) VALUES ('x', 'gemini', 'n');\n\
+            INSERT INTO prompts (id, app_type, name) VALUES ('x', 'claude', 'n');";
+
+        assert_eq!(
+            find_duplicate_ids(sql),
+            vec![
+                "providers:claude/p1".to_string(),
+                "mcp_servers:fetch".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_unique_rows_and_other_tables() {
+        let sql = "INSERT INTO settings (key, value) VALUES ('a', '1');\n\
+            INSERT INTO settings (key, value) VALUES ('a', '2');\n\
+            INSERT INTO providers (id, app_type) VALUES ('p1', 'claude');";
+        assert!(check_duplicate_ids(sql).is_ok());
+    }
+}
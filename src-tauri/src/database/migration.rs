@@ -205,8 +205,15 @@ impl Database {
 
         for repo in &config.skills.repos {
             tx.execute(
-                "INSERT OR REPLACE INTO skill_repos (owner, name, branch, enabled) VALUES (?1, ?2, ?3, ?4)",
-                params![repo.owner, repo.name, repo.branch, repo.enabled],
+                "INSERT OR REPLACE INTO skill_repos (owner, name, branch, enabled, fallback_branches) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    repo.owner,
+                    repo.name,
+                    repo.branch,
+                    repo.enabled,
+                    serde_json::to_string(&repo.fallback_branches)
+                        .map_err(|e| AppError::Database(e.to_string()))?
+                ],
             ).map_err(|e| AppError::Database(format!("Migrate skill repo failed: {e}")))?;
         }
 
@@ -14,6 +14,7 @@
 //! ├── mod.rs        - Database 结构体 + 初始化
 //! ├── schema.rs     - 表结构定义 + Schema 迁移
 //! ├── backup.rs     - SQL 导入导出 + 快照备份
+//! ├── import_check.rs - SQL 导入预检（重复 ID）
 //! ├── migration.rs  - JSON → SQLite 数据迁移
 //! └── dao/          - 数据访问对象
 //!     ├── providers.rs
@@ -25,6 +26,7 @@
 
 mod backup;
 mod dao;
+mod import_check;
 mod migration;
 mod schema;
 
@@ -96,7 +96,8 @@ impl Database {
         conn.execute(
             "CREATE TABLE IF NOT EXISTS skill_repos (
             owner TEXT NOT NULL, name TEXT NOT NULL, branch TEXT NOT NULL DEFAULT 'main',
-            enabled BOOLEAN NOT NULL DEFAULT 1, PRIMARY KEY (owner, name)
+            enabled BOOLEAN NOT NULL DEFAULT 1, fallback_branches TEXT NOT NULL DEFAULT '[]',
+            PRIMARY KEY (owner, name)
         )",
             [],
         )
@@ -295,6 +296,14 @@ impl Database {
             "BOOLEAN NOT NULL DEFAULT 0",
         )?;
 
+        // 确保 skill_repos.fallback_branches 列存在（对于已存在的数据库）
+        Self::add_column_if_missing(
+            conn,
+            "skill_repos",
+            "fallback_branches",
+            "TEXT NOT NULL DEFAULT '[]'",
+        )?;
+
         // 删除旧的 failover_queue 表（如果存在）
         let _ = conn.execute("DROP INDEX IF EXISTS idx_failover_queue_order", []);
         let _ = conn.execute("DROP TABLE IF EXISTS failover_queue", []);
@@ -35,7 +35,10 @@ fn write_json_value(path: &Path, value: &Value) -> Result<(), AppError> {
     }
     let json =
         serde_json::to_string_pretty(value).map_err(|e| AppError::JsonSerialize { source: e })?;
-    atomic_write(path, json.as_bytes())
+    atomic_write(
+        &crate::services::provider::live_write_target(path),
+        json.as_bytes(),
+    )
 }
 
 /// 读取 Gemini MCP 配置文件的完整 JSON 文本
@@ -11,6 +11,7 @@ mod gemini_config;
 mod gemini_mcp;
 mod import_export;
 mod init_status;
+mod log_file;
 mod mcp;
 mod prompt;
 mod prompt_files;
@@ -27,27 +28,38 @@ mod usage_script;
 pub mod cli;
 
 // Public exports
-pub use app_config::{AppType, McpApps, McpServer, MultiAppConfig};
+pub use app_config::{AppType, McpApps, McpServer, McpTransport, MultiAppConfig};
 pub use codex_config::{get_codex_auth_path, get_codex_config_path, write_codex_live_atomic};
 pub use config::{get_claude_mcp_path, get_claude_settings_path, read_json_file};
 pub use database::{Database, FailoverQueueItem};
 pub use deeplink::{import_provider_from_deeplink, parse_deeplink_url, DeepLinkImportRequest};
 pub use error::AppError;
 pub use import_export::export_config_to_file;
+pub use log_file::{default_log_file_path, TeeLogWriter};
 pub use mcp::{
     import_from_claude, import_from_codex, import_from_gemini, remove_server_from_claude,
     remove_server_from_codex, remove_server_from_gemini, sync_enabled_to_claude,
     sync_enabled_to_codex, sync_enabled_to_gemini, sync_single_server_to_claude,
-    sync_single_server_to_codex, sync_single_server_to_gemini,
+    sync_single_server_to_codex, sync_single_server_to_gemini, ExternalMcpSource, McpImportSummary,
 };
+pub use prompt::Prompt;
 pub use provider::{Provider, ProviderMeta};
 pub use services::{
-    ConfigService, EndpointLatency, McpService, PromptService, ProviderService, SkillService,
-    SpeedtestService, SyncDecision, WebDavSyncService, WebDavSyncSummary,
+    ApiKeyPool, ArchiveKind, AuditEntry, AuthScheme, ChangeKind, ChangeSection,
+    CommonSnippetPreview, ConfigArchiveService, ConfigChange, ConfigChangeSummary, ConfigService,
+    Diagnostic, DiagnosticReport, DiagnosticStatus, EndpointLatency, ExpiryStatus, ExpiryWarning,
+    FailoverCandidate, HealthStatus, HealthTarget, LiveField, LiveFileMethod, LiveMismatch,
+    LiveVerification, McpAppsUpdate, McpCommandCheck, McpService, NormalizeOutcome, ProfileService,
+    PromptService, ProviderHealth, ProviderService, ProviderValidation, RestoreOutcome,
+    SkillBundleImport, SkillRepo, SkillService, SkillSyncEntry, SkillSyncReport, SkillSyncStatus,
+    SpeedtestOptions, SpeedtestService, SwitchOutcome, SyncDecision, SyncMethod, WebDavSyncService,
+    WebDavSyncSummary,
 };
 pub use settings::{
-    get_skip_claude_onboarding, get_webdav_sync_settings, set_skip_claude_onboarding,
-    set_webdav_sync_settings, update_settings, webdav_jianguoyun_preset, AppSettings,
-    WebDavSyncSettings, WebDavSyncStatus,
+    ensure_writable, get_new_provider_defaults, get_offline_mode, get_skip_claude_onboarding,
+    get_webdav_sync_settings, is_offline_mode, is_read_only, set_hide_sponsors_override,
+    set_new_provider_defaults, set_offline_mode, set_offline_override, set_read_only_override,
+    set_skip_claude_onboarding, set_speedtest_defaults, set_webdav_sync_settings, update_settings,
+    webdav_jianguoyun_preset, AppSettings, WebDavSyncSettings, WebDavSyncStatus,
 };
 pub use store::AppState;
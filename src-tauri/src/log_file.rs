@@ -0,0 +1,141 @@
+//! 日志文件输出（`--log-file`）
+//!
+//! 日志在写入 stderr 的同时追加到文件，文件超过大小上限后按 `.1`、`.2`… 轮转。
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::config::get_default_app_config_dir;
+use crate::error::AppError;
+
+/// 单个日志文件的大小上限，超过后轮转
+const MAX_LOG_FILE_BYTES: u64 = 5 * 1024 * 1024;
+/// 保留的历史日志文件数量
+const MAX_ROTATED_FILES: usize = 3;
+
+/// 默认日志文件路径 (~/.cc-switch/logs/cc-switch.log)
+pub fn default_log_file_path() -> PathBuf {
+    get_default_app_config_dir()
+        .join("logs")
+        .join("cc-switch.log")
+}
+
+/// 同时写入 stderr 与日志文件的输出目标，供 env_logger 使用
+pub struct TeeLogWriter {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: u64,
+}
+
+impl TeeLogWriter {
+    pub fn open(path: &Path) -> Result<Self, AppError> {
+        Self::open_with_limit(path, MAX_LOG_FILE_BYTES)
+    }
+
+    fn open_with_limit(path: &Path, max_bytes: u64) -> Result<Self, AppError> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        let file = open_append(path).map_err(|e| AppError::io(path, e))?;
+        let written = file.metadata().map(|m| m.len()).unwrap_or(0);
+        let mut writer = Self {
+            path: path.to_path_buf(),
+            file,
+            written,
+            max_bytes,
+        };
+        if writer.written >= writer.max_bytes {
+            writer.rotate().map_err(|e| AppError::io(path, e))?;
+        }
+        Ok(writer)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let oldest = rotated_path(&self.path, MAX_ROTATED_FILES);
+        if oldest.exists() {
+            fs::remove_file(&oldest)?;
+        }
+        for index in (1..MAX_ROTATED_FILES).rev() {
+            let from = rotated_path(&self.path, index);
+            if from.exists() {
+                fs::rename(&from, rotated_path(&self.path, index + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        self.file = open_append(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for TeeLogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // stderr 输出失败不应影响日志文件
+        let _ = io::stderr().write_all(buf);
+        if self.written >= self.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(buf)?;
+        self.written += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let _ = io::stderr().flush();
+        self.file.flush()
+    }
+}
+
+fn open_append(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{index}"));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn writer_rotates_when_size_limit_is_reached() {
+        let dir = TempDir::new().expect("temp dir");
+        let path = dir.path().join("logs").join("cc-switch.log");
+        let mut writer = TeeLogWriter::open_with_limit(&path, 8).expect("open log file");
+
+        for line in ["first-line\n", "second-line\n", "third-line\n"] {
+            writer.write_all(line.as_bytes()).expect("write log");
+        }
+        writer.flush().expect("flush");
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "third-line\n");
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 1)).unwrap(),
+            "second-line\n"
+        );
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path, 2)).unwrap(),
+            "first-line\n"
+        );
+    }
+
+    #[test]
+    fn open_appends_to_existing_file_below_limit() {
+        let dir = TempDir::new().expect("temp dir");
+        let path = dir.path().join("cc-switch.log");
+        fs::write(&path, "old\n").expect("seed log");
+
+        let mut writer = TeeLogWriter::open(&path).expect("open log file");
+        writer.write_all(b"new\n").expect("write log");
+        writer.flush().expect("flush");
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old\nnew\n");
+        assert!(!rotated_path(&path, 1).exists());
+    }
+}
@@ -13,7 +13,60 @@ fn main() {
     } else {
         "error" // 默认只显示错误日志，避免 INFO 日志干扰命令输出
     };
-    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level)).init();
+    let mut logger =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(log_level));
+
+    // --log-file 将日志同时写入可轮转的日志文件，级别与 stderr 一致
+    if let Some(path) = cli.log_file.as_ref() {
+        let path = path
+            .clone()
+            .unwrap_or_else(cc_switch_lib::default_log_file_path);
+        match cc_switch_lib::TeeLogWriter::open(&path) {
+            Ok(writer) => {
+                logger
+                    .target(env_logger::Target::Pipe(Box::new(writer)))
+                    .format_timestamp_millis();
+            }
+            Err(e) => eprintln!("Warning: {}", e),
+        }
+    }
+    logger.init();
+
+    // --offline 仅对本次运行生效；持久化设置见 `config offline`
+    if cli.offline {
+        cc_switch_lib::set_offline_override(true);
+    }
+
+    // --no-sponsors 仅对本次运行生效；持久化设置见 `config sponsors`
+    if cli.no_sponsors {
+        cc_switch_lib::set_hide_sponsors_override(true);
+    }
+
+    // --read-only 仅对本次运行生效：允许查看，拒绝任何修改
+    if cli.read_only {
+        cc_switch_lib::set_read_only_override(true);
+    }
+
+    // --compact 让所有列表表格改用无边框样式
+    if cli.compact {
+        cc_switch_lib::cli::ui::set_compact_tables(true);
+    }
+
+    // --fresh 让 TUI 从主页启动，不恢复上次的页面
+    if cli.fresh {
+        cc_switch_lib::cli::tui::set_fresh_start(true);
+    }
+
+    // --plain 让交互菜单改用编号文本输入
+    if cli.plain {
+        cc_switch_lib::cli::plain::set_plain_mode(true);
+    }
+
+    // --profile 优先于持久化的激活配置档，需在任何配置读取之前生效
+    if let Err(e) = cc_switch_lib::ProfileService::activate(cli.profile.as_deref()) {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
 
     // 执行命令
     if let Err(e) = run(cli) {
@@ -23,9 +76,15 @@ fn main() {
 }
 
 fn run(cli: Cli) -> Result<(), AppError> {
+    // 在任何副作用之前拦截修改类命令
+    if cli.command.as_ref().is_some_and(Commands::is_mutation) {
+        cc_switch_lib::ensure_writable()?;
+    }
+
     match cli.command {
         // Default to interactive mode if no command is provided
         None | Some(Commands::Interactive) => cc_switch_lib::cli::interactive::run(cli.app),
+        Some(Commands::Init(cmd)) => cc_switch_lib::cli::commands::init::execute(cmd, cli.app),
         Some(Commands::Provider(cmd)) => {
             cc_switch_lib::cli::commands::provider::execute(cmd, cli.app)
         }
@@ -36,6 +95,12 @@ fn run(cli: Cli) -> Result<(), AppError> {
         Some(Commands::Skills(cmd)) => cc_switch_lib::cli::commands::skills::execute(cmd, cli.app),
         Some(Commands::Config(cmd)) => cc_switch_lib::cli::commands::config::execute(cmd, cli.app),
         Some(Commands::Env(cmd)) => cc_switch_lib::cli::commands::env::execute(cmd, cli.app),
+        Some(Commands::Profile(cmd)) => cc_switch_lib::cli::commands::profile::execute(cmd),
+        Some(Commands::Audit(cmd)) => cc_switch_lib::cli::commands::audit::execute(cmd),
+        Some(Commands::Speedtest(cmd)) => {
+            cc_switch_lib::cli::commands::speedtest::execute(cmd, cli.app)
+        }
+        Some(Commands::Stats(cmd)) => cc_switch_lib::cli::commands::stats::execute(cmd),
         Some(Commands::Update(cmd)) => cc_switch_lib::cli::commands::update::execute(cmd),
         Some(Commands::Completions { shell }) => {
             cc_switch_lib::cli::generate_completions(shell);
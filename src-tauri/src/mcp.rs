@@ -1,7 +1,7 @@
 use serde_json::{json, Value};
 use std::collections::HashMap;
 
-use crate::app_config::{AppType, McpConfig, MultiAppConfig};
+use crate::app_config::{AppType, McpApps, McpConfig, McpServer, MultiAppConfig};
 use crate::error::AppError;
 
 /// 基础校验：允许 stdio/http/sse；或省略 type（视为 stdio）。对应必填字段存在
@@ -333,18 +333,124 @@ pub fn sync_enabled_to_claude(config: &MultiAppConfig) -> Result<(), AppError> {
     crate::claude_mcp::set_mcp_servers_map(&enabled)
 }
 
+/// MCP 导入结果：新增的服务器数量与合并到已有条目的数量
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct McpImportSummary {
+    pub added: usize,
+    pub merged: usize,
+}
+
+impl McpImportSummary {
+    /// 发生变更的条目总数（新增 + 合并）
+    pub fn total(&self) -> usize {
+        self.added + self.merged
+    }
+}
+
+/// 归一化 stdio 服务器的 command + args，用于识别“不同 id 但同一命令”的重复项
+fn normalized_command_signature(spec: &Value) -> Option<(String, Vec<String>)> {
+    let command = spec.get("command")?.as_str()?.trim();
+    if command.is_empty() {
+        return None;
+    }
+    let args = spec
+        .get("args")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| a.as_str())
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default();
+    Some((command.to_string(), args))
+}
+
+/// 合并单个导入项：
+/// - id 已存在，或已有条目的 command+args 相同：仅启用对应应用（计为合并）
+/// - 否则新建服务器，默认仅启用该应用（计为新增）
+///
+/// `app` 为 None 时（外部客户端导入）新服务器不启用任何应用，已存在的服务器保持不变
+fn merge_imported_server(
+    servers: &mut HashMap<String, McpServer>,
+    id: &str,
+    spec: &Value,
+    app: Option<&AppType>,
+    summary: &mut McpImportSummary,
+) {
+    let target_id = if servers.contains_key(id) {
+        Some(id.to_string())
+    } else {
+        normalized_command_signature(spec).and_then(|signature| {
+            servers
+                .iter()
+                .filter(|(_, existing)| {
+                    normalized_command_signature(&existing.server).as_ref() == Some(&signature)
+                })
+                .map(|(existing_id, _)| existing_id.clone())
+                .min()
+        })
+    };
+
+    if let Some(target_id) = target_id {
+        let Some(app) = app else {
+            return;
+        };
+        if let Some(existing) = servers.get_mut(&target_id) {
+            if !existing.apps.is_enabled_for(app) {
+                existing.apps.set_enabled_for(app, true);
+                summary.merged += 1;
+                log::info!(
+                    "MCP 服务器 '{target_id}' 已启用 {} 应用（导入项 '{id}'）",
+                    app.as_str()
+                );
+            }
+        }
+        return;
+    }
+
+    let mut apps = McpApps::default();
+    if let Some(app) = app {
+        apps.set_enabled_for(app, true);
+    }
+    servers.insert(
+        id.to_string(),
+        McpServer {
+            id: id.to_string(),
+            name: id.to_string(),
+            server: spec.clone(),
+            apps,
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    );
+    summary.added += 1;
+    log::info!("导入新 MCP 服务器 '{id}'");
+}
+
 /// 从 ~/.claude.json 导入 mcpServers 到统一结构（v3.7.0+）
 /// 已存在的服务器将启用 Claude 应用，不覆盖其他字段和应用状态
 pub fn import_from_claude(config: &mut MultiAppConfig) -> Result<usize, AppError> {
-    use crate::app_config::{McpApps, McpServer};
+    Ok(import_summary_from_claude(config)?.total())
+}
 
+/// 从 ~/.claude.json 导入 MCP，并区分新增与合并的数量
+pub fn import_summary_from_claude(
+    config: &mut MultiAppConfig,
+) -> Result<McpImportSummary, AppError> {
     let text_opt = crate::claude_mcp::read_mcp_json()?;
-    let Some(text) = text_opt else { return Ok(0) };
+    let Some(text) = text_opt else {
+        return Ok(McpImportSummary::default());
+    };
 
     let v: Value = serde_json::from_str(&text)
         .map_err(|e| AppError::McpValidation(format!("解析 ~/.claude.json 失败: {e}")))?;
     let Some(map) = v.get("mcpServers").and_then(|x| x.as_object()) else {
-        return Ok(0);
+        return Ok(McpImportSummary::default());
     };
 
     // 确保新结构存在
@@ -353,7 +459,7 @@ pub fn import_from_claude(config: &mut MultiAppConfig) -> Result<usize, AppError
     }
     let servers = config.mcp.servers.as_mut().unwrap();
 
-    let mut changed = 0;
+    let mut summary = McpImportSummary::default();
     let mut errors = Vec::new();
 
     for (id, spec) in map.iter() {
@@ -364,43 +470,14 @@ pub fn import_from_claude(config: &mut MultiAppConfig) -> Result<usize, AppError
             continue;
         }
 
-        if let Some(existing) = servers.get_mut(id) {
-            // 已存在：仅启用 Claude 应用
-            if !existing.apps.claude {
-                existing.apps.claude = true;
-                changed += 1;
-                log::info!("MCP 服务器 '{id}' 已启用 Claude 应用");
-            }
-        } else {
-            // 新建服务器：默认仅启用 Claude
-            servers.insert(
-                id.clone(),
-                McpServer {
-                    id: id.clone(),
-                    name: id.clone(),
-                    server: spec.clone(),
-                    apps: McpApps {
-                        claude: true,
-                        codex: false,
-                        gemini: false,
-                        opencode: false,
-                    },
-                    description: None,
-                    homepage: None,
-                    docs: None,
-                    tags: Vec::new(),
-                },
-            );
-            changed += 1;
-            log::info!("导入新 MCP 服务器 '{id}'");
-        }
+        merge_imported_server(servers, id, spec, Some(&AppType::Claude), &mut summary);
     }
 
     if !errors.is_empty() {
         log::warn!("导入完成，但有 {} 项失败: {:?}", errors.len(), errors);
     }
 
-    Ok(changed)
+    Ok(summary)
 }
 
 /// 从 ~/.codex/config.toml 导入 MCP 到统一结构（v3.7.0+）
@@ -411,11 +488,16 @@ pub fn import_from_claude(config: &mut MultiAppConfig) -> Result<usize, AppError
 ///
 /// 已存在的服务器将启用 Codex 应用，不覆盖其他字段和应用状态
 pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError> {
-    use crate::app_config::{McpApps, McpServer};
+    Ok(import_summary_from_codex(config)?.total())
+}
 
+/// 从 ~/.codex/config.toml 导入 MCP，并区分新增与合并的数量
+pub fn import_summary_from_codex(
+    config: &mut MultiAppConfig,
+) -> Result<McpImportSummary, AppError> {
     let text = crate::codex_config::read_and_validate_codex_config_text()?;
     if text.trim().is_empty() {
-        return Ok(0);
+        return Ok(McpImportSummary::default());
     }
 
     let root: toml::Table = toml::from_str(&text)
@@ -427,11 +509,10 @@ pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError>
     }
     let servers = config.mcp.servers.as_mut().unwrap();
 
-    let mut changed_total = 0usize;
+    let mut summary = McpImportSummary::default();
 
     // helper：处理一组 servers 表
     let mut import_servers_tbl = |servers_tbl: &toml::value::Table| {
-        let mut changed = 0usize;
         for (id, entry_val) in servers_tbl.iter() {
             let Some(entry_tbl) = entry_val.as_table() else {
                 continue;
@@ -511,7 +592,7 @@ pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError>
                 }
                 _ => {
                     log::warn!("跳过未知类型 '{typ}' 的 Codex MCP 项 '{id}'");
-                    return changed;
+                    return;
                 }
             }
 
@@ -582,38 +663,8 @@ pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError>
                 continue;
             }
 
-            if let Some(existing) = servers.get_mut(id) {
-                // 已存在：仅启用 Codex 应用
-                if !existing.apps.codex {
-                    existing.apps.codex = true;
-                    changed += 1;
-                    log::info!("MCP 服务器 '{id}' 已启用 Codex 应用");
-                }
-            } else {
-                // 新建服务器：默认仅启用 Codex
-                servers.insert(
-                    id.clone(),
-                    McpServer {
-                        id: id.clone(),
-                        name: id.clone(),
-                        server: spec_v,
-                        apps: McpApps {
-                            claude: false,
-                            codex: true,
-                            gemini: false,
-                            opencode: false,
-                        },
-                        description: None,
-                        homepage: None,
-                        docs: None,
-                        tags: Vec::new(),
-                    },
-                );
-                changed += 1;
-                log::info!("导入新 MCP 服务器 '{id}'");
-            }
+            merge_imported_server(servers, id, &spec_v, Some(&AppType::Codex), &mut summary);
         }
-        changed
     };
 
     // 1) 处理 mcp.servers
@@ -621,7 +672,7 @@ pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError>
         if let Some(mcp_tbl) = mcp_val.as_table() {
             if let Some(servers_val) = mcp_tbl.get("servers") {
                 if let Some(servers_tbl) = servers_val.as_table() {
-                    changed_total += import_servers_tbl(servers_tbl);
+                    import_servers_tbl(servers_tbl);
                 }
             }
         }
@@ -630,11 +681,11 @@ pub fn import_from_codex(config: &mut MultiAppConfig) -> Result<usize, AppError>
     // 2) 处理 mcp_servers
     if let Some(servers_val) = root.get("mcp_servers") {
         if let Some(servers_tbl) = servers_val.as_table() {
-            changed_total += import_servers_tbl(servers_tbl);
+            import_servers_tbl(servers_tbl);
         }
     }
 
-    Ok(changed_total)
+    Ok(summary)
 }
 
 /// 将 config.json 中 Codex 的 enabled==true 项以 TOML 形式写入 ~/.codex/config.toml
@@ -700,7 +751,8 @@ pub fn sync_enabled_to_codex(config: &MultiAppConfig) -> Result<(), AppError> {
 
     // 6) 写回（仅改 TOML，不触碰 auth.json）；toml_edit 会尽量保留未改区域的注释/空白/顺序
     let new_text = doc.to_string();
-    let path = crate::codex_config::get_codex_config_path();
+    let path =
+        crate::services::provider::live_write_target(&crate::codex_config::get_codex_config_path());
     crate::config::write_text_file(&path, &new_text)?;
     Ok(())
 }
@@ -714,11 +766,16 @@ pub fn sync_enabled_to_gemini(config: &MultiAppConfig) -> Result<(), AppError> {
 /// 从 ~/.gemini/settings.json 导入 mcpServers 到统一结构（v3.7.0+）
 /// 已存在的服务器将启用 Gemini 应用，不覆盖其他字段和应用状态
 pub fn import_from_gemini(config: &mut MultiAppConfig) -> Result<usize, AppError> {
-    use crate::app_config::{McpApps, McpServer};
+    Ok(import_summary_from_gemini(config)?.total())
+}
 
+/// 从 Gemini settings.json 导入 MCP，并区分新增与合并的数量
+pub fn import_summary_from_gemini(
+    config: &mut MultiAppConfig,
+) -> Result<McpImportSummary, AppError> {
     let map = crate::gemini_mcp::read_mcp_servers_map()?;
     if map.is_empty() {
-        return Ok(0);
+        return Ok(McpImportSummary::default());
     }
 
     // 确保新结构存在
@@ -727,7 +784,7 @@ pub fn import_from_gemini(config: &mut MultiAppConfig) -> Result<usize, AppError
     }
     let servers = config.mcp.servers.as_mut().unwrap();
 
-    let mut changed = 0;
+    let mut summary = McpImportSummary::default();
     let mut errors = Vec::new();
 
     for (id, spec) in map.iter() {
@@ -738,43 +795,111 @@ pub fn import_from_gemini(config: &mut MultiAppConfig) -> Result<usize, AppError
             continue;
         }
 
-        if let Some(existing) = servers.get_mut(id) {
-            // 已存在：仅启用 Gemini 应用
-            if !existing.apps.gemini {
-                existing.apps.gemini = true;
-                changed += 1;
-                log::info!("MCP 服务器 '{id}' 已启用 Gemini 应用");
-            }
-        } else {
-            // 新建服务器：默认仅启用 Gemini
-            servers.insert(
-                id.clone(),
-                McpServer {
-                    id: id.clone(),
-                    name: id.clone(),
-                    server: spec.clone(),
-                    apps: McpApps {
-                        claude: false,
-                        codex: false,
-                        gemini: true,
-                        opencode: false,
-                    },
-                    description: None,
-                    homepage: None,
-                    docs: None,
-                    tags: Vec::new(),
-                },
-            );
-            changed += 1;
-            log::info!("导入新 MCP 服务器 '{id}'");
+        merge_imported_server(servers, id, spec, Some(&AppType::Gemini), &mut summary);
+    }
+
+    if !errors.is_empty() {
+        log::warn!("导入完成，但有 {} 项失败: {:?}", errors.len(), errors);
+    }
+
+    Ok(summary)
+}
+
+/// 非 CC-Switch 管理的 MCP 客户端，仅支持导入
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExternalMcpSource {
+    Cursor,
+    Windsurf,
+}
+
+impl ExternalMcpSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExternalMcpSource::Cursor => "cursor",
+            ExternalMcpSource::Windsurf => "windsurf",
+        }
+    }
+
+    /// 客户端的 MCP 配置文件路径
+    pub fn config_path(&self) -> std::path::PathBuf {
+        match self {
+            ExternalMcpSource::Cursor => crate::config::get_cursor_mcp_path(),
+            ExternalMcpSource::Windsurf => crate::config::get_windsurf_mcp_path(),
+        }
+    }
+}
+
+/// 将外部客户端的服务器定义转换为统一结构（Windsurf 的 serverUrl、省略 type 的远程服务器）
+fn normalize_external_server_spec(spec: &Value) -> Value {
+    let mut spec = spec.clone();
+    let Some(obj) = spec.as_object_mut() else {
+        return spec;
+    };
+    if !obj.contains_key("url") {
+        if let Some(url) = obj.remove("serverUrl") {
+            obj.insert("url".to_string(), url);
+        }
+    }
+    if !obj.contains_key("type") && !obj.contains_key("command") {
+        if let Some(url) = obj.get("url").and_then(|v| v.as_str()) {
+            let transport = if url.trim_end_matches('/').ends_with("/sse") {
+                "sse"
+            } else {
+                "http"
+            };
+            obj.insert("type".to_string(), json!(transport));
+        }
+    }
+    spec
+}
+
+/// 从 Cursor / Windsurf 的 mcpServers 导入 MCP；新服务器默认不启用任何应用
+///
+/// 配置文件不存在时视为没有可导入的服务器
+pub fn import_summary_from_external(
+    config: &mut MultiAppConfig,
+    source: ExternalMcpSource,
+) -> Result<McpImportSummary, AppError> {
+    let path = source.config_path();
+    if !path.exists() {
+        return Ok(McpImportSummary::default());
+    }
+    let text = std::fs::read_to_string(&path).map_err(|e| AppError::io(&path, e))?;
+    if text.trim().is_empty() {
+        return Ok(McpImportSummary::default());
+    }
+    let v: Value = serde_json::from_str(&text)
+        .map_err(|e| AppError::McpValidation(format!("解析 {} 失败: {e}", path.display())))?;
+    let Some(map) = v.get("mcpServers").and_then(|x| x.as_object()) else {
+        return Ok(McpImportSummary::default());
+    };
+
+    // 确保新结构存在
+    if config.mcp.servers.is_none() {
+        config.mcp.servers = Some(HashMap::new());
+    }
+    let servers = config.mcp.servers.as_mut().unwrap();
+
+    let mut summary = McpImportSummary::default();
+    let mut errors = Vec::new();
+
+    for (id, spec) in map.iter() {
+        let spec = normalize_external_server_spec(spec);
+        // 校验：单项失败不中止，收集错误继续处理
+        if let Err(e) = validate_server_spec(&spec) {
+            log::warn!("跳过无效 MCP 服务器 '{id}': {e}");
+            errors.push(format!("{id}: {e}"));
+            continue;
         }
+
+        merge_imported_server(servers, id, &spec, None, &mut summary);
     }
 
     if !errors.is_empty() {
         log::warn!("导入完成，但有 {} 项失败: {:?}", errors.len(), errors);
     }
 
-    Ok(changed)
+    Ok(summary)
 }
 
 // ============================================================================
@@ -1,7 +1,7 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // SSOT 模式：不再写供应商副本文件
 
@@ -236,6 +236,18 @@ pub struct ProviderMeta {
     /// 供应商单独的代理配置
     #[serde(rename = "proxyConfig", skip_serializing_if = "Option::is_none")]
     pub proxy_config: Option<ProviderProxyConfig>,
+    /// 结构化备注字段（如 environment、owner、expiry），与自由文本 notes 互不影响
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+    /// API Key 到期日（YYYY-MM-DD），仅用于本地到期提醒
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+    /// 轮询使用的 API Key 池（为空时只使用 settings_config 中的单个 Key）
+    #[serde(rename = "apiKeys", default, skip_serializing_if = "Vec::is_empty")]
+    pub api_keys: Vec<String>,
+    /// 下次切换时写入的 Key 在池中的位置
+    #[serde(rename = "apiKeyIndex", skip_serializing_if = "Option::is_none")]
+    pub api_key_index: Option<usize>,
 }
 
 impl ProviderManager {
@@ -244,3 +256,39 @@ impl ProviderManager {
         &self.providers
     }
 }
+
+/// 生成唯一的 Provider ID
+/// 基于名称转换为 kebab-case，如有冲突则追加数字后缀
+pub fn generate_provider_id(name: &str, existing_ids: &[String]) -> String {
+    // 转换为 kebab-case
+    let base_id = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else if c.is_whitespace() {
+                '-'
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_string();
+
+    // 检查唯一性
+    if !existing_ids.contains(&base_id) {
+        return base_id;
+    }
+
+    // 追加数字后缀
+    let mut counter = 1;
+    loop {
+        let candidate = format!("{}-{}", base_id, counter);
+        if !existing_ids.contains(&candidate) {
+            return candidate;
+        }
+        counter += 1;
+    }
+}
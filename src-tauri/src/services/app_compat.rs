@@ -0,0 +1,161 @@
+//! 应用 CLI 版本兼容性检查：检测已安装的 claude / codex / gemini 版本，
+//! 对照内置兼容表，提示 cc-switch 写入的 live 格式在该版本下不会生效的情况。
+
+use serde_json::Value;
+
+use crate::app_config::AppType;
+use crate::services::diagnostic::localized_detail;
+use crate::services::local_env_check::{check_tool, parse_version, LocalTool, ToolCheckStatus};
+use crate::services::Diagnostic;
+
+type Version = (u64, u64, u64);
+
+/// 兼容表中的一条已知问题：版本低于 `below` 且 live 文件命中 `detect` 时告警
+struct CompatRule {
+    app: AppType,
+    below: Option<Version>,
+    issue_zh: &'static str,
+    issue_en: &'static str,
+    remediation: &'static str,
+    detect: fn() -> bool,
+}
+
+const COMPAT_RULES: &[CompatRule] = &[
+    // Claude Code CHANGELOG：1.0.88 起才读取 ANTHROPIC_DEFAULT_{OPUS,SONNET,HAIKU}_MODEL
+    CompatRule {
+        app: AppType::Claude,
+        below: Some((1, 0, 88)),
+        issue_zh: "Claude Code 1.0.88 之前的版本会忽略 ANTHROPIC_DEFAULT_*_MODEL，settings.json 中的模型覆盖不会生效",
+        issue_en: "Claude Code before 1.0.88 ignores ANTHROPIC_DEFAULT_*_MODEL; the model overrides in settings.json have no effect",
+        remediation: "claude update",
+        detect: claude_uses_model_aliases,
+    },
+    // openai/codex 0.64.0 发布说明：此前的版本只从 auth.json 读取凭据
+    CompatRule {
+        app: AppType::Codex,
+        below: Some((0, 64, 0)),
+        issue_zh: "Codex 0.64 之前的版本只从 auth.json 读取凭据，但当前没有 auth.json",
+        issue_en: "Codex before 0.64 only reads credentials from auth.json, but no auth.json is present",
+        remediation: "npm install -g @openai/codex@latest",
+        detect: codex_auth_json_missing,
+    },
+    // google-gemini/gemini-cli 0.3.0 发布说明：settings.json 迁移为分组结构，selectedAuthType 移至 security.auth.selectedType
+    CompatRule {
+        app: AppType::Gemini,
+        below: Some((0, 3, 0)),
+        issue_zh: "Gemini CLI 0.3.0 之前的版本读取 selectedAuthType，而不是 cc-switch 写入的 security.auth.selectedType",
+        issue_en: "Gemini CLI before 0.3.0 reads selectedAuthType, not the security.auth.selectedType written by cc-switch",
+        remediation: "npm install -g @google/gemini-cli@latest",
+        detect: gemini_uses_nested_auth_type,
+    },
+];
+
+fn local_tool(app: &AppType) -> LocalTool {
+    match app {
+        AppType::Claude => LocalTool::Claude,
+        AppType::Codex => LocalTool::Codex,
+        AppType::Gemini => LocalTool::Gemini,
+    }
+}
+
+/// 只取主、次、修订号（预发布后缀忽略）
+fn parse_semver(version: &str) -> Option<Version> {
+    let core = parse_version(version)?;
+    let mut parts = core
+        .split(['-', '+'])
+        .next()?
+        .split('.')
+        .map(|part| part.parse::<u64>().ok());
+    Some((parts.next()??, parts.next()??, parts.next()??))
+}
+
+fn in_range(version: Version, below: Option<Version>) -> bool {
+    below.is_none_or(|below| version < below)
+}
+
+/// 检测应用版本并对照兼容表，返回 `compat.<app>` 诊断项
+pub fn check_app_compat(app: &AppType) -> Diagnostic {
+    let check = format!("compat.{}", app.as_str());
+    let version = match check_tool(local_tool(app)).status {
+        ToolCheckStatus::NotInstalledOrNotExecutable => {
+            return Diagnostic::ok(check)
+                .with_details([localized_detail("未安装，已跳过", "not installed, skipped")]);
+        }
+        ToolCheckStatus::Error { message } => {
+            return Diagnostic::warn(check).with_details([localized_detail(
+                format!("无法获取版本: {message}"),
+                format!("version unknown: {message}"),
+            )]);
+        }
+        ToolCheckStatus::Ok { version } => version,
+    };
+    let Some(parsed) = parse_semver(&version) else {
+        return Diagnostic::warn(check).with_details([localized_detail(
+            format!("无法识别的版本: {version}"),
+            format!("unrecognized version: {version}"),
+        )]);
+    };
+    let version_detail = localized_detail(format!("版本 {version}"), format!("version {version}"));
+
+    let issues: Vec<&CompatRule> = COMPAT_RULES
+        .iter()
+        .filter(|rule| rule.app == *app && in_range(parsed, rule.below))
+        .filter(|rule| (rule.detect)())
+        .collect();
+    let Some(first) = issues.first() else {
+        return Diagnostic::ok(check).with_details([version_detail]);
+    };
+    Diagnostic::warn(check)
+        .with_details(
+            std::iter::once(version_detail).chain(
+                issues
+                    .iter()
+                    .map(|rule| localized_detail(rule.issue_zh, rule.issue_en)),
+            ),
+        )
+        .with_remediation(first.remediation)
+}
+
+fn claude_uses_model_aliases() -> bool {
+    crate::config::read_json_file::<Value>(&crate::config::get_claude_settings_path())
+        .ok()
+        .and_then(|settings| settings.get("env").cloned())
+        .and_then(|env| env.as_object().cloned())
+        .is_some_and(|env| {
+            env.keys()
+                .any(|key| key.starts_with("ANTHROPIC_DEFAULT_") && key.ends_with("_MODEL"))
+        })
+}
+
+fn codex_auth_json_missing() -> bool {
+    crate::codex_config::get_codex_config_path().exists()
+        && !crate::codex_config::get_codex_auth_path().exists()
+}
+
+fn gemini_uses_nested_auth_type() -> bool {
+    crate::config::read_json_file::<Value>(&crate::gemini_config::get_gemini_settings_path())
+        .ok()
+        .and_then(|settings| settings.pointer("/security/auth/selectedType").cloned())
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_semver_ignores_prefix_and_prerelease() {
+        assert_eq!(parse_semver("codex-cli 0.63.1"), Some((0, 63, 1)));
+        assert_eq!(parse_semver("v1.0.88-beta.2"), Some((1, 0, 88)));
+        assert_eq!(parse_semver("unknown"), None);
+    }
+
+    #[test]
+    fn in_range_excludes_the_fixed_version() {
+        let below = Some((0, 64, 0));
+        assert!(in_range((0, 63, 9), below));
+        assert!(!in_range((0, 64, 0), below));
+        assert!(!in_range((1, 0, 0), below));
+        assert!(in_range((9, 9, 9), None));
+    }
+}
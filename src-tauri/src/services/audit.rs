@@ -0,0 +1,224 @@
+//! 变更审计日志：每次变更操作以一行 JSON 追加到 `~/.cc-switch/audit.log`
+//!
+//! 写入失败只记录警告，不影响操作本身。日志超过 `MAX_LOG_BYTES` 时轮转为 `audit.log.1`。
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app_config::AppType;
+use crate::config::get_app_config_dir;
+use crate::error::AppError;
+
+/// 单个日志文件的大小上限，超过后轮转（只保留一个旧文件）
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+/// 从文件末尾倒序读取时每次读取的字节数
+const TAIL_CHUNK_BYTES: u64 = 8 * 1024;
+
+/// 审计日志中的一条记录
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// RFC 3339 时间戳
+    pub timestamp: String,
+    /// 操作名，如 `provider.switch`
+    pub operation: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// `ok`，失败时为 `error`
+    pub outcome: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl AuditEntry {
+    pub fn is_ok(&self) -> bool {
+        self.outcome == "ok"
+    }
+}
+
+pub fn audit_log_path() -> PathBuf {
+    get_app_config_dir().join("audit.log")
+}
+
+/// 记录一次变更操作的结果
+pub fn record<T>(
+    operation: &str,
+    app: Option<&AppType>,
+    id: Option<&str>,
+    result: &Result<T, AppError>,
+) {
+    let entry = AuditEntry {
+        timestamp: chrono::Local::now().to_rfc3339(),
+        operation: operation.to_string(),
+        app: app.map(|app| app.as_str().to_string()),
+        id: id.map(str::to_string),
+        outcome: if result.is_ok() { "ok" } else { "error" }.to_string(),
+        error: result.as_ref().err().map(|err| err.to_string()),
+    };
+    if let Err(err) = append(&entry) {
+        log::warn!("写入审计日志失败: {err}");
+    }
+}
+
+fn append(entry: &AuditEntry) -> Result<(), AppError> {
+    let path = audit_log_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+    }
+    let line = serde_json::to_string(entry).map_err(|source| AppError::JsonSerialize { source })?;
+    rotate_if_needed(&path)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| AppError::io(&path, e))?;
+    writeln!(file, "{line}").map_err(|e| AppError::io(&path, e))
+}
+
+fn rotate_if_needed(path: &Path) -> Result<(), AppError> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Ok(());
+    };
+    if metadata.len() < MAX_LOG_BYTES {
+        return Ok(());
+    }
+    let rotated = path.with_extension("log.1");
+    fs::rename(path, &rotated).map_err(|e| AppError::io(&rotated, e))
+}
+
+/// 最近的 `limit` 条记录（按时间先后），跳过无法解析的行
+///
+/// 当前日志不足 `limit` 条时，从轮转后的 `audit.log.1` 补齐更早的记录。
+pub fn recent_entries(limit: usize) -> Result<Vec<AuditEntry>, AppError> {
+    recent_entries_in(&audit_log_path(), limit)
+}
+
+fn recent_entries_in(path: &Path, limit: usize) -> Result<Vec<AuditEntry>, AppError> {
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+    let mut entries = tail_entries(path, limit)?;
+    if entries.len() < limit {
+        let rotated = path.with_extension("log.1");
+        let mut older = tail_entries(&rotated, limit - entries.len())?;
+        older.append(&mut entries);
+        entries = older;
+    }
+    let skip = entries.len().saturating_sub(limit);
+    Ok(entries.into_iter().skip(skip).collect())
+}
+
+/// 单个日志文件末尾的至多 `limit` 条记录；文件不存在时为空
+fn tail_entries(path: &Path, limit: usize) -> Result<Vec<AuditEntry>, AppError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = read_tail_lines(path, limit).map_err(|e| AppError::io(path, e))?;
+    let entries: Vec<AuditEntry> = text
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let skip = entries.len().saturating_sub(limit);
+    Ok(entries.into_iter().skip(skip).collect())
+}
+
+/// 从文件末尾按块倒序读取，直到包含至少 `lines` 个完整行或读到文件开头
+fn read_tail_lines(path: &Path, lines: usize) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let mut start = len;
+    let mut buf = Vec::new();
+    while start > 0 {
+        let read_len = TAIL_CHUNK_BYTES.min(start);
+        start -= read_len;
+        let mut chunk = vec![0u8; read_len as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&buf);
+        buf = chunk;
+        // 末尾换行之外还需多一个换行，才能保证最前面的行是完整的
+        if buf.iter().filter(|&&b| b == b'\n').count() > lines {
+            break;
+        }
+    }
+    if start > 0 {
+        if let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+            buf.drain(..=pos);
+        }
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_tail_lines_returns_only_complete_trailing_lines() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("audit.log");
+        let text: String = (0..2000).map(|i| format!("line-{i:014}\n")).collect();
+        fs::write(&path, &text).expect("write log");
+
+        let tail = read_tail_lines(&path, 3).expect("read tail");
+        let lines: Vec<&str> = tail.lines().collect();
+        assert!(lines.len() >= 3 && (lines.len() as u64) < TAIL_CHUNK_BYTES);
+        assert_eq!(lines.last(), Some(&"line-00000000001999"));
+        assert!(
+            lines.iter().all(|line| line.len() == 19),
+            "no partial lines"
+        );
+    }
+
+    #[test]
+    fn rotate_if_needed_moves_oversized_log_aside() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("audit.log");
+        fs::write(&path, "small\n").expect("write log");
+        rotate_if_needed(&path).expect("rotate");
+        assert!(path.exists(), "small logs stay in place");
+
+        fs::write(&path, vec![b'x'; MAX_LOG_BYTES as usize]).expect("write big log");
+        rotate_if_needed(&path).expect("rotate");
+        assert!(!path.exists());
+        assert!(dir.path().join("audit.log.1").exists());
+    }
+
+    #[test]
+    fn recent_entries_fill_up_from_rotated_log() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("audit.log");
+        let lines = |ops: &[&str]| -> String {
+            ops.iter()
+                .map(|op| {
+                    let entry = AuditEntry {
+                        timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+                        operation: op.to_string(),
+                        app: None,
+                        id: None,
+                        outcome: "ok".to_string(),
+                        error: None,
+                    };
+                    format!("{}\n", serde_json::to_string(&entry).unwrap())
+                })
+                .collect()
+        };
+        fs::write(path.with_extension("log.1"), lines(&["a", "b", "c"])).expect("write old");
+        fs::write(&path, lines(&["d", "e"])).expect("write log");
+
+        let ops = |limit| -> Vec<String> {
+            recent_entries_in(&path, limit)
+                .expect("read entries")
+                .into_iter()
+                .map(|entry| entry.operation)
+                .collect()
+        };
+        assert_eq!(ops(2), ["d", "e"]);
+        assert_eq!(ops(4), ["b", "c", "d", "e"]);
+        assert_eq!(ops(10), ["a", "b", "c", "d", "e"]);
+    }
+}
@@ -1,15 +1,18 @@
-use super::provider::ProviderService;
+use super::audit;
+use super::config_changes::ConfigChangeSummary;
+use super::provider::{resolve_env_placeholders, restore_env_placeholders, ProviderService};
 use crate::app_config::{AppType, MultiAppConfig};
 use crate::database::Database;
 use crate::error::AppError;
 use crate::provider::Provider;
 use crate::store::AppState;
-use chrono::Utc;
+use chrono::{DateTime, NaiveDateTime, Utc};
 use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 const MAX_BACKUPS: usize = 10;
+const MAX_BACKUP_NAME_LEN: usize = 64;
 
 /// 备份信息
 #[derive(Debug, Clone)]
@@ -24,6 +27,32 @@ pub struct BackupInfo {
     pub display_name: String,
 }
 
+impl BackupInfo {
+    /// 解析备份创建时间（文件名中的时间戳以 UTC 生成）
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        NaiveDateTime::parse_from_str(&self.timestamp, "%Y%m%d_%H%M%S")
+            .ok()
+            .map(|naive| naive.and_utc())
+    }
+}
+
+/// `config normalize` 的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormalizeOutcome {
+    /// 文件已是规范格式，未改动
+    Unchanged,
+    /// 已按规范格式重写，原文件备份在 `backup`
+    Rewritten { backup: PathBuf },
+}
+
+/// 导入 / 恢复的结果：导入前的备份 ID 与前后配置的变更
+#[derive(Debug, Clone)]
+pub struct RestoreOutcome {
+    /// 导入前创建的备份 ID（数据库不存在时为空）
+    pub backup_id: String,
+    pub changes: ConfigChangeSummary,
+}
+
 /// 配置导入导出相关业务逻辑
 pub struct ConfigService;
 
@@ -37,21 +66,23 @@ impl ConfigService {
     /// # 命名规则
     /// - 有自定义名称：`{custom_name}_{timestamp}.sql`
     /// - 无自定义名称：`backup_{timestamp}.sql`
+    /// - 同名文件已存在时在名称后追加序号：`{name}_2_{timestamp}.sql`
     pub fn create_backup(
         config_path: &Path,
         custom_name: Option<String>,
     ) -> Result<String, AppError> {
+        let custom_name = match custom_name {
+            Some(name) => Self::sanitize_backup_name(&name)?,
+            None => None,
+        };
+
         let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
         if !db_path.exists() {
             return Ok(String::new());
         }
 
         let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
-        let backup_id = if let Some(name) = custom_name {
-            format!("{}_{}", name, timestamp)
-        } else {
-            format!("backup_{}", timestamp)
-        };
+        let stem = custom_name.unwrap_or_else(|| "backup".to_string());
 
         let backup_dir = config_path
             .parent()
@@ -61,6 +92,13 @@ impl ConfigService {
 
         fs::create_dir_all(&backup_dir).map_err(|e| AppError::io(&backup_dir, e))?;
 
+        let mut backup_id = format!("{stem}_{timestamp}");
+        let mut counter = 2;
+        while backup_dir.join(format!("{backup_id}.sql")).exists() {
+            backup_id = format!("{stem}_{counter}_{timestamp}");
+            counter += 1;
+        }
+
         let backup_path = backup_dir.join(format!("{backup_id}.sql"));
         let db = Database::init()?;
         db.export_sql(&backup_path)?;
@@ -70,6 +108,35 @@ impl ConfigService {
         Ok(backup_id)
     }
 
+    /// 校验自定义备份名称：只允许字母、数字、`-`、`_`，最长 64 个字符；
+    /// 空白名称视为未指定
+    pub fn sanitize_backup_name(name: &str) -> Result<Option<String>, AppError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Ok(None);
+        }
+        if name.chars().count() > MAX_BACKUP_NAME_LEN {
+            return Err(AppError::localized(
+                "backup.name.too_long",
+                format!("备份名称过长（最多 {MAX_BACKUP_NAME_LEN} 个字符）"),
+                format!("Backup name is too long (max {MAX_BACKUP_NAME_LEN} characters)"),
+            ));
+        }
+        if let Some(bad) = name
+            .chars()
+            .find(|c| !(c.is_alphanumeric() || *c == '-' || *c == '_'))
+        {
+            return Err(AppError::localized(
+                "backup.name.invalid",
+                format!("备份名称包含非法字符 '{bad}'：仅支持字母、数字、短横线和下划线"),
+                format!(
+                    "Backup name contains invalid character '{bad}': only letters, digits, '-' and '_' are allowed"
+                ),
+            ));
+        }
+        Ok(Some(name.to_string()))
+    }
+
     /// 列出所有可用的备份
     pub fn list_backups(config_path: &Path) -> Result<Vec<BackupInfo>, AppError> {
         let backup_dir = config_path
@@ -118,7 +185,19 @@ impl ConfigService {
     }
 
     /// 根据备份 ID 恢复配置
-    pub fn restore_from_backup_id(backup_id: &str, state: &AppState) -> Result<String, AppError> {
+    pub fn restore_from_backup_id(
+        backup_id: &str,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
+        let result = Self::restore_from_backup_id_inner(backup_id, state);
+        audit::record("config.restore", None, Some(backup_id), &result);
+        result
+    }
+
+    fn restore_from_backup_id_inner(
+        backup_id: &str,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
         let config_path = crate::config::get_app_config_path();
         let backup_dir = config_path
             .parent()
@@ -131,7 +210,7 @@ impl ConfigService {
             return Err(AppError::Message(format!("备份文件不存在: {}", backup_id)));
         }
 
-        Self::import_config_from_path(&backup_path, state)
+        Self::import_config_from_path_inner(&backup_path, state)
     }
 
     /// 从文件名提取时间戳字符串
@@ -224,25 +303,228 @@ impl ConfigService {
         Ok(())
     }
 
+    /// 规范化 ~/.cc-switch/config.json：走常规加载流程（补齐默认字段、执行旧字段迁移），
+    /// 再以稳定的键顺序重新序列化；有变化时先备份原文件再写回。
+    pub fn normalize_config_file() -> Result<NormalizeOutcome, AppError> {
+        let result = Self::normalize_config_file_inner();
+        audit::record("config.normalize", None, None, &result);
+        result
+    }
+
+    fn normalize_config_file_inner() -> Result<NormalizeOutcome, AppError> {
+        let config_path = crate::config::get_app_config_path();
+        if !config_path.exists() {
+            return Err(AppError::localized(
+                "config.normalize.missing",
+                format!(
+                    "未找到 {}（当前配置保存在 cc-switch.db 中，无需规范化）",
+                    config_path.display()
+                ),
+                format!(
+                    "{} not found (the current configuration lives in cc-switch.db and needs no normalization)",
+                    config_path.display()
+                ),
+            ));
+        }
+
+        let original =
+            fs::read_to_string(&config_path).map_err(|e| AppError::io(&config_path, e))?;
+        let config = MultiAppConfig::load()?;
+        let canonical = Self::canonical_config_json(&config)?;
+        if original.trim_end() == canonical {
+            return Ok(NormalizeOutcome::Unchanged);
+        }
+
+        let timestamp = Utc::now().format("%Y%m%d_%H%M%S");
+        let mut backup = config_path.with_file_name(format!("config.json.{timestamp}.bak"));
+        let mut counter = 2;
+        while backup.exists() {
+            backup = config_path.with_file_name(format!("config.json.{timestamp}_{counter}.bak"));
+            counter += 1;
+        }
+        crate::config::write_text_file(&backup, &original)?;
+        crate::config::write_text_file(&config_path, &canonical)?;
+
+        Ok(NormalizeOutcome::Rewritten { backup })
+    }
+
+    /// 以 config.json 的规范格式导出当前配置，供外部编辑器修改
+    pub fn editable_config_json(state: &AppState) -> Result<String, AppError> {
+        let config = state.config.read().map_err(AppError::from)?;
+        Self::canonical_config_json(&config)
+    }
+
+    /// 应用外部编辑后的完整配置：解析与校验全部通过后才备份数据库并写入
+    pub fn apply_edited_config(
+        state: &AppState,
+        content: &str,
+    ) -> Result<RestoreOutcome, AppError> {
+        let result = Self::apply_edited_config_inner(state, content);
+        audit::record("config.edit", None, None, &result);
+        result
+    }
+
+    fn apply_edited_config_inner(
+        state: &AppState,
+        content: &str,
+    ) -> Result<RestoreOutcome, AppError> {
+        let edited: MultiAppConfig = serde_json::from_str(content).map_err(|e| {
+            AppError::localized(
+                "config.edit.invalid_json",
+                format!("编辑后的配置无效，未保存: {e}"),
+                format!("The edited config is invalid and was not saved: {e}"),
+            )
+        })?;
+        Self::validate_edited_config(&edited)?;
+
+        let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
+        let backup_id = Self::create_backup(&db_path, None)?;
+        let changes = {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            let changes = ConfigChangeSummary::between(&config, &edited);
+            *config = edited;
+            changes
+        };
+        state.save()?;
+        Ok(RestoreOutcome { backup_id, changes })
+    }
+
+    /// 只重置单个应用：备份数据库后清空该应用的供应商与当前选择，其他应用保持不变
+    pub fn reset_app(state: &AppState, app_type: &AppType) -> Result<RestoreOutcome, AppError> {
+        let result = Self::reset_app_inner(state, app_type);
+        audit::record("config.reset", Some(app_type), None, &result);
+        result
+    }
+
+    fn reset_app_inner(state: &AppState, app_type: &AppType) -> Result<RestoreOutcome, AppError> {
+        let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
+        let backup_id = Self::create_backup(&db_path, None)?;
+        let changes = {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            let before = config.clone();
+            if let Some(manager) = config.get_manager_mut(app_type) {
+                manager.providers.clear();
+                manager.current.clear();
+            }
+            ConfigChangeSummary::between(&before, &config)
+        };
+        state.save()?;
+        Ok(RestoreOutcome { backup_id, changes })
+    }
+
+    /// 供应商键名须与其 id 一致，当前供应商须存在
+    fn validate_edited_config(config: &MultiAppConfig) -> Result<(), AppError> {
+        for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+            let Some(manager) = config.get_manager(&app) else {
+                continue;
+            };
+            if let Some((key, provider)) = manager
+                .providers
+                .iter()
+                .find(|(key, provider)| **key != provider.id)
+            {
+                return Err(AppError::localized(
+                    "config.edit.id_mismatch",
+                    format!(
+                        "{} 供应商键名 {key} 与其 id {} 不一致，未保存",
+                        app.as_str(),
+                        provider.id
+                    ),
+                    format!(
+                        "{} provider key {key} does not match its id {}; nothing was saved",
+                        app.as_str(),
+                        provider.id
+                    ),
+                ));
+            }
+            let current = manager.current.trim();
+            if !current.is_empty() && !manager.providers.contains_key(current) {
+                return Err(AppError::localized(
+                    "config.edit.current_missing",
+                    format!("{} 的当前供应商 {current} 不存在，未保存", app.as_str()),
+                    format!(
+                        "Current {} provider {current} does not exist; nothing was saved",
+                        app.as_str()
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 规范格式：所有对象键按字典序排列，两空格缩进
+    fn canonical_config_json(config: &MultiAppConfig) -> Result<String, AppError> {
+        // serde_json::Map 默认基于 BTreeMap，转为 Value 即得到递归排序的键
+        let value =
+            serde_json::to_value(config).map_err(|e| AppError::JsonSerialize { source: e })?;
+        serde_json::to_string_pretty(&value).map_err(|e| AppError::JsonSerialize { source: e })
+    }
+
     /// 将当前 config.json 拷贝到目标路径。
     pub fn export_config_to_path(target_path: &Path) -> Result<(), AppError> {
         let db = Database::init()?;
         db.export_sql(target_path)
     }
 
-    pub fn import_config_from_path(file_path: &Path, state: &AppState) -> Result<String, AppError> {
+    /// 重置全部配置：备份数据库后删除并重建空数据库，返回备份 ID（无数据库时为空）
+    pub fn reset_all() -> Result<String, AppError> {
+        let result = Self::reset_all_inner();
+        audit::record("config.reset", None, None, &result);
+        result
+    }
+
+    fn reset_all_inner() -> Result<String, AppError> {
+        let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
+        let backup_id = Self::create_backup(&db_path, None)?;
+        if db_path.exists() {
+            fs::remove_file(&db_path).map_err(|e| AppError::io(&db_path, e))?;
+        }
+        let _ = Database::init()?;
+        Ok(backup_id)
+    }
+
+    pub fn import_config_from_path(
+        file_path: &Path,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
+        let result = Self::import_config_from_path_inner(file_path, state);
+        let source = file_path.display().to_string();
+        audit::record("config.import", None, Some(&source), &result);
+        result
+    }
+
+    fn import_config_from_path_inner(
+        file_path: &Path,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
         let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
         if !db_path.exists() {
             return Err(AppError::Config("数据库不存在，无法导入".to_string()));
         }
 
+        // Reject malformed or duplicate-id imports before touching anything.
+        Database::validate_import_file(file_path)?;
+
         // Pre-import backup (SQL).
         let backup_id = Self::create_backup(&db_path, None)?;
 
         // Import SQL into DB (also performs an internal binary snapshot backup).
-        state.db.import_sql(file_path)?;
+        Self::import_sql_with_changes(file_path, backup_id, state)
+    }
 
-        Ok(backup_id)
+    /// 导入 SQL 并对比导入前后的数据库内容，同时刷新内存中的配置
+    pub(crate) fn import_sql_with_changes(
+        sql_path: &Path,
+        backup_id: String,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
+        let before = crate::store::export_db_to_multi_app_config(&state.db)?;
+        state.db.import_sql(sql_path)?;
+        let after = crate::store::export_db_to_multi_app_config(&state.db)?;
+
+        let changes = ConfigChangeSummary::between(&before, &after);
+        *state.config.write().map_err(AppError::from)? = after;
+        Ok(RestoreOutcome { backup_id, changes })
     }
 
     /// 同步当前供应商到对应的 live 配置。
@@ -280,12 +562,24 @@ impl ConfigService {
             (current_id, provider)
         };
 
+        // live 中写入解析后的值，回填后再还原 `${env:NAME}` 占位符
+        let original = provider.settings_config.clone();
+        let mut provider = provider;
+        provider.settings_config = resolve_env_placeholders(&original)?;
+
         match app_type {
             AppType::Codex => Self::sync_codex_live(config, &current_id, &provider)?,
             AppType::Claude => Self::sync_claude_live(config, &current_id, &provider)?,
             AppType::Gemini => Self::sync_gemini_live(config, &current_id, &provider)?,
         }
 
+        if let Some(target) = config
+            .get_manager_mut(app_type)
+            .and_then(|manager| manager.providers.get_mut(&current_id))
+        {
+            restore_env_placeholders(&original, &mut target.settings_config);
+        }
+
         Ok(())
     }
 
@@ -337,11 +631,14 @@ impl ConfigService {
             fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
         }
 
-        write_json_file(&settings_path, &provider.settings_config)?;
+        let mut content = provider.settings_config.clone();
+        crate::services::provider::strip_headers(&mut content);
+        write_json_file(&settings_path, &content)?;
 
-        let live_after = read_json_file::<serde_json::Value>(&settings_path)?;
+        let mut live_after = read_json_file::<serde_json::Value>(&settings_path)?;
         if let Some(manager) = config.get_manager_mut(&AppType::Claude) {
             if let Some(target) = manager.providers.get_mut(provider_id) {
+                crate::services::provider::keep_headers(&target.settings_config, &mut live_after);
                 target.settings_config = live_after;
             }
         }
@@ -0,0 +1,364 @@
+use std::fs;
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tempfile::tempdir;
+use zip::write::SimpleFileOptions;
+
+use crate::config::atomic_write;
+use crate::database::Database;
+use crate::error::AppError;
+use crate::services::audit;
+use crate::services::config::{ConfigService, RestoreOutcome};
+use crate::services::webdav_sync::{restore_skills_zip, zip_skills_ssot};
+use crate::store::AppState;
+
+const ARCHIVE_FORMAT: &str = "cc-switch-archive";
+const ARCHIVE_VERSION: u32 = 1;
+const ARCHIVE_MANIFEST: &str = "manifest.json";
+const ARCHIVE_DB_SQL: &str = "db.sql";
+const ARCHIVE_SKILLS_ZIP: &str = "skills.zip";
+
+const ENCRYPTED_MAGIC: &[u8; 8] = b"CCSWENC1";
+const ZIP_MAGIC: &[u8; 4] = b"PK\x03\x04";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const PBKDF2_ITERATIONS: u32 = 310_000;
+/// 解密时接受的迭代次数上限，防止篡改的头部让密钥派生长时间占用 CPU
+const MAX_PBKDF2_ITERATIONS: u32 = PBKDF2_ITERATIONS * 10;
+
+/// 归档文件推荐扩展名
+pub const ARCHIVE_EXTENSION: &str = "ccs";
+
+/// 导入文件的类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveKind {
+    /// 未加密的 ZIP 归档
+    Plain,
+    /// 使用口令加密的归档
+    Encrypted,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ArchiveManifest {
+    format: String,
+    version: u32,
+    created_at: String,
+}
+
+pub struct ConfigArchiveService;
+
+impl ConfigArchiveService {
+    /// 将数据库与 Skills 目录打包为单个归档；提供口令时加密
+    pub fn export_to_path(target_path: &Path, passphrase: Option<&str>) -> Result<(), AppError> {
+        let archive = build_archive()?;
+        let bytes = match passphrase {
+            Some(passphrase) => encrypt(&archive, passphrase, PBKDF2_ITERATIONS)?,
+            None => archive,
+        };
+
+        if let Some(parent) = target_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        atomic_write(target_path, &bytes)
+    }
+
+    /// 识别文件是否为归档；返回 None 表示普通 SQL 导出文件
+    pub fn detect(path: &Path) -> Result<Option<ArchiveKind>, AppError> {
+        let mut file = fs::File::open(path).map_err(|e| AppError::io(path, e))?;
+        let mut head = [0u8; 8];
+        let mut read = 0;
+        while read < head.len() {
+            let n = file
+                .read(&mut head[read..])
+                .map_err(|e| AppError::io(path, e))?;
+            if n == 0 {
+                break;
+            }
+            read += n;
+        }
+        Ok(detect_bytes(&head[..read]))
+    }
+
+    /// 从归档恢复数据库与 Skills 目录，返回导入前的备份 ID 与配置变更
+    pub fn import_from_path(
+        source_path: &Path,
+        passphrase: Option<&str>,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
+        let result = Self::import_from_path_inner(source_path, passphrase, state);
+        audit::record(
+            "config.import",
+            None,
+            Some(&source_path.display().to_string()),
+            &result,
+        );
+        result
+    }
+
+    fn import_from_path_inner(
+        source_path: &Path,
+        passphrase: Option<&str>,
+        state: &AppState,
+    ) -> Result<RestoreOutcome, AppError> {
+        let raw = fs::read(source_path).map_err(|e| AppError::io(source_path, e))?;
+        let archive = match detect_bytes(&raw) {
+            Some(ArchiveKind::Plain) => raw,
+            Some(ArchiveKind::Encrypted) => {
+                let passphrase = passphrase.ok_or_else(|| {
+                    AppError::localized(
+                        "archive.passphrase_required",
+                        "该归档已加密，需要提供口令",
+                        "This archive is encrypted; a passphrase is required",
+                    )
+                })?;
+                decrypt(&raw, passphrase)?
+            }
+            None => {
+                return Err(AppError::localized(
+                    "archive.invalid",
+                    format!("不是有效的 cc-switch 归档: {}", source_path.display()),
+                    format!("Not a valid cc-switch archive: {}", source_path.display()),
+                ))
+            }
+        };
+
+        let (db_sql, skills_zip) = read_archive(&archive)?;
+
+        let tmp = tempdir().map_err(|e| AppError::IoContext {
+            context: "创建归档解压临时目录失败".to_string(),
+            source: e,
+        })?;
+        let sql_path = tmp.path().join(ARCHIVE_DB_SQL);
+        atomic_write(&sql_path, &db_sql)?;
+        Database::validate_import_file(&sql_path)?;
+
+        let db_path = crate::config::get_app_config_dir().join("cc-switch.db");
+        let backup_id = ConfigService::create_backup(&db_path, None)?;
+        let outcome = ConfigService::import_sql_with_changes(&sql_path, backup_id, state)?;
+
+        if let Some(skills_zip) = skills_zip {
+            restore_skills_zip(&skills_zip)?;
+        }
+
+        Ok(outcome)
+    }
+}
+
+fn detect_bytes(head: &[u8]) -> Option<ArchiveKind> {
+    if head.starts_with(ENCRYPTED_MAGIC) {
+        Some(ArchiveKind::Encrypted)
+    } else if head.starts_with(ZIP_MAGIC) {
+        Some(ArchiveKind::Plain)
+    } else {
+        None
+    }
+}
+
+fn build_archive() -> Result<Vec<u8>, AppError> {
+    let tmp = tempdir().map_err(|e| AppError::IoContext {
+        context: "创建归档临时目录失败".to_string(),
+        source: e,
+    })?;
+
+    let sql_path = tmp.path().join(ARCHIVE_DB_SQL);
+    ConfigService::export_config_to_path(&sql_path)?;
+    let db_sql = fs::read(&sql_path).map_err(|e| AppError::io(&sql_path, e))?;
+
+    let skills_path = tmp.path().join(ARCHIVE_SKILLS_ZIP);
+    zip_skills_ssot(&skills_path)?;
+    let skills_zip = fs::read(&skills_path).map_err(|e| AppError::io(&skills_path, e))?;
+
+    let manifest = ArchiveManifest {
+        format: ARCHIVE_FORMAT.to_string(),
+        version: ARCHIVE_VERSION,
+        created_at: Utc::now().to_rfc3339(),
+    };
+    let manifest_bytes =
+        serde_json::to_vec_pretty(&manifest).map_err(|e| AppError::JsonSerialize { source: e })?;
+
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (name, bytes) in [
+        (ARCHIVE_MANIFEST, manifest_bytes.as_slice()),
+        (ARCHIVE_DB_SQL, db_sql.as_slice()),
+        (ARCHIVE_SKILLS_ZIP, skills_zip.as_slice()),
+    ] {
+        writer
+            .start_file(name, options)
+            .map_err(|e| AppError::Message(format!("写入归档文件头失败: {e}")))?;
+        writer
+            .write_all(bytes)
+            .map_err(|e| AppError::Message(format!("写入归档内容失败: {e}")))?;
+    }
+    let cursor = writer
+        .finish()
+        .map_err(|e| AppError::Message(format!("写入归档失败: {e}")))?;
+    Ok(cursor.into_inner())
+}
+
+fn read_archive(raw: &[u8]) -> Result<(Vec<u8>, Option<Vec<u8>>), AppError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(raw))
+        .map_err(|e| AppError::Message(format!("解析归档失败: {e}")))?;
+
+    let manifest: ArchiveManifest =
+        serde_json::from_slice(&read_entry(&mut archive, ARCHIVE_MANIFEST)?).map_err(|e| {
+            AppError::Json {
+                path: ARCHIVE_MANIFEST.to_string(),
+                source: e,
+            }
+        })?;
+    if manifest.format != ARCHIVE_FORMAT || manifest.version > ARCHIVE_VERSION {
+        return Err(AppError::localized(
+            "archive.unsupported",
+            format!(
+                "不支持的归档格式: {} v{}",
+                manifest.format, manifest.version
+            ),
+            format!(
+                "Unsupported archive format: {} v{}",
+                manifest.format, manifest.version
+            ),
+        ));
+    }
+
+    let db_sql = read_entry(&mut archive, ARCHIVE_DB_SQL)?;
+    let skills_zip = if archive.index_for_name(ARCHIVE_SKILLS_ZIP).is_some() {
+        Some(read_entry(&mut archive, ARCHIVE_SKILLS_ZIP)?)
+    } else {
+        None
+    };
+    Ok((db_sql, skills_zip))
+}
+
+fn read_entry(
+    archive: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Result<Vec<u8>, AppError> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|e| AppError::Message(format!("归档缺少 {name}: {e}")))?;
+    let mut buf = Vec::new();
+    entry
+        .read_to_end(&mut buf)
+        .map_err(|e| AppError::Message(format!("读取归档项 {name} 失败: {e}")))?;
+    Ok(buf)
+}
+
+fn derive_key(passphrase: &str, salt: &[u8], iterations: u32) -> Key<Aes256Gcm> {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+    key.into()
+}
+
+/// 加密格式：MAGIC | 迭代次数(u32 LE) | salt | nonce | 密文
+fn encrypt(plain: &[u8], passphrase: &str, iterations: u32) -> Result<Vec<u8>, AppError> {
+    if passphrase.is_empty() {
+        return Err(AppError::localized(
+            "archive.passphrase_empty",
+            "口令不能为空",
+            "Passphrase cannot be empty",
+        ));
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, &salt, iterations));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plain)
+        .map_err(|e| AppError::Message(format!("加密归档失败: {e}")))?;
+
+    let mut out =
+        Vec::with_capacity(ENCRYPTED_MAGIC.len() + 4 + SALT_LEN + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(ENCRYPTED_MAGIC);
+    out.extend_from_slice(&iterations.to_le_bytes());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn decrypt(raw: &[u8], passphrase: &str) -> Result<Vec<u8>, AppError> {
+    let header_len = ENCRYPTED_MAGIC.len() + 4 + SALT_LEN + NONCE_LEN;
+    if raw.len() < header_len || !raw.starts_with(ENCRYPTED_MAGIC) {
+        return Err(AppError::localized(
+            "archive.corrupted",
+            "加密归档已损坏",
+            "The encrypted archive is corrupted",
+        ));
+    }
+
+    let mut offset = ENCRYPTED_MAGIC.len();
+    let mut iter_bytes = [0u8; 4];
+    iter_bytes.copy_from_slice(&raw[offset..offset + 4]);
+    let iterations = u32::from_le_bytes(iter_bytes);
+    if iterations == 0 || iterations > MAX_PBKDF2_ITERATIONS {
+        return Err(AppError::localized(
+            "archive.corrupted",
+            "加密归档已损坏",
+            "The encrypted archive is corrupted",
+        ));
+    }
+    offset += 4;
+    let salt = &raw[offset..offset + SALT_LEN];
+    offset += SALT_LEN;
+    let nonce = Nonce::from_slice(&raw[offset..offset + NONCE_LEN]);
+    offset += NONCE_LEN;
+
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, salt, iterations));
+    cipher.decrypt(nonce, &raw[offset..]).map_err(|_| {
+        AppError::localized(
+            "archive.decrypt_failed",
+            "解密失败：口令错误或归档已损坏",
+            "Decryption failed: wrong passphrase or corrupted archive",
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_round_trips_with_correct_passphrase() {
+        let plain = b"PK\x03\x04 archive payload";
+        let sealed = encrypt(plain, "secret", 1_000).expect("encrypt");
+        assert_eq!(detect_bytes(&sealed), Some(ArchiveKind::Encrypted));
+        assert_ne!(&sealed[sealed.len() - plain.len()..], plain.as_slice());
+
+        let opened = decrypt(&sealed, "secret").expect("decrypt");
+        assert_eq!(opened, plain);
+    }
+
+    #[test]
+    fn decrypt_rejects_wrong_passphrase() {
+        let sealed = encrypt(b"payload", "secret", 1_000).expect("encrypt");
+        assert!(decrypt(&sealed, "wrong").is_err());
+    }
+
+    #[test]
+    fn decrypt_rejects_excessive_iteration_count() {
+        let mut sealed = encrypt(b"payload", "secret", 1_000).expect("encrypt");
+        let offset = ENCRYPTED_MAGIC.len();
+        sealed[offset..offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        match decrypt(&sealed, "secret") {
+            Err(AppError::Localized { key, .. }) => assert_eq!(key, "archive.corrupted"),
+            other => panic!("expected corrupted archive error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn detect_bytes_distinguishes_sql_zip_and_encrypted() {
+        assert_eq!(detect_bytes(b"-- cc-switch"), None);
+        assert_eq!(detect_bytes(b"PK\x03\x04rest"), Some(ArchiveKind::Plain));
+        assert_eq!(detect_bytes(b"CCSWENC1rest"), Some(ArchiveKind::Encrypted));
+    }
+}
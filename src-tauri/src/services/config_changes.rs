@@ -0,0 +1,223 @@
+//! 比较两份 MultiAppConfig，汇总供应商 / MCP 服务器 / 提示词的增删改（用于导入、恢复后的变更说明）
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::Serialize;
+
+use crate::app_config::{AppType, MultiAppConfig};
+
+/// 变更涉及的配置类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeSection {
+    Provider,
+    Mcp,
+    Prompt,
+}
+
+impl ChangeSection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Provider => "provider",
+            Self::Mcp => "mcp",
+            Self::Prompt => "prompt",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// 单条变更；MCP 服务器为全局配置，`app` 为 None
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigChange {
+    pub section: ChangeSection,
+    pub app: Option<AppType>,
+    pub id: String,
+    pub kind: ChangeKind,
+}
+
+/// 两份配置之间的全部变更（按类别、应用、ID 排序）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigChangeSummary {
+    pub changes: Vec<ConfigChange>,
+}
+
+impl ConfigChangeSummary {
+    pub fn between(before: &MultiAppConfig, after: &MultiAppConfig) -> Self {
+        let mut changes = Vec::new();
+
+        for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+            let providers = |config: &MultiAppConfig| {
+                config
+                    .get_manager(&app)
+                    .map(|manager| serialize_entries(&manager.providers))
+                    .unwrap_or_default()
+            };
+            diff_entries(
+                &mut changes,
+                ChangeSection::Provider,
+                Some(&app),
+                providers(before),
+                providers(after),
+            );
+
+            let prompts = |config: &MultiAppConfig| {
+                let prompts = match app {
+                    AppType::Claude => &config.prompts.claude.prompts,
+                    AppType::Codex => &config.prompts.codex.prompts,
+                    AppType::Gemini => &config.prompts.gemini.prompts,
+                };
+                serialize_entries(prompts)
+            };
+            diff_entries(
+                &mut changes,
+                ChangeSection::Prompt,
+                Some(&app),
+                prompts(before),
+                prompts(after),
+            );
+        }
+
+        let servers = |config: &MultiAppConfig| {
+            config
+                .mcp
+                .servers
+                .as_ref()
+                .map(serialize_entries)
+                .unwrap_or_default()
+        };
+        diff_entries(
+            &mut changes,
+            ChangeSection::Mcp,
+            None,
+            servers(before),
+            servers(after),
+        );
+
+        changes.sort_by(|a, b| {
+            (a.section, a.app.as_ref().map(AppType::as_str), &a.id).cmp(&(
+                b.section,
+                b.app.as_ref().map(AppType::as_str),
+                &b.id,
+            ))
+        });
+        Self { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == kind)
+            .count()
+    }
+}
+
+fn serialize_entries<'a, T: Serialize + 'a>(
+    entries: impl IntoIterator<Item = (&'a String, &'a T)>,
+) -> HashMap<String, serde_json::Value> {
+    entries
+        .into_iter()
+        .map(|(id, entry)| {
+            (
+                id.clone(),
+                serde_json::to_value(entry).unwrap_or(serde_json::Value::Null),
+            )
+        })
+        .collect()
+}
+
+fn diff_entries(
+    changes: &mut Vec<ConfigChange>,
+    section: ChangeSection,
+    app: Option<&AppType>,
+    before: HashMap<String, serde_json::Value>,
+    after: HashMap<String, serde_json::Value>,
+) {
+    let ids: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    for id in ids {
+        let kind = match (before.get(id), after.get(id)) {
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Removed,
+            (Some(old), Some(new)) if old != new => ChangeKind::Modified,
+            _ => continue,
+        };
+        changes.push(ConfigChange {
+            section,
+            app: app.cloned(),
+            id: id.clone(),
+            kind,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::Provider;
+    use serde_json::json;
+
+    fn provider(id: &str, key: &str) -> Provider {
+        Provider::with_id(
+            id.to_string(),
+            id.to_string(),
+            json!({ "env": { "ANTHROPIC_AUTH_TOKEN": key } }),
+            None,
+        )
+    }
+
+    #[test]
+    fn between_reports_added_removed_and_modified_entries() {
+        let mut before = MultiAppConfig::default();
+        let manager = before.get_manager_mut(&AppType::Claude).expect("claude");
+        manager
+            .providers
+            .insert("kept".into(), provider("kept", "k"));
+        manager
+            .providers
+            .insert("edited".into(), provider("edited", "old"));
+        manager
+            .providers
+            .insert("gone".into(), provider("gone", "g"));
+
+        let mut after = before.clone();
+        let manager = after.get_manager_mut(&AppType::Claude).expect("claude");
+        manager.providers.shift_remove("gone");
+        manager
+            .providers
+            .insert("edited".into(), provider("edited", "new"));
+        manager
+            .providers
+            .insert("fresh".into(), provider("fresh", "f"));
+
+        let summary = ConfigChangeSummary::between(&before, &after);
+        let ids: Vec<(&str, ChangeKind)> = summary
+            .changes
+            .iter()
+            .map(|change| (change.id.as_str(), change.kind))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("edited", ChangeKind::Modified),
+                ("fresh", ChangeKind::Added),
+                ("gone", ChangeKind::Removed),
+            ]
+        );
+        assert!(summary
+            .changes
+            .iter()
+            .all(|change| change.section == ChangeSection::Provider
+                && change.app == Some(AppType::Claude)));
+        assert_eq!(summary.count(ChangeKind::Added), 1);
+
+        assert!(ConfigChangeSummary::between(&after, &after).is_empty());
+    }
+}
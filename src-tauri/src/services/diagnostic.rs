@@ -0,0 +1,134 @@
+//! 诊断报告：env check、skills doctor、config validate 等排障命令共用的结构，
+//! 人类可读输出与 `--json` 输出都基于它生成，方便支持工具统一解析。
+
+use serde::Serialize;
+
+/// 单项检查的结果级别（按严重程度排序）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticStatus {
+    Ok,
+    Warn,
+    Error,
+}
+
+/// 单项检查
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// 稳定的检查标识，如 `skills.ssot_dirs`
+    pub check: String,
+    /// 人类可读输出使用的本地化名称，未设置时显示检查标识；不出现在 JSON 中
+    #[serde(skip)]
+    pub label: Option<String>,
+    pub status: DiagnosticStatus,
+    /// 具体发现（路径、名称、错误信息等）
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+    /// 修复建议（通常是一条可直接执行的命令）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remediation: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(check: impl Into<String>, status: DiagnosticStatus) -> Self {
+        Self {
+            check: check.into(),
+            label: None,
+            status,
+            details: Vec::new(),
+            remediation: None,
+        }
+    }
+
+    pub fn ok(check: impl Into<String>) -> Self {
+        Self::new(check, DiagnosticStatus::Ok)
+    }
+
+    pub fn warn(check: impl Into<String>) -> Self {
+        Self::new(check, DiagnosticStatus::Warn)
+    }
+
+    pub fn error(check: impl Into<String>) -> Self {
+        Self::new(check, DiagnosticStatus::Error)
+    }
+
+    pub fn with_details<I, S>(mut self, details: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.details.extend(details.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_remediation(mut self, remediation: impl Into<String>) -> Self {
+        self.remediation = Some(remediation.into());
+        self
+    }
+}
+
+/// 服务层生成的说明文字，与 `AppError::Localized` 的显示方式一致：同时给出中英文
+pub(crate) fn localized_detail(zh: impl std::fmt::Display, en: impl std::fmt::Display) -> String {
+    format!("{zh} ({en})")
+}
+
+/// 一次诊断命令的完整报告；`status` 取所有检查中最严重的级别
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiagnosticReport {
+    pub command: String,
+    pub status: DiagnosticStatus,
+    pub checks: Vec<Diagnostic>,
+}
+
+impl DiagnosticReport {
+    pub fn new(command: impl Into<String>, checks: Vec<Diagnostic>) -> Self {
+        let status = checks
+            .iter()
+            .map(|check| check.status)
+            .max()
+            .unwrap_or(DiagnosticStatus::Ok);
+        Self {
+            command: command.into(),
+            status,
+            checks,
+        }
+    }
+
+    pub fn count(&self, status: DiagnosticStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_status_is_the_worst_check_and_serializes_compactly() {
+        let report = DiagnosticReport::new(
+            "skills doctor",
+            vec![
+                Diagnostic::ok("skills.ssot_dirs").with_label("SSOT directories"),
+                Diagnostic::warn("skills.app_sync")
+                    .with_details(["claude: demo"])
+                    .with_remediation("cc-switch skills sync"),
+            ],
+        );
+        assert_eq!(report.status, DiagnosticStatus::Warn);
+        assert_eq!(report.count(DiagnosticStatus::Warn), 1);
+
+        let value = serde_json::to_value(&report).expect("serialize report");
+        assert_eq!(value["status"], "warn");
+        assert!(value["checks"][0].get("details").is_none());
+        assert!(value["checks"][0].get("label").is_none());
+        assert_eq!(value["checks"][1]["remediation"], "cc-switch skills sync");
+
+        let empty = DiagnosticReport::new("env check", Vec::new());
+        assert_eq!(empty.status, DiagnosticStatus::Ok);
+    }
+}
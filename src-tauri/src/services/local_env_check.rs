@@ -24,24 +24,24 @@ pub struct ToolCheckResult {
     pub status: ToolCheckStatus,
 }
 
-pub fn check_local_environment() -> Vec<ToolCheckResult> {
-    const SPECS: &[(LocalTool, &str, &str, &[&str])] = &[
-        (
-            LocalTool::Claude,
-            "claude",
-            "Claude",
-            &["--version", "version"],
-        ),
-        (LocalTool::Codex, "codex", "Codex", &["--version"]),
-        (LocalTool::Gemini, "gemini", "Gemini", &["--version", "-v"]),
-        (
-            LocalTool::OpenCode,
-            "opencode",
-            "OpenCode",
-            &["--version", "version"],
-        ),
-    ];
+const SPECS: &[(LocalTool, &str, &str, &[&str])] = &[
+    (
+        LocalTool::Claude,
+        "claude",
+        "Claude",
+        &["--version", "version"],
+    ),
+    (LocalTool::Codex, "codex", "Codex", &["--version"]),
+    (LocalTool::Gemini, "gemini", "Gemini", &["--version", "-v"]),
+    (
+        LocalTool::OpenCode,
+        "opencode",
+        "OpenCode",
+        &["--version", "version"],
+    ),
+];
 
+pub fn check_local_environment() -> Vec<ToolCheckResult> {
     SPECS
         .iter()
         .map(|(tool, bin, display_name, args)| ToolCheckResult {
@@ -52,6 +52,19 @@ pub fn check_local_environment() -> Vec<ToolCheckResult> {
         .collect()
 }
 
+/// 只检测单个工具的版本
+pub fn check_tool(tool: LocalTool) -> ToolCheckResult {
+    let (tool, bin, display_name, args) = SPECS
+        .iter()
+        .find(|(candidate, ..)| *candidate == tool)
+        .expect("every LocalTool has a spec");
+    ToolCheckResult {
+        tool: *tool,
+        display_name,
+        status: check_tool_version(bin, args),
+    }
+}
+
 fn check_tool_version(bin: &str, version_args: &[&str]) -> ToolCheckStatus {
     if which::which(bin).is_err() {
         return ToolCheckStatus::NotInstalledOrNotExecutable;
@@ -1,13 +1,45 @@
 use std::collections::HashMap;
 
-use crate::app_config::{AppType, McpServer, MultiAppConfig};
+use crate::app_config::{AppType, McpApps, McpServer, McpTransport, MultiAppConfig};
 use crate::error::AppError;
-use crate::mcp;
+use crate::mcp::{self, ExternalMcpSource, McpImportSummary};
+use crate::provider::generate_provider_id;
 use crate::store::AppState;
 
+use super::audit;
+use super::provider::track_own_write;
+
 /// MCP 相关业务逻辑（v3.7.0 统一结构）
 pub struct McpService;
 
+/// 单个 MCP 服务器的检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct McpCommandCheck {
+    pub id: String,
+    pub name: String,
+    /// 该服务器已启用的应用
+    pub apps: Vec<AppType>,
+    pub transport: McpTransport,
+    /// stdio：在 PATH 中查找的可执行文件名；sse/http：服务器 url
+    pub target: String,
+    /// stdio：命令可在 PATH 中找到；sse/http：url 为合法的 http(s) 地址
+    pub ok: bool,
+}
+
+/// 批量设置启用应用的结果：失败的应用保持原状态
+#[derive(Debug, Default)]
+pub struct McpAppsUpdate {
+    pub succeeded: Vec<AppType>,
+    pub failed: Vec<(AppType, AppError)>,
+}
+
+/// 远程 MCP 服务器的 url 是否为合法的 http(s) 地址
+pub fn is_valid_mcp_url(raw: &str) -> bool {
+    url::Url::parse(raw.trim())
+        .map(|url| matches!(url.scheme(), "http" | "https") && url.host_str().is_some())
+        .unwrap_or(false)
+}
+
 impl McpService {
     /// 获取所有 MCP 服务器（统一结构）
     pub fn get_all_servers(state: &AppState) -> Result<HashMap<String, McpServer>, AppError> {
@@ -26,8 +58,63 @@ impl McpService {
         ))
     }
 
+    /// 批量检查 MCP 服务器（按 ID 排序）：stdio 检查命令是否在 PATH 中，
+    /// sse/http 检查 url 是否合法；`app` 为 Some 时只检查对该应用启用的服务器
+    pub fn check_commands(
+        state: &AppState,
+        app: Option<&AppType>,
+    ) -> Result<Vec<McpCommandCheck>, AppError> {
+        let mut servers: Vec<McpServer> = Self::get_all_servers(state)?
+            .into_values()
+            .filter(|server| app.is_none_or(|app| server.apps.is_enabled_for(app)))
+            .collect();
+        servers.sort_by(|a, b| a.id.cmp(&b.id));
+
+        Ok(servers
+            .into_iter()
+            .map(|server| {
+                let transport = server.transport();
+                let field = if transport.is_remote() {
+                    "url"
+                } else {
+                    "command"
+                };
+                let raw = server
+                    .server
+                    .get(field)
+                    .and_then(|value| value.as_str())
+                    .unwrap_or("");
+                let (target, ok) = if transport.is_remote() {
+                    (raw.trim().to_string(), is_valid_mcp_url(raw))
+                } else {
+                    // `command` 只是程序路径（参数在 `args` 中），路径本身可能含空格
+                    let command = raw.trim();
+                    (
+                        command.to_string(),
+                        !command.is_empty() && which::which(command).is_ok(),
+                    )
+                };
+                McpCommandCheck {
+                    apps: server.apps.enabled_apps(),
+                    id: server.id,
+                    name: server.name,
+                    transport,
+                    target,
+                    ok,
+                }
+            })
+            .collect())
+    }
+
     /// 添加或更新 MCP 服务器
     pub fn upsert_server(state: &AppState, server: McpServer) -> Result<(), AppError> {
+        let id = server.id.clone();
+        let result = Self::upsert_server_inner(state, server);
+        audit::record("mcp.upsert", None, Some(&id), &result);
+        result
+    }
+
+    fn upsert_server_inner(state: &AppState, server: McpServer) -> Result<(), AppError> {
         let (server_id, apps_to_remove) = {
             let mut cfg = state.config.write()?;
 
@@ -65,8 +152,52 @@ impl McpService {
         Ok(())
     }
 
+    /// 复制 MCP 服务器为新条目：ID 取新名称的 kebab 形式（默认 `<id>-copy`），
+    /// 默认不对任何应用启用，`keep_apps` 为 true 时沿用原服务器的启用状态
+    pub fn duplicate_server(
+        state: &AppState,
+        id: &str,
+        new_name: Option<&str>,
+        keep_apps: bool,
+    ) -> Result<McpServer, AppError> {
+        let servers = Self::get_all_servers(state)?;
+        let source = servers.get(id).ok_or_else(|| {
+            AppError::localized(
+                "mcp.not_found",
+                format!("MCP 服务器 '{id}' 不存在"),
+                format!("MCP server '{id}' not found"),
+            )
+        })?;
+
+        let new_name = new_name.map(str::trim).filter(|name| !name.is_empty());
+        let existing_ids: Vec<String> = servers.keys().cloned().collect();
+        let new_id = new_name
+            .map(|name| generate_provider_id(name, &existing_ids))
+            .filter(|new_id| !new_id.is_empty())
+            .unwrap_or_else(|| generate_provider_id(&format!("{id}-copy"), &existing_ids));
+
+        let mut server = source.clone();
+        server.id = new_id;
+        server.name = match new_name {
+            Some(name) => name.to_string(),
+            None => format!("{} (copy)", source.name),
+        };
+        if !keep_apps {
+            server.apps = McpApps::default();
+        }
+
+        Self::upsert_server(state, server.clone())?;
+        Ok(server)
+    }
+
     /// 删除 MCP 服务器
     pub fn delete_server(state: &AppState, id: &str) -> Result<bool, AppError> {
+        let result = Self::delete_server_inner(state, id);
+        audit::record("mcp.delete", None, Some(id), &result);
+        result
+    }
+
+    fn delete_server_inner(state: &AppState, id: &str) -> Result<bool, AppError> {
         let server = {
             let mut cfg = state.config.write()?;
 
@@ -88,12 +219,96 @@ impl McpService {
         }
     }
 
+    /// 按目标状态批量启用/停用各应用：逐个同步 live 配置（失败不中断），
+    /// 仅记录同步成功的应用并只保存一次
+    pub fn set_apps(
+        state: &AppState,
+        server_id: &str,
+        apps: &McpApps,
+    ) -> Result<McpAppsUpdate, AppError> {
+        let result = Self::set_apps_inner(state, server_id, apps);
+        audit::record("mcp.set_apps", None, Some(server_id), &result);
+        result
+    }
+
+    fn set_apps_inner(
+        state: &AppState,
+        server_id: &str,
+        apps: &McpApps,
+    ) -> Result<McpAppsUpdate, AppError> {
+        let server = state
+            .config
+            .read()?
+            .mcp
+            .servers
+            .as_ref()
+            .and_then(|servers| servers.get(server_id))
+            .cloned()
+            .ok_or_else(|| {
+                AppError::localized(
+                    "mcp.not_found",
+                    format!("MCP 服务器 '{server_id}' 不存在"),
+                    format!("MCP server '{server_id}' not found"),
+                )
+            })?;
+
+        let mut update = McpAppsUpdate::default();
+        for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+            let enabled = apps.is_enabled_for(&app);
+            if server.apps.is_enabled_for(&app) == enabled {
+                continue;
+            }
+            let result = if enabled {
+                Self::sync_server_to_app(state, &server, &app)
+            } else {
+                Self::remove_server_from_app(state, server_id, &app)
+            };
+            match result {
+                Ok(()) => update.succeeded.push(app),
+                Err(err) => {
+                    log::warn!("MCP 服务器 {server_id} 同步到 {} 失败: {err}", app.as_str());
+                    update.failed.push((app, err));
+                }
+            }
+        }
+
+        if !update.succeeded.is_empty() {
+            {
+                let mut cfg = state.config.write()?;
+                if let Some(target) = cfg
+                    .mcp
+                    .servers
+                    .as_mut()
+                    .and_then(|servers| servers.get_mut(server_id))
+                {
+                    for app in &update.succeeded {
+                        target.apps.set_enabled_for(app, apps.is_enabled_for(app));
+                    }
+                }
+            }
+            state.save()?;
+        }
+
+        Ok(update)
+    }
+
     /// 切换指定应用的启用状态
     pub fn toggle_app(
         state: &AppState,
         server_id: &str,
         app: AppType,
         enabled: bool,
+    ) -> Result<(), AppError> {
+        let result = Self::toggle_app_inner(state, server_id, app.clone(), enabled);
+        audit::record("mcp.toggle", Some(&app), Some(server_id), &result);
+        result
+    }
+
+    fn toggle_app_inner(
+        state: &AppState,
+        server_id: &str,
+        app: AppType,
+        enabled: bool,
     ) -> Result<(), AppError> {
         let server = {
             let mut cfg = state.config.write()?;
@@ -150,18 +365,12 @@ impl McpService {
         server: &McpServer,
         app: &AppType,
     ) -> Result<(), AppError> {
-        match app {
-            AppType::Claude => {
-                mcp::sync_single_server_to_claude(cfg, &server.id, &server.server)?;
-            }
-            AppType::Codex => {
-                mcp::sync_single_server_to_codex(cfg, &server.id, &server.server)?;
-            }
-            AppType::Gemini => {
-                mcp::sync_single_server_to_gemini(cfg, &server.id, &server.server)?;
-            }
-        }
-        Ok(())
+        // MCP 同步会改写 Codex config.toml / Gemini settings.json，需同步更新 live 文件哈希
+        track_own_write(app, || match app {
+            AppType::Claude => mcp::sync_single_server_to_claude(cfg, &server.id, &server.server),
+            AppType::Codex => mcp::sync_single_server_to_codex(cfg, &server.id, &server.server),
+            AppType::Gemini => mcp::sync_single_server_to_gemini(cfg, &server.id, &server.server),
+        })
     }
 
     /// 从所有曾启用过该服务器的应用中移除
@@ -178,12 +387,11 @@ impl McpService {
     }
 
     fn remove_server_from_app(_state: &AppState, id: &str, app: &AppType) -> Result<(), AppError> {
-        match app {
-            AppType::Claude => mcp::remove_server_from_claude(id)?,
-            AppType::Codex => mcp::remove_server_from_codex(id)?,
-            AppType::Gemini => mcp::remove_server_from_gemini(id)?,
-        }
-        Ok(())
+        track_own_write(app, || match app {
+            AppType::Claude => mcp::remove_server_from_claude(id),
+            AppType::Codex => mcp::remove_server_from_codex(id),
+            AppType::Gemini => mcp::remove_server_from_gemini(id),
+        })
     }
 
     /// 手动同步所有启用的 MCP 服务器到对应的应用
@@ -245,30 +453,73 @@ impl McpService {
         Ok(())
     }
 
-    /// 从 Claude 导入 MCP（v3.7.0 已更新为统一结构）
-    pub fn import_from_claude(state: &AppState) -> Result<usize, AppError> {
+    /// 从指定应用的 live 配置导入 MCP，返回新增与合并数量
+    ///
+    /// 与已有条目 command+args 相同的导入项只会合并 apps 标记，不会生成新条目。
+    pub fn import_from_app(state: &AppState, app: &AppType) -> Result<McpImportSummary, AppError> {
+        let result = Self::import_from_app_inner(state, app);
+        audit::record("mcp.import", Some(app), None, &result);
+        result
+    }
+
+    fn import_from_app_inner(
+        state: &AppState,
+        app: &AppType,
+    ) -> Result<McpImportSummary, AppError> {
         let mut cfg = state.config.write()?;
-        let count = mcp::import_from_claude(&mut cfg)?;
+        let summary = match app {
+            AppType::Claude => mcp::import_summary_from_claude(&mut cfg)?,
+            AppType::Codex => mcp::import_summary_from_codex(&mut cfg)?,
+            AppType::Gemini => mcp::import_summary_from_gemini(&mut cfg)?,
+        };
         drop(cfg);
         state.save()?;
-        Ok(count)
+        Ok(summary)
+    }
+
+    /// 从 Claude 导入 MCP（v3.7.0 已更新为统一结构）
+    pub fn import_from_claude(state: &AppState) -> Result<usize, AppError> {
+        Ok(Self::import_from_app(state, &AppType::Claude)?.total())
     }
 
     /// 从 Codex 导入 MCP（v3.7.0 已更新为统一结构）
     pub fn import_from_codex(state: &AppState) -> Result<usize, AppError> {
-        let mut cfg = state.config.write()?;
-        let count = mcp::import_from_codex(&mut cfg)?;
-        drop(cfg);
-        state.save()?;
-        Ok(count)
+        Ok(Self::import_from_app(state, &AppType::Codex)?.total())
     }
 
     /// 从 Gemini 导入 MCP（v3.7.0 已更新为统一结构）
     pub fn import_from_gemini(state: &AppState) -> Result<usize, AppError> {
+        Ok(Self::import_from_app(state, &AppType::Gemini)?.total())
+    }
+
+    /// 从 Cursor / Windsurf 导入 MCP，新服务器不启用任何应用
+    pub fn import_from_external(
+        state: &AppState,
+        source: ExternalMcpSource,
+    ) -> Result<McpImportSummary, AppError> {
+        let result = Self::import_from_external_inner(state, source);
+        audit::record("mcp.import", None, Some(source.as_str()), &result);
+        result
+    }
+
+    fn import_from_external_inner(
+        state: &AppState,
+        source: ExternalMcpSource,
+    ) -> Result<McpImportSummary, AppError> {
         let mut cfg = state.config.write()?;
-        let count = mcp::import_from_gemini(&mut cfg)?;
+        let summary = mcp::import_summary_from_external(&mut cfg, source)?;
         drop(cfg);
         state.save()?;
-        Ok(count)
+        Ok(summary)
+    }
+
+    /// 从 Cursor (~/.cursor/mcp.json) 导入 MCP
+    pub fn import_from_cursor(state: &AppState) -> Result<usize, AppError> {
+        Ok(Self::import_from_external(state, ExternalMcpSource::Cursor)?.total())
+    }
+
+    /// 从 Windsurf (~/.codeium/windsurf/mcp_config.json) 导入 MCP
+    pub fn import_from_windsurf(state: &AppState) -> Result<usize, AppError> {
+        Ok(Self::import_from_external(state, ExternalMcpSource::Windsurf)?.total())
     }
 }
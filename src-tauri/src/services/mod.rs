@@ -1,18 +1,42 @@
+pub mod app_compat;
+pub mod audit;
 pub mod config;
+pub mod config_archive;
+pub mod config_changes;
+pub mod diagnostic;
 pub mod env_checker;
 pub mod env_manager;
 pub mod local_env_check;
 pub mod mcp;
+pub mod profile;
 pub mod prompt;
 pub mod provider;
 pub mod skill;
+pub mod skill_bundle;
 pub mod speedtest;
 pub mod webdav_sync;
 
-pub use config::ConfigService;
-pub use mcp::McpService;
+pub use app_compat::check_app_compat;
+pub use audit::AuditEntry;
+pub use config::{ConfigService, NormalizeOutcome, RestoreOutcome};
+pub use config_archive::{ArchiveKind, ConfigArchiveService};
+pub use config_changes::{ChangeKind, ChangeSection, ConfigChange, ConfigChangeSummary};
+pub use diagnostic::{Diagnostic, DiagnosticReport, DiagnosticStatus};
+pub use mcp::{is_valid_mcp_url, McpAppsUpdate, McpCommandCheck, McpService};
+pub use profile::ProfileService;
 pub use prompt::PromptService;
-pub use provider::ProviderService;
-pub use skill::SkillService;
-pub use speedtest::{EndpointLatency, SpeedtestService};
+pub use provider::{
+    collect_expiry_warnings, expiry_status, expiry_warning_days, format_header_lines,
+    parse_expiry_date, parse_header_lines, parse_meta_assignment, provider_expiry_date,
+    provider_headers, rank_failover_candidates, set_provider_headers, ApiKeyPool, AuthScheme,
+    CommonSnippetPreview, ExpiryStatus, ExpiryWarning, FailoverCandidate, HealthStatus,
+    HealthTarget, LiveField, LiveFileMethod, LiveMismatch, LiveVerification, ProviderHealth,
+    ProviderService, ProviderValidation, SwitchOutcome,
+};
+pub use skill::{
+    DiscoverProgress, SkillRepo, SkillService, SkillSyncEntry, SkillSyncReport, SkillSyncStatus,
+    SyncMethod,
+};
+pub use skill_bundle::SkillBundleImport;
+pub use speedtest::{EndpointLatency, SpeedtestOptions, SpeedtestService};
 pub use webdav_sync::{SyncDecision, WebDavSyncService, WebDavSyncSummary};
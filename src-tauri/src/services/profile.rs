@@ -0,0 +1,196 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use crate::config::{get_default_app_config_dir, set_app_config_dir_override};
+use crate::error::AppError;
+use crate::services::audit;
+use crate::settings::{get_active_profile, set_active_profile};
+
+/// 默认配置档名称（对应 ~/.cc-switch 本身）
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// 当前进程实际生效的配置档（由 activate 设置）
+static EFFECTIVE_PROFILE: RwLock<Option<String>> = RwLock::new(None);
+
+pub struct ProfileService;
+
+impl ProfileService {
+    /// 配置档根目录 (~/.cc-switch/profiles)
+    pub fn profiles_dir() -> PathBuf {
+        get_default_app_config_dir().join("profiles")
+    }
+
+    /// 配置档对应的配置目录
+    pub fn profile_dir(name: &str) -> Result<PathBuf, AppError> {
+        if name == DEFAULT_PROFILE {
+            return Ok(get_default_app_config_dir());
+        }
+        Self::validate_name(name)?;
+        Ok(Self::profiles_dir().join(name))
+    }
+
+    /// 列出所有配置档（默认配置档总是排在第一位）
+    pub fn list() -> Result<Vec<String>, AppError> {
+        let mut names = Vec::new();
+        let dir = Self::profiles_dir();
+        if dir.exists() {
+            for entry in fs::read_dir(&dir).map_err(|e| AppError::io(&dir, e))? {
+                let entry = entry.map_err(|e| AppError::io(&dir, e))?;
+                if !entry.path().is_dir() {
+                    continue;
+                }
+                if let Some(name) = entry.file_name().to_str() {
+                    if Self::validate_name(name).is_ok() {
+                        names.push(name.to_string());
+                    }
+                }
+            }
+        }
+        names.sort();
+        names.insert(0, DEFAULT_PROFILE.to_string());
+        Ok(names)
+    }
+
+    pub fn exists(name: &str) -> bool {
+        name == DEFAULT_PROFILE
+            || Self::profile_dir(name)
+                .map(|dir| dir.is_dir())
+                .unwrap_or(false)
+    }
+
+    pub fn create(name: &str) -> Result<PathBuf, AppError> {
+        let result = Self::create_inner(name);
+        audit::record("profile.create", None, Some(name), &result);
+        result
+    }
+
+    fn create_inner(name: &str) -> Result<PathBuf, AppError> {
+        Self::validate_name(name)?;
+        let dir = Self::profile_dir(name)?;
+        if dir.exists() {
+            return Err(AppError::localized(
+                "profile.exists",
+                format!("配置档已存在: {name}"),
+                format!("Profile already exists: {name}"),
+            ));
+        }
+        fs::create_dir_all(&dir).map_err(|e| AppError::io(&dir, e))?;
+        Ok(dir)
+    }
+
+    /// 删除配置档及其全部数据；不允许删除默认或当前激活的配置档
+    pub fn delete(name: &str) -> Result<(), AppError> {
+        let result = Self::delete_inner(name);
+        audit::record("profile.delete", None, Some(name), &result);
+        result
+    }
+
+    fn delete_inner(name: &str) -> Result<(), AppError> {
+        Self::validate_name(name)?;
+        if get_active_profile().as_deref() == Some(name) || Self::current() == name {
+            return Err(AppError::localized(
+                "profile.delete.active",
+                format!("不能删除当前激活的配置档: {name}，请先切换到其他配置档"),
+                format!(
+                    "Cannot delete the active profile: {name}; switch to another profile first"
+                ),
+            ));
+        }
+        let dir = Self::profile_dir(name)?;
+        if !dir.is_dir() {
+            return Err(Self::not_found(name));
+        }
+        fs::remove_dir_all(&dir).map_err(|e| AppError::io(&dir, e))
+    }
+
+    /// 持久化的激活配置档（未设置时为默认配置档）
+    pub fn active() -> String {
+        get_active_profile().unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+    }
+
+    /// 当前进程生效的配置档（含 `--profile` 覆盖）
+    pub fn current() -> String {
+        EFFECTIVE_PROFILE
+            .read()
+            .ok()
+            .and_then(|guard| guard.clone())
+            .unwrap_or_else(Self::active)
+    }
+
+    /// 持久化激活配置档
+    pub fn set_active(name: &str) -> Result<(), AppError> {
+        let result = Self::set_active_inner(name);
+        audit::record("profile.use", None, Some(name), &result);
+        result
+    }
+
+    fn set_active_inner(name: &str) -> Result<(), AppError> {
+        if !Self::exists(name) {
+            return Err(Self::not_found(name));
+        }
+        let value = (name != DEFAULT_PROFILE).then(|| name.to_string());
+        set_active_profile(value)
+    }
+
+    /// 启动时解析配置档并设置配置目录覆盖，返回生效的配置档名称
+    ///
+    /// 优先级：`--profile` 参数 > 持久化的激活配置档 > 默认配置档
+    pub fn activate(requested: Option<&str>) -> Result<String, AppError> {
+        let name = match requested {
+            Some(name) => {
+                if !Self::exists(name) {
+                    return Err(Self::not_found(name));
+                }
+                name.to_string()
+            }
+            None => {
+                let persisted = Self::active();
+                if Self::exists(&persisted) {
+                    persisted
+                } else {
+                    log::warn!("激活的配置档 {persisted} 不存在，回退到默认配置档");
+                    DEFAULT_PROFILE.to_string()
+                }
+            }
+        };
+
+        let override_dir = if name == DEFAULT_PROFILE {
+            None
+        } else {
+            Some(Self::profile_dir(&name)?)
+        };
+        set_app_config_dir_override(override_dir);
+        if let Ok(mut guard) = EFFECTIVE_PROFILE.write() {
+            *guard = Some(name.clone());
+        }
+        Ok(name)
+    }
+
+    fn validate_name(name: &str) -> Result<(), AppError> {
+        let valid = !name.is_empty()
+            && name != DEFAULT_PROFILE
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if valid {
+            Ok(())
+        } else {
+            Err(AppError::localized(
+                "profile.invalid_name",
+                format!("无效的配置档名称: {name}（仅支持字母、数字、- 和 _，且不能为 default）"),
+                format!(
+                    "Invalid profile name: {name} (use letters, digits, '-' or '_'; 'default' is reserved)"
+                ),
+            ))
+        }
+    }
+
+    fn not_found(name: &str) -> AppError {
+        AppError::localized(
+            "profile.not_found",
+            format!("配置档不存在: {name}（可使用 `cc-switch profile create {name}` 创建）"),
+            format!("Profile not found: {name} (create it with `cc-switch profile create {name}`)"),
+        )
+    }
+}
@@ -5,6 +5,7 @@ use crate::config::write_text_file;
 use crate::error::AppError;
 use crate::prompt::Prompt;
 use crate::prompt_files::prompt_file_path;
+use crate::services::audit;
 use crate::store::AppState;
 
 pub struct PromptService;
@@ -28,6 +29,17 @@ impl PromptService {
         app: AppType,
         id: &str,
         prompt: Prompt,
+    ) -> Result<(), AppError> {
+        let result = Self::upsert_prompt_inner(state, app.clone(), id, prompt);
+        audit::record("prompt.upsert", Some(&app), Some(id), &result);
+        result
+    }
+
+    fn upsert_prompt_inner(
+        state: &AppState,
+        app: AppType,
+        id: &str,
+        prompt: Prompt,
     ) -> Result<(), AppError> {
         // 检查是否为已启用的提示词
         let is_enabled = prompt.enabled;
@@ -38,20 +50,27 @@ impl PromptService {
             AppType::Codex => &mut cfg.prompts.codex.prompts,
             AppType::Gemini => &mut cfg.prompts.gemini.prompts,
         };
-        prompts.insert(id.to_string(), prompt.clone());
+        prompts.insert(id.to_string(), prompt);
+        let live_content = Self::live_content(prompts);
         drop(cfg);
         state.save()?;
 
         // 如果是已启用的提示词，同步更新到对应的文件
         if is_enabled {
             let target_path = prompt_file_path(&app)?;
-            write_text_file(&target_path, &prompt.content)?;
+            write_text_file(&target_path, &live_content)?;
         }
 
         Ok(())
     }
 
     pub fn delete_prompt(state: &AppState, app: AppType, id: &str) -> Result<(), AppError> {
+        let result = Self::delete_prompt_inner(state, app.clone(), id);
+        audit::record("prompt.delete", Some(&app), Some(id), &result);
+        result
+    }
+
+    fn delete_prompt_inner(state: &AppState, app: AppType, id: &str) -> Result<(), AppError> {
         let mut cfg = state.config.write()?;
         let prompts = match app {
             AppType::Claude => &mut cfg.prompts.claude.prompts,
@@ -71,7 +90,51 @@ impl PromptService {
         Ok(())
     }
 
+    /// 已启用的提示词，按 `updated_at` 升序（相同时按 ID）排列，即写入 live 文件的顺序
+    pub fn active_prompts(prompts: &HashMap<String, Prompt>) -> Vec<(&String, &Prompt)> {
+        let mut enabled: Vec<(&String, &Prompt)> =
+            prompts.iter().filter(|(_, p)| p.enabled).collect();
+        enabled.sort_by_key(|(id, p)| (p.updated_at.unwrap_or(0), *id));
+        enabled
+    }
+
+    /// 已启用提示词写入 live 文件的内容：按 [`Self::active_prompts`] 的顺序拼接，
+    /// 各段之间以空行分隔；没有启用项时为空
+    pub fn live_content(prompts: &HashMap<String, Prompt>) -> String {
+        let mut content = String::new();
+        for (_, prompt) in Self::active_prompts(prompts) {
+            if !content.is_empty() {
+                content.truncate(content.trim_end().len());
+                content.push_str("\n\n");
+            }
+            content.push_str(&prompt.content);
+        }
+        content
+    }
+
+    /// 独占启用：停用同一应用下的其他提示词
     pub fn enable_prompt(state: &AppState, app: AppType, id: &str) -> Result<(), AppError> {
+        Self::enable_prompt_with(state, app, id, true)
+    }
+
+    /// 启用提示词；`exclusive` 为 false 时保留其他已启用项，live 文件为它们的拼接
+    pub fn enable_prompt_with(
+        state: &AppState,
+        app: AppType,
+        id: &str,
+        exclusive: bool,
+    ) -> Result<(), AppError> {
+        let result = Self::enable_prompt_with_inner(state, app.clone(), id, exclusive);
+        audit::record("prompt.enable", Some(&app), Some(id), &result);
+        result
+    }
+
+    fn enable_prompt_with_inner(
+        state: &AppState,
+        app: AppType,
+        id: &str,
+        exclusive: bool,
+    ) -> Result<(), AppError> {
         // 回填当前 live 文件内容到已启用的提示词，或创建备份
         let target_path = prompt_file_path(&app)?;
         if target_path.exists() {
@@ -84,10 +147,16 @@ impl PromptService {
                         AppType::Gemini => &mut cfg.prompts.gemini.prompts,
                     };
 
-                    // 尝试回填到当前已启用的提示词
-                    if let Some((enabled_id, enabled_prompt)) = prompts
+                    // 仅有一个已启用项时才能回填；多个启用项时 live 文件是拼接结果，
+                    // 与之不一致时按无启用项处理（创建备份）
+                    let enabled_count = prompts.values().filter(|p| p.enabled).count();
+                    let live_matches = Self::live_content(prompts).trim() == live_content.trim();
+                    if enabled_count > 1 && live_matches {
+                        drop(cfg);
+                    } else if let Some((enabled_id, enabled_prompt)) = prompts
                         .iter_mut()
-                        .find(|(_, p)| p.enabled)
+                        .find(|(_, p)| p.enabled && p.content.trim() != live_content.trim())
+                        .filter(|_| enabled_count == 1)
                         .map(|(id, p)| (id.clone(), p))
                     {
                         let timestamp = std::time::SystemTime::now()
@@ -143,16 +212,17 @@ impl PromptService {
             AppType::Gemini => &mut cfg.prompts.gemini.prompts,
         };
 
-        for prompt in prompts.values_mut() {
-            prompt.enabled = false;
-        }
-
-        if let Some(prompt) = prompts.get_mut(id) {
-            prompt.enabled = true;
-            write_text_file(&target_path, &prompt.content)?; // 原子写入
-        } else {
+        if !prompts.contains_key(id) {
             return Err(AppError::InvalidInput(format!("提示词 {id} 不存在")));
         }
+        for (prompt_id, prompt) in prompts.iter_mut() {
+            if prompt_id == id {
+                prompt.enabled = true;
+            } else if exclusive {
+                prompt.enabled = false;
+            }
+        }
+        write_text_file(&target_path, &Self::live_content(prompts))?; // 原子写入
 
         drop(cfg);
         state.save()?; // 第二次保存：启用目标提示词并写入文件后
@@ -160,6 +230,12 @@ impl PromptService {
     }
 
     pub fn disable_prompt(state: &AppState, app: AppType, id: &str) -> Result<(), AppError> {
+        let result = Self::disable_prompt_inner(state, app.clone(), id);
+        audit::record("prompt.disable", Some(&app), Some(id), &result);
+        result
+    }
+
+    fn disable_prompt_inner(state: &AppState, app: AppType, id: &str) -> Result<(), AppError> {
         let mut cfg = state.config.write()?;
         let prompts = match app {
             AppType::Claude => &mut cfg.prompts.claude.prompts,
@@ -176,18 +252,25 @@ impl PromptService {
         } else {
             return Err(AppError::InvalidInput(format!("提示词 {} 不存在", id)));
         }
+        let live_content = Self::live_content(prompts);
 
         drop(cfg);
         state.save()?;
 
-        // 清空对应的实时文件
+        // 写入剩余启用项（没有时清空对应的实时文件）
         let target_path = prompt_file_path(&app)?;
-        write_text_file(&target_path, "")?;
+        write_text_file(&target_path, &live_content)?;
 
         Ok(())
     }
 
     pub fn import_from_file(state: &AppState, app: AppType) -> Result<String, AppError> {
+        let result = Self::import_from_file_inner(state, app.clone());
+        audit::record("prompt.import", Some(&app), None, &result);
+        result
+    }
+
+    fn import_from_file_inner(state: &AppState, app: AppType) -> Result<String, AppError> {
         let file_path = prompt_file_path(&app)?;
 
         if !file_path.exists() {
@@ -0,0 +1,119 @@
+//! 通用配置片段预览：按切换时的合并规则，计算片段与当前供应商配置合并后的结果（不写入任何文件）
+
+use serde_json::Value;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::{merge_json_values, ProviderService};
+
+/// 片段合并到当前供应商后的预览
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommonSnippetPreview {
+    pub provider_id: String,
+    /// 供应商是否启用通用配置（关闭时切换不会合并片段）
+    pub applies: bool,
+    /// 不含片段时的配置，格式与 `merged` 相同，用于对比
+    pub base: String,
+    /// 合并结果：Codex 为 config.toml 文本，其余为格式化的 JSON
+    pub merged: String,
+}
+
+impl ProviderService {
+    /// 预览片段与当前供应商 `settings_config` 的合并结果；无当前供应商时返回 None
+    pub fn preview_common_snippet(
+        state: &AppState,
+        app_type: AppType,
+        snippet: &str,
+    ) -> Result<Option<CommonSnippetPreview>, AppError> {
+        let current_id = Self::current(state, app_type.clone())?;
+        if current_id.trim().is_empty() {
+            return Ok(None);
+        }
+        let provider = {
+            let config = state.config.read().map_err(AppError::from)?;
+            config
+                .get_manager(&app_type)
+                .and_then(|manager| manager.providers.get(&current_id))
+                .cloned()
+        };
+        let Some(provider) = provider else {
+            return Ok(None);
+        };
+
+        let applies = provider
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.apply_common_config)
+            .unwrap_or(true);
+        // 用空片段走同一套合并流程，保证两侧格式一致，diff 只反映片段带来的变化
+        let empty = if matches!(app_type, AppType::Codex) {
+            ""
+        } else {
+            "{}"
+        };
+        let base = Self::render_common_merge(&app_type, &provider.settings_config, empty)?;
+        let merged = Self::render_common_merge(&app_type, &provider.settings_config, snippet)?;
+
+        Ok(Some(CommonSnippetPreview {
+            provider_id: current_id,
+            applies,
+            base,
+            merged,
+        }))
+    }
+
+    /// 预览已保存的片段（未设置时视为空片段）
+    pub fn preview_saved_common_snippet(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Option<CommonSnippetPreview>, AppError> {
+        let snippet = {
+            let config = state.config.read().map_err(AppError::from)?;
+            config.common_config_snippets.get(&app_type).cloned()
+        };
+        let snippet = match snippet {
+            Some(snippet) if !snippet.trim().is_empty() => snippet,
+            _ if matches!(app_type, AppType::Codex) => String::new(),
+            _ => "{}".to_string(),
+        };
+        Self::preview_common_snippet(state, app_type, &snippet)
+    }
+
+    fn render_common_merge(
+        app_type: &AppType,
+        settings: &Value,
+        snippet: &str,
+    ) -> Result<String, AppError> {
+        let merged = match app_type {
+            AppType::Codex => return Self::merge_codex_common_preview(settings, snippet),
+            AppType::Claude => Self::build_claude_live_content(settings, Some(snippet))?,
+            AppType::Gemini => {
+                let mut merged = Self::parse_common_gemini_config_snippet(snippet)?;
+                merge_json_values(&mut merged, settings);
+                merged
+            }
+        };
+        serde_json::to_string_pretty(&merged).map_err(|e| AppError::JsonSerialize { source: e })
+    }
+
+    /// 供应商自身的 config 优先，片段补齐其余字段
+    fn merge_codex_common_preview(settings: &Value, snippet: &str) -> Result<String, AppError> {
+        let mut doc = snippet.parse::<toml_edit::DocumentMut>().map_err(|e| {
+            AppError::localized(
+                "common_config.codex.invalid_toml",
+                format!("Codex 通用配置片段不是有效的 TOML：{e}"),
+                format!("Codex common config snippet is not valid TOML: {e}"),
+            )
+        })?;
+        let provider_text = settings.get("config").and_then(Value::as_str).unwrap_or("");
+        if !provider_text.trim().is_empty() {
+            let provider_doc = provider_text
+                .parse::<toml_edit::DocumentMut>()
+                .map_err(|e| AppError::Config(format!("解析供应商 config TOML 失败: {e}")))?;
+            Self::merge_toml_tables(doc.as_table_mut(), provider_doc.as_table());
+        }
+        Ok(doc.to_string().trim().to_string())
+    }
+}
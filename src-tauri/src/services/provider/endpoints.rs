@@ -56,13 +56,10 @@ impl ProviderService {
             let manager = cfg
                 .get_manager_mut(&app_type)
                 .ok_or_else(|| Self::app_not_found(&app_type))?;
-            let provider = manager.providers.get_mut(provider_id).ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            let provider = manager
+                .providers
+                .get_mut(provider_id)
+                .ok_or_else(|| Self::provider_not_found(provider_id))?;
             let meta = provider.meta.get_or_insert_with(ProviderMeta::default);
 
             let endpoint = CustomEndpoint {
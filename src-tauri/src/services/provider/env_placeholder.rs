@@ -0,0 +1,209 @@
+//! 供应商配置中的环境变量占位符（`${env:NAME}`）
+//!
+//! 占位符只保存在 CC-Switch 的配置中；写入 live 配置时解析为环境变量的值，
+//! 从 live 配置回填时再还原为占位符，保证 config.json 中不出现明文密钥。
+
+use serde_json::Value;
+
+use crate::error::AppError;
+
+const PLACEHOLDER_PREFIX: &str = "${env:";
+
+/// 解析字符串中的全部占位符，返回 (原文, 变量名) 列表
+fn find_placeholders(text: &str) -> Vec<(&str, &str)> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(PLACEHOLDER_PREFIX) {
+        let after = &rest[start + PLACEHOLDER_PREFIX.len()..];
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let name = &after[..end];
+        let valid = !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        let token_len = PLACEHOLDER_PREFIX.len() + end + 1;
+        if valid {
+            found.push((&rest[start..start + token_len], name));
+        }
+        rest = &rest[start + token_len..];
+    }
+    found
+}
+
+fn lookup_env(name: &str) -> Result<String, AppError> {
+    match std::env::var(name) {
+        Ok(value) if !value.is_empty() => Ok(value),
+        _ => Err(AppError::localized(
+            "provider.env_placeholder.unset",
+            format!("供应商配置引用的环境变量 {name} 未设置或为空（占位符 ${{env:{name}}}）"),
+            format!(
+                "Environment variable {name} referenced by the provider config is not set or empty (placeholder ${{env:{name}}})"
+            ),
+        )),
+    }
+}
+
+fn resolve_str(text: &str) -> Result<String, AppError> {
+    let mut resolved = text.to_string();
+    for (token, name) in find_placeholders(text) {
+        resolved = resolved.replace(token, &lookup_env(name)?);
+    }
+    Ok(resolved)
+}
+
+/// 将配置中所有 `${env:NAME}` 占位符替换为环境变量的值；变量未设置时报错
+pub(crate) fn resolve_env_placeholders(value: &Value) -> Result<Value, AppError> {
+    Ok(match value {
+        Value::String(text) => Value::String(resolve_str(text)?),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(resolve_env_placeholders)
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, item)| Ok((key.clone(), resolve_env_placeholders(item)?)))
+                .collect::<Result<_, AppError>>()?,
+        ),
+        other => other.clone(),
+    })
+}
+
+fn is_toml_section(line: &str) -> bool {
+    line.trim_start().starts_with('[')
+}
+
+/// 还原单个字符串：单行时整体比较；多行（如 Codex 的 config.toml）按 `[section]` + key 定位到行，
+/// 仅当该行仍等于占位符解析后的结果时才还原，不会改动其他位置碰巧相同的文本
+fn restore_str(template: &str, text: &str) -> Option<String> {
+    if find_placeholders(template).is_empty() {
+        return None;
+    }
+    if !template.contains('\n') {
+        return (resolve_str(template).ok()? == text).then(|| template.to_string());
+    }
+
+    let mut templates = std::collections::HashMap::new();
+    let mut section = "";
+    for line in template.lines() {
+        if is_toml_section(line) {
+            section = line.trim();
+        } else if !find_placeholders(line).is_empty() {
+            if let Some((key, _)) = line.split_once('=') {
+                templates.insert((section, key.trim()), line);
+            }
+        }
+    }
+
+    let mut section = "";
+    let mut changed = false;
+    let mut lines = Vec::new();
+    for line in text.split('\n') {
+        if is_toml_section(line) {
+            section = line.trim();
+        }
+        let template_line = line
+            .split_once('=')
+            .and_then(|(key, _)| templates.get(&(section, key.trim())))
+            .filter(|template_line| resolve_str(template_line).is_ok_and(|r| r == line));
+        match template_line {
+            Some(template_line) => {
+                lines.push(*template_line);
+                changed = true;
+            }
+            None => lines.push(line),
+        }
+    }
+    changed.then(|| lines.join("\n"))
+}
+
+/// 按原配置中的占位符位置，把回填的 live 值中的环境变量值还原为占位符
+pub(crate) fn restore_env_placeholders(original: &Value, live: &mut Value) {
+    match (original, live) {
+        (Value::String(template), Value::String(text)) => {
+            if let Some(restored) = restore_str(template, text) {
+                *text = restored;
+            }
+        }
+        (Value::Array(templates), Value::Array(items)) => {
+            for (template, item) in templates.iter().zip(items.iter_mut()) {
+                restore_env_placeholders(template, item);
+            }
+        }
+        (Value::Object(templates), Value::Object(items)) => {
+            for (key, template) in templates {
+                if let Some(item) = items.get_mut(key) {
+                    restore_env_placeholders(template, item);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn find_placeholders_skips_invalid_names() {
+        let found = find_placeholders("a ${env:KEY_1} b ${env:} ${env:1X} ${env:OTHER}");
+        assert_eq!(
+            found,
+            vec![("${env:KEY_1}", "KEY_1"), ("${env:OTHER}", "OTHER")]
+        );
+    }
+
+    #[test]
+    fn resolve_and_restore_round_trip() {
+        std::env::set_var("CC_SWITCH_TEST_PLACEHOLDER_KEY", "sk-from-env");
+        let original = json!({
+            "env": { "ANTHROPIC_AUTH_TOKEN": "${env:CC_SWITCH_TEST_PLACEHOLDER_KEY}" },
+            "config": "api_key = \"${env:CC_SWITCH_TEST_PLACEHOLDER_KEY}\"\n"
+        });
+
+        let mut resolved = resolve_env_placeholders(&original).expect("resolve");
+        assert_eq!(resolved["env"]["ANTHROPIC_AUTH_TOKEN"], "sk-from-env");
+        assert_eq!(resolved["config"], "api_key = \"sk-from-env\"\n");
+
+        restore_env_placeholders(&original, &mut resolved);
+        assert_eq!(resolved, original);
+        std::env::remove_var("CC_SWITCH_TEST_PLACEHOLDER_KEY");
+    }
+
+    #[test]
+    fn restore_only_touches_the_placeholder_key() {
+        std::env::set_var("CC_SWITCH_TEST_PLACEHOLDER_SHORT", "1");
+        let original = json!({
+            "config": "[model_providers.relay]\nenv_key = \"${env:CC_SWITCH_TEST_PLACEHOLDER_SHORT}\"\nretries = 1\n",
+            "env": { "TOKEN": "${env:CC_SWITCH_TEST_PLACEHOLDER_SHORT}", "LEVEL": "1" }
+        });
+        let mut live = resolve_env_placeholders(&original).expect("resolve");
+        restore_env_placeholders(&original, &mut live);
+        assert_eq!(live, original);
+
+        // live 中手动改过的行保留新值
+        let mut edited = json!({
+            "config": "[model_providers.relay]\nenv_key = \"2\"\nretries = 1\n",
+            "env": { "TOKEN": "2", "LEVEL": "1" }
+        });
+        let expected = edited.clone();
+        restore_env_placeholders(&original, &mut edited);
+        assert_eq!(edited, expected);
+        std::env::remove_var("CC_SWITCH_TEST_PLACEHOLDER_SHORT");
+    }
+
+    #[test]
+    fn resolve_errors_when_variable_is_unset() {
+        let err = resolve_env_placeholders(&json!({
+            "key": "${env:CC_SWITCH_TEST_PLACEHOLDER_MISSING}"
+        }))
+        .expect_err("unset variable should fail");
+        assert!(err
+            .to_string()
+            .contains("CC_SWITCH_TEST_PLACEHOLDER_MISSING"));
+    }
+}
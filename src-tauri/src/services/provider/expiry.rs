@@ -0,0 +1,161 @@
+//! API Key 到期提醒：只比较本地记录的到期日，不访问网络
+
+use chrono::{Local, NaiveDate};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 默认提前提醒天数
+pub const DEFAULT_EXPIRY_WARNING_DAYS: u32 = 7;
+
+pub(super) const EXPIRY_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// 到期状态（仅在已过期或位于提醒窗口内时产生）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryStatus {
+    /// 已过期若干天
+    Expired { days_ago: i64 },
+    /// 若干天后到期（0 表示今天到期）
+    ExpiringSoon { days_left: i64 },
+}
+
+/// 需要提醒的供应商
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpiryWarning {
+    pub id: String,
+    pub name: String,
+    pub expires_at: NaiveDate,
+    pub status: ExpiryStatus,
+}
+
+/// 解析到期日（YYYY-MM-DD）
+pub fn parse_expiry_date(input: &str) -> Result<NaiveDate, AppError> {
+    NaiveDate::parse_from_str(input.trim(), EXPIRY_DATE_FORMAT).map_err(|_| {
+        AppError::localized(
+            "provider.expiry.invalid_date",
+            format!("到期日格式无效（应为 YYYY-MM-DD）: {input}"),
+            format!("Invalid expiry date (expected YYYY-MM-DD): {input}"),
+        )
+    })
+}
+
+/// 供应商记录的到期日；未设置或格式无效时返回 None
+pub fn provider_expiry_date(provider: &Provider) -> Option<NaiveDate> {
+    let raw = provider.meta.as_ref()?.expires_at.as_deref()?;
+    parse_expiry_date(raw).ok()
+}
+
+/// 计算到期状态；距离到期超过 `window_days` 天时返回 None
+pub fn expiry_status(
+    provider: &Provider,
+    today: NaiveDate,
+    window_days: u32,
+) -> Option<ExpiryStatus> {
+    let days_left = (provider_expiry_date(provider)? - today).num_days();
+    if days_left < 0 {
+        Some(ExpiryStatus::Expired {
+            days_ago: -days_left,
+        })
+    } else if days_left <= i64::from(window_days) {
+        Some(ExpiryStatus::ExpiringSoon { days_left })
+    } else {
+        None
+    }
+}
+
+/// 当前生效的提醒窗口（天）
+pub fn expiry_warning_days() -> u32 {
+    crate::settings::get_expiry_warning_days().unwrap_or(DEFAULT_EXPIRY_WARNING_DAYS)
+}
+
+impl ProviderService {
+    /// 设置或清除（None）供应商的 API Key 到期日
+    pub fn set_expiry(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        expires_at: Option<NaiveDate>,
+    ) -> Result<(), AppError> {
+        Self::update_meta(state, app_type, provider_id, |meta| {
+            meta.expires_at = expires_at.map(|date| date.format(EXPIRY_DATE_FORMAT).to_string());
+        })
+    }
+
+    /// 列出当前应用中已过期或即将到期的供应商
+    pub fn expiry_warnings(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<ExpiryWarning>, AppError> {
+        let providers = Self::list(state, app_type)?;
+        Ok(collect_expiry_warnings(
+            providers.values(),
+            Local::now().date_naive(),
+            expiry_warning_days(),
+        ))
+    }
+}
+
+/// 筛选需要提醒的供应商，按到期日升序排列
+pub fn collect_expiry_warnings<'a>(
+    providers: impl IntoIterator<Item = &'a Provider>,
+    today: NaiveDate,
+    window_days: u32,
+) -> Vec<ExpiryWarning> {
+    let mut warnings: Vec<ExpiryWarning> = providers
+        .into_iter()
+        .filter_map(|provider| {
+            Some(ExpiryWarning {
+                status: expiry_status(provider, today, window_days)?,
+                expires_at: provider_expiry_date(provider)?,
+                id: provider.id.clone(),
+                name: provider.name.clone(),
+            })
+        })
+        .collect();
+    warnings.sort_by(|a, b| a.expires_at.cmp(&b.expires_at).then(a.id.cmp(&b.id)));
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::ProviderMeta;
+    use serde_json::json;
+
+    fn provider_expiring(date: &str) -> Provider {
+        let mut provider = Provider::with_id("p".into(), "P".into(), json!({}), None);
+        provider.meta = Some(ProviderMeta {
+            expires_at: Some(date.to_string()),
+            ..Default::default()
+        });
+        provider
+    }
+
+    #[test]
+    fn expiry_status_respects_window_and_past_dates() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).expect("date");
+
+        assert_eq!(
+            expiry_status(&provider_expiring("2026-03-08"), today, 7),
+            Some(ExpiryStatus::Expired { days_ago: 2 })
+        );
+        assert_eq!(
+            expiry_status(&provider_expiring("2026-03-10"), today, 7),
+            Some(ExpiryStatus::ExpiringSoon { days_left: 0 })
+        );
+        assert_eq!(
+            expiry_status(&provider_expiring("2026-03-17"), today, 7),
+            Some(ExpiryStatus::ExpiringSoon { days_left: 7 })
+        );
+        assert_eq!(
+            expiry_status(&provider_expiring("2026-03-18"), today, 7),
+            None
+        );
+        assert_eq!(expiry_status(&provider_expiring("soon"), today, 7), None);
+        assert!(parse_expiry_date("2026-13-01").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::services::{SpeedtestOptions, SpeedtestService};
+use crate::store::AppState;
+
+use super::{provider_headers, resolve_env_placeholders, ProviderService};
+
+/// 故障转移队列中的供应商及其测速结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailoverCandidate {
+    pub provider_id: String,
+    pub provider_name: String,
+    /// 未配置 Base URL 或配置无法解析时为 None，不参与测速
+    pub url: Option<String>,
+    /// 测速时附带的供应商自定义请求头
+    pub headers: Vec<(String, String)>,
+    pub latency: Option<u128>,
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+impl FailoverCandidate {
+    /// 可达且未返回 5xx 视为健康（根路径常见 401/404，不代表不可用）
+    pub fn is_healthy(&self) -> bool {
+        self.url.is_some()
+            && self.error.is_none()
+            && self.latency.is_some()
+            && self.status.is_some_and(|status| status < 500)
+    }
+}
+
+/// 健康的排在前面并按延迟升序，其余保持原顺序
+pub fn rank_failover_candidates(candidates: &mut [FailoverCandidate]) {
+    candidates.sort_by_key(|c| {
+        (
+            !c.is_healthy(),
+            if c.is_healthy() {
+                c.latency.unwrap_or(u128::MAX)
+            } else {
+                0
+            },
+        )
+    });
+}
+
+impl ProviderService {
+    /// 加入 / 移出故障转移队列
+    pub fn set_failover_queued(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        queued: bool,
+    ) -> Result<(), AppError> {
+        let mut config = state.config.write().map_err(AppError::from)?;
+        let provider = config
+            .get_manager_mut(&app_type)
+            .and_then(|manager| manager.providers.get_mut(provider_id))
+            .ok_or_else(|| Self::provider_not_found(provider_id))?;
+
+        if queued {
+            state
+                .db
+                .add_to_failover_queue(app_type.as_str(), provider_id)?;
+        } else {
+            state
+                .db
+                .remove_from_failover_queue(app_type.as_str(), provider_id)?;
+        }
+        provider.in_failover_queue = queued;
+        Ok(())
+    }
+
+    /// 故障转移队列中的供应商（按 sort_index、ID 排序），尚未测速
+    pub fn failover_candidates(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<FailoverCandidate>, AppError> {
+        let mut providers: Vec<_> = Self::list(state, app_type.clone())?
+            .into_values()
+            .filter(|p| p.in_failover_queue)
+            .collect();
+        providers.sort_by(|a, b| {
+            (a.sort_index.unwrap_or(usize::MAX), &a.id)
+                .cmp(&(b.sort_index.unwrap_or(usize::MAX), &b.id))
+        });
+
+        Ok(providers
+            .into_iter()
+            .map(|mut provider| {
+                // 占位符无法解析时记为不健康并保留原因，其余候选照常测速
+                let (url, error) = match resolve_env_placeholders(&provider.settings_config) {
+                    Ok(settings) => {
+                        provider.settings_config = settings;
+                        (Self::extract_base_url(&provider, &app_type).ok(), None)
+                    }
+                    Err(err) => (None, Some(err.to_string())),
+                };
+                FailoverCandidate {
+                    url,
+                    headers: provider_headers(&provider.settings_config),
+                    provider_id: provider.id,
+                    provider_name: provider.name,
+                    latency: None,
+                    status: None,
+                    error,
+                }
+            })
+            .collect())
+    }
+
+    /// 对候选供应商测速并排序，最快的健康供应商排在首位
+    pub async fn speedtest_failover_candidates(
+        mut candidates: Vec<FailoverCandidate>,
+        options: SpeedtestOptions,
+    ) -> Result<Vec<FailoverCandidate>, AppError> {
+        let targets = candidates
+            .iter()
+            .filter_map(|c| Some((c.url.clone()?, c.headers.clone())))
+            .collect();
+        let mut results = SpeedtestService::test_endpoints_with_headers(targets, options)
+            .await?
+            .into_iter();
+
+        for candidate in candidates.iter_mut().filter(|c| c.url.is_some()) {
+            if let Some(result) = results.next() {
+                candidate.latency = result.latency;
+                candidate.status = result.status;
+                candidate.error = result.error;
+            }
+        }
+
+        rank_failover_candidates(&mut candidates);
+        Ok(candidates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(id: &str, latency: Option<u128>, status: Option<u16>) -> FailoverCandidate {
+        FailoverCandidate {
+            provider_id: id.to_string(),
+            provider_name: id.to_string(),
+            url: Some(format!("https://{id}.example.com")),
+            headers: Vec::new(),
+            latency,
+            status,
+            error: latency.is_none().then(|| "连接失败".to_string()),
+        }
+    }
+
+    #[test]
+    fn rank_puts_fastest_healthy_first() {
+        let mut candidates = vec![
+            candidate("down", None, None),
+            candidate("slow", Some(300), Some(200)),
+            candidate("broken", Some(10), Some(502)),
+            candidate("fast", Some(80), Some(401)),
+        ];
+        rank_failover_candidates(&mut candidates);
+
+        let order: Vec<_> = candidates.iter().map(|c| c.provider_id.as_str()).collect();
+        assert_eq!(order, ["fast", "slow", "down", "broken"]);
+        assert!(!candidates[3].is_healthy());
+    }
+}
@@ -0,0 +1,136 @@
+//! 供应商自定义请求头（`settingsConfig.headers`）
+//!
+//! 仅用于测速等探测请求并作为记录保存；不会写入任何应用的 live 配置。
+
+use serde_json::{Map, Value};
+
+use crate::error::AppError;
+
+use super::env_placeholder::resolve_env_placeholders;
+
+const HEADERS_KEY: &str = "headers";
+
+/// 供应商配置中的请求头（已解析 `${env:NAME}` 占位符，解析失败时保留原文）
+pub fn provider_headers(settings_config: &Value) -> Vec<(String, String)> {
+    let Some(headers) = settings_config.get(HEADERS_KEY) else {
+        return Vec::new();
+    };
+    let headers = resolve_env_placeholders(headers).unwrap_or_else(|_| headers.clone());
+    headers
+        .as_object()
+        .map(|map| {
+            map.iter()
+                .filter_map(|(name, value)| Some((name.clone(), value.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 解析 `Key: Value` 形式的请求头，条目以换行或 `;` 分隔
+pub fn parse_header_lines(text: &str) -> Result<Map<String, Value>, AppError> {
+    let mut headers = Map::new();
+    for entry in text
+        .split(['\n', ';'])
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+    {
+        let (name, value) = entry
+            .split_once(':')
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .filter(|(name, _)| is_valid_header_name(name))
+            .ok_or_else(|| {
+                AppError::localized(
+                    "provider.headers.invalid",
+                    format!("无效的请求头: {entry}（格式应为 Key: Value）"),
+                    format!("Invalid header: {entry} (expected Key: Value)"),
+                )
+            })?;
+        headers.insert(name.to_string(), Value::String(value.to_string()));
+    }
+    Ok(headers)
+}
+
+/// 将请求头渲染为单行 `Key: Value; Key2: Value2`，便于编辑
+pub fn format_header_lines(settings_config: &Value) -> String {
+    settings_config
+        .get(HEADERS_KEY)
+        .and_then(Value::as_object)
+        .map(|map| {
+            map.iter()
+                .filter_map(|(name, value)| Some(format!("{name}: {}", value.as_str()?)))
+                .collect::<Vec<_>>()
+                .join("; ")
+        })
+        .unwrap_or_default()
+}
+
+/// 写入请求头；为空时移除该字段
+pub fn set_provider_headers(settings_config: &mut Value, headers: Map<String, Value>) {
+    let Some(obj) = settings_config.as_object_mut() else {
+        return;
+    };
+    if headers.is_empty() {
+        obj.remove(HEADERS_KEY);
+    } else {
+        obj.insert(HEADERS_KEY.to_string(), Value::Object(headers));
+    }
+}
+
+/// 写入 live 前去掉请求头
+pub(crate) fn strip_headers(settings_config: &mut Value) {
+    if let Some(obj) = settings_config.as_object_mut() {
+        obj.remove(HEADERS_KEY);
+    }
+}
+
+/// 从 live 回填整个配置时保留原有的请求头（live 中不含该字段）
+pub(crate) fn keep_headers(original: &Value, live: &mut Value) {
+    if let (Some(headers), Some(obj)) = (original.get(HEADERS_KEY), live.as_object_mut()) {
+        obj.insert(HEADERS_KEY.to_string(), headers.clone());
+    }
+}
+
+fn is_valid_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_header_lines_accepts_newlines_and_semicolons() {
+        let headers = parse_header_lines("x-api-version: 2024-01\n X-Relay : a:b ; ").unwrap();
+        assert_eq!(headers["x-api-version"], json!("2024-01"));
+        assert_eq!(headers["X-Relay"], json!("a:b"));
+        assert!(parse_header_lines("no colon").is_err());
+        assert!(parse_header_lines("bad name: v").is_err());
+    }
+
+    #[test]
+    fn headers_round_trip_and_survive_backfill() {
+        let mut config = json!({ "env": {} });
+        set_provider_headers(&mut config, parse_header_lines("x-a: 1; x-b: 2").unwrap());
+        assert_eq!(format_header_lines(&config), "x-a: 1; x-b: 2");
+        assert_eq!(
+            provider_headers(&config),
+            vec![
+                ("x-a".to_string(), "1".to_string()),
+                ("x-b".to_string(), "2".to_string())
+            ]
+        );
+
+        let mut live = config.clone();
+        strip_headers(&mut live);
+        assert!(live.get("headers").is_none());
+        keep_headers(&config, &mut live);
+        assert_eq!(live, config);
+
+        set_provider_headers(&mut config, Map::new());
+        assert!(config.get("headers").is_none());
+    }
+}
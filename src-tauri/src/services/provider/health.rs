@@ -0,0 +1,396 @@
+//! 供应商健康检查：对每个供应商同时测速端点并用 API Key 发起一次鉴权探测，
+//! 汇总为「可用 / 鉴权失败 / 鉴权未知 / 不可达 / 不适用」几种状态。
+
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::services::{SpeedtestOptions, SpeedtestService};
+use crate::store::AppState;
+
+use crate::provider::Provider;
+
+use super::{
+    is_codex_official_provider, provider_headers, resolve_env_placeholders, ProviderService,
+};
+
+/// 同时检查的供应商数量上限
+const HEALTH_CONCURRENCY: usize = 4;
+
+/// 供应商的综合健康状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// 端点可达且 API Key 未被拒绝
+    Healthy,
+    /// 端点可达，但 API Key 被拒绝（401/403）或未配置
+    AuthFailed,
+    /// 端点可达，但鉴权探测请求本身失败（连接失败 / 超时）
+    AuthUnknown,
+    /// 未配置端点，或连接失败 / 超时
+    Unreachable,
+    /// 官方供应商使用内置端点或登录态，未配置 URL / Key 时不做检查
+    NotApplicable,
+}
+
+/// 鉴权探测发送的凭据请求头，与供应商实际使用的一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthScheme {
+    /// `Authorization: Bearer`（Claude `ANTHROPIC_AUTH_TOKEN`、Codex）
+    Bearer,
+    /// `x-api-key`（Claude `ANTHROPIC_API_KEY`）或 `x-goog-api-key`（Gemini）
+    ApiKey,
+}
+
+/// 待检查的供应商（占位符已解析）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthTarget {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub url: Option<String>,
+    pub api_key: Option<String>,
+    pub auth_scheme: AuthScheme,
+    pub headers: Vec<(String, String)>,
+    pub official: bool,
+    /// `${env:NAME}` 占位符解析失败原因，此时不发请求
+    pub error: Option<String>,
+}
+
+/// 单个供应商的检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderHealth {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub url: Option<String>,
+    pub status: HealthStatus,
+    pub latency: Option<u128>,
+    /// 鉴权探测返回的 HTTP 状态码
+    pub auth_status: Option<u16>,
+    /// 测速失败原因
+    pub error: Option<String>,
+    /// 鉴权探测请求失败原因
+    pub auth_error: Option<String>,
+}
+
+impl ProviderHealth {
+    /// 端点可达但未配置 API Key
+    pub fn is_missing_key(&self) -> bool {
+        self.status == HealthStatus::AuthFailed
+            && self.auth_status.is_none()
+            && self.auth_error.is_none()
+    }
+}
+
+impl ProviderService {
+    /// 应用下所有供应商（按 sort_index、ID 排序），尚未检查
+    pub fn health_targets(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<HealthTarget>, AppError> {
+        let mut providers: Vec<_> = Self::list(state, app_type.clone())?.into_values().collect();
+        providers.sort_by(|a, b| {
+            (a.sort_index.unwrap_or(usize::MAX), &a.id)
+                .cmp(&(b.sort_index.unwrap_or(usize::MAX), &b.id))
+        });
+
+        Ok(providers
+            .into_iter()
+            .map(|mut provider| {
+                let official = is_official_provider(&app_type, &provider);
+                let resolved = resolve_env_placeholders(&provider.settings_config);
+                let (url, api_key, error) = match resolved {
+                    Ok(settings) => {
+                        provider.settings_config = settings;
+                        (
+                            Self::extract_base_url(&provider, &app_type).ok(),
+                            Self::extract_api_key(&provider, &app_type)
+                                .ok()
+                                .filter(|key| !key.trim().is_empty()),
+                            None,
+                        )
+                    }
+                    Err(err) => (None, None, Some(err.to_string())),
+                };
+                HealthTarget {
+                    url,
+                    api_key,
+                    auth_scheme: auth_scheme(&app_type, &provider),
+                    headers: provider_headers(&provider.settings_config),
+                    official,
+                    error,
+                    provider_id: provider.id,
+                    provider_name: provider.name,
+                }
+            })
+            .collect())
+    }
+
+    /// 检查每个供应商：测速与鉴权探测并发进行，供应商之间最多同时检查 `HEALTH_CONCURRENCY` 个。
+    /// 结果保持输入顺序
+    pub async fn check_provider_health(
+        app_type: AppType,
+        targets: Vec<HealthTarget>,
+        options: SpeedtestOptions,
+    ) -> Result<Vec<ProviderHealth>, AppError> {
+        if targets.is_empty() {
+            return Ok(vec![]);
+        }
+        crate::settings::ensure_online()?;
+        let client = SpeedtestService::build_client(
+            options.effective_timeout_secs(),
+            &options.effective_user_agent(),
+        )?;
+
+        let results = stream::iter(targets)
+            .map(|target| {
+                let client = client.clone();
+                let options = options.clone();
+                let app_type = app_type.clone();
+                async move { check_one(&client, &app_type, target, options).await }
+            })
+            .buffered(HEALTH_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(results)
+    }
+}
+
+async fn check_one(
+    client: &Client,
+    app_type: &AppType,
+    target: HealthTarget,
+    options: SpeedtestOptions,
+) -> ProviderHealth {
+    let mut health = ProviderHealth {
+        provider_id: target.provider_id,
+        provider_name: target.provider_name,
+        url: target.url.clone(),
+        status: HealthStatus::Unreachable,
+        latency: None,
+        auth_status: None,
+        error: None,
+        auth_error: None,
+    };
+    if let Some(err) = target.error {
+        health.error = Some(err);
+        return health;
+    }
+    if target.official && (target.url.is_none() || target.api_key.is_none()) {
+        health.status = HealthStatus::NotApplicable;
+        return health;
+    }
+    let Some(url) = target.url else {
+        return health;
+    };
+
+    let latency_probe = async {
+        SpeedtestService::test_endpoints_with_headers(
+            vec![(url.clone(), target.headers.clone())],
+            options,
+        )
+        .await
+        .ok()
+        .and_then(|mut results| results.pop())
+    };
+    let auth_probe = async {
+        match &target.api_key {
+            Some(key) => Some(
+                probe_api_key(
+                    client,
+                    app_type,
+                    &url,
+                    key,
+                    target.auth_scheme,
+                    &target.headers,
+                )
+                .await,
+            ),
+            None => None,
+        }
+    };
+    let (latency, auth) = futures::join!(latency_probe, auth_probe);
+
+    health.latency = latency.as_ref().and_then(|result| result.latency);
+    let speedtest_error = latency.and_then(|result| result.error);
+    health.status = classify_health(health.latency, auth.as_ref());
+    if health.latency.is_none() {
+        health.error = Some(speedtest_error.unwrap_or_else(|| "请求超时".to_string()));
+    }
+    match auth {
+        Some(Ok(code)) => health.auth_status = Some(code),
+        Some(Err(err)) => health.auth_error = Some(err),
+        None => {}
+    }
+    health
+}
+
+/// 汇总测速与鉴权探测结果；`auth` 为 None 表示未配置 API Key。
+/// 可达性只由测速决定，鉴权探测的网络错误不会把可达的端点判为不可达
+fn classify_health(latency: Option<u128>, auth: Option<&Result<u16, String>>) -> HealthStatus {
+    if latency.is_none() {
+        return HealthStatus::Unreachable;
+    }
+    match auth {
+        None | Some(Ok(401 | 403)) => HealthStatus::AuthFailed,
+        Some(Ok(_)) => HealthStatus::Healthy,
+        Some(Err(_)) => HealthStatus::AuthUnknown,
+    }
+}
+
+/// 官方供应商：Codex 沿用切换逻辑的判断，其余看分类
+fn is_official_provider(app_type: &AppType, provider: &Provider) -> bool {
+    match app_type {
+        AppType::Codex => is_codex_official_provider(provider),
+        AppType::Claude | AppType::Gemini => provider
+            .category
+            .as_deref()
+            .is_some_and(|category| category.eq_ignore_ascii_case("official")),
+    }
+}
+
+/// Claude 按实际配置的环境变量选择请求头（与 `extract_api_key` 的优先级一致）
+fn auth_scheme(app_type: &AppType, provider: &Provider) -> AuthScheme {
+    match app_type {
+        AppType::Claude => {
+            let has_token = provider
+                .settings_config
+                .pointer("/env/ANTHROPIC_AUTH_TOKEN")
+                .and_then(|v| v.as_str())
+                .is_some();
+            if has_token {
+                AuthScheme::Bearer
+            } else {
+                AuthScheme::ApiKey
+            }
+        }
+        AppType::Codex => AuthScheme::Bearer,
+        AppType::Gemini => AuthScheme::ApiKey,
+    }
+}
+
+/// 用 API Key 请求模型列表接口，只关心是否被拒绝（401/403）；
+/// 其他状态码（如中转不支持该接口时的 404）视为 Key 有效
+async fn probe_api_key(
+    client: &Client,
+    app_type: &AppType,
+    base_url: &str,
+    api_key: &str,
+    scheme: AuthScheme,
+    headers: &[(String, String)],
+) -> Result<u16, String> {
+    let request = client.get(auth_probe_url(app_type, base_url));
+    let request = match (app_type, scheme) {
+        (_, AuthScheme::Bearer) => request.bearer_auth(api_key),
+        (AppType::Gemini, AuthScheme::ApiKey) => request.header("x-goog-api-key", api_key),
+        (_, AuthScheme::ApiKey) => request.header("x-api-key", api_key),
+    };
+    let request = match app_type {
+        AppType::Claude => request.header("anthropic-version", "2023-06-01"),
+        AppType::Codex | AppType::Gemini => request,
+    };
+    let request = headers.iter().fold(request, |req, (name, value)| {
+        req.header(name.as_str(), value.as_str())
+    });
+
+    request
+        .send()
+        .await
+        .map(|resp| resp.status().as_u16())
+        .map_err(|err| SpeedtestService::describe_request_error(&err))
+}
+
+/// 各应用的模型列表接口：Claude `/v1/models`，Codex `{base_url}/models`，Gemini `/v1beta/models`
+fn auth_probe_url(app_type: &AppType, base_url: &str) -> String {
+    let base = base_url.trim().trim_end_matches('/');
+    match app_type {
+        AppType::Claude if base.ends_with("/v1") => format!("{base}/models"),
+        AppType::Claude => format!("{base}/v1/models"),
+        AppType::Codex => format!("{base}/models"),
+        AppType::Gemini if base.ends_with("/v1beta") => format!("{base}/models"),
+        AppType::Gemini => format!("{base}/v1beta/models"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auth_probe_url_targets_model_listing() {
+        assert_eq!(
+            auth_probe_url(&AppType::Claude, "https://relay.example.com/"),
+            "https://relay.example.com/v1/models"
+        );
+        assert_eq!(
+            auth_probe_url(&AppType::Claude, "https://relay.example.com/v1"),
+            "https://relay.example.com/v1/models"
+        );
+        assert_eq!(
+            auth_probe_url(&AppType::Codex, "https://api.openai.com/v1"),
+            "https://api.openai.com/v1/models"
+        );
+        assert_eq!(
+            auth_probe_url(
+                &AppType::Gemini,
+                "https://generativelanguage.googleapis.com"
+            ),
+            "https://generativelanguage.googleapis.com/v1beta/models"
+        );
+    }
+
+    #[test]
+    fn classify_health_combines_latency_and_auth() {
+        assert_eq!(
+            classify_health(Some(120), Some(&Ok(200))),
+            HealthStatus::Healthy
+        );
+        assert_eq!(
+            classify_health(Some(120), Some(&Ok(404))),
+            HealthStatus::Healthy
+        );
+        assert_eq!(
+            classify_health(Some(120), Some(&Ok(401))),
+            HealthStatus::AuthFailed
+        );
+        assert_eq!(classify_health(Some(120), None), HealthStatus::AuthFailed);
+        assert_eq!(
+            classify_health(None, Some(&Ok(200))),
+            HealthStatus::Unreachable
+        );
+        // 测速成功时，鉴权探测的网络错误不影响可达性
+        assert_eq!(
+            classify_health(Some(120), Some(&Err("请求超时".to_string()))),
+            HealthStatus::AuthUnknown
+        );
+    }
+
+    #[test]
+    fn auth_scheme_follows_configured_claude_credential() {
+        let provider = |env: serde_json::Value| {
+            Provider::with_id(
+                "p".to_string(),
+                "P".to_string(),
+                serde_json::json!({ "env": env }),
+                None,
+            )
+        };
+        assert_eq!(
+            auth_scheme(
+                &AppType::Claude,
+                &provider(serde_json::json!({ "ANTHROPIC_AUTH_TOKEN": "t" }))
+            ),
+            AuthScheme::Bearer
+        );
+        assert_eq!(
+            auth_scheme(
+                &AppType::Claude,
+                &provider(serde_json::json!({ "ANTHROPIC_API_KEY": "k" }))
+            ),
+            AuthScheme::ApiKey
+        );
+        assert_eq!(
+            auth_scheme(&AppType::Codex, &provider(serde_json::json!({}))),
+            AuthScheme::Bearer
+        );
+    }
+}
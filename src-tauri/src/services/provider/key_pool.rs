@@ -0,0 +1,192 @@
+//! 多 API Key 轮询：供应商在 meta 中保存一组 Key，每次切换按顺序把其中一个写入 settings_config
+//!
+//! settings_config 始终只保存一个标量 Key，未配置 Key 池的供应商行为不变。
+
+use crate::app_config::{AppType, MultiAppConfig};
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::services::audit;
+use crate::store::AppState;
+
+use super::key_rotation::set_api_key;
+use super::ProviderService;
+
+/// 供应商的 Key 池与下次切换将使用的位置
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiKeyPool {
+    pub keys: Vec<String>,
+    pub next_index: usize,
+}
+
+impl ProviderService {
+    /// 查看供应商的 Key 池
+    pub fn api_key_pool(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<ApiKeyPool, AppError> {
+        let config = state.config.read().map_err(AppError::from)?;
+        let provider = config
+            .get_manager(&app_type)
+            .and_then(|manager| manager.providers.get(provider_id))
+            .ok_or_else(|| Self::provider_not_found(provider_id))?;
+        Ok(pool_of(provider))
+    }
+
+    /// 向 Key 池追加 Key（忽略空白与重复）
+    ///
+    /// 池为空时先收录 settings_config 中现有的单个 Key，使其继续参与轮询。
+    pub fn add_api_keys(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        keys: Vec<String>,
+    ) -> Result<ApiKeyPool, AppError> {
+        let result = Self::add_api_keys_inner(state, app_type.clone(), provider_id, keys);
+        audit::record(
+            "provider.add_api_keys",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result
+    }
+
+    fn add_api_keys_inner(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        keys: Vec<String>,
+    ) -> Result<ApiKeyPool, AppError> {
+        let keys: Vec<String> = keys
+            .iter()
+            .map(|key| key.trim().to_string())
+            .filter(|key| !key.is_empty())
+            .collect();
+        if keys.is_empty() {
+            return Err(AppError::localized(
+                "provider.keys.empty",
+                "API Key 不能为空",
+                "The API key must not be empty",
+            ));
+        }
+
+        let pool = {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            let provider = config
+                .get_manager_mut(&app_type)
+                .and_then(|manager| manager.providers.get_mut(provider_id))
+                .ok_or_else(|| Self::provider_not_found(provider_id))?;
+            let existing_key = Self::extract_api_key(provider, &app_type)
+                .ok()
+                .filter(|key| !key.trim().is_empty());
+            let meta = provider.meta.get_or_insert_with(Default::default);
+            if meta.api_keys.is_empty() {
+                meta.api_keys.extend(existing_key);
+            }
+            for key in keys {
+                if !meta.api_keys.contains(&key) {
+                    meta.api_keys.push(key);
+                }
+            }
+            pool_of(provider)
+        };
+
+        state.save()?;
+        Ok(pool)
+    }
+
+    /// 按 1 起始的序号从 Key 池移除一个 Key；settings_config 中的当前 Key 不受影响
+    pub fn remove_api_key(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        position: usize,
+    ) -> Result<ApiKeyPool, AppError> {
+        let result = Self::remove_api_key_inner(state, app_type.clone(), provider_id, position);
+        audit::record(
+            "provider.remove_api_key",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result
+    }
+
+    fn remove_api_key_inner(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        position: usize,
+    ) -> Result<ApiKeyPool, AppError> {
+        let pool = {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            let provider = config
+                .get_manager_mut(&app_type)
+                .and_then(|manager| manager.providers.get_mut(provider_id))
+                .ok_or_else(|| Self::provider_not_found(provider_id))?;
+            let len = provider.meta.as_ref().map_or(0, |meta| meta.api_keys.len());
+            if position == 0 || position > len {
+                return Err(AppError::localized(
+                    "provider.keys.out_of_range",
+                    format!("Key 序号超出范围: {position}（共 {len} 个）"),
+                    format!("Key number out of range: {position} ({len} configured)"),
+                ));
+            }
+            let meta = provider.meta.get_or_insert_with(Default::default);
+            meta.api_keys.remove(position - 1);
+            meta.api_key_index = match meta.api_key_index {
+                _ if meta.api_keys.is_empty() => None,
+                Some(index) if index >= position => Some(index - 1),
+                index => index,
+            };
+            pool_of(provider)
+        };
+
+        state.save()?;
+        Ok(pool)
+    }
+
+    /// 切换前从 Key 池取出下一个 Key 写入 settings_config，并推进轮询位置
+    pub(super) fn advance_key_pool(
+        config: &mut MultiAppConfig,
+        app_type: &AppType,
+        provider_id: &str,
+    ) {
+        let Some(provider) = config
+            .get_manager_mut(app_type)
+            .and_then(|manager| manager.providers.get_mut(provider_id))
+        else {
+            return;
+        };
+        let Some(meta) = provider.meta.as_mut() else {
+            return;
+        };
+        if meta.api_keys.is_empty() {
+            return;
+        }
+        let index = meta.api_key_index.unwrap_or(0) % meta.api_keys.len();
+        if set_api_key(
+            &mut provider.settings_config,
+            app_type,
+            &meta.api_keys[index],
+        ) {
+            meta.api_key_index = Some((index + 1) % meta.api_keys.len());
+        }
+    }
+}
+
+fn pool_of(provider: &Provider) -> ApiKeyPool {
+    provider
+        .meta
+        .as_ref()
+        .map(|meta| ApiKeyPool {
+            next_index: meta
+                .api_key_index
+                .unwrap_or(0)
+                .checked_rem(meta.api_keys.len())
+                .unwrap_or(0),
+            keys: meta.api_keys.clone(),
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,181 @@
+use serde_json::{json, Value};
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::services::audit;
+use crate::store::AppState;
+
+use super::{PostCommitAction, ProviderService};
+
+impl ProviderService {
+    /// 批量轮换 API Key：更新所有 Base URL 匹配的供应商，一次性保存
+    ///
+    /// 返回被更新的供应商 ID 列表（按原有顺序）
+    pub fn rotate_key(
+        state: &AppState,
+        app_type: AppType,
+        base_url: &str,
+        new_key: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let result = Self::rotate_key_inner(state, app_type.clone(), base_url, new_key);
+        audit::record(
+            "provider.rotate_key",
+            Some(&app_type),
+            Some(base_url),
+            &result,
+        );
+        result
+    }
+
+    fn rotate_key_inner(
+        state: &AppState,
+        app_type: AppType,
+        base_url: &str,
+        new_key: &str,
+    ) -> Result<Vec<String>, AppError> {
+        let new_key = new_key.trim();
+        if new_key.is_empty() {
+            return Err(AppError::localized(
+                "provider.rotate_key.empty",
+                "新的 API Key 不能为空",
+                "The new API key must not be empty",
+            ));
+        }
+        let target = normalize_base_url(base_url);
+        if target.is_empty() {
+            return Err(AppError::localized(
+                "provider.rotate_key.base_url_empty",
+                "Base URL 不能为空",
+                "Base URL must not be empty",
+            ));
+        }
+
+        let app_type_clone = app_type.clone();
+        let new_key = new_key.to_string();
+        Self::run_transaction(state, move |config| {
+            let manager = config
+                .get_manager_mut(&app_type_clone)
+                .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
+
+            let mut updated = Vec::new();
+            for (id, provider) in manager.providers.iter_mut() {
+                let matches = Self::extract_base_url(provider, &app_type_clone)
+                    .is_ok_and(|url| normalize_base_url(&url) == target);
+                if matches && set_api_key(&mut provider.settings_config, &app_type_clone, &new_key)
+                {
+                    updated.push(id.clone());
+                }
+            }
+
+            let current = manager.current.clone();
+            let action = match manager.providers.get(&current) {
+                Some(provider) if updated.contains(&current) => {
+                    let provider = provider.clone();
+                    let backup = Self::capture_live_snapshot(&app_type_clone)?;
+                    let common_config_snippet =
+                        config.common_config_snippets.get(&app_type_clone).cloned();
+                    Some(PostCommitAction {
+                        app_type: app_type_clone.clone(),
+                        provider,
+                        backup,
+                        sync_mcp: false,
+                        refresh_snapshot: false,
+                        common_config_snippet,
+                        backup_modified_live: false,
+                    })
+                }
+                _ => None,
+            };
+
+            Ok((updated, action))
+        })
+    }
+}
+
+/// 归一化 Base URL 以便比较（忽略首尾空白、末尾斜杠与大小写）
+fn normalize_base_url(raw: &str) -> String {
+    raw.trim().trim_end_matches('/').to_ascii_lowercase()
+}
+
+/// 写入新的 API Key；配置结构不符合预期时返回 false
+pub(super) fn set_api_key(settings_config: &mut Value, app_type: &AppType, new_key: &str) -> bool {
+    let Some(obj) = settings_config.as_object_mut() else {
+        return false;
+    };
+    match app_type {
+        AppType::Claude => {
+            let Some(env) = obj.get_mut("env").and_then(|v| v.as_object_mut()) else {
+                return false;
+            };
+            let mut replaced = false;
+            for key in ["ANTHROPIC_AUTH_TOKEN", "ANTHROPIC_API_KEY"] {
+                if env.contains_key(key) {
+                    env.insert(key.to_string(), json!(new_key));
+                    replaced = true;
+                }
+            }
+            if !replaced {
+                env.insert("ANTHROPIC_AUTH_TOKEN".to_string(), json!(new_key));
+            }
+            true
+        }
+        AppType::Codex => {
+            let auth = obj.entry("auth".to_string()).or_insert_with(|| json!({}));
+            if !auth.is_object() {
+                *auth = json!({});
+            }
+            auth.as_object_mut()
+                .map(|auth| auth.insert("OPENAI_API_KEY".to_string(), json!(new_key)))
+                .is_some()
+        }
+        AppType::Gemini => {
+            let Some(env) = obj.get_mut("env").and_then(|v| v.as_object_mut()) else {
+                return false;
+            };
+            env.insert("GEMINI_API_KEY".to_string(), json!(new_key));
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_base_url_ignores_trailing_slash_and_case() {
+        assert_eq!(
+            normalize_base_url(" https://Relay.Example/v1/ "),
+            "https://relay.example/v1"
+        );
+    }
+
+    #[test]
+    fn set_api_key_replaces_existing_claude_key_fields() {
+        let mut config = json!({
+            "env": {
+                "ANTHROPIC_BASE_URL": "https://relay.example",
+                "ANTHROPIC_API_KEY": "old"
+            }
+        });
+        assert!(set_api_key(&mut config, &AppType::Claude, "new"));
+        assert_eq!(config["env"]["ANTHROPIC_API_KEY"], "new");
+        assert!(config["env"].get("ANTHROPIC_AUTH_TOKEN").is_none());
+    }
+
+    #[test]
+    fn extract_base_url_reads_codex_toml() {
+        let provider = crate::provider::Provider::with_id(
+            "relay".to_string(),
+            "Relay".to_string(),
+            json!({ "config": "model = \"gpt\"\nbase_url = \"https://relay.example/v1\"\n" }),
+            None,
+        );
+        assert_eq!(
+            ProviderService::extract_base_url(&provider, &AppType::Codex)
+                .ok()
+                .as_deref(),
+            Some("https://relay.example/v1")
+        );
+    }
+}
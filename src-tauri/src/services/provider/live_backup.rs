@@ -0,0 +1,165 @@
+//! 切换前备份被手动修改过的 live 文件
+//!
+//! 每次写入 live 文件后记录其内容哈希；下次切换覆盖前若内容与记录不同（或从未记录），
+//! 先把现有文件复制到 `~/.cc-switch/backups/live/` 下的带时间戳副本（只保留最近 `MAX_LIVE_BACKUPS` 个）。
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+use crate::app_config::AppType;
+use crate::codex_config::{get_codex_auth_path, get_codex_config_path};
+use crate::config::{get_app_config_dir, get_claude_settings_path};
+use crate::error::AppError;
+use crate::gemini_config::{get_gemini_env_path, get_gemini_settings_path};
+
+/// `backups/live` 中保留的备份数量
+const MAX_LIVE_BACKUPS: usize = 20;
+
+fn live_files(app: &AppType) -> Vec<PathBuf> {
+    match app {
+        AppType::Claude => vec![get_claude_settings_path()],
+        AppType::Codex => vec![get_codex_auth_path(), get_codex_config_path()],
+        AppType::Gemini => vec![get_gemini_env_path(), get_gemini_settings_path()],
+    }
+}
+
+/// 切换前备份的存放目录
+fn live_backup_dir() -> PathBuf {
+    get_app_config_dir().join("backups").join("live")
+}
+
+/// live 文件路径 -> 上次写入后的内容哈希
+fn written_hashes_path() -> PathBuf {
+    get_app_config_dir().join("live-written.json")
+}
+
+fn load_written_hashes() -> BTreeMap<String, String> {
+    fs::read_to_string(written_hashes_path())
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn content_hash(path: &Path) -> Option<String> {
+    let bytes = fs::read(path).ok()?;
+    Some(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+fn save_written_hashes(hashes: &BTreeMap<String, String>) -> Result<(), AppError> {
+    let path = written_hashes_path();
+    let json =
+        serde_json::to_vec_pretty(hashes).map_err(|e| AppError::JsonSerialize { source: e })?;
+    crate::config::atomic_write(&path, &json)
+}
+
+fn update_hash(hashes: &mut BTreeMap<String, String>, live: &Path) {
+    let key = live.display().to_string();
+    match content_hash(live) {
+        Some(hash) => hashes.insert(key, hash),
+        None => hashes.remove(&key),
+    };
+}
+
+/// 记录应用 live 文件当前内容的哈希（写入 live 后调用）
+pub(super) fn record_written(app: &AppType) -> Result<(), AppError> {
+    let mut hashes = load_written_hashes();
+    for live in live_files(app) {
+        update_hash(&mut hashes, &live);
+    }
+    save_written_hashes(&hashes)
+}
+
+/// cc-switch 在切换之外改写 live 文件（如同步 MCP）时使用：写入前与记录一致的文件在写入后更新哈希，
+/// 写入前已被手动修改的文件保持原记录，下次切换时仍会备份
+pub(crate) fn track_own_write<T>(
+    app: &AppType,
+    write: impl FnOnce() -> Result<T, AppError>,
+) -> Result<T, AppError> {
+    let hashes = load_written_hashes();
+    let unmodified: Vec<PathBuf> = live_files(app)
+        .into_iter()
+        .filter(|live| hashes.get(&live.display().to_string()) == content_hash(live).as_ref())
+        .collect();
+
+    let result = write()?;
+    if !unmodified.is_empty() {
+        let mut hashes = load_written_hashes();
+        for live in &unmodified {
+            update_hash(&mut hashes, live);
+        }
+        if let Err(e) = save_written_hashes(&hashes) {
+            log::warn!("记录 live 文件哈希失败: {e}");
+        }
+    }
+    Ok(result)
+}
+
+/// `dir` 下尚未存在的 `{stem}{ext}`；同一秒内多次备份时追加序号 `{stem}-{n}{ext}`，避免覆盖之前的备份
+pub(super) fn unique_backup_path(dir: &Path, stem: &str, ext: &str) -> PathBuf {
+    std::iter::once(dir.join(format!("{stem}{ext}")))
+        .chain((1..).map(|n| dir.join(format!("{stem}-{n}{ext}"))))
+        .find(|path| !path.exists())
+        .expect("unbounded candidate names")
+}
+
+/// 备份自上次写入后被修改过的 live 文件，返回生成的备份路径
+pub(super) fn backup_modified(app: &AppType) -> Result<Vec<PathBuf>, AppError> {
+    let hashes = load_written_hashes();
+    let ts = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup_dir = live_backup_dir();
+    let mut backups = Vec::new();
+    for live in live_files(app) {
+        let Some(hash) = content_hash(&live) else {
+            continue;
+        };
+        if hashes.get(&live.display().to_string()) == Some(&hash) {
+            continue;
+        }
+        fs::create_dir_all(&backup_dir).map_err(|e| AppError::io(&backup_dir, e))?;
+        let file_name = live.file_name().unwrap_or_default().to_string_lossy();
+        let backup = unique_backup_path(
+            &backup_dir,
+            &format!("{}-{file_name}.{ts}", app.as_str()),
+            "",
+        );
+        fs::copy(&live, &backup).map_err(|e| AppError::io(&backup, e))?;
+        log::warn!(
+            "live 文件自上次写入后已被修改，覆盖前已备份: {} -> {}",
+            live.display(),
+            backup.display()
+        );
+        backups.push(backup);
+    }
+    if !backups.is_empty() {
+        prune_live_backups(&backup_dir, MAX_LIVE_BACKUPS);
+    }
+    Ok(backups)
+}
+
+/// 只保留最近的 `retain` 个备份（按修改时间、文件名排序）
+fn prune_live_backups(backup_dir: &Path, retain: usize) {
+    let Ok(entries) = fs::read_dir(backup_dir) else {
+        return;
+    };
+    let mut entries: Vec<_> = entries
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_file())
+        .map(|entry| {
+            let modified = entry.metadata().and_then(|m| m.modified()).ok();
+            (modified, entry.file_name(), entry.path())
+        })
+        .collect();
+    if entries.len() <= retain {
+        return;
+    }
+    entries.sort();
+    let remove_count = entries.len() - retain;
+    for (_, _, path) in entries.into_iter().take(remove_count) {
+        if let Err(e) = fs::remove_file(&path) {
+            log::warn!("删除旧的 live 备份失败: {}: {e}", path.display());
+        }
+    }
+}
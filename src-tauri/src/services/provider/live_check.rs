@@ -0,0 +1,94 @@
+//! live 文件健康检查：应用已初始化时确认 live 文件存在且可解析（`config validate --live`）
+
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::services::diagnostic::localized_detail;
+use crate::services::Diagnostic;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+impl ProviderService {
+    /// 检查应用的 live 文件：未初始化为 ok（跳过），必需文件缺失为 warn，解析失败为 error
+    pub fn check_live_files(state: &AppState, app_type: AppType) -> Result<Diagnostic, AppError> {
+        let check = format!("live.{}", app_type.as_str());
+        if !crate::sync_policy::should_sync_live(&app_type) {
+            return Ok(Diagnostic::ok(check).with_details([localized_detail(
+                "未初始化，已跳过",
+                "not initialized, skipped",
+            )]));
+        }
+
+        let mut missing = Vec::new();
+        let mut invalid = Vec::new();
+        let mut present = Vec::new();
+        for (path, required) in live_files(&app_type) {
+            if !path.exists() {
+                if required {
+                    missing.push(localized_detail(
+                        format!("缺失: {}", path.display()),
+                        format!("missing: {}", path.display()),
+                    ));
+                }
+                continue;
+            }
+            match parse_live_file(&path) {
+                Ok(()) => present.push(path.display().to_string()),
+                Err(err) => invalid.push(format!("{}: {err}", path.display())),
+            }
+        }
+
+        let diagnostic = if !invalid.is_empty() {
+            Diagnostic::error(check).with_details(invalid.into_iter().chain(missing))
+        } else if !missing.is_empty() {
+            Diagnostic::warn(check).with_details(missing)
+        } else {
+            return Ok(Diagnostic::ok(check).with_details(present));
+        };
+
+        // 重新切换到当前供应商即可重写 live 文件
+        let current = Self::current(state, app_type.clone())?;
+        Ok(if current.trim().is_empty() {
+            diagnostic
+        } else {
+            diagnostic.with_remediation(format!(
+                "cc-switch --app {} provider switch {current}",
+                app_type.as_str()
+            ))
+        })
+    }
+}
+
+/// 应用的 live 文件及其是否为切换时必定写入的文件
+fn live_files(app_type: &AppType) -> Vec<(PathBuf, bool)> {
+    match app_type {
+        AppType::Claude => vec![(crate::config::get_claude_settings_path(), true)],
+        // Codex 0.64+ 可通过环境变量认证，auth.json 可选
+        AppType::Codex => vec![
+            (crate::codex_config::get_codex_config_path(), true),
+            (crate::codex_config::get_codex_auth_path(), false),
+        ],
+        AppType::Gemini => vec![
+            (crate::gemini_config::get_gemini_env_path(), true),
+            (crate::gemini_config::get_gemini_settings_path(), false),
+        ],
+    }
+}
+
+fn parse_live_file(path: &Path) -> Result<(), AppError> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => crate::config::read_json_file::<Value>(path).map(|_| ()),
+        Some("toml") => {
+            let text = std::fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+            crate::codex_config::validate_config_toml(&text)
+        }
+        _ => {
+            let text = std::fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+            crate::gemini_config::parse_env_file_strict(&text).map(|_| ())
+        }
+    }
+}
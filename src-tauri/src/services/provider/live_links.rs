@@ -0,0 +1,219 @@
+//! live 配置文件的符号链接模式
+//!
+//! 开启后，写入 live 文件后会把它移动到 `~/.cc-switch/live/<app>/<provider>/` 下的托管副本，
+//! 并在原位置留下指向该副本的符号链接。首次接管时已有的真实文件会先备份到 `live/backups/`。
+//! 系统不支持符号链接时会记下标记，之后的切换直接按普通文件写入，直到重新开启符号链接模式。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app_config::AppType;
+use crate::codex_config::{get_codex_auth_path, get_codex_config_path};
+use crate::config::{get_app_config_dir, get_claude_settings_path};
+use crate::error::AppError;
+use crate::gemini_config::{get_gemini_env_path, get_gemini_settings_path};
+
+/// live 配置文件的写入方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum LiveFileMethod {
+    /// Write plain copies into the app config dir.
+    #[default]
+    Copy,
+    /// Symlink live files to per-provider managed copies.
+    Symlink,
+}
+
+fn live_root() -> PathBuf {
+    get_app_config_dir().join("live")
+}
+
+/// 符号链接创建失败后写入的标记文件
+fn unsupported_marker() -> PathBuf {
+    live_root().join(".symlink-unsupported")
+}
+
+/// 之前是否已确认系统不支持符号链接
+pub(super) fn symlinks_supported() -> bool {
+    !unsupported_marker().exists()
+}
+
+/// 重新开启符号链接模式时清除标记，再尝试一次
+pub(super) fn reset_symlink_support() -> Result<(), AppError> {
+    let marker = unsupported_marker();
+    match fs::remove_file(&marker) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(AppError::io(&marker, e)),
+    }
+}
+
+fn mark_symlinks_unsupported() {
+    let marker = unsupported_marker();
+    if let Err(err) = crate::config::atomic_write(&marker, b"") {
+        log::warn!("记录符号链接不可用标记失败: {err}");
+    }
+}
+
+fn live_files(app: &AppType) -> Vec<PathBuf> {
+    match app {
+        AppType::Claude => vec![get_claude_settings_path()],
+        AppType::Codex => vec![get_codex_auth_path(), get_codex_config_path()],
+        AppType::Gemini => vec![get_gemini_env_path(), get_gemini_settings_path()],
+    }
+}
+
+/// 供应商 ID 可能包含路径分隔符等字符，转为安全的目录名
+fn provider_dir_name(provider_id: &str) -> String {
+    let name: String = provider_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match name.trim_matches('.') {
+        "" => "_".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+fn managed_path(app: &AppType, provider_id: &str, live: &Path) -> PathBuf {
+    let file_name = live
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    live_root()
+        .join(app.as_str())
+        .join(provider_dir_name(provider_id))
+        .join(file_name)
+}
+
+fn is_symlink(path: &Path) -> bool {
+    path.symlink_metadata()
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false)
+}
+
+fn is_regular_file(path: &Path) -> bool {
+    path.symlink_metadata()
+        .map(|m| m.file_type().is_file())
+        .unwrap_or(false)
+}
+
+#[cfg(unix)]
+fn create_symlink(src: &Path, dest: &Path) -> Result<(), AppError> {
+    std::os::unix::fs::symlink(src, dest).map_err(|e| AppError::IoContext {
+        context: format!("创建符号链接失败 ({} -> {})", src.display(), dest.display()),
+        source: e,
+    })
+}
+
+#[cfg(windows)]
+fn create_symlink(src: &Path, dest: &Path) -> Result<(), AppError> {
+    std::os::windows::fs::symlink_file(src, dest).map_err(|e| AppError::IoContext {
+        context: format!("创建符号链接失败 ({} -> {})", src.display(), dest.display()),
+        source: e,
+    })
+}
+
+/// 指向托管副本的 live 符号链接应写入其目标，避免原子替换把链接变回普通文件
+pub(crate) fn live_write_target(path: &Path) -> PathBuf {
+    if is_symlink(path) {
+        if let Ok(target) = fs::read_link(path) {
+            if target.starts_with(live_root()) {
+                return target;
+            }
+        }
+    }
+    path.to_path_buf()
+}
+
+/// 写入前备份尚未被接管的真实 live 文件
+pub(super) fn backup_real_files(app: &AppType) -> Result<(), AppError> {
+    let ts = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let backup_dir = live_root().join("backups").join(app.as_str());
+    for live in live_files(app) {
+        if !is_regular_file(&live) {
+            continue;
+        }
+        fs::create_dir_all(&backup_dir).map_err(|e| AppError::io(&backup_dir, e))?;
+        let file_name = live.file_name().unwrap_or_default().to_string_lossy();
+        let backup = backup_dir.join(format!("{file_name}.{ts}"));
+        fs::copy(&live, &backup).map_err(|e| AppError::io(&backup, e))?;
+        log::info!(
+            "已备份 live 文件 {} -> {}",
+            live.display(),
+            backup.display()
+        );
+    }
+    Ok(())
+}
+
+/// 把刚写入的 live 文件复制到供应商托管目录，再用指向该副本的符号链接原子替换原文件
+pub(super) fn link_live_files(app: &AppType, provider_id: &str) -> Result<(), AppError> {
+    for live in live_files(app) {
+        if !is_regular_file(&live) {
+            continue;
+        }
+        let managed = managed_path(app, provider_id, &live);
+        let content = fs::read(&live).map_err(|e| AppError::io(&live, e))?;
+        crate::config::atomic_write(&managed, &content)?;
+
+        // 先在同目录创建临时链接再 rename 覆盖，live 路径任何时刻都可读
+        let file_name = live.file_name().unwrap_or_default().to_string_lossy();
+        let tmp_link = live.with_file_name(format!(".{file_name}.cc-switch-link"));
+        let _ = fs::remove_file(&tmp_link);
+        match create_symlink(&managed, &tmp_link) {
+            Ok(()) => fs::rename(&tmp_link, &live).map_err(|e| AppError::io(&live, e))?,
+            Err(err) => {
+                // 无法创建符号链接（如 Windows 未开启开发者模式）时保留普通文件，且不再重试
+                log::warn!("live 文件符号链接创建失败，保留普通文件: {err}");
+                mark_symlinks_unsupported();
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 将指向托管副本的 live 符号链接还原为普通文件，返回还原的文件数
+pub(super) fn unlink_live_files() -> Result<usize, AppError> {
+    let root = live_root();
+    let mut restored = 0;
+    for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+        for live in live_files(&app) {
+            if !is_symlink(&live) {
+                continue;
+            }
+            let Ok(target) = fs::read_link(&live) else {
+                continue;
+            };
+            if !target.starts_with(&root) {
+                continue;
+            }
+            let content = fs::read(&target).map_err(|e| AppError::io(&target, e))?;
+            fs::remove_file(&live).map_err(|e| AppError::io(&live, e))?;
+            crate::config::atomic_write(&live, &content)?;
+            restored += 1;
+        }
+    }
+    Ok(restored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_dir_name_replaces_unsafe_characters() {
+        assert_eq!(provider_dir_name("openrouter"), "openrouter");
+        assert_eq!(provider_dir_name("team/a b"), "team_a_b");
+        assert_eq!(provider_dir_name(".."), "_");
+    }
+}
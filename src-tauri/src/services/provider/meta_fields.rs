@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::ProviderMeta;
+use crate::services::audit;
+use crate::store::AppState;
+
+use super::expiry::{parse_expiry_date, EXPIRY_DATE_FORMAT};
+use super::ProviderService;
+
+const MAX_META_KEY_LEN: usize = 64;
+
+/// 写入密钥到期日（同 `set-expiry`）而非自定义字段的键名
+const EXPIRES_AT_KEY: &str = "expires_at";
+
+/// 解析 `key=value`；`key=`（空值）表示删除该字段
+pub fn parse_meta_assignment(input: &str) -> Result<(String, Option<String>), AppError> {
+    let Some((key, value)) = input.split_once('=') else {
+        return Err(AppError::localized(
+            "provider.meta.invalid_assignment",
+            format!("格式应为 key=value: {input}"),
+            format!("Expected key=value: {input}"),
+        ));
+    };
+    let key = key.trim();
+    let valid_key = !key.is_empty()
+        && key.len() <= MAX_META_KEY_LEN
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid_key {
+        return Err(AppError::localized(
+            "provider.meta.invalid_key",
+            format!("字段名无效（仅支持字母、数字、-、_、.，最长 {MAX_META_KEY_LEN} 个字符）: {key}"),
+            format!(
+                "Invalid field name (letters, digits, '-', '_' and '.', max {MAX_META_KEY_LEN} characters): {key}"
+            ),
+        ));
+    }
+    let value = value.trim();
+    Ok((
+        key.to_string(),
+        (!value.is_empty()).then(|| value.to_string()),
+    ))
+}
+
+impl ProviderService {
+    /// 更新供应商的结构化元数据字段（值为 None 时删除），返回更新后的全部字段；
+    /// `expires_at` 会校验并写入密钥到期日
+    pub fn set_meta_fields(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        updates: Vec<(String, Option<String>)>,
+    ) -> Result<BTreeMap<String, String>, AppError> {
+        let result = Self::set_meta_fields_inner(state, app_type.clone(), provider_id, updates);
+        audit::record(
+            "provider.set_meta",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result
+    }
+
+    fn set_meta_fields_inner(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        updates: Vec<(String, Option<String>)>,
+    ) -> Result<BTreeMap<String, String>, AppError> {
+        let mut expiry = None;
+        let mut field_updates = Vec::new();
+        for (key, value) in updates {
+            if key == EXPIRES_AT_KEY {
+                let date = value.as_deref().map(parse_expiry_date).transpose()?;
+                expiry = Some(date.map(|date| date.format(EXPIRY_DATE_FORMAT).to_string()));
+            } else {
+                field_updates.push((key, value));
+            }
+        }
+
+        Self::update_meta(state, app_type, provider_id, |meta| {
+            if let Some(expires_at) = expiry {
+                meta.expires_at = expires_at;
+            }
+            for (key, value) in field_updates {
+                match value {
+                    Some(value) => {
+                        meta.fields.insert(key, value);
+                    }
+                    None => {
+                        meta.fields.remove(&key);
+                    }
+                }
+            }
+            meta.fields.clone()
+        })
+    }
+
+    /// 修改指定供应商的 meta（不存在时创建）并持久化
+    pub(super) fn update_meta<T>(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        update: impl FnOnce(&mut ProviderMeta) -> T,
+    ) -> Result<T, AppError> {
+        let result = {
+            let mut config = state.config.write().map_err(AppError::from)?;
+            let provider = config
+                .get_manager_mut(&app_type)
+                .and_then(|manager| manager.providers.get_mut(provider_id))
+                .ok_or_else(|| Self::provider_not_found(provider_id))?;
+            update(provider.meta.get_or_insert_with(Default::default))
+        };
+
+        state.save()?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_meta_assignment_handles_values_removals_and_bad_keys() {
+        assert_eq!(
+            parse_meta_assignment("owner = team-a").expect("valid"),
+            ("owner".to_string(), Some("team-a".to_string()))
+        );
+        assert_eq!(
+            parse_meta_assignment("note=a=b").expect("value may contain '='"),
+            ("note".to_string(), Some("a=b".to_string()))
+        );
+        assert_eq!(
+            parse_meta_assignment("expiry=").expect("removal"),
+            ("expiry".to_string(), None)
+        );
+        assert!(parse_meta_assignment("owner").is_err());
+        assert!(parse_meta_assignment("=x").is_err());
+        assert!(parse_meta_assignment("bad key=x").is_err());
+    }
+}
@@ -1,7 +1,23 @@
+mod common_snippet;
 mod endpoints;
+mod env_placeholder;
+mod expiry;
+mod failover;
 mod gemini_auth;
+mod headers;
+mod health;
+mod key_pool;
+mod key_rotation;
 mod live;
+mod live_backup;
+mod live_check;
+mod live_links;
+mod meta_fields;
+mod prune;
+mod switch_hook;
 mod usage;
+mod validation;
+mod verify;
 
 use indexmap::IndexMap;
 use serde::Deserialize;
@@ -15,14 +31,41 @@ use crate::config::{
 };
 use crate::error::AppError;
 use crate::provider::Provider;
+use crate::services::{audit, SkillService, SkillSyncReport};
 use crate::store::AppState;
 
+pub use common_snippet::CommonSnippetPreview;
+pub(crate) use env_placeholder::{resolve_env_placeholders, restore_env_placeholders};
+pub use expiry::{
+    collect_expiry_warnings, expiry_status, expiry_warning_days, parse_expiry_date,
+    provider_expiry_date, ExpiryStatus, ExpiryWarning,
+};
+pub use failover::{rank_failover_candidates, FailoverCandidate};
 use gemini_auth::GeminiAuthType;
+pub use headers::{
+    format_header_lines, parse_header_lines, provider_headers, set_provider_headers,
+};
+pub(crate) use headers::{keep_headers, strip_headers};
+pub use health::{AuthScheme, HealthStatus, HealthTarget, ProviderHealth};
+pub use key_pool::ApiKeyPool;
 use live::LiveSnapshot;
+pub(crate) use live_backup::track_own_write;
+pub(crate) use live_links::live_write_target;
+pub use live_links::LiveFileMethod;
+pub use meta_fields::parse_meta_assignment;
+pub use validation::ProviderValidation;
+pub use verify::{LiveField, LiveMismatch, LiveVerification};
 
 /// 供应商相关业务逻辑
 pub struct ProviderService;
 
+/// 切换完成后附带执行的操作结果
+#[derive(Debug, Default)]
+pub struct SwitchOutcome {
+    /// 启用 `sync_skills_on_switch` 时的 Skills 同步结果；未启用时为 None
+    pub skills_sync: Option<Result<SkillSyncReport, AppError>>,
+}
+
 #[cfg(test)]
 fn state_from_config(config: MultiAppConfig) -> AppState {
     AppState {
@@ -65,6 +108,8 @@ struct PostCommitAction {
     sync_mcp: bool,
     refresh_snapshot: bool,
     common_config_snippet: Option<String>,
+    /// 覆盖前备份自上次写入后被修改过的 live 文件
+    backup_modified_live: bool,
 }
 
 #[cfg(test)]
@@ -1374,6 +1419,10 @@ impl ProviderService {
             .as_ref()
             .and_then(|meta| meta.apply_common_config)
             .unwrap_or(true);
+        let live_synced = crate::sync_policy::should_sync_live(&action.app_type);
+        if action.backup_modified_live && live_synced {
+            live_backup::backup_modified(&action.app_type)?;
+        }
         Self::write_live_snapshot(
             &action.app_type,
             &action.provider,
@@ -1385,9 +1434,14 @@ impl ProviderService {
             use crate::services::mcp::McpService;
             McpService::sync_all_enabled(state)?;
         }
-        if action.refresh_snapshot && crate::sync_policy::should_sync_live(&action.app_type) {
+        if action.refresh_snapshot && live_synced {
             Self::refresh_provider_snapshot(state, &action.app_type, &action.provider.id)?;
         }
+        if live_synced {
+            if let Err(e) = live_backup::record_written(&action.app_type) {
+                log::warn!("记录 live 文件哈希失败: {e}");
+            }
+        }
 
         // D6: Align upstream live flows - also sync skills (best effort, should not block provider ops).
         if let Err(e) = crate::services::skill::SkillService::sync_all_enabled_best_effort() {
@@ -1429,6 +1483,8 @@ impl ProviderService {
                     let mut guard = state.config.write().map_err(AppError::from)?;
                     if let Some(manager) = guard.get_manager_mut(app_type) {
                         if let Some(target) = manager.providers.get_mut(provider_id) {
+                            restore_env_placeholders(&target.settings_config, &mut live_after);
+                            keep_headers(&target.settings_config, &mut live_after);
                             target.settings_config = live_after;
                         }
                     }
@@ -1461,6 +1517,7 @@ impl ProviderService {
                     }
                     if let Some(manager) = guard.get_manager_mut(app_type) {
                         if let Some(target) = manager.providers.get_mut(provider_id) {
+                            let original = target.settings_config.clone();
                             let obj = target.settings_config.as_object_mut().ok_or_else(|| {
                                 AppError::Config(format!(
                                     "供应商 {provider_id} 的 Codex 配置必须是 JSON 对象"
@@ -1472,6 +1529,7 @@ impl ProviderService {
                                 obj.remove("auth");
                             }
                             obj.insert("config".to_string(), Value::String(cfg_snippet.clone()));
+                            restore_env_placeholders(&original, &mut target.settings_config);
                         }
                     }
                 }
@@ -1520,6 +1578,8 @@ impl ProviderService {
                     let mut guard = state.config.write().map_err(AppError::from)?;
                     if let Some(manager) = guard.get_manager_mut(app_type) {
                         if let Some(target) = manager.providers.get_mut(provider_id) {
+                            restore_env_placeholders(&target.settings_config, &mut live_after);
+                            keep_headers(&target.settings_config, &mut live_after);
                             target.settings_config = live_after;
                         }
                     }
@@ -1596,7 +1656,7 @@ impl ProviderService {
         let app_type_clone = app_type.clone();
         let provider_clone = provider.clone();
 
-        Self::run_transaction(state, move |config| {
+        let result = Self::run_transaction(state, move |config| {
             config.ensure_app(&app_type_clone);
             let manager = config
                 .get_manager_mut(&app_type_clone)
@@ -1623,13 +1683,16 @@ impl ProviderService {
                     sync_mcp: false,
                     refresh_snapshot: false,
                     common_config_snippet,
+                    backup_modified_live: false,
                 })
             } else {
                 None
             };
 
             Ok((true, action))
-        })
+        });
+        audit::record("provider.add", Some(&app_type), Some(&provider.id), &result);
+        result
     }
 
     /// 更新供应商
@@ -1646,17 +1709,13 @@ impl ProviderService {
         let app_type_clone = app_type.clone();
         let provider_clone = provider.clone();
 
-        Self::run_transaction(state, move |config| {
+        let result = Self::run_transaction(state, move |config| {
             let manager = config
                 .get_manager_mut(&app_type_clone)
                 .ok_or_else(|| Self::app_not_found(&app_type_clone))?;
 
             if !manager.providers.contains_key(&provider_id) {
-                return Err(AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                ));
+                return Err(Self::provider_not_found(&provider_id));
             }
 
             let is_current = manager.current == provider_id;
@@ -1693,13 +1752,21 @@ impl ProviderService {
                     sync_mcp: false,
                     refresh_snapshot: false,
                     common_config_snippet,
+                    backup_modified_live: false,
                 })
             } else {
                 None
             };
 
             Ok((true, action))
-        })
+        });
+        audit::record(
+            "provider.update",
+            Some(&app_type),
+            Some(&provider.id),
+            &result,
+        );
+        result
     }
 
     /// 导入当前 live 配置为默认供应商
@@ -1885,12 +1952,69 @@ impl ProviderService {
     }
 
     /// 切换指定应用的供应商
-    pub fn switch(state: &AppState, app_type: AppType, provider_id: &str) -> Result<(), AppError> {
+    pub fn switch(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<SwitchOutcome, AppError> {
+        Self::switch_with_live_backup(state, app_type, provider_id, true)
+    }
+
+    /// 切换供应商；`backup_live` 为 false 时跳过对手动修改过的 live 文件的备份
+    pub fn switch_with_live_backup(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        backup_live: bool,
+    ) -> Result<SwitchOutcome, AppError> {
+        let result = Self::write_provider_to_live(state, &app_type, provider_id, backup_live, true);
+        audit::record(
+            "provider.switch",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result?;
+
+        Self::run_switch_hook(state, &app_type, provider_id);
+        Ok(SwitchOutcome {
+            skills_sync: SkillService::sync_on_switch(&app_type),
+        })
+    }
+
+    /// 把当前供应商重新写入 live 配置（如通用配置片段或供应商编辑后同步）。
+    /// 不属于用户切换：不轮换 Key 池，也不执行切换钩子与 Skills 同步
+    pub fn resync_live(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<(), AppError> {
+        let result = Self::write_provider_to_live(state, &app_type, provider_id, true, false);
+        audit::record(
+            "provider.resync",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result
+    }
+
+    /// 切换与重新同步共用的写入流程；`rotate_keys` 为 true 时先从 Key 池取出下一个 Key
+    fn write_provider_to_live(
+        state: &AppState,
+        app_type: &AppType,
+        provider_id: &str,
+        backup_live: bool,
+        rotate_keys: bool,
+    ) -> Result<(), AppError> {
         let app_type_clone = app_type.clone();
         let provider_id_owned = provider_id.to_string();
 
         Self::run_transaction(state, move |config| {
             let backup = Self::capture_live_snapshot(&app_type_clone)?;
+            if rotate_keys {
+                Self::advance_key_pool(config, &app_type_clone, &provider_id_owned);
+            }
             let provider = match app_type_clone {
                 AppType::Codex => Self::prepare_switch_codex(config, &provider_id_owned)?,
                 AppType::Claude => Self::prepare_switch_claude(config, &provider_id_owned)?,
@@ -1904,6 +2028,7 @@ impl ProviderService {
                 sync_mcp: true, // v3.7.0: 所有应用切换时都同步 MCP，防止配置丢失
                 refresh_snapshot: true,
                 common_config_snippet: config.common_config_snippets.get(&app_type_clone).cloned(),
+                backup_modified_live: backup_live,
             };
 
             Ok(((), Some(action)))
@@ -2019,13 +2144,7 @@ impl ProviderService {
             .providers
             .get(provider_id)
             .cloned()
-            .ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            .ok_or_else(|| Self::provider_not_found(provider_id))?;
 
         Self::backfill_codex_current(config, provider_id)?;
 
@@ -2075,6 +2194,7 @@ impl ProviderService {
                     current.settings_config = json!({});
                 }
 
+                let original = current.settings_config.clone();
                 let obj = current.settings_config.as_object_mut().unwrap();
                 if let Some(auth) = auth {
                     obj.insert("auth".to_string(), auth);
@@ -2082,6 +2202,7 @@ impl ProviderService {
                 if let Some(config_snippet) = config_snippet {
                     obj.insert("config".to_string(), Value::String(config_snippet));
                 }
+                restore_env_placeholders(&original, &mut current.settings_config);
             }
         }
 
@@ -2334,13 +2455,7 @@ impl ProviderService {
             .providers
             .get(provider_id)
             .cloned()
-            .ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            .ok_or_else(|| Self::provider_not_found(provider_id))?;
 
         Self::backfill_claude_current(config, provider_id)?;
 
@@ -2361,13 +2476,7 @@ impl ProviderService {
             .providers
             .get(provider_id)
             .cloned()
-            .ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            .ok_or_else(|| Self::provider_not_found(provider_id))?;
 
         Self::backfill_gemini_current(config, provider_id)?;
 
@@ -2406,6 +2515,8 @@ impl ProviderService {
         }
         if let Some(manager) = config.get_manager_mut(&AppType::Claude) {
             if let Some(current) = manager.providers.get_mut(&current_id) {
+                restore_env_placeholders(&current.settings_config, &mut live);
+                keep_headers(&current.settings_config, &mut live);
                 current.settings_config = live;
             }
         }
@@ -2457,6 +2568,8 @@ impl ProviderService {
 
         if let Some(manager) = config.get_manager_mut(&AppType::Gemini) {
             if let Some(current) = manager.providers.get_mut(&current_id) {
+                restore_env_placeholders(&current.settings_config, &mut live);
+                keep_headers(&current.settings_config, &mut live);
                 current.settings_config = live;
             }
         }
@@ -2473,28 +2586,32 @@ impl ProviderService {
         }
 
         let settings_path = get_claude_settings_path();
-        let mut provider_content = provider.settings_config.clone();
-        let _ = Self::normalize_claude_models_in_value(&mut provider_content);
-
-        let content_to_write = if let Some(snippet) = common_config_snippet {
-            let snippet = snippet.trim();
-            if snippet.is_empty() {
-                provider_content
-            } else {
-                let common = Self::parse_common_claude_config_snippet(snippet)?;
-                let mut merged = common;
-                merge_json_values(&mut merged, &provider_content);
-                let _ = Self::normalize_claude_models_in_value(&mut merged);
-                merged
-            }
-        } else {
-            provider_content
-        };
-
+        let content_to_write =
+            Self::build_claude_live_content(&provider.settings_config, common_config_snippet)?;
         write_json_file(&settings_path, &content_to_write)?;
         Ok(())
     }
 
+    /// 计算写入 Claude settings.json 的内容：去掉 headers、规范化模型字段，
+    /// 再合并通用配置片段（供应商字段优先）。切换写入与片段预览共用
+    pub(super) fn build_claude_live_content(
+        settings_config: &Value,
+        common_config_snippet: Option<&str>,
+    ) -> Result<Value, AppError> {
+        let mut provider_content = settings_config.clone();
+        strip_headers(&mut provider_content);
+        let _ = Self::normalize_claude_models_in_value(&mut provider_content);
+
+        let snippet = common_config_snippet.map(str::trim).unwrap_or_default();
+        if snippet.is_empty() {
+            return Ok(provider_content);
+        }
+        let mut merged = Self::parse_common_claude_config_snippet(snippet)?;
+        merge_json_values(&mut merged, &provider_content);
+        let _ = Self::normalize_claude_models_in_value(&mut merged);
+        Ok(merged)
+    }
+
     pub(crate) fn write_gemini_live(
         provider: &Provider,
         common_config_snippet: Option<&str>,
@@ -2634,6 +2751,18 @@ impl ProviderService {
         common_config_snippet: Option<&str>,
         apply_common_config: bool,
     ) -> Result<(), AppError> {
+        // 写入 live 前解析 `${env:NAME}` 占位符，存储的配置保持不含明文密钥
+        let mut resolved = provider.clone();
+        resolved.settings_config = resolve_env_placeholders(&provider.settings_config)?;
+        let provider = &resolved;
+
+        let symlink_mode = crate::settings::get_live_file_method() == LiveFileMethod::Symlink
+            && crate::sync_policy::should_sync_live(app_type)
+            && live_links::symlinks_supported();
+        if symlink_mode {
+            live_links::backup_real_files(app_type)?;
+        }
+
         match app_type {
             AppType::Codex => {
                 Self::write_codex_live(provider, common_config_snippet, apply_common_config)
@@ -2654,7 +2783,41 @@ impl ProviderService {
                     None
                 },
             ), // 新增
+        }?;
+
+        if symlink_mode {
+            live_links::link_live_files(app_type, &provider.id)?;
         }
+        Ok(())
+    }
+
+    /// 设置 live 文件写入方式，并立即转换当前各应用的 live 文件
+    pub fn set_live_file_method(state: &AppState, method: LiveFileMethod) -> Result<(), AppError> {
+        crate::settings::set_live_file_method(method)?;
+        match method {
+            LiveFileMethod::Copy => {
+                let restored = live_links::unlink_live_files()?;
+                log::info!("已将 {restored} 个 live 符号链接还原为普通文件");
+            }
+            LiveFileMethod::Symlink => {
+                live_links::reset_symlink_support()?;
+                for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+                    if !live_links::symlinks_supported() {
+                        break;
+                    }
+                    if !crate::sync_policy::should_sync_live(&app) {
+                        continue;
+                    }
+                    let current = Self::current(state, app.clone())?;
+                    if current.is_empty() {
+                        continue;
+                    }
+                    live_links::backup_real_files(&app)?;
+                    live_links::link_live_files(&app, &current)?;
+                }
+            }
+        }
+        Ok(())
     }
 
     fn validate_provider_settings(app_type: &AppType, provider: &Provider) -> Result<(), AppError> {
@@ -2764,28 +2927,70 @@ impl ProviderService {
         )
     }
 
+    pub(crate) fn provider_not_found(provider_id: &str) -> AppError {
+        AppError::localized(
+            "provider.not_found",
+            format!("供应商不存在: {provider_id}"),
+            format!("Provider not found: {provider_id}"),
+        )
+    }
+
     pub fn delete(state: &AppState, app_type: AppType, provider_id: &str) -> Result<(), AppError> {
+        let result = Self::delete_inner(state, app_type.clone(), provider_id, false);
+        audit::record(
+            "provider.delete",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result
+    }
+
+    /// 强制删除供应商；若为当前供应商则同时清空当前选择
+    pub fn force_delete(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<(), AppError> {
+        let result = Self::delete_inner(state, app_type.clone(), provider_id, true);
+        audit::record(
+            "provider.delete",
+            Some(&app_type),
+            Some(provider_id),
+            &result,
+        );
+        result
+    }
+
+    fn delete_current_error() -> AppError {
+        AppError::localized(
+            "provider.delete.current",
+            "不能删除当前正在使用的供应商，请先切换到其他供应商",
+            "Cannot delete the provider currently in use; switch to another provider first",
+        )
+    }
+
+    fn delete_inner(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+        force: bool,
+    ) -> Result<(), AppError> {
         let provider_snapshot = {
             let config = state.config.read().map_err(AppError::from)?;
             let manager = config
                 .get_manager(&app_type)
                 .ok_or_else(|| Self::app_not_found(&app_type))?;
 
-            if manager.current == provider_id {
-                return Err(AppError::localized(
-                    "provider.delete.current",
-                    "不能删除当前正在使用的供应商",
-                    "Cannot delete the provider currently in use",
-                ));
+            if manager.current == provider_id && !force {
+                return Err(Self::delete_current_error());
             }
 
-            manager.providers.get(provider_id).cloned().ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?
+            manager
+                .providers
+                .get(provider_id)
+                .cloned()
+                .ok_or_else(|| Self::provider_not_found(provider_id))?
         };
 
         match app_type {
@@ -2815,11 +3020,10 @@ impl ProviderService {
                 .ok_or_else(|| Self::app_not_found(&app_type))?;
 
             if manager.current == provider_id {
-                return Err(AppError::localized(
-                    "provider.delete.current",
-                    "不能删除当前正在使用的供应商",
-                    "Cannot delete the provider currently in use",
-                ));
+                if !force {
+                    return Err(Self::delete_current_error());
+                }
+                manager.current.clear();
             }
 
             manager.providers.shift_remove(provider_id);
@@ -0,0 +1,109 @@
+//! 清理端点不可达的供应商：测速全部供应商（当前供应商除外），
+//! 将失败/超时的供应商归档到 `~/.cc-switch/backups/providers/` 后删除，或直接删除。
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde_json::json;
+
+use crate::app_config::AppType;
+use crate::config::get_app_config_dir;
+use crate::error::AppError;
+use crate::store::AppState;
+
+use super::live_backup::unique_backup_path;
+use super::{provider_headers, resolve_env_placeholders, FailoverCandidate, ProviderService};
+
+/// 归档文件的存放目录
+fn provider_archive_dir() -> PathBuf {
+    get_app_config_dir().join("backups").join("providers")
+}
+
+impl FailoverCandidate {
+    /// 配置了端点但测速失败或超时
+    pub fn is_unreachable(&self) -> bool {
+        self.url.is_some() && self.latency.is_none()
+    }
+}
+
+impl ProviderService {
+    /// 除当前供应商外的所有供应商，尚未测速；占位符无法解析的供应商不参与测速
+    pub fn prune_candidates(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<FailoverCandidate>, AppError> {
+        let current = Self::current(state, app_type.clone())?;
+        let mut providers: Vec<_> = Self::list(state, app_type.clone())?
+            .into_values()
+            .filter(|p| p.id != current)
+            .collect();
+        providers.sort_by(|a, b| {
+            (a.sort_index.unwrap_or(usize::MAX), &a.id)
+                .cmp(&(b.sort_index.unwrap_or(usize::MAX), &b.id))
+        });
+
+        Ok(providers
+            .into_iter()
+            .map(|mut provider| {
+                let resolved = resolve_env_placeholders(&provider.settings_config);
+                let url = resolved.ok().and_then(|settings| {
+                    provider.settings_config = settings;
+                    Self::extract_base_url(&provider, &app_type).ok()
+                });
+                FailoverCandidate {
+                    url,
+                    headers: provider_headers(&provider.settings_config),
+                    provider_id: provider.id,
+                    provider_name: provider.name,
+                    latency: None,
+                    status: None,
+                    error: None,
+                }
+            })
+            .collect())
+    }
+
+    /// 删除指定供应商；`archive` 为 true 时先把完整配置写入归档文件并返回其路径。
+    /// 当前供应商始终跳过
+    pub fn prune_providers(
+        state: &AppState,
+        app_type: AppType,
+        ids: &[String],
+        archive: bool,
+    ) -> Result<Option<PathBuf>, AppError> {
+        let current = Self::current(state, app_type.clone())?;
+        let ids: Vec<&String> = ids.iter().filter(|id| **id != current).collect();
+        if ids.is_empty() {
+            return Ok(None);
+        }
+
+        let archive_path = if archive {
+            let providers = Self::list(state, app_type.clone())?;
+            let archived: Vec<_> = ids.iter().filter_map(|id| providers.get(*id)).collect();
+            let ts = chrono::Local::now();
+            let dir = provider_archive_dir();
+            fs::create_dir_all(&dir).map_err(|e| AppError::io(&dir, e))?;
+            let path = unique_backup_path(
+                &dir,
+                &format!("{}-{}", app_type.as_str(), ts.format("%Y%m%d-%H%M%S")),
+                ".json",
+            );
+            let content = json!({
+                "app": app_type.as_str(),
+                "archivedAt": ts.to_rfc3339(),
+                "providers": archived,
+            });
+            let bytes = serde_json::to_vec_pretty(&content)
+                .map_err(|source| AppError::JsonSerialize { source })?;
+            crate::config::atomic_write(&path, &bytes)?;
+            Some(path)
+        } else {
+            None
+        };
+
+        for id in ids {
+            Self::delete(state, app_type.clone(), id)?;
+        }
+        Ok(archive_path)
+    }
+}
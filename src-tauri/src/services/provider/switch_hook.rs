@@ -0,0 +1,143 @@
+//! 切换后钩子：切换成功后执行用户配置的命令（如刷新 tmux 状态栏），失败只记录日志
+
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use crate::app_config::AppType;
+use crate::store::AppState;
+
+use super::ProviderService;
+
+/// 钩子最长运行时间，超时后终止，避免卡住切换流程
+const HOOK_TIMEOUT: Duration = Duration::from_secs(5);
+const HOOK_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+impl ProviderService {
+    /// 若配置了 `on_switch_command`，以 shell 执行它并通过环境变量传入切换结果
+    pub(super) fn run_switch_hook(state: &AppState, app_type: &AppType, provider_id: &str) {
+        let Some(template) = crate::settings::get_on_switch_command() else {
+            return;
+        };
+
+        let provider_name = state
+            .config
+            .read()
+            .ok()
+            .and_then(|config| {
+                config
+                    .get_manager(app_type)
+                    .and_then(|manager| manager.providers.get(provider_id))
+                    .map(|provider| provider.name.clone())
+            })
+            .unwrap_or_default();
+
+        let child = shell_command(&template)
+            .env("CC_SWITCH_APP", app_type.as_str())
+            .env("CC_SWITCH_PROVIDER_ID", provider_id)
+            .env("CC_SWITCH_PROVIDER_NAME", provider_name)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn();
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                log::warn!("切换钩子启动失败: {template}: {e}");
+                return;
+            }
+        };
+
+        // 在后台读取 stderr，避免输出过多时写满管道阻塞子进程
+        let (stderr_tx, stderr_rx) = mpsc::channel();
+        if let Some(mut stderr) = child.stderr.take() {
+            std::thread::spawn(move || {
+                let mut buf = String::new();
+                let _ = stderr.read_to_string(&mut buf);
+                let _ = stderr_tx.send(buf);
+            });
+        }
+
+        match wait_with_timeout(&mut child, HOOK_TIMEOUT) {
+            Ok(Some(status)) if status.success() => {
+                log::debug!("切换钩子执行成功: {template}");
+            }
+            Ok(Some(status)) => log::warn!(
+                "切换钩子退出状态异常 ({status}): {template}: {}",
+                stderr_rx
+                    .recv_timeout(HOOK_POLL_INTERVAL)
+                    .unwrap_or_default()
+                    .trim()
+            ),
+            Ok(None) => {
+                kill_hook(&mut child);
+                let _ = child.wait();
+                log::warn!(
+                    "切换钩子超过 {} 秒未结束，已终止: {template}",
+                    HOOK_TIMEOUT.as_secs()
+                );
+            }
+            Err(e) => log::warn!("切换钩子等待失败: {template}: {e}"),
+        }
+    }
+}
+
+/// 等待子进程退出；超时返回 `Ok(None)`，由调用方终止
+fn wait_with_timeout(child: &mut Child, timeout: Duration) -> std::io::Result<Option<ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            return Ok(None);
+        }
+        std::thread::sleep(HOOK_POLL_INTERVAL);
+    }
+}
+
+#[cfg(windows)]
+fn shell_command(template: &str) -> Command {
+    let mut command = Command::new("cmd");
+    command.args(["/C", template]);
+    command
+}
+
+/// 钩子在独立的进程组中运行，超时后可连同它派生的子进程一起终止
+#[cfg(not(windows))]
+fn shell_command(template: &str) -> Command {
+    use std::os::unix::process::CommandExt;
+
+    let mut command = Command::new("sh");
+    command.args(["-c", template]).process_group(0);
+    command
+}
+
+/// 终止钩子的整个进程树，失败时退回只终止 shell 本身
+#[cfg(windows)]
+fn kill_hook(child: &mut Child) {
+    let killed = Command::new("taskkill")
+        .args(["/T", "/F", "/PID", &child.id().to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    if !killed {
+        let _ = child.kill();
+    }
+}
+
+/// 向钩子所在的进程组发送 SIGKILL，失败时退回只终止 shell 本身
+#[cfg(not(windows))]
+fn kill_hook(child: &mut Child) {
+    let killed = Command::new("kill")
+        .args(["-KILL", "--", &format!("-{}", child.id())])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    if !killed {
+        let _ = child.kill();
+    }
+}
@@ -7,7 +7,7 @@ use crate::settings;
 use crate::store::AppState;
 use crate::usage_script;
 
-use super::ProviderService;
+use super::{resolve_env_placeholders, ProviderService};
 
 impl ProviderService {
     /// 执行用量脚本并格式化结果（私有辅助方法）
@@ -91,13 +91,11 @@ impl ProviderService {
             let manager = config
                 .get_manager(&app_type)
                 .ok_or_else(|| Self::app_not_found(&app_type))?;
-            let provider = manager.providers.get(provider_id).cloned().ok_or_else(|| {
-                AppError::localized(
-                    "provider.not_found",
-                    format!("供应商不存在: {provider_id}"),
-                    format!("Provider not found: {provider_id}"),
-                )
-            })?;
+            let provider = manager
+                .providers
+                .get(provider_id)
+                .cloned()
+                .ok_or_else(|| Self::provider_not_found(provider_id))?;
 
             let usage_script = provider
                 .meta
@@ -187,7 +185,35 @@ impl ProviderService {
         Ok(())
     }
 
-    fn extract_api_key(provider: &Provider, app_type: &AppType) -> Result<String, AppError> {
+    /// 当前供应商的明文 API Key（已解析 `${env:NAME}` 占位符）
+    pub fn current_api_key(state: &AppState, app_type: AppType) -> Result<String, AppError> {
+        let current_id = Self::current(state, app_type.clone())?;
+        let mut provider = Self::list(state, app_type.clone())?
+            .shift_remove(&current_id)
+            .ok_or_else(|| {
+                AppError::localized(
+                    "provider.current.missing",
+                    "当前没有激活的供应商",
+                    "No current provider is set",
+                )
+            })?;
+        provider.settings_config = resolve_env_placeholders(&provider.settings_config)?;
+
+        let key = Self::extract_api_key(&provider, &app_type)?;
+        if key.trim().is_empty() {
+            return Err(AppError::localized(
+                "provider.api_key.empty",
+                format!("供应商 {current_id} 的 API Key 为空"),
+                format!("API key of provider {current_id} is empty"),
+            ));
+        }
+        Ok(key)
+    }
+
+    pub(super) fn extract_api_key(
+        provider: &Provider,
+        app_type: &AppType,
+    ) -> Result<String, AppError> {
         match app_type {
             AppType::Claude => {
                 let env = provider
@@ -254,7 +280,10 @@ impl ProviderService {
         }
     }
 
-    fn extract_base_url(provider: &Provider, app_type: &AppType) -> Result<String, AppError> {
+    pub(super) fn extract_base_url(
+        provider: &Provider,
+        app_type: &AppType,
+    ) -> Result<String, AppError> {
         match app_type {
             AppType::Claude => provider
                 .settings_config
@@ -0,0 +1,107 @@
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::{is_codex_official_provider, ProviderService};
+
+/// 单个供应商的校验结果
+#[derive(Debug, Clone)]
+pub struct ProviderValidation {
+    pub id: String,
+    pub name: String,
+    pub problems: Vec<String>,
+}
+
+impl ProviderValidation {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+impl ProviderService {
+    /// 逐个校验指定应用下的全部供应商，单个失败不会中断其余校验
+    pub fn validate_all(
+        state: &AppState,
+        app_type: AppType,
+    ) -> Result<Vec<ProviderValidation>, AppError> {
+        let providers = Self::list(state, app_type.clone())?;
+        Ok(providers
+            .values()
+            .map(|provider| ProviderValidation {
+                id: provider.id.clone(),
+                name: provider.name.clone(),
+                problems: Self::provider_problems(&app_type, provider),
+            })
+            .collect())
+    }
+
+    fn provider_problems(app_type: &AppType, provider: &Provider) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Err(err) = Self::validate_provider_settings(app_type, provider) {
+            problems.push(err.to_string());
+        }
+
+        // 官方供应商使用内置端点；Gemini 未配置 Base URL 时使用 Google 官方端点
+        let needs_base_url = match app_type {
+            AppType::Claude => !provider
+                .category
+                .as_deref()
+                .is_some_and(|category| category.eq_ignore_ascii_case("official")),
+            AppType::Codex => !is_codex_official_provider(provider),
+            AppType::Gemini => false,
+        };
+        let has_base_url =
+            Self::extract_base_url(provider, app_type).is_ok_and(|url| !url.trim().is_empty());
+        if needs_base_url && !has_base_url {
+            problems.push(
+                AppError::localized(
+                    "provider.base_url.missing",
+                    "缺少 Base URL",
+                    "Base URL is missing",
+                )
+                .to_string(),
+            );
+        }
+
+        problems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn provider_problems_reports_invalid_codex_toml_and_missing_base_url() {
+        let provider = Provider::with_id(
+            "broken".to_string(),
+            "Broken".to_string(),
+            json!({
+                "auth": { "OPENAI_API_KEY": "sk-test" },
+                "config": "model = [unterminated"
+            }),
+            None,
+        );
+        let problems = ProviderService::provider_problems(&AppType::Codex, &provider);
+        assert_eq!(problems.len(), 2, "problems: {problems:?}");
+        assert!(problems[1].contains("Base URL is missing"));
+    }
+
+    #[test]
+    fn provider_problems_accepts_complete_claude_provider() {
+        let provider = Provider::with_id(
+            "ok".to_string(),
+            "OK".to_string(),
+            json!({
+                "env": {
+                    "ANTHROPIC_BASE_URL": "https://relay.example",
+                    "ANTHROPIC_AUTH_TOKEN": "sk-test"
+                }
+            }),
+            None,
+        );
+        assert!(ProviderService::provider_problems(&AppType::Claude, &provider).is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::app_config::AppType;
+use crate::error::AppError;
+use crate::provider::Provider;
+use crate::store::AppState;
+
+use super::{resolve_env_placeholders, ProviderService};
+
+/// 参与核对的 live 字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveField {
+    BaseUrl,
+    ApiKey,
+}
+
+impl LiveField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            LiveField::BaseUrl => "Base URL",
+            LiveField::ApiKey => "API Key",
+        }
+    }
+}
+
+/// live 文件中与供应商不一致的字段；`actual` 为 None 表示 live 中缺失
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveMismatch {
+    pub field: LiveField,
+    pub expected: String,
+    pub actual: Option<String>,
+}
+
+/// 切换后对 live 文件的核对结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LiveVerification {
+    /// live 文件中的 Base URL / API Key 与供应商一致
+    Verified,
+    /// 应用未初始化，未写入 live 文件
+    Skipped,
+    Mismatch(Vec<LiveMismatch>),
+}
+
+impl ProviderService {
+    /// 重新读取 live 文件，核对其中的 Base URL / API Key 是否与指定供应商一致
+    pub fn verify_live(
+        state: &AppState,
+        app_type: AppType,
+        provider_id: &str,
+    ) -> Result<LiveVerification, AppError> {
+        if !crate::sync_policy::should_sync_live(&app_type) {
+            return Ok(LiveVerification::Skipped);
+        }
+
+        let mut provider = Self::list(state, app_type.clone())?
+            .shift_remove(provider_id)
+            .ok_or_else(|| Self::provider_not_found(provider_id))?;
+        provider.settings_config = resolve_env_placeholders(&provider.settings_config)?;
+
+        let live = Provider::with_id(
+            provider.id.clone(),
+            provider.name.clone(),
+            Self::read_live_settings(app_type.clone())?,
+            None,
+        );
+
+        let mut mismatches = Vec::new();
+        for field in [LiveField::BaseUrl, LiveField::ApiKey] {
+            let extract = |p: &Provider| match field {
+                LiveField::BaseUrl => Self::extract_base_url(p, &app_type),
+                LiveField::ApiKey => Self::extract_api_key(p, &app_type),
+            };
+            // 供应商本身未配置的字段（如官方登录）不参与核对
+            let Ok(expected) = extract(&provider) else {
+                continue;
+            };
+            let actual = extract(&live).ok();
+            if actual.as_deref() != Some(expected.as_str()) {
+                mismatches.push(LiveMismatch {
+                    field,
+                    expected,
+                    actual,
+                });
+            }
+        }
+
+        Ok(if mismatches.is_empty() {
+            LiveVerification::Verified
+        } else {
+            LiveVerification::Mismatch(mismatches)
+        })
+    }
+}
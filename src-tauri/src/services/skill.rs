@@ -8,9 +8,10 @@ use chrono::{DateTime, Utc};
 use futures::future::join_all;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use tokio::time::timeout;
 
 use crate::app_config::AppType;
@@ -18,8 +19,15 @@ pub use crate::app_config::{InstalledSkill, SkillApps, UnmanagedSkill};
 use crate::config::get_app_config_dir;
 use crate::database::Database;
 use crate::error::{format_skill_error, AppError};
+use crate::services::diagnostic::Diagnostic;
 
 const SKILLS_INDEX_VERSION: u32 = 1;
+/// SSOT 迁移失败时记录错误信息的设置键
+const SSOT_MIGRATION_ERROR_KEY: &str = "skills_ssot_migration_error";
+/// 最近一次 `import_from_apps` 新建的 SSOT 目录（JSON 数组），供撤销使用
+const LAST_APP_IMPORT_KEY: &str = "skills_last_app_import";
+/// Auto 同步时已回退为复制的 Skill 目录（JSON 数组），再次回退不重复告警
+const COPY_FALLBACKS_KEY: &str = "skills_copy_fallbacks";
 
 fn default_skills_index_version() -> u32 {
     SKILLS_INDEX_VERSION
@@ -40,6 +48,38 @@ pub struct SkillRepo {
     pub branch: String,
     /// 是否启用
     pub enabled: bool,
+    /// 分支下载失败时依次尝试的备用分支（在全局备用分支与 main/master 之前）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_branches: Vec<String>,
+}
+
+impl SkillRepo {
+    /// 解析 `branch[,fallback...]`，返回主分支与备用分支（主分支缺省为 main）
+    pub fn parse_branch_list(raw: Option<&str>) -> (String, Vec<String>) {
+        let mut branches = raw
+            .unwrap_or("")
+            .split(',')
+            .map(str::trim)
+            .filter(|b| !b.is_empty())
+            .map(str::to_string);
+        let branch = branches.next().unwrap_or_else(|| "main".to_string());
+        (branch, branches.collect())
+    }
+
+    /// 下载时依次尝试的分支：主分支 → 仓库备用分支 → 全局备用分支 → main/master（去重）
+    pub fn download_branches(&self, global_fallbacks: &[String]) -> Vec<String> {
+        let mut branches: Vec<String> = Vec::new();
+        let candidates = std::iter::once(self.branch.as_str())
+            .chain(self.fallback_branches.iter().map(String::as_str))
+            .chain(global_fallbacks.iter().map(String::as_str))
+            .chain(["main", "master"]);
+        for branch in candidates.map(str::trim) {
+            if !branch.is_empty() && !branches.iter().any(|b| b == branch) {
+                branches.push(branch.to_string());
+            }
+        }
+        branches
+    }
 }
 
 /// Legacy install state: directory -> installed timestamp (Claude-only era).
@@ -72,24 +112,28 @@ impl Default for SkillStore {
                     name: "skills".to_string(),
                     branch: "main".to_string(),
                     enabled: true,
+                    fallback_branches: Vec::new(),
                 },
                 SkillRepo {
                     owner: "ComposioHQ".to_string(),
                     name: "awesome-claude-skills".to_string(),
                     branch: "master".to_string(),
                     enabled: true,
+                    fallback_branches: Vec::new(),
                 },
                 SkillRepo {
                     owner: "cexll".to_string(),
                     name: "myclaude".to_string(),
                     branch: "master".to_string(),
                     enabled: true,
+                    fallback_branches: Vec::new(),
                 },
                 SkillRepo {
                     owner: "JimLiu".to_string(),
                     name: "baoyu-skills".to_string(),
                     branch: "main".to_string(),
                     enabled: true,
+                    fallback_branches: Vec::new(),
                 },
             ],
         }
@@ -113,6 +157,89 @@ pub enum SyncMethod {
     Copy,
 }
 
+/// 单个 Skill 同步到某个应用的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkillSyncStatus {
+    /// 通过符号链接同步
+    Symlinked,
+    /// 通过目录复制同步
+    Copied,
+    /// 未对该应用启用，已跳过
+    Skipped,
+    /// 已从应用目录移除（停用时）
+    Removed,
+    /// 同步失败（附错误信息）
+    Failed(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct SkillSyncEntry {
+    pub app: AppType,
+    pub directory: String,
+    pub status: SkillSyncStatus,
+}
+
+/// Skills 同步汇总（按应用/Skill 记录结果）
+#[derive(Debug, Clone, Default)]
+pub struct SkillSyncReport {
+    pub entries: Vec<SkillSyncEntry>,
+}
+
+impl SkillSyncReport {
+    pub fn synced_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e.status,
+                    SkillSyncStatus::Symlinked | SkillSyncStatus::Copied
+                )
+            })
+            .count()
+    }
+
+    pub fn skipped_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| e.status == SkillSyncStatus::Skipped)
+            .count()
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e.status, SkillSyncStatus::Failed(_)))
+            .count()
+    }
+
+    fn extend(&mut self, other: SkillSyncReport) {
+        self.entries.extend(other.entries);
+    }
+
+    /// 存在失败项时汇总为一个错误（列出所有失败的应用/Skill）
+    pub fn ensure_no_failures(&self) -> Result<(), AppError> {
+        let failures: Vec<String> = self
+            .entries
+            .iter()
+            .filter_map(|e| match &e.status {
+                SkillSyncStatus::Failed(err) => {
+                    Some(format!("{}/{}: {err}", e.app.as_str(), e.directory))
+                }
+                _ => None,
+            })
+            .collect();
+        if failures.is_empty() {
+            return Ok(());
+        }
+        let detail = failures.join("\n  ");
+        Err(AppError::localized(
+            "skills.sync.partial_failure",
+            format!("{} 个 Skill 同步失败:\n  {detail}", failures.len()),
+            format!("{} skill sync(s) failed:\n  {detail}", failures.len()),
+        ))
+    }
+}
+
 /// skills.json (SSOT index; no DB).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -165,6 +292,31 @@ pub struct DiscoverableSkill {
     pub repo_name: String,
     #[serde(rename = "repoBranch")]
     pub repo_branch: String,
+    /// SKILL.md body text; only loaded for deep search.
+    #[serde(skip)]
+    pub body: Option<String>,
+}
+
+/// Result of `skills install --dry-run`: what a real install would do.
+#[derive(Debug, Clone)]
+pub struct InstallPlan {
+    pub skill: DiscoverableSkill,
+    pub install_name: String,
+    /// Where the skill would be stored in the SSOT directory.
+    pub ssot_path: PathBuf,
+    /// Where the skill would be synced for the target app.
+    pub app_path: PathBuf,
+    /// Same repo already installed: only the app would be enabled.
+    pub already_installed: bool,
+}
+
+/// Progress of a discovery run, reported each time a repo finishes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoverProgress {
+    pub done: usize,
+    pub total: usize,
+    /// Repos still being fetched ("owner/name").
+    pub pending: Vec<String>,
 }
 
 /// CLI-friendly skill object (discoverable + installed flag).
@@ -184,6 +336,9 @@ pub struct Skill {
     pub repo_name: Option<String>,
     #[serde(rename = "repoBranch")]
     pub repo_branch: Option<String>,
+    /// SKILL.md body text; only loaded for deep search.
+    #[serde(skip)]
+    pub body: Option<String>,
 }
 
 /// Skill metadata extracted from SKILL.md YAML front matter.
@@ -222,8 +377,12 @@ impl SkillService {
     // Paths
     // ---------------------------------------------------------------------
 
+    fn ssot_dir_path() -> PathBuf {
+        get_app_config_dir().join("skills")
+    }
+
     pub fn get_ssot_dir() -> Result<PathBuf, AppError> {
-        let dir = get_app_config_dir().join("skills");
+        let dir = Self::ssot_dir_path();
         fs::create_dir_all(&dir).map_err(|e| AppError::io(&dir, e))?;
         Ok(dir)
     }
@@ -320,6 +479,34 @@ impl SkillService {
         }
 
         let db = Database::init()?;
+
+        // 在副本上迁移：任何一步失败都不写回索引、保留待迁移标记并记录错误，下次运行干净重试
+        let mut migrated = index.clone();
+        let created = match Self::run_ssot_migration(&mut migrated) {
+            Ok(created) => created,
+            Err(err) => {
+                log::warn!("SSOT 迁移失败，将在下次运行时重试: {err}");
+                let _ = db.set_setting(SSOT_MIGRATION_ERROR_KEY, &err.to_string());
+                return Err(err);
+            }
+        };
+
+        migrated.ssot_migration_pending = false;
+        Self::save_index(&migrated)?;
+        db.set_setting("skills_ssot_migration_pending", "false")?;
+        db.set_setting(SSOT_MIGRATION_ERROR_KEY, "")?;
+        *index = migrated;
+        Ok(created)
+    }
+
+    /// 上次 SSOT 迁移失败时记录的错误（成功后清空）
+    pub fn ssot_migration_error() -> Result<Option<String>, AppError> {
+        Ok(Database::init()?
+            .get_setting(SSOT_MIGRATION_ERROR_KEY)?
+            .filter(|err| !err.is_empty()))
+    }
+
+    fn run_ssot_migration(index: &mut SkillsIndex) -> Result<usize, AppError> {
         let ssot_dir = Self::get_ssot_dir()?;
         let mut created = 0usize;
 
@@ -327,7 +514,7 @@ impl SkillService {
         // - If we already have managed skills in the index, do NOT auto-import everything
         //   from app dirs (that could unexpectedly "claim" user directories as managed).
         // - Instead, only try to populate SSOT for the already-managed skills (best effort),
-        //   then clear the pending flag.
+        //   then let the caller clear the pending flag.
         if !index.skills.is_empty() {
             for (directory, record) in index.skills.iter_mut() {
                 let dest = ssot_dir.join(directory);
@@ -360,7 +547,7 @@ impl SkillService {
 
                 match source {
                     Some(source) => {
-                        Self::copy_dir_recursive(&source, &dest)?;
+                        Self::copy_dir_staged(&source, &dest)?;
                         created += 1;
 
                         // Backfill metadata if missing.
@@ -387,9 +574,6 @@ impl SkillService {
                 }
             }
 
-            index.ssot_migration_pending = false;
-            let _ = db.set_setting("skills_ssot_migration_pending", "false");
-            Self::save_index(index)?;
             return Ok(created);
         }
 
@@ -419,7 +603,7 @@ impl SkillService {
                 // Copy to SSOT if needed.
                 let ssot_path = ssot_dir.join(&dir_name);
                 if !ssot_path.exists() {
-                    Self::copy_dir_recursive(&path, &ssot_path)?;
+                    Self::copy_dir_staged(&path, &ssot_path)?;
                 }
 
                 discovered
@@ -476,9 +660,6 @@ impl SkillService {
             }
         }
 
-        index.ssot_migration_pending = false;
-        let _ = db.set_setting("skills_ssot_migration_pending", "false");
-        Self::save_index(index)?;
         Ok(created)
     }
 
@@ -502,6 +683,17 @@ impl SkillService {
         })
     }
 
+    /// 是否为指向 `dir` 内部的软链接（按解析后的真实路径判断）
+    fn is_symlink_into(path: &Path, dir: Option<&Path>) -> bool {
+        let Some(dir) = dir else {
+            return false;
+        };
+        Self::is_symlink(path)
+            && path
+                .canonicalize()
+                .is_ok_and(|target| target.starts_with(dir))
+    }
+
     fn is_symlink(path: &Path) -> bool {
         path.symlink_metadata()
             .map(|m| m.file_type().is_symlink())
@@ -525,11 +717,25 @@ impl SkillService {
         Ok(())
     }
 
+    /// 同步单个 Skill 到应用目录，返回实际使用的方式（符号链接或复制）
     pub fn sync_to_app_dir(
         directory: &str,
         app: &AppType,
         method: SyncMethod,
-    ) -> Result<(), AppError> {
+    ) -> Result<SkillSyncStatus, AppError> {
+        let mut fallbacks = CopyFallbacks::load_for(method)?;
+        let status = Self::sync_dir(directory, app, method, fallbacks.as_mut())?;
+        CopyFallbacks::save_if_loaded(fallbacks)?;
+        Ok(status)
+    }
+
+    /// 同步单个 Skill；Auto 模式下在 `fallbacks` 中记录是否回退为复制（由调用方统一保存）
+    fn sync_dir(
+        directory: &str,
+        app: &AppType,
+        method: SyncMethod,
+        fallbacks: Option<&mut CopyFallbacks>,
+    ) -> Result<SkillSyncStatus, AppError> {
         let ssot_dir = Self::get_ssot_dir()?;
         let source = ssot_dir.join(directory);
         if !source.exists() {
@@ -543,27 +749,60 @@ impl SkillService {
         fs::create_dir_all(&app_dir).map_err(|e| AppError::io(&app_dir, e))?;
 
         let dest = app_dir.join(directory);
-        if dest.exists() || Self::is_symlink(&dest) {
+        // 复制模式下已有的真实目录做增量更新，其余情况先清理再重新创建
+        let incremental = method == SyncMethod::Copy && dest.is_dir() && !Self::is_symlink(&dest);
+        if !incremental && (dest.exists() || Self::is_symlink(&dest)) {
             Self::remove_path(&dest)?;
         }
 
         match method {
             SyncMethod::Auto => match Self::create_symlink(&source, &dest) {
-                Ok(()) => Ok(()),
+                Ok(()) => {
+                    if let Some(fallbacks) = fallbacks {
+                        fallbacks.set(directory, false);
+                    }
+                    Ok(SkillSyncStatus::Symlinked)
+                }
                 Err(err) => {
-                    log::warn!(
+                    let recorded = fallbacks
+                        .as_deref()
+                        .is_some_and(|fallbacks| fallbacks.contains(directory));
+                    let level = copy_fallback_log_level(
+                        crate::settings::get_quiet_skill_copy_fallback(),
+                        recorded,
+                    );
+                    log::log!(
+                        level,
                         "Symlink 创建失败，将回退到文件复制: {} -> {}. 错误: {err}",
                         source.display(),
                         dest.display()
                     );
-                    Self::copy_dir_recursive(&source, &dest)
+                    Self::copy_dir_recursive(&source, &dest)?;
+                    if let Some(fallbacks) = fallbacks {
+                        fallbacks.set(directory, true);
+                    }
+                    Ok(SkillSyncStatus::Copied)
                 }
             },
-            SyncMethod::Symlink => Self::create_symlink(&source, &dest),
-            SyncMethod::Copy => Self::copy_dir_recursive(&source, &dest),
+            SyncMethod::Symlink => {
+                Self::create_symlink(&source, &dest)?;
+                Ok(SkillSyncStatus::Symlinked)
+            }
+            SyncMethod::Copy => {
+                Self::copy_dir_incremental(&source, &dest)?;
+                Ok(SkillSyncStatus::Copied)
+            }
         }
     }
 
+    /// Auto 同步时已回退为复制的 Skill 目录（按名称排序）
+    pub fn copy_fallbacks() -> Result<Vec<String>, AppError> {
+        Ok(Database::init()?
+            .get_setting(COPY_FALLBACKS_KEY)?
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default())
+    }
+
     pub fn remove_from_app(directory: &str, app: &AppType) -> Result<(), AppError> {
         let app_dir = Self::get_app_skills_dir(app)?;
         let path = app_dir.join(directory);
@@ -573,41 +812,75 @@ impl SkillService {
         Ok(())
     }
 
-    pub fn sync_to_app(index: &SkillsIndex, app: &AppType) -> Result<(), AppError> {
-        for skill in index.skills.values() {
-            if skill.apps.is_enabled_for(app) {
-                Self::sync_to_app_dir(&skill.directory, app, index.sync_method)?;
-            }
+    /// 同步所有已启用的 Skill 到指定应用，逐个记录结果（单个失败不影响其他 Skill）；
+    /// 复制回退记录只在开始时读取一次、结束时保存一次
+    pub fn sync_to_app(index: &SkillsIndex, app: &AppType) -> Result<SkillSyncReport, AppError> {
+        let mut skills: Vec<&InstalledSkill> = index.skills.values().collect();
+        skills.sort_by(|a, b| a.directory.cmp(&b.directory));
+
+        let mut fallbacks = CopyFallbacks::load_for(index.sync_method)?;
+        let mut report = SkillSyncReport::default();
+        for skill in skills {
+            let status = if skill.apps.is_enabled_for(app) {
+                Self::sync_dir(&skill.directory, app, index.sync_method, fallbacks.as_mut())
+                    .unwrap_or_else(|e| {
+                        log::warn!("同步 Skill {} 到 {app:?} 失败: {e}", skill.directory);
+                        SkillSyncStatus::Failed(e.to_string())
+                    })
+            } else {
+                SkillSyncStatus::Skipped
+            };
+            report.entries.push(SkillSyncEntry {
+                app: app.clone(),
+                directory: skill.directory.clone(),
+                status,
+            });
         }
-        Ok(())
+        CopyFallbacks::save_if_loaded(fallbacks)?;
+        Ok(report)
     }
 
     /// Best-effort sync for live-flow triggers (provider switch etc).
     pub fn sync_all_enabled_best_effort() -> Result<(), AppError> {
         let mut index = Self::load_index()?;
         let _ = Self::migrate_ssot_if_pending(&mut index);
+        // 单个 Skill 的失败已在 sync_to_app 中记录日志，这里不中断调用方流程
         for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
             if let Err(e) = Self::sync_to_app(&index, &app) {
-                log::warn!("同步 Skill 到 {app:?} 失败: {e}");
+                log::warn!("同步 Skills 到 {app:?} 失败: {e}");
             }
         }
         Ok(())
     }
 
-    pub fn sync_all_enabled(app: Option<&AppType>) -> Result<(), AppError> {
+    /// 启用 `sync_skills_on_switch` 时，在切换供应商后同步该应用的 Skills；未启用时返回 None
+    pub fn sync_on_switch(app: &AppType) -> Option<Result<SkillSyncReport, AppError>> {
+        if !crate::settings::get_sync_skills_on_switch() {
+            return None;
+        }
+        let result = Self::sync_all_enabled(Some(app));
+        if let Err(e) = &result {
+            log::warn!("切换后同步 Skills 失败: {e}");
+        }
+        Some(result)
+    }
+
+    /// 同步已启用的 Skills（app 为 None 时同步全部应用），返回逐项结果汇总
+    pub fn sync_all_enabled(app: Option<&AppType>) -> Result<SkillSyncReport, AppError> {
         let mut index = Self::load_index()?;
         let _ = Self::migrate_ssot_if_pending(&mut index)?;
 
+        let mut report = SkillSyncReport::default();
         match app {
-            Some(app) => Self::sync_to_app(&index, app)?,
+            Some(app) => report.extend(Self::sync_to_app(&index, app)?),
             None => {
                 for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
-                    Self::sync_to_app(&index, &app)?;
+                    report.extend(Self::sync_to_app(&index, &app)?);
                 }
             }
         }
 
-        Ok(())
+        Ok(report)
     }
 
     pub fn list_installed() -> Result<Vec<InstalledSkill>, AppError> {
@@ -618,10 +891,25 @@ impl SkillService {
         Ok(skills)
     }
 
+    /// 已安装 Skill 记录的 README 链接（按目录名或 ID 匹配，忽略大小写）；未安装或未记录时为 None
+    pub fn installed_readme_url(spec: &str) -> Result<Option<String>, AppError> {
+        let spec = spec.trim();
+        Ok(Self::list_installed()?
+            .into_iter()
+            .find(|s| s.directory.eq_ignore_ascii_case(spec) || s.id.eq_ignore_ascii_case(spec))
+            .and_then(|s| s.readme_url)
+            .filter(|url| !url.trim().is_empty()))
+    }
+
     pub fn list_repos() -> Result<Vec<SkillRepo>, AppError> {
         Ok(Self::load_index()?.repos)
     }
 
+    /// 补齐缺失的默认技能仓库，返回新增数量
+    pub fn seed_default_repos() -> Result<usize, AppError> {
+        Database::init()?.init_default_skill_repos()
+    }
+
     pub fn get_sync_method() -> Result<SyncMethod, AppError> {
         Ok(crate::settings::get_skill_sync_method())
     }
@@ -684,6 +972,16 @@ impl SkillService {
     }
 
     pub fn toggle_app(directory_or_id: &str, app: &AppType, enabled: bool) -> Result<(), AppError> {
+        let result = Self::toggle_app_inner(directory_or_id, app, enabled);
+        crate::services::audit::record("skill.toggle", Some(app), Some(directory_or_id), &result);
+        result
+    }
+
+    fn toggle_app_inner(
+        directory_or_id: &str,
+        app: &AppType,
+        enabled: bool,
+    ) -> Result<(), AppError> {
         let mut index = Self::load_index()?;
         let Some(dir) = Self::resolve_directory_from_input(&index, directory_or_id) else {
             return Err(AppError::Message(format!(
@@ -706,7 +1004,73 @@ impl SkillService {
         Ok(())
     }
 
+    /// 为所有应用启用/停用 Skill：逐个应用同步或移除（单个失败不影响其他应用），索引只保存一次
+    pub fn toggle_all_apps(
+        directory_or_id: &str,
+        enabled: bool,
+    ) -> Result<SkillSyncReport, AppError> {
+        let result = Self::toggle_all_apps_inner(directory_or_id, enabled);
+        crate::services::audit::record("skill.toggle_all", None, Some(directory_or_id), &result);
+        result
+    }
+
+    fn toggle_all_apps_inner(
+        directory_or_id: &str,
+        enabled: bool,
+    ) -> Result<SkillSyncReport, AppError> {
+        let mut index = Self::load_index()?;
+        let Some(dir) = Self::resolve_directory_from_input(&index, directory_or_id) else {
+            return Err(AppError::Message(format!(
+                "未找到已安装的 Skill: {directory_or_id}"
+            )));
+        };
+        let Some(record) = index.skills.get_mut(&dir) else {
+            return Err(AppError::Message(format!("未找到已安装的 Skill: {dir}")));
+        };
+
+        let mut fallbacks = CopyFallbacks::load_for(index.sync_method)?;
+        let mut report = SkillSyncReport::default();
+        for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+            let result = if enabled {
+                Self::sync_dir(
+                    &record.directory,
+                    &app,
+                    index.sync_method,
+                    fallbacks.as_mut(),
+                )
+            } else {
+                Self::remove_from_app(&record.directory, &app).map(|()| SkillSyncStatus::Removed)
+            };
+            // 只有同步/移除成功才更新该应用的开关，失败时保持原状态与文件一致
+            let status = match result {
+                Ok(status) => {
+                    record.apps.set_enabled_for(&app, enabled);
+                    status
+                }
+                Err(e) => {
+                    log::warn!("切换 Skill {} 在 {app:?} 的状态失败: {e}", record.directory);
+                    SkillSyncStatus::Failed(e.to_string())
+                }
+            };
+            report.entries.push(SkillSyncEntry {
+                app,
+                directory: record.directory.clone(),
+                status,
+            });
+        }
+
+        CopyFallbacks::save_if_loaded(fallbacks)?;
+        Self::save_index(&index)?;
+        Ok(report)
+    }
+
     pub fn uninstall(directory_or_id: &str) -> Result<(), AppError> {
+        let result = Self::uninstall_inner(directory_or_id);
+        crate::services::audit::record("skill.uninstall", None, Some(directory_or_id), &result);
+        result
+    }
+
+    fn uninstall_inner(directory_or_id: &str) -> Result<(), AppError> {
         let index = Self::load_index()?;
         let Some(dir) = Self::resolve_directory_from_input(&index, directory_or_id) else {
             return Err(AppError::Message(format!(
@@ -739,51 +1103,26 @@ impl SkillService {
     }
 
     pub async fn install(&self, spec: &str, app: &AppType) -> Result<InstalledSkill, AppError> {
+        let result = self.install_inner(spec, app).await;
+        crate::services::audit::record("skill.install", Some(app), Some(spec.trim()), &result);
+        result
+    }
+
+    async fn install_inner(&self, spec: &str, app: &AppType) -> Result<InstalledSkill, AppError> {
         let spec = spec.trim();
         if spec.is_empty() {
             return Err(AppError::InvalidInput("Skill 不能为空".to_string()));
         }
+        crate::settings::ensure_online()?;
 
         let mut index = Self::load_index()?;
         let _ = Self::migrate_ssot_if_pending(&mut index)?;
 
         // Resolve spec to a discoverable skill.
         let discoverable = self.resolve_install_spec(&index, spec).await?;
+        let install_name = Self::install_dir_name(&discoverable);
 
-        // Directory install name is always the last segment.
-        let install_name = Path::new(&discoverable.directory)
-            .file_name()
-            .map(|s| s.to_string_lossy().to_string())
-            .unwrap_or_else(|| discoverable.directory.clone());
-
-        // Conflict check (directory collisions across repos).
-        if let Some(existing) = index.skills.get(&install_name) {
-            let same_repo = existing.repo_owner.as_deref()
-                == Some(discoverable.repo_owner.as_str())
-                && existing.repo_name.as_deref() == Some(discoverable.repo_name.as_str());
-            if !same_repo
-                && (existing.repo_owner.is_some()
-                    || existing.repo_name.is_some()
-                    || existing.id.starts_with("local:"))
-            {
-                let existing_repo = format!(
-                    "{}/{}",
-                    existing.repo_owner.as_deref().unwrap_or("unknown"),
-                    existing.repo_name.as_deref().unwrap_or("unknown")
-                );
-                let new_repo = format!("{}/{}", discoverable.repo_owner, discoverable.repo_name);
-
-                return Err(AppError::Message(format_skill_error(
-                    "SKILL_DIRECTORY_CONFLICT",
-                    &[
-                        ("directory", install_name.as_str()),
-                        ("existing_repo", existing_repo.as_str()),
-                        ("new_repo", new_repo.as_str()),
-                    ],
-                    Some("uninstallFirst"),
-                )));
-            }
-
+        if let Some(existing) = Self::existing_install(&index, &discoverable, &install_name)? {
             // Already installed: just enable current app and sync.
             let mut updated = existing.clone();
             updated.apps.set_enabled_for(app, true);
@@ -797,11 +1136,18 @@ impl SkillService {
         let ssot_dir = Self::get_ssot_dir()?;
         let dest = ssot_dir.join(&install_name);
         if !dest.exists() {
+            let fallback_branches = index
+                .repos
+                .iter()
+                .find(|r| r.owner == discoverable.repo_owner && r.name == discoverable.repo_name)
+                .map(|r| r.fallback_branches.clone())
+                .unwrap_or_default();
             let repo = SkillRepo {
                 owner: discoverable.repo_owner.clone(),
                 name: discoverable.repo_name.clone(),
                 branch: discoverable.repo_branch.clone(),
                 enabled: true,
+                fallback_branches,
             };
 
             let temp_dir = timeout(
@@ -869,6 +1215,91 @@ impl SkillService {
         Ok(installed)
     }
 
+    /// Resolve an install spec without downloading or touching the filesystem.
+    ///
+    /// Reports the same resolution and conflict errors as `install`.
+    pub async fn plan_install(&self, spec: &str, app: &AppType) -> Result<InstallPlan, AppError> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return Err(AppError::InvalidInput("Skill 不能为空".to_string()));
+        }
+        crate::settings::ensure_online()?;
+
+        let index = Self::load_index()?;
+        let discoverable = self.resolve_install_spec(&index, spec).await?;
+        Self::build_install_plan(
+            &index,
+            discoverable,
+            &Self::ssot_dir_path(),
+            &Self::get_app_skills_dir(app)?,
+        )
+    }
+
+    /// 根据已解析的 Skill 与索引生成安装计划；目录被其他仓库占用时返回冲突错误
+    fn build_install_plan(
+        index: &SkillsIndex,
+        discoverable: DiscoverableSkill,
+        ssot_dir: &Path,
+        app_dir: &Path,
+    ) -> Result<InstallPlan, AppError> {
+        let install_name = Self::install_dir_name(&discoverable);
+        let already_installed =
+            Self::existing_install(index, &discoverable, &install_name)?.is_some();
+
+        Ok(InstallPlan {
+            ssot_path: ssot_dir.join(&install_name),
+            app_path: app_dir.join(&install_name),
+            install_name,
+            already_installed,
+            skill: discoverable,
+        })
+    }
+
+    /// Directory install name is always the last segment.
+    fn install_dir_name(discoverable: &DiscoverableSkill) -> String {
+        Path::new(&discoverable.directory)
+            .file_name()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| discoverable.directory.clone())
+    }
+
+    /// Existing install of the same directory, or a conflict error when it
+    /// belongs to another repo.
+    fn existing_install<'a>(
+        index: &'a SkillsIndex,
+        discoverable: &DiscoverableSkill,
+        install_name: &str,
+    ) -> Result<Option<&'a InstalledSkill>, AppError> {
+        let Some(existing) = index.skills.get(install_name) else {
+            return Ok(None);
+        };
+        let same_repo = existing.repo_owner.as_deref() == Some(discoverable.repo_owner.as_str())
+            && existing.repo_name.as_deref() == Some(discoverable.repo_name.as_str());
+        if !same_repo
+            && (existing.repo_owner.is_some()
+                || existing.repo_name.is_some()
+                || existing.id.starts_with("local:"))
+        {
+            let existing_repo = format!(
+                "{}/{}",
+                existing.repo_owner.as_deref().unwrap_or("unknown"),
+                existing.repo_name.as_deref().unwrap_or("unknown")
+            );
+            let new_repo = format!("{}/{}", discoverable.repo_owner, discoverable.repo_name);
+
+            return Err(AppError::Message(format_skill_error(
+                "SKILL_DIRECTORY_CONFLICT",
+                &[
+                    ("directory", install_name),
+                    ("existing_repo", existing_repo.as_str()),
+                    ("new_repo", new_repo.as_str()),
+                ],
+                Some("uninstallFirst"),
+            )));
+        }
+        Ok(Some(existing))
+    }
+
     async fn resolve_install_spec(
         &self,
         index: &SkillsIndex,
@@ -901,8 +1332,15 @@ impl SkillService {
     // ---------------------------------------------------------------------
 
     pub fn scan_unmanaged() -> Result<Vec<UnmanagedSkill>, AppError> {
+        Self::scan_unmanaged_with(false)
+    }
+
+    /// 扫描应用目录中未纳管的 Skill；指向 SSOT 的软链接默认视为已纳管而跳过，
+    /// `follow_symlinks` 为 true 时仍将其列出
+    pub fn scan_unmanaged_with(follow_symlinks: bool) -> Result<Vec<UnmanagedSkill>, AppError> {
         let index = Self::load_index()?;
         let managed: HashSet<String> = index.skills.keys().cloned().collect();
+        let ssot_dir = Self::ssot_dir_path().canonicalize().ok();
 
         let mut unmanaged: HashMap<String, UnmanagedSkill> = HashMap::new();
 
@@ -929,6 +1367,9 @@ impl SkillService {
                 if managed.contains(&dir_name) {
                     continue;
                 }
+                if !follow_symlinks && Self::is_symlink_into(&path, ssot_dir.as_deref()) {
+                    continue;
+                }
 
                 let skill_md = path.join("SKILL.md");
                 let (name, description) = if skill_md.exists() {
@@ -964,10 +1405,201 @@ impl SkillService {
         Ok(unmanaged.into_values().collect())
     }
 
+    /// 排障检查：SSOT 迁移状态、SSOT 目录完整性、应用目录同步情况与未纳管 Skill
+    pub fn doctor() -> Result<Vec<Diagnostic>, AppError> {
+        let index = Self::load_index()?;
+        let mut skills: Vec<&InstalledSkill> = index.skills.values().collect();
+        skills.sort_by(|a, b| a.directory.cmp(&b.directory));
+        let mut checks = Vec::new();
+
+        checks.push(match Self::ssot_migration_error()? {
+            Some(err) => Diagnostic::error("skills.ssot_migration")
+                .with_details([err])
+                .with_remediation("cc-switch skills list"),
+            None => Diagnostic::ok("skills.ssot_migration"),
+        });
+
+        let ssot_dir = Self::ssot_dir_path();
+        let missing: Vec<String> = skills
+            .iter()
+            .filter(|skill| !ssot_dir.join(&skill.directory).is_dir())
+            .map(|skill| ssot_dir.join(&skill.directory).display().to_string())
+            .collect();
+        checks.push(if missing.is_empty() {
+            Diagnostic::ok("skills.ssot_dirs")
+        } else {
+            Diagnostic::error("skills.ssot_dirs")
+                .with_details(missing)
+                .with_remediation("cc-switch skills uninstall <directory>")
+        });
+
+        let orphans = Self::orphaned_ssot_dirs(&index)?;
+        checks.push(if orphans.is_empty() {
+            Diagnostic::ok("skills.ssot_orphans")
+        } else {
+            Diagnostic::warn("skills.ssot_orphans")
+                .with_details(orphans)
+                .with_remediation("cc-switch skills doctor --orphans register|delete")
+        });
+
+        let mut unsynced = Vec::new();
+        for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+            let Ok(app_dir) = Self::get_app_skills_dir(&app) else {
+                continue;
+            };
+            for skill in skills
+                .iter()
+                .filter(|skill| skill.apps.is_enabled_for(&app))
+            {
+                if !app_dir.join(&skill.directory).exists() {
+                    unsynced.push(format!("{}: {}", app.as_str(), skill.directory));
+                }
+            }
+        }
+        checks.push(if unsynced.is_empty() {
+            Diagnostic::ok("skills.app_sync")
+        } else {
+            Diagnostic::warn("skills.app_sync")
+                .with_details(unsynced)
+                .with_remediation("cc-switch skills sync")
+        });
+
+        let mut unmanaged = Self::scan_unmanaged()?;
+        unmanaged.sort_by(|a, b| a.directory.cmp(&b.directory));
+        checks.push(if unmanaged.is_empty() {
+            Diagnostic::ok("skills.unmanaged")
+        } else {
+            Diagnostic::warn("skills.unmanaged")
+                .with_details(
+                    unmanaged
+                        .iter()
+                        .map(|s| format!("{} ({})", s.directory, s.found_in.join(", "))),
+                )
+                .with_remediation("cc-switch skills import-from-apps <directory>...")
+        });
+
+        Ok(checks)
+    }
+
+    /// SSOT 目录中存在、但 skills.json 没有对应记录的 Skill 目录（按名称排序）
+    fn orphaned_ssot_dirs(index: &SkillsIndex) -> Result<Vec<String>, AppError> {
+        let ssot = Self::ssot_dir_path();
+        if !ssot.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut orphans = Vec::new();
+        for entry in fs::read_dir(&ssot).map_err(|e| AppError::io(&ssot, e))? {
+            let entry = entry.map_err(|e| AppError::io(&ssot, e))?;
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let directory = entry.file_name().to_string_lossy().to_string();
+            if directory.starts_with('.') {
+                continue;
+            }
+            let tracked = index
+                .skills
+                .values()
+                .any(|skill| skill.directory.eq_ignore_ascii_case(&directory));
+            if !tracked {
+                orphans.push(directory);
+            }
+        }
+        orphans.sort();
+        Ok(orphans)
+    }
+
+    /// 为孤立的 SSOT 目录重建索引记录（名称/描述取自 SKILL.md，默认不启用任何应用）
+    pub fn register_orphaned_ssot_skills() -> Result<Vec<InstalledSkill>, AppError> {
+        let result = Self::register_orphaned_ssot_skills_inner();
+        crate::services::audit::record("skill.register_orphans", None, None, &result);
+        result
+    }
+
+    fn register_orphaned_ssot_skills_inner() -> Result<Vec<InstalledSkill>, AppError> {
+        let mut index = Self::load_index()?;
+        let ssot = Self::ssot_dir_path();
+        let mut registered = Vec::new();
+
+        for directory in Self::orphaned_ssot_dirs(&index)? {
+            let skill_md = ssot.join(&directory).join("SKILL.md");
+            let meta = if skill_md.exists() {
+                Self::parse_skill_metadata_static(&skill_md).ok()
+            } else {
+                None
+            };
+            let (name, description) = match meta {
+                Some(meta) => (
+                    meta.name
+                        .filter(|name| !name.trim().is_empty())
+                        .unwrap_or_else(|| directory.clone()),
+                    meta.description,
+                ),
+                None => (directory.clone(), None),
+            };
+
+            let record = InstalledSkill {
+                id: format!("local:{directory}"),
+                name,
+                description,
+                directory: directory.clone(),
+                readme_url: None,
+                repo_owner: None,
+                repo_name: None,
+                repo_branch: None,
+                apps: SkillApps::default(),
+                installed_at: Utc::now().timestamp(),
+            };
+            index.skills.insert(directory, record.clone());
+            registered.push(record);
+        }
+
+        if !registered.is_empty() {
+            Self::save_index(&index)?;
+        }
+        Ok(registered)
+    }
+
+    /// 删除没有索引记录的 SSOT 目录，返回被删除的目录名
+    pub fn delete_orphaned_ssot_skills() -> Result<Vec<String>, AppError> {
+        let result = Self::delete_orphaned_ssot_skills_inner();
+        crate::services::audit::record("skill.delete_orphans", None, None, &result);
+        result
+    }
+
+    fn delete_orphaned_ssot_skills_inner() -> Result<Vec<String>, AppError> {
+        let index = Self::load_index()?;
+        let ssot = Self::ssot_dir_path();
+        let orphans = Self::orphaned_ssot_dirs(&index)?;
+        for directory in &orphans {
+            Self::remove_path(&ssot.join(directory))?;
+        }
+        Ok(orphans)
+    }
+
+    /// 即将从应用目录导入的 Skill（取自 `scan_unmanaged`），用于导入前的确认摘要
+    pub fn preview_import_from_apps(
+        directories: &[String],
+    ) -> Result<Vec<UnmanagedSkill>, AppError> {
+        Ok(Self::scan_unmanaged()?
+            .into_iter()
+            .filter(|skill| directories.contains(&skill.directory))
+            .collect())
+    }
+
     pub fn import_from_apps(directories: Vec<String>) -> Result<Vec<InstalledSkill>, AppError> {
+        let label = directories.join(",");
+        let result = Self::import_from_apps_inner(directories);
+        crate::services::audit::record("skill.import_from_apps", None, Some(&label), &result);
+        result
+    }
+
+    fn import_from_apps_inner(directories: Vec<String>) -> Result<Vec<InstalledSkill>, AppError> {
         let mut index = Self::load_index()?;
         let ssot_dir = Self::get_ssot_dir()?;
         let mut imported = Vec::new();
+        let mut created = Vec::new();
 
         for dir_name in directories {
             let mut source_path: Option<PathBuf> = None;
@@ -990,6 +1622,9 @@ impl SkillService {
             let dest = ssot_dir.join(&dir_name);
             if !dest.exists() {
                 Self::copy_dir_recursive(&source, &dest)?;
+                if !index.skills.contains_key(&dir_name) {
+                    created.push(dir_name.clone());
+                }
             }
 
             let skill_md = dest.join("SKILL.md");
@@ -1038,9 +1673,62 @@ impl SkillService {
         }
 
         Self::save_index(&index)?;
+        if !created.is_empty() {
+            let record = serde_json::to_string(&created)
+                .map_err(|source| AppError::JsonSerialize { source })?;
+            Database::init()?.set_setting(LAST_APP_IMPORT_KEY, &record)?;
+        }
         Ok(imported)
     }
 
+    /// 最近一次从应用目录导入时新建的 SSOT 目录
+    pub fn last_app_import() -> Result<Vec<String>, AppError> {
+        Ok(Database::init()?
+            .get_setting(LAST_APP_IMPORT_KEY)?
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default())
+    }
+
+    /// 撤销最近一次从应用目录的导入：只删除当时新建的 SSOT 目录与索引记录。
+    /// 应用目录中已被替换为指向 SSOT 的软链接会先还原为普通目录，返回被移除的目录名
+    pub fn undo_last_app_import() -> Result<Vec<String>, AppError> {
+        let result = Self::undo_last_app_import_inner();
+        crate::services::audit::record("skill.import_undo", None, None, &result);
+        result
+    }
+
+    fn undo_last_app_import_inner() -> Result<Vec<String>, AppError> {
+        let directories = Self::last_app_import()?;
+        let index = Self::load_index()?;
+        let ssot_dir = Self::get_ssot_dir()?;
+        let ssot_canonical = ssot_dir.canonicalize().ok();
+        let db = Database::init()?;
+
+        for dir_name in &directories {
+            let ssot_path = ssot_dir.join(dir_name);
+            for app in [AppType::Claude, AppType::Codex, AppType::Gemini] {
+                let Ok(app_dir) = Self::get_app_skills_dir(&app) else {
+                    continue;
+                };
+                let app_path = app_dir.join(dir_name);
+                if ssot_path.exists() && Self::is_symlink_into(&app_path, ssot_canonical.as_deref())
+                {
+                    Self::remove_path(&app_path)?;
+                    Self::copy_dir_recursive(&ssot_path, &app_path)?;
+                }
+            }
+            if ssot_path.exists() {
+                Self::remove_path(&ssot_path)?;
+            }
+            if let Some(record) = index.skills.get(dir_name) {
+                let _ = db.delete_skill(&record.id)?;
+            }
+        }
+
+        db.set_setting(LAST_APP_IMPORT_KEY, "")?;
+        Ok(directories)
+    }
+
     // ---------------------------------------------------------------------
     // Repo discovery / list
     // ---------------------------------------------------------------------
@@ -1049,10 +1737,60 @@ impl SkillService {
         &self,
         repos: Vec<SkillRepo>,
     ) -> Result<Vec<DiscoverableSkill>, AppError> {
+        self.discover_available_with_progress(repos, &|_| {}).await
+    }
+
+    /// Same as `discover_available`, reporting which repos are still pending.
+    pub async fn discover_available_with_progress(
+        &self,
+        repos: Vec<SkillRepo>,
+        on_progress: &(dyn Fn(DiscoverProgress) + Sync),
+    ) -> Result<Vec<DiscoverableSkill>, AppError> {
+        self.discover_available_inner(repos, on_progress, false)
+            .await
+    }
+
+    async fn discover_available_inner(
+        &self,
+        repos: Vec<SkillRepo>,
+        on_progress: &(dyn Fn(DiscoverProgress) + Sync),
+        with_body: bool,
+    ) -> Result<Vec<DiscoverableSkill>, AppError> {
+        crate::settings::ensure_online()?;
         let enabled_repos: Vec<SkillRepo> = repos.into_iter().filter(|r| r.enabled).collect();
-        let tasks = enabled_repos
-            .iter()
-            .map(|repo| self.fetch_repo_skills(repo));
+        let total = enabled_repos.len();
+        let pending = Mutex::new(
+            enabled_repos
+                .iter()
+                .map(|repo| format!("{}/{}", repo.owner, repo.name))
+                .collect::<Vec<_>>(),
+        );
+        let report = |pending: &Vec<String>| {
+            on_progress(DiscoverProgress {
+                done: total - pending.len(),
+                total,
+                pending: pending.clone(),
+            })
+        };
+        if let Ok(pending) = pending.lock() {
+            report(&pending);
+        }
+
+        let tasks = enabled_repos.iter().map(|repo| {
+            let pending = &pending;
+            let report = &report;
+            async move {
+                let result = self.fetch_repo_skills(repo, with_body).await;
+                let label = format!("{}/{}", repo.owner, repo.name);
+                if let Ok(mut pending) = pending.lock() {
+                    if let Some(pos) = pending.iter().position(|p| *p == label) {
+                        pending.remove(pos);
+                    }
+                    report(&pending);
+                }
+                result
+            }
+        });
         let results: Vec<Result<Vec<DiscoverableSkill>, AppError>> = join_all(tasks).await;
 
         let mut skills = Vec::new();
@@ -1069,9 +1807,33 @@ impl SkillService {
     }
 
     pub async fn list_skills(&self) -> Result<Vec<Skill>, AppError> {
+        self.list_skills_with_progress(&|_| {}).await
+    }
+
+    /// Same as `list_skills`, reporting repo fetch progress as it goes.
+    pub async fn list_skills_with_progress(
+        &self,
+        on_progress: &(dyn Fn(DiscoverProgress) + Sync),
+    ) -> Result<Vec<Skill>, AppError> {
+        self.list_skills_inner(on_progress, false).await
+    }
+
+    /// Same as `list_skills`, also loading each SKILL.md body for deep search:
+    /// the downloaded copy for repo skills, the SSOT copy for installed ones.
+    pub async fn list_skills_with_body(&self) -> Result<Vec<Skill>, AppError> {
+        self.list_skills_inner(&|_| {}, true).await
+    }
+
+    async fn list_skills_inner(
+        &self,
+        on_progress: &(dyn Fn(DiscoverProgress) + Sync),
+        with_body: bool,
+    ) -> Result<Vec<Skill>, AppError> {
         let mut index = Self::load_index()?;
         let _ = Self::migrate_ssot_if_pending(&mut index)?;
-        let discoverable = self.discover_available(index.repos.clone()).await?;
+        let discoverable = self
+            .discover_available_inner(index.repos.clone(), on_progress, with_body)
+            .await?;
         let installed_dirs: HashSet<String> =
             index.skills.keys().map(|s| s.to_lowercase()).collect();
 
@@ -1089,6 +1851,7 @@ impl SkillService {
                     repo_owner: Some(d.repo_owner),
                     repo_name: Some(d.repo_name),
                     repo_branch: Some(d.repo_branch),
+                    body: d.body,
                 }
             })
             .collect();
@@ -1096,6 +1859,17 @@ impl SkillService {
         // Add local SSOT-only skills not in repos.
         Self::merge_local_ssot_skills(&index, &mut out)?;
 
+        if with_body {
+            let ssot = Self::get_ssot_dir()?;
+            for skill in out.iter_mut().filter(|s| s.installed) {
+                if let Some(body) =
+                    Self::read_skill_body(&ssot.join(&skill.directory).join("SKILL.md"))
+                {
+                    skill.body = Some(body);
+                }
+            }
+        }
+
         // De-dup + sort.
         Self::deduplicate_skills(&mut out);
         out.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
@@ -1160,6 +1934,7 @@ impl SkillService {
                 repo_owner: None,
                 repo_name: None,
                 repo_branch: None,
+                body: None,
             });
         }
 
@@ -1169,6 +1944,7 @@ impl SkillService {
     async fn fetch_repo_skills(
         &self,
         repo: &SkillRepo,
+        with_body: bool,
     ) -> Result<Vec<DiscoverableSkill>, AppError> {
         let temp_dir = timeout(std::time::Duration::from_secs(60), self.download_repo(repo))
             .await
@@ -1228,6 +2004,9 @@ impl SkillService {
                 repo_owner: repo.owner.clone(),
                 repo_name: repo.name.clone(),
                 repo_branch: repo.branch.clone(),
+                body: with_body
+                    .then(|| Self::read_skill_body(&skill_md))
+                    .flatten(),
             });
         }
 
@@ -1279,6 +2058,17 @@ impl SkillService {
         Ok(meta)
     }
 
+    /// SKILL.md 去掉 YAML front matter 后的正文
+    fn read_skill_body(path: &Path) -> Option<String> {
+        let content = fs::read_to_string(path).ok()?;
+        let content = content.trim_start_matches('\u{feff}');
+        let body = match content.splitn(3, "---").collect::<Vec<_>>()[..] {
+            [before, _, body] if before.trim().is_empty() => body,
+            _ => content,
+        };
+        Some(body.trim().to_string())
+    }
+
     async fn download_repo(&self, repo: &SkillRepo) -> Result<PathBuf, AppError> {
         let temp_dir = tempfile::tempdir().map_err(|e| {
             AppError::localized(
@@ -1290,11 +2080,7 @@ impl SkillService {
         let temp_path = temp_dir.path().to_path_buf();
         let _ = temp_dir.keep();
 
-        let branches = if repo.branch.trim().is_empty() {
-            vec!["main", "master"]
-        } else {
-            vec![repo.branch.as_str(), "main", "master"]
-        };
+        let branches = repo.download_branches(&crate::settings::get_skill_fallback_branches());
 
         let mut last_error: Option<AppError> = None;
         for branch in branches {
@@ -1477,7 +2263,24 @@ impl SkillService {
         Ok(matches.into_iter().next())
     }
 
-    fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<(), AppError> {
+    /// 先复制到同级临时目录再整体重命名，失败时不会留下看似完整的半成品目录
+    fn copy_dir_staged(src: &Path, dest: &Path) -> Result<(), AppError> {
+        let name = dest
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let staging = dest.with_file_name(format!(".{name}.migrating"));
+        if staging.exists() {
+            fs::remove_dir_all(&staging).map_err(|e| AppError::io(&staging, e))?;
+        }
+        if let Err(err) = Self::copy_dir_recursive(src, &staging) {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(err);
+        }
+        fs::rename(&staging, dest).map_err(|e| AppError::io(dest, e))
+    }
+
+    pub(crate) fn copy_dir_recursive(src: &Path, dest: &Path) -> Result<(), AppError> {
         fs::create_dir_all(dest).map_err(|e| AppError::io(dest, e))?;
         for entry in fs::read_dir(src).map_err(|e| AppError::io(src, e))? {
             let entry = entry.map_err(|e| AppError::io(src, e))?;
@@ -1492,4 +2295,201 @@ impl SkillService {
         }
         Ok(())
     }
+
+    /// 增量复制：跳过大小一致且目标不旧于源的文件，并删除源中已不存在的条目，
+    /// 返回实际复制的文件数
+    pub(crate) fn copy_dir_incremental(src: &Path, dest: &Path) -> Result<usize, AppError> {
+        fs::create_dir_all(dest).map_err(|e| AppError::io(dest, e))?;
+        let mut copied = 0;
+        let mut seen = HashSet::new();
+
+        for entry in fs::read_dir(src).map_err(|e| AppError::io(src, e))? {
+            let entry = entry.map_err(|e| AppError::io(src, e))?;
+            let path = entry.path();
+            let dest_path = dest.join(entry.file_name());
+            seen.insert(entry.file_name());
+
+            let dest_is_dir = dest_path.is_dir() && !Self::is_symlink(&dest_path);
+            if path.is_dir() {
+                if !dest_is_dir && (dest_path.exists() || Self::is_symlink(&dest_path)) {
+                    Self::remove_path(&dest_path)?;
+                }
+                copied += Self::copy_dir_incremental(&path, &dest_path)?;
+                continue;
+            }
+
+            if dest_is_dir || Self::is_symlink(&dest_path) {
+                Self::remove_path(&dest_path)?;
+            } else if Self::file_unchanged(&path, &dest_path) {
+                continue;
+            }
+            fs::copy(&path, &dest_path).map_err(|e| AppError::io(&dest_path, e))?;
+            copied += 1;
+        }
+
+        for entry in fs::read_dir(dest).map_err(|e| AppError::io(dest, e))? {
+            let entry = entry.map_err(|e| AppError::io(dest, e))?;
+            if !seen.contains(&entry.file_name()) {
+                Self::remove_path(&entry.path())?;
+            }
+        }
+        Ok(copied)
+    }
+
+    fn file_unchanged(src: &Path, dest: &Path) -> bool {
+        let (Ok(src_meta), Ok(dest_meta)) = (fs::metadata(src), fs::metadata(dest)) else {
+            return false;
+        };
+        if src_meta.len() != dest_meta.len() {
+            return false;
+        }
+        match (src_meta.modified(), dest_meta.modified()) {
+            (Ok(src_time), Ok(dest_time)) => dest_time >= src_time,
+            _ => false,
+        }
+    }
+}
+
+/// 同一 Skill 只在首次回退为复制时告警，之后（或开启静默设置时）降为 debug
+/// Auto 同步回退为复制的 Skill 集合：批量同步前读取一次，有变化时才写回
+struct CopyFallbacks {
+    dirs: BTreeSet<String>,
+    dirty: bool,
+}
+
+impl CopyFallbacks {
+    /// 仅 Auto 模式需要记录
+    fn load_for(method: SyncMethod) -> Result<Option<Self>, AppError> {
+        if method != SyncMethod::Auto {
+            return Ok(None);
+        }
+        Ok(Some(Self {
+            dirs: SkillService::copy_fallbacks()?.into_iter().collect(),
+            dirty: false,
+        }))
+    }
+
+    fn contains(&self, directory: &str) -> bool {
+        self.dirs.contains(directory)
+    }
+
+    fn set(&mut self, directory: &str, copied: bool) {
+        let changed = if copied {
+            self.dirs.insert(directory.to_string())
+        } else {
+            self.dirs.remove(directory)
+        };
+        self.dirty |= changed;
+    }
+
+    fn save_if_loaded(fallbacks: Option<Self>) -> Result<(), AppError> {
+        let Some(fallbacks) = fallbacks.filter(|f| f.dirty) else {
+            return Ok(());
+        };
+        let dirs: Vec<&String> = fallbacks.dirs.iter().collect();
+        let raw =
+            serde_json::to_string(&dirs).map_err(|source| AppError::JsonSerialize { source })?;
+        Database::init()?.set_setting(COPY_FALLBACKS_KEY, &raw)
+    }
+}
+
+fn copy_fallback_log_level(quiet: bool, already_recorded: bool) -> log::Level {
+    if quiet || already_recorded {
+        log::Level::Debug
+    } else {
+        log::Level::Warn
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_fallback_warns_only_the_first_time() {
+        assert_eq!(copy_fallback_log_level(false, false), log::Level::Warn);
+        assert_eq!(copy_fallback_log_level(false, true), log::Level::Debug);
+        assert_eq!(copy_fallback_log_level(true, false), log::Level::Debug);
+    }
+
+    fn discoverable(owner: &str, name: &str, directory: &str) -> DiscoverableSkill {
+        DiscoverableSkill {
+            key: format!("{owner}/{name}:{directory}"),
+            name: directory.to_string(),
+            description: String::new(),
+            directory: directory.to_string(),
+            readme_url: None,
+            repo_owner: owner.to_string(),
+            repo_name: name.to_string(),
+            repo_branch: "main".to_string(),
+            body: None,
+        }
+    }
+
+    fn installed_from(owner: &str, name: &str, directory: &str) -> InstalledSkill {
+        InstalledSkill {
+            id: format!("{owner}/{name}:{directory}"),
+            name: directory.to_string(),
+            description: None,
+            directory: directory.to_string(),
+            readme_url: None,
+            repo_owner: Some(owner.to_string()),
+            repo_name: Some(name.to_string()),
+            repo_branch: Some("main".to_string()),
+            apps: SkillApps::default(),
+            installed_at: 0,
+        }
+    }
+
+    #[test]
+    fn install_plan_uses_the_last_path_segment_for_both_targets() {
+        let plan = SkillService::build_install_plan(
+            &SkillsIndex::default(),
+            discoverable("acme", "skills", "tools/pdf"),
+            Path::new("/ssot"),
+            Path::new("/claude/skills"),
+        )
+        .expect("plan");
+        assert_eq!(plan.install_name, "pdf");
+        assert_eq!(plan.ssot_path, Path::new("/ssot/pdf"));
+        assert_eq!(plan.app_path, Path::new("/claude/skills/pdf"));
+        assert!(!plan.already_installed);
+    }
+
+    #[test]
+    fn install_plan_reports_existing_target_from_the_same_repo() {
+        let mut index = SkillsIndex::default();
+        index
+            .skills
+            .insert("pdf".to_string(), installed_from("acme", "skills", "pdf"));
+
+        let plan = SkillService::build_install_plan(
+            &index,
+            discoverable("acme", "skills", "pdf"),
+            Path::new("/ssot"),
+            Path::new("/claude/skills"),
+        )
+        .expect("plan");
+        assert!(plan.already_installed);
+    }
+
+    #[test]
+    fn install_plan_rejects_a_directory_owned_by_another_repo() {
+        let mut index = SkillsIndex::default();
+        index
+            .skills
+            .insert("pdf".to_string(), installed_from("other", "repo", "pdf"));
+
+        let err = SkillService::build_install_plan(
+            &index,
+            discoverable("acme", "skills", "pdf"),
+            Path::new("/ssot"),
+            Path::new("/claude/skills"),
+        )
+        .expect_err("directory conflict");
+        assert!(
+            err.to_string().contains("SKILL_DIRECTORY_CONFLICT"),
+            "{err}"
+        );
+    }
 }
@@ -0,0 +1,185 @@
+//! Skills 整体导出/导入：把 SSOT 目录与索引打包为 tar.gz，便于迁移到新机器
+
+use std::fs;
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use tempfile::tempdir;
+
+use crate::error::AppError;
+use crate::services::audit;
+use crate::services::skill::{SkillService, SkillSyncReport, SkillsIndex};
+
+const BUNDLE_INDEX_FILE: &str = "skills.json";
+const BUNDLE_SKILLS_DIR: &str = "skills";
+
+/// `skills import` 的结果
+#[derive(Debug, Clone, Default)]
+pub struct SkillBundleImport {
+    pub imported: Vec<String>,
+    /// 与本机冲突且选择保留本机版本的技能
+    pub skipped: Vec<String>,
+    /// 索引中存在但归档里缺少目录的技能
+    pub missing: Vec<String>,
+    pub repos_added: usize,
+    pub sync: SkillSyncReport,
+}
+
+/// 归档中的目录名来自外部文件，只接受单级普通目录名
+fn is_safe_directory(directory: &str) -> bool {
+    !directory.is_empty()
+        && directory != "."
+        && directory != ".."
+        && !directory.contains(['/', '\\'])
+}
+
+impl SkillService {
+    /// 导出已管理的技能（SSOT 目录 + 索引），返回导出的技能数
+    pub fn export_bundle(target: &Path) -> Result<usize, AppError> {
+        let mut index = Self::load_index()?;
+        let _ = Self::migrate_ssot_if_pending(&mut index)?;
+        index.ssot_migration_pending = false;
+        let ssot_dir = Self::get_ssot_dir()?;
+
+        if let Some(parent) = target.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        let file = fs::File::create(target).map_err(|e| AppError::io(target, e))?;
+        let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+        let json = serde_json::to_vec_pretty(&index).map_err(|e| AppError::json(target, e))?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(json.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, BUNDLE_INDEX_FILE, json.as_slice())
+            .map_err(|e| AppError::io(target, e))?;
+
+        let mut exported = 0;
+        for directory in index.skills.keys() {
+            let source = ssot_dir.join(directory);
+            if !source.is_dir() {
+                log::warn!("导出 Skills: SSOT 中缺少目录 {directory}，已跳过");
+                continue;
+            }
+            builder
+                .append_dir_all(format!("{BUNDLE_SKILLS_DIR}/{directory}"), &source)
+                .map_err(|e| AppError::io(&source, e))?;
+            exported += 1;
+        }
+
+        builder
+            .into_inner()
+            .and_then(|gz| gz.finish())
+            .map_err(|e| AppError::io(target, e))?;
+        Ok(exported)
+    }
+
+    /// 从归档恢复技能与仓库并同步到各应用；`overwrite` 决定冲突目录是否覆盖本机版本
+    pub fn import_bundle(
+        source: &Path,
+        overwrite: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<SkillBundleImport, AppError> {
+        let result = Self::import_bundle_inner(source, overwrite);
+        audit::record(
+            "skill.bundle_import",
+            None,
+            Some(&source.display().to_string()),
+            &result,
+        );
+        result
+    }
+
+    fn import_bundle_inner(
+        source: &Path,
+        overwrite: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<SkillBundleImport, AppError> {
+        let tmp = tempdir().map_err(|e| AppError::IoContext {
+            context: "创建 Skills 导入临时目录失败".to_string(),
+            source: e,
+        })?;
+        let file = fs::File::open(source).map_err(|e| AppError::io(source, e))?;
+        tar::Archive::new(GzDecoder::new(file))
+            .unpack(tmp.path())
+            .map_err(|e| AppError::io(source, e))?;
+
+        let index_path = tmp.path().join(BUNDLE_INDEX_FILE);
+        let raw = fs::read(&index_path).map_err(|_| {
+            AppError::localized(
+                "skills.bundle.invalid",
+                format!(
+                    "{} 不是有效的 Skills 导出文件（缺少 skills.json）",
+                    source.display()
+                ),
+                format!(
+                    "{} is not a skills export (skills.json missing)",
+                    source.display()
+                ),
+            )
+        })?;
+        let bundle: SkillsIndex =
+            serde_json::from_slice(&raw).map_err(|e| AppError::json(source, e))?;
+
+        let mut index = Self::load_index()?;
+        let _ = Self::migrate_ssot_if_pending(&mut index)?;
+        let ssot_dir = Self::get_ssot_dir()?;
+        let mut report = SkillBundleImport::default();
+
+        let mut records: Vec<_> = bundle.skills.into_values().collect();
+        records.sort_by(|a, b| a.directory.cmp(&b.directory));
+        for record in records {
+            let directory = record.directory.clone();
+            let unpacked = tmp.path().join(BUNDLE_SKILLS_DIR).join(&directory);
+            if !is_safe_directory(&directory) || !unpacked.is_dir() {
+                report.missing.push(directory);
+                continue;
+            }
+
+            let dest = ssot_dir.join(&directory);
+            if dest.exists() || index.skills.contains_key(&directory) {
+                if !overwrite(&directory) {
+                    report.skipped.push(directory);
+                    continue;
+                }
+                if dest.exists() {
+                    fs::remove_dir_all(&dest).map_err(|e| AppError::io(&dest, e))?;
+                }
+            }
+
+            Self::copy_dir_recursive(&unpacked, &dest)?;
+            index.skills.insert(directory.clone(), record);
+            report.imported.push(directory);
+        }
+
+        for repo in bundle.repos {
+            if !index
+                .repos
+                .iter()
+                .any(|r| r.owner == repo.owner && r.name == repo.name)
+            {
+                index.repos.push(repo);
+                report.repos_added += 1;
+            }
+        }
+
+        Self::save_index(&index)?;
+        report.sync = Self::sync_all_enabled(None)?;
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_safe_directory_rejects_traversal() {
+        assert!(is_safe_directory("my-skill"));
+        assert!(!is_safe_directory(".."));
+        assert!(!is_safe_directory("a/b"));
+        assert!(!is_safe_directory(""));
+    }
+}
@@ -8,6 +8,48 @@ use crate::error::AppError;
 const DEFAULT_TIMEOUT_SECS: u64 = 8;
 const MAX_TIMEOUT_SECS: u64 = 30;
 const MIN_TIMEOUT_SECS: u64 = 2;
+const DEFAULT_USER_AGENT: &str = "cc-switch-speedtest/1.0";
+
+/// 测速请求选项；未指定的字段回退到持久化设置，再回退到默认值
+#[derive(Debug, Clone, Default)]
+pub struct SpeedtestOptions {
+    pub timeout_secs: Option<u64>,
+    pub user_agent: Option<String>,
+}
+
+impl SpeedtestOptions {
+    pub fn with_timeout(mut self, timeout_secs: Option<u64>) -> Self {
+        if timeout_secs.is_some() {
+            self.timeout_secs = timeout_secs;
+        }
+        self
+    }
+
+    pub fn with_user_agent(mut self, user_agent: Option<String>) -> Self {
+        if user_agent.is_some() {
+            self.user_agent = user_agent;
+        }
+        self
+    }
+
+    /// 解析实际生效的超时秒数（已限制在允许范围内）
+    pub fn effective_timeout_secs(&self) -> u64 {
+        SpeedtestService::sanitize_timeout(
+            self.timeout_secs
+                .or_else(crate::settings::get_speedtest_timeout_secs),
+        )
+    }
+
+    /// 解析实际生效的 User-Agent
+    pub fn effective_user_agent(&self) -> String {
+        self.user_agent
+            .clone()
+            .or_else(crate::settings::get_speedtest_user_agent)
+            .map(|ua| ua.trim().to_string())
+            .filter(|ua| !ua.is_empty())
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string())
+    }
+}
 
 /// 端点测速结果
 #[derive(Debug, Clone, Serialize)]
@@ -25,16 +67,28 @@ impl SpeedtestService {
     /// 测试一组端点的响应延迟。
     pub async fn test_endpoints(
         urls: Vec<String>,
-        timeout_secs: Option<u64>,
+        options: SpeedtestOptions,
     ) -> Result<Vec<EndpointLatency>, AppError> {
-        if urls.is_empty() {
+        let targets = urls.into_iter().map(|url| (url, Vec::new())).collect();
+        Self::test_endpoints_with_headers(targets, options).await
+    }
+
+    /// 同 `test_endpoints`，但每个端点可附带额外请求头（如供应商自定义的 `headers`）
+    pub async fn test_endpoints_with_headers(
+        targets: Vec<(String, Vec<(String, String)>)>,
+        options: SpeedtestOptions,
+    ) -> Result<Vec<EndpointLatency>, AppError> {
+        if targets.is_empty() {
             return Ok(vec![]);
         }
+        crate::settings::ensure_online()?;
 
-        let timeout = Self::sanitize_timeout(timeout_secs);
-        let client = Self::build_client(timeout)?;
+        let client = Self::build_client(
+            options.effective_timeout_secs(),
+            &options.effective_user_agent(),
+        )?;
 
-        let tasks = urls.into_iter().map(|raw_url| {
+        let tasks = targets.into_iter().map(|(raw_url, headers)| {
             let client = client.clone();
             async move {
                 let trimmed = raw_url.trim().to_string();
@@ -59,35 +113,32 @@ impl SpeedtestService {
                     }
                 };
 
+                let request = || {
+                    headers
+                        .iter()
+                        .fold(client.get(parsed_url.clone()), |req, (name, value)| {
+                            req.header(name.as_str(), value.as_str())
+                        })
+                };
+
                 // 先进行一次热身请求，忽略结果，仅用于复用连接/绕过首包惩罚。
-                let _ = client.get(parsed_url.clone()).send().await;
+                let _ = request().send().await;
 
                 // 第二次请求开始计时，并将其作为结果返回。
                 let start = Instant::now();
-                match client.get(parsed_url).send().await {
+                match request().send().await {
                     Ok(resp) => EndpointLatency {
                         url: trimmed,
                         latency: Some(start.elapsed().as_millis()),
                         status: Some(resp.status().as_u16()),
                         error: None,
                     },
-                    Err(err) => {
-                        let status = err.status().map(|s| s.as_u16());
-                        let error_message = if err.is_timeout() {
-                            "请求超时".to_string()
-                        } else if err.is_connect() {
-                            "连接失败".to_string()
-                        } else {
-                            err.to_string()
-                        };
-
-                        EndpointLatency {
-                            url: trimmed,
-                            latency: None,
-                            status,
-                            error: Some(error_message),
-                        }
-                    }
+                    Err(err) => EndpointLatency {
+                        url: trimmed,
+                        latency: None,
+                        status: err.status().map(|s| s.as_u16()),
+                        error: Some(Self::describe_request_error(&err)),
+                    },
                 }
             }
         });
@@ -95,11 +146,22 @@ impl SpeedtestService {
         Ok(join_all(tasks).await)
     }
 
-    fn build_client(timeout_secs: u64) -> Result<Client, AppError> {
+    /// 请求失败的简短说明（超时 / 连接失败 / 原始错误）
+    pub(crate) fn describe_request_error(err: &reqwest::Error) -> String {
+        if err.is_timeout() {
+            "请求超时".to_string()
+        } else if err.is_connect() {
+            "连接失败".to_string()
+        } else {
+            err.to_string()
+        }
+    }
+
+    pub(crate) fn build_client(timeout_secs: u64, user_agent: &str) -> Result<Client, AppError> {
         Client::builder()
             .timeout(Duration::from_secs(timeout_secs))
             .redirect(reqwest::redirect::Policy::limited(5))
-            .user_agent("cc-switch-speedtest/1.0")
+            .user_agent(user_agent)
             .build()
             .map_err(|e| {
                 AppError::localized(
@@ -149,10 +211,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explicit_options_take_precedence_and_are_sanitized() {
+        let options = SpeedtestOptions::default()
+            .with_timeout(Some(1))
+            .with_user_agent(Some("  my-agent/2.0 ".to_string()));
+        assert_eq!(options.effective_timeout_secs(), MIN_TIMEOUT_SECS);
+        assert_eq!(options.effective_user_agent(), "my-agent/2.0");
+
+        let kept = options.clone().with_timeout(None).with_user_agent(None);
+        assert_eq!(kept.timeout_secs, Some(1));
+        assert_eq!(kept.user_agent.as_deref(), Some("  my-agent/2.0 "));
+    }
+
     #[test]
     fn test_endpoints_handles_empty_list() {
-        let result = run_async(SpeedtestService::test_endpoints(Vec::new(), Some(5)))
-            .expect("empty list should succeed");
+        let result = run_async(SpeedtestService::test_endpoints(
+            Vec::new(),
+            SpeedtestOptions::default().with_timeout(Some(5)),
+        ))
+        .expect("empty list should succeed");
         assert!(result.is_empty());
     }
 
@@ -160,7 +238,7 @@ mod tests {
     fn test_endpoints_reports_invalid_url() {
         let result = run_async(SpeedtestService::test_endpoints(
             vec!["not a url".into(), "".into()],
-            None,
+            SpeedtestOptions::default(),
         ))
         .expect("invalid inputs should still succeed");
 
@@ -270,7 +270,7 @@ fn apply_syncable_settings(raw: &[u8]) -> Result<(), AppError> {
     update_settings(settings)
 }
 
-fn restore_skills_zip(raw: &[u8]) -> Result<(), AppError> {
+pub(crate) fn restore_skills_zip(raw: &[u8]) -> Result<(), AppError> {
     let tmp = tempdir().map_err(|e| AppError::IoContext {
         context: "创建 skills 解压临时目录失败".to_string(),
         source: e,
@@ -727,7 +727,7 @@ where
     runtime.block_on(future)
 }
 
-fn zip_skills_ssot(dest_path: &Path) -> Result<(), AppError> {
+pub(crate) fn zip_skills_ssot(dest_path: &Path) -> Result<(), AppError> {
     let source = SkillService::get_ssot_dir()?;
     if let Some(parent) = dest_path.parent() {
         fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
@@ -2,9 +2,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{OnceLock, RwLock};
 use url::Url;
 
+use crate::app_config::AppType;
 use crate::error::AppError;
 
 /// 自定义端点配置
@@ -184,6 +186,61 @@ pub struct AppSettings {
     /// 是否跳过 Claude Code 初次安装确认
     #[serde(default)]
     pub skip_claude_onboarding: bool,
+    /// 离线模式：禁用 Skills 发现/安装、更新检查与测速等网络功能
+    #[serde(default)]
+    pub offline_mode: bool,
+    /// 在 TUI 添加供应商模板中隐藏赞助商预设
+    #[serde(default)]
+    pub hide_sponsor_presets: bool,
+    /// 当前激活的配置档（profile），None 表示默认配置目录
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+    /// 测速请求使用的 User-Agent（None 表示使用默认值）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speedtest_user_agent: Option<String>,
+    /// 测速请求超时秒数（None 表示使用默认值）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speedtest_timeout_secs: Option<u64>,
+    /// 供应商密钥到期前多少天开始提醒（None 表示使用默认值）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry_warning_days: Option<u32>,
+    /// 新建供应商表单预填的请求地址 / 模型（None 表示使用内置默认值）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_claude_base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_claude_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_codex_base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_codex_model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_gemini_base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_gemini_model: Option<String>,
+    /// 切换供应商成功后执行的 shell 命令（通过 CC_SWITCH_APP / CC_SWITCH_PROVIDER_ID 等环境变量获取结果）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_switch_command: Option<String>,
+    /// 切换供应商成功后同步该应用已启用的 Skills
+    #[serde(default)]
+    pub sync_skills_on_switch: bool,
+    /// Skill 仓库下载失败时依次尝试的全局备用分支（在 main/master 之前）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skill_fallback_branches: Vec<String>,
+    /// Skills Auto 同步回退到复制时只记 debug 日志（关闭时每个 Skill 首次回退记 warn）
+    #[serde(default)]
+    pub quiet_skill_copy_fallback: bool,
+    /// TUI toast 基础显示秒数（None 表示默认；警告/错误会停留更久）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toast_duration_secs: Option<u64>,
+    /// TUI toast 显示位置
+    #[serde(default)]
+    pub toast_position: ToastPosition,
+    /// TUI 上次退出时所在的页面与应用（下次启动时恢复）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tui_last_session: Option<TuiSession>,
+    /// TUI 事件循环 tick 间隔毫秒（None 表示默认；环境变量 CC_SWITCH_TUI_TICK_MS 优先）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tui_tick_rate_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub claude_config_dir: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -198,6 +255,9 @@ pub struct AppSettings {
     /// Skills 同步方式（auto|symlink|copy）
     #[serde(default)]
     pub skill_sync_method: crate::services::skill::SyncMethod,
+    /// live 配置文件写入方式（copy|symlink）
+    #[serde(default)]
+    pub live_file_method: crate::services::LiveFileMethod,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security: Option<SecuritySettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -210,6 +270,22 @@ pub struct AppSettings {
     pub custom_endpoints_codex: HashMap<String, CustomEndpoint>,
 }
 
+/// TUI 退出时的位置：页面标识与当前应用
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TuiSession {
+    pub route: String,
+    pub app: crate::app_config::AppType,
+}
+
+/// TUI 提示消息（toast）的显示位置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToastPosition {
+    Top,
+    #[default]
+    Bottom,
+}
+
 fn default_show_in_tray() -> bool {
     true
 }
@@ -225,12 +301,33 @@ impl Default for AppSettings {
             minimize_to_tray_on_close: true,
             enable_claude_plugin_integration: false,
             skip_claude_onboarding: false,
+            offline_mode: false,
+            hide_sponsor_presets: false,
+            active_profile: None,
+            speedtest_user_agent: None,
+            speedtest_timeout_secs: None,
+            expiry_warning_days: None,
+            default_claude_base_url: None,
+            default_claude_model: None,
+            default_codex_base_url: None,
+            default_codex_model: None,
+            default_gemini_base_url: None,
+            default_gemini_model: None,
+            on_switch_command: None,
+            sync_skills_on_switch: false,
+            skill_fallback_branches: Vec::new(),
+            quiet_skill_copy_fallback: false,
+            toast_duration_secs: None,
+            toast_position: ToastPosition::default(),
+            tui_last_session: None,
+            tui_tick_rate_ms: None,
             claude_config_dir: None,
             codex_config_dir: None,
             gemini_config_dir: None,
             language: None,
             launch_on_startup: false,
             skill_sync_method: crate::services::skill::SyncMethod::default(),
+            live_file_method: crate::services::LiveFileMethod::default(),
             security: None,
             webdav_sync: None,
             custom_endpoints_claude: HashMap::new(),
@@ -415,6 +512,19 @@ pub fn set_skill_sync_method(method: crate::services::skill::SyncMethod) -> Resu
     update_settings(settings)
 }
 
+pub fn get_live_file_method() -> crate::services::LiveFileMethod {
+    settings_store()
+        .read()
+        .map(|s| s.live_file_method)
+        .unwrap_or_default()
+}
+
+pub fn set_live_file_method(method: crate::services::LiveFileMethod) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.live_file_method = method;
+    update_settings(settings)
+}
+
 pub fn get_webdav_sync_settings() -> Option<WebDavSyncSettings> {
     settings_store()
         .read()
@@ -457,3 +567,290 @@ pub fn set_skip_claude_onboarding(enabled: bool) -> Result<(), AppError> {
     settings.skip_claude_onboarding = enabled;
     update_settings(settings)
 }
+
+/// 命令行 `--offline` 覆盖（仅对当前进程生效，不写入 settings.json）
+static OFFLINE_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_offline_override(enabled: bool) {
+    OFFLINE_OVERRIDE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn get_offline_mode() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.offline_mode)
+        .unwrap_or(false)
+}
+
+pub fn set_offline_mode(enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.offline_mode = enabled;
+    update_settings(settings)
+}
+
+/// 当前是否处于离线模式（命令行覆盖或持久化设置任一启用即生效）
+pub fn is_offline_mode() -> bool {
+    OFFLINE_OVERRIDE.load(Ordering::Relaxed) || get_offline_mode()
+}
+
+/// 命令行 `--read-only` 开关（仅对当前进程生效，不写入 settings.json）
+static READ_ONLY_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_read_only_override(enabled: bool) {
+    READ_ONLY_OVERRIDE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_read_only() -> bool {
+    READ_ONLY_OVERRIDE.load(Ordering::Relaxed)
+}
+
+/// 只读模式下拒绝修改操作；应在产生任何副作用之前调用
+pub fn ensure_writable() -> Result<(), AppError> {
+    if is_read_only() {
+        return Err(AppError::localized(
+            "read_only.blocked",
+            "当前处于只读模式（--read-only），已拒绝修改操作",
+            "Refusing to modify anything in read-only mode (--read-only)",
+        ));
+    }
+    Ok(())
+}
+
+/// 命令行 `--no-sponsors` 覆盖（仅对当前进程生效，不写入 settings.json）
+static HIDE_SPONSORS_OVERRIDE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_hide_sponsors_override(enabled: bool) {
+    HIDE_SPONSORS_OVERRIDE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn get_hide_sponsor_presets() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.hide_sponsor_presets)
+        .unwrap_or(false)
+}
+
+pub fn set_hide_sponsor_presets(enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.hide_sponsor_presets = enabled;
+    update_settings(settings)
+}
+
+/// 是否隐藏赞助商预设（命令行覆盖或持久化设置任一启用即生效）
+pub fn is_sponsor_presets_hidden() -> bool {
+    HIDE_SPONSORS_OVERRIDE.load(Ordering::Relaxed) || get_hide_sponsor_presets()
+}
+
+pub fn get_speedtest_user_agent() -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.speedtest_user_agent.clone())
+}
+
+pub fn get_speedtest_timeout_secs() -> Option<u64> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.speedtest_timeout_secs)
+}
+
+pub fn set_speedtest_defaults(
+    user_agent: Option<String>,
+    timeout_secs: Option<u64>,
+) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.speedtest_user_agent = user_agent;
+    settings.speedtest_timeout_secs = timeout_secs;
+    update_settings(settings)
+}
+
+pub fn get_expiry_warning_days() -> Option<u32> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.expiry_warning_days)
+}
+
+pub fn set_expiry_warning_days(days: Option<u32>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.expiry_warning_days = days;
+    update_settings(settings)
+}
+
+/// 新建供应商表单的自定义默认值（请求地址, 模型），空白值视为未设置
+pub fn get_new_provider_defaults(app: &AppType) -> (Option<String>, Option<String>) {
+    let non_blank = |value: &Option<String>| value.clone().filter(|v| !v.trim().is_empty());
+    settings_store()
+        .read()
+        .map(|s| match app {
+            AppType::Claude => (
+                non_blank(&s.default_claude_base_url),
+                non_blank(&s.default_claude_model),
+            ),
+            AppType::Codex => (
+                non_blank(&s.default_codex_base_url),
+                non_blank(&s.default_codex_model),
+            ),
+            AppType::Gemini => (
+                non_blank(&s.default_gemini_base_url),
+                non_blank(&s.default_gemini_model),
+            ),
+        })
+        .unwrap_or_default()
+}
+
+pub fn set_new_provider_defaults(
+    app: &AppType,
+    base_url: Option<String>,
+    model: Option<String>,
+) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    let (base_url_slot, model_slot) = match app {
+        AppType::Claude => (
+            &mut settings.default_claude_base_url,
+            &mut settings.default_claude_model,
+        ),
+        AppType::Codex => (
+            &mut settings.default_codex_base_url,
+            &mut settings.default_codex_model,
+        ),
+        AppType::Gemini => (
+            &mut settings.default_gemini_base_url,
+            &mut settings.default_gemini_model,
+        ),
+    };
+    *base_url_slot = base_url;
+    *model_slot = model;
+    update_settings(settings)
+}
+
+pub fn get_on_switch_command() -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.on_switch_command.clone())
+        .filter(|command| !command.trim().is_empty())
+}
+
+pub fn set_on_switch_command(command: Option<String>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.on_switch_command = command;
+    update_settings(settings)
+}
+
+pub fn get_sync_skills_on_switch() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.sync_skills_on_switch)
+        .unwrap_or(false)
+}
+
+pub fn set_sync_skills_on_switch(enabled: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.sync_skills_on_switch = enabled;
+    update_settings(settings)
+}
+
+pub fn get_tui_last_session() -> Option<TuiSession> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.tui_last_session.clone())
+}
+
+pub fn set_tui_last_session(session: Option<TuiSession>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.tui_last_session = session;
+    update_settings(settings)
+}
+
+pub fn get_skill_fallback_branches() -> Vec<String> {
+    settings_store()
+        .read()
+        .map(|s| s.skill_fallback_branches.clone())
+        .unwrap_or_default()
+}
+
+pub fn set_skill_fallback_branches(branches: Vec<String>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.skill_fallback_branches = branches;
+    update_settings(settings)
+}
+
+pub fn get_quiet_skill_copy_fallback() -> bool {
+    settings_store()
+        .read()
+        .map(|s| s.quiet_skill_copy_fallback)
+        .unwrap_or(false)
+}
+
+pub fn set_quiet_skill_copy_fallback(quiet: bool) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.quiet_skill_copy_fallback = quiet;
+    update_settings(settings)
+}
+
+pub fn get_toast_duration_secs() -> Option<u64> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.toast_duration_secs)
+}
+
+pub fn set_toast_duration_secs(secs: Option<u64>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.toast_duration_secs = secs;
+    update_settings(settings)
+}
+
+pub fn get_tui_tick_rate_ms() -> Option<u64> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.tui_tick_rate_ms)
+}
+
+pub fn set_tui_tick_rate_ms(ms: Option<u64>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.tui_tick_rate_ms = ms;
+    update_settings(settings)
+}
+
+pub fn get_toast_position() -> ToastPosition {
+    settings_store()
+        .read()
+        .map(|s| s.toast_position)
+        .unwrap_or_default()
+}
+
+pub fn set_toast_position(position: ToastPosition) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.toast_position = position;
+    update_settings(settings)
+}
+
+pub fn get_active_profile() -> Option<String> {
+    settings_store()
+        .read()
+        .ok()
+        .and_then(|s| s.active_profile.clone())
+}
+
+pub fn set_active_profile(profile: Option<String>) -> Result<(), AppError> {
+    let mut settings = get_settings();
+    settings.active_profile = profile;
+    update_settings(settings)
+}
+
+/// 离线模式下拒绝网络操作，返回明确的提示而不是尝试网络 I/O
+pub fn ensure_online() -> Result<(), AppError> {
+    if is_offline_mode() {
+        return Err(AppError::localized(
+            "offline_mode",
+            "离线模式已启用，已跳过网络操作（可使用 `cc-switch config offline off` 关闭）",
+            "Offline mode is enabled; network access skipped (disable with `cc-switch config offline off`)",
+        ));
+    }
+    Ok(())
+}
@@ -90,7 +90,7 @@ impl AppState {
     }
 }
 
-fn export_db_to_multi_app_config(db: &Database) -> Result<MultiAppConfig, AppError> {
+pub(crate) fn export_db_to_multi_app_config(db: &Database) -> Result<MultiAppConfig, AppError> {
     use crate::app_config::AppType;
     use crate::provider::ProviderManager;
 
@@ -0,0 +1,74 @@
+use cc_switch_lib::cli::commands::provider::ProviderCommand;
+use cc_switch_lib::cli::commands::skills::SkillsCommand;
+use cc_switch_lib::cli::{Cli, Commands};
+use clap::Parser;
+
+fn parse(args: &[&str]) -> Cli {
+    Cli::try_parse_from(std::iter::once("cc-switch").chain(args.iter().copied()))
+        .unwrap_or_else(|e| panic!("parse {args:?}: {e}"))
+}
+
+#[test]
+fn log_file_takes_a_path_only_with_equals() {
+    let cli = parse(&["--log-file", "provider", "list"]);
+    assert_eq!(cli.log_file, Some(None));
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Provider(ProviderCommand::List))
+    ));
+
+    assert_eq!(
+        parse(&["--log-file=/tmp/cc.log", "provider", "list"]).log_file,
+        Some(Some("/tmp/cc.log".into()))
+    );
+    assert_eq!(
+        parse(&["provider", "list", "--log-file=/tmp/cc.log"]).log_file,
+        Some(Some("/tmp/cc.log".into()))
+    );
+    assert_eq!(
+        parse(&["provider", "list", "--log-file"]).log_file,
+        Some(None)
+    );
+    assert_eq!(parse(&["provider", "list"]).log_file, None);
+}
+
+#[test]
+fn speedtest_timeout_is_limited_to_two_to_thirty_seconds() {
+    let accepts = |args: &[&str]| {
+        Cli::try_parse_from(std::iter::once("cc-switch").chain(args.iter().copied())).is_ok()
+    };
+    for args in [
+        &["speedtest", "--all", "--timeout"][..],
+        &["provider", "health", "--timeout"],
+        &["provider", "speedtest", "p1", "--timeout"],
+        &["provider", "failover", "--timeout"],
+        &["provider", "prune", "--unreachable", "--timeout"],
+        &["config", "speedtest", "--timeout"],
+    ] {
+        for (value, ok) in [("1", false), ("2", true), ("30", true), ("31", false)] {
+            let args: Vec<&str> = args.iter().copied().chain([value]).collect();
+            assert_eq!(accepts(&args), ok, "{args:?}");
+        }
+    }
+}
+
+#[test]
+fn skills_import_takes_any_bundle_name_and_undo_is_a_flag() {
+    match parse(&["skills", "import", "undo"]).command {
+        Some(Commands::Skills(SkillsCommand::Import { file, .. })) => {
+            assert_eq!(file, std::path::PathBuf::from("undo"))
+        }
+        _ => panic!("expected skills import"),
+    }
+    assert!(matches!(
+        parse(&["skills", "import-from-apps", "--undo"]).command,
+        Some(Commands::Skills(SkillsCommand::ImportFromApps {
+            undo: true,
+            ..
+        }))
+    ));
+    assert!(
+        Cli::try_parse_from(["cc-switch", "skills", "import-from-apps", "demo", "--undo"]).is_err()
+    );
+    assert!(Cli::try_parse_from(["cc-switch", "skills", "import"]).is_err());
+}
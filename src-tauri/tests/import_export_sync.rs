@@ -3,8 +3,9 @@ use serde_json::json;
 use std::{fs, path::Path};
 
 use cc_switch_lib::{
-    get_claude_settings_path, read_json_file, AppError, AppType, ConfigService, Database,
-    MultiAppConfig, Provider, ProviderMeta,
+    get_claude_settings_path, read_json_file, AppError, AppType, ArchiveKind, ChangeKind,
+    ChangeSection, ConfigArchiveService, ConfigService, Database, MultiAppConfig, NormalizeOutcome,
+    Provider, ProviderMeta,
 };
 
 #[path = "support.rs"]
@@ -891,8 +892,9 @@ fn import_config_from_path_overwrites_state_and_creates_backup() {
         .export_sql(&import_path)
         .expect("export import sql");
 
-    let backup_id = ConfigService::import_config_from_path(&import_path, &app_state)
+    let outcome = ConfigService::import_config_from_path(&import_path, &app_state)
         .expect("import should succeed");
+    let backup_id = outcome.backup_id;
     assert!(
         !backup_id.is_empty(),
         "expected pre-import backup id when database exists"
@@ -917,6 +919,43 @@ fn import_config_from_path_overwrites_state_and_creates_backup() {
         Some("p-new"),
         "db should be replaced by imported SQL"
     );
+
+    let changes: Vec<(ChangeSection, Option<AppType>, &str, ChangeKind)> = outcome
+        .changes
+        .changes
+        .iter()
+        .map(|change| {
+            (
+                change.section,
+                change.app.clone(),
+                change.id.as_str(),
+                change.kind,
+            )
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![
+            (
+                ChangeSection::Provider,
+                Some(AppType::Claude),
+                "p-new",
+                ChangeKind::Added
+            ),
+            (
+                ChangeSection::Provider,
+                Some(AppType::Claude),
+                "p-old",
+                ChangeKind::Removed
+            ),
+        ]
+    );
+    let guard = app_state.config.read().expect("read config");
+    assert_eq!(
+        guard.get_manager(&AppType::Claude).expect("claude").current,
+        "p-new",
+        "in-memory config should reflect the import"
+    );
 }
 
 #[test]
@@ -1135,3 +1174,409 @@ fn export_config_to_file_returns_error_when_path_invalid() {
         "expected invalid export path message, got {err}"
     );
 }
+
+#[test]
+fn encrypted_archive_round_trips_database_and_skills() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "p-archived".to_string();
+        manager.providers.insert(
+            "p-archived".to_string(),
+            Provider::with_id(
+                "p-archived".to_string(),
+                "Archived Claude".to_string(),
+                json!({
+                    "env": { "ANTHROPIC_AUTH_TOKEN": "archived-key" }
+                }),
+                None,
+            ),
+        );
+    }
+    let app_state = state_from_config(config);
+    app_state.save().expect("persist initial db");
+
+    let skill_file = home
+        .join(".cc-switch")
+        .join("skills")
+        .join("demo")
+        .join("SKILL.md");
+    fs::create_dir_all(skill_file.parent().unwrap()).expect("create skill dir");
+    fs::write(&skill_file, "# demo").expect("write skill");
+
+    let archive_path = home.join("backup.ccs");
+    ConfigArchiveService::export_to_path(&archive_path, Some("s3cret"))
+        .expect("export encrypted archive");
+    assert_eq!(
+        ConfigArchiveService::detect(&archive_path).expect("detect archive"),
+        Some(ArchiveKind::Encrypted)
+    );
+    let raw = fs::read(&archive_path).expect("read archive");
+    assert!(
+        !String::from_utf8_lossy(&raw).contains("archived-key"),
+        "encrypted archive must not contain plaintext secrets"
+    );
+
+    // Mutate local state after export.
+    app_state
+        .db
+        .set_current_provider(AppType::Claude.as_str(), "")
+        .expect("clear current");
+    fs::remove_dir_all(skill_file.parent().unwrap()).expect("remove skill dir");
+
+    ConfigArchiveService::import_from_path(&archive_path, None, &app_state)
+        .expect_err("missing passphrase should fail");
+
+    let outcome = ConfigArchiveService::import_from_path(&archive_path, Some("s3cret"), &app_state)
+        .expect("import encrypted archive");
+    assert!(
+        !outcome.backup_id.is_empty(),
+        "expected pre-import backup id"
+    );
+
+    let current = app_state
+        .db
+        .get_current_provider(AppType::Claude.as_str())
+        .expect("read current provider from db");
+    assert_eq!(current.as_deref(), Some("p-archived"));
+    assert_eq!(
+        fs::read_to_string(&skill_file).expect("skill restored"),
+        "# demo"
+    );
+}
+
+#[test]
+fn plain_archive_is_detected_and_sql_dump_is_not() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let app_state = state_from_config(MultiAppConfig::default());
+    app_state.save().expect("persist initial db");
+
+    let archive_path = home.join("backup.ccs");
+    ConfigArchiveService::export_to_path(&archive_path, None).expect("export plain archive");
+    assert_eq!(
+        ConfigArchiveService::detect(&archive_path).expect("detect archive"),
+        Some(ArchiveKind::Plain)
+    );
+
+    let sql_path = home.join("backup.sql");
+    ConfigService::export_config_to_path(&sql_path).expect("export sql");
+    assert_eq!(
+        ConfigArchiveService::detect(&sql_path).expect("detect sql"),
+        None
+    );
+}
+
+#[test]
+fn import_config_from_path_rejects_duplicate_ids_before_mutation() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager")
+        .providers
+        .insert(
+            "p-old".to_string(),
+            Provider::with_id(
+                "p-old".to_string(),
+                "Old Claude".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "old-key" } }),
+                None,
+            ),
+        );
+    let app_state = state_from_config(config);
+    app_state.save().expect("persist initial db");
+
+    let import_db = Database::memory().expect("create import db");
+    import_db
+        .save_provider(
+            AppType::Claude.as_str(),
+            &Provider::with_id(
+                "dup".to_string(),
+                "First".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "first" } }),
+                None,
+            ),
+        )
+        .expect("seed provider");
+    let import_path = home.join(".cc-switch").join("dup.sql");
+    import_db
+        .export_sql(&import_path)
+        .expect("export import sql");
+
+    // 模拟手工编辑：同一 ID 再出现一次，并改为 INSERT OR REPLACE 以免执行时报错
+    let sql = fs::read_to_string(&import_path).expect("read sql");
+    let insert = sql
+        .lines()
+        .find(|line| line.starts_with("INSERT INTO \"providers\""))
+        .expect("provider insert")
+        .replace("INSERT INTO", "INSERT OR REPLACE INTO")
+        .replace("'First'", "'Second'");
+    let edited = sql.replace("COMMIT;", &format!("{insert}\nCOMMIT;"));
+    fs::write(&import_path, edited).expect("write edited sql");
+
+    let err = ConfigService::import_config_from_path(&import_path, &app_state)
+        .expect_err("duplicate ids should be rejected");
+    match &err {
+        AppError::Localized { key, en, .. } => {
+            assert_eq!(*key, "backup.sql.duplicate_ids");
+            assert!(en.contains("providers:claude/dup"), "message: {en}");
+        }
+        other => panic!("expected Localized duplicate id error, got {other:?}"),
+    }
+
+    let providers = app_state
+        .db
+        .get_all_providers(AppType::Claude.as_str())
+        .expect("read providers");
+    assert!(
+        providers.contains_key("p-old"),
+        "existing data must be kept"
+    );
+    assert!(!providers.contains_key("dup"));
+    assert!(
+        !home.join(".cc-switch").join("backups").exists(),
+        "no pre-import backup should be written for a rejected import"
+    );
+}
+
+#[test]
+fn sanitize_backup_name_rejects_unsafe_names() {
+    assert_eq!(
+        ConfigService::sanitize_backup_name("  before-update_1 ").expect("valid"),
+        Some("before-update_1".to_string())
+    );
+    assert_eq!(
+        ConfigService::sanitize_backup_name("升级前").expect("unicode letters"),
+        Some("升级前".to_string())
+    );
+    assert_eq!(
+        ConfigService::sanitize_backup_name("   ").expect("blank"),
+        None
+    );
+
+    for bad in ["../escape", "a/b", "a\\b", "name.sql", "with space"] {
+        let err = ConfigService::sanitize_backup_name(bad).expect_err(bad);
+        assert!(
+            matches!(err, AppError::Localized { key, .. } if key == "backup.name.invalid"),
+            "{bad} should be rejected as invalid"
+        );
+    }
+
+    let err = ConfigService::sanitize_backup_name(&"x".repeat(65)).expect_err("too long");
+    assert!(matches!(err, AppError::Localized { key, .. } if key == "backup.name.too_long"));
+}
+
+#[test]
+fn create_backup_with_same_name_appends_suffix_instead_of_overwriting() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    let db_path = home.join(".cc-switch").join("cc-switch.db");
+
+    let state = state_from_config(MultiAppConfig::default());
+    state.save().expect("persist db");
+
+    let err = ConfigService::create_backup(&db_path, Some("../outside".to_string()))
+        .expect_err("path separators must be rejected");
+    assert!(matches!(err, AppError::Localized { key, .. } if key == "backup.name.invalid"));
+
+    // 先占用当前秒的备份名，再创建同名备份；跨秒时时间戳变化，重试即可
+    let backups_dir = home.join(".cc-switch").join("backups");
+    std::fs::create_dir_all(&backups_dir).expect("create backups dir");
+    let (first, second) = loop {
+        let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S").to_string();
+        let first = format!("nightly_{timestamp}");
+        std::fs::write(backups_dir.join(format!("{first}.sql")), "-- original")
+            .expect("seed colliding backup");
+        let second = ConfigService::create_backup(&db_path, Some("nightly".to_string()))
+            .expect("second backup");
+        if second.ends_with(&timestamp) {
+            assert_eq!(second, format!("nightly_2_{timestamp}"));
+            break (first, second);
+        }
+    };
+
+    assert_eq!(
+        std::fs::read_to_string(backups_dir.join(format!("{first}.sql"))).expect("read original"),
+        "-- original",
+        "existing backup must not be overwritten"
+    );
+    assert!(backups_dir.join(format!("{second}.sql")).exists());
+
+    let listed = ConfigService::list_backups(&db_path).expect("list backups");
+    for id in [&first, &second] {
+        let info = listed
+            .iter()
+            .find(|b| &b.id == id)
+            .expect("backup should be listed");
+        assert_eq!(info.timestamp.len(), "YYYYmmdd_HHMMSS".len());
+    }
+}
+
+#[test]
+fn normalize_config_file_sorts_keys_and_backs_up_original() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let err = ConfigService::normalize_config_file().expect_err("missing config.json");
+    assert!(err.to_string().contains("config.json"));
+
+    let config_dir = home.join(".cc-switch");
+    fs::create_dir_all(&config_dir).expect("create config dir");
+    let config_path = config_dir.join("config.json");
+    let original = r#"{
+  "version": 2,
+  "codex": { "providers": {}, "current": "" },
+  "claude": {
+    "current": "p1",
+    "providers": {
+      "p1": {
+        "settingsConfig": { "env": { "Z_KEY": "z", "A_KEY": "a" } },
+        "name": "P1",
+        "id": "p1"
+      }
+    }
+  }
+}
+"#;
+    fs::write(&config_path, original).expect("write config.json");
+
+    let outcome = ConfigService::normalize_config_file().expect("normalize");
+    let NormalizeOutcome::Rewritten { backup } = outcome else {
+        panic!("expected a rewrite, got {outcome:?}");
+    };
+    assert_eq!(
+        fs::read_to_string(&backup).expect("read backup"),
+        original,
+        "backup should hold the original bytes"
+    );
+
+    let normalized = fs::read_to_string(&config_path).expect("read normalized");
+    let pos = |needle: &str| normalized.find(needle).expect(needle);
+    assert!(pos("\"claude\"") < pos("\"codex\""));
+    assert!(
+        pos("\"gemini\"") < pos("\"version\""),
+        "defaults are filled in"
+    );
+    assert!(
+        pos("\"A_KEY\"") < pos("\"Z_KEY\""),
+        "nested keys are sorted"
+    );
+    assert!(pos("\"id\"") < pos("\"name\""));
+
+    assert_eq!(
+        ConfigService::normalize_config_file().expect("second normalize"),
+        NormalizeOutcome::Unchanged
+    );
+}
+
+#[test]
+fn apply_edited_config_validates_before_saving() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "main".to_string();
+        manager.providers.insert(
+            "main".to_string(),
+            Provider::with_id(
+                "main".to_string(),
+                "Main".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "sk-main" } }),
+                None,
+            ),
+        );
+    }
+    let state = state_from_config(config);
+    state.save().expect("seed database");
+
+    let original = ConfigService::editable_config_json(&state).expect("export config");
+    let mut value: serde_json::Value = serde_json::from_str(&original).expect("parse export");
+
+    let err = ConfigService::apply_edited_config(&state, "{ not json")
+        .expect_err("invalid JSON must be rejected");
+    assert!(matches!(err, AppError::Localized { key, .. } if key == "config.edit.invalid_json"));
+
+    value["claude"]["current"] = json!("missing");
+    let err = ConfigService::apply_edited_config(&state, &value.to_string())
+        .expect_err("dangling current provider must be rejected");
+    assert!(matches!(err, AppError::Localized { key, .. } if key == "config.edit.current_missing"));
+
+    value["claude"]["current"] = json!("main");
+    value["claude"]["providers"]["main"]["name"] = json!("Renamed");
+    let outcome =
+        ConfigService::apply_edited_config(&state, &value.to_string()).expect("apply edit");
+    assert_eq!(outcome.changes.count(ChangeKind::Modified), 1);
+
+    let providers = Database::init()
+        .expect("open database")
+        .get_all_providers("claude")
+        .expect("read providers");
+    assert_eq!(providers["main"].name, "Renamed");
+}
+
+#[test]
+fn reset_app_clears_only_that_app_after_backup() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    for (app, id) in [
+        (AppType::Claude, "claude-main"),
+        (AppType::Codex, "codex-main"),
+    ] {
+        let manager = config.get_manager_mut(&app).expect("manager");
+        manager.current = id.to_string();
+        manager.providers.insert(
+            id.to_string(),
+            Provider::with_id(id.to_string(), id.to_string(), json!({}), None),
+        );
+    }
+    let state = state_from_config(config);
+    state.save().expect("seed database");
+
+    let outcome = ConfigService::reset_app(&state, &AppType::Codex).expect("reset codex");
+    assert!(!outcome.backup_id.is_empty(), "a backup is created first");
+    assert!(home
+        .join(".cc-switch")
+        .join("backups")
+        .join(format!("{}.sql", outcome.backup_id))
+        .exists());
+    assert_eq!(outcome.changes.count(ChangeKind::Removed), 1);
+
+    let db = Database::init().expect("open database");
+    assert!(db.get_all_providers("codex").expect("codex").is_empty());
+    assert_eq!(
+        db.get_current_provider("codex").expect("codex current"),
+        None
+    );
+    assert!(db
+        .get_all_providers("claude")
+        .expect("claude")
+        .contains_key("claude-main"));
+    assert_eq!(
+        db.get_current_provider("claude")
+            .expect("claude current")
+            .as_deref(),
+        Some("claude-main")
+    );
+}
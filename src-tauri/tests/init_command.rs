@@ -0,0 +1,101 @@
+use serde_json::json;
+
+use cc_switch_lib::cli::commands::init::{execute, InitCommand};
+use cc_switch_lib::{
+    get_claude_settings_path, AppState, AppType, Provider, ProviderService, SkillService,
+};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs};
+
+fn run_init() {
+    execute(
+        InitCommand {
+            lang: None,
+            import_live: true,
+            skip_provider: true,
+            yes: true,
+        },
+        Some(AppType::Claude),
+    )
+    .expect("init");
+}
+
+fn write_claude_live(token: &str) {
+    let path = get_claude_settings_path();
+    std::fs::create_dir_all(path.parent().expect("claude dir")).expect("create claude dir");
+    std::fs::write(
+        &path,
+        serde_json::to_string(&json!({ "env": { "ANTHROPIC_AUTH_TOKEN": token } }))
+            .expect("serialize settings"),
+    )
+    .expect("write claude settings");
+}
+
+#[test]
+fn init_imports_live_configs_and_seeds_skill_repos() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    write_claude_live("sk-live");
+    run_init();
+
+    let state = AppState::try_new().expect("load state");
+    let claude = ProviderService::list(&state, AppType::Claude).expect("list claude");
+    let imported = claude.get("default").expect("imported claude provider");
+    assert_eq!(
+        imported.settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+        "sk-live"
+    );
+    assert!(
+        ProviderService::list(&state, AppType::Codex)
+            .expect("list codex")
+            .is_empty(),
+        "apps without live files are skipped"
+    );
+    assert!(
+        !SkillService::list_repos().expect("list repos").is_empty(),
+        "default skill repos are seeded"
+    );
+}
+
+#[test]
+fn init_does_not_overwrite_existing_providers() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    write_claude_live("sk-first");
+    run_init();
+
+    {
+        let state = AppState::try_new().expect("load state");
+        ProviderService::add(
+            &state,
+            AppType::Codex,
+            Provider::with_id(
+                "mine".to_string(),
+                "Mine".to_string(),
+                json!({ "auth": { "OPENAI_API_KEY": "sk-mine" }, "config": "" }),
+                None,
+            ),
+        )
+        .expect("add codex provider");
+    }
+
+    // 再次运行 init：已有供应商的应用一律跳过，不会被 live 配置覆盖
+    write_claude_live("sk-second");
+    run_init();
+
+    let state = AppState::try_new().expect("reload state");
+    let claude = ProviderService::list(&state, AppType::Claude).expect("list claude");
+    assert_eq!(claude.len(), 1);
+    assert_eq!(
+        claude["default"].settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+        "sk-first"
+    );
+    let codex = ProviderService::list(&state, AppType::Codex).expect("list codex");
+    assert_eq!(codex.keys().collect::<Vec<_>>(), ["mine"]);
+}
@@ -4,7 +4,7 @@ use serde_json::json;
 
 use cc_switch_lib::{
     get_claude_mcp_path, get_claude_settings_path, AppError, AppState, AppType, McpApps, McpServer,
-    McpService, MultiAppConfig, ProviderService,
+    McpService, McpTransport, MultiAppConfig, ProviderService,
 };
 
 #[path = "support.rs"]
@@ -151,6 +151,77 @@ fn import_mcp_from_claude_creates_config_and_enables_servers() {
     );
 }
 
+#[test]
+fn import_mcp_from_claude_merges_servers_with_same_command_and_args() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mcp_path = get_claude_mcp_path();
+    let claude_json = json!({
+        "mcpServers": {
+            "fetch-from-claude": {
+                "type": "stdio",
+                "command": "uvx",
+                "args": ["mcp-server-fetch"]
+            },
+            "brand-new": {
+                "type": "stdio",
+                "command": "npx",
+                "args": ["-y", "brand-new-server"]
+            }
+        }
+    });
+    fs::write(
+        &mcp_path,
+        serde_json::to_string_pretty(&claude_json).expect("serialize claude mcp"),
+    )
+    .expect("seed ~/.claude.json");
+
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::new());
+    config.mcp.servers.as_mut().unwrap().insert(
+        "fetch".into(),
+        McpServer {
+            id: "fetch".to_string(),
+            name: "Fetch".to_string(),
+            server: json!({
+                "type": "stdio",
+                "command": "uvx",
+                "args": ["mcp-server-fetch"]
+            }),
+            apps: McpApps {
+                claude: false,
+                codex: true,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    );
+
+    let state = state_from_config(config);
+
+    let summary = McpService::import_from_app(&state, &AppType::Claude)
+        .expect("import mcp from claude succeeds");
+    assert_eq!(summary.added, 1, "only the unknown server should be added");
+    assert_eq!(summary.merged, 1, "duplicate command+args should be merged");
+
+    let guard = state.config.read().expect("lock config");
+    let servers = guard.mcp.servers.as_ref().expect("unified servers");
+    assert!(
+        !servers.contains_key("fetch-from-claude"),
+        "duplicate server should not be inserted under a new id"
+    );
+    let fetch = servers.get("fetch").expect("existing server kept");
+    assert!(fetch.apps.claude, "merged server should enable Claude");
+    assert!(fetch.apps.codex, "merged server keeps existing apps");
+    assert!(servers.contains_key("brand-new"));
+}
+
 #[test]
 fn import_mcp_from_claude_invalid_json_preserves_state() {
     let _guard = lock_test_mutex();
@@ -366,6 +437,56 @@ fn set_mcp_enabled_for_codex_writes_live_config() {
     );
 }
 
+#[test]
+fn set_apps_applies_successful_apps_and_reports_failures() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // Codex config.toml 无法解析，同步到 Codex 会失败
+    let codex_dir = home.join(".codex");
+    fs::create_dir_all(&codex_dir).expect("create codex dir");
+    fs::write(codex_dir.join("config.toml"), "[[[broken").expect("write config.toml");
+
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([(
+        "multi".to_string(),
+        McpServer {
+            id: "multi".to_string(),
+            name: "Multi".to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: McpApps::default(),
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )]));
+    let state = state_from_config(config);
+
+    let target = McpApps {
+        claude: true,
+        codex: true,
+        gemini: false,
+        opencode: false,
+    };
+    let update = McpService::set_apps(&state, "multi", &target).expect("set apps");
+    assert_eq!(update.succeeded, vec![AppType::Claude]);
+    assert_eq!(update.failed.len(), 1);
+    assert_eq!(update.failed[0].0, AppType::Codex);
+
+    let guard = state.config.read().expect("lock config");
+    let apps = &guard.mcp.servers.as_ref().unwrap()["multi"].apps;
+    assert!(apps.claude, "successful app should be enabled");
+    assert!(!apps.codex, "failed app should keep its previous state");
+    drop(guard);
+
+    let claude_text = fs::read_to_string(get_claude_mcp_path()).expect("read claude mcp");
+    assert!(claude_text.contains("multi"));
+
+    assert!(McpService::set_apps(&state, "missing", &target).is_err());
+}
+
 #[test]
 fn upsert_server_skips_live_sync_when_gemini_uninitialized() {
     let _guard = lock_test_mutex();
@@ -507,3 +628,216 @@ fn upsert_server_disables_app_removes_from_gemini_live() {
         "upsert with Gemini disabled should remove it from ~/.gemini/settings.json, got: {settings_text}"
     );
 }
+
+#[test]
+fn import_mcp_from_cursor_and_windsurf_leaves_apps_disabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let cursor_dir = home.join(".cursor");
+    fs::create_dir_all(&cursor_dir).expect("create cursor dir");
+    fs::write(
+        cursor_dir.join("mcp.json"),
+        serde_json::to_string_pretty(&json!({
+            "mcpServers": {
+                "fetch": { "command": "uvx", "args": ["mcp-server-fetch"] },
+                "remote": { "url": "https://mcp.example.com/sse" },
+                "broken": { "args": ["missing-command"] }
+            }
+        }))
+        .expect("serialize cursor config"),
+    )
+    .expect("seed ~/.cursor/mcp.json");
+
+    let windsurf_dir = home.join(".codeium").join("windsurf");
+    fs::create_dir_all(&windsurf_dir).expect("create windsurf dir");
+    fs::write(
+        windsurf_dir.join("mcp_config.json"),
+        serde_json::to_string_pretty(&json!({
+            "mcpServers": {
+                "docs": { "serverUrl": "https://docs.example.com/mcp" }
+            }
+        }))
+        .expect("serialize windsurf config"),
+    )
+    .expect("seed windsurf mcp_config.json");
+
+    let state = state_from_config(MultiAppConfig::default());
+
+    assert_eq!(
+        McpService::import_from_cursor(&state).expect("import from cursor"),
+        2
+    );
+    assert_eq!(
+        McpService::import_from_windsurf(&state).expect("import from windsurf"),
+        1
+    );
+    // Re-importing is a no-op.
+    assert_eq!(
+        McpService::import_from_cursor(&state).expect("re-import from cursor"),
+        0
+    );
+
+    let guard = state.config.read().expect("lock config");
+    let servers = guard.mcp.servers.as_ref().expect("unified servers");
+    assert_eq!(servers.len(), 3);
+    assert!(servers.values().all(|server| server.apps.is_empty()));
+    assert_eq!(servers["remote"].server["type"], "sse");
+    assert_eq!(servers["docs"].server["type"], "http");
+    assert_eq!(
+        servers["docs"].server["url"],
+        "https://docs.example.com/mcp"
+    );
+    assert!(!servers.contains_key("broken"));
+}
+
+#[test]
+fn import_mcp_from_cursor_without_config_file_is_noop() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let state = state_from_config(MultiAppConfig::default());
+    assert_eq!(
+        McpService::import_from_cursor(&state).expect("missing file is not an error"),
+        0
+    );
+}
+
+#[test]
+fn duplicate_server_assigns_fresh_id_and_preserves_server_spec() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let spec = json!({
+        "type": "stdio",
+        "command": "npx",
+        "args": ["-y", "@modelcontextprotocol/server-filesystem", "/tmp"]
+    });
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::new());
+    config.mcp.servers.as_mut().unwrap().insert(
+        "fs".into(),
+        McpServer {
+            id: "fs".to_string(),
+            name: "Filesystem".to_string(),
+            server: spec.clone(),
+            apps: McpApps {
+                claude: true,
+                codex: false,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: vec!["files".to_string()],
+        },
+    );
+    let state = state_from_config(config);
+
+    let copy = McpService::duplicate_server(&state, "fs", None, false).expect("duplicate");
+    assert_eq!(copy.id, "fs-copy");
+    assert_eq!(copy.name, "Filesystem (copy)");
+    assert_eq!(copy.server, spec);
+    assert_eq!(copy.tags, vec!["files".to_string()]);
+    assert_eq!(copy.apps, McpApps::default());
+
+    let named = McpService::duplicate_server(&state, "fs", Some("Filesystem Home"), true)
+        .expect("duplicate with name");
+    assert_eq!(named.id, "filesystem-home");
+    assert!(named.apps.claude);
+
+    let again = McpService::duplicate_server(&state, "fs", None, false).expect("duplicate again");
+    assert_eq!(again.id, "fs-copy-1");
+
+    let servers = McpService::get_all_servers(&state).expect("servers");
+    assert_eq!(servers.len(), 4);
+    assert_eq!(servers["fs"].server, spec);
+}
+
+#[test]
+fn check_commands_checks_stdio_commands_and_remote_urls() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // 程序路径含空格时应整体查找，而不是截断到第一个空格
+    let exe_dir = home.join("My Tools").join("bin");
+    std::fs::create_dir_all(&exe_dir).expect("create exe dir");
+    let current = std::env::current_exe().expect("current exe");
+    let exe = exe_dir.join(current.file_name().expect("exe name"));
+    std::fs::copy(&current, &exe).expect("copy exe");
+    let server = |id: &str, spec: serde_json::Value, claude: bool| McpServer {
+        id: id.to_string(),
+        name: id.to_string(),
+        server: spec,
+        apps: McpApps {
+            claude,
+            codex: !claude,
+            gemini: false,
+            opencode: false,
+        },
+        description: None,
+        homepage: None,
+        docs: None,
+        tags: Vec::new(),
+    };
+    let mut config = MultiAppConfig::default();
+    config.mcp.servers = Some(HashMap::from([
+        (
+            "present".to_string(),
+            server(
+                "present",
+                json!({ "type": "stdio", "command": exe.display().to_string(), "args": ["--serve"] }),
+                true,
+            ),
+        ),
+        (
+            "missing".to_string(),
+            server(
+                "missing",
+                json!({ "type": "stdio", "command": "cc-switch-no-such-binary-xyz" }),
+                false,
+            ),
+        ),
+        (
+            "remote".to_string(),
+            server(
+                "remote",
+                json!({ "type": "http", "url": "https://mcp.example.com" }),
+                true,
+            ),
+        ),
+        (
+            "broken".to_string(),
+            server("broken", json!({ "url": "not a url" }), false),
+        ),
+    ]));
+    let state = state_from_config(config);
+
+    let checks = McpService::check_commands(&state, None).expect("check all");
+    let summary: Vec<(&str, McpTransport, bool)> = checks
+        .iter()
+        .map(|c| (c.id.as_str(), c.transport, c.ok))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("broken", McpTransport::Sse, false),
+            ("missing", McpTransport::Stdio, false),
+            ("present", McpTransport::Stdio, true),
+            ("remote", McpTransport::Http, true),
+        ]
+    );
+    assert_eq!(checks[2].target, exe.display().to_string());
+    assert_eq!(checks[3].target, "https://mcp.example.com");
+    assert_eq!(checks[1].apps, vec![AppType::Codex]);
+
+    let claude_only =
+        McpService::check_commands(&state, Some(&AppType::Claude)).expect("check claude");
+    let ids: Vec<&str> = claude_only.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["present", "remote"]);
+}
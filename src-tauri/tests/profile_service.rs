@@ -0,0 +1,78 @@
+use cc_switch_lib::{AppError, Database, ProfileService};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs};
+
+fn assert_error_key(err: AppError, expected: &str) {
+    match err {
+        AppError::Localized { key, .. } => assert_eq!(key, expected),
+        other => panic!("expected {expected} error, got {other:?}"),
+    }
+}
+
+#[test]
+fn profiles_redirect_config_dir_and_persist_active_selection() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let work_dir = ProfileService::create("work").expect("create profile");
+    assert_eq!(
+        work_dir,
+        home.join(".cc-switch").join("profiles").join("work")
+    );
+    assert_eq!(
+        ProfileService::list().expect("list profiles"),
+        vec!["default".to_string(), "work".to_string()]
+    );
+
+    // --profile 仅对当前进程生效
+    assert_eq!(
+        ProfileService::activate(Some("work")).expect("activate work"),
+        "work"
+    );
+    Database::init().expect("init db in profile");
+    assert!(work_dir.join("cc-switch.db").exists());
+    assert_eq!(ProfileService::active(), "default");
+    assert_eq!(ProfileService::current(), "work");
+
+    // 持久化后无需 --profile 也会生效
+    ProfileService::set_active("work").expect("persist active profile");
+    assert_eq!(ProfileService::activate(None).expect("activate"), "work");
+    assert_error_key(
+        ProfileService::delete("work").expect_err("active profile cannot be deleted"),
+        "profile.delete.active",
+    );
+
+    ProfileService::set_active("default").expect("switch back");
+    assert_eq!(ProfileService::activate(None).expect("activate"), "default");
+    assert!(!home.join(".cc-switch").join("cc-switch.db").exists());
+
+    ProfileService::delete("work").expect("delete profile");
+    assert!(!work_dir.exists());
+}
+
+#[test]
+fn profile_names_are_validated_and_missing_profiles_rejected() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    assert_error_key(
+        ProfileService::create("../escape").expect_err("path traversal rejected"),
+        "profile.invalid_name",
+    );
+    assert_error_key(
+        ProfileService::create("default").expect_err("default is reserved"),
+        "profile.invalid_name",
+    );
+    assert_error_key(
+        ProfileService::activate(Some("missing")).expect_err("missing profile rejected"),
+        "profile.not_found",
+    );
+    assert_error_key(
+        ProfileService::set_active("missing").expect_err("missing profile rejected"),
+        "profile.not_found",
+    );
+}
@@ -0,0 +1,62 @@
+use cc_switch_lib::{AppType, MultiAppConfig, Prompt, PromptService};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs, state_from_config};
+
+fn prompt(id: &str, content: &str, updated_at: i64) -> Prompt {
+    Prompt {
+        id: id.to_string(),
+        name: id.to_string(),
+        content: content.to_string(),
+        description: None,
+        enabled: false,
+        created_at: Some(updated_at),
+        updated_at: Some(updated_at),
+    }
+}
+
+#[test]
+fn append_keeps_other_prompts_and_joins_them_by_updated_at() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    let prompts = &mut config.prompts.claude.prompts;
+    prompts.insert("later".into(), prompt("later", "Second layer\n", 200));
+    prompts.insert("base".into(), prompt("base", "Base rules\n", 100));
+    prompts.insert("solo".into(), prompt("solo", "Only me\n", 300));
+    let state = state_from_config(config);
+    let live_path = home.join(".claude").join("CLAUDE.md");
+
+    PromptService::enable_prompt_with(&state, AppType::Claude, "later", true).expect("enable");
+    PromptService::enable_prompt_with(&state, AppType::Claude, "base", false).expect("append");
+    assert_eq!(
+        std::fs::read_to_string(&live_path).expect("read live prompt"),
+        "Base rules\n\nSecond layer\n"
+    );
+
+    PromptService::disable_prompt(&state, AppType::Claude, "base").expect("disable");
+    assert_eq!(
+        std::fs::read_to_string(&live_path).expect("read live prompt"),
+        "Second layer\n"
+    );
+
+    PromptService::enable_prompt_with(&state, AppType::Claude, "base", false).expect("append");
+    PromptService::enable_prompt(&state, AppType::Claude, "solo").expect("exclusive");
+    let prompts = PromptService::get_prompts(&state, AppType::Claude).expect("prompts");
+    let active: Vec<_> = PromptService::active_prompts(&prompts)
+        .into_iter()
+        .map(|(id, _)| id.as_str())
+        .collect();
+    assert_eq!(active, ["solo"]);
+    assert_eq!(
+        std::fs::read_to_string(&live_path).expect("read live prompt"),
+        "Only me\n"
+    );
+    assert!(
+        !prompts.keys().any(|id| id.starts_with("backup-")),
+        "matching live content must not be backed up"
+    );
+}
@@ -408,3 +408,209 @@ fn switch_provider_codex_allows_missing_auth_and_writes_config() {
     let cfg_path = get_codex_config_path();
     assert!(cfg_path.exists(), "config.toml should be written");
 }
+
+#[test]
+fn config_current_summary_reports_active_provider_and_enabled_mcp() {
+    use cc_switch_lib::cli::commands::config::collect_current_config_summary;
+
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "relay".to_string();
+        manager.providers.insert(
+            "relay".to_string(),
+            Provider::with_id(
+                "relay".to_string(),
+                "Relay".to_string(),
+                json!({
+                    "env": {
+                        "ANTHROPIC_BASE_URL": "https://relay.example.com",
+                        "ANTHROPIC_AUTH_TOKEN": "sk-relay"
+                    }
+                }),
+                None,
+            ),
+        );
+    }
+
+    let mut servers = HashMap::new();
+    for (id, claude) in [("enabled", true), ("disabled", false)] {
+        servers.insert(
+            id.to_string(),
+            McpServer {
+                id: id.to_string(),
+                name: id.to_string(),
+                server: json!({ "type": "stdio", "command": "echo" }),
+                apps: McpApps {
+                    claude,
+                    codex: false,
+                    gemini: false,
+                    opencode: false,
+                },
+                description: None,
+                homepage: None,
+                docs: None,
+                tags: Vec::new(),
+            },
+        );
+    }
+    config.mcp.servers = Some(servers);
+
+    let state = state_from_config(config);
+    let summary =
+        collect_current_config_summary(&state, &AppType::Claude).expect("collect summary");
+    let value = serde_json::to_value(&summary).expect("serialize summary");
+
+    assert_eq!(value["app"], json!("claude"));
+    assert_eq!(value["provider"]["id"], json!("relay"));
+    assert_eq!(value["provider"]["name"], json!("Relay"));
+    assert_eq!(
+        value["provider"]["apiUrl"],
+        json!("https://relay.example.com")
+    );
+    assert_eq!(value["mcpTotal"], json!(2));
+    assert_eq!(value["mcpEnabled"], json!(1));
+    assert_eq!(value["activePrompt"], serde_json::Value::Null);
+}
+
+#[test]
+fn stats_counts_providers_mcp_and_prompts_per_app() {
+    use cc_switch_lib::cli::commands::stats::collect_stats;
+
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "a".to_string();
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_string(), json!({ "env": {} }), None),
+            );
+        }
+    }
+    let mut servers = HashMap::new();
+    for (id, codex) in [("one", true), ("two", false)] {
+        servers.insert(
+            id.to_string(),
+            McpServer {
+                id: id.to_string(),
+                name: id.to_string(),
+                server: json!({ "type": "stdio", "command": "echo" }),
+                apps: McpApps {
+                    claude: true,
+                    codex,
+                    gemini: false,
+                    opencode: false,
+                },
+                description: None,
+                homepage: None,
+                docs: None,
+                tags: Vec::new(),
+            },
+        );
+    }
+    config.mcp.servers = Some(servers);
+
+    let state = state_from_config(config);
+    let stats = collect_stats(&state).expect("collect stats");
+    let value = serde_json::to_value(&stats).expect("serialize stats");
+
+    assert_eq!(value["mcpTotal"], json!(2));
+    assert_eq!(value["skillsInstalled"], json!(0));
+    let apps = value["apps"].as_array().expect("apps");
+    assert_eq!(apps.len(), 3);
+    assert_eq!(apps[0]["app"], json!("claude"));
+    assert_eq!(apps[0]["providers"], json!(2));
+    assert_eq!(apps[0]["currentProvider"], json!("a"));
+    assert_eq!(apps[0]["mcpEnabled"], json!(2));
+    assert_eq!(apps[1]["app"], json!("codex"));
+    assert_eq!(apps[1]["providers"], json!(0));
+    assert_eq!(apps[1]["currentProvider"], serde_json::Value::Null);
+    assert_eq!(apps[1]["mcpEnabled"], json!(1));
+}
+
+#[test]
+fn config_current_env_exports_provider_and_enabled_mcp_env() {
+    use cc_switch_lib::cli::commands::config::render_current_env_exports;
+
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "relay".to_string();
+        manager.providers.insert(
+            "relay".to_string(),
+            Provider::with_id(
+                "relay".to_string(),
+                "Relay".to_string(),
+                json!({
+                    "env": {
+                        "ANTHROPIC_BASE_URL": "https://relay.example.com",
+                        "ANTHROPIC_AUTH_TOKEN": "sk-it's",
+                        "ANTHROPIC_API_KEY": "${env:CC_SWITCH_TEST_EXPORT_KEY}",
+                        "CLAUDE_CODE_MAX_OUTPUT_TOKENS": "32000"
+                    }
+                }),
+                None,
+            ),
+        );
+    }
+
+    let mut servers = HashMap::new();
+    for (id, claude) in [("enabled", true), ("disabled", false)] {
+        servers.insert(
+            id.to_string(),
+            McpServer {
+                id: id.to_string(),
+                name: id.to_string(),
+                server: json!({
+                    "type": "stdio",
+                    "command": "echo",
+                    "env": { "GITHUB_TOKEN": format!("ghp-{id}") }
+                }),
+                apps: McpApps {
+                    claude,
+                    codex: false,
+                    gemini: false,
+                    opencode: false,
+                },
+                description: None,
+                homepage: None,
+                docs: None,
+                tags: Vec::new(),
+            },
+        );
+    }
+    config.mcp.servers = Some(servers);
+
+    let state = state_from_config(config);
+    std::env::set_var("CC_SWITCH_TEST_EXPORT_KEY", "sk-from-env");
+    let exports = render_current_env_exports(&state, &AppType::Claude).expect("render exports");
+    std::env::remove_var("CC_SWITCH_TEST_EXPORT_KEY");
+
+    assert!(exports.starts_with("# cc-switch claude: provider 'relay' (Relay)\n"));
+    assert!(exports.contains("export ANTHROPIC_AUTH_TOKEN='sk-it'\\''s'  # secret\n"));
+    assert!(exports.contains("export ANTHROPIC_BASE_URL='https://relay.example.com'\n"));
+    assert!(exports.contains("export ANTHROPIC_API_KEY='sk-from-env'  # secret\n"));
+    assert!(!exports.contains("${env:"));
+    assert!(exports.contains("export CLAUDE_CODE_MAX_OUTPUT_TOKENS='32000'\n"));
+    assert!(exports.contains("# MCP server 'enabled'\nexport GITHUB_TOKEN='ghp-enabled'  # secret"));
+    assert!(!exports.contains("ghp-disabled"));
+}
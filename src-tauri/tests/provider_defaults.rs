@@ -0,0 +1,46 @@
+use std::fs;
+
+use cc_switch_lib::{get_new_provider_defaults, set_new_provider_defaults, AppType};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs};
+
+#[test]
+fn new_provider_defaults_are_persisted_per_app() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    assert_eq!(get_new_provider_defaults(&AppType::Codex), (None, None));
+
+    set_new_provider_defaults(
+        &AppType::Codex,
+        Some("https://llm.example.org/v1".to_string()),
+        Some("org-codex".to_string()),
+    )
+    .expect("save codex defaults");
+    assert_eq!(
+        get_new_provider_defaults(&AppType::Codex),
+        (
+            Some("https://llm.example.org/v1".to_string()),
+            Some("org-codex".to_string())
+        )
+    );
+    assert_eq!(
+        get_new_provider_defaults(&AppType::Claude),
+        (None, None),
+        "other apps keep their built-in defaults"
+    );
+
+    let settings_json =
+        fs::read_to_string(home.join(".cc-switch").join("settings.json")).expect("read settings");
+    assert!(
+        settings_json.contains("\"defaultCodexModel\": \"org-codex\""),
+        "defaults should be persisted: {settings_json}"
+    );
+
+    set_new_provider_defaults(&AppType::Codex, Some("  ".to_string()), None)
+        .expect("save blank defaults");
+    assert_eq!(get_new_provider_defaults(&AppType::Codex), (None, None));
+}
@@ -2,8 +2,10 @@ use serde_json::json;
 use std::collections::HashMap;
 
 use cc_switch_lib::{
-    get_claude_settings_path, read_json_file, write_codex_live_atomic, AppError, AppType, McpApps,
-    McpServer, MultiAppConfig, Provider, ProviderMeta, ProviderService,
+    get_claude_settings_path, read_json_file, update_settings, write_codex_live_atomic, AppError,
+    AppSettings, AppType, AuditEntry, AuthScheme, DiagnosticStatus, ExpiryStatus, HealthStatus,
+    LiveField, LiveFileMethod, LiveVerification, McpApps, McpServer, McpService, MultiAppConfig,
+    PromptService, Provider, ProviderMeta, ProviderService, SpeedtestOptions,
 };
 
 #[path = "support.rs"]
@@ -571,476 +573,637 @@ fn provider_service_switch_claude_updates_live_and_state() {
 }
 
 #[test]
-fn provider_service_switch_missing_provider_returns_error() {
-    let _guard = lock_test_mutex();
-    reset_test_fs();
-    ensure_test_home();
-
-    let state = state_from_config(MultiAppConfig::default());
-
-    let err = ProviderService::switch(&state, AppType::Claude, "missing")
-        .expect_err("switching missing provider should fail");
-    match err {
-        AppError::Localized { key, .. } => assert_eq!(key, "provider.not_found"),
-        other => panic!("expected Localized error for provider not found, got {other:?}"),
-    }
-}
-
-#[test]
-fn provider_service_switch_codex_missing_auth_is_allowed() {
+fn provider_service_verify_live_reports_skipped_verified_and_mismatch() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let _home = ensure_test_home();
-    if let Some(parent) = cc_switch_lib::get_codex_config_path().parent() {
-        std::fs::create_dir_all(parent).expect("create codex dir (initialized)");
-    }
 
     let mut config = MultiAppConfig::default();
     {
         let manager = config
-            .get_manager_mut(&AppType::Codex)
-            .expect("codex manager");
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
         manager.providers.insert(
-            "invalid".to_string(),
+            "relay".to_string(),
             Provider::with_id(
-                "invalid".to_string(),
-                "Broken Codex".to_string(),
+                "relay".to_string(),
+                "Relay".to_string(),
                 json!({
-                    "config": "base_url = \"https://api.example.com/v1\"\nmodel = \"gpt-4o\"\nenv_key = \"OPENAI_API_KEY\"\nwire_api = \"chat\""
+                    "env": {
+                        "ANTHROPIC_AUTH_TOKEN": "relay-key",
+                        "ANTHROPIC_BASE_URL": "https://relay.example"
+                    }
                 }),
                 None,
             ),
         );
     }
-
     let state = state_from_config(config);
 
-    ProviderService::switch(&state, AppType::Codex, "invalid")
-        .expect("switching should succeed without auth.json for Codex 0.64+");
+    // ~/.claude 不存在时不会写入 live 文件
+    ProviderService::switch(&state, AppType::Claude, "relay").expect("switch uninitialized");
+    assert_eq!(
+        ProviderService::verify_live(&state, AppType::Claude, "relay").expect("verify"),
+        LiveVerification::Skipped
+    );
 
-    assert!(
-        !cc_switch_lib::get_codex_auth_path().exists(),
-        "auth.json should not be written when provider has no auth"
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("parent")).expect("create claude dir");
+    ProviderService::switch(&state, AppType::Claude, "relay").expect("switch");
+    assert_eq!(
+        ProviderService::verify_live(&state, AppType::Claude, "relay").expect("verify"),
+        LiveVerification::Verified
     );
-    assert!(
-        cc_switch_lib::get_codex_config_path().exists(),
-        "config.toml should be written"
+
+    let mut live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    live["env"]["ANTHROPIC_BASE_URL"] = json!("https://other.example");
+    std::fs::write(&settings_path, live.to_string()).expect("tamper live");
+    let LiveVerification::Mismatch(mismatches) =
+        ProviderService::verify_live(&state, AppType::Claude, "relay").expect("verify")
+    else {
+        panic!("expected mismatch");
+    };
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].field, LiveField::BaseUrl);
+    assert_eq!(mismatches[0].expected, "https://relay.example");
+    assert_eq!(
+        mismatches[0].actual.as_deref(),
+        Some("https://other.example")
     );
 }
 
 #[test]
-fn provider_service_switch_codex_openai_auth_removes_existing_auth_json() {
+fn provider_service_switch_backs_up_hand_edited_live_file() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let home = ensure_test_home();
 
-    // Mark Codex as initialized so live sync is enabled.
-    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
-
-    // Seed a legacy auth.json that should not survive an OpenAI-auth (credential store) provider.
-    let auth_path = cc_switch_lib::get_codex_auth_path();
-    std::fs::write(&auth_path, r#"{"OPENAI_API_KEY":"stale-key"}"#).expect("seed auth.json");
-    assert!(auth_path.exists(), "auth.json should exist before switch");
-
     let mut config = MultiAppConfig::default();
     {
         let manager = config
-            .get_manager_mut(&AppType::Codex)
-            .expect("codex manager");
-        manager.current = "p2".to_string();
-
-        // Target provider: OpenAI auth mode, no auth.json required.
-        manager.providers.insert(
-            "p1".to_string(),
-            Provider::with_id(
-                "p1".to_string(),
-                "OpenAI Official".to_string(),
-                json!({
-                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"responses\"\nrequires_openai_auth = true\n"
-                }),
-                None,
-            ),
-        );
-
-        // Current provider: uses API key (auth.json).
-        manager.providers.insert(
-            "p2".to_string(),
-            Provider::with_id(
-                "p2".to_string(),
-                "Other".to_string(),
-                json!({
-                    "auth": { "OPENAI_API_KEY": "sk-other" },
-                    "config": "base_url = \"https://api.other.example/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"chat\"\nenv_key = \"OPENAI_API_KEY\"\nrequires_openai_auth = false\n"
-                }),
-                None,
-            ),
-        );
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for id in ["p1", "p2"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_AUTH_TOKEN": format!("{id}-key") } }),
+                    None,
+                ),
+            );
+        }
     }
-
     let state = state_from_config(config);
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("parent")).expect("create claude dir");
+    let backup_dir = home.join(".cc-switch").join("backups").join("live");
+    let backups = || {
+        std::fs::read_dir(&backup_dir)
+            .map(|entries| entries.count())
+            .unwrap_or(0)
+    };
+
+    ProviderService::switch(&state, AppType::Claude, "p1").expect("switch p1");
+    ProviderService::switch(&state, AppType::Claude, "p2").expect("switch p2");
+    assert_eq!(backups(), 0, "files written by cc-switch are not backed up");
+
+    let tweaked = r#"{"env":{"ANTHROPIC_AUTH_TOKEN":"p2-key"},"hand":"edit"}"#;
+    std::fs::write(&settings_path, tweaked).expect("hand edit live");
+    ProviderService::switch_with_live_backup(&state, AppType::Claude, "p1", false)
+        .expect("switch without backup");
+    assert_eq!(backups(), 0, "--no-backup skips the backup");
+
+    std::fs::write(&settings_path, tweaked).expect("hand edit live again");
+    ProviderService::switch(&state, AppType::Claude, "p2").expect("switch with backup");
+    let entry = std::fs::read_dir(&backup_dir)
+        .expect("backup dir")
+        .next()
+        .expect("one backup")
+        .expect("dir entry");
+    assert!(entry
+        .file_name()
+        .to_string_lossy()
+        .starts_with("claude-settings.json."));
+    assert_eq!(
+        std::fs::read_to_string(entry.path()).expect("read backup"),
+        tweaked
+    );
 
-    ProviderService::switch(&state, AppType::Codex, "p1")
-        .expect("switch to OpenAI auth provider should succeed");
+    std::fs::write(&settings_path, tweaked).expect("hand edit live a third time");
+    ProviderService::switch(&state, AppType::Claude, "p1").expect("switch in the same second");
+    assert_eq!(backups(), 2, "back-to-back backups get unique names");
 
-    assert!(
-        !auth_path.exists(),
-        "auth.json should be removed when switching to OpenAI auth mode provider without auth config"
-    );
-    let backup_exists = std::fs::read_dir(home.join(".codex"))
-        .expect("read codex dir")
-        .filter_map(Result::ok)
-        .any(|entry| {
-            entry
-                .file_name()
-                .to_string_lossy()
-                .starts_with("auth.json.cc-switch.bak.")
-        });
-    assert!(
-        backup_exists,
-        "auth.json should be backed up when removed in OpenAI auth mode"
-    );
+    for _ in 0..25 {
+        std::fs::write(&settings_path, tweaked).expect("hand edit live");
+        ProviderService::switch(&state, AppType::Claude, "p2").expect("switch with backup");
+    }
+    assert_eq!(backups(), 20, "old live backups are pruned");
 }
 
 #[test]
-fn provider_service_switch_codex_openai_auth_removes_existing_auth_json_even_if_provider_has_auth()
-{
+fn provider_service_switch_does_not_back_up_files_rewritten_by_mcp_sync() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let home = ensure_test_home();
-
-    // Mark Codex as initialized so live sync is enabled.
-    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
-
-    // Seed a legacy auth.json that should not survive an OpenAI-auth (credential store) provider,
-    // even when the provider snapshot still carries a legacy API key.
-    let auth_path = cc_switch_lib::get_codex_auth_path();
-    std::fs::write(&auth_path, r#"{"OPENAI_API_KEY":"stale-key"}"#).expect("seed auth.json");
-    assert!(auth_path.exists(), "auth.json should exist before switch");
+    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir");
 
     let mut config = MultiAppConfig::default();
     {
         let manager = config
             .get_manager_mut(&AppType::Codex)
             .expect("codex manager");
-        manager.current = "p2".to_string();
-
-        // Target provider: OpenAI auth mode, but carries a legacy auth object (e.g. user filled it
-        // previously). This must NOT result in a written auth.json that overrides OAuth.
-        manager.providers.insert(
-            "p1".to_string(),
-            Provider::with_id(
-                "p1".to_string(),
-                "OpenAI Official".to_string(),
-                json!({
-                    "auth": { "OPENAI_API_KEY": "sk-should-not-write" },
-                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"responses\"\nrequires_openai_auth = true\n"
-                }),
-                None,
-            ),
-        );
-
-        // Current provider: uses API key (auth.json).
-        manager.providers.insert(
-            "p2".to_string(),
-            Provider::with_id(
-                "p2".to_string(),
-                "Other".to_string(),
-                json!({
-                    "auth": { "OPENAI_API_KEY": "sk-other" },
-                    "config": "base_url = \"https://api.other.example/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"chat\"\nenv_key = \"OPENAI_API_KEY\"\nrequires_openai_auth = false\n"
-                }),
-                None,
-            ),
-        );
+        for id in ["p1", "p2"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({
+                        "auth": { "OPENAI_API_KEY": format!("{id}-key") },
+                        "config": format!(
+                            "model_provider = \"{id}\"\n\n[model_providers.{id}]\nbase_url = \"https://{id}.example/v1\"\n"
+                        )
+                    }),
+                    None,
+                ),
+            );
+        }
     }
-
     let state = state_from_config(config);
+    let backup_dir = home.join(".cc-switch").join("backups").join("live");
 
-    ProviderService::switch(&state, AppType::Codex, "p1")
-        .expect("switch to OpenAI auth provider should succeed");
-
-    assert!(
-        !auth_path.exists(),
-        "auth.json should be removed when switching to OpenAI auth mode provider, even if provider has legacy auth"
-    );
-
-    let backup_exists = std::fs::read_dir(home.join(".codex"))
-        .expect("read codex dir")
-        .filter_map(Result::ok)
-        .any(|entry| {
-            entry
-                .file_name()
-                .to_string_lossy()
-                .starts_with("auth.json.cc-switch.bak.")
-        });
+    ProviderService::switch(&state, AppType::Codex, "p1").expect("switch p1");
+    McpService::upsert_server(
+        &state,
+        McpServer {
+            id: "echo-server".to_string(),
+            name: "Echo Server".to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: McpApps {
+                claude: false,
+                codex: true,
+                gemini: false,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )
+    .expect("add codex mcp server");
     assert!(
-        backup_exists,
-        "auth.json should be backed up when removed in OpenAI auth mode"
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path())
+            .expect("read config.toml")
+            .contains("mcp_servers.echo-server"),
+        "MCP sync rewrote config.toml"
     );
 
-    let live_text =
-        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
-    assert!(
-        live_text.contains("requires_openai_auth = true"),
-        "config.toml should enable OpenAI auth"
-    );
-    assert!(
-        !live_text.contains("env_key = \"OPENAI_API_KEY\""),
-        "config.toml should not force OPENAI_API_KEY env var in OpenAI auth mode"
+    ProviderService::switch(&state, AppType::Codex, "p2").expect("switch p2");
+    let backups = std::fs::read_dir(&backup_dir)
+        .map(|entries| entries.count())
+        .unwrap_or(0);
+    assert_eq!(
+        backups, 0,
+        "MCP writes by cc-switch are not treated as hand edits"
     );
 }
 
 #[test]
-fn provider_service_switch_codex_defaults_wire_api_for_openai_official_when_missing() {
+fn provider_service_check_live_files_reports_per_app_status() {
     let _guard = lock_test_mutex();
     reset_test_fs();
-    let home = ensure_test_home();
-
-    // Mark Codex as initialized so live sync is enabled.
-    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+    let _home = ensure_test_home();
 
     let mut config = MultiAppConfig::default();
     {
         let manager = config
-            .get_manager_mut(&AppType::Codex)
-            .expect("codex manager");
-        manager.current = "p1".to_string();
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
         manager.providers.insert(
-            "p1".to_string(),
+            "relay".to_string(),
             Provider::with_id(
-                "p1".to_string(),
-                "OpenAI Official".to_string(),
-                json!({
-                    // Intentionally omit wire_api to simulate older/partial configs.
-                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\nrequires_openai_auth = true\n"
-                }),
+                "relay".to_string(),
+                "Relay".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "relay-key" } }),
                 None,
             ),
         );
+        manager.current = "relay".to_string();
     }
-
     let state = state_from_config(config);
 
-    ProviderService::switch(&state, AppType::Codex, "p1")
-        .expect("switch to OpenAI official provider should succeed");
-
-    let live_text =
-        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
-    let live_value: toml::Value = toml::from_str(&live_text).expect("parse live config.toml");
-    let model_provider = live_value
-        .get("model_provider")
-        .and_then(|v| v.as_str())
-        .expect("model_provider should be set");
-    let providers = live_value
-        .get("model_providers")
-        .and_then(|v| v.as_table())
-        .expect("model_providers should exist");
-    let openai_official = providers
-        .get(model_provider)
-        .and_then(|v| v.as_table())
-        .expect("active provider table should exist");
+    let check = ProviderService::check_live_files(&state, AppType::Codex).expect("check codex");
+    assert_eq!(check.check, "live.codex");
+    assert_eq!(
+        check.status,
+        DiagnosticStatus::Ok,
+        "uninitialized apps are skipped"
+    );
 
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("parent")).expect("create claude dir");
+    let check = ProviderService::check_live_files(&state, AppType::Claude).expect("check");
     assert_eq!(
-        openai_official.get("wire_api").and_then(|v| v.as_str()),
-        Some("responses"),
-        "wire_api should default to 'responses' for OpenAI official when missing from snippet"
+        check.status,
+        DiagnosticStatus::Warn,
+        "missing settings.json"
+    );
+    assert_eq!(
+        check.remediation.as_deref(),
+        Some("cc-switch --app claude provider switch relay")
     );
+
+    ProviderService::switch(&state, AppType::Claude, "relay").expect("switch");
+    let check = ProviderService::check_live_files(&state, AppType::Claude).expect("check");
+    assert_eq!(check.status, DiagnosticStatus::Ok);
+
+    std::fs::write(&settings_path, "{ not json").expect("corrupt live");
+    let check = ProviderService::check_live_files(&state, AppType::Claude).expect("check");
+    assert_eq!(check.status, DiagnosticStatus::Error);
+    assert!(check.remediation.is_some());
 }
 
+#[cfg(unix)]
 #[test]
-fn provider_service_switch_codex_defaults_requires_openai_auth_for_openai_official_when_missing() {
+fn provider_service_switch_in_symlink_mode_links_live_file_to_managed_copy() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let home = ensure_test_home();
 
-    // Mark Codex as initialized so live sync is enabled.
-    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().unwrap()).expect("create claude dir");
+    std::fs::write(
+        &settings_path,
+        r#"{"env":{"ANTHROPIC_API_KEY":"hand-written"}}"#,
+    )
+    .expect("seed claude live config");
+    update_settings(AppSettings {
+        live_file_method: LiveFileMethod::Symlink,
+        ..AppSettings::default()
+    })
+    .expect("enable symlink mode");
 
     let mut config = MultiAppConfig::default();
     {
         let manager = config
-            .get_manager_mut(&AppType::Codex)
-            .expect("codex manager");
-        manager.current = "p1".to_string();
-        manager.providers.insert(
-            "p1".to_string(),
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, key) in [("a", "key-a"), ("b", "key-b")] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_uppercase(),
+                    json!({ "env": { "ANTHROPIC_API_KEY": key } }),
+                    None,
+                ),
+            );
+        }
+    }
+    let state = state_from_config(config);
+    let live_root = home.join(".cc-switch").join("live");
+
+    ProviderService::switch(&state, AppType::Claude, "a").expect("switch to a");
+    assert_eq!(
+        std::fs::read_link(&settings_path).expect("live file should be a symlink"),
+        live_root.join("claude").join("a").join("settings.json")
+    );
+    let backups: Vec<_> = std::fs::read_dir(live_root.join("backups").join("claude"))
+        .expect("backup dir")
+        .collect();
+    assert_eq!(
+        backups.len(),
+        1,
+        "hand-written live file should be backed up"
+    );
+
+    ProviderService::switch(&state, AppType::Claude, "b").expect("switch to b");
+    assert_eq!(
+        std::fs::read_link(&settings_path).expect("live file should stay a symlink"),
+        live_root.join("claude").join("b").join("settings.json")
+    );
+    let live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    assert_eq!(live["env"]["ANTHROPIC_API_KEY"], "key-b");
+
+    ProviderService::set_live_file_method(&state, LiveFileMethod::Copy)
+        .expect("switch back to copy mode");
+    assert!(!settings_path
+        .symlink_metadata()
+        .expect("live metadata")
+        .file_type()
+        .is_symlink());
+    let live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    assert_eq!(live["env"]["ANTHROPIC_API_KEY"], "key-b");
+}
+
+#[cfg(unix)]
+#[test]
+fn mcp_sync_in_symlink_mode_writes_through_live_symlink() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let gemini_dir = home.join(".gemini");
+    std::fs::create_dir_all(&gemini_dir).expect("create gemini dir");
+    update_settings(AppSettings {
+        live_file_method: LiveFileMethod::Symlink,
+        ..AppSettings::default()
+    })
+    .expect("enable symlink mode");
+
+    let mut config = MultiAppConfig::default();
+    config
+        .get_manager_mut(&AppType::Gemini)
+        .expect("gemini manager")
+        .providers
+        .insert(
+            "a".to_string(),
             Provider::with_id(
-                "p1".to_string(),
-                "OpenAI Official".to_string(),
-                json!({
-                    // Intentionally omit requires_openai_auth (and wire_api) to simulate older/partial configs.
-                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\n"
-                }),
+                "a".to_string(),
+                "A".to_string(),
+                json!({ "env": { "GEMINI_API_KEY": "key-a" }, "config": {} }),
                 None,
             ),
         );
-    }
-
     let state = state_from_config(config);
 
-    ProviderService::switch(&state, AppType::Codex, "p1")
-        .expect("switch to OpenAI official provider should succeed");
-
-    let live_text =
-        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
-    let live_value: toml::Value = toml::from_str(&live_text).expect("parse live config.toml");
+    ProviderService::switch(&state, AppType::Gemini, "a").expect("switch to a");
+    let settings_path = gemini_dir.join("settings.json");
+    let managed = home
+        .join(".cc-switch")
+        .join("live")
+        .join("gemini")
+        .join("a")
+        .join("settings.json");
+    assert_eq!(
+        std::fs::read_link(&settings_path).expect("live file should be a symlink"),
+        managed
+    );
 
-    let model_provider = live_value
-        .get("model_provider")
-        .and_then(|v| v.as_str())
-        .expect("model_provider should be set");
-    let provider_table = live_value
-        .get("model_providers")
-        .and_then(|v| v.as_table())
-        .and_then(|providers| providers.get(model_provider))
-        .and_then(|v| v.as_table())
-        .expect("model_providers.<id> table should exist");
+    McpService::upsert_server(
+        &state,
+        McpServer {
+            id: "echo-server".to_string(),
+            name: "Echo Server".to_string(),
+            server: json!({ "type": "stdio", "command": "echo" }),
+            apps: McpApps {
+                claude: false,
+                codex: false,
+                gemini: true,
+                opencode: false,
+            },
+            description: None,
+            homepage: None,
+            docs: None,
+            tags: Vec::new(),
+        },
+    )
+    .expect("add gemini mcp server");
 
     assert_eq!(
-        provider_table
-            .get("requires_openai_auth")
-            .and_then(|v| v.as_bool()),
-        Some(true),
-        "requires_openai_auth should default to true for OpenAI official base_url when missing"
+        std::fs::read_link(&settings_path).expect("MCP sync keeps the live symlink"),
+        managed
     );
+    let managed_settings: serde_json::Value = read_json_file(&managed).expect("read managed copy");
     assert_eq!(
-        provider_table.get("wire_api").and_then(|v| v.as_str()),
-        Some("responses"),
-        "wire_api should default to 'responses' for OpenAI official base_url when missing"
-    );
-    assert!(
-        provider_table.get("env_key").is_none(),
-        "env_key should be omitted when defaulting to OpenAI auth mode"
+        managed_settings["mcpServers"]["echo-server"]["command"],
+        "echo"
     );
 }
 
 #[test]
-fn provider_service_delete_codex_removes_provider_and_files() {
+fn provider_service_symlink_mode_stops_retrying_once_symlinks_are_unsupported() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     let home = ensure_test_home();
 
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().unwrap()).expect("create claude dir");
+    std::fs::write(&settings_path, r#"{"env":{}}"#).expect("seed claude live config");
+    update_settings(AppSettings {
+        live_file_method: LiveFileMethod::Symlink,
+        ..AppSettings::default()
+    })
+    .expect("enable symlink mode");
+
     let mut config = MultiAppConfig::default();
     {
         let manager = config
-            .get_manager_mut(&AppType::Codex)
-            .expect("codex manager");
-        manager.current = "keep".to_string();
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
         manager.providers.insert(
-            "keep".to_string(),
+            "a".to_string(),
             Provider::with_id(
-                "keep".to_string(),
-                "Keep".to_string(),
-                json!({
-                    "auth": {"OPENAI_API_KEY": "keep-key"},
-                    "config": ""
-                }),
+                "a".to_string(),
+                "A".to_string(),
+                json!({ "env": { "ANTHROPIC_API_KEY": "key-a" } }),
                 None,
             ),
         );
+    }
+    let state = state_from_config(config);
+
+    // 模拟之前已确认系统不支持符号链接
+    let live_root = home.join(".cc-switch").join("live");
+    std::fs::create_dir_all(&live_root).expect("create live root");
+    let marker = live_root.join(".symlink-unsupported");
+    std::fs::write(&marker, "").expect("write marker");
+
+    ProviderService::switch(&state, AppType::Claude, "a").expect("switch to a");
+    assert!(settings_path
+        .symlink_metadata()
+        .expect("live metadata")
+        .file_type()
+        .is_file());
+    assert!(
+        !live_root.join("backups").exists(),
+        "no backup when symlinks are known to be unsupported"
+    );
+
+    // 重新开启符号链接模式会清除标记并再试一次
+    ProviderService::set_live_file_method(&state, LiveFileMethod::Symlink)
+        .expect("re-enable symlink mode");
+    assert!(!marker.exists());
+    assert_eq!(
+        std::fs::read_link(&settings_path).expect("live file should be a symlink"),
+        live_root.join("claude").join("a").join("settings.json")
+    );
+}
+
+#[test]
+fn provider_service_current_api_key_resolves_env_placeholders() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    std::env::set_var("CC_SWITCH_TEST_PRINT_KEY", "sk-from-env");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "env-key".to_string();
         manager.providers.insert(
-            "to-delete".to_string(),
+            "env-key".to_string(),
             Provider::with_id(
-                "to-delete".to_string(),
-                "DeleteCodex".to_string(),
-                json!({
-                    "auth": {"OPENAI_API_KEY": "delete-key"},
-                    "config": ""
-                }),
+                "env-key".to_string(),
+                "Env Key".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "${env:CC_SWITCH_TEST_PRINT_KEY}" } }),
                 None,
             ),
         );
     }
+    let state = state_from_config(config);
 
-    let sanitized = sanitize_provider_name("DeleteCodex");
-    let codex_dir = home.join(".codex");
-    std::fs::create_dir_all(&codex_dir).expect("create codex dir");
-    let auth_path = codex_dir.join(format!("auth-{sanitized}.json"));
-    let cfg_path = codex_dir.join(format!("config-{sanitized}.toml"));
-    std::fs::write(&auth_path, "{}").expect("seed auth file");
-    std::fs::write(&cfg_path, "base_url = \"https://example\"").expect("seed config file");
-
-    let app_state = state_from_config(config);
-
-    ProviderService::delete(&app_state, AppType::Codex, "to-delete")
-        .expect("delete provider should succeed");
-
-    let locked = app_state.config.read().expect("lock config after delete");
-    let manager = locked.get_manager(&AppType::Codex).expect("codex manager");
+    let key = ProviderService::current_api_key(&state, AppType::Claude).expect("current key");
+    assert_eq!(key, "sk-from-env");
     assert!(
-        !manager.providers.contains_key("to-delete"),
-        "provider entry should be removed"
-    );
-    assert!(
-        !auth_path.exists() && !cfg_path.exists(),
-        "provider-specific files should be deleted"
+        ProviderService::current_api_key(&state, AppType::Codex).is_err(),
+        "no current Codex provider should be an error"
     );
+    std::env::remove_var("CC_SWITCH_TEST_PRINT_KEY");
 }
 
 #[test]
-fn provider_service_delete_claude_removes_provider_files() {
+fn provider_service_preview_common_snippet_merges_without_saving() {
     let _guard = lock_test_mutex();
     reset_test_fs();
-    let home = ensure_test_home();
+    let _home = ensure_test_home();
 
     let mut config = MultiAppConfig::default();
     {
         let manager = config
             .get_manager_mut(&AppType::Claude)
             .expect("claude manager");
-        manager.current = "keep".to_string();
+        manager.current = "p1".to_string();
         manager.providers.insert(
-            "keep".to_string(),
+            "p1".to_string(),
             Provider::with_id(
-                "keep".to_string(),
-                "Keep".to_string(),
+                "p1".to_string(),
+                "P1".to_string(),
                 json!({
-                    "env": { "ANTHROPIC_API_KEY": "keep-key" }
+                    "env": { "ANTHROPIC_AUTH_TOKEN": "sk-p1", "SHARED": "provider" },
+                    "headers": { "X-Team": "infra" }
                 }),
                 None,
             ),
         );
+    }
+    let state = state_from_config(config);
+
+    let preview = ProviderService::preview_common_snippet(
+        &state,
+        AppType::Claude,
+        r#"{"env":{"SHARED":"common","EXTRA":"1"},"includeCoAuthoredBy":false}"#,
+    )
+    .expect("preview")
+    .expect("current provider exists");
+    assert_eq!(preview.provider_id, "p1");
+    assert!(preview.applies);
+    let merged: serde_json::Value = serde_json::from_str(&preview.merged).expect("json");
+    assert_eq!(merged["env"]["SHARED"], "provider", "provider values win");
+    assert_eq!(merged["env"]["EXTRA"], "1");
+    assert_eq!(merged["includeCoAuthoredBy"], false);
+    assert!(
+        merged.get("headers").is_none(),
+        "headers are never written to the live file"
+    );
+    assert!(
+        state
+            .config
+            .read()
+            .expect("read config")
+            .common_config_snippets
+            .claude
+            .is_none(),
+        "preview must not store the snippet"
+    );
+
+    let base: serde_json::Value = serde_json::from_str(&preview.base).expect("json");
+    assert_eq!(base["env"]["SHARED"], "provider");
+    assert!(
+        base.get("includeCoAuthoredBy").is_none(),
+        "base omits the snippet"
+    );
+
+    let saved = ProviderService::preview_saved_common_snippet(&state, AppType::Claude)
+        .expect("preview saved")
+        .expect("current provider exists");
+    assert_eq!(saved.base, saved.merged, "no saved snippet means no change");
+    assert_eq!(saved.base, preview.base);
+
+    assert!(ProviderService::preview_common_snippet(&state, AppType::Claude, "[1]").is_err());
+    assert!(
+        ProviderService::preview_common_snippet(&state, AppType::Gemini, "{}")
+            .expect("no gemini provider")
+            .is_none()
+    );
+}
+
+#[test]
+fn provider_service_saved_snippet_preview_matches_applied_live_file() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    let settings_path = get_claude_settings_path();
+    std::fs::create_dir_all(settings_path.parent().expect("parent")).expect("create claude dir");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "p1".to_string();
         manager.providers.insert(
-            "delete".to_string(),
+            "p1".to_string(),
             Provider::with_id(
-                "delete".to_string(),
-                "DeleteClaude".to_string(),
+                "p1".to_string(),
+                "P1".to_string(),
                 json!({
-                    "env": { "ANTHROPIC_API_KEY": "delete-key" }
+                    "env": { "ANTHROPIC_AUTH_TOKEN": "sk-p1", "ANTHROPIC_MODEL": "claude-x" },
+                    "headers": { "X-Team": "infra" }
                 }),
                 None,
             ),
         );
     }
+    config.common_config_snippets.claude =
+        Some(r#"{"env":{"EXTRA":"1"},"includeCoAuthoredBy":false}"#.to_string());
+    let state = state_from_config(config);
 
-    let sanitized = sanitize_provider_name("DeleteClaude");
-    let claude_dir = home.join(".claude");
-    std::fs::create_dir_all(&claude_dir).expect("create claude dir");
-    let by_name = claude_dir.join(format!("settings-{sanitized}.json"));
-    let by_id = claude_dir.join("settings-delete.json");
-    std::fs::write(&by_name, "{}").expect("seed settings by name");
-    std::fs::write(&by_id, "{}").expect("seed settings by id");
+    let preview = ProviderService::preview_saved_common_snippet(&state, AppType::Claude)
+        .expect("preview")
+        .expect("current provider exists");
+    ProviderService::resync_live(&state, AppType::Claude, "p1").expect("apply snippet");
+    let written: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    let previewed: serde_json::Value = serde_json::from_str(&preview.merged).expect("json");
+    assert_eq!(
+        previewed, written,
+        "dry-run shows exactly what --apply writes"
+    );
+}
 
-    let app_state = state_from_config(config);
+#[test]
+fn provider_service_switch_missing_provider_returns_error() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
 
-    ProviderService::delete(&app_state, AppType::Claude, "delete").expect("delete claude provider");
+    let state = state_from_config(MultiAppConfig::default());
 
-    let locked = app_state.config.read().expect("lock config after delete");
-    let manager = locked
-        .get_manager(&AppType::Claude)
-        .expect("claude manager");
-    assert!(
-        !manager.providers.contains_key("delete"),
-        "claude provider should be removed"
-    );
-    assert!(
-        !by_name.exists() && !by_id.exists(),
-        "provider config files should be deleted"
-    );
+    let err = ProviderService::switch(&state, AppType::Claude, "missing")
+        .expect_err("switching missing provider should fail");
+    match err {
+        AppError::Localized { key, .. } => assert_eq!(key, "provider.not_found"),
+        other => panic!("expected Localized error for provider not found, got {other:?}"),
+    }
 }
 
 #[test]
-fn provider_service_delete_current_provider_returns_error() {
+fn provider_service_health_targets_resolve_url_and_key() {
     let _guard = lock_test_mutex();
     reset_test_fs();
     ensure_test_home();
@@ -1050,29 +1213,659 @@ fn provider_service_delete_current_provider_returns_error() {
         let manager = config
             .get_manager_mut(&AppType::Claude)
             .expect("claude manager");
-        manager.current = "keep".to_string();
+        manager.current = "relay".to_string();
         manager.providers.insert(
-            "keep".to_string(),
+            "relay".to_string(),
             Provider::with_id(
-                "keep".to_string(),
-                "Keep".to_string(),
-                json!({
-                    "env": { "ANTHROPIC_API_KEY": "keep-key" }
-                }),
+                "relay".to_string(),
+                "Relay".to_string(),
+                json!({ "env": {
+                    "ANTHROPIC_BASE_URL": "https://relay.example.com",
+                    "ANTHROPIC_AUTH_TOKEN": "sk-relay"
+                } }),
+                None,
+            ),
+        );
+        manager.providers.insert(
+            "bare".to_string(),
+            Provider::with_id(
+                "bare".to_string(),
+                "Bare".to_string(),
+                json!({ "env": {} }),
+                None,
+            ),
+        );
+        let mut official = Provider::with_id(
+            "official".to_string(),
+            "Claude Official".to_string(),
+            json!({ "env": {} }),
+            None,
+        );
+        official.category = Some("official".to_string());
+        manager.providers.insert("official".to_string(), official);
+        manager.providers.insert(
+            "unset-env".to_string(),
+            Provider::with_id(
+                "unset-env".to_string(),
+                "Unset Env".to_string(),
+                json!({ "env": {
+                    "ANTHROPIC_BASE_URL": "https://relay.example.com",
+                    "ANTHROPIC_AUTH_TOKEN": "${env:CC_SWITCH_TEST_HEALTH_UNSET_KEY}"
+                } }),
                 None,
             ),
         );
     }
+    let state = state_from_config(config);
 
-    let app_state = state_from_config(config);
+    let targets = ProviderService::health_targets(&state, AppType::Claude).expect("health targets");
+    let relay = targets
+        .iter()
+        .find(|t| t.provider_id == "relay")
+        .expect("relay");
+    assert_eq!(relay.url.as_deref(), Some("https://relay.example.com"));
+    assert_eq!(relay.api_key.as_deref(), Some("sk-relay"));
+    let bare = targets
+        .iter()
+        .find(|t| t.provider_id == "bare")
+        .expect("bare");
+    assert!(bare.url.is_none() && bare.api_key.is_none());
+    assert_eq!(relay.auth_scheme, AuthScheme::Bearer);
+    let official = targets
+        .iter()
+        .find(|t| t.provider_id == "official")
+        .expect("official")
+        .clone();
+    assert!(official.official);
+
+    // 未配置端点的供应商不发请求，直接判为不可达
+    let bare = bare.clone();
+    let results = tokio::runtime::Runtime::new()
+        .expect("runtime")
+        .block_on(ProviderService::check_provider_health(
+            AppType::Claude,
+            vec![bare],
+            SpeedtestOptions::default(),
+        ))
+        .expect("health check");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].status, HealthStatus::Unreachable);
+    assert!(results[0].latency.is_none());
+
+    // 官方供应商未配置端点时不适用，而不是不可达
+    let results = tokio::runtime::Runtime::new()
+        .expect("runtime")
+        .block_on(ProviderService::check_provider_health(
+            AppType::Claude,
+            vec![official],
+            SpeedtestOptions::default(),
+        ))
+        .expect("health check");
+    assert_eq!(results[0].status, HealthStatus::NotApplicable);
+
+    // 占位符解析失败时保留原因，而不是静默判为不可达
+    let unset = targets
+        .iter()
+        .find(|t| t.provider_id == "unset-env")
+        .expect("unset-env")
+        .clone();
+    let reason = unset.error.clone().expect("resolve error is kept");
+    assert!(
+        reason.contains("CC_SWITCH_TEST_HEALTH_UNSET_KEY"),
+        "{reason}"
+    );
+    let results = tokio::runtime::Runtime::new()
+        .expect("runtime")
+        .block_on(ProviderService::check_provider_health(
+            AppType::Claude,
+            vec![unset],
+            SpeedtestOptions::default(),
+        ))
+        .expect("health check");
+    assert_eq!(results[0].status, HealthStatus::Unreachable);
+    assert_eq!(results[0].error.as_deref(), Some(reason.as_str()));
+}
 
-    let err = ProviderService::delete(&app_state, AppType::Claude, "keep")
-        .expect_err("deleting current provider should fail");
-    match err {
-        AppError::Localized { zh, .. } => assert!(
-            zh.contains("不能删除当前正在使用的供应商"),
-            "unexpected message: {zh}"
-        ),
+#[test]
+fn provider_service_mutations_are_appended_to_audit_log() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "a".to_string();
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_uppercase(),
+                    json!({ "env": { "ANTHROPIC_API_KEY": format!("{id}-key") } }),
+                    None,
+                ),
+            );
+        }
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Claude, "b").expect("switch should succeed");
+    ProviderService::switch(&state, AppType::Claude, "missing").expect_err("missing provider");
+    ProviderService::resync_live(&state, AppType::Claude, "b").expect("resync should succeed");
+    PromptService::delete_prompt(&state, AppType::Claude, "ghost").ok();
+
+    let log = std::fs::read_to_string(home.join(".cc-switch").join("audit.log"))
+        .expect("audit log written");
+    let entries: Vec<AuditEntry> = log
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("valid audit line"))
+        .collect();
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries[0].operation, "provider.switch");
+    assert_eq!(entries[0].app.as_deref(), Some("claude"));
+    assert_eq!(entries[0].id.as_deref(), Some("b"));
+    assert!(entries[0].is_ok());
+    assert_eq!(entries[1].id.as_deref(), Some("missing"));
+    assert_eq!(entries[1].outcome, "error");
+    assert!(entries[1].error.is_some());
+    assert_eq!(
+        entries[2].operation, "provider.resync",
+        "re-syncs are not logged as user switches"
+    );
+    assert_eq!(entries[3].operation, "prompt.delete");
+    assert_eq!(entries[3].id.as_deref(), Some("ghost"));
+}
+
+#[test]
+fn provider_service_switch_codex_missing_auth_is_allowed() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    if let Some(parent) = cc_switch_lib::get_codex_config_path().parent() {
+        std::fs::create_dir_all(parent).expect("create codex dir (initialized)");
+    }
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.providers.insert(
+            "invalid".to_string(),
+            Provider::with_id(
+                "invalid".to_string(),
+                "Broken Codex".to_string(),
+                json!({
+                    "config": "base_url = \"https://api.example.com/v1\"\nmodel = \"gpt-4o\"\nenv_key = \"OPENAI_API_KEY\"\nwire_api = \"chat\""
+                }),
+                None,
+            ),
+        );
+    }
+
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Codex, "invalid")
+        .expect("switching should succeed without auth.json for Codex 0.64+");
+
+    assert!(
+        !cc_switch_lib::get_codex_auth_path().exists(),
+        "auth.json should not be written when provider has no auth"
+    );
+    assert!(
+        cc_switch_lib::get_codex_config_path().exists(),
+        "config.toml should be written"
+    );
+}
+
+#[test]
+fn provider_service_switch_codex_openai_auth_removes_existing_auth_json() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // Mark Codex as initialized so live sync is enabled.
+    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+
+    // Seed a legacy auth.json that should not survive an OpenAI-auth (credential store) provider.
+    let auth_path = cc_switch_lib::get_codex_auth_path();
+    std::fs::write(&auth_path, r#"{"OPENAI_API_KEY":"stale-key"}"#).expect("seed auth.json");
+    assert!(auth_path.exists(), "auth.json should exist before switch");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "p2".to_string();
+
+        // Target provider: OpenAI auth mode, no auth.json required.
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "OpenAI Official".to_string(),
+                json!({
+                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"responses\"\nrequires_openai_auth = true\n"
+                }),
+                None,
+            ),
+        );
+
+        // Current provider: uses API key (auth.json).
+        manager.providers.insert(
+            "p2".to_string(),
+            Provider::with_id(
+                "p2".to_string(),
+                "Other".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-other" },
+                    "config": "base_url = \"https://api.other.example/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"chat\"\nenv_key = \"OPENAI_API_KEY\"\nrequires_openai_auth = false\n"
+                }),
+                None,
+            ),
+        );
+    }
+
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Codex, "p1")
+        .expect("switch to OpenAI auth provider should succeed");
+
+    assert!(
+        !auth_path.exists(),
+        "auth.json should be removed when switching to OpenAI auth mode provider without auth config"
+    );
+    let backup_exists = std::fs::read_dir(home.join(".codex"))
+        .expect("read codex dir")
+        .filter_map(Result::ok)
+        .any(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("auth.json.cc-switch.bak.")
+        });
+    assert!(
+        backup_exists,
+        "auth.json should be backed up when removed in OpenAI auth mode"
+    );
+}
+
+#[test]
+fn provider_service_switch_codex_openai_auth_removes_existing_auth_json_even_if_provider_has_auth()
+{
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // Mark Codex as initialized so live sync is enabled.
+    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+
+    // Seed a legacy auth.json that should not survive an OpenAI-auth (credential store) provider,
+    // even when the provider snapshot still carries a legacy API key.
+    let auth_path = cc_switch_lib::get_codex_auth_path();
+    std::fs::write(&auth_path, r#"{"OPENAI_API_KEY":"stale-key"}"#).expect("seed auth.json");
+    assert!(auth_path.exists(), "auth.json should exist before switch");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "p2".to_string();
+
+        // Target provider: OpenAI auth mode, but carries a legacy auth object (e.g. user filled it
+        // previously). This must NOT result in a written auth.json that overrides OAuth.
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "OpenAI Official".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-should-not-write" },
+                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"responses\"\nrequires_openai_auth = true\n"
+                }),
+                None,
+            ),
+        );
+
+        // Current provider: uses API key (auth.json).
+        manager.providers.insert(
+            "p2".to_string(),
+            Provider::with_id(
+                "p2".to_string(),
+                "Other".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-other" },
+                    "config": "base_url = \"https://api.other.example/v1\"\nmodel = \"gpt-5.2-codex\"\nwire_api = \"chat\"\nenv_key = \"OPENAI_API_KEY\"\nrequires_openai_auth = false\n"
+                }),
+                None,
+            ),
+        );
+    }
+
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Codex, "p1")
+        .expect("switch to OpenAI auth provider should succeed");
+
+    assert!(
+        !auth_path.exists(),
+        "auth.json should be removed when switching to OpenAI auth mode provider, even if provider has legacy auth"
+    );
+
+    let backup_exists = std::fs::read_dir(home.join(".codex"))
+        .expect("read codex dir")
+        .filter_map(Result::ok)
+        .any(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with("auth.json.cc-switch.bak.")
+        });
+    assert!(
+        backup_exists,
+        "auth.json should be backed up when removed in OpenAI auth mode"
+    );
+
+    let live_text =
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
+    assert!(
+        live_text.contains("requires_openai_auth = true"),
+        "config.toml should enable OpenAI auth"
+    );
+    assert!(
+        !live_text.contains("env_key = \"OPENAI_API_KEY\""),
+        "config.toml should not force OPENAI_API_KEY env var in OpenAI auth mode"
+    );
+}
+
+#[test]
+fn provider_service_switch_codex_defaults_wire_api_for_openai_official_when_missing() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // Mark Codex as initialized so live sync is enabled.
+    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "p1".to_string();
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "OpenAI Official".to_string(),
+                json!({
+                    // Intentionally omit wire_api to simulate older/partial configs.
+                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\nrequires_openai_auth = true\n"
+                }),
+                None,
+            ),
+        );
+    }
+
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Codex, "p1")
+        .expect("switch to OpenAI official provider should succeed");
+
+    let live_text =
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
+    let live_value: toml::Value = toml::from_str(&live_text).expect("parse live config.toml");
+    let model_provider = live_value
+        .get("model_provider")
+        .and_then(|v| v.as_str())
+        .expect("model_provider should be set");
+    let providers = live_value
+        .get("model_providers")
+        .and_then(|v| v.as_table())
+        .expect("model_providers should exist");
+    let openai_official = providers
+        .get(model_provider)
+        .and_then(|v| v.as_table())
+        .expect("active provider table should exist");
+
+    assert_eq!(
+        openai_official.get("wire_api").and_then(|v| v.as_str()),
+        Some("responses"),
+        "wire_api should default to 'responses' for OpenAI official when missing from snippet"
+    );
+}
+
+#[test]
+fn provider_service_switch_codex_defaults_requires_openai_auth_for_openai_official_when_missing() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    // Mark Codex as initialized so live sync is enabled.
+    std::fs::create_dir_all(home.join(".codex")).expect("create codex dir (initialized)");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "p1".to_string();
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "OpenAI Official".to_string(),
+                json!({
+                    // Intentionally omit requires_openai_auth (and wire_api) to simulate older/partial configs.
+                    "config": "base_url = \"https://api.openai.com/v1\"\nmodel = \"gpt-5.2-codex\"\n"
+                }),
+                None,
+            ),
+        );
+    }
+
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Codex, "p1")
+        .expect("switch to OpenAI official provider should succeed");
+
+    let live_text =
+        std::fs::read_to_string(cc_switch_lib::get_codex_config_path()).expect("read config.toml");
+    let live_value: toml::Value = toml::from_str(&live_text).expect("parse live config.toml");
+
+    let model_provider = live_value
+        .get("model_provider")
+        .and_then(|v| v.as_str())
+        .expect("model_provider should be set");
+    let provider_table = live_value
+        .get("model_providers")
+        .and_then(|v| v.as_table())
+        .and_then(|providers| providers.get(model_provider))
+        .and_then(|v| v.as_table())
+        .expect("model_providers.<id> table should exist");
+
+    assert_eq!(
+        provider_table
+            .get("requires_openai_auth")
+            .and_then(|v| v.as_bool()),
+        Some(true),
+        "requires_openai_auth should default to true for OpenAI official base_url when missing"
+    );
+    assert_eq!(
+        provider_table.get("wire_api").and_then(|v| v.as_str()),
+        Some("responses"),
+        "wire_api should default to 'responses' for OpenAI official base_url when missing"
+    );
+    assert!(
+        provider_table.get("env_key").is_none(),
+        "env_key should be omitted when defaulting to OpenAI auth mode"
+    );
+}
+
+#[test]
+fn provider_service_delete_codex_removes_provider_and_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.current = "keep".to_string();
+        manager.providers.insert(
+            "keep".to_string(),
+            Provider::with_id(
+                "keep".to_string(),
+                "Keep".to_string(),
+                json!({
+                    "auth": {"OPENAI_API_KEY": "keep-key"},
+                    "config": ""
+                }),
+                None,
+            ),
+        );
+        manager.providers.insert(
+            "to-delete".to_string(),
+            Provider::with_id(
+                "to-delete".to_string(),
+                "DeleteCodex".to_string(),
+                json!({
+                    "auth": {"OPENAI_API_KEY": "delete-key"},
+                    "config": ""
+                }),
+                None,
+            ),
+        );
+    }
+
+    let sanitized = sanitize_provider_name("DeleteCodex");
+    let codex_dir = home.join(".codex");
+    std::fs::create_dir_all(&codex_dir).expect("create codex dir");
+    let auth_path = codex_dir.join(format!("auth-{sanitized}.json"));
+    let cfg_path = codex_dir.join(format!("config-{sanitized}.toml"));
+    std::fs::write(&auth_path, "{}").expect("seed auth file");
+    std::fs::write(&cfg_path, "base_url = \"https://example\"").expect("seed config file");
+
+    let app_state = state_from_config(config);
+
+    ProviderService::delete(&app_state, AppType::Codex, "to-delete")
+        .expect("delete provider should succeed");
+
+    let locked = app_state.config.read().expect("lock config after delete");
+    let manager = locked.get_manager(&AppType::Codex).expect("codex manager");
+    assert!(
+        !manager.providers.contains_key("to-delete"),
+        "provider entry should be removed"
+    );
+    assert!(
+        !auth_path.exists() && !cfg_path.exists(),
+        "provider-specific files should be deleted"
+    );
+}
+
+#[test]
+fn provider_service_delete_claude_removes_provider_files() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "keep".to_string();
+        manager.providers.insert(
+            "keep".to_string(),
+            Provider::with_id(
+                "keep".to_string(),
+                "Keep".to_string(),
+                json!({
+                    "env": { "ANTHROPIC_API_KEY": "keep-key" }
+                }),
+                None,
+            ),
+        );
+        manager.providers.insert(
+            "delete".to_string(),
+            Provider::with_id(
+                "delete".to_string(),
+                "DeleteClaude".to_string(),
+                json!({
+                    "env": { "ANTHROPIC_API_KEY": "delete-key" }
+                }),
+                None,
+            ),
+        );
+    }
+
+    let sanitized = sanitize_provider_name("DeleteClaude");
+    let claude_dir = home.join(".claude");
+    std::fs::create_dir_all(&claude_dir).expect("create claude dir");
+    let by_name = claude_dir.join(format!("settings-{sanitized}.json"));
+    let by_id = claude_dir.join("settings-delete.json");
+    std::fs::write(&by_name, "{}").expect("seed settings by name");
+    std::fs::write(&by_id, "{}").expect("seed settings by id");
+
+    let app_state = state_from_config(config);
+
+    ProviderService::delete(&app_state, AppType::Claude, "delete").expect("delete claude provider");
+
+    let locked = app_state.config.read().expect("lock config after delete");
+    let manager = locked
+        .get_manager(&AppType::Claude)
+        .expect("claude manager");
+    assert!(
+        !manager.providers.contains_key("delete"),
+        "claude provider should be removed"
+    );
+    assert!(
+        !by_name.exists() && !by_id.exists(),
+        "provider config files should be deleted"
+    );
+}
+
+#[test]
+fn provider_service_delete_current_provider_returns_error() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "keep".to_string();
+        manager.providers.insert(
+            "keep".to_string(),
+            Provider::with_id(
+                "keep".to_string(),
+                "Keep".to_string(),
+                json!({
+                    "env": { "ANTHROPIC_API_KEY": "keep-key" }
+                }),
+                None,
+            ),
+        );
+    }
+
+    let app_state = state_from_config(config);
+
+    let err = ProviderService::delete(&app_state, AppType::Claude, "keep")
+        .expect_err("deleting current provider should fail");
+    match err {
+        AppError::Localized { zh, .. } => assert!(
+            zh.contains("不能删除当前正在使用的供应商"),
+            "unexpected message: {zh}"
+        ),
         AppError::Config(msg) => assert!(
             msg.contains("不能删除当前正在使用的供应商"),
             "unexpected message: {msg}"
@@ -1080,3 +1873,887 @@ fn provider_service_delete_current_provider_returns_error() {
         other => panic!("expected Config error, got {other:?}"),
     }
 }
+
+#[test]
+fn provider_service_force_delete_current_provider_clears_selection() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "keep".to_string();
+        manager.providers.insert(
+            "keep".to_string(),
+            Provider::with_id(
+                "keep".to_string(),
+                "Keep".to_string(),
+                json!({
+                    "env": { "ANTHROPIC_API_KEY": "keep-key" }
+                }),
+                None,
+            ),
+        );
+    }
+
+    let app_state = state_from_config(config);
+
+    let err = ProviderService::delete(&app_state, AppType::Claude, "keep")
+        .expect_err("deleting current provider without force should fail");
+    assert!(
+        err.to_string().contains("switch to another provider first")
+            || err.to_string().contains("请先切换到其他供应商"),
+        "error should suggest switching first: {err}"
+    );
+
+    ProviderService::force_delete(&app_state, AppType::Claude, "keep")
+        .expect("force delete current provider");
+
+    let config = app_state.config.read().expect("read config");
+    let manager = config
+        .get_manager(&AppType::Claude)
+        .expect("claude manager");
+    assert!(
+        manager.current.is_empty(),
+        "current selection should be cleared"
+    );
+    assert!(!manager.providers.contains_key("keep"));
+}
+
+#[test]
+fn provider_service_rotate_key_updates_matching_providers_and_live_config() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let settings_path = get_claude_settings_path();
+    if let Some(parent) = settings_path.parent() {
+        std::fs::create_dir_all(parent).expect("create claude settings dir");
+    }
+    std::fs::write(&settings_path, "{}").expect("seed claude live config");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "relay-a".to_string();
+        for (id, url, key) in [
+            ("relay-a", "https://relay.example", "ANTHROPIC_AUTH_TOKEN"),
+            ("relay-b", "https://Relay.Example/", "ANTHROPIC_API_KEY"),
+            ("other", "https://other.example", "ANTHROPIC_AUTH_TOKEN"),
+        ] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_BASE_URL": url, key: "old-key" } }),
+                    None,
+                ),
+            );
+        }
+    }
+
+    let app_state = state_from_config(config);
+
+    let updated = ProviderService::rotate_key(
+        &app_state,
+        AppType::Claude,
+        "https://relay.example",
+        "sk-rotated",
+    )
+    .expect("rotate key");
+    assert_eq!(updated, vec!["relay-a".to_string(), "relay-b".to_string()]);
+
+    {
+        let config = app_state.config.read().expect("read config");
+        let providers = &config
+            .get_manager(&AppType::Claude)
+            .expect("claude manager")
+            .providers;
+        assert_eq!(
+            providers["relay-a"].settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+            "sk-rotated"
+        );
+        assert_eq!(
+            providers["relay-b"].settings_config["env"]["ANTHROPIC_API_KEY"],
+            "sk-rotated"
+        );
+        assert_eq!(
+            providers["other"].settings_config["env"]["ANTHROPIC_AUTH_TOKEN"],
+            "old-key"
+        );
+    }
+
+    let live: serde_json::Value =
+        read_json_file(&settings_path).expect("read claude live settings");
+    assert_eq!(live["env"]["ANTHROPIC_AUTH_TOKEN"], "sk-rotated");
+
+    let none = ProviderService::rotate_key(
+        &app_state,
+        AppType::Claude,
+        "https://unknown.example",
+        "sk-x",
+    )
+    .expect("rotate with no match");
+    assert!(none.is_empty());
+}
+
+#[test]
+fn provider_service_switch_resolves_env_placeholders_without_persisting_secrets() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let settings_path = get_claude_settings_path();
+    if let Some(parent) = settings_path.parent() {
+        std::fs::create_dir_all(parent).expect("create claude settings dir");
+    }
+    std::fs::write(&settings_path, "{}").expect("seed claude live config");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "plain".to_string();
+        manager.providers.insert(
+            "plain".to_string(),
+            Provider::with_id(
+                "plain".to_string(),
+                "Plain".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "plain-key" } }),
+                None,
+            ),
+        );
+        manager.providers.insert(
+            "from-env".to_string(),
+            Provider::with_id(
+                "from-env".to_string(),
+                "From Env".to_string(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "${env:CC_SWITCH_IT_RELAY_KEY}" } }),
+                None,
+            ),
+        );
+    }
+    let app_state = state_from_config(config);
+
+    std::env::remove_var("CC_SWITCH_IT_RELAY_KEY");
+    let err = ProviderService::switch(&app_state, AppType::Claude, "from-env")
+        .expect_err("switch should fail while the variable is unset");
+    assert!(
+        err.to_string().contains("CC_SWITCH_IT_RELAY_KEY"),
+        "error should name the missing variable: {err}"
+    );
+
+    std::env::set_var("CC_SWITCH_IT_RELAY_KEY", "sk-secret-from-env");
+    ProviderService::switch(&app_state, AppType::Claude, "from-env").expect("switch");
+    let live: serde_json::Value = read_json_file(&settings_path).expect("read live");
+    assert_eq!(live["env"]["ANTHROPIC_AUTH_TOKEN"], "sk-secret-from-env");
+
+    ProviderService::switch(&app_state, AppType::Claude, "plain").expect("switch back");
+    std::env::remove_var("CC_SWITCH_IT_RELAY_KEY");
+
+    let config = app_state.config.read().expect("read config");
+    let stored = &config
+        .get_manager(&AppType::Claude)
+        .expect("claude manager")
+        .providers["from-env"];
+    assert_eq!(
+        stored.settings_config["env"]["ANTHROPIC_AUTH_TOKEN"], "${env:CC_SWITCH_IT_RELAY_KEY}",
+        "stored config must keep the placeholder instead of the secret"
+    );
+}
+
+#[test]
+fn provider_service_validate_all_reports_problems_per_provider() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Codex)
+            .expect("codex manager");
+        manager.providers.insert(
+            "good".to_string(),
+            Provider::with_id(
+                "good".to_string(),
+                "Good".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-good" },
+                    "config": "model_provider = \"relay\"\n\n[model_providers.relay]\nbase_url = \"https://relay.example/v1\"\n"
+                }),
+                None,
+            ),
+        );
+        manager.providers.insert(
+            "broken".to_string(),
+            Provider::with_id(
+                "broken".to_string(),
+                "Broken".to_string(),
+                json!({
+                    "auth": { "OPENAI_API_KEY": "sk-broken" },
+                    "config": "model = [unterminated"
+                }),
+                None,
+            ),
+        );
+    }
+
+    let app_state = state_from_config(config);
+    let results =
+        ProviderService::validate_all(&app_state, AppType::Codex).expect("validate providers");
+    assert_eq!(results.len(), 2);
+
+    let good = results
+        .iter()
+        .find(|r| r.id == "good")
+        .expect("good result");
+    assert!(good.is_ok(), "unexpected problems: {:?}", good.problems);
+
+    let broken = results
+        .iter()
+        .find(|r| r.id == "broken")
+        .expect("broken result");
+    assert!(!broken.is_ok());
+    assert!(broken
+        .problems
+        .iter()
+        .any(|problem| problem.contains("Base URL is missing")));
+}
+
+#[test]
+fn provider_service_failover_queue_persists_and_lists_candidates_in_order() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, sort_index, url) in [
+            ("main", Some(0), Some("https://main.example")),
+            ("relay-b", Some(2), Some("https://b.example")),
+            ("relay-a", Some(1), None),
+        ] {
+            let mut env = serde_json::Map::new();
+            env.insert("ANTHROPIC_AUTH_TOKEN".to_string(), json!("key"));
+            if let Some(url) = url {
+                env.insert("ANTHROPIC_BASE_URL".to_string(), json!(url));
+            }
+            let mut provider = Provider::with_id(
+                id.to_string(),
+                id.to_uppercase(),
+                json!({ "env": env }),
+                None,
+            );
+            provider.sort_index = sort_index;
+            manager.providers.insert(id.to_string(), provider);
+        }
+        manager.current = "main".to_string();
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist providers");
+
+    assert!(
+        ProviderService::failover_candidates(&state, AppType::Claude)
+            .expect("empty queue")
+            .is_empty()
+    );
+
+    ProviderService::set_failover_queued(&state, AppType::Claude, "relay-b", true)
+        .expect("queue relay-b");
+    ProviderService::set_failover_queued(&state, AppType::Claude, "relay-a", true)
+        .expect("queue relay-a");
+    let err = ProviderService::set_failover_queued(&state, AppType::Claude, "missing", true)
+        .expect_err("unknown provider");
+    assert!(matches!(err, AppError::Localized { key, .. } if key == "provider.not_found"));
+
+    let candidates =
+        ProviderService::failover_candidates(&state, AppType::Claude).expect("candidates");
+    let ids: Vec<_> = candidates.iter().map(|c| c.provider_id.as_str()).collect();
+    assert_eq!(ids, ["relay-a", "relay-b"]);
+    assert_eq!(candidates[0].url, None);
+    assert_eq!(candidates[1].url.as_deref(), Some("https://b.example"));
+
+    let mut queued: Vec<_> = state
+        .db
+        .get_failover_providers(AppType::Claude.as_str())
+        .expect("read queue")
+        .into_iter()
+        .map(|p| p.id)
+        .collect();
+    queued.sort();
+    assert_eq!(queued, ["relay-a", "relay-b"]);
+
+    ProviderService::set_failover_queued(&state, AppType::Claude, "relay-a", false)
+        .expect("dequeue relay-a");
+    let ids: Vec<_> = ProviderService::failover_candidates(&state, AppType::Claude)
+        .expect("candidates")
+        .into_iter()
+        .map(|c| c.provider_id)
+        .collect();
+    assert_eq!(ids, ["relay-b"]);
+}
+
+#[test]
+fn provider_service_failover_skips_candidates_with_unresolvable_placeholders() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind test server");
+    let healthy_url = format!("http://{}", listener.local_addr().expect("server addr"));
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut stream = stream;
+            let mut buf = [0u8; 1024];
+            let _ = std::io::Read::read(&mut stream, &mut buf);
+            let _ = std::io::Write::write_all(
+                &mut stream,
+                b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            );
+        }
+    });
+
+    std::env::remove_var("CC_SWITCH_TEST_FAILOVER_UNSET");
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, url) in [
+            ("broken", "${env:CC_SWITCH_TEST_FAILOVER_UNSET}"),
+            ("healthy", healthy_url.as_str()),
+        ] {
+            let provider = Provider::with_id(
+                id.to_string(),
+                id.to_uppercase(),
+                json!({ "env": { "ANTHROPIC_AUTH_TOKEN": "key", "ANTHROPIC_BASE_URL": url } }),
+                None,
+            );
+            manager.providers.insert(id.to_string(), provider);
+        }
+        manager.current = "broken".to_string();
+    }
+    let state = state_from_config(config);
+    state.save().expect("persist providers");
+    for id in ["broken", "healthy"] {
+        ProviderService::set_failover_queued(&state, AppType::Claude, id, true)
+            .expect("queue provider");
+    }
+
+    let candidates = ProviderService::failover_candidates(&state, AppType::Claude)
+        .expect("an unresolvable candidate must not abort failover");
+    let broken = &candidates[0];
+    assert_eq!(broken.provider_id, "broken");
+    assert_eq!(broken.url, None);
+    assert!(
+        broken
+            .error
+            .as_deref()
+            .is_some_and(|err| err.contains("CC_SWITCH_TEST_FAILOVER_UNSET")),
+        "unexpected error: {:?}",
+        broken.error
+    );
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let ranked = runtime
+        .block_on(ProviderService::speedtest_failover_candidates(
+            candidates,
+            SpeedtestOptions::default(),
+        ))
+        .expect("speedtest");
+    assert_eq!(ranked[0].provider_id, "healthy");
+    assert!(ranked[0].is_healthy());
+    assert!(!ranked[1].is_healthy());
+}
+
+#[test]
+fn provider_service_set_meta_fields_persists_and_removes_entries() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.providers.insert(
+            "p1".to_string(),
+            Provider::with_id(
+                "p1".to_string(),
+                "P1".to_string(),
+                json!({ "env": { "ANTHROPIC_API_KEY": "k" } }),
+                None,
+            ),
+        );
+    }
+    let app_state = state_from_config(config);
+
+    let fields = ProviderService::set_meta_fields(
+        &app_state,
+        AppType::Claude,
+        "p1",
+        vec![
+            ("owner".to_string(), Some("team-a".to_string())),
+            ("expiry".to_string(), Some("2026-12-31".to_string())),
+        ],
+    )
+    .expect("set meta fields");
+    assert_eq!(fields.len(), 2);
+
+    let fields = ProviderService::set_meta_fields(
+        &app_state,
+        AppType::Claude,
+        "p1",
+        vec![("expiry".to_string(), None)],
+    )
+    .expect("remove meta field");
+    assert_eq!(fields.get("owner").map(String::as_str), Some("team-a"));
+    assert!(!fields.contains_key("expiry"));
+
+    let stored = app_state
+        .db
+        .get_all_providers(AppType::Claude.as_str())
+        .expect("read providers");
+    let meta = stored["p1"].meta.as_ref().expect("meta persisted");
+    assert_eq!(meta.fields, fields);
+
+    let err = ProviderService::set_meta_fields(&app_state, AppType::Claude, "missing", Vec::new())
+        .expect_err("unknown provider should fail");
+    assert!(err.to_string().contains("missing"));
+
+    // expires_at 写入密钥到期日，而不是自定义字段
+    let fields = ProviderService::set_meta_fields(
+        &app_state,
+        AppType::Claude,
+        "p1",
+        vec![("expires_at".to_string(), Some(" 2026-12-31 ".to_string()))],
+    )
+    .expect("set expiry via meta");
+    assert!(!fields.contains_key("expires_at"));
+    let stored = app_state
+        .db
+        .get_all_providers(AppType::Claude.as_str())
+        .expect("read providers");
+    assert_eq!(
+        stored["p1"]
+            .meta
+            .as_ref()
+            .and_then(|m| m.expires_at.as_deref()),
+        Some("2026-12-31")
+    );
+    assert!(ProviderService::set_meta_fields(
+        &app_state,
+        AppType::Claude,
+        "p1",
+        vec![("expires_at".to_string(), Some("soon".to_string()))],
+    )
+    .is_err());
+}
+
+#[test]
+fn provider_service_set_expiry_reports_expiring_and_expired_keys() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for id in ["soon", "later", "gone"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_uppercase(),
+                    json!({ "env": {} }),
+                    None,
+                ),
+            );
+        }
+    }
+    let app_state = state_from_config(config);
+
+    let today = chrono::Local::now().date_naive();
+    for (id, offset) in [("soon", 3), ("later", 30), ("gone", -1)] {
+        ProviderService::set_expiry(
+            &app_state,
+            AppType::Claude,
+            id,
+            Some(today + chrono::Duration::days(offset)),
+        )
+        .expect("set expiry");
+    }
+
+    let warnings =
+        ProviderService::expiry_warnings(&app_state, AppType::Claude).expect("expiry warnings");
+    let summary: Vec<_> = warnings.iter().map(|w| (w.id.as_str(), w.status)).collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("gone", ExpiryStatus::Expired { days_ago: 1 }),
+            ("soon", ExpiryStatus::ExpiringSoon { days_left: 3 }),
+        ]
+    );
+
+    ProviderService::set_expiry(&app_state, AppType::Claude, "gone", None).expect("clear expiry");
+    let stored = app_state
+        .db
+        .get_all_providers(AppType::Claude.as_str())
+        .expect("read providers");
+    assert!(stored["gone"]
+        .meta
+        .as_ref()
+        .is_some_and(|meta| meta.expires_at.is_none()));
+    assert_eq!(
+        stored["later"]
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.expires_at.clone()),
+        Some(
+            (today + chrono::Duration::days(30))
+                .format("%Y-%m-%d")
+                .to_string()
+        )
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn provider_service_switch_runs_on_switch_hook_and_ignores_hook_failures() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let marker = home.join("on-switch.txt");
+    let _ = std::fs::remove_file(&marker);
+    update_settings(AppSettings {
+        on_switch_command: Some(format!(
+            "printf '%s|%s|%s' \"$CC_SWITCH_APP\" \"$CC_SWITCH_PROVIDER_ID\" \"$CC_SWITCH_PROVIDER_NAME\" > '{}'",
+            marker.display()
+        )),
+        ..AppSettings::default()
+    })
+    .expect("configure hook");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for (id, name) in [("a", "Provider A"), ("b", "Provider B")] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    name.to_string(),
+                    json!({ "env": { "ANTHROPIC_API_KEY": format!("key-{id}") } }),
+                    None,
+                ),
+            );
+        }
+        manager.current = "a".to_string();
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Claude, "b").expect("switch with hook");
+    assert_eq!(
+        std::fs::read_to_string(&marker).expect("hook output"),
+        "claude|b|Provider B"
+    );
+
+    update_settings(AppSettings {
+        on_switch_command: Some("exit 3".to_string()),
+        ..AppSettings::default()
+    })
+    .expect("configure failing hook");
+    ProviderService::switch(&state, AppType::Claude, "a").expect("failing hook is not fatal");
+    assert_eq!(
+        state
+            .config
+            .read()
+            .expect("config")
+            .get_manager(&AppType::Claude)
+            .expect("manager")
+            .current,
+        "a"
+    );
+
+    update_settings(AppSettings {
+        on_switch_command: Some("sleep 60".to_string()),
+        ..AppSettings::default()
+    })
+    .expect("configure hanging hook");
+    let started = std::time::Instant::now();
+    ProviderService::switch(&state, AppType::Claude, "b").expect("hanging hook is not fatal");
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(30),
+        "hanging hook should be killed after its timeout"
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn provider_service_switch_hook_timeout_kills_background_children() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let pid_file = home.join("hook-child.pid");
+    update_settings(AppSettings {
+        on_switch_command: Some(format!(
+            "sleep 60 & echo $! > '{}'; wait",
+            pid_file.display()
+        )),
+        ..AppSettings::default()
+    })
+    .expect("configure hook with background child");
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_API_KEY": format!("key-{id}") } }),
+                    None,
+                ),
+            );
+        }
+        manager.current = "a".to_string();
+    }
+    let state = state_from_config(config);
+
+    ProviderService::switch(&state, AppType::Claude, "b").expect("hanging hook is not fatal");
+    let pid = std::fs::read_to_string(&pid_file)
+        .expect("hook wrote child pid")
+        .trim()
+        .to_string();
+
+    // 子进程被终止后可能短暂处于僵尸状态，只要不再运行即可
+    let running = || {
+        std::fs::read_to_string(format!("/proc/{pid}/stat"))
+            .ok()
+            .and_then(|stat| {
+                stat.rsplit_once(") ")
+                    .map(|(_, rest)| !rest.starts_with('Z'))
+            })
+            .unwrap_or(false)
+    };
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(2);
+    while running() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(20));
+    }
+    assert!(
+        !running(),
+        "background child {pid} should be killed with the hook"
+    );
+}
+
+#[test]
+fn provider_service_switch_syncs_skills_only_when_enabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        for id in ["a", "b"] {
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(
+                    id.to_string(),
+                    id.to_string(),
+                    json!({ "env": { "ANTHROPIC_API_KEY": format!("key-{id}") } }),
+                    None,
+                ),
+            );
+        }
+        manager.current = "a".to_string();
+    }
+    let state = state_from_config(config);
+
+    update_settings(AppSettings::default()).expect("reset settings");
+    let outcome = ProviderService::switch(&state, AppType::Claude, "b").expect("switch");
+    assert!(outcome.skills_sync.is_none());
+
+    update_settings(AppSettings {
+        sync_skills_on_switch: true,
+        ..AppSettings::default()
+    })
+    .expect("enable skills sync on switch");
+    let outcome = ProviderService::switch(&state, AppType::Claude, "a").expect("switch back");
+    assert!(matches!(outcome.skills_sync, Some(Ok(_))));
+
+    update_settings(AppSettings::default()).expect("reset settings");
+}
+
+#[test]
+fn provider_service_switch_rotates_pooled_api_keys() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let _home = ensure_test_home();
+    if let Some(parent) = get_claude_settings_path().parent() {
+        std::fs::create_dir_all(parent).expect("create claude settings dir");
+    }
+
+    let mut config = MultiAppConfig::default();
+    config
+        .get_manager_mut(&AppType::Claude)
+        .expect("claude manager")
+        .providers
+        .insert(
+            "pooled".to_string(),
+            Provider::with_id(
+                "pooled".to_string(),
+                "Pooled".to_string(),
+                json!({
+                    "env": {
+                        "ANTHROPIC_BASE_URL": "https://relay.example",
+                        "ANTHROPIC_AUTH_TOKEN": "key-a"
+                    }
+                }),
+                None,
+            ),
+        );
+    let state = state_from_config(config);
+
+    let pool = ProviderService::add_api_keys(
+        &state,
+        AppType::Claude,
+        "pooled",
+        vec![
+            "key-b".to_string(),
+            "key-c".to_string(),
+            " key-b ".to_string(),
+        ],
+    )
+    .expect("add keys");
+    assert_eq!(
+        pool.keys,
+        ["key-a", "key-b", "key-c"],
+        "the existing single key joins the pool and duplicates are ignored"
+    );
+
+    let live_key = || {
+        let live: serde_json::Value =
+            read_json_file(&get_claude_settings_path()).expect("read claude live settings");
+        live["env"]["ANTHROPIC_AUTH_TOKEN"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string()
+    };
+    for expected in ["key-a", "key-b", "key-c", "key-a"] {
+        ProviderService::switch(&state, AppType::Claude, "pooled").expect("switch");
+        assert_eq!(live_key(), expected);
+    }
+
+    ProviderService::resync_live(&state, AppType::Claude, "pooled").expect("resync");
+    assert_eq!(live_key(), "key-a", "re-syncing keeps the active key");
+    assert_eq!(
+        ProviderService::api_key_pool(&state, AppType::Claude, "pooled")
+            .expect("pool")
+            .next_index,
+        1
+    );
+
+    let pool = ProviderService::remove_api_key(&state, AppType::Claude, "pooled", 2)
+        .expect("remove key-b");
+    assert_eq!(pool.keys, ["key-a", "key-c"]);
+    assert_eq!(
+        pool.next_index, 1,
+        "rotation continues with the following key"
+    );
+    assert!(ProviderService::remove_api_key(&state, AppType::Claude, "pooled", 3).is_err());
+
+    ProviderService::switch(&state, AppType::Claude, "pooled").expect("switch");
+    assert_eq!(live_key(), "key-c");
+}
+
+#[test]
+fn provider_service_prune_archives_providers_but_keeps_current() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let mut config = MultiAppConfig::default();
+    {
+        let manager = config
+            .get_manager_mut(&AppType::Claude)
+            .expect("claude manager");
+        manager.current = "current".to_string();
+        for id in ["current", "dead-relay", "oauth"] {
+            let env = if id == "oauth" {
+                json!({})
+            } else {
+                json!({ "ANTHROPIC_BASE_URL": format!("https://{id}.example.com") })
+            };
+            manager.providers.insert(
+                id.to_string(),
+                Provider::with_id(id.to_string(), id.to_string(), json!({ "env": env }), None),
+            );
+        }
+    }
+    let app_state = state_from_config(config);
+
+    let candidates =
+        ProviderService::prune_candidates(&app_state, AppType::Claude).expect("candidates");
+    let ids: Vec<_> = candidates.iter().map(|c| c.provider_id.as_str()).collect();
+    assert_eq!(
+        ids,
+        ["dead-relay", "oauth"],
+        "current provider is never tested"
+    );
+    assert!(candidates[1].url.is_none());
+
+    let archive = ProviderService::prune_providers(
+        &app_state,
+        AppType::Claude,
+        &["dead-relay".to_string(), "current".to_string()],
+        true,
+    )
+    .expect("prune")
+    .expect("archive path");
+    assert!(archive.starts_with(home.join(".cc-switch").join("backups").join("providers")));
+    let archived: serde_json::Value = read_json_file(&archive).expect("read archive");
+    assert_eq!(archived["providers"][0]["id"], "dead-relay");
+    assert_eq!(archived["providers"].as_array().map(Vec::len), Some(1));
+
+    let providers = ProviderService::list(&app_state, AppType::Claude).expect("list");
+    assert!(!providers.contains_key("dead-relay"));
+    assert!(
+        providers.contains_key("current"),
+        "current provider is kept"
+    );
+    assert!(providers.contains_key("oauth"));
+
+    // 同一秒内再次归档不会覆盖之前的归档文件
+    let second =
+        ProviderService::prune_providers(&app_state, AppType::Claude, &["oauth".to_string()], true)
+            .expect("prune again")
+            .expect("archive path");
+    assert_ne!(second, archive);
+    let archived: serde_json::Value = read_json_file(&archive).expect("read first archive");
+    assert_eq!(archived["providers"][0]["id"], "dead-relay");
+}
@@ -0,0 +1,126 @@
+use cc_switch_lib::cli::Cli;
+use cc_switch_lib::{ensure_writable, is_read_only, set_read_only_override, AppError};
+use clap::Parser;
+
+// 只用到测试互斥锁，其余辅助函数在此不需要
+#[allow(dead_code)]
+#[path = "support.rs"]
+mod support;
+use support::lock_test_mutex;
+
+fn is_mutation(args: &[&str]) -> bool {
+    let cli = Cli::try_parse_from(std::iter::once("cc-switch").chain(args.iter().copied()))
+        .unwrap_or_else(|e| panic!("parse {args:?}: {e}"));
+    cli.command
+        .as_ref()
+        .unwrap_or_else(|| panic!("no command for {args:?}"))
+        .is_mutation()
+}
+
+#[test]
+fn read_only_classification_allows_views_and_blocks_changes() {
+    for args in [
+        &["provider", "list"][..],
+        &["provider", "current"],
+        &["provider", "current", "--porcelain"],
+        &["provider", "failover", "--dry-run"],
+        &["provider", "live-method"],
+        &["provider", "health"],
+        &["provider", "share", "p1"],
+        &["mcp", "list"],
+        &["prompts", "show", "p1"],
+        &["skills", "install", "owner/repo", "--dry-run"],
+        &["skills", "repos", "list"],
+        &["skills", "doctor"],
+        &["config", "show"],
+        &["config", "current", "--env"],
+        &["config", "validate", "--json"],
+        &["config", "validate", "--live"],
+        &["config", "offline"],
+        &["config", "on-switch"],
+        &["config", "skills-on-switch"],
+        &["config", "quiet-skill-fallback"],
+        &["config", "provider-defaults"],
+        &["provider", "keys", "list", "demo"],
+        &["config", "common", "show"],
+        &["config", "common", "apply", "--dry-run"],
+        &["env", "check"],
+        &["audit", "tail"],
+        &["stats"],
+        &["stats", "--json"],
+        &["env", "check-compat"],
+        &["profile", "list"],
+        &["speedtest", "--all"],
+        &[
+            "speedtest",
+            "--url",
+            "https://example.com",
+            "--file",
+            "urls.txt",
+        ],
+    ] {
+        assert!(!is_mutation(args), "{args:?} should be allowed");
+    }
+
+    for args in [
+        &["provider", "switch", "p1"][..],
+        &["provider", "delete", "p1"],
+        &["provider", "failover"],
+        &["provider", "prune", "--unreachable", "--yes"],
+        &["provider", "live-method", "symlink"],
+        &["mcp", "sync"],
+        &["prompts", "activate", "p1"],
+        &["skills", "install", "owner/repo"],
+        &["skills", "enable", "demo", "--all"],
+        &["skills", "repos", "remove", "owner/repo"],
+        &["skills", "doctor", "--orphans", "delete"],
+        &["skills", "import-from-apps", "demo", "--yes"],
+        &["skills", "import", "skills.tar.gz"],
+        &["skills", "import-from-apps", "--undo"],
+        &["config", "import", "dump.sql"],
+        &["config", "offline", "on"],
+        &["config", "reset", "--yes"],
+        &["config", "on-switch", "--clear"],
+        &["config", "skills-on-switch", "on"],
+        &["config", "quiet-skill-fallback", "on"],
+        &["config", "provider-defaults", "--reset"],
+        &["provider", "keys", "add", "demo", "sk-extra"],
+        &["provider", "keys", "remove", "demo", "1"],
+        &["config", "common", "clear"],
+        &["config", "common", "apply"],
+        &["profile", "use", "work"],
+        &["update"],
+    ] {
+        assert!(is_mutation(args), "{args:?} should be refused");
+    }
+}
+
+#[test]
+fn ensure_writable_fails_only_when_read_only_is_set() {
+    let _guard = lock_test_mutex();
+    assert!(!is_read_only());
+    ensure_writable().expect("writable by default");
+
+    let cli = Cli::try_parse_from(["cc-switch", "--read-only", "provider", "list"])
+        .expect("parse --read-only");
+    assert!(cli.read_only);
+
+    set_read_only_override(true);
+    match ensure_writable() {
+        Err(AppError::Localized { key, .. }) => assert_eq!(key, "read_only.blocked"),
+        other => panic!("expected read-only error, got {other:?}"),
+    }
+    set_read_only_override(false);
+}
+
+#[test]
+fn read_only_env_accepts_numeric_and_falsey_values() {
+    let _guard = lock_test_mutex();
+    for (value, expected) in [("1", true), ("true", true), ("0", false), ("", false)] {
+        std::env::set_var("CC_SWITCH_READ_ONLY", value);
+        let cli = Cli::try_parse_from(["cc-switch", "provider", "list"])
+            .unwrap_or_else(|e| panic!("parse CC_SWITCH_READ_ONLY={value:?}: {e}"));
+        assert_eq!(cli.read_only, expected, "CC_SWITCH_READ_ONLY={value:?}");
+    }
+    std::env::remove_var("CC_SWITCH_READ_ONLY");
+}
@@ -0,0 +1,20 @@
+use cc_switch_lib::cli::Cli;
+use clap::Parser;
+
+// 只用到测试互斥锁，其余辅助函数在此不需要
+#[allow(dead_code)]
+#[path = "support.rs"]
+mod support;
+use support::lock_test_mutex;
+
+#[test]
+fn compact_env_accepts_numeric_and_falsey_values() {
+    let _guard = lock_test_mutex();
+    for (value, expected) in [("1", true), ("yes", true), ("0", false), ("", false)] {
+        std::env::set_var("CC_SWITCH_COMPACT", value);
+        let cli = Cli::try_parse_from(["cc-switch", "provider", "list"])
+            .unwrap_or_else(|e| panic!("parse CC_SWITCH_COMPACT={value:?}: {e}"));
+        assert_eq!(cli.compact, expected, "CC_SWITCH_COMPACT={value:?}");
+    }
+    std::env::remove_var("CC_SWITCH_COMPACT");
+}
@@ -0,0 +1,98 @@
+use std::fs;
+
+use cc_switch_lib::{
+    get_offline_mode, is_offline_mode, set_offline_mode, set_speedtest_defaults, AppError,
+    SkillService, SpeedtestOptions, SpeedtestService,
+};
+
+#[path = "support.rs"]
+mod support;
+use support::{ensure_test_home, lock_test_mutex, reset_test_fs};
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("create runtime")
+}
+
+fn assert_offline_error(err: AppError) {
+    match err {
+        AppError::Localized { key, .. } => assert_eq!(key, "offline_mode"),
+        other => panic!("expected offline mode error, got {other:?}"),
+    }
+}
+
+#[test]
+fn offline_mode_persists_and_short_circuits_network_features() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    assert!(
+        !is_offline_mode(),
+        "offline mode should default to disabled"
+    );
+
+    set_offline_mode(true).expect("enable offline mode");
+    assert!(get_offline_mode());
+    let settings_json =
+        fs::read_to_string(home.join(".cc-switch").join("settings.json")).expect("read settings");
+    assert!(
+        settings_json.contains("\"offlineMode\": true"),
+        "offline mode should be persisted: {settings_json}"
+    );
+
+    let rt = runtime();
+    let err = rt
+        .block_on(SpeedtestService::test_endpoints(
+            vec!["http://127.0.0.1:9".to_string()],
+            SpeedtestOptions::default(),
+        ))
+        .expect_err("speedtest should be refused while offline");
+    assert_offline_error(err);
+
+    let service = SkillService::new().expect("create skill service");
+    let err = rt
+        .block_on(service.discover_available(Vec::new()))
+        .expect_err("skill discovery should be refused while offline");
+    assert_offline_error(err);
+
+    set_offline_mode(false).expect("disable offline mode");
+    assert!(!is_offline_mode());
+    let skills = rt
+        .block_on(service.discover_available(Vec::new()))
+        .expect("discovery without enabled repos succeeds when online");
+    assert!(skills.is_empty());
+}
+
+#[test]
+fn speedtest_defaults_persist_and_yield_to_explicit_options() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let defaults = SpeedtestOptions::default();
+    assert_eq!(defaults.effective_timeout_secs(), 8);
+    assert_eq!(defaults.effective_user_agent(), "cc-switch-speedtest/1.0");
+
+    set_speedtest_defaults(Some("corp-proxy-agent/1.0".to_string()), Some(20))
+        .expect("persist speedtest defaults");
+    let settings_json =
+        fs::read_to_string(home.join(".cc-switch").join("settings.json")).expect("read settings");
+    assert!(settings_json.contains("\"speedtestUserAgent\": \"corp-proxy-agent/1.0\""));
+    assert!(settings_json.contains("\"speedtestTimeoutSecs\": 20"));
+
+    let persisted = SpeedtestOptions::default();
+    assert_eq!(persisted.effective_timeout_secs(), 20);
+    assert_eq!(persisted.effective_user_agent(), "corp-proxy-agent/1.0");
+
+    let explicit = SpeedtestOptions::default()
+        .with_timeout(Some(4))
+        .with_user_agent(Some("cli-agent".to_string()));
+    assert_eq!(explicit.effective_timeout_secs(), 4);
+    assert_eq!(explicit.effective_user_agent(), "cli-agent");
+
+    set_speedtest_defaults(None, None).expect("reset speedtest defaults");
+    assert_eq!(SpeedtestOptions::default().effective_timeout_secs(), 8);
+}
@@ -1,4 +1,7 @@
-use cc_switch_lib::{Database, SkillService};
+use cc_switch_lib::{
+    AppError, AppType, Database, DiagnosticReport, DiagnosticStatus, SkillRepo, SkillService,
+    SkillSyncStatus, SyncMethod,
+};
 
 #[path = "support.rs"]
 mod support;
@@ -130,3 +133,583 @@ fn pending_migration_with_existing_managed_list_does_not_claim_unmanaged_skills(
         "unmanaged skill should remain unmanaged (not added to db)"
     );
 }
+
+#[cfg(unix)]
+#[test]
+fn failed_ssot_migration_keeps_pending_flag_and_retries_cleanly() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let app_skills = home.join(".claude").join("skills");
+    write_skill_md(&app_skills.join("good-skill"), "Good", "copies fine");
+    let bad_skill = app_skills.join("bad-skill");
+    write_skill_md(&bad_skill, "Bad", "has a dangling symlink");
+    let broken_link = bad_skill.join("broken");
+    std::os::unix::fs::symlink(home.join("does-not-exist"), &broken_link)
+        .expect("create dangling symlink");
+
+    let db = Database::init().expect("init db");
+    db.set_setting("skills_ssot_migration_pending", "true")
+        .expect("set migration pending flag");
+
+    SkillService::list_installed().expect_err("copy failure should surface");
+
+    let ssot_dir = home.join(".cc-switch").join("skills");
+    assert!(
+        !ssot_dir.join("bad-skill").exists(),
+        "a failed copy must not leave a partial SSOT directory behind"
+    );
+    assert!(!ssot_dir.join(".bad-skill.migrating").exists());
+
+    let db = Database::init().expect("init db");
+    assert_eq!(
+        db.get_setting("skills_ssot_migration_pending")
+            .expect("read pending flag")
+            .as_deref(),
+        Some("true"),
+        "pending flag should stay set after a failed migration"
+    );
+    assert!(SkillService::ssot_migration_error()
+        .expect("read migration error")
+        .is_some());
+    assert!(db
+        .get_all_installed_skills()
+        .expect("get installed skills")
+        .is_empty());
+
+    std::fs::remove_file(&broken_link).expect("fix broken skill");
+    let installed = SkillService::list_installed().expect("retry migration");
+    let mut dirs: Vec<_> = installed.iter().map(|s| s.directory.as_str()).collect();
+    dirs.sort();
+    assert_eq!(dirs, vec!["bad-skill", "good-skill"]);
+    assert!(ssot_dir.join("bad-skill").join("SKILL.md").exists());
+
+    let db = Database::init().expect("init db");
+    assert_eq!(
+        db.get_setting("skills_ssot_migration_pending")
+            .expect("read pending flag")
+            .as_deref(),
+        Some("false")
+    );
+    assert!(SkillService::ssot_migration_error()
+        .expect("read migration error")
+        .is_none());
+}
+
+#[test]
+fn sync_all_enabled_reports_per_app_results() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skill_dir = home.join(".claude").join("skills").join("report-skill");
+    write_skill_md(&claude_skill_dir, "Report Skill", "A test skill");
+
+    let db = Database::init().expect("init db");
+    db.set_setting("skills_ssot_migration_pending", "true")
+        .expect("set migration pending flag");
+
+    let report = SkillService::sync_all_enabled(None).expect("sync all");
+    assert_eq!(report.entries.len(), 3, "one entry per app for the skill");
+    assert_eq!(report.synced_count(), 1);
+    assert_eq!(report.skipped_count(), 2);
+    assert_eq!(report.failed_count(), 0);
+
+    let claude = report
+        .entries
+        .iter()
+        .find(|e| e.app == AppType::Claude)
+        .expect("claude entry");
+    assert_eq!(claude.directory, "report-skill");
+    assert!(matches!(
+        claude.status,
+        SkillSyncStatus::Symlinked | SkillSyncStatus::Copied
+    ));
+
+    let codex = report
+        .entries
+        .iter()
+        .find(|e| e.app == AppType::Codex)
+        .expect("codex entry");
+    assert_eq!(codex.status, SkillSyncStatus::Skipped);
+}
+
+#[test]
+fn toggle_all_apps_syncs_and_removes_every_app_at_once() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skill_dir = home.join(".claude").join("skills").join("shared-skill");
+    write_skill_md(&claude_skill_dir, "Shared Skill", "Used everywhere");
+    SkillService::import_from_apps(vec!["shared-skill".to_string()]).expect("import skill");
+
+    let report = SkillService::toggle_all_apps("shared-skill", true).expect("enable all");
+    assert_eq!(report.entries.len(), 3);
+    assert_eq!(report.synced_count(), 3);
+    assert!(home.join(".codex/skills/shared-skill/SKILL.md").exists());
+    assert!(home.join(".gemini/skills/shared-skill/SKILL.md").exists());
+    let apps = &SkillService::list_installed().expect("list")[0].apps;
+    assert!(apps.claude && apps.codex && apps.gemini);
+
+    let report = SkillService::toggle_all_apps("shared-skill", false).expect("disable all");
+    assert!(report
+        .entries
+        .iter()
+        .all(|e| e.status == SkillSyncStatus::Removed));
+    assert!(!home.join(".codex/skills/shared-skill").exists());
+    assert!(SkillService::list_installed().expect("list")[0]
+        .apps
+        .is_empty());
+}
+
+#[test]
+fn toggle_all_apps_keeps_failed_app_disabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skill_dir = home.join(".claude").join("skills").join("shared-skill");
+    write_skill_md(&claude_skill_dir, "Shared Skill", "Used everywhere");
+    SkillService::import_from_apps(vec!["shared-skill".to_string()]).expect("import skill");
+
+    // Gemini 的 skills 目录被同名文件占用，同步必然失败
+    std::fs::create_dir_all(home.join(".gemini")).expect("create gemini dir");
+    std::fs::write(home.join(".gemini").join("skills"), "not a dir").expect("block skills dir");
+
+    let report = SkillService::toggle_all_apps("shared-skill", true).expect("enable all");
+    assert_eq!(report.synced_count(), 2);
+    assert!(report
+        .entries
+        .iter()
+        .any(|e| e.app == AppType::Gemini && matches!(e.status, SkillSyncStatus::Failed(_))));
+    let apps = &SkillService::list_installed().expect("list")[0].apps;
+    assert!(apps.claude && apps.codex);
+    assert!(!apps.gemini, "failed app must not be recorded as enabled");
+}
+
+#[test]
+fn sync_on_switch_runs_only_when_enabled() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    write_skill_md(
+        &home.join(".claude").join("skills").join("switch-skill"),
+        "Switch Skill",
+        "Synced on switch",
+    );
+    let db = Database::init().expect("init db");
+    db.set_setting("skills_ssot_migration_pending", "true")
+        .expect("set migration pending flag");
+
+    cc_switch_lib::update_settings(cc_switch_lib::AppSettings::default()).expect("reset settings");
+    assert!(SkillService::sync_on_switch(&AppType::Claude).is_none());
+
+    cc_switch_lib::update_settings(cc_switch_lib::AppSettings {
+        sync_skills_on_switch: true,
+        ..Default::default()
+    })
+    .expect("enable sync on switch");
+    let report = SkillService::sync_on_switch(&AppType::Claude)
+        .expect("enabled")
+        .expect("sync succeeds");
+    assert_eq!(report.synced_count(), 1);
+    assert!(report.entries.iter().all(|e| e.app == AppType::Claude));
+
+    cc_switch_lib::update_settings(cc_switch_lib::AppSettings::default()).expect("reset settings");
+}
+
+#[test]
+fn sync_continues_past_failing_skill_and_aggregates_errors() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skills = home.join(".claude").join("skills");
+    write_skill_md(
+        &claude_skills.join("a-broken"),
+        "Broken",
+        "Missing from SSOT",
+    );
+    write_skill_md(&claude_skills.join("b-healthy"), "Healthy", "Syncs fine");
+
+    let db = Database::init().expect("init db");
+    db.set_setting("skills_ssot_migration_pending", "true")
+        .expect("set migration pending flag");
+    SkillService::list_installed().expect("migrate skills into SSOT");
+
+    // 删除 SSOT 中的目录，模拟单个 Skill 无法同步
+    std::fs::remove_dir_all(home.join(".cc-switch").join("skills").join("a-broken"))
+        .expect("remove ssot dir");
+    std::fs::remove_dir_all(claude_skills.join("b-healthy")).expect("remove app copy");
+
+    let report = SkillService::sync_all_enabled(Some(&AppType::Claude)).expect("sync claude");
+    assert_eq!(report.failed_count(), 1);
+    assert_eq!(report.synced_count(), 1);
+    assert!(
+        claude_skills.join("b-healthy").join("SKILL.md").exists(),
+        "healthy skill should still be synced after an earlier failure"
+    );
+
+    match report.ensure_no_failures() {
+        Err(AppError::Localized { key, en, .. }) => {
+            assert_eq!(key, "skills.sync.partial_failure");
+            assert!(en.contains("claude/a-broken"), "unexpected message: {en}");
+        }
+        other => panic!("expected aggregated failure, got {other:?}"),
+    }
+}
+
+#[test]
+fn repo_download_branches_try_repo_then_global_fallbacks_before_defaults() {
+    let (branch, fallback_branches) = SkillRepo::parse_branch_list(Some("develop, trunk ,,main"));
+    assert_eq!(branch, "develop");
+    assert_eq!(
+        fallback_branches,
+        vec!["trunk".to_string(), "main".to_string()]
+    );
+    assert_eq!(SkillRepo::parse_branch_list(None).0, "main");
+
+    let repo = SkillRepo {
+        owner: "acme".to_string(),
+        name: "skills".to_string(),
+        branch,
+        enabled: true,
+        fallback_branches,
+    };
+    let global = vec!["release".to_string(), "trunk".to_string()];
+    assert_eq!(
+        repo.download_branches(&global),
+        vec!["develop", "trunk", "main", "release", "master"]
+    );
+}
+
+#[test]
+fn skills_bundle_round_trips_and_honours_conflict_choice() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skills = home.join(".claude").join("skills");
+    write_skill_md(
+        &claude_skills.join("portable-skill"),
+        "Portable",
+        "Travels well",
+    );
+    SkillService::import_from_apps(vec!["portable-skill".to_string()])
+        .expect("import portable-skill from apps");
+
+    let bundle = home.join("skills-bundle.tar.gz");
+    assert_eq!(SkillService::export_bundle(&bundle).expect("export"), 1);
+
+    // 模拟新机器
+    reset_test_fs();
+    std::fs::create_dir_all(home.join(".claude")).expect("create claude dir");
+    let report = SkillService::import_bundle(&bundle, &mut |dir: &str| {
+        panic!("unexpected conflict for {dir}")
+    })
+    .expect("import bundle");
+    assert_eq!(report.imported, vec!["portable-skill".to_string()]);
+
+    let ssot_md = home
+        .join(".cc-switch")
+        .join("skills")
+        .join("portable-skill")
+        .join("SKILL.md");
+    assert!(ssot_md.exists(), "SSOT copy should be restored");
+    assert!(
+        claude_skills
+            .join("portable-skill")
+            .join("SKILL.md")
+            .exists(),
+        "enabled skill should be synced back to the app dir"
+    );
+    let installed = SkillService::list_installed().expect("list installed");
+    assert_eq!(installed.len(), 1);
+    assert!(installed[0].apps.claude);
+
+    std::fs::write(&ssot_md, "local edit").expect("edit local copy");
+    let report = SkillService::import_bundle(&bundle, &mut |_: &str| false).expect("import skip");
+    assert_eq!(report.skipped, vec!["portable-skill".to_string()]);
+    assert_eq!(
+        std::fs::read_to_string(&ssot_md).expect("read"),
+        "local edit"
+    );
+
+    let report =
+        SkillService::import_bundle(&bundle, &mut |_: &str| true).expect("import overwrite");
+    assert_eq!(report.imported, vec!["portable-skill".to_string()]);
+    assert!(std::fs::read_to_string(&ssot_md)
+        .expect("read")
+        .contains("Travels well"));
+}
+
+#[cfg(unix)]
+#[test]
+fn scan_unmanaged_skips_symlinks_into_ssot_unless_following() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot_skill_dir = home.join(".cc-switch").join("skills").join("linked-skill");
+    write_skill_md(&ssot_skill_dir, "Linked Skill", "Lives in SSOT");
+    let claude_skills = home.join(".claude").join("skills");
+    std::fs::create_dir_all(&claude_skills).expect("create claude skills dir");
+    std::os::unix::fs::symlink(&ssot_skill_dir, claude_skills.join("linked-skill"))
+        .expect("create symlink");
+    write_skill_md(
+        &claude_skills.join("local-skill"),
+        "Local Skill",
+        "Real dir",
+    );
+
+    let mut dirs: Vec<_> = SkillService::scan_unmanaged()
+        .expect("scan unmanaged")
+        .into_iter()
+        .map(|s| s.directory)
+        .collect();
+    dirs.sort();
+    assert_eq!(dirs, ["local-skill"]);
+
+    let mut dirs: Vec<_> = SkillService::scan_unmanaged_with(true)
+        .expect("scan unmanaged following symlinks")
+        .into_iter()
+        .map(|s| s.directory)
+        .collect();
+    dirs.sort();
+    assert_eq!(dirs, ["linked-skill", "local-skill"]);
+}
+
+#[test]
+fn copy_sync_updates_changed_files_and_keeps_unchanged_ones() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let source = home.join(".cc-switch").join("skills").join("big-skill");
+    write_skill_md(&source, "Big Skill", "Copied incrementally");
+    std::fs::write(source.join("data.txt"), "v1").expect("write data");
+
+    SkillService::sync_to_app_dir("big-skill", &AppType::Claude, SyncMethod::Copy)
+        .expect("initial copy");
+    let dest = home.join(".claude").join("skills").join("big-skill");
+    std::fs::write(dest.join("stale.txt"), "old").expect("write stale file");
+    let skill_md_mtime = std::fs::metadata(dest.join("SKILL.md"))
+        .and_then(|m| m.modified())
+        .expect("SKILL.md mtime");
+
+    std::thread::sleep(std::time::Duration::from_millis(20));
+    std::fs::write(source.join("data.txt"), "version 2").expect("update data");
+    SkillService::sync_to_app_dir("big-skill", &AppType::Claude, SyncMethod::Copy)
+        .expect("incremental copy");
+
+    assert_eq!(
+        std::fs::read_to_string(dest.join("data.txt")).expect("read data"),
+        "version 2"
+    );
+    assert!(!dest.join("stale.txt").exists(), "stale files are removed");
+    assert_eq!(
+        std::fs::metadata(dest.join("SKILL.md"))
+            .and_then(|m| m.modified())
+            .expect("SKILL.md mtime"),
+        skill_md_mtime,
+        "unchanged files are not re-copied"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn auto_sync_symlink_clears_recorded_copy_fallback() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+    cc_switch_lib::update_settings(cc_switch_lib::AppSettings::default()).expect("reset settings");
+
+    let source = home.join(".cc-switch").join("skills").join("linked");
+    write_skill_md(&source, "Linked", "Symlinked in auto mode");
+
+    // 回退记录保存在 Skills 索引（数据库）中
+    Database::init()
+        .expect("init db")
+        .set_setting("skills_copy_fallbacks", r#"["linked","other"]"#)
+        .expect("record fallback");
+    assert_eq!(
+        SkillService::copy_fallbacks().expect("copy fallbacks"),
+        vec!["linked".to_string(), "other".to_string()]
+    );
+
+    let status = SkillService::sync_to_app_dir("linked", &AppType::Claude, SyncMethod::Auto)
+        .expect("auto sync");
+    assert_eq!(status, SkillSyncStatus::Symlinked);
+    assert_eq!(
+        SkillService::copy_fallbacks().expect("copy fallbacks"),
+        vec!["other".to_string()]
+    );
+}
+
+#[test]
+fn doctor_reports_unsynced_and_unmanaged_skills() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skills = home.join(".claude").join("skills");
+    write_skill_md(&claude_skills.join("managed-skill"), "Managed", "Imported");
+    SkillService::import_from_apps(vec!["managed-skill".to_string()]).expect("import skill");
+    std::fs::remove_dir_all(claude_skills.join("managed-skill")).expect("remove app copy");
+    write_skill_md(&claude_skills.join("stray-skill"), "Stray", "Not managed");
+
+    let report = DiagnosticReport::new("skills doctor", SkillService::doctor().expect("doctor"));
+    assert_eq!(report.status, DiagnosticStatus::Warn);
+
+    let check = |id: &str| {
+        report
+            .checks
+            .iter()
+            .find(|c| c.check == id)
+            .unwrap_or_else(|| panic!("missing check {id}"))
+    };
+    assert_eq!(check("skills.ssot_dirs").status, DiagnosticStatus::Ok);
+    assert_eq!(check("skills.app_sync").status, DiagnosticStatus::Warn);
+    assert_eq!(check("skills.app_sync").details, ["claude: managed-skill"]);
+    assert_eq!(
+        check("skills.app_sync").remediation.as_deref(),
+        Some("cc-switch skills sync")
+    );
+    assert_eq!(check("skills.unmanaged").details, ["stray-skill (claude)"]);
+}
+
+#[test]
+fn doctor_detects_and_repairs_orphaned_ssot_dirs() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let ssot = home.join(".cc-switch").join("skills");
+    write_skill_md(&ssot.join("ghost-skill"), "Ghost", "Lost its index record");
+
+    let orphans_check = || {
+        SkillService::doctor()
+            .expect("doctor")
+            .into_iter()
+            .find(|c| c.check == "skills.ssot_orphans")
+            .expect("orphan check")
+    };
+    let check = orphans_check();
+    assert_eq!(check.status, DiagnosticStatus::Warn);
+    assert_eq!(check.details, ["ghost-skill"]);
+
+    let registered = SkillService::register_orphaned_ssot_skills().expect("register");
+    assert_eq!(registered.len(), 1);
+    assert_eq!(registered[0].name, "Ghost");
+    assert_eq!(
+        registered[0].description.as_deref(),
+        Some("Lost its index record")
+    );
+    assert!(
+        !registered[0].apps.claude,
+        "re-registered skills start disabled"
+    );
+    assert_eq!(orphans_check().status, DiagnosticStatus::Ok);
+    assert!(SkillService::list_installed()
+        .expect("list installed")
+        .iter()
+        .any(|s| s.directory == "ghost-skill"));
+
+    write_skill_md(&ssot.join("junk-skill"), "Junk", "Delete me");
+    let deleted = SkillService::delete_orphaned_ssot_skills().expect("delete");
+    assert_eq!(deleted, ["junk-skill"]);
+    assert!(!ssot.join("junk-skill").exists());
+    assert!(ssot.join("ghost-skill").exists(), "tracked skills are kept");
+}
+
+#[test]
+fn undo_last_app_import_removes_only_newly_imported_dirs() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    let claude_skills = home.join(".claude").join("skills");
+    let codex_skills = home.join(".codex").join("skills");
+    write_skill_md(&claude_skills.join("fresh-skill"), "Fresh", "New import");
+    write_skill_md(&codex_skills.join("fresh-skill"), "Fresh", "New import");
+    write_skill_md(&claude_skills.join("other-skill"), "Other", "Not selected");
+
+    let preview = SkillService::preview_import_from_apps(&["fresh-skill".to_string()])
+        .expect("preview import");
+    assert_eq!(preview.len(), 1);
+    assert_eq!(preview[0].found_in, ["claude", "codex"]);
+
+    let imported = SkillService::import_from_apps(vec!["fresh-skill".to_string()]).expect("import");
+    assert_eq!(imported.len(), 1);
+    assert_eq!(
+        SkillService::last_app_import().expect("record"),
+        ["fresh-skill"]
+    );
+
+    // 再次导入已纳管的目录不会覆盖撤销记录
+    SkillService::import_from_apps(vec!["fresh-skill".to_string()]).expect("re-import");
+    assert_eq!(
+        SkillService::last_app_import().expect("record"),
+        ["fresh-skill"]
+    );
+
+    let ssot = home.join(".cc-switch").join("skills");
+    assert!(ssot.join("fresh-skill").exists());
+
+    let removed = SkillService::undo_last_app_import().expect("undo");
+    assert_eq!(removed, ["fresh-skill"]);
+    assert!(!ssot.join("fresh-skill").exists());
+    assert!(
+        claude_skills.join("fresh-skill").join("SKILL.md").exists(),
+        "app-dir originals are kept"
+    );
+    assert!(!SkillService::list_installed()
+        .expect("list installed")
+        .iter()
+        .any(|s| s.directory == "fresh-skill"));
+    assert!(SkillService::undo_last_app_import()
+        .expect("second undo")
+        .is_empty());
+}
+
+#[test]
+fn installed_readme_url_matches_directory_or_id() {
+    let _guard = lock_test_mutex();
+    reset_test_fs();
+    let home = ensure_test_home();
+
+    write_skill_md(
+        &home.join(".claude").join("skills").join("pdf-tools"),
+        "PDF Tools",
+        "Work with PDFs",
+    );
+    SkillService::import_from_apps(vec!["pdf-tools".to_string()]).expect("import skill");
+    assert_eq!(
+        SkillService::installed_readme_url("pdf-tools").expect("lookup"),
+        None,
+        "local imports have no README link"
+    );
+
+    let mut index = SkillService::load_index().expect("load index");
+    let record = index.skills.get_mut("pdf-tools").expect("record");
+    record.id = "acme/skills:pdf-tools".to_string();
+    record.readme_url = Some("https://github.com/acme/skills/tree/main/pdf-tools".to_string());
+    SkillService::save_index(&index).expect("save index");
+
+    for spec in ["pdf-tools", "PDF-Tools", " acme/skills:pdf-tools "] {
+        assert_eq!(
+            SkillService::installed_readme_url(spec)
+                .expect("lookup")
+                .as_deref(),
+            Some("https://github.com/acme/skills/tree/main/pdf-tools"),
+            "{spec}"
+        );
+    }
+    assert_eq!(
+        SkillService::installed_readme_url("missing").expect("lookup"),
+        None
+    );
+}